@@ -139,6 +139,7 @@ impl pallet_message_queue::Config for Test {
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
 	type IdleMaxServiceWeight = ();
+	type OnPageReaped = ();
 	type QueuePausedQuery = ();
 }
 
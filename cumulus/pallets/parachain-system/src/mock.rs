@@ -124,6 +124,7 @@ impl pallet_message_queue::Config for Test {
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MaxWeight;
 	type IdleMaxServiceWeight = ();
+	type OnPageReaped = ();
 	type WeightInfo = ();
 }
 
@@ -454,9 +454,22 @@ impl pallet_multisig::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type OldCurrency = Balances;
+	type CallFee = pallet_multisig::TransactionPaymentFee<Runtime>;
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = MaxSignatories;
+	type MaxMetadataLen = ConstU32<64>;
+	type Preimages = ();
+	type BlockNumberProvider = System;
+	type Scheduler = ();
+	type MaxDeadline = ConstU32<0>;
+	type PalletsOrigin = OriginCaller;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -695,6 +708,7 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type OnPageReaped = ();
 }
 
 impl parachain_info::Config for Runtime {}
@@ -756,6 +770,7 @@ impl pallet_session::Config for Runtime {
 	// Essentially just Aura, but let's be pedantic.
 	type SessionHandler = <SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
+	type RequireProofOfPossession = ConstBool<false>;
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 }
 
@@ -1017,6 +1032,8 @@ pub type Migrations = (
 	cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
 	pallet_collator_selection::migration::v2::MigrationToV2<Runtime>,
 	frame_support::migrations::RemovePallet<StateTrieMigrationName, RocksDbWeight>,
+	// unreleased
+	pallet_multisig::migrations::v2::MigrateToV2<Runtime>,
 	// permanent
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 );
@@ -1044,7 +1061,8 @@ impl frame_support::traits::OnRuntimeUpgrade for InitStorageVersions {
 		}
 
 		if Multisig::on_chain_storage_version() == StorageVersion::new(0) {
-			Multisig::in_code_storage_version().put::<Multisig>();
+			// The operations are brought to the latest layout by the multisig migrations.
+			StorageVersion::new(1).put::<Multisig>();
 			writes.saturating_inc();
 		}
 
@@ -1364,6 +1382,18 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_runtime_apis::transfers::TransferValidationApi<Block> for Runtime {
+		fn validate_transfer(
+			origin: VersionedLocation,
+			dest: VersionedLocation,
+			beneficiary: VersionedLocation,
+			assets: VersionedAssets,
+			fee_asset_item: u32,
+		) -> Vec<xcm_runtime_apis::transfers::TransferFailure> {
+			PolkadotXcm::validate_transfer(origin, dest, beneficiary, assets, fee_asset_item)
+		}
+	}
+
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
 		fn convert_location(location: VersionedLocation) -> Result<
 			AccountId,
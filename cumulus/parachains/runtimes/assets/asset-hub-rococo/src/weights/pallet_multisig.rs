@@ -62,104 +62,371 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(598, 0).saturating_mul(z.into()))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:0 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `262 + s * (2 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `12573`
 		// Minimum execution time: 44_768_000 picoseconds.
 		Weight::from_parts(33_662_218, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12573))
 			// Standard Error: 1_633
 			.saturating_add(Weight::from_parts(128_927, 0).saturating_mul(s.into()))
 			// Standard Error: 16
 			.saturating_add(Weight::from_parts(1_543, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:0)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[3, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
-		//  Estimated: `6811`
+		//  Estimated: `12568`
 		// Minimum execution time: 29_745_000 picoseconds.
 		Weight::from_parts(20_559_891, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12568))
 			// Standard Error: 914
 			.saturating_add(Weight::from_parts(103_601, 0).saturating_mul(s.into()))
 			// Standard Error: 8
 			.saturating_add(Weight::from_parts(1_504, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigTimelocks` (r:1 w:1)
+	/// Proof: `Multisig::MultisigTimelocks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::ExecutionDelays` (r:1 w:0)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigDeadlines` (r:1 w:1)
+	/// Proof: `Multisig::MultisigDeadlines` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:0 w:1)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancellationApprovals` (r:0 w:1)
+	/// Proof: `Multisig::CancellationApprovals` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:1 w:1)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385 + s * (33 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `28006`
 		// Minimum execution time: 51_506_000 picoseconds.
 		Weight::from_parts(36_510_777, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 28006))
 			// Standard Error: 2_183
 			.saturating_add(Weight::from_parts(183_764, 0).saturating_mul(s.into()))
 			// Standard Error: 21
 			.saturating_add(Weight::from_parts(1_653, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigTimelocks` (r:1 w:1)
+	/// Proof: `Multisig::MultisigTimelocks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::ExecutionDelays` (r:1 w:0)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigDeadlines` (r:1 w:1)
+	/// Proof: `Multisig::MultisigDeadlines` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:0 w:1)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancellationApprovals` (r:0 w:1)
+	/// Proof: `Multisig::CancellationApprovals` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:1 w:1)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_with_signatures(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `385 + s * (33 ±0)`
+		//  Estimated: `28006`
+		// Not measured: `as_multi_complete` plus one `sr25519_verification` of
+		// `frame_benchmarking` per signatory, until the benchmark is run.
+		Weight::from_parts(41_787_879, 0)
+			.saturating_add(Weight::from_parts(0, 28006))
+			.saturating_add(Weight::from_parts(40_794_275, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(1_653, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:0 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `263 + s * (2 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `12573`
 		// Minimum execution time: 31_072_000 picoseconds.
 		Weight::from_parts(32_408_621, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12573))
 			// Standard Error: 913
 			.saturating_add(Weight::from_parts(121_410, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
-		//  Estimated: `6811`
+		//  Estimated: `10013`
 		// Minimum execution time: 18_301_000 picoseconds.
 		Weight::from_parts(18_223_547, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 10013))
 			// Standard Error: 747
 			.saturating_add(Weight::from_parts(114_584, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `454 + s * (1 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `10013`
 		// Minimum execution time: 32_107_000 picoseconds.
 		Weight::from_parts(33_674_827, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 10013))
 			// Standard Error: 1_220
 			.saturating_add(Weight::from_parts(122_011, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:0)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_expiry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `418 + s * (1 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 18_813_000 picoseconds.
+		Weight::from_parts(20_066_622, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 706
+			.saturating_add(Weight::from_parts(117_679, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:0 w:1)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	fn reap_expired() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `552`
+		//  Estimated: `10013`
+		// Minimum execution time: 30_895_000 picoseconds.
+		Weight::from_parts(32_483_264, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:200 w:200)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:100 w:200)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:100 w:200)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `c` is `[0, 100]`.
+	fn migrate_multisig(s: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178 + c * (179 ±0)`
+		//  Estimated: `6196 + c * (9023 ±0)`
+		// Minimum execution time: 61_346_000 picoseconds.
+		Weight::from_parts(62_754_868, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 1_356
+			.saturating_add(Weight::from_parts(26_424, 0).saturating_mul(s.into()))
+			// Standard Error: 1_270
+			.saturating_add(Weight::from_parts(28_915_520, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 9023).saturating_mul(c.into()))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:0 w:1)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_store_call(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `209 + s * (2 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 41_558_000 picoseconds.
+		Weight::from_parts(42_832_398, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_263
+			.saturating_add(Weight::from_parts(131_411, 0).saturating_mul(s.into()))
+			// Standard Error: 12
+			.saturating_add(Weight::from_parts(2_233, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancellationApprovals` (r:1 w:1)
+	/// Proof: `Multisig::CancellationApprovals` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_multi_by_threshold(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `562 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 33_147_000 picoseconds.
+		Weight::from_parts(35_293_450, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_360
+			.saturating_add(Weight::from_parts(147_655, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:1 w:0)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:1 w:0)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn poke_deposit(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `624 + s * (1 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 41_893_000 picoseconds.
+		Weight::from_parts(43_443_795, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_332
+			.saturating_add(Weight::from_parts(126_885, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn revoke_approval(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 18_490_000 picoseconds.
+		Weight::from_parts(19_846_962, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_208
+			.saturating_add(Weight::from_parts(120_518, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::ExecutionDelays` (r:0 w:1)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_execution_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_304_000 picoseconds.
+		Weight::from_parts(5_537_398, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn reject_as_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `671 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 34_953_000 picoseconds.
+		Weight::from_parts(36_876_500, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_463
+			.saturating_add(Weight::from_parts(156_794, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::CreatedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::CreatedMultisigs` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `6196`
+		// Minimum execution time: 55_701_000 picoseconds.
+		Weight::from_parts(57_700_993, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 1_788
+			.saturating_add(Weight::from_parts(128_634, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Multisig::CreatedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::CreatedMultisigs` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn destroy_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338`
+		//  Estimated: `6196`
+		// Minimum execution time: 43_175_000 picoseconds.
+		Weight::from_parts(44_390_252, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
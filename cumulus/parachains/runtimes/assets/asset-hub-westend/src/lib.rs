@@ -448,9 +448,22 @@ impl pallet_multisig::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type OldCurrency = Balances;
+	type CallFee = pallet_multisig::TransactionPaymentFee<Runtime>;
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = MaxSignatories;
+	type MaxMetadataLen = ConstU32<64>;
+	type Preimages = ();
+	type BlockNumberProvider = System;
+	type Scheduler = ();
+	type MaxDeadline = ConstU32<0>;
+	type PalletsOrigin = OriginCaller;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -691,6 +704,7 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type OnPageReaped = ();
 }
 
 impl cumulus_pallet_aura_ext::Config for Runtime {}
@@ -751,6 +765,7 @@ impl pallet_session::Config for Runtime {
 	// Essentially just Aura, but let's be pedantic.
 	type SessionHandler = <SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
+	type RequireProofOfPossession = ConstBool<false>;
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 }
 
@@ -1009,6 +1024,7 @@ pub type Migrations = (
 	pallet_collator_selection::migration::v2::MigrationToV2<Runtime>,
 	// unreleased
 	pallet_multisig::migrations::v1::MigrateToV1<Runtime>,
+	pallet_multisig::migrations::v2::MigrateToV2<Runtime>,
 	// unreleased
 	InitStorageVersions,
 	// unreleased
@@ -1392,6 +1408,18 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_runtime_apis::transfers::TransferValidationApi<Block> for Runtime {
+		fn validate_transfer(
+			origin: VersionedLocation,
+			dest: VersionedLocation,
+			beneficiary: VersionedLocation,
+			assets: VersionedAssets,
+			fee_asset_item: u32,
+		) -> Vec<xcm_runtime_apis::transfers::TransferFailure> {
+			PolkadotXcm::validate_transfer(origin, dest, beneficiary, assets, fee_asset_item)
+		}
+	}
+
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
 		fn convert_location(location: VersionedLocation) -> Result<
 			AccountId,
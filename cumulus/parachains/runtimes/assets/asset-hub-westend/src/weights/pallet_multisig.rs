@@ -61,104 +61,371 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(454, 0).saturating_mul(z.into()))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:0 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `262 + s * (2 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `12573`
 		// Minimum execution time: 44_573_000 picoseconds.
 		Weight::from_parts(32_633_219, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12573))
 			// Standard Error: 1_256
 			.saturating_add(Weight::from_parts(131_767, 0).saturating_mul(s.into()))
 			// Standard Error: 12
 			.saturating_add(Weight::from_parts(1_512, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:0)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[3, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
-		//  Estimated: `6811`
+		//  Estimated: `12568`
 		// Minimum execution time: 30_035_000 picoseconds.
 		Weight::from_parts(20_179_371, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12568))
 			// Standard Error: 827
 			.saturating_add(Weight::from_parts(110_520, 0).saturating_mul(s.into()))
 			// Standard Error: 8
 			.saturating_add(Weight::from_parts(1_419, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigTimelocks` (r:1 w:1)
+	/// Proof: `Multisig::MultisigTimelocks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::ExecutionDelays` (r:1 w:0)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigDeadlines` (r:1 w:1)
+	/// Proof: `Multisig::MultisigDeadlines` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:0 w:1)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancellationApprovals` (r:0 w:1)
+	/// Proof: `Multisig::CancellationApprovals` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:1 w:1)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385 + s * (33 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `28006`
 		// Minimum execution time: 50_444_000 picoseconds.
 		Weight::from_parts(36_060_265, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 28006))
 			// Standard Error: 1_604
 			.saturating_add(Weight::from_parts(187_796, 0).saturating_mul(s.into()))
 			// Standard Error: 15
 			.saturating_add(Weight::from_parts(1_506, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigTimelocks` (r:1 w:1)
+	/// Proof: `Multisig::MultisigTimelocks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::ExecutionDelays` (r:1 w:0)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigDeadlines` (r:1 w:1)
+	/// Proof: `Multisig::MultisigDeadlines` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:0 w:1)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancellationApprovals` (r:0 w:1)
+	/// Proof: `Multisig::CancellationApprovals` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:1 w:1)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_with_signatures(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `385 + s * (33 ±0)`
+		//  Estimated: `28006`
+		// Not measured: `as_multi_complete` plus one `sr25519_verification` of
+		// `frame_benchmarking` per signatory, until the benchmark is run.
+		Weight::from_parts(41_337_367, 0)
+			.saturating_add(Weight::from_parts(0, 28006))
+			.saturating_add(Weight::from_parts(40_798_307, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(1_506, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:0 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `263 + s * (2 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `12573`
 		// Minimum execution time: 30_298_000 picoseconds.
 		Weight::from_parts(31_284_628, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12573))
 			// Standard Error: 924
 			.saturating_add(Weight::from_parts(132_724, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
-		//  Estimated: `6811`
+		//  Estimated: `10013`
 		// Minimum execution time: 17_486_000 picoseconds.
 		Weight::from_parts(18_518_530, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 10013))
 			// Standard Error: 1_274
 			.saturating_add(Weight::from_parts(103_767, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `454 + s * (1 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `10013`
 		// Minimum execution time: 31_236_000 picoseconds.
 		Weight::from_parts(32_663_816, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 10013))
 			// Standard Error: 1_445
 			.saturating_add(Weight::from_parts(131_060, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:0)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_expiry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `418 + s * (1 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 19_117_000 picoseconds.
+		Weight::from_parts(20_391_439, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 639
+			.saturating_add(Weight::from_parts(106_570, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:0 w:1)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	fn reap_expired() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `552`
+		//  Estimated: `10013`
+		// Minimum execution time: 29_968_000 picoseconds.
+		Weight::from_parts(31_508_027, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:200 w:200)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:100 w:200)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:100 w:200)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `c` is `[0, 100]`.
+	fn migrate_multisig(s: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178 + c * (179 ±0)`
+		//  Estimated: `6196 + c * (9023 ±0)`
+		// Minimum execution time: 59_504_000 picoseconds.
+		Weight::from_parts(60_870_794, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 1_457
+			.saturating_add(Weight::from_parts(28_383, 0).saturating_mul(s.into()))
+			// Standard Error: 1_232
+			.saturating_add(Weight::from_parts(28_047_397, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 9023).saturating_mul(c.into()))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:0 w:1)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_store_call(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `209 + s * (2 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 40_288_000 picoseconds.
+		Weight::from_parts(41_523_082, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_290
+			.saturating_add(Weight::from_parts(134_306, 0).saturating_mul(s.into()))
+			// Standard Error: 12
+			.saturating_add(Weight::from_parts(2_188, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancellationApprovals` (r:1 w:1)
+	/// Proof: `Multisig::CancellationApprovals` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_multi_by_threshold(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `562 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 32_152_000 picoseconds.
+		Weight::from_parts(34_233_844, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_461
+			.saturating_add(Weight::from_parts(158_605, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:1 w:0)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:1 w:0)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn poke_deposit(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `624 + s * (1 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 40_636_000 picoseconds.
+		Weight::from_parts(42_139_493, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_431
+			.saturating_add(Weight::from_parts(136_296, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn revoke_approval(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 18_790_000 picoseconds.
+		Weight::from_parts(20_168_223, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_094
+			.saturating_add(Weight::from_parts(109_141, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::ExecutionDelays` (r:0 w:1)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_execution_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_478_000 picoseconds.
+		Weight::from_parts(5_719_710, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn reject_as_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `671 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 33_903_000 picoseconds.
+		Weight::from_parts(35_769_366, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_572
+			.saturating_add(Weight::from_parts(168_422, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::CreatedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::CreatedMultisigs` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `6196`
+		// Minimum execution time: 53_769_000 picoseconds.
+		Weight::from_parts(55_699_812, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 1_955
+			.saturating_add(Weight::from_parts(140_622, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Multisig::CreatedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::CreatedMultisigs` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn destroy_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338`
+		//  Estimated: `6196`
+		// Minimum execution time: 41_879_000 picoseconds.
+		Weight::from_parts(43_057_535, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
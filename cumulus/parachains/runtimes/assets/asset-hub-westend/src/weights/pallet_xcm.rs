@@ -393,4 +393,64 @@ impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `PolkadotXcm::FeeSponsors` (r:0 w:1)
+	/// Proof: `PolkadotXcm::FeeSponsors` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn register_fee_sponsor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_912_000 picoseconds.
+		Weight::from_parts(8_912_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `PolkadotXcm::FeeSponsors` (r:1 w:1)
+	/// Proof: `PolkadotXcm::FeeSponsors` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn deregister_fee_sponsor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141`
+		//  Estimated: `3606`
+		// Minimum execution time: 14_287_000 picoseconds.
+		Weight::from_parts(14_287_000, 0)
+			.saturating_add(Weight::from_parts(0, 3606))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `PolkadotXcm::FeeSponsors` (r:1 w:0)
+	/// Proof: `PolkadotXcm::FeeSponsors` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::FeeSponsoredOrigins` (r:0 w:1)
+	/// Proof: `PolkadotXcm::FeeSponsoredOrigins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_fee_sponsored_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141`
+		//  Estimated: `3606`
+		// Minimum execution time: 15_033_000 picoseconds.
+		Weight::from_parts(15_033_000, 0)
+			.saturating_add(Weight::from_parts(0, 3606))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `PolkadotXcm::SandboxedExecutionLimits` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SandboxedExecutionLimits` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::SandboxedExecutionUsage` (r:1 w:1)
+	/// Proof: `PolkadotXcm::SandboxedExecutionUsage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn execute_sandboxed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145`
+		//  Estimated: `3610`
+		// Minimum execution time: 17_362_000 picoseconds.
+		Weight::from_parts(17_362_000, 0)
+			.saturating_add(Weight::from_parts(0, 3610))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `PolkadotXcm::SandboxedExecutionLimits` (r:0 w:1)
+	/// Proof: `PolkadotXcm::SandboxedExecutionLimits` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_sandbox_limits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_841_000 picoseconds.
+		Weight::from_parts(7_841_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
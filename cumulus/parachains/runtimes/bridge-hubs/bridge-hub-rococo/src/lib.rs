@@ -151,6 +151,7 @@ pub type UncheckedExtrinsic =
 pub type Migrations = (
 	pallet_collator_selection::migration::v2::MigrationToV2<Runtime>,
 	pallet_multisig::migrations::v1::MigrateToV1<Runtime>,
+	pallet_multisig::migrations::v2::MigrateToV2<Runtime>,
 	InitStorageVersions,
 	// unreleased
 	cumulus_pallet_xcmp_queue::migration::v4::MigrationToV4<Runtime>,
@@ -398,6 +399,7 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type OnPageReaped = ();
 }
 
 impl cumulus_pallet_aura_ext::Config for Runtime {}
@@ -456,6 +458,7 @@ impl pallet_session::Config for Runtime {
 	// Essentially just Aura, but let's be pedantic.
 	type SessionHandler = <SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
+	type RequireProofOfPossession = ConstBool<false>;
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 }
 
@@ -501,9 +504,22 @@ impl pallet_multisig::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type OldCurrency = Balances;
+	type CallFee = pallet_multisig::TransactionPaymentFee<Runtime>;
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU32<100>;
+	type MaxMetadataLen = ConstU32<64>;
+	type Preimages = ();
+	type BlockNumberProvider = System;
+	type Scheduler = ();
+	type MaxDeadline = ConstU32<0>;
+	type PalletsOrigin = OriginCaller;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as sp_runtime::traits::Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -62,104 +62,371 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(626, 0).saturating_mul(z.into()))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:0 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `263 + s * (2 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `12573`
 		// Minimum execution time: 44_067_000 picoseconds.
 		Weight::from_parts(33_432_998, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12573))
 			// Standard Error: 1_250
 			.saturating_add(Weight::from_parts(131_851, 0).saturating_mul(s.into()))
 			// Standard Error: 12
 			.saturating_add(Weight::from_parts(1_459, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:0)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[3, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
-		//  Estimated: `6811`
+		//  Estimated: `12568`
 		// Minimum execution time: 29_373_000 picoseconds.
 		Weight::from_parts(19_409_201, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12568))
 			// Standard Error: 725
 			.saturating_add(Weight::from_parts(110_824, 0).saturating_mul(s.into()))
 			// Standard Error: 7
 			.saturating_add(Weight::from_parts(1_502, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigTimelocks` (r:1 w:1)
+	/// Proof: `Multisig::MultisigTimelocks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::ExecutionDelays` (r:1 w:0)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigDeadlines` (r:1 w:1)
+	/// Proof: `Multisig::MultisigDeadlines` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:0 w:1)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancellationApprovals` (r:0 w:1)
+	/// Proof: `Multisig::CancellationApprovals` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:1 w:1)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `388 + s * (33 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `28006`
 		// Minimum execution time: 49_724_000 picoseconds.
 		Weight::from_parts(34_153_321, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 28006))
 			// Standard Error: 1_376
 			.saturating_add(Weight::from_parts(174_634, 0).saturating_mul(s.into()))
 			// Standard Error: 13
 			.saturating_add(Weight::from_parts(1_753, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigTimelocks` (r:1 w:1)
+	/// Proof: `Multisig::MultisigTimelocks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::ExecutionDelays` (r:1 w:0)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigDeadlines` (r:1 w:1)
+	/// Proof: `Multisig::MultisigDeadlines` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:0 w:1)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancellationApprovals` (r:0 w:1)
+	/// Proof: `Multisig::CancellationApprovals` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:1 w:1)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_with_signatures(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `388 + s * (33 ±0)`
+		//  Estimated: `28006`
+		// Not measured: `as_multi_complete` plus one `sr25519_verification` of
+		// `frame_benchmarking` per signatory, until the benchmark is run.
+		Weight::from_parts(39_430_423, 0)
+			.saturating_add(Weight::from_parts(0, 28006))
+			.saturating_add(Weight::from_parts(40_785_145, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(1_753, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:0 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `263 + s * (2 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `12573`
 		// Minimum execution time: 31_081_000 picoseconds.
 		Weight::from_parts(31_552_702, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12573))
 			// Standard Error: 1_066
 			.saturating_add(Weight::from_parts(135_081, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
-		//  Estimated: `6811`
+		//  Estimated: `10013`
 		// Minimum execution time: 17_807_000 picoseconds.
 		Weight::from_parts(18_241_044, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 10013))
 			// Standard Error: 768
 			.saturating_add(Weight::from_parts(112_957, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `454 + s * (1 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `10013`
 		// Minimum execution time: 32_421_000 picoseconds.
 		Weight::from_parts(32_554_061, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 10013))
 			// Standard Error: 1_157
 			.saturating_add(Weight::from_parts(141_221, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:0)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_expiry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `418 + s * (1 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 18_831_000 picoseconds.
+		Weight::from_parts(20_085_889, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 696
+			.saturating_add(Weight::from_parts(116_008, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:0 w:1)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	fn reap_expired() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `552`
+		//  Estimated: `10013`
+		// Minimum execution time: 29_867_000 picoseconds.
+		Weight::from_parts(31_402_156, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:200 w:200)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:100 w:200)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:100 w:200)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `c` is `[0, 100]`.
+	fn migrate_multisig(s: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178 + c * (179 ±0)`
+		//  Estimated: `6196 + c * (9023 ±0)`
+		// Minimum execution time: 59_304_000 picoseconds.
+		Weight::from_parts(60_666_260, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 1_570
+			.saturating_add(Weight::from_parts(30_584, 0).saturating_mul(s.into()))
+			// Standard Error: 1_228
+			.saturating_add(Weight::from_parts(27_953_153, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 9023).saturating_mul(c.into()))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:0 w:1)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_store_call(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `209 + s * (2 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 41_275_000 picoseconds.
+		Weight::from_parts(42_540_735, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_291
+			.saturating_add(Weight::from_parts(134_391, 0).saturating_mul(s.into()))
+			// Standard Error: 11
+			.saturating_add(Weight::from_parts(2_112, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancellationApprovals` (r:1 w:1)
+	/// Proof: `Multisig::CancellationApprovals` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_multi_by_threshold(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `562 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 32_044_000 picoseconds.
+		Weight::from_parts(34_118_813, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_574
+			.saturating_add(Weight::from_parts(170_902, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:1 w:0)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:1 w:0)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn poke_deposit(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `624 + s * (1 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 40_499_000 picoseconds.
+		Weight::from_parts(41_997_898, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_541
+			.saturating_add(Weight::from_parts(146_862, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn revoke_approval(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 18_508_000 picoseconds.
+		Weight::from_parts(19_866_017, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_191
+			.saturating_add(Weight::from_parts(118_807, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::ExecutionDelays` (r:0 w:1)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_execution_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_326_000 picoseconds.
+		Weight::from_parts(5_560_974, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn reject_as_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `671 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 33_790_000 picoseconds.
+		Weight::from_parts(35_649_176, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_694
+			.saturating_add(Weight::from_parts(181_480, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::CreatedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::CreatedMultisigs` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `6196`
+		// Minimum execution time: 54_230_000 picoseconds.
+		Weight::from_parts(56_177_097, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 1_989
+			.saturating_add(Weight::from_parts(143_119, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Multisig::CreatedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::CreatedMultisigs` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn destroy_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338`
+		//  Estimated: `6196`
+		// Minimum execution time: 41_738_000 picoseconds.
+		Weight::from_parts(42_912_855, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
@@ -152,8 +152,11 @@ impl pallet_referenda::Config<AmbassadorReferendaInstance> for Runtime {
 	type MaxQueued = ConstU32<20>;
 	type UndecidingTimeout = UndecidingTimeout;
 	type AlarmInterval = AlarmInterval;
+	type MaxEnactmentHorizon = MaxSchedulingHorizon;
 	type Tracks = tracks::TracksInfo;
 	type Preimages = Preimage;
+	type ProposalValue = ();
+	type ProposalConsideration = ();
 }
 
 parameter_types! {
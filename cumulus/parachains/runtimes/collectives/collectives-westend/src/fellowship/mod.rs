@@ -22,8 +22,8 @@ use crate::{
 	weights,
 	xcm_config::{FellowshipAdminBodyId, LocationToAccountId, TreasurerBodyId, UsdtAssetHub},
 	AccountId, AssetRate, Balance, Balances, FellowshipReferenda, GovernanceLocation,
-	ParachainInfo, Preimage, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Scheduler,
-	WestendTreasuryAccount, DAYS,
+	MaxSchedulingHorizon, ParachainInfo, Preimage, Runtime, RuntimeCall, RuntimeEvent,
+	RuntimeOrigin, Scheduler, WestendTreasuryAccount, DAYS,
 };
 use cumulus_primitives_core::ParaId;
 use frame_support::{
@@ -43,7 +43,8 @@ use pallet_ranked_collective::EnsureOfRank;
 use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
 use parachains_common::impls::ToParentTreasury;
 use polkadot_runtime_common::impls::{
-	ContainsParts, LocatableAssetConverter, VersionedLocatableAsset, VersionedLocationConverter,
+	AnyVersionConversion, ContainsParts, LocatableAssetConverter, VersionedLocatableAsset,
+	VersionedLocationConverter,
 };
 use sp_arithmetic::Permill;
 use sp_core::{ConstU128, ConstU32, ConstU8};
@@ -104,8 +105,11 @@ impl pallet_referenda::Config<FellowshipReferendaInstance> for Runtime {
 	type MaxQueued = ConstU32<100>;
 	type UndecidingTimeout = ConstU32<{ 7 * DAYS }>;
 	type AlarmInterval = ConstU32<1>;
+	type MaxEnactmentHorizon = MaxSchedulingHorizon;
 	type Tracks = tracks::TracksInfo;
 	type Preimages = Preimage;
+	type ProposalValue = ();
+	type ProposalConsideration = ();
 }
 
 pub type FellowshipCollectiveInstance = pallet_ranked_collective::Instance1;
@@ -325,9 +329,12 @@ impl pallet_treasury::Config<FellowshipTreasuryInstance> for Runtime {
 				xcm_builder::IsParentsOnly<ConstU8<1>>,
 			>,
 		>,
-		AssetRate,
+		AnyVersionConversion<AssetRate>,
 	>;
 	type PayoutPeriod = ConstU32<{ 30 * DAYS }>;
+	type Clawback = ();
+	type SpendSubmitter = ();
+	type SpendConsideration = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments<
 		sp_core::ConstU8<1>,
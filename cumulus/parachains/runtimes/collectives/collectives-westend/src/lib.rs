@@ -247,9 +247,22 @@ impl pallet_multisig::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type OldCurrency = Balances;
+	type CallFee = pallet_multisig::TransactionPaymentFee<Runtime>;
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU32<100>;
+	type MaxMetadataLen = ConstU32<64>;
+	type Preimages = Preimage;
+	type BlockNumberProvider = System;
+	type Scheduler = Scheduler;
+	type MaxDeadline = ConstU32<{ 30 * DAYS }>;
+	type PalletsOrigin = OriginCaller;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as sp_runtime::traits::Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -430,6 +443,7 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type OnPageReaped = ();
 }
 
 impl cumulus_pallet_aura_ext::Config for Runtime {}
@@ -488,6 +502,7 @@ impl pallet_session::Config for Runtime {
 	// Essentially just Aura, but let's be pedantic.
 	type SessionHandler = <SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
+	type RequireProofOfPossession = ConstBool<false>;
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 }
 
@@ -588,6 +603,10 @@ impl pallet_alliance::Config for Runtime {
 
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+	pub const MaximumSchedulerBlockTime: u64 = 2 * SLOT_DURATION;
+	pub const SchedulerDeposit: Balance = deposit(1, 128);
+	pub const MaxScheduledPerOrigin: Option<u32> = None;
+	pub const MaxSchedulingHorizon: Option<BlockNumber> = Some(365 * DAYS);
 }
 
 #[cfg(not(feature = "runtime-benchmarks"))]
@@ -608,9 +627,22 @@ impl pallet_scheduler::Config for Runtime {
 	type MaximumWeight = MaximumSchedulerWeight;
 	type ScheduleOrigin = EnsureRoot<AccountId>;
 	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type BlockNumberProvider = System;
 	type WeightInfo = weights::pallet_scheduler::WeightInfo<Runtime>;
 	type OriginPrivilegeCmp = EqualOrGreatestRootCmp;
 	type Preimages = Preimage;
+	type RejectOverweight = ConstBool<false>;
+	type TimeProvider = Timestamp;
+	type MaximumBlockTime = MaximumSchedulerBlockTime;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type ScheduleDeposit = SchedulerDeposit;
+	type MaxScheduledPerOrigin = MaxScheduledPerOrigin;
+	type MaxSchedulingHorizon = MaxSchedulingHorizon;
+	type MaxMergedAgendas = ConstU32<1>;
+	type MaxIdleWeight = ();
+	type MaxOverflowed = ConstU32<100>;
+	type OnChargeDispatch = ();
 }
 
 parameter_types! {
@@ -628,8 +660,10 @@ impl pallet_preimage::Config for Runtime {
 		AccountId,
 		Balances,
 		PreimageHoldReason,
-		LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+		sp_runtime::traits::Identity,
+		Balance,
 	>;
+	type DepositPrice = LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>;
 }
 
 impl pallet_asset_rate::Config for Runtime {
@@ -643,6 +677,7 @@ impl pallet_asset_rate::Config for Runtime {
 	type UpdateOrigin = Self::CreateOrigin;
 	type Currency = Balances;
 	type AssetKind = VersionedLocatableAsset;
+	type NativeDecimals = ConstU8<12>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::AssetRateArguments;
 }
@@ -743,12 +778,18 @@ type Migrations = (
 	// unreleased
 	cumulus_pallet_xcmp_queue::migration::v4::MigrationToV4<Runtime>,
 	cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
+	// unreleased
+	pallet_multisig::migrations::v2::MigrateToV2<Runtime>,
 	// permanent
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 	// unreleased
 	pallet_core_fellowship::migration::MigrateV0ToV1<Runtime, FellowshipCoreInstance>,
 	// unreleased
 	pallet_core_fellowship::migration::MigrateV0ToV1<Runtime, AmbassadorCoreInstance>,
+	// unreleased
+	pallet_asset_rate::migration::v1::MigrateV0ToV1<Runtime, frame_support::traits::GetDefault>,
+	// unreleased
+	pallet_scheduler::migration::v5::MigrateToV5<Runtime>,
 );
 
 /// Executive: handles dispatch to the various modules.
@@ -897,6 +938,32 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_scheduler::runtime_api::SchedulerApi<Block, BlockNumber, Hash> for Runtime {
+		fn lookup(
+			id: frame_support::traits::schedule::v3::TaskName,
+		) -> Option<pallet_scheduler::TaskSummary<BlockNumber, Hash>> {
+			Scheduler::lookup(id)
+		}
+
+		fn agenda(when: BlockNumber) -> Vec<pallet_scheduler::TaskSummary<BlockNumber, Hash>> {
+			Scheduler::agenda(when)
+		}
+
+		fn next_dispatch(
+			id: frame_support::traits::schedule::v3::TaskName,
+		) -> Option<BlockNumber> {
+			Scheduler::next_dispatch(id)
+		}
+
+		fn agenda_occupancy(when: BlockNumber) -> pallet_scheduler::AgendaOccupancy {
+			Scheduler::agenda_occupancy(when)
+		}
+
+		fn total_scheduled() -> u32 {
+			Scheduler::total_scheduled()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
@@ -72,12 +72,28 @@ impl<T: frame_system::Config> pallet_asset_rate::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:1)
 	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(1238), added: 3713, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRate::AssetDecimals` (r:0 w:1)
+	/// Proof: `AssetRate::AssetDecimals` (`max_values`: None, `max_size`: Some(1223), added: 3698, mode: `MaxEncodedLen`)
 	fn remove() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `74`
 		//  Estimated: `4703`
 		// Minimum execution time: 112_000_000 picoseconds.
 		Weight::from_parts(116_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4703))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:0)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(1238), added: 3713, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRate::AssetDecimals` (r:0 w:1)
+	/// Proof: `AssetRate::AssetDecimals` (`max_values`: None, `max_size`: Some(1223), added: 3698, mode: `MaxEncodedLen`)
+	fn set_decimals() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `74`
+		//  Estimated: `4703`
+		// Minimum execution time: 100_800_000 picoseconds.
+		Weight::from_parts(104_400_000, 0)
 			.saturating_add(Weight::from_parts(0, 4703))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
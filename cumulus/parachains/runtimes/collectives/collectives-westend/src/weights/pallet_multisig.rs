@@ -61,104 +61,385 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(500, 0).saturating_mul(z.into()))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:0 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `328 + s * (2 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `12573`
 		// Minimum execution time: 44_865_000 picoseconds.
 		Weight::from_parts(33_468_056, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12573))
 			// Standard Error: 1_513
 			.saturating_add(Weight::from_parts(130_544, 0).saturating_mul(s.into()))
 			// Standard Error: 14
 			.saturating_add(Weight::from_parts(1_422, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:0)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[3, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `348`
-		//  Estimated: `6811`
+		//  Estimated: `12568`
 		// Minimum execution time: 29_284_000 picoseconds.
 		Weight::from_parts(18_708_967, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12568))
 			// Standard Error: 916
 			.saturating_add(Weight::from_parts(119_202, 0).saturating_mul(s.into()))
 			// Standard Error: 8
 			.saturating_add(Weight::from_parts(1_447, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigTimelocks` (r:1 w:1)
+	/// Proof: `Multisig::MultisigTimelocks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::ExecutionDelays` (r:1 w:0)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigDeadlines` (r:1 w:1)
+	/// Proof: `Multisig::MultisigDeadlines` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:0 w:1)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancellationApprovals` (r:0 w:1)
+	/// Proof: `Multisig::CancellationApprovals` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:1 w:1)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `451 + s * (33 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `188818`
 		// Minimum execution time: 49_462_000 picoseconds.
 		Weight::from_parts(34_470_286, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 188818))
 			// Standard Error: 1_738
 			.saturating_add(Weight::from_parts(178_227, 0).saturating_mul(s.into()))
 			// Standard Error: 17
 			.saturating_add(Weight::from_parts(1_644, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(12))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigTimelocks` (r:1 w:1)
+	/// Proof: `Multisig::MultisigTimelocks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::ExecutionDelays` (r:1 w:0)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigDeadlines` (r:1 w:1)
+	/// Proof: `Multisig::MultisigDeadlines` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:0 w:1)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancellationApprovals` (r:0 w:1)
+	/// Proof: `Multisig::CancellationApprovals` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:1 w:1)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_with_signatures(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `451 + s * (33 ±0)`
+		//  Estimated: `188818`
+		// Not measured: `as_multi_complete` plus one `sr25519_verification` of
+		// `frame_benchmarking` per signatory, until the benchmark is run.
+		Weight::from_parts(39_747_388, 0)
+			.saturating_add(Weight::from_parts(0, 188818))
+			.saturating_add(Weight::from_parts(40_788_738, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(1_644, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(12))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:0 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `329 + s * (2 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `12573`
 		// Minimum execution time: 30_749_000 picoseconds.
 		Weight::from_parts(31_841_438, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12573))
 			// Standard Error: 1_033
 			.saturating_add(Weight::from_parts(123_126, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `348`
-		//  Estimated: `6811`
+		//  Estimated: `10013`
 		// Minimum execution time: 17_436_000 picoseconds.
 		Weight::from_parts(18_036_002, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 10013))
 			// Standard Error: 829
 			.saturating_add(Weight::from_parts(109_450, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
-	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `520 + s * (1 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `10013`
 		// Minimum execution time: 31_532_000 picoseconds.
 		Weight::from_parts(32_818_015, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 10013))
 			// Standard Error: 977
 			.saturating_add(Weight::from_parts(123_121, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:0)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_expiry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `418 + s * (1 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 18_619_000 picoseconds.
+		Weight::from_parts(19_860_109, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 674
+			.saturating_add(Weight::from_parts(112_406, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:1 w:1)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:0 w:1)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	fn reap_expired() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `552`
+		//  Estimated: `10013`
+		// Minimum execution time: 30_109_000 picoseconds.
+		Weight::from_parts(31_656_770, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:200 w:200)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:100 w:200)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigExpiries` (r:100 w:200)
+	/// Proof: `Multisig::MultisigExpiries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `c` is `[0, 100]`.
+	fn migrate_multisig(s: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178 + c * (179 ±0)`
+		//  Estimated: `6196 + c * (9023 ±0)`
+		// Minimum execution time: 59_785_000 picoseconds.
+		Weight::from_parts(61_158_152, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 1_369
+			.saturating_add(Weight::from_parts(26_664, 0).saturating_mul(s.into()))
+			// Standard Error: 1_238
+			.saturating_add(Weight::from_parts(28_179_802, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 9023).saturating_mul(c.into()))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageFor` (r:0 w:1)
+	/// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:0 w:1)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_store_call(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247 + s * (2 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 41_318_000 picoseconds.
+		Weight::from_parts(42_585_343, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_278
+			.saturating_add(Weight::from_parts(133_059, 0).saturating_mul(s.into()))
+			// Standard Error: 11
+			.saturating_add(Weight::from_parts(2_058, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancellationApprovals` (r:1 w:1)
+	/// Proof: `Multisig::CancellationApprovals` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_multi_by_threshold(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `562 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 32_304_000 picoseconds.
+		Weight::from_parts(34_395_454, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_372
+			.saturating_add(Weight::from_parts(148_998, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigMetadata` (r:1 w:0)
+	/// Proof: `Multisig::MultisigMetadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::MultisigCalls` (r:1 w:0)
+	/// Proof: `Multisig::MultisigCalls` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn poke_deposit(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `624 + s * (1 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 40_827_000 picoseconds.
+		Weight::from_parts(42_338_424, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_344
+			.saturating_add(Weight::from_parts(128_039, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn revoke_approval(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 18_300_000 picoseconds.
+		Weight::from_parts(19_642_710, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_154
+			.saturating_add(Weight::from_parts(115_118, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::ExecutionDelays` (r:0 w:1)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_execution_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_228_000 picoseconds.
+		Weight::from_parts(5_458_982, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn reject_as_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `671 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 34_064_000 picoseconds.
+		Weight::from_parts(35_938_225, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 1_477
+			.saturating_add(Weight::from_parts(158_220, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::CreatedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::CreatedMultisigs` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `6196`
+		// Minimum execution time: 54_726_000 picoseconds.
+		Weight::from_parts(56_691_169, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 1_813
+			.saturating_add(Weight::from_parts(130_452, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Multisig::CreatedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::CreatedMultisigs` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn destroy_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338`
+		//  Estimated: `6196`
+		// Minimum execution time: 42_076_000 picoseconds.
+		Weight::from_parts(43_260_800, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
@@ -222,4 +222,37 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Proxy::NextPureIndex` (r:1 w:1)
+	/// Proof: `Proxy::NextPureIndex` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::PureProxies` (r:0 w:1)
+	/// Proof: `Proxy::PureProxies` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	fn create_pure_namespaced() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `239`
+		//  Estimated: `4706`
+		// Minimum execution time: 31_208_000 picoseconds.
+		Weight::from_parts(32_054_000, 0)
+			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Proxy::PureProxies` (r:1 w:1)
+	/// Proof: `Proxy::PureProxies` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 30]`.
+	fn kill_pure_namespaced(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `396 + p * (37 ±0)`
+		//  Estimated: `4706`
+		// Minimum execution time: 28_731_000 picoseconds.
+		Weight::from_parts(29_562_114, 0)
+			.saturating_add(Weight::from_parts(0, 4706))
+			// Standard Error: 712
+			.saturating_add(Weight::from_parts(30_215, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -176,4 +176,32 @@ impl<T: frame_system::Config> pallet_ranked_collective::WeightInfo for WeightInf
 	fn exchange_member() -> Weight {
 		todo!()
 	}
+	/// Storage: `FellowshipCollective::Members` (r:100 w:100)
+	/// Proof: `FellowshipCollective::Members` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCollective::MemberCount` (r:100 w:100)
+	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCollective::IndexToId` (r:0 w:200)
+	/// Proof: `FellowshipCollective::IndexToId` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipCollective::IdToIndex` (r:0 w:200)
+	/// Proof: `FellowshipCollective::IdToIndex` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	/// The range of component `r` is `[0, 100]`.
+	fn import_members(n: u32, r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `1489 + n * (2529 ±0) + r * (2529 ±0)`
+		// Minimum execution time: 16_201_000 picoseconds.
+		Weight::from_parts(4_103_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			// Standard Error: 9_281
+			.saturating_add(Weight::from_parts(12_742_390, 0).saturating_mul(n.into()))
+			// Standard Error: 9_281
+			.saturating_add(Weight::from_parts(9_317_553, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2529).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2529).saturating_mul(r.into()))
+	}
 }
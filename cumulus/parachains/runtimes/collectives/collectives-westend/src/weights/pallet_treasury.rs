@@ -174,4 +174,18 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `FellowshipTreasury::Spends` (r:1 w:1)
+	/// Proof: `FellowshipTreasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipTreasury::VoidReasons` (r:0 w:1)
+	/// Proof: `FellowshipTreasury::VoidReasons` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn void_spend_with_reason() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `179`
+		//  Estimated: `5318`
+		// Minimum execution time: 136_000_000 picoseconds.
+		Weight::from_parts(138_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5318))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
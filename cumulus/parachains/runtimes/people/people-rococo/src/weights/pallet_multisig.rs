@@ -59,104 +59,371 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(528, 0).saturating_mul(z.into()))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:0 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `263 + s * (2 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `12573`
 		// Minimum execution time: 41_105_000 picoseconds.
 		Weight::from_parts(34_947_072, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12573))
 			// Standard Error: 499
 			.saturating_add(Weight::from_parts(67_375, 0).saturating_mul(s.into()))
 			// Standard Error: 4
 			.saturating_add(Weight::from_parts(1_227, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:0)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// The range of component `s` is `[3, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
-		//  Estimated: `6811`
+		//  Estimated: `12568`
 		// Minimum execution time: 26_640_000 picoseconds.
 		Weight::from_parts(21_515_344, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12568))
 			// Standard Error: 943
 			.saturating_add(Weight::from_parts(58_769, 0).saturating_mul(s.into()))
 			// Standard Error: 9
 			.saturating_add(Weight::from_parts(1_233, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:1)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigTimelocks (r:1 w:1)
+	/// Proof: Multisig MultisigTimelocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig ExecutionDelays (r:1 w:0)
+	/// Proof: Multisig ExecutionDelays (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigDeadlines (r:1 w:1)
+	/// Proof: Multisig MultisigDeadlines (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:0 w:1)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Multisig CancellationApprovals (r:0 w:1)
+	/// Proof: Multisig CancellationApprovals (max_values: None, max_size: Some(3250), added: 5725, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigCalls (r:1 w:1)
+	/// Proof: Multisig MultisigCalls (max_values: None, max_size: Some(187), added: 2662, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:1 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `388 + s * (33 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `28006`
 		// Minimum execution time: 45_875_000 picoseconds.
 		Weight::from_parts(38_052_994, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 28006))
 			// Standard Error: 507
 			.saturating_add(Weight::from_parts(82_957, 0).saturating_mul(s.into()))
 			// Standard Error: 4
 			.saturating_add(Weight::from_parts(1_277, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:1)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigTimelocks (r:1 w:1)
+	/// Proof: Multisig MultisigTimelocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig ExecutionDelays (r:1 w:0)
+	/// Proof: Multisig ExecutionDelays (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigDeadlines (r:1 w:1)
+	/// Proof: Multisig MultisigDeadlines (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:0 w:1)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Multisig CancellationApprovals (r:0 w:1)
+	/// Proof: Multisig CancellationApprovals (max_values: None, max_size: Some(3250), added: 5725, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigCalls (r:1 w:1)
+	/// Proof: Multisig MultisigCalls (max_values: None, max_size: Some(187), added: 2662, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:1 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_with_signatures(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `388 + s * (33 ±0)`
+		//  Estimated: `28006`
+		// Not measured: `as_multi_complete` plus one `sr25519_verification` of
+		// `frame_benchmarking` per signatory, until the benchmark is run.
+		Weight::from_parts(43_330_096, 0)
+			.saturating_add(Weight::from_parts(0, 28006))
+			.saturating_add(Weight::from_parts(40_693_468, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(1_277, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:0 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `263 + s * (2 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `12573`
 		// Minimum execution time: 32_359_000 picoseconds.
 		Weight::from_parts(33_845_761, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12573))
 			// Standard Error: 623
 			.saturating_add(Weight::from_parts(69_809, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
-		//  Estimated: `6811`
+		//  Estimated: `10013`
 		// Minimum execution time: 18_791_000 picoseconds.
 		Weight::from_parts(20_017_375, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 10013))
 			// Standard Error: 466
 			.saturating_add(Weight::from_parts(64_780, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `454 + s * (1 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `10013`
 		// Minimum execution time: 33_132_000 picoseconds.
 		Weight::from_parts(34_485_734, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 10013))
 			// Standard Error: 601
 			.saturating_add(Weight::from_parts(70_191, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Multisig Multisigs (r:1 w:0)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:1)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_expiry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `418 + s * (1 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 20_664_000 picoseconds.
+		Weight::from_parts(22_041_873, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 399
+			.saturating_add(Weight::from_parts(66_530, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:1)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:0 w:1)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	fn reap_expired() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `552`
+		//  Estimated: `10013`
+		// Minimum execution time: 31_639_000 picoseconds.
+		Weight::from_parts(33_265_478, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Multisig Multisigs (r:200 w:200)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:100 w:200)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:100 w:200)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `c` is `[0, 100]`.
+	fn migrate_multisig(s: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178 + c * (179 ±0)`
+		//  Estimated: `6196 + c * (9023 ±0)`
+		// Minimum execution time: 62_823_000 picoseconds.
+		Weight::from_parts(64_266_037, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 780
+			.saturating_add(Weight::from_parts(15_201, 0).saturating_mul(s.into()))
+			// Standard Error: 1_301
+			.saturating_add(Weight::from_parts(29_611_820, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 9023).saturating_mul(c.into()))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigCalls (r:0 w:1)
+	/// Proof: Multisig MultisigCalls (max_values: None, max_size: Some(187), added: 2662, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_store_call(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `209 + s * (2 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 43_144_000 picoseconds.
+		Weight::from_parts(44_467_269, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 660
+			.saturating_add(Weight::from_parts(68_673, 0).saturating_mul(s.into()))
+			// Standard Error: 9
+			.saturating_add(Weight::from_parts(1_776, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig CancellationApprovals (r:1 w:1)
+	/// Proof: Multisig CancellationApprovals (max_values: None, max_size: Some(3250), added: 5725, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_multi_by_threshold(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `562 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 33_945_000 picoseconds.
+		Weight::from_parts(36_143_335, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 782
+			.saturating_add(Weight::from_parts(84_943, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:1 w:0)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigCalls (r:1 w:0)
+	/// Proof: Multisig MultisigCalls (max_values: None, max_size: Some(187), added: 2662, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:1 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	fn poke_deposit(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `624 + s * (1 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 42_902_000 picoseconds.
+		Weight::from_parts(44_489_944, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 766
+			.saturating_add(Weight::from_parts(72_995, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn revoke_approval(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 20_310_000 picoseconds.
+		Weight::from_parts(21_800_590, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 683
+			.saturating_add(Weight::from_parts(68_135, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::ExecutionDelays` (r:0 w:1)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_execution_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_227_000 picoseconds.
+		Weight::from_parts(4_413_794, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn reject_as_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `671 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 35_795_000 picoseconds.
+		Weight::from_parts(37_764_505, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 842
+			.saturating_add(Weight::from_parts(90_201, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::CreatedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::CreatedMultisigs` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `6196`
+		// Minimum execution time: 58_171_000 picoseconds.
+		Weight::from_parts(60_259_708, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 1_028
+			.saturating_add(Weight::from_parts(73_963, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Multisig::CreatedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::CreatedMultisigs` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn destroy_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338`
+		//  Estimated: `6196`
+		// Minimum execution time: 44_215_000 picoseconds.
+		Weight::from_parts(45_459_192, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
@@ -59,104 +59,371 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(504, 0).saturating_mul(z.into()))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:0 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `263 + s * (2 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `12573`
 		// Minimum execution time: 41_128_000 picoseconds.
 		Weight::from_parts(35_215_592, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12573))
 			// Standard Error: 429
 			.saturating_add(Weight::from_parts(65_959, 0).saturating_mul(s.into()))
 			// Standard Error: 4
 			.saturating_add(Weight::from_parts(1_230, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:0)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// The range of component `s` is `[3, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
-		//  Estimated: `6811`
+		//  Estimated: `12568`
 		// Minimum execution time: 26_878_000 picoseconds.
 		Weight::from_parts(21_448_577, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12568))
 			// Standard Error: 354
 			.saturating_add(Weight::from_parts(60_286, 0).saturating_mul(s.into()))
 			// Standard Error: 3
 			.saturating_add(Weight::from_parts(1_236, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:1)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigTimelocks (r:1 w:1)
+	/// Proof: Multisig MultisigTimelocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig ExecutionDelays (r:1 w:0)
+	/// Proof: Multisig ExecutionDelays (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigDeadlines (r:1 w:1)
+	/// Proof: Multisig MultisigDeadlines (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:0 w:1)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Multisig CancellationApprovals (r:0 w:1)
+	/// Proof: Multisig CancellationApprovals (max_values: None, max_size: Some(3250), added: 5725, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigCalls (r:1 w:1)
+	/// Proof: Multisig MultisigCalls (max_values: None, max_size: Some(187), added: 2662, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:1 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `388 + s * (33 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `28006`
 		// Minimum execution time: 45_716_000 picoseconds.
 		Weight::from_parts(38_332_947, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 28006))
 			// Standard Error: 554
 			.saturating_add(Weight::from_parts(81_026, 0).saturating_mul(s.into()))
 			// Standard Error: 5
 			.saturating_add(Weight::from_parts(1_265, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:1)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigTimelocks (r:1 w:1)
+	/// Proof: Multisig MultisigTimelocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig ExecutionDelays (r:1 w:0)
+	/// Proof: Multisig ExecutionDelays (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigDeadlines (r:1 w:1)
+	/// Proof: Multisig MultisigDeadlines (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:0 w:1)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Multisig CancellationApprovals (r:0 w:1)
+	/// Proof: Multisig CancellationApprovals (max_values: None, max_size: Some(3250), added: 5725, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigCalls (r:1 w:1)
+	/// Proof: Multisig MultisigCalls (max_values: None, max_size: Some(187), added: 2662, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:1 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_with_signatures(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `388 + s * (33 ±0)`
+		//  Estimated: `28006`
+		// Not measured: `as_multi_complete` plus one `sr25519_verification` of
+		// `frame_benchmarking` per signatory, until the benchmark is run.
+		Weight::from_parts(43_610_049, 0)
+			.saturating_add(Weight::from_parts(0, 28006))
+			.saturating_add(Weight::from_parts(40_691_537, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(1_265, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:0 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `263 + s * (2 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `12573`
 		// Minimum execution time: 32_089_000 picoseconds.
 		Weight::from_parts(33_664_508, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12573))
 			// Standard Error: 487
 			.saturating_add(Weight::from_parts(67_443, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `282`
-		//  Estimated: `6811`
+		//  Estimated: `10013`
 		// Minimum execution time: 18_631_000 picoseconds.
 		Weight::from_parts(19_909_964, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 10013))
 			// Standard Error: 434
 			.saturating_add(Weight::from_parts(62_989, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `454 + s * (1 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `10013`
 		// Minimum execution time: 32_486_000 picoseconds.
 		Weight::from_parts(34_303_784, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 10013))
 			// Standard Error: 585
 			.saturating_add(Weight::from_parts(69_979, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Multisig Multisigs (r:1 w:0)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:1)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_expiry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `418 + s * (1 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 20_554_000 picoseconds.
+		Weight::from_parts(21_923_598, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 388
+			.saturating_add(Weight::from_parts(64_690, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:1)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:0 w:1)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	fn reap_expired() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `552`
+		//  Estimated: `10013`
+		// Minimum execution time: 31_473_000 picoseconds.
+		Weight::from_parts(33_089_966, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Multisig Multisigs (r:200 w:200)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:100 w:200)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:100 w:200)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `c` is `[0, 100]`.
+	fn migrate_multisig(s: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178 + c * (179 ±0)`
+		//  Estimated: `6196 + c * (9023 ±0)`
+		// Minimum execution time: 62_492_000 picoseconds.
+		Weight::from_parts(63_926_963, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 778
+			.saturating_add(Weight::from_parts(15_155, 0).saturating_mul(s.into()))
+			// Standard Error: 1_294
+			.saturating_add(Weight::from_parts(29_455_586, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 9023).saturating_mul(c.into()))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigCalls (r:0 w:1)
+	/// Proof: Multisig MultisigCalls (max_values: None, max_size: Some(187), added: 2662, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_store_call(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `209 + s * (2 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 43_476_000 picoseconds.
+		Weight::from_parts(44_808_939, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 646
+			.saturating_add(Weight::from_parts(67_230, 0).saturating_mul(s.into()))
+			// Standard Error: 9
+			.saturating_add(Weight::from_parts(1_780, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig CancellationApprovals (r:1 w:1)
+	/// Proof: Multisig CancellationApprovals (max_values: None, max_size: Some(3250), added: 5725, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_multi_by_threshold(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `562 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 33_766_000 picoseconds.
+		Weight::from_parts(35_952_639, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 780
+			.saturating_add(Weight::from_parts(84_687, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:1 w:0)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigCalls (r:1 w:0)
+	/// Proof: Multisig MultisigCalls (max_values: None, max_size: Some(187), added: 2662, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:1 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	fn poke_deposit(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `624 + s * (1 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 42_676_000 picoseconds.
+		Weight::from_parts(44_255_211, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 764
+			.saturating_add(Weight::from_parts(72_774, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn revoke_approval(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 20_201_000 picoseconds.
+		Weight::from_parts(21_683_610, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 664
+			.saturating_add(Weight::from_parts(66_251, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::ExecutionDelays` (r:0 w:1)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_execution_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_393_000 picoseconds.
+		Weight::from_parts(4_586_504, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn reject_as_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `671 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 35_606_000 picoseconds.
+		Weight::from_parts(37_565_256, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 839
+			.saturating_add(Weight::from_parts(89_928, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::CreatedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::CreatedMultisigs` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `6196`
+		// Minimum execution time: 57_859_000 picoseconds.
+		Weight::from_parts(59_937_001, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 993
+			.saturating_add(Weight::from_parts(71_456, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Multisig::CreatedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::CreatedMultisigs` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn destroy_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338`
+		//  Estimated: `6196`
+		// Minimum execution time: 43_981_000 picoseconds.
+		Weight::from_parts(45_219_345, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
@@ -672,14 +672,15 @@ pub fn westend_local_testnet_config() -> Result<WestendChainSpec, String> {
 /// Rococo local testnet config (multivalidator Alice + Bob)
 #[cfg(feature = "rococo-native")]
 pub fn rococo_local_testnet_config() -> Result<RococoChainSpec, String> {
+	const PRESET: &str = "local_testnet_fast_runtime";
 	Ok(RococoChainSpec::builder(
-		rococo::fast_runtime_binary::WASM_BINARY.ok_or("Rococo development wasm not available")?,
+		rococo::wasm_binary_for_preset(PRESET).ok_or("Rococo development wasm not available")?,
 		Default::default(),
 	)
 	.with_name("Rococo Local Testnet")
 	.with_id("rococo_local_testnet")
 	.with_chain_type(ChainType::Local)
-	.with_genesis_config_preset_name("local_testnet")
+	.with_genesis_config_preset_name(PRESET)
 	.with_protocol_id(DEFAULT_PROTOCOL_ID)
 	.build())
 }
@@ -425,4 +425,16 @@ sp_api::impl_runtime_apis! {
 			unimplemented!()
 		}
 	}
+
+	impl xcm_runtime_apis::transfers::TransferValidationApi<Block> for Runtime {
+		fn validate_transfer(
+			_: VersionedLocation,
+			_: VersionedLocation,
+			_: VersionedLocation,
+			_: VersionedAssets,
+			_: u32,
+		) -> Vec<xcm_runtime_apis::transfers::TransferFailure> {
+			unimplemented!()
+		}
+	}
 }
@@ -293,5 +293,15 @@ sp_api::decl_runtime_apis! {
 		/// Elastic scaling support
 		#[api_version(11)]
 		fn candidates_pending_availability(para_id: ppp::Id) -> Vec<CommittedCandidateReceipt<Hash>>;
+
+		/***** Added in v12 *****/
+		/// Get at most `count` messages of the downward message queue for the given para,
+		/// starting with the message at index `start`, where `0` is the oldest message.
+		#[api_version(12)]
+		fn dmq_contents_page(
+			recipient: ppp::Id,
+			start: u32,
+			count: u32,
+		) -> Vec<pcp::v2::InboundDownwardMessage<BlockNumber>>;
 	}
 }
@@ -704,6 +704,7 @@ mod tests {
 	}
 
 	impl parachains_configuration::Config for Test {
+		type RuntimeEvent = RuntimeEvent;
 		type WeightInfo = parachains_configuration::TestWeightInfo;
 	}
 
@@ -718,6 +719,7 @@ mod tests {
 		type QueueFootprinter = ();
 		type NextSessionRotation = crate::mock::TestNextSessionRotation;
 		type OnNewHead = ();
+		type OnUpgradeRestriction = ();
 		type AssignCoretime = ();
 	}
 
@@ -29,7 +29,7 @@ use scale_info::TypeInfo;
 use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
 use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
 use sp_runtime::{
-	traits::{CheckedSub, DispatchInfoOf, SignedExtension, Zero},
+	traits::{CheckedSub, DispatchInfoOf, Saturating, SignedExtension, Zero},
 	transaction_validity::{
 		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
 	},
@@ -50,6 +50,8 @@ pub trait WeightInfo {
 	fn claim_attest() -> Weight;
 	fn attest() -> Weight;
 	fn move_claim() -> Weight;
+	fn set_sunset() -> Weight;
+	fn sweep_unclaimed(n: u32) -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -69,6 +71,25 @@ impl WeightInfo for TestWeightInfo {
 	fn move_claim() -> Weight {
 		Weight::zero()
 	}
+	fn set_sunset() -> Weight {
+		Weight::zero()
+	}
+	fn sweep_unclaimed(_n: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Statistics about the claims which were processed.
+#[derive(Encode, Decode, Clone, Default, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ClaimStatistics<Balance> {
+	/// The number of claims which were claimed.
+	pub claimed_count: u32,
+	/// The total amount of all claims which were claimed.
+	pub claimed: Balance,
+	/// The number of unclaimed claims which were swept after the sunset.
+	pub swept_count: u32,
+	/// The total amount of all unclaimed claims which were swept after the sunset.
+	pub swept: Balance,
 }
 
 /// The kind of statement an account needs to make for a claim to be valid.
@@ -175,6 +196,8 @@ pub mod pallet {
 		#[pallet::constant]
 		type Prefix: Get<&'static [u8]>;
 		type MoveClaimOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The account unclaimed balances are swept to after the sunset, usually the treasury.
+		type UnclaimedBeneficiary: Get<Self::AccountId>;
 		type WeightInfo: WeightInfo;
 	}
 
@@ -183,6 +206,10 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// Someone claimed some DOTs.
 		Claimed { who: T::AccountId, ethereum_address: EthereumAddress, amount: BalanceOf<T> },
+		/// The block after which unclaimed balances can be swept was set.
+		SunsetSet { sunset: Option<BlockNumberFor<T>> },
+		/// Some unclaimed claims were swept to the unclaimed beneficiary.
+		UnclaimedSwept { count: u32, amount: BalanceOf<T>, remaining: bool },
 	}
 
 	#[pallet::error]
@@ -200,6 +227,10 @@ pub mod pallet {
 		InvalidStatement,
 		/// The account already has a vested balance.
 		VestedBalanceExists,
+		/// No sunset was set or it was not reached yet.
+		SunsetNotReached,
+		/// There are no unclaimed claims left to sweep.
+		NothingToSweep,
 	}
 
 	#[pallet::storage]
@@ -208,6 +239,14 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Total<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+	/// Statistics about the claims which were claimed or swept so far.
+	#[pallet::storage]
+	pub type Statistics<T: Config> = StorageValue<_, ClaimStatistics<BalanceOf<T>>, ValueQuery>;
+
+	/// The block after which unclaimed balances can be swept to the unclaimed beneficiary.
+	#[pallet::storage]
+	pub type Sunset<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
 	/// Vesting schedule for a claim.
 	/// First balance is the total amount that should be held for vesting.
 	/// Second balance is how much should be unlocked per block.
@@ -454,6 +493,69 @@ pub mod pallet {
 			});
 			Ok(Pays::No.into())
 		}
+
+		/// Set the block after which unclaimed balances can be swept, or clear it.
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// Parameters:
+		/// - `sunset`: The block after which `sweep_unclaimed` may be called, or `None` to keep the
+		///   claims alive indefinitely.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_sunset())]
+		pub fn set_sunset(
+			origin: OriginFor<T>,
+			sunset: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Sunset::<T>::set(sunset);
+			Self::deposit_event(Event::<T>::SunsetSet { sunset });
+			Ok(())
+		}
+
+		/// Sweep up to `limit` unclaimed claims to the unclaimed beneficiary.
+		///
+		/// The dispatch origin for this call must be _Signed_. It may only be called once the
+		/// sunset has passed.
+		///
+		/// The swept claims are removed together with their vesting schedules and statements.
+		/// The unclaimed balance is minted to the unclaimed beneficiary without a vesting
+		/// schedule.
+		///
+		/// Parameters:
+		/// - `limit`: The maximum number of claims to sweep.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::sweep_unclaimed(*limit))]
+		pub fn sweep_unclaimed(origin: OriginFor<T>, limit: u32) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let sunset = Sunset::<T>::get().ok_or(Error::<T>::SunsetNotReached)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > sunset,
+				Error::<T>::SunsetNotReached
+			);
+
+			let mut count = 0u32;
+			let mut amount: BalanceOf<T> = Zero::zero();
+			for (address, balance) in Claims::<T>::drain().take(limit as usize) {
+				Vesting::<T>::remove(&address);
+				Signing::<T>::remove(&address);
+				amount = amount.saturating_add(balance);
+				count.saturating_inc();
+			}
+			ensure!(count > 0, Error::<T>::NothingToSweep);
+
+			let _ = CurrencyOf::<T>::deposit_creating(&T::UnclaimedBeneficiary::get(), amount);
+			Total::<T>::mutate(|t| *t = t.saturating_sub(amount));
+			Statistics::<T>::mutate(|s| {
+				s.swept_count.saturating_accrue(count);
+				s.swept = s.swept.saturating_add(amount);
+			});
+
+			let remaining = Claims::<T>::iter_keys().next().is_some();
+			Self::deposit_event(Event::<T>::UnclaimedSwept { count, amount, remaining });
+			Ok(Some(T::WeightInfo::sweep_unclaimed(count)).into())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -570,6 +672,10 @@ impl<T: Config> Pallet<T> {
 		}
 
 		Total::<T>::put(new_total);
+		Statistics::<T>::mutate(|s| {
+			s.claimed_count.saturating_inc();
+			s.claimed = s.claimed.saturating_add(balance_due);
+		});
 		Claims::<T>::remove(&signer);
 		Vesting::<T>::remove(&signer);
 		Signing::<T>::remove(&signer);
@@ -765,6 +871,7 @@ mod tests {
 	}
 	ord_parameter_types! {
 		pub const Six: u64 = 6;
+		pub const UnclaimedBeneficiary: u64 = 99;
 	}
 
 	impl Config for Test {
@@ -772,6 +879,7 @@ mod tests {
 		type VestingSchedule = Vesting;
 		type Prefix = Prefix;
 		type MoveClaimOrigin = frame_system::EnsureSignedBy<Six, u64>;
+		type UnclaimedBeneficiary = UnclaimedBeneficiary;
 		type WeightInfo = TestWeightInfo;
 	}
 
@@ -851,6 +959,58 @@ mod tests {
 			assert_eq!(Balances::free_balance(&42), 100);
 			assert_eq!(Vesting::vesting_balance(&42), Some(50));
 			assert_eq!(claims::Total::<Test>::get(), total_claims() - 100);
+			assert_eq!(
+				claims::Statistics::<Test>::get(),
+				ClaimStatistics { claimed_count: 1, claimed: 100, ..Default::default() }
+			);
+		});
+	}
+
+	#[test]
+	fn sweeping_unclaimed_works() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Claims::claim(
+				RuntimeOrigin::none(),
+				42,
+				sig::<Test>(&alice(), &42u64.encode(), &[][..])
+			));
+
+			// Nothing can be swept without a sunset.
+			assert_noop!(
+				Claims::sweep_unclaimed(RuntimeOrigin::signed(1), 10),
+				Error::<Test>::SunsetNotReached
+			);
+			assert_noop!(Claims::set_sunset(RuntimeOrigin::signed(1), Some(10)), BadOrigin);
+			assert_ok!(Claims::set_sunset(RuntimeOrigin::root(), Some(10)));
+			System::assert_last_event(Event::<Test>::SunsetSet { sunset: Some(10) }.into());
+			System::set_block_number(10);
+			assert_noop!(
+				Claims::sweep_unclaimed(RuntimeOrigin::signed(1), 10),
+				Error::<Test>::SunsetNotReached
+			);
+
+			// The sweep is done in batches.
+			System::set_block_number(11);
+			assert_ok!(Claims::sweep_unclaimed(RuntimeOrigin::signed(1), 2));
+			let swept = Balances::free_balance(UnclaimedBeneficiary::get());
+			System::assert_last_event(
+				Event::<Test>::UnclaimedSwept { count: 2, amount: swept, remaining: true }.into(),
+			);
+			assert_eq!(claims::Claims::<Test>::iter().count(), 1);
+			assert_ok!(Claims::sweep_unclaimed(RuntimeOrigin::signed(1), 2));
+			assert_noop!(
+				Claims::sweep_unclaimed(RuntimeOrigin::signed(1), 2),
+				Error::<Test>::NothingToSweep
+			);
+
+			assert_eq!(Balances::free_balance(UnclaimedBeneficiary::get()), total_claims() - 100);
+			assert_eq!(claims::Total::<Test>::get(), 0);
+			assert_eq!(claims::Signing::<Test>::iter().count(), 0);
+			assert_eq!(
+				claims::Statistics::<Test>::get(),
+				ClaimStatistics { claimed_count: 1, claimed: 100, swept_count: 3, swept: 900 }
+			);
 		});
 	}
 
@@ -1432,7 +1592,7 @@ mod benchmarking {
 	use crate::claims::Call;
 	use frame_benchmarking::{account, benchmarks};
 	use frame_support::traits::UnfilteredDispatchable;
-	use frame_system::RawOrigin;
+	use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 	use secp_utils::*;
 	use sp_runtime::{traits::ValidateUnsigned, DispatchResult};
 
@@ -1621,6 +1781,27 @@ mod benchmarking {
 			assert!(Claims::<T>::contains_key(new_eth_address));
 		}
 
+		set_sunset {
+		}: _(RawOrigin::Root, Some(1u32.into()))
+		verify {
+			assert_eq!(Sunset::<T>::get(), Some(1u32.into()));
+		}
+
+		// Benchmark sweeping `n` unclaimed claims with vesting schedules and statements.
+		sweep_unclaimed {
+			let n in 1 .. 1_000;
+
+			for i in 0 .. n {
+				create_claim_attest::<T>(i)?;
+			}
+			Sunset::<T>::put(BlockNumberFor::<T>::zero());
+			frame_system::Pallet::<T>::set_block_number(1u32.into());
+			let caller: T::AccountId = account("caller", 0, SEED);
+		}: _(RawOrigin::Signed(caller), n)
+		verify {
+			assert_eq!(Claims::<T>::iter().count(), 0);
+		}
+
 		// Benchmark the time it takes to do `repeat` number of keccak256 hashes
 		#[extra]
 		keccak256 {
@@ -19,13 +19,16 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::traits::{
 	fungible::{Balanced, Credit},
-	tokens::imbalance::ResolveTo,
+	tokens::{imbalance::ResolveTo, ConversionFromAssetBalance},
 	Contains, ContainsPair, Imbalance, OnUnbalanced,
 };
 use pallet_treasury::TreasuryAccountId;
 use polkadot_primitives::Balance;
-use sp_runtime::{traits::TryConvert, Perquintill, RuntimeDebug};
-use xcm::VersionedLocation;
+use sp_runtime::{
+	traits::{Convert, TryConvert},
+	DispatchError, Perquintill, RuntimeDebug,
+};
+use xcm::{IntoVersion, Version, VersionedAssets, VersionedLocation, VersionedXcm};
 
 /// Logic for the author to get a portion of fees.
 pub struct ToAuthor<R>(sp_std::marker::PhantomData<R>);
@@ -118,6 +121,40 @@ pub enum VersionedLocatableAsset {
 	V4 { location: xcm::v4::Location, asset_id: xcm::v4::AssetId },
 }
 
+impl VersionedLocatableAsset {
+	/// The XCM versions an asset can be expressed in, oldest first.
+	pub const VERSIONS: [Version; 2] = [xcm::v3::VERSION, xcm::v4::VERSION];
+
+	/// The location and asset id of the asset in the latest XCM version.
+	///
+	/// Assets stored in an older version are upgraded when they are used rather than migrated,
+	/// so this is how they should be read.
+	pub fn into_latest_parts(self) -> Result<(xcm::latest::Location, xcm::latest::AssetId), ()> {
+		match self.into_latest()? {
+			VersionedLocatableAsset::V4 { location, asset_id } => Ok((location, asset_id)),
+			_ => Err(()),
+		}
+	}
+}
+
+impl IntoVersion for VersionedLocatableAsset {
+	fn into_version(self, n: Version) -> Result<Self, ()> {
+		use VersionedLocatableAsset::*;
+		Ok(match (self, n) {
+			(asset @ V3 { .. }, 3) | (asset @ V4 { .. }, 4) => asset,
+			(V3 { location, asset_id }, 4) => V4 {
+				location: location.try_into().map_err(|_| ())?,
+				asset_id: asset_id.try_into().map_err(|_| ())?,
+			},
+			(V4 { location, asset_id }, 3) => V3 {
+				location: location.try_into().map_err(|_| ())?,
+				asset_id: asset_id.try_into().map_err(|_| ())?,
+			},
+			_ => return Err(()),
+		})
+	}
+}
+
 /// Converts the [`VersionedLocatableAsset`] to the [`xcm_builder::LocatableAssetId`].
 pub struct LocatableAssetConverter;
 impl TryConvert<VersionedLocatableAsset, xcm_builder::LocatableAssetId>
@@ -126,15 +163,48 @@ impl TryConvert<VersionedLocatableAsset, xcm_builder::LocatableAssetId>
 	fn try_convert(
 		asset: VersionedLocatableAsset,
 	) -> Result<xcm_builder::LocatableAssetId, VersionedLocatableAsset> {
-		match asset {
-			VersionedLocatableAsset::V3 { location, asset_id } =>
-				Ok(xcm_builder::LocatableAssetId {
-					location: location.try_into().map_err(|_| asset.clone())?,
-					asset_id: asset_id.try_into().map_err(|_| asset.clone())?,
-				}),
-			VersionedLocatableAsset::V4 { location, asset_id } =>
-				Ok(xcm_builder::LocatableAssetId { location, asset_id }),
+		let (location, asset_id) = asset.clone().into_latest_parts().map_err(|_| asset)?;
+		Ok(xcm_builder::LocatableAssetId { location, asset_id })
+	}
+}
+
+/// Wraps the [`ConversionFromAssetBalance`] `C` of [`VersionedLocatableAsset`]s to retry a failed
+/// conversion with the asset expressed in the other supported XCM versions.
+///
+/// Conversion rates are keyed by the encoded asset, so a rate set for an asset in an older
+/// version is still found when the asset is given in the latest version, until the rate is set
+/// again.
+pub struct AnyVersionConversion<C>(core::marker::PhantomData<C>);
+impl<AssetBalance, OutBalance, C>
+	ConversionFromAssetBalance<AssetBalance, VersionedLocatableAsset, OutBalance>
+	for AnyVersionConversion<C>
+where
+	AssetBalance: Clone,
+	C: ConversionFromAssetBalance<AssetBalance, VersionedLocatableAsset, OutBalance>,
+{
+	type Error = C::Error;
+	fn from_asset_balance(
+		balance: AssetBalance,
+		asset_id: VersionedLocatableAsset,
+	) -> Result<OutBalance, Self::Error> {
+		let result = C::from_asset_balance(balance.clone(), asset_id.clone());
+		if result.is_ok() {
+			return result
+		}
+		for version in VersionedLocatableAsset::VERSIONS.into_iter().rev() {
+			match asset_id.clone().into_version(version) {
+				Ok(other) if other != asset_id =>
+					if let Ok(out) = C::from_asset_balance(balance.clone(), other) {
+						return Ok(out)
+					},
+				_ => {},
+			}
 		}
+		result
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(asset_id: VersionedLocatableAsset) {
+		C::ensure_successful(asset_id)
 	}
 }
 
@@ -156,6 +226,36 @@ impl TryConvert<&VersionedLocation, xcm::latest::Location> for VersionedLocation
 	}
 }
 
+/// Converts between the accounts of a relay chain and the [`VersionedLocation`]s of treasury
+/// beneficiaries, which are relative to the system parachain the payout is made on.
+///
+/// An account of the relay chain is designated as `(1, [AccountId32 { .. }])`, i.e. as an account
+/// of the parent of that parachain.
+pub struct RelayChainAccountConverter;
+impl<AccountId: From<[u8; 32]>> TryConvert<VersionedLocation, AccountId>
+	for RelayChainAccountConverter
+{
+	fn try_convert(location: VersionedLocation) -> Result<AccountId, VersionedLocation> {
+		let Some(latest) = VersionedLocationConverter::try_convert(&location).ok() else {
+			return Err(location)
+		};
+		match latest.unpack() {
+			(1, [xcm::latest::Junction::AccountId32 { id, .. }]) => Ok((*id).into()),
+			_ => Err(location),
+		}
+	}
+}
+impl<AccountId: Into<[u8; 32]>> Convert<AccountId, VersionedLocation>
+	for RelayChainAccountConverter
+{
+	fn convert(who: AccountId) -> VersionedLocation {
+		VersionedLocation::V4(xcm::v4::Location::new(
+			1,
+			[xcm::v4::Junction::AccountId32 { network: None, id: who.into() }],
+		))
+	}
+}
+
 /// Adapter for [`Contains`] trait to match [`VersionedLocatableAsset`] type converted to the latest
 /// version of itself where it's location matched by `L` and it's asset id by `A` parameter types.
 pub struct ContainsParts<C>(core::marker::PhantomData<C>);
@@ -164,18 +264,61 @@ where
 	C: ContainsPair<xcm::latest::Location, xcm::latest::Location>,
 {
 	fn contains(asset: &VersionedLocatableAsset) -> bool {
-		use VersionedLocatableAsset::*;
-		let (location, asset_id) = match asset.clone() {
-			V3 { location, asset_id } => match (location.try_into(), asset_id.try_into()) {
-				(Ok(l), Ok(a)) => (l, a),
-				_ => return false,
-			},
-			V4 { location, asset_id } => (location, asset_id),
-		};
+		let Ok((location, asset_id)) = asset.clone().into_latest_parts() else { return false };
 		C::contains(&location, &asset_id.0)
 	}
 }
 
+/// The projected outcome of a treasury spend, see [`TreasurySpendApi`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct SpendDryRun<Balance, BlockNumber> {
+	/// The amount of the spend converted to the native balance.
+	pub native_amount: Balance,
+	/// The maximum amount of native balance the origin may spend.
+	pub max_amount: Balance,
+	/// The block number from which the spend could be paid out.
+	pub valid_from: BlockNumber,
+	/// The block number by which the spend would have to be paid out.
+	pub expire_at: BlockNumber,
+	/// The destination the payout message would be sent to.
+	pub destination: VersionedLocation,
+	/// The payout message, with a placeholder query id for the payment status.
+	pub message: VersionedXcm<()>,
+	/// The fees for delivering the payout message.
+	pub delivery_fees: VersionedAssets,
+}
+
+/// The reason a treasury spend would fail, see [`TreasurySpendApi`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+pub enum SpendDryRunError {
+	/// The spend would be rejected by the treasury, e.g. since it exceeds the limit of the origin
+	/// or its asset kind has no conversion rate.
+	Rejected(DispatchError),
+	/// The payout message could not be built or would not be deliverable.
+	Payout(xcm::latest::Error),
+}
+
+sp_api::decl_runtime_apis! {
+	/// API to validate treasury spends before they are proposed.
+	pub trait TreasurySpendApi<OriginCaller, Balance, BlockNumber> where
+		OriginCaller: codec::Codec,
+		Balance: codec::Codec,
+		BlockNumber: codec::Codec,
+	{
+		/// Dry-runs a `spend` of `amount` of `asset_kind` to `beneficiary` from `origin`, returning
+		/// the payout message which would be sent for it together with its delivery fees.
+		///
+		/// Nothing is stored and no message is sent.
+		fn dry_run_spend(
+			origin: OriginCaller,
+			asset_kind: VersionedLocatableAsset,
+			amount: Balance,
+			beneficiary: VersionedLocation,
+			valid_from: Option<BlockNumber>,
+		) -> Result<SpendDryRun<Balance, BlockNumber>, SpendDryRunError>;
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarks {
 	use super::VersionedLocatableAsset;
@@ -216,16 +359,16 @@ pub mod benchmarks {
 		for TreasuryArguments<Parents, ParaId>
 	{
 		fn create_asset_kind(seed: u32) -> VersionedLocatableAsset {
-			VersionedLocatableAsset::V3 {
-				location: xcm::v3::Location::new(
+			VersionedLocatableAsset::V4 {
+				location: xcm::v4::Location::new(
 					Parents::get(),
-					[xcm::v3::Junction::Parachain(ParaId::get())],
+					[xcm::v4::Junction::Parachain(ParaId::get())],
 				),
-				asset_id: xcm::v3::Location::new(
+				asset_id: xcm::v4::Location::new(
 					0,
 					[
-						xcm::v3::Junction::PalletInstance(seed.try_into().unwrap()),
-						xcm::v3::Junction::GeneralIndex(seed.into()),
+						xcm::v4::Junction::PalletInstance(seed.try_into().unwrap()),
+						xcm::v4::Junction::GeneralIndex(seed.into()),
 					],
 				)
 				.into(),
@@ -249,14 +392,14 @@ mod tests {
 		parameter_types,
 		traits::{
 			tokens::{PayFromAccount, UnityAssetBalanceConversion},
-			FindAuthor,
+			FindAuthor, FromContains,
 		},
 		weights::Weight,
 		PalletId,
 	};
 	use frame_system::limits;
-	use polkadot_primitives::AccountId;
-	use sp_core::{ConstU64, H256};
+	use polkadot_primitives::{AccountId, Id as ParaId};
+	use sp_core::{ConstU64, ConstU8, H256};
 	use sp_runtime::{
 		traits::{BlakeTwo256, IdentityLookup},
 		BuildStorage, Perbill,
@@ -344,6 +487,9 @@ mod tests {
 		type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
 		type BalanceConverter = UnityAssetBalanceConversion;
 		type PayoutPeriod = ConstU64<0>;
+		type Clawback = ();
+		type SpendSubmitter = ();
+		type SpendConsideration = ();
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper = ();
 	}
@@ -362,6 +508,35 @@ mod tests {
 		type EventHandler = ();
 	}
 
+	fn v3_asset() -> VersionedLocatableAsset {
+		VersionedLocatableAsset::V3 {
+			location: xcm::v3::Location::new(0, [xcm::v3::Junction::Parachain(1000)]),
+			asset_id: xcm::v3::Location::parent().into(),
+		}
+	}
+
+	fn v4_asset() -> VersionedLocatableAsset {
+		VersionedLocatableAsset::V4 {
+			location: xcm::v4::Location::new(0, [xcm::v4::Junction::Parachain(1000)]),
+			asset_id: xcm::v4::Location::parent().into(),
+		}
+	}
+
+	/// Converts the balance of the asset only if it is given in XCM v3.
+	pub struct V3RateOnly;
+	impl ConversionFromAssetBalance<u64, VersionedLocatableAsset, u64> for V3RateOnly {
+		type Error = ();
+		fn from_asset_balance(balance: u64, asset_id: VersionedLocatableAsset) -> Result<u64, ()> {
+			if asset_id == v3_asset() {
+				Ok(balance * 2)
+			} else {
+				Err(())
+			}
+		}
+		#[cfg(feature = "runtime-benchmarks")]
+		fn ensure_successful(_: VersionedLocatableAsset) {}
+	}
+
 	pub fn new_test_ext() -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 		// We use default for brevity, but you can configure as desired if needed.
@@ -434,4 +609,59 @@ mod tests {
 			(6, 4)
 		);
 	}
+
+	#[test]
+	fn locatable_asset_versions_convert() {
+		assert_eq!(v3_asset().into_latest(), Ok(v4_asset()));
+		assert_eq!(v4_asset().into_version(3), Ok(v3_asset()));
+		assert_eq!(v4_asset().into_version(2), Err(()));
+		// abstract asset ids have no representation in the latest version.
+		let asset = VersionedLocatableAsset::V3 {
+			location: xcm::v3::Location::here(),
+			asset_id: xcm::v3::AssetId::Abstract([1; 32]),
+		};
+		assert_eq!(asset.clone().into_latest(), Err(()));
+		assert_eq!(LocatableAssetConverter::try_convert(asset.clone()).err(), Some(asset));
+
+		let latest = (
+			xcm::v4::Location::new(0, [xcm::v4::Junction::Parachain(1000)]),
+			xcm::v4::Location::parent().into(),
+		);
+		for asset in [v3_asset(), v4_asset()] {
+			let converted = LocatableAssetConverter::try_convert(asset.clone()).ok();
+			assert_eq!(converted.map(|a| (a.location, a.asset_id)), Some(latest.clone()));
+			assert!(ContainsParts::<
+				FromContains<
+					xcm_builder::IsChildSystemParachain<ParaId>,
+					xcm_builder::IsParentsOnly<ConstU8<1>>,
+				>,
+			>::contains(&asset));
+		}
+	}
+
+	#[test]
+	fn any_version_conversion_finds_older_rates() {
+		assert_eq!(V3RateOnly::from_asset_balance(10, v4_asset()), Err(()));
+		assert_eq!(AnyVersionConversion::<V3RateOnly>::from_asset_balance(10, v4_asset()), Ok(20));
+		assert_eq!(AnyVersionConversion::<V3RateOnly>::from_asset_balance(10, v3_asset()), Ok(20));
+	}
+
+	#[test]
+	fn relay_chain_account_converter_works() {
+		let location: VersionedLocation = RelayChainAccountConverter::convert(TEST_ACCOUNT);
+		assert_eq!(
+			<RelayChainAccountConverter as TryConvert<_, AccountId>>::try_convert(location),
+			Ok(TEST_ACCOUNT)
+		);
+
+		// accounts of the parachain are paid out by the treasury.
+		let location = VersionedLocation::V4(xcm::v4::Location::new(
+			0,
+			[xcm::v4::Junction::AccountId32 { network: None, id: [1; 32] }],
+		));
+		assert_eq!(
+			<RelayChainAccountConverter as TryConvert<_, AccountId>>::try_convert(location.clone()),
+			Err(location)
+		);
+	}
 }
@@ -182,6 +182,7 @@ impl pallet_balances::Config for Test {
 }
 
 impl configuration::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = configuration::TestWeightInfo;
 }
 
@@ -189,7 +190,9 @@ impl shared::Config for Test {
 	type DisabledValidators = ();
 }
 
-impl dmp::Config for Test {}
+impl dmp::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+}
 
 impl origin::Config for Test {}
 
@@ -204,6 +207,7 @@ impl paras::Config for Test {
 	type QueueFootprinter = ();
 	type NextSessionRotation = crate::mock::TestNextSessionRotation;
 	type OnNewHead = ();
+	type OnUpgradeRestriction = ();
 	type AssignCoretime = ();
 }
 
@@ -283,6 +287,7 @@ impl pallet_identity::Config for Test {
 	type MaxSubAccounts = ConstU32<2>;
 	type IdentityInformation = IdentityInfo<ConstU32<2>>;
 	type MaxRegistrars = ConstU32<20>;
+	type MaxJudgementBatch = ConstU32<32>;
 	type RegistrarOrigin = EnsureRoot<AccountId>;
 	type ForceOrigin = EnsureRoot<AccountId>;
 	type OffchainSignature = MultiSignature;
@@ -823,10 +823,12 @@ mod tests {
 		type QueueFootprinter = ();
 		type NextSessionRotation = crate::mock::TestNextSessionRotation;
 		type OnNewHead = ();
+		type OnUpgradeRestriction = ();
 		type AssignCoretime = ();
 	}
 
 	impl configuration::Config for Test {
+		type RuntimeEvent = RuntimeEvent;
 		type WeightInfo = configuration::TestWeightInfo;
 	}
 
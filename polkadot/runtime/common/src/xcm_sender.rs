@@ -24,7 +24,8 @@ use polkadot_runtime_parachains::{
 	configuration::{self, HostConfiguration},
 	dmp, FeeTracker,
 };
-use sp_runtime::FixedPointNumber;
+use scale_info::TypeInfo;
+use sp_runtime::{FixedPointNumber, FixedU128, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 use xcm::prelude::*;
 use xcm_builder::InspectMessageQueues;
@@ -93,9 +94,52 @@ impl<A: Get<AssetId>, B: Get<u128>, M: Get<u128>, F: FeeTracker> PriceForMessage
 	}
 }
 
+/// The state of the exponential delivery fee factor of a destination.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DeliveryFeeFactor {
+	/// The current factor by which the delivery fee is multiplied.
+	pub factor: FixedU128,
+	/// Whether the factor is above its initial value due to the destination being congested.
+	pub congested: bool,
+	/// The number of messages currently queued for the destination.
+	pub queue_length: u32,
+	/// The number of queued messages above which every new message multiplies the factor by
+	/// `base` plus a message size component. At or below it, the factor is divided by `base`
+	/// whenever the destination processes messages, until it reaches its initial value.
+	pub queue_threshold: u32,
+	/// The base by which the factor grows and decays.
+	pub base: FixedU128,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API to query the exponential delivery fee factors of destinations.
+	pub trait DeliveryFeeFactorApi {
+		/// Returns the delivery fee factor of `destination`, or `None` if its delivery fees are
+		/// not priced by an exponential fee factor.
+		fn delivery_fee_factor(destination: VersionedLocation) -> Option<DeliveryFeeFactor>;
+	}
+}
+
 /// XCM sender for relay chain. It only sends downward message.
 pub struct ChildParachainRouter<T, W, P>(PhantomData<(T, W, P)>);
 
+impl<T: configuration::Config + dmp::Config, W, P> ChildParachainRouter<T, W, P> {
+	/// Returns the delivery fee factor of `dest`, or `None` if it is not a child parachain.
+	pub fn delivery_fee_factor(dest: &Location) -> Option<DeliveryFeeFactor> {
+		let (0, [Parachain(id)]) = dest.unpack() else { return None };
+		let para = ParaId::from(*id);
+		let config = configuration::ActiveConfig::<T>::get();
+		let factor = dmp::Pallet::<T>::get_fee_factor(para);
+		Some(DeliveryFeeFactor {
+			factor,
+			congested: factor > dmp::InitialFactor::get(),
+			queue_length: dmp::Pallet::<T>::dmq_length(para),
+			queue_threshold: dmp::Pallet::<T>::dmq_fee_threshold(&config),
+			base: dmp::EXPONENTIAL_FEE_BASE,
+		})
+	}
+}
+
 impl<T: configuration::Config + dmp::Config, W: xcm::WrapVersion, P> SendXcm
 	for ChildParachainRouter<T, W, P>
 where
@@ -260,7 +304,6 @@ mod tests {
 	use crate::integration_tests::new_test_ext;
 	use frame_support::{assert_ok, parameter_types};
 	use polkadot_runtime_parachains::FeeTracker;
-	use sp_runtime::FixedU128;
 	use xcm::MAX_XCM_DECODE_DEPTH;
 
 	parameter_types! {
@@ -356,4 +399,29 @@ mod tests {
 			);
 		});
 	}
+
+	#[test]
+	fn child_parachain_router_delivery_fee_factor_works() {
+		type Router = ChildParachainRouter<crate::integration_tests::Test, (), ()>;
+		let para = ParaId::from(5555);
+
+		new_test_ext().execute_with(|| {
+			assert_eq!(Router::delivery_fee_factor(&Parent.into()), None);
+			assert_eq!(Router::delivery_fee_factor(&(Parent, Parachain(5555)).into()), None);
+
+			let info = Router::delivery_fee_factor(&Parachain(5555).into()).unwrap();
+			assert_eq!(info.factor, FixedU128::from_u32(1));
+			assert!(!info.congested);
+			assert_eq!(info.queue_length, 0);
+			assert_eq!(info.base, dmp::EXPONENTIAL_FEE_BASE);
+
+			dmp::Pallet::<crate::integration_tests::Test>::increase_fee_factor(
+				para,
+				FixedU128::from_u32(0),
+			);
+			let info = Router::delivery_fee_factor(&Parachain(5555).into()).unwrap();
+			assert_eq!(info.factor, dmp::EXPONENTIAL_FEE_BASE);
+			assert!(info.congested);
+		});
+	}
 }
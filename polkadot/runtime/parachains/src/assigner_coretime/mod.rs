@@ -22,6 +22,13 @@
 //!
 //! `CoreDescriptor` contains pointers to the begin and the end of a list of schedules, together
 //! with the currently active assignments.
+//!
+//! The assignments served on each core, that is the number of blocks of each para included on it,
+//! are recorded per timeslice of the coretime chain and kept for the last
+//! [`Config::AssignmentHistoryDepth`] timeslices. They can be queried with the
+//! [`CoretimeAssignmentHistoryApi`], so coretime buyers can check that they were served the
+//! timeslices they bought. A storage proof of the [`ServedAssignments`] of a para on a core in a
+//! timeslice can serve as evidence in disputes.
 
 mod mock_helpers;
 #[cfg(test)]
@@ -38,11 +45,19 @@ use frame_support::{defensive, pallet_prelude::*};
 use frame_system::pallet_prelude::*;
 use pallet_broker::CoreAssignment;
 use polkadot_primitives::CoreIndex;
-use sp_runtime::traits::{One, Saturating};
+use sp_runtime::{
+	traits::{One, Saturating, Zero},
+	SaturatedConversion,
+};
 
 use sp_std::prelude::*;
 
 pub use pallet::*;
+// Re-exported for the runtime API.
+pub use pallet_broker::Timeslice;
+
+/// The maximum number of served assignments pruned per block.
+const MAX_PRUNED_SERVED_ASSIGNMENTS: u32 = 100;
 
 /// Fraction expressed as a nominator with an assumed denominator of 57,600.
 #[derive(RuntimeDebug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Encode, Decode, TypeInfo)]
@@ -205,6 +220,13 @@ pub mod pallet {
 	pub trait Config:
 		frame_system::Config + configuration::Config + assigner_on_demand::Config
 	{
+		/// The number of relay chain blocks per timeslice of the coretime chain.
+		#[pallet::constant]
+		type TimeslicePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The number of timeslices for which the served assignments are kept.
+		#[pallet::constant]
+		type AssignmentHistoryDepth: Get<u32>;
 	}
 
 	/// Scheduled assignment sets.
@@ -234,8 +256,31 @@ pub mod pallet {
 		GetDefault,
 	>;
 
+	/// The number of blocks of a para included on a core per timeslice.
+	///
+	/// An assignment counts as served once a candidate of its para is included on the core.
+	#[pallet::storage]
+	pub type ServedAssignments<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, Timeslice>,
+			NMapKey<Twox64Concat, CoreIndex>,
+			NMapKey<Twox64Concat, ParaId>,
+		),
+		u32,
+		ValueQuery,
+	>;
+
+	/// The oldest timeslice whose served assignments may not be pruned yet.
+	#[pallet::storage]
+	pub type OldestServedTimeslice<T: Config> = StorageValue<_, Timeslice, OptionQuery>;
+
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::prune_served_assignments(now)
+		}
+	}
 
 	#[pallet::error]
 	pub enum Error<T> {
@@ -316,6 +361,18 @@ impl<T: Config> AssignmentProvider<BlockNumberFor<T>> for Pallet<T> {
 		}
 	}
 
+	/// Record that `para_id` was served on `core_idx` in the current timeslice.
+	fn note_included(core_idx: CoreIndex, para_id: ParaId) {
+		let timeslice = Self::timeslice_of(frame_system::Pallet::<T>::block_number());
+		ServedAssignments::<T>::mutate((timeslice, core_idx, para_id), |blocks| {
+			blocks.saturating_inc()
+		});
+	}
+
+	fn note_included_weight() -> Weight {
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+
 	#[cfg(any(feature = "runtime-benchmarks", test))]
 	fn get_mock_assignment(_: CoreIndex, para_id: polkadot_primitives::Id) -> Assignment {
 		// Given that we are not tracking anything in `Bulk` assignments, it is safe to always
@@ -468,6 +525,77 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// The timeslice of the coretime chain the block `now` belongs to.
+	fn timeslice_of(now: BlockNumberFor<T>) -> Timeslice {
+		let period = T::TimeslicePeriod::get();
+		if period.is_zero() {
+			return now.saturated_into()
+		}
+		(now / period).saturated_into()
+	}
+
+	/// The oldest timeslice kept in the history at block `now`.
+	fn oldest_kept_timeslice(now: BlockNumberFor<T>) -> Timeslice {
+		let depth = T::AssignmentHistoryDepth::get().max(1);
+		Self::timeslice_of(now).saturating_sub(depth - 1)
+	}
+
+	/// Prune the served assignments of the oldest timeslice which fell out of the history.
+	///
+	/// At most [`MAX_PRUNED_SERVED_ASSIGNMENTS`] are removed per block, the rest in the following
+	/// blocks.
+	fn prune_served_assignments(now: BlockNumberFor<T>) -> Weight {
+		let oldest_kept = Self::oldest_kept_timeslice(now);
+		let oldest = match OldestServedTimeslice::<T>::get() {
+			Some(oldest) if oldest < oldest_kept => oldest,
+			Some(_) => return T::DbWeight::get().reads(1),
+			None => {
+				// Nothing was recorded before.
+				OldestServedTimeslice::<T>::put(oldest_kept);
+				return T::DbWeight::get().reads_writes(1, 1)
+			},
+		};
+
+		let removal =
+			ServedAssignments::<T>::clear_prefix((oldest,), MAX_PRUNED_SERVED_ASSIGNMENTS, None);
+		if removal.maybe_cursor.is_none() {
+			OldestServedTimeslice::<T>::put(oldest.saturating_add(1));
+		}
+		T::DbWeight::get().reads_writes(
+			1u64.saturating_add(removal.loops.into()),
+			1u64.saturating_add(removal.unique.into()),
+		)
+	}
+
+	/// The paras served on `core_idx` in `timeslice` with the number of their blocks included on
+	/// it.
+	///
+	/// Returns `None` if the timeslice is not kept in the history.
+	pub fn served_assignments(
+		core_idx: CoreIndex,
+		timeslice: Timeslice,
+	) -> Option<Vec<(ParaId, u32)>> {
+		let now = frame_system::Pallet::<T>::block_number();
+		(Self::oldest_kept_timeslice(now)..=Self::timeslice_of(now))
+			.contains(&timeslice)
+			.then(|| ServedAssignments::<T>::iter_prefix((timeslice, core_idx)).collect())
+	}
+
+	/// The storage key of the number of blocks of `para_id` included on `core_idx` in
+	/// `timeslice`.
+	///
+	/// A storage proof of this key can be obtained from any node to prove that the para was
+	/// served.
+	pub fn served_assignments_key(
+		core_idx: CoreIndex,
+		timeslice: Timeslice,
+		para_id: ParaId,
+	) -> Vec<u8> {
+		ServedAssignments::<T>::hashed_key_for((timeslice, core_idx, para_id))
+	}
+}
+
 impl<T: Config> AssignCoretime for Pallet<T> {
 	fn assign_coretime(id: ParaId) -> DispatchResult {
 		let current_block = frame_system::Pallet::<T>::block_number();
@@ -486,3 +614,18 @@ impl<T: Config> AssignCoretime for Pallet<T> {
 		Pallet::<T>::assign_core(CoreIndex(core), begin, assignment, None)
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// API to query which coretime assignments were served on the cores.
+	pub trait CoretimeAssignmentHistoryApi {
+		/// The paras served on `core` in `timeslice` with the number of their blocks included on
+		/// it.
+		///
+		/// Returns `None` if the timeslice is no longer or not yet kept in the history.
+		fn served_assignments(core: CoreIndex, timeslice: Timeslice) -> Option<Vec<(ParaId, u32)>>;
+
+		/// The storage key of the number of blocks of `para` included on `core` in `timeslice`,
+		/// for obtaining a storage proof of it.
+		fn served_assignments_key(core: CoreIndex, timeslice: Timeslice, para: ParaId) -> Vec<u8>;
+	}
+}
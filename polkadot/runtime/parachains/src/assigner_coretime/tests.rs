@@ -76,6 +76,7 @@ fn run_to_block(
 
 		// Update the spot traffic and revenue on every block.
 		OnDemandAssigner::on_initialize(b + 1);
+		CoretimeAssigner::on_initialize(b + 1);
 
 		// In the real runtime this is expected to be called by the `InclusionInherent` pallet.
 		Scheduler::free_cores_and_fill_claim_queue(BTreeMap::new(), b + 1);
@@ -715,6 +716,47 @@ fn equal_assignments_served_equally() {
 	});
 }
 
+#[test]
+fn served_assignments_are_recorded_per_timeslice() {
+	let core_idx = CoreIndex(0);
+	let para_1 = ParaId::from(1u32);
+	let para_2 = ParaId::from(2u32);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		let served = |timeslice| {
+			CoretimeAssigner::served_assignments(core_idx, timeslice).map(|mut served| {
+				served.sort();
+				served
+			})
+		};
+
+		// Blocks 10 to 19 form timeslice 1.
+		run_to_block(11, |_| None);
+		CoretimeAssigner::note_included(core_idx, para_1);
+		CoretimeAssigner::note_included(core_idx, para_1);
+		CoretimeAssigner::note_included(core_idx, para_2);
+		assert_eq!(served(1), Some(vec![(para_1, 2), (para_2, 1)]));
+		assert_eq!(served(2), None);
+
+		// Only the last two timeslices are kept.
+		run_to_block(21, |_| None);
+		CoretimeAssigner::note_included(core_idx, para_2);
+		assert_eq!(served(1), Some(vec![(para_1, 2), (para_2, 1)]));
+		assert_eq!(served(2), Some(vec![(para_2, 1)]));
+		run_to_block(31, |_| None);
+		assert_eq!(served(1), None);
+		assert_eq!(served(3), Some(vec![]));
+		// Timeslice 1 has been pruned.
+		assert_eq!(ServedAssignments::<Test>::iter_prefix((1u32,)).count(), 0);
+		assert_eq!(OldestServedTimeslice::<Test>::get(), Some(2));
+
+		assert_eq!(
+			CoretimeAssigner::served_assignments_key(core_idx, 2, para_2),
+			ServedAssignments::<Test>::hashed_key_for((2u32, core_idx, para_2)),
+		);
+	});
+}
+
 #[test]
 // Checks that core is shared fairly, even in case of `ratio` not being
 // divisible by `step` (over multiple rounds).
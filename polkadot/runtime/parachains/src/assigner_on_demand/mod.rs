@@ -42,7 +42,7 @@ extern crate alloc;
 #[cfg(test)]
 mod tests;
 
-use crate::{configuration, paras, scheduler::common::Assignment};
+use crate::{configuration, paras, scheduler::common::Assignment, OnParaPayment};
 use alloc::collections::BinaryHeap;
 use core::mem::take;
 use frame_support::{
@@ -56,7 +56,7 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::{pallet_prelude::*, Pallet as System};
-use polkadot_primitives::{CoreIndex, Id as ParaId};
+use polkadot_primitives::{Balance, CoreIndex, Id as ParaId};
 use sp_runtime::{
 	traits::{AccountIdConversion, One, SaturatedConversion},
 	FixedPointNumber, FixedPointOperand, FixedU128, Perbill, Saturating,
@@ -124,6 +124,9 @@ pub mod pallet {
 		/// Identifier for the internal revenue balance.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
+
+		/// Something that is notified about the spot price paid for the orders of the paras.
+		type OnParaPayment: OnParaPayment;
 	}
 
 	/// Creates an empty queue status for an empty queue with initial traffic value.
@@ -416,6 +419,8 @@ where
 				}
 			});
 
+			T::OnParaPayment::on_demand_order_paid(para_id, spot_price.saturated_into::<Balance>());
+
 			Pallet::<T>::add_on_demand_order(queue_status, para_id, QueuePushDirection::Back);
 			Pallet::<T>::deposit_event(Event::<T>::OnDemandOrderPlaced {
 				para_id,
@@ -22,6 +22,7 @@ mod mock_helpers;
 #[cfg(test)]
 mod tests;
 
+use frame_support::weights::Weight;
 use frame_system::pallet_prelude::BlockNumberFor;
 use polkadot_primitives::CoreIndex;
 
@@ -58,6 +59,12 @@ impl<T: Config> AssignmentProvider<BlockNumberFor<T>> for Pallet<T> {
 	/// this is a no-op in the case of a bulk assignment slot.
 	fn push_back_assignment(_: Assignment) {}
 
+	fn note_included(_: CoreIndex, _: polkadot_primitives::Id) {}
+
+	fn note_included_weight() -> Weight {
+		Weight::zero()
+	}
+
 	#[cfg(any(feature = "runtime-benchmarks", test))]
 	fn get_mock_assignment(_: CoreIndex, para_id: polkadot_primitives::Id) -> Assignment {
 		Assignment::Bulk(para_id)
@@ -19,7 +19,7 @@
 //! Configuration can change only at session boundaries and is buffered until then.
 
 use crate::{inclusion::MAX_UPWARD_MESSAGE_SIZE_BOUND, shared};
-use codec::{Decode, Encode};
+use codec::{Codec, Decode, Encode};
 use frame_support::{pallet_prelude::*, DefaultNoBound};
 use frame_system::pallet_prelude::*;
 use polkadot_parachain_primitives::primitives::{
@@ -461,6 +461,79 @@ where
 	}
 }
 
+/// A changed field of the [`HostConfiguration`], as reported by [`Event::ActiveConfigUpdated`].
+#[derive(Clone, Encode, Decode, PartialEq, Eq, sp_core::RuntimeDebug, scale_info::TypeInfo)]
+pub struct ConfigFieldChange {
+	/// The name of the field.
+	pub field: Vec<u8>,
+	/// The SCALE encoded value of the field before the change.
+	pub old: Vec<u8>,
+	/// The SCALE encoded value of the field after the change.
+	pub new: Vec<u8>,
+}
+
+impl<BlockNumber: Encode> HostConfiguration<BlockNumber> {
+	/// The fields whose values differ between `self` and `other`, in the order of declaration.
+	pub fn diff(&self, other: &Self) -> Vec<ConfigFieldChange> {
+		let (old_config, new_config) = (self, other);
+		let mut changes = Vec::new();
+		macro_rules! diff_fields {
+			($($field:ident),* $(,)?) => {
+				// Destructure without a rest pattern so that new fields can not be missed.
+				let Self { $($field),* } = old_config;
+				$(
+					let (old, new) = ($field.encode(), new_config.$field.encode());
+					if old != new {
+						changes.push(ConfigFieldChange {
+							field: stringify!($field).as_bytes().to_vec(),
+							old,
+							new,
+						});
+					}
+				)*
+			};
+		}
+		diff_fields!(
+			max_code_size,
+			max_head_data_size,
+			max_upward_queue_count,
+			max_upward_queue_size,
+			max_upward_message_size,
+			max_upward_message_num_per_candidate,
+			hrmp_max_message_num_per_candidate,
+			validation_upgrade_cooldown,
+			validation_upgrade_delay,
+			async_backing_params,
+			max_pov_size,
+			max_downward_message_size,
+			hrmp_max_parachain_outbound_channels,
+			hrmp_sender_deposit,
+			hrmp_recipient_deposit,
+			hrmp_channel_max_capacity,
+			hrmp_channel_max_total_size,
+			hrmp_max_parachain_inbound_channels,
+			hrmp_channel_max_message_size,
+			executor_params,
+			code_retention_period,
+			max_validators,
+			dispute_period,
+			dispute_post_conclusion_acceptance_period,
+			no_show_slots,
+			n_delay_tranches,
+			zeroth_delay_tranche_width,
+			needed_approvals,
+			relay_vrf_modulo_samples,
+			pvf_voting_ttl,
+			minimum_validation_upgrade_delay,
+			minimum_backing_votes,
+			node_features,
+			approval_voting_params,
+			scheduler_params,
+		);
+		changes
+	}
+}
+
 pub trait WeightInfo {
 	fn set_config_with_block_number() -> Weight;
 	fn set_config_with_u32() -> Weight;
@@ -533,10 +606,20 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + shared::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pending configuration became active at the start of `session`.
+		ActiveConfigUpdated { session: SessionIndex, changes: Vec<ConfigFieldChange> },
+	}
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// The new value for a configuration parameter is invalid.
@@ -1341,6 +1424,10 @@ impl<T: Config> Pallet<T> {
 		if let Some(ref new_config) = new_config {
 			// Apply the new configuration.
 			ActiveConfig::<T>::put(new_config);
+			Self::deposit_event(Event::ActiveConfigUpdated {
+				session: *session_index,
+				changes: prev_config.diff(new_config),
+			});
 		}
 
 		PendingConfigs::<T>::put(future);
@@ -1348,6 +1435,11 @@ impl<T: Config> Pallet<T> {
 		SessionChangeOutcome { prev_config, new_config }
 	}
 
+	/// The pending configurations, each with the index of the session at which it becomes active.
+	pub fn pending_configs() -> Vec<(SessionIndex, HostConfiguration<BlockNumberFor<T>>)> {
+		PendingConfigs::<T>::get()
+	}
+
 	/// Return the session index that should be used for any future scheduled changes.
 	fn scheduled_session() -> SessionIndex {
 		shared::Pallet::<T>::scheduled_session()
@@ -1479,3 +1571,12 @@ impl<T: crate::hrmp::pallet::Config, P: Get<Percent>> Get<(u32, u32)>
 		(percent * config.hrmp_channel_max_message_size, percent * config.hrmp_channel_max_capacity)
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// API to query the pending changes of the host configuration.
+	pub trait ConfigurationApi<BlockNumber> where BlockNumber: Codec {
+		/// The pending configurations, each with the index of the session at which it becomes
+		/// active, sorted ascending by session index.
+		fn pending_configs() -> Vec<(SessionIndex, HostConfiguration<BlockNumber>)>;
+	}
+}
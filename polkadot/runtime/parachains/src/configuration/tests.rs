@@ -17,7 +17,10 @@
 use super::*;
 use crate::{
 	configuration,
-	mock::{new_test_ext, Configuration, MockGenesisConfig, ParasShared, RuntimeOrigin, Test},
+	mock::{
+		new_test_ext, Configuration, MockGenesisConfig, ParasShared, RuntimeEvent, RuntimeOrigin,
+		System, Test,
+	},
 };
 use bitvec::{bitvec, prelude::Lsb0};
 use frame_support::{assert_err, assert_noop, assert_ok};
@@ -63,6 +66,44 @@ fn initializer_on_new_session() {
 	});
 }
 
+#[test]
+fn config_activation_reports_changed_fields() {
+	new_test_ext(Default::default()).execute_with(|| {
+		System::set_block_number(1);
+		let old_config = configuration::ActiveConfig::<Test>::get();
+		let new_config = HostConfiguration {
+			max_code_size: 1024,
+			validation_upgrade_delay: 100,
+			..old_config.clone()
+		};
+
+		assert_ok!(Configuration::set_validation_upgrade_delay(RuntimeOrigin::root(), 100));
+		assert_ok!(Configuration::set_max_code_size(RuntimeOrigin::root(), 1024));
+		assert_eq!(Configuration::pending_configs(), vec![(2, new_config)]);
+
+		on_new_session(1);
+		assert!(System::events().is_empty());
+
+		on_new_session(2);
+		assert!(Configuration::pending_configs().is_empty());
+		System::assert_last_event(RuntimeEvent::Configuration(Event::ActiveConfigUpdated {
+			session: 2,
+			changes: vec![
+				ConfigFieldChange {
+					field: b"max_code_size".to_vec(),
+					old: old_config.max_code_size.encode(),
+					new: 1024u32.encode(),
+				},
+				ConfigFieldChange {
+					field: b"validation_upgrade_delay".to_vec(),
+					old: old_config.validation_upgrade_delay.encode(),
+					new: 100u32.encode(),
+				},
+			],
+		}));
+	});
+}
+
 #[test]
 fn config_changes_after_2_session_boundary() {
 	new_test_ext(Default::default()).execute_with(|| {
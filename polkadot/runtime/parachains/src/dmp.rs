@@ -44,7 +44,7 @@
 
 use crate::{
 	configuration::{self, HostConfiguration},
-	initializer, FeeTracker,
+	initializer, paras, FeeTracker,
 };
 use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::BlockNumberFor;
@@ -63,8 +63,14 @@ pub use pallet::*;
 mod tests;
 
 const THRESHOLD_FACTOR: u32 = 2;
-const EXPONENTIAL_FEE_BASE: FixedU128 = FixedU128::from_rational(105, 100); // 1.05
+/// The base by which the delivery fee factor is multiplied when a queue is above the threshold,
+/// and divided when it is not.
+pub const EXPONENTIAL_FEE_BASE: FixedU128 = FixedU128::from_rational(105, 100); // 1.05
 const MESSAGE_SIZE_FEE_BASE: FixedU128 = FixedU128::from_rational(1, 1000); // 0.001
+/// The prefix of the downward messages notifying a para of the restrictions of its code upgrades.
+///
+/// It distinguishes the notifications from XCM messages, whose encoding starts with the version.
+pub const UPGRADE_RESTRICTION_NOTIFICATION_PREFIX: [u8; 4] = *b"upgr";
 
 /// An error sending a downward message.
 #[cfg_attr(test, derive(Debug))]
@@ -115,7 +121,20 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config + configuration::Config {}
+	pub trait Config: frame_system::Config + configuration::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The delivery fee factor of the para rose above its initial value since its downward
+		/// message queue is congested.
+		CongestionPricingStarted { para: ParaId },
+		/// The delivery fee factor of the para decayed back to its initial value.
+		CongestionPricingEnded { para: ParaId },
+	}
 
 	/// The downward messages addressed for a certain para.
 	#[pallet::storage]
@@ -240,9 +259,7 @@ impl<T: Config> Pallet<T> {
 			v.len()
 		});
 
-		let threshold =
-			Self::dmq_max_length(config.max_downward_message_size).saturating_div(THRESHOLD_FACTOR);
-		if q_len > (threshold as usize) {
+		if q_len > (Self::dmq_fee_threshold(&config) as usize) {
 			let message_size_factor = FixedU128::from((serialized_len / 1024) as u128)
 				.saturating_mul(MESSAGE_SIZE_FEE_BASE);
 			Self::increase_fee_factor(para, message_size_factor);
@@ -300,9 +317,7 @@ impl<T: Config> Pallet<T> {
 		});
 
 		let config = configuration::ActiveConfig::<T>::get();
-		let threshold =
-			Self::dmq_max_length(config.max_downward_message_size).saturating_div(THRESHOLD_FACTOR);
-		if q_len <= (threshold as usize) {
+		if q_len <= (Self::dmq_fee_threshold(&config) as usize) {
 			Self::decrease_fee_factor(para);
 		}
 		T::DbWeight::get().reads_writes(1, 1)
@@ -318,7 +333,7 @@ impl<T: Config> Pallet<T> {
 	/// Returns the number of pending downward messages addressed to the given para.
 	///
 	/// Returns 0 if the para doesn't have an associated downward message queue.
-	pub(crate) fn dmq_length(para: ParaId) -> u32 {
+	pub fn dmq_length(para: ParaId) -> u32 {
 		DownwardMessageQueues::<T>::decode_len(&para)
 			.unwrap_or(0)
 			.saturated_into::<u32>()
@@ -328,6 +343,13 @@ impl<T: Config> Pallet<T> {
 		MAX_POSSIBLE_ALLOCATION.checked_div(max_downward_message_size).unwrap_or(0)
 	}
 
+	/// Returns the number of pending downward messages above which the delivery fee factor of a
+	/// para increases with every new message. At or below it, the fee factor decreases with every
+	/// pruning of the queue.
+	pub fn dmq_fee_threshold(config: &HostConfiguration<BlockNumberFor<T>>) -> u32 {
+		Self::dmq_max_length(config.max_downward_message_size).saturating_div(THRESHOLD_FACTOR)
+	}
+
 	/// Returns the downward message queue contents for the given para.
 	///
 	/// The most recent messages are the latest in the vector.
@@ -336,6 +358,23 @@ impl<T: Config> Pallet<T> {
 	) -> Vec<InboundDownwardMessage<BlockNumberFor<T>>> {
 		DownwardMessageQueues::<T>::get(&recipient)
 	}
+
+	/// Returns at most `count` messages of the downward message queue of the given para, starting
+	/// with the message at index `start`.
+	///
+	/// The oldest message in the queue has index `0`, so pages of the queue can be requested by
+	/// advancing `start` by the number of messages received so far.
+	pub(crate) fn dmq_contents_page(
+		recipient: ParaId,
+		start: u32,
+		count: u32,
+	) -> Vec<InboundDownwardMessage<BlockNumberFor<T>>> {
+		DownwardMessageQueues::<T>::get(&recipient)
+			.into_iter()
+			.skip(start as usize)
+			.take(count as usize)
+			.collect()
+	}
 }
 
 impl<T: Config> FeeTracker for Pallet<T> {
@@ -346,16 +385,51 @@ impl<T: Config> FeeTracker for Pallet<T> {
 	}
 
 	fn increase_fee_factor(id: Self::Id, message_size_factor: FixedU128) -> FixedU128 {
-		DeliveryFeeFactor::<T>::mutate(id, |f| {
+		let (old, new) = DeliveryFeeFactor::<T>::mutate(id, |f| {
+			let old = *f;
 			*f = f.saturating_mul(EXPONENTIAL_FEE_BASE.saturating_add(message_size_factor));
-			*f
-		})
+			(old, *f)
+		});
+		if old == InitialFactor::get() && new > old {
+			Self::deposit_event(Event::CongestionPricingStarted { para: id });
+		}
+		new
 	}
 
 	fn decrease_fee_factor(id: Self::Id) -> FixedU128 {
-		DeliveryFeeFactor::<T>::mutate(id, |f| {
+		let (old, new) = DeliveryFeeFactor::<T>::mutate(id, |f| {
+			let old = *f;
 			*f = InitialFactor::get().max(*f / EXPONENTIAL_FEE_BASE);
-			*f
-		})
+			(old, *f)
+		});
+		if old > new && new == InitialFactor::get() {
+			Self::deposit_event(Event::CongestionPricingEnded { para: id });
+		}
+		new
+	}
+}
+
+/// Notifies paras of the restrictions of their code upgrades with a downward message.
+///
+/// The message is the encoding of [`UPGRADE_RESTRICTION_NOTIFICATION_PREFIX`] followed by the
+/// [`paras::VersionedUpgradeRestrictionNotification`]. The notification is dropped if the message
+/// cannot be queued.
+impl<T: Config> paras::OnUpgradeRestriction<BlockNumberFor<T>> for Pallet<T> {
+	fn on_upgrade_restriction(
+		id: ParaId,
+		notification: &paras::UpgradeRestrictionNotification<BlockNumberFor<T>>,
+	) -> Weight {
+		let config = configuration::ActiveConfig::<T>::get();
+		let notification = paras::VersionedUpgradeRestrictionNotification::V0(notification.clone());
+		let msg = (UPGRADE_RESTRICTION_NOTIFICATION_PREFIX, notification).encode();
+		if Self::queue_downward_message(&config, id, msg).is_err() {
+			log::debug!(
+				target: "runtime::dmp",
+				"failed to notify para {:?} of its upgrade restriction",
+				id,
+			);
+		}
+		// `ActiveConfig`, the queue, its head and the delivery fee factor.
+		T::DbWeight::get().reads_writes(4, 3)
 	}
 }
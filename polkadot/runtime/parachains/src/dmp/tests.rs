@@ -186,6 +186,31 @@ fn dmq_pruning() {
 	});
 }
 
+#[test]
+fn dmq_contents_page_works() {
+	let a = ParaId::from(1312);
+
+	new_test_ext(default_genesis_config()).execute_with(|| {
+		for i in 0..5u8 {
+			queue_downward_message(a, vec![i]).unwrap();
+		}
+		let messages = |page: Vec<InboundDownwardMessage<BlockNumber>>| {
+			page.into_iter().map(|m| m.msg).collect::<Vec<_>>()
+		};
+
+		assert_eq!(messages(Dmp::dmq_contents_page(a, 0, 2)), vec![vec![0], vec![1]]);
+		assert_eq!(messages(Dmp::dmq_contents_page(a, 2, 2)), vec![vec![2], vec![3]]);
+		assert_eq!(messages(Dmp::dmq_contents_page(a, 4, 2)), vec![vec![4]]);
+		assert!(Dmp::dmq_contents_page(a, 5, 2).is_empty());
+		assert!(Dmp::dmq_contents_page(a, 0, 0).is_empty());
+		assert_eq!(Dmp::dmq_contents_page(a, 0, u32::MAX), Dmp::dmq_contents(a));
+
+		// the indices refer to the messages remaining after pruning
+		Dmp::prune_dmq(a, 3);
+		assert_eq!(messages(Dmp::dmq_contents_page(a, 0, 2)), vec![vec![3], vec![4]]);
+	});
+}
+
 #[test]
 fn queue_downward_message_critical() {
 	let a = ParaId::from(1312);
@@ -281,6 +306,37 @@ fn verify_fee_increase_and_decrease() {
 	});
 }
 
+#[test]
+fn congestion_pricing_events_are_emitted() {
+	let a = ParaId::from(123);
+	let mut genesis = default_genesis_config();
+	genesis.configuration.config.max_downward_message_size = 16777216;
+	new_test_ext(genesis).execute_with(|| {
+		System::set_block_number(1);
+
+		// Under fee limit
+		queue_downward_message(a, vec![1]).unwrap();
+		assert!(System::events().is_empty());
+
+		// Limit reached so congestion pricing starts
+		queue_downward_message(a, vec![1]).unwrap();
+		System::assert_last_event(Event::CongestionPricingStarted { para: a }.into());
+
+		// Further increases do not restart it
+		queue_downward_message(a, vec![1]).unwrap();
+		assert_eq!(System::events().len(), 1);
+
+		Dmp::prune_dmq(a, 2);
+		assert!(DeliveryFeeFactor::<Test>::get(a) > InitialFactor::get());
+		assert_eq!(System::events().len(), 1);
+
+		// Congestion pricing ends once the fee factor decayed to its initial value
+		Dmp::prune_dmq(a, 1);
+		assert_eq!(DeliveryFeeFactor::<Test>::get(a), InitialFactor::get());
+		System::assert_last_event(Event::CongestionPricingEnded { para: a }.into());
+	});
+}
+
 #[test]
 fn verify_fee_factor_reaches_high_value() {
 	let a = ParaId::from(123);
@@ -297,3 +353,28 @@ fn verify_fee_factor_reaches_high_value() {
 		assert!(total_fee_factor > FixedU128::from_u32(100_000_000));
 	});
 }
+
+#[test]
+fn upgrade_restriction_is_notified() {
+	let a = ParaId::from(1312);
+	let b = ParaId::from(228);
+
+	new_test_ext(default_genesis_config()).execute_with(|| {
+		let notification = paras::UpgradeRestrictionNotification {
+			reason: paras::UpgradeRestrictionReason::Cooldown,
+			expected_end: 10,
+		};
+		<Dmp as paras::OnUpgradeRestriction<BlockNumber>>::on_upgrade_restriction(a, &notification);
+
+		let queue = Dmp::dmq_contents(a);
+		assert_eq!(queue.len(), 1);
+		assert!(Dmp::dmq_contents(b).is_empty());
+		let (prefix, received) = <(
+			[u8; 4],
+			paras::VersionedUpgradeRestrictionNotification<BlockNumber>,
+		)>::decode(&mut &queue[0].msg[..])
+		.unwrap();
+		assert_eq!(prefix, UPGRADE_RESTRICTION_NOTIFICATION_PREFIX);
+		assert_eq!(received, paras::VersionedUpgradeRestrictionNotification::V0(notification));
+	});
+}
@@ -16,7 +16,7 @@
 
 use crate::{
 	configuration::{self, HostConfiguration},
-	dmp, ensure_parachain, initializer, paras,
+	dmp, ensure_parachain, initializer, paras, shared, OnParaPayment,
 };
 use codec::{Decode, Encode};
 use frame_support::{pallet_prelude::*, traits::ReservableCurrency, DefaultNoBound};
@@ -47,6 +47,8 @@ pub const HRMP_MAX_INBOUND_CHANNELS_BOUND: u32 = 128;
 /// Same as [`HRMP_MAX_INBOUND_CHANNELS_BOUND`], but for outbound channels.
 pub const HRMP_MAX_OUTBOUND_CHANNELS_BOUND: u32 = 128;
 
+pub mod migration;
+
 #[cfg(test)]
 pub(crate) mod tests;
 
@@ -62,10 +64,12 @@ pub trait WeightInfo {
 	fn force_process_hrmp_close(c: u32) -> Weight;
 	fn hrmp_cancel_open_request(c: u32) -> Weight;
 	fn clean_open_channel_requests(c: u32) -> Weight;
+	fn clean_expired_open_requests(c: u32) -> Weight;
 	fn force_open_hrmp_channel(c: u32) -> Weight;
 	fn establish_system_channel() -> Weight;
 	fn poke_channel_deposits() -> Weight;
 	fn establish_channel_with_system() -> Weight;
+	fn force_close_para_channels(c: u32) -> Weight;
 }
 
 /// A weight info that is only suitable for testing.
@@ -96,6 +100,9 @@ impl WeightInfo for TestWeightInfo {
 	fn clean_open_channel_requests(_: u32) -> Weight {
 		Weight::MAX
 	}
+	fn clean_expired_open_requests(_: u32) -> Weight {
+		Weight::MAX
+	}
 	fn force_open_hrmp_channel(_: u32) -> Weight {
 		Weight::MAX
 	}
@@ -108,6 +115,9 @@ impl WeightInfo for TestWeightInfo {
 	fn establish_channel_with_system() -> Weight {
 		Weight::MAX
 	}
+	fn force_close_para_channels(_: u32) -> Weight {
+		Weight::MAX
+	}
 }
 
 /// A description of a request to open an HRMP channel.
@@ -115,9 +125,10 @@ impl WeightInfo for TestWeightInfo {
 pub struct HrmpOpenChannelRequest {
 	/// Indicates if this request was confirmed by the recipient.
 	pub confirmed: bool,
-	/// NOTE: this field is deprecated. Channel open requests became non-expiring and this value
-	/// became unused.
-	pub _age: SessionIndex,
+	/// The session index at which this request was made.
+	///
+	/// Unconfirmed requests expire once `OpenChannelRequestTtl` sessions have passed since then.
+	pub requested_at: SessionIndex,
 	/// The amount that the sender supplied at the time of creation of this request.
 	pub sender_deposit: Balance,
 	/// The maximum message size that could be put into the channel.
@@ -249,7 +260,11 @@ impl fmt::Debug for OutboundHrmpAcceptanceErr {
 pub mod pallet {
 	use super::*;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -286,6 +301,16 @@ pub mod pallet {
 		/// the default `()` implementation uses the latest XCM version for all parachains.
 		type VersionWrapper: xcm::WrapVersion;
 
+		/// The number of sessions after which an unconfirmed open channel request expires and the
+		/// sender's deposit is returned.
+		///
+		/// `None` means that open channel requests never expire.
+		type OpenChannelRequestTtl: Get<Option<SessionIndex>>;
+
+		/// Something that is notified about the deposits reserved from and returned to the
+		/// sovereign accounts of the paras.
+		type OnParaPayment: OnParaPayment;
+
 		/// Something that provides the weight of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -322,6 +347,10 @@ pub mod pallet {
 		},
 		/// An HRMP channel's deposits were updated.
 		OpenChannelDepositsUpdated { sender: ParaId, recipient: ParaId },
+		/// An unconfirmed HRMP open channel request expired and the sender's deposit was returned.
+		OpenChannelRequestExpired { channel_id: HrmpChannelId },
+		/// An HRMP channel was closed immediately via the `ChannelManager` origin.
+		ChannelForceClosed { channel_id: HrmpChannelId },
 	}
 
 	#[pallet::error]
@@ -804,20 +833,12 @@ pub mod pallet {
 						let amount = current_sender_deposit
 							.checked_sub(new_sender_deposit)
 							.ok_or(ArithmeticError::Underflow)?;
-						T::Currency::unreserve(
-							&channel_id.sender.into_account_truncating(),
-							// The difference should always be convertible into `Balance`, but be
-							// paranoid and do nothing in case.
-							amount.try_into().unwrap_or(Zero::zero()),
-						);
+						Self::release_deposit(channel_id.sender, amount);
 					} else if current_sender_deposit < new_sender_deposit {
 						let amount = new_sender_deposit
 							.checked_sub(current_sender_deposit)
 							.ok_or(ArithmeticError::Underflow)?;
-						T::Currency::reserve(
-							&channel_id.sender.into_account_truncating(),
-							amount.try_into().unwrap_or(Zero::zero()),
-						)?;
+						Self::reserve_deposit(channel_id.sender, amount)?;
 					}
 
 					// recipient
@@ -825,18 +846,12 @@ pub mod pallet {
 						let amount = current_recipient_deposit
 							.checked_sub(new_recipient_deposit)
 							.ok_or(ArithmeticError::Underflow)?;
-						T::Currency::unreserve(
-							&channel_id.recipient.into_account_truncating(),
-							amount.try_into().unwrap_or(Zero::zero()),
-						);
+						Self::release_deposit(channel_id.recipient, amount);
 					} else if current_recipient_deposit < new_recipient_deposit {
 						let amount = new_recipient_deposit
 							.checked_sub(current_recipient_deposit)
 							.ok_or(ArithmeticError::Underflow)?;
-						T::Currency::reserve(
-							&channel_id.recipient.into_account_truncating(),
-							amount.try_into().unwrap_or(Zero::zero()),
-						)?;
+						Self::reserve_deposit(channel_id.recipient, amount)?;
 					}
 
 					// update storage
@@ -896,6 +911,70 @@ pub mod pallet {
 
 			Ok(Pays::No.into())
 		}
+
+		/// Remove all unconfirmed open channel requests that have outlived
+		/// `OpenChannelRequestTtl` and return the deposits to their senders.
+		///
+		/// Expired requests are also removed automatically on session change; this call allows
+		/// anyone to clean them up earlier. If at least one request was removed, the transaction
+		/// fees are refunded.
+		///
+		/// Total number of open requests (i.e. `HrmpOpenChannelRequestsList`) must be provided as
+		/// witness data.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::clean_expired_open_requests(*open_requests))]
+		pub fn clean_expired_open_requests(
+			origin: OriginFor<T>,
+			open_requests: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(
+				HrmpOpenChannelRequestsList::<T>::decode_len().unwrap_or_default() as u32 <=
+					open_requests,
+				Error::<T>::WrongWitness
+			);
+
+			let expired = Self::prune_expired_open_channel_requests();
+			if expired > 0 {
+				Ok(Pays::No.into())
+			} else {
+				Ok(Pays::Yes.into())
+			}
+		}
+
+		/// Immediately close the inbound and outbound HRMP channels of `para` and return their
+		/// deposits, e.g. to cut off a misbehaving para.
+		///
+		/// At most `limit` channels are closed, the inbound ones first. If `para` has more
+		/// channels, this can be called again to close the remaining ones. A `ChannelForceClosed`
+		/// event is emitted for each closed channel.
+		///
+		/// Origin must be the `ChannelManager`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_close_para_channels(*limit))]
+		pub fn force_close_para_channels(
+			origin: OriginFor<T>,
+			para: ParaId,
+			limit: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ChannelManager::ensure_origin(origin)?;
+
+			let ingress = HrmpIngressChannelsIndex::<T>::get(para)
+				.into_iter()
+				.map(|sender| HrmpChannelId { sender, recipient: para });
+			let egress = HrmpEgressChannelsIndex::<T>::get(para)
+				.into_iter()
+				.map(|recipient| HrmpChannelId { sender: para, recipient });
+			let to_close = ingress.chain(egress).take(limit as usize).collect::<Vec<_>>();
+
+			for channel_id in &to_close {
+				Self::close_hrmp_channel(channel_id);
+				Self::deposit_event(Event::ChannelForceClosed { channel_id: channel_id.clone() });
+			}
+
+			Ok(Some(<T as Config>::WeightInfo::force_close_para_channels(to_close.len() as u32))
+				.into())
+		}
 	}
 }
 
@@ -938,9 +1017,14 @@ impl<T: Config> Pallet<T> {
 		outgoing_paras: &[ParaId],
 	) -> Weight {
 		let w1 = Self::perform_outgoing_para_cleanup(&notification.prev_config, outgoing_paras);
+		let open_requests = HrmpOpenChannelRequestsList::<T>::decode_len().unwrap_or_default();
+		Self::prune_expired_open_channel_requests();
 		Self::process_hrmp_open_channel_requests(&notification.prev_config);
 		Self::process_hrmp_close_channel_requests();
-		w1.saturating_add(<T as Config>::WeightInfo::force_process_hrmp_open(
+		w1.saturating_add(<T as Config>::WeightInfo::clean_expired_open_requests(
+			open_requests as u32,
+		))
+		.saturating_add(<T as Config>::WeightInfo::force_process_hrmp_open(
 			outgoing_paras.len() as u32
 		))
 		.saturating_add(<T as Config>::WeightInfo::force_process_hrmp_close(
@@ -1003,10 +1087,7 @@ impl<T: Config> Pallet<T> {
 
 			// Return the deposit of the sender, but only if it is not the para being offboarded.
 			if !outgoing.contains(&req_id.sender) {
-				T::Currency::unreserve(
-					&req_id.sender.into_account_truncating(),
-					req_data.sender_deposit.unique_saturated_into(),
-				);
+				Self::release_deposit(req_id.sender, req_data.sender_deposit);
 			}
 
 			// If the request was confirmed, then it means it was confirmed in the finished session.
@@ -1016,10 +1097,7 @@ impl<T: Config> Pallet<T> {
 			// We still want to refund the deposit only if the para is not being offboarded.
 			if req_data.confirmed {
 				if !outgoing.contains(&req_id.recipient) {
-					T::Currency::unreserve(
-						&req_id.recipient.into_account_truncating(),
-						config.hrmp_recipient_deposit.unique_saturated_into(),
-					);
+					Self::release_deposit(req_id.recipient, config.hrmp_recipient_deposit);
 				}
 				Self::decrease_accepted_channel_request_count(req_id.recipient);
 			}
@@ -1028,6 +1106,43 @@ impl<T: Config> Pallet<T> {
 		<T as Config>::WeightInfo::clean_open_channel_requests(outgoing.len() as u32)
 	}
 
+	/// Remove all unconfirmed open channel requests that were made at least
+	/// `OpenChannelRequestTtl` sessions ago and return the deposits to their senders.
+	///
+	/// Returns the number of removed requests.
+	pub(crate) fn prune_expired_open_channel_requests() -> u32 {
+		let Some(ttl) = T::OpenChannelRequestTtl::get() else { return 0 };
+		let current_session = shared::CurrentSessionIndex::<T>::get();
+
+		let open_req_channels = HrmpOpenChannelRequestsList::<T>::get();
+		let (expired, stay): (Vec<HrmpChannelId>, Vec<HrmpChannelId>) =
+			open_req_channels.into_iter().partition(|channel_id| {
+				HrmpOpenChannelRequests::<T>::get(channel_id).map_or(false, |req| {
+					!req.confirmed && req.requested_at.saturating_add(ttl) <= current_session
+				})
+			});
+		if expired.is_empty() {
+			return 0
+		}
+		HrmpOpenChannelRequestsList::<T>::put(stay);
+
+		let count = expired.len() as u32;
+		for channel_id in expired {
+			let Some(req_data) = HrmpOpenChannelRequests::<T>::take(&channel_id) else {
+				// Can't normally happen but no need to panic.
+				continue
+			};
+
+			Self::decrease_open_channel_request_count(channel_id.sender);
+			// The request is not confirmed, so only the sender has a deposit to return.
+			Self::release_deposit(channel_id.sender, req_data.sender_deposit);
+
+			Self::deposit_event(Event::OpenChannelRequestExpired { channel_id });
+		}
+
+		count
+	}
+
 	/// Remove all storage entries associated with the given para.
 	fn clean_hrmp_after_outgoing(outgoing_para: &ParaId) {
 		HrmpOpenChannelRequestCount::<T>::remove(outgoing_para);
@@ -1127,6 +1242,21 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Reserve a channel deposit of `amount` from the sovereign account of `para`.
+	fn reserve_deposit(para: ParaId, amount: Balance) -> DispatchResult {
+		T::Currency::reserve(&para.into_account_truncating(), amount.unique_saturated_into())?;
+		T::OnParaPayment::hrmp_deposit_reserved(para, amount);
+		Ok(())
+	}
+
+	/// Return a channel deposit of `amount` to the sovereign account of `para`.
+	fn release_deposit(para: ParaId, amount: Balance) {
+		let missing: Balance =
+			T::Currency::unreserve(&para.into_account_truncating(), amount.unique_saturated_into())
+				.unique_saturated_into();
+		T::OnParaPayment::hrmp_deposit_released(para, amount.saturating_sub(missing));
+	}
+
 	/// Close and remove the designated HRMP channel.
 	///
 	/// This includes returning the deposits.
@@ -1137,14 +1267,8 @@ impl<T: Config> Pallet<T> {
 		if let Some(HrmpChannel { sender_deposit, recipient_deposit, .. }) =
 			HrmpChannels::<T>::take(channel_id)
 		{
-			T::Currency::unreserve(
-				&channel_id.sender.into_account_truncating(),
-				sender_deposit.unique_saturated_into(),
-			);
-			T::Currency::unreserve(
-				&channel_id.recipient.into_account_truncating(),
-				recipient_deposit.unique_saturated_into(),
-			);
+			Self::release_deposit(channel_id.sender, sender_deposit);
+			Self::release_deposit(channel_id.recipient, recipient_deposit);
 		}
 
 		HrmpChannelContents::<T>::remove(channel_id);
@@ -1485,10 +1609,7 @@ impl<T: Config> Pallet<T> {
 		let is_system = origin.is_system() || recipient.is_system();
 		let deposit = if is_system { 0 } else { config.hrmp_sender_deposit };
 		if !deposit.is_zero() {
-			T::Currency::reserve(
-				&origin.into_account_truncating(),
-				deposit.unique_saturated_into(),
-			)?;
+			Self::reserve_deposit(origin, deposit)?;
 		}
 
 		// mutating storage directly now -- shall not bail henceforth.
@@ -1498,7 +1619,7 @@ impl<T: Config> Pallet<T> {
 			&channel_id,
 			HrmpOpenChannelRequest {
 				confirmed: false,
-				_age: 0,
+				requested_at: shared::CurrentSessionIndex::<T>::get(),
 				sender_deposit: deposit,
 				max_capacity: proposed_max_capacity,
 				max_message_size: proposed_max_message_size,
@@ -1549,10 +1670,7 @@ impl<T: Config> Pallet<T> {
 		let is_system = origin.is_system() || sender.is_system();
 		let deposit = if is_system { 0 } else { config.hrmp_recipient_deposit };
 		if !deposit.is_zero() {
-			T::Currency::reserve(
-				&origin.into_account_truncating(),
-				deposit.unique_saturated_into(),
-			)?;
+			Self::reserve_deposit(origin, deposit)?;
 		}
 
 		// persist the updated open channel request and then increment the number of accepted
@@ -1596,10 +1714,7 @@ impl<T: Config> Pallet<T> {
 
 		// Unreserve the sender's deposit. The recipient could not have left their deposit because
 		// we ensured that the request is not confirmed.
-		T::Currency::unreserve(
-			&channel_id.sender.into_account_truncating(),
-			open_channel_req.sender_deposit.unique_saturated_into(),
-		);
+		Self::release_deposit(channel_id.sender, open_channel_req.sender_deposit);
 
 		Ok(())
 	}
@@ -20,7 +20,7 @@ use crate::{
 	configuration::Pallet as Configuration,
 	hrmp::{Pallet as Hrmp, *},
 	paras::{Pallet as Paras, ParaKind, ParachainsCache},
-	shared::Pallet as Shared,
+	shared::{CurrentSessionIndex, Pallet as Shared},
 };
 use frame_benchmarking::{v2::*, whitelisted_caller};
 use frame_support::{assert_ok, traits::Currency};
@@ -282,6 +282,44 @@ mod benchmarks {
 		Hrmp::<T>::assert_storage_consistency_exhaustive();
 	}
 
+	#[benchmark]
+	fn force_close_para_channels(
+		// channels of a single parachain that need to be closed.
+		c: Linear<0, { HRMP_MAX_INBOUND_CHANNELS_BOUND - 1 }>,
+	) {
+		assert_ok!(Configuration::<T>::set_hrmp_max_parachain_inbound_channels(
+			frame_system::RawOrigin::Root.into(),
+			c + 1
+		));
+		assert_ok!(Configuration::<T>::set_max_downward_message_size(
+			frame_system::RawOrigin::Root.into(),
+			1024
+		));
+		Configuration::<T>::initializer_on_new_session(&Shared::<T>::scheduled_session());
+
+		let config = configuration::ActiveConfig::<T>::get();
+		let deposit: BalanceOf<T> = config.hrmp_sender_deposit.unique_saturated_into();
+
+		let para: ParaId = 1u32.into();
+		register_parachain_with_balance::<T>(para, deposit);
+		T::Currency::make_free_balance_be(&para.into_account_truncating(), deposit * 256u32.into());
+
+		for ingress_para_id in 0..c {
+			let _ = establish_para_connection::<T>(
+				ingress_para_id + PREFIX_0,
+				para.into(),
+				ParachainSetupStep::Established,
+			);
+		}
+		assert_eq!(HrmpChannels::<T>::iter().count() as u32, c);
+
+		#[extrinsic_call]
+		_(frame_system::Origin::<T>::Root, para, c);
+
+		assert_eq!(HrmpChannels::<T>::iter().count() as u32, 0);
+		Hrmp::<T>::assert_storage_consistency_exhaustive();
+	}
+
 	#[benchmark]
 	fn force_process_hrmp_open(
 		// number of channels that need to be processed. Worse case is an N-M relation: unique
@@ -378,6 +416,35 @@ mod benchmarks {
 		assert_eq!(HrmpOpenChannelRequestsList::<T>::decode_len().unwrap_or_default() as u32, 0);
 	}
 
+	#[benchmark]
+	fn clean_expired_open_requests(c: Linear<0, MAX_UNIQUE_CHANNELS>) {
+		for id in 0..c {
+			let _ = establish_para_connection::<T>(
+				PREFIX_0 + id,
+				PREFIX_1 + id,
+				ParachainSetupStep::Requested,
+			);
+		}
+
+		assert_eq!(HrmpOpenChannelRequestsList::<T>::decode_len().unwrap_or_default() as u32, c);
+		// Move past the expiry of all the requests made above.
+		let ttl = T::OpenChannelRequestTtl::get();
+		if let Some(ttl) = ttl {
+			Shared::<T>::set_session_index(CurrentSessionIndex::<T>::get().saturating_add(ttl));
+		}
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(frame_system::RawOrigin::Signed(caller), c);
+
+		if ttl.is_some() {
+			assert_eq!(
+				HrmpOpenChannelRequestsList::<T>::decode_len().unwrap_or_default() as u32,
+				0
+			);
+		}
+	}
+
 	#[benchmark]
 	fn force_open_hrmp_channel(
 		// Weight parameter only accepts `u32`, `0` and `1` used to represent `false` and `true`,
@@ -0,0 +1,98 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+//! A module that is responsible for migration of storage.
+
+pub use v1::MigrateToV1;
+
+mod v1 {
+	use crate::{
+		hrmp::{Config, HrmpOpenChannelRequest, HrmpOpenChannelRequests, Pallet},
+		shared,
+	};
+	use frame_support::{traits::UncheckedOnRuntimeUpgrade, weights::Weight};
+	use sp_core::Get;
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	#[cfg(feature = "try-runtime")]
+	use codec::{Decode, Encode};
+	#[cfg(feature = "try-runtime")]
+	use frame_support::{
+		ensure,
+		traits::{GetStorageVersion, StorageVersion},
+	};
+
+	pub struct VersionUncheckedMigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateToV1<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let requests_before_upgrade = HrmpOpenChannelRequests::<T>::iter().count() as u32;
+			Ok(requests_before_upgrade.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			// The deprecated `_age` field was never written, so existing requests would be
+			// considered expired right away. Start counting their lifetime from the current
+			// session instead.
+			let current_session = shared::CurrentSessionIndex::<T>::get();
+			let mut count = 0u64;
+			HrmpOpenChannelRequests::<T>::translate_values(|mut req: HrmpOpenChannelRequest| {
+				req.requested_at = current_session;
+				count += 1;
+				Some(req)
+			});
+
+			T::DbWeight::get().reads_writes(count + 1, count)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(1),
+				"Storage version should be >= 1 after the migration"
+			);
+
+			let requests_before_upgrade =
+				u32::decode(&mut &state[..]).expect("Was properly encoded");
+			let current_session = shared::CurrentSessionIndex::<T>::get();
+			let mut requests_after_upgrade = 0u32;
+			for req in HrmpOpenChannelRequests::<T>::iter_values() {
+				ensure!(
+					req.requested_at == current_session,
+					"Open channel requests should be stamped with the current session"
+				);
+				requests_after_upgrade += 1;
+			}
+
+			ensure!(
+				requests_before_upgrade == requests_after_upgrade,
+				"Number of open channel requests should be the same as the one before the upgrade."
+			);
+
+			Ok(())
+		}
+	}
+
+	/// Migrate to v1 hrmp module storage.
+	/// - sets `requested_at` of all pending open channel requests to the current session, so that
+	///   they only start expiring from the time of the upgrade.
+	pub type MigrateToV1<T> = frame_support::migrations::VersionedMigration<
+		0,
+		1,
+		VersionUncheckedMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -22,8 +22,8 @@ use super::*;
 use crate::{
 	mock::{
 		deregister_parachain, new_test_ext, register_parachain, register_parachain_with_balance,
-		Dmp, Hrmp, MockGenesisConfig, Paras, ParasShared, RuntimeEvent as MockEvent, RuntimeOrigin,
-		System, Test, TestUsesOnlyStoredVersionWrapper,
+		Dmp, Hrmp, MockGenesisConfig, OpenChannelRequestTtl, Paras, ParasShared,
+		RuntimeEvent as MockEvent, RuntimeOrigin, System, Test, TestUsesOnlyStoredVersionWrapper,
 	},
 	shared,
 };
@@ -821,6 +821,65 @@ fn refund_deposit_on_offboarding() {
 	});
 }
 
+#[test]
+fn force_close_para_channels_works() {
+	let para_a = 2032.into();
+	let para_b = 2064.into();
+	let para_c = 3000.into();
+
+	let mut genesis = GenesisConfigBuilder::default();
+	genesis.hrmp_sender_deposit = 20;
+	genesis.hrmp_recipient_deposit = 15;
+	new_test_ext(genesis.build()).execute_with(|| {
+		register_parachain_with_balance(para_a, 100);
+		register_parachain_with_balance(para_b, 100);
+		register_parachain_with_balance(para_c, 100);
+		run_to_block(5, Some(vec![4, 5]));
+
+		// `para_a` has one outbound and one inbound channel, `para_b` and `para_c` have one more
+		// channel between them which is unaffected.
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+		Hrmp::init_open_channel(para_c, para_a, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_a, para_c).unwrap();
+		Hrmp::init_open_channel(para_b, para_c, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_c, para_b).unwrap();
+		run_to_block(8, Some(vec![8]));
+		assert_eq!(<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()), 65);
+
+		assert_noop!(
+			Hrmp::force_close_para_channels(RuntimeOrigin::signed(1), para_a, 2),
+			BadOrigin
+		);
+
+		// The inbound channel is closed first.
+		assert_ok!(Hrmp::force_close_para_channels(RuntimeOrigin::root(), para_a, 1));
+		assert!(!channel_exists(para_c, para_a));
+		assert!(channel_exists(para_a, para_b));
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::ChannelForceClosed {
+				channel_id: HrmpChannelId { sender: para_c, recipient: para_a }
+			})));
+		Hrmp::assert_storage_consistency_exhaustive();
+
+		// Calling again closes the remaining channel.
+		assert_ok!(Hrmp::force_close_para_channels(RuntimeOrigin::root(), para_a, 2));
+		assert!(!channel_exists(para_a, para_b));
+		assert!(channel_exists(para_b, para_c));
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::ChannelForceClosed {
+				channel_id: HrmpChannelId { sender: para_a, recipient: para_b }
+			})));
+		Hrmp::assert_storage_consistency_exhaustive();
+
+		// All deposits of `para_a` are returned.
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()),
+			100
+		);
+	});
+}
+
 #[test]
 fn no_dangling_open_requests() {
 	let para_a = 2032.into();
@@ -891,6 +950,92 @@ fn cancel_pending_open_channel_request() {
 	});
 }
 
+#[test]
+fn unconfirmed_open_channel_request_expires_on_session_change() {
+	let para_a = 2032.into();
+	let para_b = 2064.into();
+	let para_c = 2096.into();
+
+	let mut genesis = GenesisConfigBuilder::default();
+	genesis.hrmp_sender_deposit = 20;
+	genesis.hrmp_recipient_deposit = 15;
+	new_test_ext(genesis.build()).execute_with(|| {
+		OpenChannelRequestTtl::set(Some(2));
+		register_parachain_with_balance(para_a, 100);
+		register_parachain_with_balance(para_b, 110);
+		register_parachain_with_balance(para_c, 120);
+		run_to_block(5, Some(vec![4, 5]));
+
+		// Request a->b, which is never accepted, and c->b, which is accepted.
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::init_open_channel(para_c, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_c).unwrap();
+		assert_eq!(<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()), 80);
+
+		// One session later the request is still pending.
+		run_to_block(8, Some(vec![8]));
+		let channel_id = HrmpChannelId { sender: para_a, recipient: para_b };
+		assert!(HrmpOpenChannelRequests::<Test>::get(&channel_id).is_some());
+		assert!(channel_exists(para_c, para_b));
+
+		// Once the TTL has passed, the request is removed and the deposit returned.
+		run_to_block(10, Some(vec![10]));
+		assert!(HrmpOpenChannelRequests::<Test>::get(&channel_id).is_none());
+		assert!(!channel_exists(para_a, para_b));
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()),
+			100
+		);
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::OpenChannelRequestExpired {
+				channel_id: channel_id.clone()
+			})));
+		Hrmp::assert_storage_consistency_exhaustive();
+	});
+}
+
+#[test]
+fn clean_expired_open_requests_works() {
+	let para_a = 2032.into();
+	let para_b = 2064.into();
+
+	let mut genesis = GenesisConfigBuilder::default();
+	genesis.hrmp_sender_deposit = 20;
+	genesis.hrmp_recipient_deposit = 15;
+	new_test_ext(genesis.build()).execute_with(|| {
+		OpenChannelRequestTtl::set(Some(2));
+		register_parachain_with_balance(para_a, 100);
+		register_parachain_with_balance(para_b, 110);
+		run_to_block(5, Some(vec![4, 5]));
+
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		let channel_id = HrmpChannelId { sender: para_a, recipient: para_b };
+
+		// The witness must cover all the open requests.
+		assert_noop!(
+			Hrmp::clean_expired_open_requests(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::WrongWitness
+		);
+
+		// Nothing to clean yet, so the caller pays.
+		let post_info = Hrmp::clean_expired_open_requests(RuntimeOrigin::signed(1), 1).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::Yes);
+		assert!(HrmpOpenChannelRequests::<Test>::get(&channel_id).is_some());
+
+		// Move past the TTL without triggering the session change logic.
+		shared::Pallet::<Test>::set_session_index(4);
+		let post_info = Hrmp::clean_expired_open_requests(RuntimeOrigin::signed(1), 1).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+		assert!(HrmpOpenChannelRequests::<Test>::get(&channel_id).is_none());
+		assert_eq!(HrmpOpenChannelRequestCount::<Test>::get(&para_a), 0);
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()),
+			100
+		);
+		Hrmp::assert_storage_consistency_exhaustive();
+	});
+}
+
 #[test]
 fn watermark_maxed_out_at_relay_parent() {
 	let para_a = 2032.into();
@@ -23,7 +23,7 @@ use crate::{
 	configuration::{self, HostConfiguration},
 	disputes, dmp, hrmp,
 	paras::{self, UpgradeStrategy},
-	scheduler,
+	scheduler::{self, common::AssignmentProvider},
 	shared::{self, AllowedRelayParentsTracker},
 	util::make_persisted_validation_data_with_parent,
 };
@@ -32,7 +32,8 @@ use codec::{Decode, Encode};
 use frame_support::{
 	defensive,
 	pallet_prelude::*,
-	traits::{EnqueueMessage, Footprint, QueueFootprint},
+	traits::{EnqueueMessage, Footprint, ProcessMessage, ProcessMessageError, QueueFootprint},
+	weights::WeightMeter,
 	BoundedSlice,
 };
 use frame_system::pallet_prelude::*;
@@ -45,13 +46,18 @@ use polkadot_primitives::{
 	ValidityAttestation,
 };
 use scale_info::TypeInfo;
-use sp_runtime::{traits::One, DispatchError, SaturatedConversion, Saturating};
+use sp_runtime::{
+	traits::One, DispatchError, FixedPointNumber, FixedU128, SaturatedConversion, Saturating,
+};
 #[cfg(feature = "std")]
 use sp_std::fmt;
 use sp_std::{
 	collections::{btree_map::BTreeMap, btree_set::BTreeSet, vec_deque::VecDeque},
+	marker::PhantomData,
 	prelude::*,
 };
+use xcm::latest::{Asset, Junction, Location, XcmContext, XcmError};
+use xcm_executor::{traits::WeightTrader, AssetsInHolding};
 
 pub use pallet::*;
 
@@ -231,6 +237,32 @@ impl QueueFootprinter for () {
 	}
 }
 
+/// The outcomes of the upward messages of a para which were executed in the current session.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct UmpDispatchOutcomes {
+	/// The number of messages which executed successfully.
+	pub succeeded: u32,
+	/// The number of messages which failed to execute.
+	pub failed: u32,
+}
+
+/// Parameters for adjusting the UMP dispatch fee factor of the paras at each session change.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct UmpFeeAdjustment {
+	/// Subtracted from the fee factor of a para whose upward messages all executed successfully
+	/// during the session.
+	pub rebate: FixedU128,
+	/// Added to the fee factor of a para with at least `failure_threshold` failed upward
+	/// messages during the session.
+	pub surcharge: FixedU128,
+	/// The number of failed upward messages within a session from which on a para is surcharged.
+	pub failure_threshold: u32,
+	/// The lowest fee factor a para can be rebated to.
+	pub min_factor: FixedU128,
+	/// The highest fee factor a para can be surcharged to.
+	pub max_factor: FixedU128,
+}
+
 /// Aggregate message origin for the `MessageQueue` pallet.
 ///
 /// Can be extended to serve further use-cases besides just UMP. Is stored in storage, so any change
@@ -298,6 +330,14 @@ pub mod pallet {
 
 		/// Weight info for the calls of this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// The adjustment of the UMP dispatch fee factors at each session change.
+		///
+		/// The outcomes of upward messages are only tracked when this is `Some` and the message
+		/// processor is wrapped in [`RecordUmpOutcome`]. The factors are applied to the price of
+		/// executing upward messages by wrapping the XCM weight trader in
+		/// [`UmpFeeFactorTrader`]. Use `()` to disable the adjustment.
+		type UmpFeeAdjustment: Get<Option<UmpFeeAdjustment>>;
 	}
 
 	#[pallet::event]
@@ -311,6 +351,9 @@ pub mod pallet {
 		CandidateTimedOut(CandidateReceipt<T::Hash>, HeadData, CoreIndex),
 		/// Some upward messages have been received and will be processed.
 		UpwardMessagesReceived { from: ParaId, count: u32 },
+		/// The UMP dispatch fee factor of a para was adjusted according to the outcomes of its
+		/// upward messages in the last session.
+		UmpFeeFactorAdjusted { para: ParaId, factor: FixedU128 },
 	}
 
 	#[pallet::error]
@@ -370,6 +413,25 @@ pub mod pallet {
 		VecDeque<CandidatePendingAvailability<T::Hash, BlockNumberFor<T>>>,
 	>;
 
+	/// The outcomes of the upward messages executed in the current session, by `ParaId`.
+	#[pallet::storage]
+	pub(crate) type UmpOutcomes<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, UmpDispatchOutcomes, ValueQuery>;
+
+	/// Initialization value for the UMP dispatch fee factor.
+	#[pallet::type_value]
+	pub fn InitialUmpFeeFactor() -> FixedU128 {
+		FixedU128::from_u32(1)
+	}
+
+	/// The factor to multiply the UMP dispatch fee of a para by.
+	///
+	/// Lowered for paras whose upward messages execute successfully and raised for paras whose
+	/// upward messages repeatedly fail, see [`Config::UmpFeeAdjustment`].
+	#[pallet::storage]
+	pub(crate) type UmpFeeFactor<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, FixedU128, ValueQuery, InitialUmpFeeFactor>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {}
 }
@@ -484,9 +546,44 @@ impl<T: Config> Pallet<T> {
 		// and require consumption.
 		for _ in PendingAvailability::<T>::drain() {}
 
+		Self::adjust_ump_fee_factors(outgoing_paras);
 		Self::cleanup_outgoing_ump_dispatch_queues(outgoing_paras);
 	}
 
+	/// Adjust the UMP dispatch fee factors of the paras according to the outcomes of their
+	/// upward messages in the ending session.
+	fn adjust_ump_fee_factors(outgoing_paras: &[ParaId]) {
+		let outcomes: Vec<_> = UmpOutcomes::<T>::drain().collect();
+		for para in outgoing_paras {
+			UmpFeeFactor::<T>::remove(para);
+		}
+		let Some(adjustment) = T::UmpFeeAdjustment::get() else { return };
+
+		for (para, outcome) in outcomes {
+			if outgoing_paras.contains(&para) {
+				continue
+			}
+			let old = UmpFeeFactor::<T>::get(para);
+			let new = if outcome.failed >= adjustment.failure_threshold.max(1) {
+				old.saturating_add(adjustment.surcharge).min(adjustment.max_factor)
+			} else if outcome.failed == 0 && outcome.succeeded > 0 {
+				old.saturating_sub(adjustment.rebate).max(adjustment.min_factor)
+			} else {
+				continue
+			};
+			if new != old {
+				UmpFeeFactor::<T>::insert(para, new);
+				Self::deposit_event(Event::UmpFeeFactorAdjusted { para, factor: new });
+			}
+		}
+	}
+
+	/// The factor to multiply the UMP dispatch fee of `para` by, as applied by
+	/// [`UmpFeeFactorTrader`].
+	pub fn ump_fee_factor(para: ParaId) -> FixedU128 {
+		UmpFeeFactor::<T>::get(para)
+	}
+
 	pub(crate) fn cleanup_outgoing_ump_dispatch_queues(outgoing: &[ParaId]) {
 		for outgoing_para in outgoing {
 			Self::cleanup_outgoing_ump_dispatch_queue(*outgoing_para);
@@ -885,6 +982,13 @@ impl<T: Config> Pallet<T> {
 			commitments.horizontal_messages,
 		));
 
+		<T as scheduler::Config>::AssignmentProvider::note_included(
+			core_index,
+			receipt.descriptor.para_id,
+		);
+		weight
+			.saturating_accrue(<T as scheduler::Config>::AssignmentProvider::note_included_weight());
+
 		Self::deposit_event(Event::<T>::CandidateIncluded(
 			plain,
 			commitments.head_data.clone(),
@@ -1206,6 +1310,103 @@ impl<T: Config> OnQueueChanged<AggregateMessageOrigin> for Pallet<T> {
 	}
 }
 
+/// Wraps the UMP message processor `P` to track the outcome of the upward messages of each para
+/// for adjusting their UMP dispatch fee factors.
+///
+/// Messages which are processed without errors count as successful, messages which are corrupt,
+/// unsupported or fail to execute count as failed. Overweight messages are not counted since they
+/// are retried. Nothing is tracked unless [`Config::UmpFeeAdjustment`] is set.
+pub struct RecordUmpOutcome<T, P>(PhantomData<(T, P)>);
+
+impl<T, P> ProcessMessage for RecordUmpOutcome<T, P>
+where
+	T: Config,
+	P: ProcessMessage<Origin = AggregateMessageOrigin>,
+{
+	type Origin = AggregateMessageOrigin;
+
+	fn process_message(
+		message: &[u8],
+		origin: Self::Origin,
+		meter: &mut WeightMeter,
+		id: &mut [u8; 32],
+	) -> Result<bool, ProcessMessageError> {
+		if T::UmpFeeAdjustment::get().is_none() {
+			return P::process_message(message, origin, meter, id)
+		}
+		let para = match &origin {
+			AggregateMessageOrigin::Ump(UmpQueueId::Para(p)) => *p,
+		};
+
+		// Reserve the weight for recording the outcome.
+		let record_weight = T::DbWeight::get().reads_writes(1, 1);
+		if !meter.can_consume(record_weight) {
+			return Err(ProcessMessageError::Yield)
+		}
+		let mut inner = WeightMeter::with_limit(meter.remaining().saturating_sub(record_weight));
+		let result = P::process_message(message, origin, &mut inner, id);
+		meter.consume(inner.consumed());
+
+		let succeeded = match result {
+			Ok(success) => success,
+			Err(ProcessMessageError::Overweight(_) | ProcessMessageError::Yield) => return result,
+			Err(_) => false,
+		};
+		meter.consume(record_weight);
+		UmpOutcomes::<T>::mutate(para, |outcomes| {
+			if succeeded {
+				outcomes.succeeded.saturating_inc();
+			} else {
+				outcomes.failed.saturating_inc();
+			}
+		});
+		result
+	}
+}
+
+/// Wraps the XCM weight trader `Inner` to multiply the price of the weight bought for messages
+/// originating from a para by its UMP dispatch fee factor, see [`Config::UmpFeeAdjustment`].
+///
+/// The weight is scaled by the factor before it is bought from or refunded to `Inner`. Messages of
+/// other origins are priced by `Inner` alone.
+pub struct UmpFeeFactorTrader<T, Inner> {
+	factor: FixedU128,
+	inner: Inner,
+	_phantom: PhantomData<T>,
+}
+
+impl<T, Inner> UmpFeeFactorTrader<T, Inner> {
+	fn scale(&self, weight: Weight) -> Weight {
+		Weight::from_parts(
+			self.factor.saturating_mul_int(weight.ref_time()),
+			self.factor.saturating_mul_int(weight.proof_size()),
+		)
+	}
+}
+
+impl<T: Config, Inner: WeightTrader> WeightTrader for UmpFeeFactorTrader<T, Inner> {
+	fn new() -> Self {
+		Self { factor: FixedU128::one(), inner: Inner::new(), _phantom: PhantomData }
+	}
+
+	fn buy_weight(
+		&mut self,
+		weight: Weight,
+		payment: AssetsInHolding,
+		context: &XcmContext,
+	) -> Result<AssetsInHolding, XcmError> {
+		self.factor = match context.origin.as_ref().map(Location::unpack) {
+			Some((0, [Junction::Parachain(id), ..])) => Pallet::<T>::ump_fee_factor((*id).into()),
+			_ => FixedU128::one(),
+		};
+		self.inner.buy_weight(self.scale(weight), payment, context)
+	}
+
+	fn refund_weight(&mut self, weight: Weight, context: &XcmContext) -> Option<Asset> {
+		self.inner.refund_weight(self.scale(weight), context)
+	}
+}
+
 /// A collection of data required for checking a candidate.
 pub(crate) struct CandidateCheckContext<T: Config> {
 	config: configuration::HostConfiguration<BlockNumberFor<T>>,
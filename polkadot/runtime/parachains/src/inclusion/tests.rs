@@ -19,7 +19,8 @@ use crate::{
 	configuration::HostConfiguration,
 	initializer::SessionChangeNotification,
 	mock::{
-		new_test_ext, MockGenesisConfig, ParaInclusion, Paras, ParasShared, Scheduler, System, Test,
+		new_test_ext, MockGenesisConfig, ParaInclusion, Paras, ParasShared, Scheduler, System,
+		Test, TestProcessMessage, TestUmpFeeAdjustment,
 	},
 	paras::{ParaGenesisArgs, ParaKind},
 	paras_inherent::DisputedBitfield,
@@ -2724,6 +2725,112 @@ fn aggregate_origin_decode_regression_check() {
 	assert_eq!(decoded, Ok(ump), "Migration needed for AggregateMessageOrigin");
 }
 
+#[test]
+fn ump_fee_factor_is_adjusted_at_session_change() {
+	let chain_a = ParaId::from(1_u32);
+	let chain_b = ParaId::from(2_u32);
+	let chain_c = ParaId::from(3_u32);
+	let paras = vec![
+		(chain_a, ParaKind::Parachain),
+		(chain_b, ParaKind::Parachain),
+		(chain_c, ParaKind::Parachain),
+	];
+
+	new_test_ext(genesis_config(paras)).execute_with(|| {
+		TestUmpFeeAdjustment::set(Some(UmpFeeAdjustment {
+			rebate: FixedU128::from_rational(1, 10),
+			surcharge: FixedU128::from_rational(1, 2),
+			failure_threshold: 2,
+			min_factor: FixedU128::from_rational(1, 2),
+			max_factor: FixedU128::from_u32(2),
+		}));
+		let process = |para: ParaId, message: &[u8]| {
+			RecordUmpOutcome::<Test, TestProcessMessage>::process_message(
+				message,
+				AggregateMessageOrigin::Ump(UmpQueueId::Para(para)),
+				&mut WeightMeter::new(),
+				&mut [0u8; 32],
+			)
+		};
+
+		// All messages of A succeed, B fails twice and C only once.
+		assert_eq!(process(chain_a, &10u32.encode()), Ok(true));
+		assert_eq!(process(chain_b, &[1]), Err(ProcessMessageError::Corrupt));
+		assert_eq!(process(chain_b, &[2]), Err(ProcessMessageError::Corrupt));
+		assert_eq!(process(chain_c, &10u32.encode()), Ok(true));
+		assert_eq!(process(chain_c, &[3]), Err(ProcessMessageError::Corrupt));
+
+		ParaInclusion::initializer_on_new_session(&Default::default(), &[]);
+		assert_eq!(ParaInclusion::ump_fee_factor(chain_a), FixedU128::from_rational(9, 10));
+		assert_eq!(ParaInclusion::ump_fee_factor(chain_b), FixedU128::from_rational(3, 2));
+		assert_eq!(ParaInclusion::ump_fee_factor(chain_c), FixedU128::from_u32(1));
+		assert_eq!(UmpOutcomes::<Test>::iter().count(), 0);
+
+		// The factors are bounded.
+		for _ in 0..2 {
+			assert_eq!(process(chain_a, &10u32.encode()), Ok(true));
+			assert_eq!(process(chain_b, &[1]), Err(ProcessMessageError::Corrupt));
+			assert_eq!(process(chain_b, &[2]), Err(ProcessMessageError::Corrupt));
+			ParaInclusion::initializer_on_new_session(&Default::default(), &[]);
+		}
+		assert_eq!(ParaInclusion::ump_fee_factor(chain_a), FixedU128::from_rational(7, 10));
+		assert_eq!(ParaInclusion::ump_fee_factor(chain_b), FixedU128::from_u32(2));
+
+		// Outgoing paras are reset.
+		ParaInclusion::initializer_on_new_session(&Default::default(), &[chain_b]);
+		assert_eq!(ParaInclusion::ump_fee_factor(chain_b), FixedU128::from_u32(1));
+	});
+}
+
+frame_support::parameter_types! {
+	static BoughtWeight: Weight = Weight::zero();
+}
+
+/// Buys any weight for nothing, recording the weight bought.
+struct RecordingTrader;
+impl WeightTrader for RecordingTrader {
+	fn new() -> Self {
+		RecordingTrader
+	}
+
+	fn buy_weight(
+		&mut self,
+		weight: Weight,
+		payment: AssetsInHolding,
+		_: &XcmContext,
+	) -> Result<AssetsInHolding, XcmError> {
+		BoughtWeight::set(weight);
+		Ok(payment)
+	}
+}
+
+#[test]
+fn ump_fee_factor_scales_the_weight_bought_for_messages_of_a_para() {
+	let chain_a = ParaId::from(1_u32);
+	let paras = vec![(chain_a, ParaKind::Parachain)];
+
+	new_test_ext(genesis_config(paras)).execute_with(|| {
+		UmpFeeFactor::<Test>::insert(chain_a, FixedU128::from_rational(3, 2));
+		let buy = |origin: Location| {
+			let mut trader = UmpFeeFactorTrader::<Test, RecordingTrader>::new();
+			let context = XcmContext { origin: Some(origin), message_id: [0; 32], topic: None };
+			let weight = Weight::from_parts(100, 10);
+			assert!(trader.buy_weight(weight, AssetsInHolding::new(), &context).is_ok());
+			BoughtWeight::get()
+		};
+
+		assert_eq!(buy(Location::new(0, [Junction::Parachain(1)])), Weight::from_parts(150, 15));
+		let account = Junction::AccountId32 { network: None, id: [1; 32] };
+		assert_eq!(
+			buy(Location::new(0, [Junction::Parachain(1), account])),
+			Weight::from_parts(150, 15)
+		);
+		// Paras without an adjusted factor and other origins pay the unscaled price.
+		assert_eq!(buy(Location::new(0, [Junction::Parachain(2)])), Weight::from_parts(100, 10));
+		assert_eq!(buy(Location::parent()), Weight::from_parts(100, 10));
+	});
+}
+
 #[test]
 fn para_upgrade_delay_scheduled_from_inclusion() {
 	let chain_a = ParaId::from(1_u32);
@@ -35,6 +35,7 @@ pub mod inclusion;
 pub mod initializer;
 pub mod metrics;
 pub mod origin;
+pub mod para_payments;
 pub mod paras;
 pub mod paras_inherent;
 pub mod reward_points;
@@ -55,7 +56,7 @@ mod ump_tests;
 
 pub use origin::{ensure_parachain, Origin};
 pub use paras::{ParaLifecycle, UpgradeStrategy};
-use polkadot_primitives::{HeadData, Id as ParaId, ValidationCode};
+use polkadot_primitives::{Balance, HeadData, Id as ParaId, ValidationCode};
 use sp_runtime::{DispatchResult, FixedU128};
 
 /// Trait for tracking message delivery fees on a transport protocol.
@@ -77,6 +78,25 @@ pub trait FeeTracker {
 	fn decrease_fee_factor(id: Self::Id) -> FixedU128;
 }
 
+/// Trait for keeping track of what the paras pay the relay chain for.
+///
+/// See [`para_payments`] for an implementation aggregating the payments on-chain.
+pub trait OnParaPayment {
+	/// An on-demand order for `para` was placed and `amount` was paid for it.
+	fn on_demand_order_paid(para: ParaId, amount: Balance);
+	/// A deposit of `amount` for an HRMP channel was reserved from the sovereign account of
+	/// `para`.
+	fn hrmp_deposit_reserved(para: ParaId, amount: Balance);
+	/// A deposit of `amount` for an HRMP channel was returned to the sovereign account of `para`.
+	fn hrmp_deposit_released(para: ParaId, amount: Balance);
+}
+
+impl OnParaPayment for () {
+	fn on_demand_order_paid(_: ParaId, _: Balance) {}
+	fn hrmp_deposit_reserved(_: ParaId, _: Balance) {}
+	fn hrmp_deposit_released(_: ParaId, _: Balance) {}
+}
+
 /// Schedule a para to be initialized at the start of the next session with the given genesis data.
 pub fn schedule_para_initialize<T: paras::Config>(
 	id: ParaId,
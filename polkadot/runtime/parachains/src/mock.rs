@@ -20,7 +20,7 @@ use crate::{
 	assigner_coretime, assigner_on_demand, assigner_parachains, configuration, coretime, disputes,
 	dmp, hrmp,
 	inclusion::{self, AggregateMessageOrigin, UmpQueueId},
-	initializer, origin, paras,
+	initializer, origin, para_payments, paras,
 	paras::ParaKind,
 	paras_inherent, scheduler,
 	scheduler::common::AssignmentProvider,
@@ -85,6 +85,7 @@ frame_support::construct_runtime!(
 		Initializer: initializer,
 		Dmp: dmp,
 		Hrmp: hrmp,
+		ParaPayments: para_payments,
 		ParachainsOrigin: origin,
 		SessionInfo: session_info,
 		Disputes: disputes,
@@ -187,6 +188,7 @@ impl crate::initializer::Config for Test {
 }
 
 impl crate::configuration::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = crate::configuration::TestWeightInfo;
 }
 
@@ -238,13 +240,17 @@ impl crate::paras::Config for Test {
 	type QueueFootprinter = ParaInclusion;
 	type NextSessionRotation = TestNextSessionRotation;
 	type OnNewHead = ();
+	type OnUpgradeRestriction = ();
 	type AssignCoretime = ();
 }
 
-impl crate::dmp::Config for Test {}
+impl crate::dmp::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+}
 
 parameter_types! {
 	pub const DefaultChannelSizeAndCapacityWithSystem: (u32, u32) = (4, 1);
+	pub static OpenChannelRequestTtl: Option<SessionIndex> = None;
 }
 
 thread_local! {
@@ -279,6 +285,8 @@ impl crate::hrmp::Config for Test {
 	type Currency = pallet_balances::Pallet<Test>;
 	type DefaultChannelSizeAndCapacityWithSystem = DefaultChannelSizeAndCapacityWithSystem;
 	type VersionWrapper = TestUsesOnlyStoredVersionWrapper;
+	type OpenChannelRequestTtl = OpenChannelRequestTtl;
+	type OnParaPayment = ParaPayments;
 	type WeightInfo = crate::hrmp::TestWeightInfo;
 }
 
@@ -383,13 +391,17 @@ impl pallet_message_queue::Config for Test {
 	type Size = MessageQueueSize;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = TestMessageQueueWeight;
-	type MessageProcessor = TestProcessMessage;
+	type MessageProcessor = inclusion::RecordUmpOutcome<
+		Test,
+		para_payments::RecordUmpProcessing<Test, TestProcessMessage>,
+	>;
 	type QueueChangeHandler = ParaInclusion;
 	type QueuePausedQuery = ();
 	type HeapSize = ConstU32<65536>;
 	type MaxStale = ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = ();
+	type OnPageReaped = ();
 }
 
 impl assigner_parachains::Config for Test {}
@@ -409,9 +421,28 @@ impl assigner_on_demand::Config for Test {
 	type WeightInfo = crate::assigner_on_demand::TestWeightInfo;
 	type MaxHistoricalRevenue = MaxHistoricalRevenue;
 	type PalletId = OnDemandPalletId;
+	type OnParaPayment = ParaPayments;
+}
+
+parameter_types! {
+	pub const ParaPaymentsPeriodLength: BlockNumber = 10;
+	pub const ParaPaymentsHistoryDepth: u32 = 3;
 }
 
-impl assigner_coretime::Config for Test {}
+impl para_payments::Config for Test {
+	type PeriodLength = ParaPaymentsPeriodLength;
+	type HistoryDepth = ParaPaymentsHistoryDepth;
+}
+
+parameter_types! {
+	pub const CoretimeTimeslicePeriod: BlockNumber = 10;
+	pub const CoretimeAssignmentHistoryDepth: u32 = 2;
+}
+
+impl assigner_coretime::Config for Test {
+	type TimeslicePeriod = CoretimeTimeslicePeriod;
+	type AssignmentHistoryDepth = CoretimeAssignmentHistoryDepth;
+}
 
 parameter_types! {
 	pub const BrokerId: u32 = 10u32;
@@ -457,6 +488,7 @@ impl crate::inclusion::Config for Test {
 	type DisputesHandler = Disputes;
 	type RewardValidators = TestRewardValidators;
 	type MessageQueue = MessageQueue;
+	type UmpFeeAdjustment = TestUmpFeeAdjustment;
 }
 
 impl crate::paras_inherent::Config for Test {
@@ -545,6 +577,12 @@ pub mod mock_assigner {
 		// in the mock assigner.
 		fn push_back_assignment(_assignment: Assignment) {}
 
+		fn note_included(_core_idx: CoreIndex, _para_id: ParaId) {}
+
+		fn note_included_weight() -> Weight {
+			Weight::zero()
+		}
+
 		#[cfg(any(feature = "runtime-benchmarks", test))]
 		fn get_mock_assignment(_: CoreIndex, para_id: ParaId) -> Assignment {
 			Assignment::Bulk(para_id)
@@ -618,6 +656,7 @@ pub fn disabled_validators() -> Vec<u32> {
 
 parameter_types! {
 	pub static Processed: Vec<(ParaId, UpwardMessage)> = vec![];
+	pub static TestUmpFeeAdjustment: Option<inclusion::UmpFeeAdjustment> = None;
 }
 
 /// An implementation of a UMP sink that just records which messages were processed.
@@ -0,0 +1,291 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The para payments module.
+//!
+//! Keeps track of what each para pays the relay chain for, so that parachain treasuries can
+//! account for their relay chain expenses from on-chain data:
+//!
+//! - the spot price paid for on-demand orders for the para,
+//! - the HRMP channel deposits reserved from and returned to the sovereign account of the para,
+//! - the upward messages of the para which were processed and the weight they consumed.
+//!
+//! The payments are aggregated per period of [`Config::PeriodLength`] blocks and kept for the
+//! last [`Config::HistoryDepth`] periods. They can be queried with the [`ParaPaymentsApi`]
+//! runtime API.
+//!
+//! This module is not handled by the initializer but is instead instantiated in the
+//! `construct_runtime` macro. The payments are recorded by configuring the pallet as the
+//! [`OnParaPayment`] handler of the HRMP and on-demand assigner modules and by wrapping the UMP
+//! message processor in [`RecordUmpProcessing`].
+
+use crate::{
+	inclusion::{AggregateMessageOrigin, UmpQueueId},
+	OnParaPayment,
+};
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{ProcessMessage, ProcessMessageError},
+	weights::WeightMeter,
+};
+use frame_system::pallet_prelude::*;
+use polkadot_primitives::{Balance, Id as ParaId};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{One, Zero},
+	Saturating,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+/// What a para paid the relay chain for within some blocks.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ParaPayments {
+	/// The total spot price paid for on-demand orders for the para.
+	pub on_demand: Balance,
+	/// The number of on-demand orders placed for the para.
+	pub on_demand_orders: u32,
+	/// The HRMP channel deposits reserved from the sovereign account of the para.
+	pub hrmp_deposits_reserved: Balance,
+	/// The HRMP channel deposits returned to the sovereign account of the para.
+	pub hrmp_deposits_released: Balance,
+	/// The number of upward messages of the para which were processed.
+	pub ump_messages: u32,
+	/// The weight consumed by processing the upward messages of the para.
+	pub ump_weight: Weight,
+}
+
+impl ParaPayments {
+	/// Add the payments of `other` to `self`.
+	pub fn saturating_accrue(&mut self, other: &Self) {
+		self.on_demand.saturating_accrue(other.on_demand);
+		self.on_demand_orders.saturating_accrue(other.on_demand_orders);
+		self.hrmp_deposits_reserved.saturating_accrue(other.hrmp_deposits_reserved);
+		self.hrmp_deposits_released.saturating_accrue(other.hrmp_deposits_released);
+		self.ump_messages.saturating_accrue(other.ump_messages);
+		self.ump_weight.saturating_accrue(other.ump_weight);
+	}
+}
+
+/// The payments of a para over a range of blocks, as returned by the [`ParaPaymentsApi`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ParaPaymentStatement<BlockNumber> {
+	/// The first block covered by the statement.
+	pub from: BlockNumber,
+	/// The last block covered by the statement.
+	pub to: BlockNumber,
+	/// What the para paid for within the covered blocks.
+	pub payments: ParaPayments,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The number of blocks the payments of the paras are aggregated over.
+		#[pallet::constant]
+		type PeriodLength: Get<BlockNumberFor<Self>>;
+
+		/// The number of periods the payments of the paras are kept for. Should be at least 1.
+		#[pallet::constant]
+		type HistoryDepth: Get<u32>;
+	}
+
+	/// The payments of the paras per period, keyed by the first block of the period.
+	#[pallet::storage]
+	pub type Payments<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Twox64Concat,
+		ParaId,
+		ParaPayments,
+		OptionQuery,
+	>;
+
+	/// The first blocks of the periods for which payments are kept, oldest first.
+	#[pallet::storage]
+	pub type Periods<T: Config> =
+		StorageValue<_, BoundedVec<BlockNumberFor<T>, T::HistoryDepth>, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let period = Self::period_of(now);
+			let mut periods = Periods::<T>::get();
+			if periods.last() == Some(&period) {
+				// Reads: `Periods`
+				return T::DbWeight::get().reads(1)
+			}
+
+			// Start a new period and prune the payments of the oldest one if it falls out of
+			// the window.
+			let mut removed = 0;
+			if periods.is_full() && !periods.is_empty() {
+				let oldest = periods.remove(0);
+				removed = Payments::<T>::clear_prefix(oldest, u32::MAX, None).unique;
+			}
+			let _ = periods.try_push(period);
+			Periods::<T>::put(periods);
+
+			// Reads: `Periods`
+			// Writes: `Periods`, removed `Payments`
+			T::DbWeight::get().reads_writes(1, 1 + removed as u64)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The first block of the period `block` belongs to.
+	fn period_of(block: BlockNumberFor<T>) -> BlockNumberFor<T> {
+		let length = T::PeriodLength::get();
+		if length.is_zero() {
+			return block
+		}
+		block.saturating_sub(block % length)
+	}
+
+	/// Record a payment of `para` in the current period.
+	fn record(para: ParaId, f: impl FnOnce(&mut ParaPayments)) {
+		let period = Self::period_of(frame_system::Pallet::<T>::block_number());
+		Payments::<T>::mutate(period, para, |payments| {
+			f(payments.get_or_insert_with(Default::default))
+		});
+	}
+
+	/// The payments of `para` in all kept periods which overlap with the blocks `from..=to`.
+	///
+	/// The statement covers the overlapping periods in full, up to the current block. Returns
+	/// `None` if no kept period overlaps with the blocks.
+	pub fn payment_statement(
+		para: ParaId,
+		from: BlockNumberFor<T>,
+		to: BlockNumberFor<T>,
+	) -> Option<ParaPaymentStatement<BlockNumberFor<T>>> {
+		let now = frame_system::Pallet::<T>::block_number();
+		let length = T::PeriodLength::get().max(One::one());
+		let mut statement: Option<ParaPaymentStatement<BlockNumberFor<T>>> = None;
+		for start in Periods::<T>::get() {
+			let end = start.saturating_add(length).saturating_sub(One::one()).min(now);
+			if end < from || start > to {
+				continue
+			}
+			let statement = statement.get_or_insert_with(|| ParaPaymentStatement {
+				from: start,
+				to: end,
+				payments: Default::default(),
+			});
+			statement.to = end;
+			if let Some(payments) = Payments::<T>::get(start, para) {
+				statement.payments.saturating_accrue(&payments);
+			}
+		}
+		statement
+	}
+}
+
+impl<T: Config> OnParaPayment for Pallet<T> {
+	fn on_demand_order_paid(para: ParaId, amount: Balance) {
+		Self::record(para, |payments| {
+			payments.on_demand.saturating_accrue(amount);
+			payments.on_demand_orders.saturating_inc();
+		});
+	}
+
+	fn hrmp_deposit_reserved(para: ParaId, amount: Balance) {
+		if amount.is_zero() {
+			return
+		}
+		Self::record(para, |payments| payments.hrmp_deposits_reserved.saturating_accrue(amount));
+	}
+
+	fn hrmp_deposit_released(para: ParaId, amount: Balance) {
+		if amount.is_zero() {
+			return
+		}
+		Self::record(para, |payments| payments.hrmp_deposits_released.saturating_accrue(amount));
+	}
+}
+
+/// Wraps the UMP message processor `P` to record the upward messages of each para which were
+/// processed and the weight they consumed.
+///
+/// Overweight messages and messages which yield are not recorded since they are retried.
+pub struct RecordUmpProcessing<T, P>(PhantomData<(T, P)>);
+
+impl<T, P> ProcessMessage for RecordUmpProcessing<T, P>
+where
+	T: Config,
+	P: ProcessMessage<Origin = AggregateMessageOrigin>,
+{
+	type Origin = AggregateMessageOrigin;
+
+	fn process_message(
+		message: &[u8],
+		origin: Self::Origin,
+		meter: &mut WeightMeter,
+		id: &mut [u8; 32],
+	) -> Result<bool, ProcessMessageError> {
+		let para = match &origin {
+			AggregateMessageOrigin::Ump(UmpQueueId::Para(p)) => *p,
+		};
+
+		// Reserve the weight for recording the payment.
+		let record_weight = T::DbWeight::get().reads_writes(2, 1);
+		if !meter.can_consume(record_weight) {
+			return Err(ProcessMessageError::Yield)
+		}
+		let mut inner = WeightMeter::with_limit(meter.remaining().saturating_sub(record_weight));
+		let result = P::process_message(message, origin, &mut inner, id);
+		meter.consume(inner.consumed());
+
+		if let Err(ProcessMessageError::Overweight(_) | ProcessMessageError::Yield) = result {
+			return result
+		}
+		meter.consume(record_weight);
+		Pallet::<T>::record(para, |payments| {
+			payments.ump_messages.saturating_inc();
+			payments.ump_weight.saturating_accrue(inner.consumed());
+		});
+		result
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// API to query what the paras paid the relay chain for.
+	pub trait ParaPaymentsApi<BlockNumber> where BlockNumber: Codec {
+		/// The payments of `para` in all periods still kept on-chain which overlap with the
+		/// blocks `from..=to`.
+		///
+		/// Payments are aggregated per period, so the blocks covered by the statement are
+		/// rounded to whole periods. Returns `None` if no kept period overlaps with the blocks.
+		fn para_payment_statement(
+			para: ParaId,
+			from: BlockNumber,
+			to: BlockNumber,
+		) -> Option<ParaPaymentStatement<BlockNumber>>;
+	}
+}
@@ -0,0 +1,143 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::{
+	new_test_ext, Balances, OnDemandAssigner, ParaPayments as ParaPaymentsPallet, RuntimeOrigin,
+	System, Test, TestProcessMessage,
+};
+use frame_support::assert_ok;
+use polkadot_primitives::BlockNumber;
+
+fn run_to_block(to: BlockNumber) {
+	while System::block_number() < to {
+		let b = System::block_number() + 1;
+		System::set_block_number(b);
+		ParaPaymentsPallet::on_initialize(b);
+	}
+}
+
+fn process_upward_message(para: ParaId, weight: u32) {
+	let mut meter = WeightMeter::new();
+	assert_ok!(RecordUmpProcessing::<Test, TestProcessMessage>::process_message(
+		&weight.encode(),
+		AggregateMessageOrigin::Ump(UmpQueueId::Para(para)),
+		&mut meter,
+		&mut [0; 32],
+	));
+}
+
+#[test]
+fn payments_are_recorded_per_period() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let para_a = ParaId::from(2000);
+		let para_b = ParaId::from(2001);
+		run_to_block(1);
+
+		ParaPaymentsPallet::on_demand_order_paid(para_a, 100);
+		ParaPaymentsPallet::hrmp_deposit_reserved(para_a, 50);
+		process_upward_message(para_a, 7);
+		ParaPaymentsPallet::hrmp_deposit_reserved(para_b, 20);
+
+		run_to_block(12);
+		ParaPaymentsPallet::on_demand_order_paid(para_a, 30);
+		ParaPaymentsPallet::hrmp_deposit_released(para_a, 50);
+		process_upward_message(para_a, 3);
+
+		assert_eq!(Periods::<Test>::get().into_inner(), vec![0, 10]);
+		assert_eq!(
+			ParaPaymentsPallet::payment_statement(para_a, 0, 12),
+			Some(ParaPaymentStatement {
+				from: 0,
+				to: 12,
+				payments: ParaPayments {
+					on_demand: 130,
+					on_demand_orders: 2,
+					hrmp_deposits_reserved: 50,
+					hrmp_deposits_released: 50,
+					ump_messages: 2,
+					ump_weight: Weight::from_parts(10, 10),
+				},
+			})
+		);
+		// Only the second period overlaps.
+		assert_eq!(
+			ParaPaymentsPallet::payment_statement(para_a, 11, 100),
+			Some(ParaPaymentStatement {
+				from: 10,
+				to: 12,
+				payments: ParaPayments {
+					on_demand: 30,
+					on_demand_orders: 1,
+					hrmp_deposits_released: 50,
+					ump_messages: 1,
+					ump_weight: Weight::from_parts(3, 3),
+					..Default::default()
+				},
+			})
+		);
+		assert_eq!(
+			ParaPaymentsPallet::payment_statement(para_b, 0, 9).map(|s| s.payments),
+			Some(ParaPayments { hrmp_deposits_reserved: 20, ..Default::default() })
+		);
+	});
+}
+
+#[test]
+fn old_periods_are_pruned() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let para = ParaId::from(2000);
+		run_to_block(1);
+		ParaPaymentsPallet::on_demand_order_paid(para, 100);
+
+		run_to_block(29);
+		assert_eq!(Periods::<Test>::get().into_inner(), vec![0, 10, 20]);
+		assert!(Payments::<Test>::contains_key(0, para));
+
+		// The fourth period pushes the first one out of the window.
+		run_to_block(30);
+		assert_eq!(Periods::<Test>::get().into_inner(), vec![10, 20, 30]);
+		assert!(!Payments::<Test>::contains_key(0, para));
+		assert_eq!(ParaPaymentsPallet::payment_statement(para, 0, 9), None);
+		assert_eq!(
+			ParaPaymentsPallet::payment_statement(para, 0, 30).map(|s| (s.from, s.to, s.payments)),
+			Some((10, 30, ParaPayments::default()))
+		);
+	});
+}
+
+#[test]
+fn on_demand_orders_are_recorded() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let para = ParaId::from(111);
+		let alice = 100u64;
+		run_to_block(1);
+		Balances::make_free_balance_be(&alice, 20_000_000);
+
+		let before = Balances::free_balance(&alice);
+		assert_ok!(OnDemandAssigner::place_order_allow_death(
+			RuntimeOrigin::signed(alice),
+			10_000_000,
+			para
+		));
+		let paid = before - Balances::free_balance(&alice);
+
+		assert_eq!(
+			ParaPaymentsPallet::payment_statement(para, 1, 1).map(|s| s.payments),
+			Some(ParaPayments { on_demand: paid, on_demand_orders: 1, ..Default::default() })
+		);
+	});
+}
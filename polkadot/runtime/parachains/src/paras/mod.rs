@@ -520,6 +520,53 @@ impl OnNewHead for Tuple {
 	}
 }
 
+/// The reason why the code upgrades of a para are restricted.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum UpgradeRestrictionReason {
+	/// The para scheduled an upgrade and has to wait for the validation upgrade cooldown before
+	/// it may signal the next one.
+	Cooldown,
+}
+
+/// Notification of a para that its code upgrades became restricted.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct UpgradeRestrictionNotification<N> {
+	/// Why the upgrades are restricted.
+	pub reason: UpgradeRestrictionReason,
+	/// The relay chain block at which the restriction is expected to be lifted.
+	pub expected_end: N,
+}
+
+/// A versioned [`UpgradeRestrictionNotification`], as sent to a para in a downward message.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum VersionedUpgradeRestrictionNotification<N> {
+	/// The first version of the notification.
+	#[codec(index = 0)]
+	V0(UpgradeRestrictionNotification<N>),
+}
+
+/// Runtime hook for when the code upgrades of a parachain become restricted.
+pub trait OnUpgradeRestriction<N> {
+	/// Called when the code upgrades of a parachain become restricted.
+	/// Returns the weight consumed by this function.
+	fn on_upgrade_restriction(
+		id: ParaId,
+		notification: &UpgradeRestrictionNotification<N>,
+	) -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<N> OnUpgradeRestriction<N> for Tuple {
+	fn on_upgrade_restriction(
+		id: ParaId,
+		notification: &UpgradeRestrictionNotification<N>,
+	) -> Weight {
+		let mut weight: Weight = Default::default();
+		for_tuples!( #( weight.saturating_accrue(Tuple::on_upgrade_restriction(id, notification)); )* );
+		weight
+	}
+}
+
 /// Assign coretime to some parachain.
 ///
 /// This assigns coretime to a parachain without using the coretime chain. Thus, this should only be
@@ -632,6 +679,10 @@ pub mod pallet {
 		/// Runtime hook for when a parachain head is updated.
 		type OnNewHead: OnNewHead;
 
+		/// Runtime hook for when the code upgrades of a parachain become restricted, e.g. to
+		/// notify the parachain.
+		type OnUpgradeRestriction: OnUpgradeRestriction<BlockNumberFor<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -1990,6 +2041,13 @@ impl<T: Config> Pallet<T> {
 				.unwrap_or_else(|idx| idx);
 			upgrade_cooldowns.insert(insert_idx, (id, next_possible_upgrade_at));
 		});
+		weight += T::OnUpgradeRestriction::on_upgrade_restriction(
+			id,
+			&UpgradeRestrictionNotification {
+				reason: UpgradeRestrictionReason::Cooldown,
+				expected_end: next_possible_upgrade_at,
+			},
+		);
 
 		weight += Self::kick_off_pvf_check(
 			PvfCheckCause::Upgrade { id, included_at: inclusion_block_number, upgrade_strategy },
@@ -26,6 +26,7 @@ use polkadot_primitives::{
 };
 
 use super::{BackedCandidate, Config, DisputeStatementSet, Weight};
+use crate::scheduler::{self, common::AssignmentProvider};
 
 pub trait WeightInfo {
 	/// Variant over `v`, the count of dispute statements in a dispute statement set. This gives the
@@ -148,16 +149,17 @@ pub fn signed_bitfield_weight<T: Config>(bitfield: &UncheckedSignedAvailabilityB
 pub fn backed_candidate_weight<T: frame_system::Config + Config>(
 	candidate: &BackedCandidate<T::Hash>,
 ) -> Weight {
-	set_proof_size_to_tx_size(
-		if candidate.candidate().commitments.new_validation_code.is_some() {
-			<<T as Config>::WeightInfo as WeightInfo>::enter_backed_candidate_code_upgrade()
-		} else {
-			<<T as Config>::WeightInfo as WeightInfo>::enter_backed_candidates_variable(
-				candidate.validity_votes().len() as u32,
-			)
-		},
-		candidate,
-	)
+	let weight = if candidate.candidate().commitments.new_validation_code.is_some() {
+		<<T as Config>::WeightInfo as WeightInfo>::enter_backed_candidate_code_upgrade()
+	} else {
+		<<T as Config>::WeightInfo as WeightInfo>::enter_backed_candidates_variable(
+			candidate.validity_votes().len() as u32,
+		)
+	};
+	// The inclusion of the candidate is noted to the assignment provider.
+	let weight =
+		weight.saturating_add(<T as scheduler::Config>::AssignmentProvider::note_included_weight());
+	set_proof_size_to_tx_size(weight, candidate)
 }
 
 pub fn backed_candidates_weight<T: frame_system::Config + Config>(
@@ -16,8 +16,11 @@
 
 //! Put implementations of functions from staging APIs here.
 
-use crate::{configuration, inclusion, initializer, scheduler};
-use polkadot_primitives::{CommittedCandidateReceipt, CoreIndex, Id as ParaId};
+use crate::{configuration, dmp, inclusion, initializer, scheduler};
+use frame_system::pallet_prelude::BlockNumberFor;
+use polkadot_primitives::{
+	CommittedCandidateReceipt, CoreIndex, Id as ParaId, InboundDownwardMessage,
+};
 use sp_runtime::traits::One;
 use sp_std::{
 	collections::{btree_map::BTreeMap, vec_deque::VecDeque},
@@ -58,3 +61,13 @@ pub fn candidates_pending_availability<T: initializer::Config>(
 ) -> Vec<CommittedCandidateReceipt<T::Hash>> {
 	<inclusion::Pallet<T>>::candidates_pending_availability(para_id)
 }
+
+/// Returns at most `count` messages of the downward message queue of the given para, starting
+/// with the message at index `start`.
+pub fn dmq_contents_page<T: dmp::Config>(
+	recipient: ParaId,
+	start: u32,
+	count: u32,
+) -> Vec<InboundDownwardMessage<BlockNumberFor<T>>> {
+	dmp::Pallet::<T>::dmq_contents_page(recipient, start, count)
+}
@@ -16,6 +16,7 @@
 
 //! Common traits and types used by the scheduler and assignment providers.
 
+use frame_support::weights::Weight;
 use scale_info::TypeInfo;
 use sp_runtime::{
 	codec::{Decode, Encode},
@@ -70,6 +71,16 @@ pub trait AssignmentProvider<BlockNumber> {
 	/// This is the second way the life of an assignment can come to an end.
 	fn push_back_assignment(assignment: Assignment);
 
+	/// A candidate of `para_id` has been included on the core `core_idx`.
+	///
+	/// This is when an assignment has actually been served.
+	fn note_included(core_idx: CoreIndex, para_id: ParaId);
+
+	/// The weight of [`Self::note_included`].
+	///
+	/// It is accounted for in the weight of each backed candidate.
+	fn note_included_weight() -> Weight;
+
 	/// Push some assignment for mocking/benchmarks purposes.
 	///
 	/// Useful for benchmarks and testing. The returned assignment is "valid" and can if need be
@@ -529,9 +529,54 @@ fn wococo_local_testnet_genesis() -> serde_json::Value {
 	)
 }
 
+/// The presets provided by every build of the runtime.
+const PRESETS: [&str; 5] = [
+	"local_testnet",
+	"development",
+	"staging_testnet",
+	"wococo_local_testnet",
+	"versi_local_testnet",
+];
+
+/// The presets provided only by the runtime built with the `fast-runtime` feature, together with
+/// the preset each of them is based on.
+///
+/// A chain started from one of these presets uses the fast-runtime constants, e.g. 1-minute epochs
+/// and 1-day leases, so it has to be paired with `rococo_runtime::fast_runtime_binary`. Since the
+/// constants are fixed when the runtime is compiled, the production binary does not offer them.
+pub const FAST_RUNTIME_PRESETS: [(&str, &str); 4] = [
+	("local_testnet_fast_runtime", "local_testnet"),
+	("development_fast_runtime", "development"),
+	("wococo_local_testnet_fast_runtime", "wococo_local_testnet"),
+	("versi_local_testnet_fast_runtime", "versi_local_testnet"),
+];
+
+/// Maps a fast-runtime preset to the preset it is based on, if this is the runtime built with the
+/// `fast-runtime` feature.
+fn base_preset(id: &str) -> &str {
+	if !cfg!(feature = "fast-runtime") {
+		return id
+	}
+	FAST_RUNTIME_PRESETS
+		.iter()
+		.find(|(fast, _)| *fast == id)
+		.map_or(id, |(_, base)| *base)
+}
+
+/// The names of the presets provided by this build of the runtime.
+pub fn preset_names() -> Vec<sp_genesis_builder::PresetId> {
+	let fast_presets: &[(&str, &str)] =
+		if cfg!(feature = "fast-runtime") { &FAST_RUNTIME_PRESETS } else { &[] };
+	PRESETS
+		.into_iter()
+		.chain(fast_presets.iter().map(|(fast, _)| *fast))
+		.map(sp_genesis_builder::PresetId::from)
+		.collect()
+}
+
 /// Provides the JSON representation of predefined genesis config for given `id`.
 pub fn get_preset(id: &sp_genesis_builder::PresetId) -> Option<sp_std::vec::Vec<u8>> {
-	let patch = match id.try_into() {
+	let patch = match id.try_into().map(base_preset) {
 		Ok("local_testnet") => rococo_local_testnet_genesis(),
 		Ok("development") => rococo_development_config_genesis(),
 		Ok("staging_testnet") => rococo_staging_testnet_config_genesis(),
@@ -306,8 +306,11 @@ impl pallet_referenda::Config<FellowshipReferendaInstance> for Runtime {
 	type MaxQueued = ConstU32<100>;
 	type UndecidingTimeout = UndecidingTimeout;
 	type AlarmInterval = AlarmInterval;
+	type MaxEnactmentHorizon = MaxSchedulingHorizon;
 	type Tracks = TracksInfo;
 	type Preimages = Preimage;
+	type ProposalValue = ();
+	type ProposalConsideration = ();
 }
 
 pub type FellowshipCollectiveInstance = pallet_ranked_collective::Instance1;
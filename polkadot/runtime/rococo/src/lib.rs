@@ -37,8 +37,8 @@ use polkadot_primitives::{
 use polkadot_runtime_common::{
 	assigned_slots, auctions, claims, crowdloan, identity_migrator, impl_runtime_weights,
 	impls::{
-		ContainsParts, LocatableAssetConverter, ToAuthor, VersionedLocatableAsset,
-		VersionedLocationConverter,
+		AnyVersionConversion, ContainsParts, LocatableAssetConverter, RelayChainAccountConverter,
+		ToAuthor, VersionedLocatableAsset, VersionedLocationConverter,
 	},
 	paras_registrar, paras_sudo_wrapper, prod_or_fast, slots,
 	traits::{Leaser, OnSwap},
@@ -52,7 +52,8 @@ use polkadot_runtime_parachains::{
 	disputes::slashing as parachains_slashing,
 	dmp as parachains_dmp, hrmp as parachains_hrmp, inclusion as parachains_inclusion,
 	inclusion::{AggregateMessageOrigin, UmpQueueId},
-	initializer as parachains_initializer, origin as parachains_origin, paras as parachains_paras,
+	initializer as parachains_initializer, origin as parachains_origin,
+	para_payments as parachains_para_payments, paras as parachains_paras,
 	paras_inherent as parachains_paras_inherent,
 	runtime_api_impl::{
 		v10 as parachains_runtime_api_impl, vstaging as vstaging_parachains_runtime_api_impl,
@@ -60,7 +61,9 @@ use polkadot_runtime_parachains::{
 	scheduler as parachains_scheduler, session_info as parachains_session_info,
 	shared as parachains_shared,
 };
-use rococo_runtime_constants::system_parachain::{coretime::TIMESLICE_PERIOD, BROKER_ID};
+use rococo_runtime_constants::system_parachain::{
+	coretime::TIMESLICE_PERIOD, ASSET_HUB_ID, BROKER_ID,
+};
 use scale_info::TypeInfo;
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_consensus_beefy::{
@@ -79,10 +82,11 @@ use frame_support::{
 	genesis_builder_helper::{build_state, get_preset},
 	parameter_types,
 	traits::{
-		fungible::HoldConsideration, tokens::UnityOrOuterConversion, Contains, EitherOf,
-		EitherOfDiverse, EnsureOrigin, EnsureOriginWithArg, EverythingBut, InstanceFilter,
-		KeyOwnerProofSystem, LinearStoragePrice, PrivilegeCmp, ProcessMessage, ProcessMessageError,
-		StorageMapShim, WithdrawReasons,
+		fungible::HoldConsideration,
+		tokens::{imbalance::ResolveTo, UnityOrOuterConversion},
+		Contains, EitherOf, EitherOfDiverse, EnsureOrigin, EnsureOriginWithArg, EverythingBut,
+		InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice, PrivilegeCmp, ProcessMessage,
+		ProcessMessageError, QueryPreimage, StorageMapShim, WithdrawReasons,
 	},
 	weights::{ConstantMultiplier, WeightMeter, WeightToFee as _},
 	PalletId,
@@ -92,11 +96,11 @@ use pallet_grandpa::{fg_primitives, AuthorityId as GrandpaId};
 use pallet_identity::legacy::IdentityInfo;
 use pallet_session::historical as session_historical;
 use pallet_transaction_payment::{FeeDetails, FungibleAdapter, RuntimeDispatchInfo};
-use sp_core::{ConstU128, ConstU8, Get, OpaqueMetadata, H256};
+use sp_core::{ConstBool, ConstU128, ConstU8, Get, OpaqueMetadata, H256};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdConversion, BlakeTwo256, Block as BlockT, ConstU32, ConvertInto,
+		AccountIdConversion, BlakeTwo256, Block as BlockT, ConstU32, Convert, ConvertInto,
 		Extrinsic as ExtrinsicT, IdentityLookup, Keccak256, OpaqueKeys, SaturatedConversion,
 		Verify,
 	},
@@ -157,6 +161,19 @@ pub mod fast_runtime_binary {
 	include!(concat!(env!("OUT_DIR"), "/fast_runtime_binary.rs"));
 }
 
+/// The wasm binary providing the genesis preset `id`.
+///
+/// The presets suffixed with `_fast_runtime` are provided by the [`fast_runtime_binary`] only, as
+/// they rely on its constants. All other presets are provided by the production [`WASM_BINARY`].
+#[cfg(feature = "std")]
+pub fn wasm_binary_for_preset(id: &str) -> Option<&'static [u8]> {
+	if genesis_config_presets::FAST_RUNTIME_PRESETS.iter().any(|(fast, _)| *fast == id) {
+		fast_runtime_binary::WASM_BINARY
+	} else {
+		WASM_BINARY
+	}
+}
+
 /// Runtime version (Rococo).
 #[sp_version::runtime_version]
 pub const VERSION: RuntimeVersion = RuntimeVersion {
@@ -220,6 +237,10 @@ impl frame_system::Config for Runtime {
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
 		BlockWeights::get().max_block;
+	pub const MaximumSchedulerBlockTime: u64 = 2 * MILLISECS_PER_BLOCK;
+	pub const SchedulerDeposit: Balance = deposit(1, 128);
+	pub const MaxScheduledPerOrigin: Option<u32> = None;
+	pub const MaxSchedulingHorizon: Option<BlockNumber> = Some(365 * DAYS);
 	pub const MaxScheduledPerBlock: u32 = 50;
 	pub const NoPreimagePostponement: Option<u32> = Some(10);
 }
@@ -318,9 +339,22 @@ impl pallet_scheduler::Config for Runtime {
 	// OpenGov to schedule periodic auctions.
 	type ScheduleOrigin = EitherOf<EnsureRoot<AccountId>, AuctionAdmin>;
 	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type BlockNumberProvider = System;
 	type WeightInfo = weights::pallet_scheduler::WeightInfo<Runtime>;
 	type OriginPrivilegeCmp = OriginPrivilegeCmp;
 	type Preimages = Preimage;
+	type RejectOverweight = ConstBool<false>;
+	type TimeProvider = Timestamp;
+	type MaximumBlockTime = MaximumSchedulerBlockTime;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type ScheduleDeposit = SchedulerDeposit;
+	type MaxScheduledPerOrigin = MaxScheduledPerOrigin;
+	type MaxSchedulingHorizon = MaxSchedulingHorizon;
+	type MaxMergedAgendas = ConstU32<1>;
+	type MaxIdleWeight = ();
+	type MaxOverflowed = ConstU32<100>;
+	type OnChargeDispatch = ();
 }
 
 parameter_types! {
@@ -336,11 +370,13 @@ impl pallet_preimage::Config for Runtime {
 		AccountId,
 		Balances,
 		PreimageHoldReason,
-		LinearStoragePrice<
-			dynamic_params::preimage::BaseDeposit,
-			dynamic_params::preimage::ByteDeposit,
-			Balance,
-		>,
+		sp_runtime::traits::Identity,
+		Balance,
+	>;
+	type DepositPrice = LinearStoragePrice<
+		dynamic_params::preimage::BaseDeposit,
+		dynamic_params::preimage::ByteDeposit,
+		Balance,
 	>;
 }
 
@@ -372,6 +408,7 @@ impl pallet_indices::Config for Runtime {
 	type Currency = Balances;
 	type Deposit = IndexDeposit;
 	type RuntimeEvent = RuntimeEvent;
+	type IdentityProvider = ();
 	type WeightInfo = weights::pallet_indices::WeightInfo<Runtime>;
 }
 
@@ -425,7 +462,7 @@ impl pallet_timestamp::Config for Runtime {
 
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Babe>;
-	type EventHandler = ();
+	type EventHandler = ValidatorManager;
 }
 
 impl_opaque_keys! {
@@ -456,6 +493,7 @@ impl pallet_session::Config for Runtime {
 	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, ValidatorManager>;
 	type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
+	type RequireProofOfPossession = ConstBool<true>;
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 }
 
@@ -496,6 +534,94 @@ parameter_types! {
 	pub const MaxBalance: Balance = Balance::max_value();
 }
 
+/// Pays out the treasury spends over XCM.
+pub type TreasuryPaymaster = PayOverXcm<
+	TreasuryInteriorLocation,
+	crate::xcm_config::XcmRouter,
+	crate::XcmPallet,
+	ConstU32<{ 6 * HOURS }>,
+	VersionedLocation,
+	VersionedLocatableAsset,
+	LocatableAssetConverter,
+	VersionedLocationConverter,
+>;
+
+parameter_types! {
+	pub const SpendConsiderationHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::Treasury(pallet_treasury::HoldReason::SpendConsideration);
+	pub const SpendBond: Permill = Permill::from_percent(5);
+	pub const SpendBondMinimum: Balance = 2000 * CENTS;
+	pub const SpendBondMaximum: Balance = 1 * GRAND;
+}
+
+/// The bond held from the submitter of a referendum spending from the treasury locally, until the
+/// spend is paid.
+pub type SpendBondConsideration =
+	HoldConsideration<AccountId, Balances, SpendConsiderationHoldReason, SpendBondOf, Balance>;
+
+/// The bond taken when the referendum whose proposal is being enacted was submitted, if any.
+pub struct ReferendumSubmitter;
+impl ReferendumSubmitter {
+	/// The task name the bond is handed over with in benchmarks, where no task is dispatching.
+	#[cfg(feature = "runtime-benchmarks")]
+	const BENCHMARK_TASK: [u8; 32] = [0u8; 32];
+}
+impl pallet_treasury::SpendSubmitter<AccountId, SpendBondConsideration> for ReferendumSubmitter {
+	fn take_consideration() -> Option<(AccountId, SpendBondConsideration)> {
+		let task = Scheduler::dispatching_task();
+		#[cfg(feature = "runtime-benchmarks")]
+		let task = task.or(Some(Self::BENCHMARK_TASK));
+		task.and_then(Referenda::take_enactment_consideration)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_consideration(who: AccountId, ticket: SpendBondConsideration) {
+		pallet_referenda::EnactmentConsiderations::<Runtime>::insert(
+			Self::BENCHMARK_TASK,
+			(who, ticket),
+		);
+	}
+}
+
+/// The value of a referendum's proposal, the amount it spends from the treasury locally.
+///
+/// A proposal whose preimage is not available yet is valued at the maximum, so its submitter is
+/// bonded [`SpendBondMaximum`].
+pub struct LocalSpendValue;
+impl pallet_referenda::ProposalValue<pallet_referenda::BoundedCallOf<Runtime, ()>, Balance>
+	for LocalSpendValue
+{
+	fn value(proposal: &pallet_referenda::BoundedCallOf<Runtime, ()>) -> Balance {
+		match <Preimage as QueryPreimage>::peek(proposal) {
+			Ok((RuntimeCall::Treasury(pallet_treasury::Call::spend_local { amount, .. }), _)) =>
+				amount,
+			Ok(_) => 0,
+			Err(_) => Balance::MAX,
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn valued_proposal() -> Option<pallet_referenda::BoundedCallOf<Runtime, ()>> {
+		use frame_support::traits::StorePreimage;
+		let call = RuntimeCall::Treasury(pallet_treasury::Call::spend_local {
+			amount: 1 * GRAND,
+			beneficiary: Address::Id(AccountId::from([0u8; 32])),
+		});
+		<Preimage as StorePreimage>::bound(call).ok()
+	}
+}
+
+/// The bond held for a local treasury spend, a share of its amount within some bounds.
+pub struct SpendBondOf;
+impl Convert<Balance, Balance> for SpendBondOf {
+	fn convert(amount: Balance) -> Balance {
+		if amount == 0 {
+			return 0
+		}
+		(SpendBond::get() * amount).clamp(SpendBondMinimum::get(), SpendBondMaximum::get())
+	}
+}
+
 impl pallet_treasury::Config for Runtime {
 	type PalletId = TreasuryPalletId;
 	type Currency = Balances;
@@ -511,16 +637,7 @@ impl pallet_treasury::Config for Runtime {
 	type AssetKind = VersionedLocatableAsset;
 	type Beneficiary = VersionedLocation;
 	type BeneficiaryLookup = IdentityLookup<Self::Beneficiary>;
-	type Paymaster = PayOverXcm<
-		TreasuryInteriorLocation,
-		crate::xcm_config::XcmRouter,
-		crate::XcmPallet,
-		ConstU32<{ 6 * HOURS }>,
-		Self::Beneficiary,
-		Self::AssetKind,
-		LocatableAssetConverter,
-		VersionedLocationConverter,
-	>;
+	type Paymaster = TreasuryPaymaster;
 	type BalanceConverter = UnityOrOuterConversion<
 		ContainsParts<
 			FromContains<
@@ -528,9 +645,12 @@ impl pallet_treasury::Config for Runtime {
 				xcm_builder::IsParentsOnly<ConstU8<1>>,
 			>,
 		>,
-		AssetRate,
+		AnyVersionConversion<AssetRate>,
 	>;
 	type PayoutPeriod = PayoutSpendPeriod;
+	type Clawback = ();
+	type SpendSubmitter = ReferendumSubmitter;
+	type SpendConsideration = SpendBondConsideration;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments;
 }
@@ -539,45 +659,82 @@ parameter_types! {
 	pub const BountyDepositBase: Balance = 100 * CENTS;
 	pub const BountyDepositPayoutDelay: BlockNumber = 4 * DAYS;
 	pub const BountyUpdatePeriod: BlockNumber = 90 * DAYS;
+	pub const BountyFundedExpiry: BlockNumber = 30 * DAYS;
 	pub const MaximumReasonLength: u32 = 16384;
 	pub const CuratorDepositMultiplier: Permill = Permill::from_percent(50);
 	pub const CuratorDepositMin: Balance = 10 * CENTS;
 	pub const CuratorDepositMax: Balance = 500 * CENTS;
 	pub const BountyValueMinimum: Balance = 200 * CENTS;
+	pub const BountyWithdrawalFee: Permill = Permill::from_percent(5);
+	pub const CuratorSlashReporterShare: Permill = Permill::from_percent(10);
+	pub const CuratorSlashAppealPeriod: BlockNumber = 7 * DAYS;
+	// The native token on the Asset Hub, in which bounties are paid out to its accounts.
+	pub BountyNativeAssetKind: Option<VersionedLocatableAsset> = Some(VersionedLocatableAsset::V4 {
+		location: Location::new(0, [Parachain(ASSET_HUB_ID)]),
+		asset_id: Location::parent().into(),
+	});
 }
 
 impl pallet_bounties::Config for Runtime {
 	type BountyDepositBase = BountyDepositBase;
 	type BountyDepositPayoutDelay = BountyDepositPayoutDelay;
 	type BountyUpdatePeriod = BountyUpdatePeriod;
+	type FundedExpiry = BountyFundedExpiry;
 	type CuratorDepositMultiplier = CuratorDepositMultiplier;
 	type CuratorDepositMin = CuratorDepositMin;
 	type CuratorDepositMax = CuratorDepositMax;
 	type BountyValueMinimum = BountyValueMinimum;
 	type ChildBountyManager = ChildBounties;
+	type PayoutGuard = ();
 	type DataDepositPerByte = DataDepositPerByte;
+	type BountyWithdrawalFee = BountyWithdrawalFee;
+	type CuratorSlashReporterShare = CuratorSlashReporterShare;
+	type CuratorSlashAppealPeriod = CuratorSlashAppealPeriod;
 	type RuntimeEvent = RuntimeEvent;
 	type MaximumReasonLength = MaximumReasonLength;
 	type WeightInfo = weights::pallet_bounties::WeightInfo<Runtime>;
 	type OnSlash = Treasury;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type NativeBalance = Balances;
+	type OnDepositSlash = ResolveTo<xcm_config::TreasuryAccount, Balances>;
+	type BlockNumberProvider = System;
+	type BeneficiaryAccount = RelayChainAccountConverter;
+	type NativeAssetKind = BountyNativeAssetKind;
 }
 
 parameter_types! {
 	pub const MaxActiveChildBountyCount: u32 = 100;
 	pub ChildBountyValueMinimum: Balance = BountyValueMinimum::get() / 10;
+	pub const MaxChildBountyDepth: u32 = 3;
 }
 
 impl pallet_child_bounties::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = MaxActiveChildBountyCount;
 	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type MaxDepth = MaxChildBountyDepth;
 	type WeightInfo = weights::pallet_child_bounties::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const DisputesOffenceKind: sp_staking::offence::Kind =
+		<parachains_slashing::SlashingOffence<
+			pallet_session::historical::IdentificationTuple<Runtime>,
+		> as sp_staking::offence::Offence<
+			pallet_session::historical::IdentificationTuple<Runtime>,
+		>>::ID;
+}
+
 impl pallet_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
-	type OnOffenceHandler = ();
+	// There is no staking on Rococo, so validators losing disputes are only disabled for the
+	// rest of the session. All other offences, e.g. BEEFY equivocations, are ignored.
+	type OnOffenceHandler = pallet_offences::OffenceKindRouter<
+		DisputesOffenceKind,
+		session_historical::DisableOffenders<Runtime>,
+		(),
+	>;
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -671,6 +828,7 @@ impl claims::Config for Runtime {
 	type VestingSchedule = Vesting;
 	type Prefix = Prefix;
 	type MoveClaimOrigin = EnsureRoot<AccountId>;
+	type UnclaimedBeneficiary = xcm_config::TreasuryAccount;
 	type WeightInfo = weights::runtime_common_claims::WeightInfo<Runtime>;
 }
 
@@ -693,6 +851,7 @@ impl pallet_identity::Config for Runtime {
 	type MaxSubAccounts = MaxSubAccounts;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
+	type MaxJudgementBatch = ConstU32<32>;
 	type Slashed = Treasury;
 	type ForceOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type RegistrarOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
@@ -724,9 +883,22 @@ impl pallet_multisig::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type OldCurrency = Balances;
+	type CallFee = pallet_multisig::TransactionPaymentFee<Runtime>;
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = MaxSignatories;
+	type MaxMetadataLen = ConstU32<64>;
+	type Preimages = Preimage;
+	type BlockNumberProvider = System;
+	type Scheduler = Scheduler;
+	type MaxDeadline = ConstU32<{ 30 * DAYS }>;
+	type PalletsOrigin = OriginCaller;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -765,6 +937,8 @@ impl pallet_society::Config for Runtime {
 	type ChallengePeriod = ConstU32<{ 7 * DAYS }>;
 	type MaxPayouts = ConstU32<8>;
 	type MaxBids = ConstU32<512>;
+	type PayoutSmoothingThreshold = ();
+	type PayoutSmoothingPeriods = ConstU32<1>;
 	type PalletId = SocietyPalletId;
 	type WeightInfo = ();
 }
@@ -937,6 +1111,7 @@ impl pallet_proxy::Config for Runtime {
 impl parachains_origin::Config for Runtime {}
 
 impl parachains_configuration::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::runtime_parachains_configuration::WeightInfo<Runtime>;
 }
 
@@ -948,11 +1123,35 @@ impl parachains_session_info::Config for Runtime {
 	type ValidatorSet = Historical;
 }
 
-/// Special `RewardValidators` that does nothing ;)
+/// Special `RewardValidators` that does not reward anyone, but feeds the signed availability
+/// bitfields into the liveness scores of the `ValidatorManager`.
 pub struct RewardValidators;
 impl polkadot_runtime_parachains::inclusion::RewardValidators for RewardValidators {
 	fn reward_backing(_: impl IntoIterator<Item = ValidatorIndex>) {}
-	fn reward_bitfields(_: impl IntoIterator<Item = ValidatorIndex>) {}
+	fn reward_bitfields(indices: impl IntoIterator<Item = ValidatorIndex>) {
+		let session_index = parachains_shared::CurrentSessionIndex::<Runtime>::get();
+		let Some(validators) = parachains_session_info::AccountKeys::<Runtime>::get(session_index)
+		else {
+			return
+		};
+		ValidatorManager::note_bitfields(
+			indices.into_iter().filter_map(|i| validators.get(i.0 as usize).cloned()),
+		);
+	}
+}
+
+parameter_types! {
+	/// Paras whose upward messages all succeed get 1% off the price of their execution each
+	/// session, down to half the price. Paras with 10 or more failed upward messages in a session
+	/// pay 10% more, up to twice the price.
+	pub const UmpFeeAdjustment: Option<parachains_inclusion::UmpFeeAdjustment> =
+		Some(parachains_inclusion::UmpFeeAdjustment {
+			rebate: FixedU128::from_rational(1, 100),
+			surcharge: FixedU128::from_rational(1, 10),
+			failure_threshold: 10,
+			min_factor: FixedU128::from_rational(1, 2),
+			max_factor: FixedU128::from_u32(2),
+		});
 }
 
 impl parachains_inclusion::Config for Runtime {
@@ -961,6 +1160,7 @@ impl parachains_inclusion::Config for Runtime {
 	type RewardValidators = RewardValidators;
 	type MessageQueue = MessageQueue;
 	type WeightInfo = weights::runtime_parachains_inclusion::WeightInfo<Runtime>;
+	type UmpFeeAdjustment = UmpFeeAdjustment;
 }
 
 parameter_types! {
@@ -974,6 +1174,7 @@ impl parachains_paras::Config for Runtime {
 	type QueueFootprinter = ParaInclusion;
 	type NextSessionRotation = Babe;
 	type OnNewHead = Registrar;
+	type OnUpgradeRestriction = Dmp;
 	type AssignCoretime = CoretimeAssignmentProvider;
 }
 
@@ -986,6 +1187,8 @@ parameter_types! {
 	pub MessageQueueServiceWeight: Weight = Perbill::from_percent(20) * BlockWeights::get().max_block;
 	pub const MessageQueueHeapSize: u32 = 32 * 1024;
 	pub const MessageQueueMaxStale: u32 = 96;
+	/// Reward paid from the treasury for reaping a page of the message queue.
+	pub const MessageQueueReapReward: Balance = CENTS;
 }
 
 /// Message processor to handle any messages that were enqueued into the `MessageQueue` pallet.
@@ -1017,8 +1220,17 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = MessageQueueMaxStale;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type OnPageReaped = pallet_message_queue::RewardReaperFromPot<
+		Balances,
+		xcm_config::TreasuryAccount,
+		MessageQueueReapReward,
+		RocksDbWeight,
+	>;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type MessageProcessor = MessageProcessor;
+	type MessageProcessor = parachains_inclusion::RecordUmpOutcome<
+		Runtime,
+		parachains_para_payments::RecordUmpProcessing<Runtime, MessageProcessor>,
+	>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type MessageProcessor =
 		pallet_message_queue::mock_helpers::NoopMessageProcessor<AggregateMessageOrigin>;
@@ -1027,10 +1239,14 @@ impl pallet_message_queue::Config for Runtime {
 	type WeightInfo = weights::pallet_message_queue::WeightInfo<Runtime>;
 }
 
-impl parachains_dmp::Config for Runtime {}
+impl parachains_dmp::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+}
 
 parameter_types! {
 	pub const HrmpChannelSizeAndCapacityWithSystemRatio: Percent = Percent::from_percent(100);
+	/// Unconfirmed HRMP open channel requests expire after roughly a week worth of sessions.
+	pub const HrmpOpenChannelRequestTtl: Option<SessionIndex> = Some(7 * 24);
 }
 
 impl parachains_hrmp::Config for Runtime {
@@ -1043,6 +1259,8 @@ impl parachains_hrmp::Config for Runtime {
 		HrmpChannelSizeAndCapacityWithSystemRatio,
 	>;
 	type VersionWrapper = crate::XcmPallet;
+	type OpenChannelRequestTtl = HrmpOpenChannelRequestTtl;
+	type OnParaPayment = ParaPayments;
 	type WeightInfo = weights::runtime_parachains_hrmp::WeightInfo<Runtime>;
 }
 
@@ -1100,9 +1318,30 @@ impl parachains_assigner_on_demand::Config for Runtime {
 	type WeightInfo = weights::runtime_parachains_assigner_on_demand::WeightInfo<Runtime>;
 	type MaxHistoricalRevenue = MaxHistoricalRevenue;
 	type PalletId = OnDemandPalletId;
+	type OnParaPayment = ParaPayments;
+}
+
+parameter_types! {
+	pub const CoretimeTimeslicePeriod: BlockNumber = TIMESLICE_PERIOD;
+	// Keep four weeks of served coretime assignments.
+	pub const CoretimeAssignmentHistoryDepth: u32 = 28 * DAYS / TIMESLICE_PERIOD;
+}
+
+impl parachains_assigner_coretime::Config for Runtime {
+	type TimeslicePeriod = CoretimeTimeslicePeriod;
+	type AssignmentHistoryDepth = CoretimeAssignmentHistoryDepth;
 }
 
-impl parachains_assigner_coretime::Config for Runtime {}
+parameter_types! {
+	/// The payments of the paras are aggregated per day and kept for four weeks.
+	pub const ParaPaymentsPeriodLength: BlockNumber = DAYS;
+	pub const ParaPaymentsHistoryDepth: u32 = 28;
+}
+
+impl parachains_para_payments::Config for Runtime {
+	type PeriodLength = ParaPaymentsPeriodLength;
+	type HistoryDepth = ParaPaymentsHistoryDepth;
+}
 
 impl parachains_initializer::Config for Runtime {
 	type Randomness = pallet_babe::RandomnessFromOneEpochAgo<Runtime>;
@@ -1354,9 +1593,16 @@ impl assigned_slots::Config for Runtime {
 	type WeightInfo = weights::runtime_common_assigned_slots::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const LivenessThreshold: u32 = 1;
+	pub const MaxOfflineSessions: Option<u32> = Some(6);
+}
+
 impl validator_manager::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PrivilegedOrigin = EnsureRoot<AccountId>;
+	type LivenessThreshold = LivenessThreshold;
+	type MaxOfflineSessions = MaxOfflineSessions;
 }
 
 impl pallet_sudo::Config for Runtime {
@@ -1377,6 +1623,7 @@ impl pallet_asset_rate::Config for Runtime {
 	type UpdateOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type AssetKind = <Runtime as pallet_treasury::Config>::AssetKind;
+	type NativeDecimals = ConstU8<12>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::AssetRateArguments;
 }
@@ -1483,6 +1730,7 @@ construct_runtime! {
 		MessageQueue: pallet_message_queue = 64,
 		OnDemandAssignmentProvider: parachains_assigner_on_demand = 66,
 		CoretimeAssignmentProvider: parachains_assigner_coretime = 68,
+		ParaPayments: parachains_para_payments = 69,
 
 		// Parachain Onboarding Pallets. Start indices at 70 to leave room.
 		Registrar: paras_registrar = 70,
@@ -1587,6 +1835,7 @@ pub mod migrations {
 		pub const PhragmenElectionPalletName: &'static str = "PhragmenElection";
 		pub const TechnicalMembershipPalletName: &'static str = "TechnicalMembership";
 		pub const TipsPalletName: &'static str = "Tips";
+		pub const ChildBountiesPalletName: &'static str = "ChildBounties";
 		pub const PhragmenElectionPalletId: LockIdentifier = *b"phrelect";
 	}
 
@@ -1637,6 +1886,8 @@ pub mod migrations {
 		paras_registrar::migration::MigrateToV1<Runtime, ()>,
 		pallet_referenda::migration::v1::MigrateV0ToV1<Runtime, ()>,
 		pallet_referenda::migration::v1::MigrateV0ToV1<Runtime, pallet_referenda::Instance2>,
+		pallet_scheduler::migration::v5::MigrateToV5<Runtime>,
+		pallet_multisig::migrations::v2::MigrateToV2<Runtime>,
 
 		// Unlock & unreserve Gov1 funds
 
@@ -1668,6 +1919,19 @@ pub mod migrations {
 		pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 
 		parachains_inclusion::migration::MigrateToV1<Runtime>,
+		parachains_hrmp::migration::MigrateToV1<Runtime>,
+		pallet_bounties::migrations::v5::MigrateV4ToV5<
+			Runtime,
+			(),
+			System,
+			sp_runtime::traits::Identity,
+			ChildBountiesPalletName,
+		>,
+		pallet_bounties::migrations::v6::MigrateV5ToV6<Runtime, ()>,
+		pallet_bounties::migrations::v7::MigrateV6ToV7<Runtime, (), RelayChainAccountConverter>,
+		pallet_bounties::migrations::v8::MigrateV7ToV8<Runtime, ()>,
+		pallet_bounties::migrations::v9::MigrateV8ToV9<Runtime, ()>,
+		pallet_asset_rate::migration::v1::MigrateV0ToV1<Runtime, frame_support::traits::GetDefault>,
 	);
 }
 
@@ -1813,6 +2077,78 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl parachains_para_payments::ParaPaymentsApi<Block, BlockNumber> for Runtime {
+		fn para_payment_statement(
+			para: ParaId,
+			from: BlockNumber,
+			to: BlockNumber,
+		) -> Option<parachains_para_payments::ParaPaymentStatement<BlockNumber>> {
+			ParaPayments::payment_statement(para, from, to)
+		}
+	}
+
+	impl parachains_configuration::ConfigurationApi<Block, BlockNumber> for Runtime {
+		fn pending_configs(
+		) -> Vec<(SessionIndex, parachains_configuration::HostConfiguration<BlockNumber>)> {
+			Configuration::pending_configs()
+		}
+	}
+
+	impl parachains_assigner_coretime::CoretimeAssignmentHistoryApi<Block> for Runtime {
+		fn served_assignments(
+			core: CoreIndex,
+			timeslice: parachains_assigner_coretime::Timeslice,
+		) -> Option<Vec<(ParaId, u32)>> {
+			CoretimeAssignmentProvider::served_assignments(core, timeslice)
+		}
+
+		fn served_assignments_key(
+			core: CoreIndex,
+			timeslice: parachains_assigner_coretime::Timeslice,
+			para: ParaId,
+		) -> Vec<u8> {
+			CoretimeAssignmentProvider::served_assignments_key(core, timeslice, para)
+		}
+	}
+
+	impl polkadot_runtime_common::xcm_sender::DeliveryFeeFactorApi<Block> for Runtime {
+		fn delivery_fee_factor(destination: VersionedLocation) -> Option<polkadot_runtime_common::xcm_sender::DeliveryFeeFactor> {
+			let destination = Location::try_from(destination).ok()?;
+			polkadot_runtime_common::xcm_sender::ChildParachainRouter::<
+				Runtime,
+				XcmPallet,
+				xcm_config::PriceForChildParachainDelivery,
+			>::delivery_fee_factor(&destination)
+		}
+	}
+
+	impl polkadot_runtime_common::impls::TreasurySpendApi<Block, OriginCaller, Balance, BlockNumber> for Runtime {
+		fn dry_run_spend(
+			origin: OriginCaller,
+			asset_kind: VersionedLocatableAsset,
+			amount: Balance,
+			beneficiary: VersionedLocation,
+			valid_from: Option<BlockNumber>,
+		) -> Result<polkadot_runtime_common::impls::SpendDryRun<Balance, BlockNumber>, polkadot_runtime_common::impls::SpendDryRunError> {
+			use polkadot_runtime_common::impls::{SpendDryRun, SpendDryRunError};
+
+			let check = Treasury::check_spend(origin.into(), asset_kind.clone(), amount, valid_from)
+				.map_err(SpendDryRunError::Rejected)?;
+			let (destination, message, delivery_fees) =
+				TreasuryPaymaster::dry_run_pay(&beneficiary, asset_kind, amount)
+					.map_err(SpendDryRunError::Payout)?;
+			Ok(SpendDryRun {
+				native_amount: check.native_amount,
+				max_amount: check.max_amount,
+				valid_from: check.valid_from,
+				expire_at: check.expire_at,
+				destination: destination.into(),
+				message: VersionedXcm::from(message),
+				delivery_fees: delivery_fees.into(),
+			})
+		}
+	}
+
 	impl xcm_runtime_apis::dry_run::DryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for Runtime {
 		fn dry_run_call(origin: OriginCaller, call: RuntimeCall) -> Result<CallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
 			XcmPallet::dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(origin, call)
@@ -1823,6 +2159,18 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_runtime_apis::transfers::TransferValidationApi<Block> for Runtime {
+		fn validate_transfer(
+			origin: VersionedLocation,
+			dest: VersionedLocation,
+			beneficiary: VersionedLocation,
+			assets: VersionedAssets,
+			fee_asset_item: u32,
+		) -> Vec<xcm_runtime_apis::transfers::TransferFailure> {
+			XcmPallet::validate_transfer(origin, dest, beneficiary, assets, fee_asset_item)
+		}
+	}
+
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
 		fn convert_location(location: VersionedLocation) -> Result<
 			AccountId,
@@ -1835,6 +2183,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(4)]
 	impl sp_api::Metadata<Block> for Runtime {
 		fn metadata() -> OpaqueMetadata {
 			OpaqueMetadata::new(Runtime::metadata().into())
@@ -1847,6 +2196,16 @@ sp_api::impl_runtime_apis! {
 		fn metadata_versions() -> sp_std::vec::Vec<u32> {
 			Runtime::metadata_versions()
 		}
+
+		fn module_error_metadata(
+			error: sp_runtime::ModuleError,
+		) -> Option<sp_runtime::ModuleErrorMetadata> {
+			Runtime::module_error_metadata(error)
+		}
+
+		fn storage_layout_hash() -> [u8; 32] {
+			Runtime::storage_layout_hash()
+		}
 	}
 
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
@@ -1886,7 +2245,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
-	#[api_version(11)]
+	#[api_version(12)]
 	impl polkadot_primitives::runtime_api::ParachainHost<Block> for Runtime {
 		fn validators() -> Vec<ValidatorId> {
 			parachains_runtime_api_impl::validators::<Runtime>()
@@ -2050,6 +2409,14 @@ sp_api::impl_runtime_apis! {
 		fn candidates_pending_availability(para_id: ParaId) -> Vec<CommittedCandidateReceipt<Hash>> {
 			vstaging_parachains_runtime_api_impl::candidates_pending_availability::<Runtime>(para_id)
 		}
+
+		fn dmq_contents_page(
+			recipient: ParaId,
+			start: u32,
+			count: u32,
+		) -> Vec<InboundDownwardMessage<BlockNumber>> {
+			vstaging_parachains_runtime_api_impl::dmq_contents_page::<Runtime>(recipient, start, count)
+		}
 	}
 
 	#[api_version(3)]
@@ -2222,6 +2589,16 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl sp_consensus_babe::BabeAuthoringStatsApi<Block> for Runtime {
+		fn current_epoch_authoring_stats() -> sp_consensus_babe::EpochAuthoringStats {
+			Babe::current_epoch_authoring_stats()
+		}
+
+		fn previous_epoch_authoring_stats() -> Option<sp_consensus_babe::EpochAuthoringStats> {
+			Babe::previous_epoch_authoring_stats()
+		}
+	}
+
 	impl sp_authority_discovery::AuthorityDiscoveryApi<Block> for Runtime {
 		fn authorities() -> Vec<AuthorityDiscoveryId> {
 			parachains_runtime_api_impl::relevant_authority_ids::<Runtime>()
@@ -2246,6 +2623,112 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl frame_system_rpc_runtime_api::BlockDigestApi<Block, AccountId> for Runtime {
+		fn block_author() -> Option<AccountId> {
+			Babe::block_author()
+		}
+
+		fn pre_runtime_digests() -> Vec<frame_system_rpc_runtime_api::PreRuntimeDigest<AccountId>> {
+			Babe::pre_runtime_digests()
+		}
+	}
+
+	impl pallet_bounties::runtime_api::BountiesApi<
+		Block,
+		AccountId,
+		Balance,
+		BlockNumber,
+		VersionedLocatableAsset,
+		Balance,
+	> for Runtime {
+		fn bounty(
+			index: pallet_bounties::BountyIndex,
+		) -> Option<pallet_bounties::BountySummaryOf<Runtime>> {
+			Bounties::bounty_summary(index)
+		}
+
+		fn bounties(
+			after: Option<pallet_bounties::BountyCursor>,
+			limit: u32,
+		) -> Vec<pallet_bounties::BountySummaryOf<Runtime>> {
+			Bounties::bounty_summaries(after, limit)
+		}
+
+		fn curator_deposit(fee: Balance) -> Balance {
+			Bounties::calculate_curator_deposit(&fee)
+		}
+
+		fn approvals_capacity() -> u32 {
+			Bounties::approvals_capacity()
+		}
+
+		fn bounty_ledger(
+			index: pallet_bounties::BountyIndex,
+		) -> Option<pallet_bounties::BountyLedger<Balance>> {
+			pallet_bounties::BountyLedgers::<Runtime>::get(index)
+		}
+
+		fn total_encumbered() -> Balance {
+			pallet_bounties::TotalEncumbered::<Runtime>::get()
+		}
+	}
+
+	impl pallet_multisig::runtime_api::MultisigApi<Block, AccountId> for Runtime {
+		fn multi_account_id(signatories: Vec<AccountId>, threshold: u16) -> AccountId {
+			Multisig::derive_multi_account_id(signatories, threshold)
+		}
+	}
+
+	impl pallet_proxy::runtime_api::ProxyApi<Block, AccountId, ProxyType, BlockNumber, Balance> for Runtime {
+		fn namespaced_pure_account(
+			spawner: AccountId,
+			namespace: pallet_proxy::PureNamespace,
+			index: u16,
+		) -> AccountId {
+			Proxy::namespaced_pure_account(&spawner, namespace, index)
+		}
+
+		fn pure_proxies(
+			spawner: AccountId,
+			namespace: pallet_proxy::PureNamespace,
+		) -> Vec<(u16, pallet_proxy::PureProxyInfo<AccountId, ProxyType, BlockNumber, Balance>)> {
+			Proxy::pure_proxies(&spawner, namespace)
+		}
+	}
+
+	impl pallet_message_queue::runtime_api::MessageQueueApi<Block, AggregateMessageOrigin> for Runtime {
+		fn reapable_pages(limit: u32) -> Vec<(AggregateMessageOrigin, u32)> {
+			MessageQueue::reapable_pages(limit)
+		}
+	}
+
+	impl pallet_scheduler::runtime_api::SchedulerApi<Block, BlockNumber, Hash> for Runtime {
+		fn lookup(
+			id: frame_support::traits::schedule::v3::TaskName,
+		) -> Option<pallet_scheduler::TaskSummary<BlockNumber, Hash>> {
+			Scheduler::lookup(id)
+		}
+
+		fn agenda(when: BlockNumber) -> Vec<pallet_scheduler::TaskSummary<BlockNumber, Hash>> {
+			Scheduler::agenda(when)
+		}
+
+		fn next_dispatch(
+			id: frame_support::traits::schedule::v3::TaskName,
+		) -> Option<BlockNumber> {
+			Scheduler::next_dispatch(id)
+		}
+
+		fn agenda_occupancy(when: BlockNumber) -> pallet_scheduler::AgendaOccupancy {
+			Scheduler::agenda_occupancy(when)
+		}
+
+		fn total_scheduled() -> u32 {
+			Scheduler::total_scheduled()
+		}
+	}
+
+	#[api_version(5)]
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -2262,6 +2745,14 @@ sp_api::impl_runtime_apis! {
 		fn query_length_to_fee(length: u32) -> Balance {
 			TransactionPayment::length_to_fee(length)
 		}
+		fn query_priority_info(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			tip: Balance,
+			target_priority: sp_runtime::transaction_validity::TransactionPriority,
+		) -> pallet_transaction_payment::PriorityInfo<Balance> {
+			TransactionPayment::query_priority_info(uxt, len, tip, target_priority)
+		}
 	}
 
 	impl pallet_beefy_mmr::BeefyMmrApi<Block, Hash> for RuntimeApi {
@@ -2498,12 +2989,20 @@ sp_api::impl_runtime_apis! {
 					Err(BenchmarkError::Skip)
 				}
 
+				#[cfg(not(feature = "test-message-exporter"))]
 				fn export_message_origin_and_destination(
 				) -> Result<(Location, NetworkId, InteriorLocation), BenchmarkError> {
 					// Rococo doesn't support exporting messages
 					Err(BenchmarkError::Skip)
 				}
 
+				#[cfg(feature = "test-message-exporter")]
+				fn export_message_origin_and_destination(
+				) -> Result<(Location, NetworkId, InteriorLocation), BenchmarkError> {
+					// The test message exporter exports to any other network.
+					Ok((AssetHub::get(), NetworkId::Westend, [Parachain(1000)].into()))
+				}
+
 				fn alias_origin() -> Result<(Location, Location), BenchmarkError> {
 					// The XCM executor of Rococo doesn't have a configured `Aliasers`
 					Err(BenchmarkError::Skip)
@@ -2533,13 +3032,7 @@ sp_api::impl_runtime_apis! {
 		}
 
 		fn preset_names() -> Vec<PresetId> {
-			vec![
-				PresetId::from("local_testnet"),
-				PresetId::from("development"),
-				PresetId::from("staging_testnet"),
-				PresetId::from("wococo_local_testnet"),
-				PresetId::from("versi_local_testnet"),
-			]
+			genesis_config_presets::preset_names()
 		}
 	}
 }
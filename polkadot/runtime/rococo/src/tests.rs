@@ -53,6 +53,19 @@ fn check_treasury_pallet_id() {
 	);
 }
 
+#[test]
+fn fast_runtime_presets_require_the_fast_runtime() {
+	for name in genesis_config_presets::preset_names() {
+		assert!(genesis_config_presets::get_preset(&name).is_some());
+	}
+	for (fast, _) in genesis_config_presets::FAST_RUNTIME_PRESETS {
+		assert_eq!(
+			genesis_config_presets::get_preset(&fast.into()).is_some(),
+			cfg!(feature = "fast-runtime")
+		);
+	}
+}
+
 mod encoding_tests {
 	use super::*;
 
@@ -61,3 +74,53 @@ mod encoding_tests {
 		assert_eq!(RuntimeHoldReason::Nis(pallet_nis::HoldReason::NftReceipt).encode(), [38, 0]);
 	}
 }
+
+#[test]
+#[cfg(feature = "test-message-exporter")]
+fn test_message_exporter_records_exported_messages() {
+	use xcm::latest::prelude::*;
+	use xcm_config::test_message_exporter::{ExportedMessage, ExportedMessages};
+	use xcm_executor::traits::{validate_export, ExportXcm};
+
+	type Exporter = <xcm_config::XcmConfig as xcm_executor::Config>::MessageExporter;
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		let source: InteriorLocation = [Parachain(1000)].into();
+		let destination: InteriorLocation = [Parachain(2000)].into();
+		let message = Xcm::<()>(vec![ClearOrigin]);
+
+		// Messages can not be exported to this network.
+		assert_eq!(
+			validate_export::<Exporter>(
+				NetworkId::Rococo,
+				0,
+				source.clone(),
+				destination.clone(),
+				message.clone()
+			)
+			.err(),
+			Some(SendError::NotApplicable)
+		);
+
+		let (ticket, price) = validate_export::<Exporter>(
+			NetworkId::Westend,
+			0,
+			source.clone(),
+			destination.clone(),
+			message.clone(),
+		)
+		.unwrap();
+		assert!(price.is_none());
+		frame_support::assert_ok!(Exporter::deliver(ticket));
+		assert_eq!(
+			ExportedMessages::get(),
+			vec![ExportedMessage {
+				network: NetworkId::Westend,
+				channel: 0,
+				universal_source: source,
+				destination,
+				message,
+			}]
+		);
+	});
+}
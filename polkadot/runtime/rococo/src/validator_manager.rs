@@ -15,7 +15,15 @@
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
 //! A pallet for managing validators on Rococo.
-
+//!
+//! Besides the manual registration of validators, the pallet keeps a lightweight liveness score
+//! for every validator in each session. The score is made up of the blocks authored by the
+//! validator and the availability bitfields it signed. Validators that stay below
+//! [`Config::LivenessThreshold`] for [`Config::MaxOfflineSessions`] consecutive sessions are
+//! deregistered automatically.
+
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::Convert;
 use sp_staking::SessionIndex;
 use sp_std::vec::Vec;
 
@@ -23,6 +31,13 @@ pub use pallet::*;
 
 type Session<T> = pallet_session::Pallet<T>;
 
+/// The liveness points given for authoring a block.
+pub const AUTHORED_BLOCK_POINTS: u32 = 20;
+/// The liveness points given for signing an availability bitfield.
+pub const BITFIELD_POINTS: u32 = 1;
+/// The number of past sessions for which liveness scores are kept.
+pub const LIVENESS_HISTORY_DEPTH: SessionIndex = 6;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -41,6 +56,17 @@ pub mod pallet {
 
 		/// Privileged origin that can add or remove validators.
 		type PrivilegedOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// The minimum liveness score a validator needs to collect in a session to be considered
+		/// online.
+		#[pallet::constant]
+		type LivenessThreshold: Get<u32>;
+
+		/// The number of consecutive offline sessions after which a validator is deregistered.
+		///
+		/// `None` disables the automatic deregistration.
+		#[pallet::constant]
+		type MaxOfflineSessions: Get<Option<u32>>;
 	}
 
 	#[pallet::event]
@@ -50,6 +76,8 @@ pub mod pallet {
 		ValidatorsRegistered(Vec<T::ValidatorId>),
 		/// Validators were removed from the set.
 		ValidatorsDeregistered(Vec<T::ValidatorId>),
+		/// Validators were removed from the set for being offline for too many sessions.
+		OfflineValidatorsDeregistered(Vec<T::ValidatorId>),
 	}
 
 	/// Validators that should be retired, because their Parachain was deregistered.
@@ -61,6 +89,23 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type ValidatorsToAdd<T: Config> = StorageValue<_, Vec<T::ValidatorId>, ValueQuery>;
 
+	/// The liveness score of each validator in the recent sessions.
+	#[pallet::storage]
+	pub type LivenessScores<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Twox64Concat,
+		T::ValidatorId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// The number of consecutive sessions each validator stayed below the liveness threshold.
+	#[pallet::storage]
+	pub type OfflineSessions<T: Config> =
+		StorageMap<_, Twox64Concat, T::ValidatorId, u32, ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Add new validators to the set.
@@ -99,6 +144,55 @@ pub mod pallet {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Add `points` to the liveness score of `validator` in the current session.
+	pub fn note_activity(validator: &T::ValidatorId, points: u32) {
+		LivenessScores::<T>::mutate(Session::<T>::current_index(), validator, |score| {
+			*score = score.saturating_add(points)
+		});
+	}
+
+	/// Note that the given validators signed availability bitfields.
+	pub fn note_bitfields(validators: impl IntoIterator<Item = T::ValidatorId>) {
+		validators.into_iter().for_each(|v| Self::note_activity(&v, BITFIELD_POINTS));
+	}
+
+	/// Update the offline counters of the validators of the ending session and schedule the ones
+	/// that were offline for too long for removal.
+	fn check_liveness(end_index: SessionIndex) {
+		let validators = Session::<T>::validators();
+		let threshold = T::LivenessThreshold::get();
+		let max_offline = T::MaxOfflineSessions::get();
+
+		let mut offline = Vec::new();
+		for v in validators.iter() {
+			if LivenessScores::<T>::get(end_index, v) >= threshold {
+				OfflineSessions::<T>::remove(v);
+				continue
+			}
+			let sessions = OfflineSessions::<T>::mutate(v, |n| {
+				*n = n.saturating_add(1);
+				*n
+			});
+			if max_offline.map_or(false, |max| sessions >= max) {
+				offline.push(v.clone());
+			}
+		}
+
+		// Never leave the chain without any validators.
+		if !offline.is_empty() && offline.len() < validators.len() {
+			offline.iter().for_each(|v| ValidatorsToRetire::<T>::append(v));
+			Self::deposit_event(Event::OfflineValidatorsDeregistered(offline));
+		}
+
+		let _ = LivenessScores::<T>::clear_prefix(
+			end_index.saturating_sub(LIVENESS_HISTORY_DEPTH),
+			u32::MAX,
+			None,
+		);
+	}
+}
+
 impl<T: Config> pallet_session::SessionManager<T::ValidatorId> for Pallet<T> {
 	fn new_session(new_index: SessionIndex) -> Option<Vec<T::ValidatorId>> {
 		if new_index <= 1 {
@@ -111,6 +205,7 @@ impl<T: Config> pallet_session::SessionManager<T::ValidatorId> for Pallet<T> {
 			if let Some(pos) = validators.iter().position(|r| r == v) {
 				validators.swap_remove(pos);
 			}
+			OfflineSessions::<T>::remove(v);
 		});
 
 		ValidatorsToAdd::<T>::take().into_iter().for_each(|v| {
@@ -122,11 +217,21 @@ impl<T: Config> pallet_session::SessionManager<T::ValidatorId> for Pallet<T> {
 		Some(validators)
 	}
 
-	fn end_session(_: SessionIndex) {}
+	fn end_session(end_index: SessionIndex) {
+		Self::check_liveness(end_index);
+	}
 
 	fn start_session(_start_index: SessionIndex) {}
 }
 
+impl<T: Config> pallet_authorship::EventHandler<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+	fn note_author(author: T::AccountId) {
+		if let Some(validator) = T::ValidatorIdOf::convert(author) {
+			Self::note_activity(&validator, AUTHORED_BLOCK_POINTS);
+		}
+	}
+}
+
 impl<T: Config> pallet_session::historical::SessionManager<T::ValidatorId, ()> for Pallet<T> {
 	fn new_session(new_index: SessionIndex) -> Option<Vec<(T::ValidatorId, ())>> {
 		<Self as pallet_session::SessionManager<_>>::new_session(new_index)
@@ -116,6 +116,30 @@ impl<T: frame_system::Config> pallet_bounties::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(0, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(694), added: 3169, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildrenCuratorFees` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildrenCuratorFees` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyLedgers` (r:1 w:1)
+	/// Proof: `Bounties::BountyLedgers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::TotalEncumbered` (r:1 w:1)
+	/// Proof: `Bounties::TotalEncumbered` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::RemotePayouts` (r:1 w:1)
+	/// Proof: `Bounties::RemotePayouts` (`max_values`: None, `max_size`: Some(1841), added: 4316, mode: `MaxEncodedLen`)
+	fn claim_bounty_remote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1342`
+		//  Estimated: `9031`
+		// Minimum execution time: 148_306_000 picoseconds.
+		Weight::from_parts(151_124_000, 0)
+			.saturating_add(Weight::from_parts(0, 9031))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
 	/// Storage: Bounties Bounties (r:1 w:1)
 	/// Proof: Bounties Bounties (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
 	/// Storage: ChildBounties ParentChildBounties (r:1 w:0)
@@ -161,4 +185,306 @@ impl<T: frame_system::Config> pallet_bounties::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(2_405_233, 0)
 			.saturating_add(Weight::from_parts(0, 1887))
 	}
+	/// Storage: Bounties BountyCount (r:1 w:1)
+	/// Proof: Bounties BountyCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Bounties BountyDescriptions (r:0 w:1)
+	/// Proof: Bounties BountyDescriptions (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
+	/// Storage: Bounties AssetBounties (r:0 w:1)
+	/// Proof: Bounties AssetBounties (max_values: None, max_size: Some(214), added: 2689, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 16384]`.
+	fn propose_asset_bounty(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `309`
+		//  Estimated: `3593`
+		// Minimum execution time: 29_731_000 picoseconds.
+		Weight::from_parts(31_524_811, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			// Standard Error: 18
+			.saturating_add(Weight::from_parts(611, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: Bounties AssetBounties (r:1 w:1)
+	/// Proof: Bounties AssetBounties (max_values: None, max_size: Some(214), added: 2689, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn approve_asset_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3679`
+		// Minimum execution time: 22_914_000 picoseconds.
+		Weight::from_parts(23_882_000, 0)
+			.saturating_add(Weight::from_parts(0, 3679))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Bounties AssetBounties (r:1 w:1)
+	/// Proof: Bounties AssetBounties (max_values: None, max_size: Some(214), added: 2689, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn accept_asset_curator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `634`
+		//  Estimated: `3679`
+		// Minimum execution time: 28_613_000 picoseconds.
+		Weight::from_parts(29_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 3679))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Bounties AssetBounties (r:1 w:1)
+	/// Proof: Bounties AssetBounties (max_values: None, max_size: Some(214), added: 2689, mode: MaxEncodedLen)
+	fn extend_asset_bounty_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `494`
+		//  Estimated: `3679`
+		// Minimum execution time: 12_802_000 picoseconds.
+		Weight::from_parts(13_596_000, 0)
+			.saturating_add(Weight::from_parts(0, 3679))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Bounties AssetBounties (r:1 w:1)
+	/// Proof: Bounties AssetBounties (max_values: None, max_size: Some(214), added: 2689, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Bounties SlashedCuratorDeposits (r:0 w:1)
+	/// Proof: Bounties SlashedCuratorDeposits (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn unassign_asset_curator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `671`
+		//  Estimated: `6196`
+		// Minimum execution time: 30_214_000 picoseconds.
+		Weight::from_parts(31_370_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: Bounties AssetBounties (r:1 w:1)
+	/// Proof: Bounties AssetBounties (max_values: None, max_size: Some(214), added: 2689, mode: MaxEncodedLen)
+	fn propose_asset_curator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `482`
+		//  Estimated: `3679`
+		// Minimum execution time: 13_590_000 picoseconds.
+		Weight::from_parts(14_288_000, 0)
+			.saturating_add(Weight::from_parts(0, 3679))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Bounties AssetBounties (r:1 w:1)
+	/// Proof: Bounties AssetBounties (max_values: None, max_size: Some(214), added: 2689, mode: MaxEncodedLen)
+	fn award_asset_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `598`
+		//  Estimated: `3679`
+		// Minimum execution time: 13_406_000 picoseconds.
+		Weight::from_parts(14_102_000, 0)
+			.saturating_add(Weight::from_parts(0, 3679))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Bounties AssetBounties (r:1 w:1)
+	/// Proof: Bounties AssetBounties (max_values: None, max_size: Some(214), added: 2689, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn payout_asset_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `902`
+		//  Estimated: `8799`
+		// Minimum execution time: 98_617_000 picoseconds.
+		Weight::from_parts(101_345_000, 0)
+			.saturating_add(Weight::from_parts(0, 8799))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: Bounties AssetBounties (r:1 w:1)
+	/// Proof: Bounties AssetBounties (max_values: None, max_size: Some(214), added: 2689, mode: MaxEncodedLen)
+	/// Storage: Bounties BountyDescriptions (r:0 w:1)
+	/// Proof: Bounties BountyDescriptions (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
+	fn check_asset_bounty_payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `693`
+		//  Estimated: `3679`
+		// Minimum execution time: 16_284_000 picoseconds.
+		Weight::from_parts(17_015_000, 0)
+			.saturating_add(Weight::from_parts(0, 3679))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Bounties AssetBounties (r:1 w:1)
+	/// Proof: Bounties AssetBounties (max_values: None, max_size: Some(214), added: 2689, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Bounties BountyDescriptions (r:0 w:1)
+	/// Proof: Bounties BountyDescriptions (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
+	fn close_asset_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `681`
+		//  Estimated: `3679`
+		// Minimum execution time: 31_208_000 picoseconds.
+		Weight::from_parts(32_563_000, 0)
+			.saturating_add(Weight::from_parts(0, 3679))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(694), added: 3169, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::CommitteeApprovals` (r:1 w:0)
+	/// Proof: `Bounties::CommitteeApprovals` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 15]`.
+	fn set_curator_committee(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `521`
+		//  Estimated: `4159`
+		// Minimum execution time: 21_306_000 picoseconds.
+		Weight::from_parts(22_148_790, 0)
+			.saturating_add(Weight::from_parts(0, 4159))
+			// Standard Error: 2_954
+			.saturating_add(Weight::from_parts(398_112, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(694), added: 3169, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::FundedExpiries` (r:1 w:1)
+	/// Proof: `Bounties::FundedExpiries` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ParentChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::ParentChildBounties` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(16400), added: 18875, mode: `MaxEncodedLen`)
+	fn sweep_expired_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `776`
+		//  Estimated: `6196`
+		// Minimum execution time: 73_184_000 picoseconds.
+		Weight::from_parts(75_026_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(694), added: 3169, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildrenCuratorFees` (r:1 w:0)
+	/// Proof: `ChildBounties::ChildrenCuratorFees` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::CuratorFeeUpdates` (r:1 w:1)
+	/// Proof: `Bounties::CuratorFeeUpdates` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn update_curator_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `759`
+		//  Estimated: `4159`
+		// Minimum execution time: 43_287_000 picoseconds.
+		Weight::from_parts(44_910_000, 0)
+			.saturating_add(Weight::from_parts(0, 4159))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Bounties::RemotePayouts` (r:1 w:1)
+	/// Proof: `Bounties::RemotePayouts` (`max_values`: None, `max_size`: Some(1841), added: 4316, mode: `MaxEncodedLen`)
+	fn check_bounty_payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `5306`
+		// Minimum execution time: 15_207_000 picoseconds.
+		Weight::from_parts(15_884_000, 0)
+			.saturating_add(Weight::from_parts(0, 5306))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:0)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(694), added: 3169, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::RecurringBounties` (r:0 w:1)
+	/// Proof: `Bounties::RecurringBounties` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	fn set_bounty_recurring() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `536`
+		//  Estimated: `4159`
+		// Minimum execution time: 12_604_000 picoseconds.
+		Weight::from_parts(13_179_000, 0)
+			.saturating_add(Weight::from_parts(0, 4159))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(694), added: 3169, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn poke_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `792`
+		//  Estimated: `4159`
+		// Minimum execution time: 49_116_000 picoseconds.
+		Weight::from_parts(50_732_000, 0)
+			.saturating_add(Weight::from_parts(0, 4159))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(694), added: 3169, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(1049), added: 3524, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(16400), added: 18875, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::RecurringBounties` (r:0 w:1)
+	/// Proof: `Bounties::RecurringBounties` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	fn withdraw_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `782`
+		//  Estimated: `4514`
+		// Minimum execution time: 54_207_000 picoseconds.
+		Weight::from_parts(55_981_000, 0)
+			.saturating_add(Weight::from_parts(0, 4514))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `Bounties::SlashedCuratorDeposits` (r:1 w:1)
+	/// Proof: `Bounties::SlashedCuratorDeposits` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn appeal_curator_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183`
+		//  Estimated: `3534`
+		// Minimum execution time: 12_914_000 picoseconds.
+		Weight::from_parts(13_402_000, 0)
+			.saturating_add(Weight::from_parts(0, 3534))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Bounties::SlashedCuratorDeposits` (r:1 w:1)
+	/// Proof: `Bounties::SlashedCuratorDeposits` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn resolve_curator_slash_appeal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `403`
+		//  Estimated: `6196`
+		// Minimum execution time: 63_507_000 picoseconds.
+		Weight::from_parts(65_130_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Bounties::SlashedCuratorDeposits` (r:1 w:1)
+	/// Proof: `Bounties::SlashedCuratorDeposits` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn remove_expired_curator_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183`
+		//  Estimated: `3534`
+		// Minimum execution time: 12_187_000 picoseconds.
+		Weight::from_parts(12_741_000, 0)
+			.saturating_add(Weight::from_parts(0, 3534))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
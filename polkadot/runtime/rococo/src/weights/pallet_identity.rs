@@ -428,4 +428,23 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Identity Registrars (r:1 w:0)
+	/// Proof: Identity Registrars (max_values: Some(1), max_size: Some(1141), added: 1636, mode: MaxEncodedLen)
+	/// Storage: Identity IdentityOf (r:32 w:32)
+	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	fn provide_judgement_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1165 + n * (7111 ±0)`
+		//  Estimated: `2626 + n * (10013 ±0)`
+		// Minimum execution time: 24_417_000 picoseconds.
+		Weight::from_parts(4_893_512, 0)
+			.saturating_add(Weight::from_parts(0, 2626))
+			// Standard Error: 9_871
+			.saturating_add(Weight::from_parts(21_907_364, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10013).saturating_mul(n.into()))
+	}
 }
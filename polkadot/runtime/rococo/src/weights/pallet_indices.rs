@@ -63,6 +63,8 @@ impl<T: frame_system::Config> pallet_indices::WeightInfo for WeightInfo<T> {
 	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Indices IdentityLinks (r:0 w:1)
+	/// Proof: Indices IdentityLinks (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `341`
@@ -71,10 +73,12 @@ impl<T: frame_system::Config> pallet_indices::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(36_521_000, 0)
 			.saturating_add(Weight::from_parts(0, 3593))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Indices Accounts (r:1 w:1)
 	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Indices IdentityLinks (r:0 w:1)
+	/// Proof: Indices IdentityLinks (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn free() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `238`
@@ -83,12 +87,14 @@ impl<T: frame_system::Config> pallet_indices::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(26_220_000, 0)
 			.saturating_add(Weight::from_parts(0, 3534))
 			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Indices Accounts (r:1 w:1)
 	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Indices IdentityLinks (r:0 w:1)
+	/// Proof: Indices IdentityLinks (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `341`
@@ -97,7 +103,7 @@ impl<T: frame_system::Config> pallet_indices::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(28_845_000, 0)
 			.saturating_add(Weight::from_parts(0, 3593))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Indices Accounts (r:1 w:1)
 	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
@@ -111,4 +117,32 @@ impl<T: frame_system::Config> pallet_indices::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Indices Accounts (r:1 w:0)
+	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Indices IdentityLinks (r:0 w:1)
+	/// Proof: Indices IdentityLinks (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn link_identity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `172`
+		//  Estimated: `3534`
+		// Minimum execution time: 13_639_000 picoseconds.
+		Weight::from_parts(14_208_000, 0)
+			.saturating_add(Weight::from_parts(0, 3534))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Indices Accounts (r:1 w:0)
+	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Indices IdentityLinks (r:1 w:1)
+	/// Proof: Indices IdentityLinks (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn unlink_identity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `211`
+		//  Estimated: `3534`
+		// Minimum execution time: 16_196_000 picoseconds.
+		Weight::from_parts(16_871_000, 0)
+			.saturating_add(Weight::from_parts(0, 3534))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -59,104 +59,385 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(492, 0).saturating_mul(z.into()))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:0 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `193 + s * (2 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `12573`
 		// Minimum execution time: 38_857_000 picoseconds.
 		Weight::from_parts(33_611_791, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12573))
 			// Standard Error: 400
 			.saturating_add(Weight::from_parts(59_263, 0).saturating_mul(s.into()))
 			// Standard Error: 3
 			.saturating_add(Weight::from_parts(1_211, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:0)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// The range of component `s` is `[3, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `211`
-		//  Estimated: `6811`
+		//  Estimated: `12568`
 		// Minimum execution time: 25_715_000 picoseconds.
 		Weight::from_parts(20_607_294, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12568))
 			// Standard Error: 285
 			.saturating_add(Weight::from_parts(58_225, 0).saturating_mul(s.into()))
 			// Standard Error: 2
 			.saturating_add(Weight::from_parts(1_160, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:1)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigTimelocks (r:1 w:1)
+	/// Proof: Multisig MultisigTimelocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig ExecutionDelays (r:1 w:0)
+	/// Proof: Multisig ExecutionDelays (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigDeadlines (r:1 w:1)
+	/// Proof: Multisig MultisigDeadlines (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:0 w:1)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Multisig CancellationApprovals (r:0 w:1)
+	/// Proof: Multisig CancellationApprovals (max_values: None, max_size: Some(3250), added: 5725, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigCalls (r:1 w:1)
+	/// Proof: Multisig MultisigCalls (max_values: None, max_size: Some(187), added: 2662, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:1 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:1 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `317 + s * (33 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `71967`
 		// Minimum execution time: 43_751_000 picoseconds.
 		Weight::from_parts(37_398_513, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 71967))
 			// Standard Error: 426
 			.saturating_add(Weight::from_parts(70_904, 0).saturating_mul(s.into()))
 			// Standard Error: 4
 			.saturating_add(Weight::from_parts(1_235, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(12))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:1)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigTimelocks (r:1 w:1)
+	/// Proof: Multisig MultisigTimelocks (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig ExecutionDelays (r:1 w:0)
+	/// Proof: Multisig ExecutionDelays (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigDeadlines (r:1 w:1)
+	/// Proof: Multisig MultisigDeadlines (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:0 w:1)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Multisig CancellationApprovals (r:0 w:1)
+	/// Proof: Multisig CancellationApprovals (max_values: None, max_size: Some(3250), added: 5725, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigCalls (r:1 w:1)
+	/// Proof: Multisig MultisigCalls (max_values: None, max_size: Some(187), added: 2662, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:1 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:1 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_with_signatures(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `317 + s * (33 ±0)`
+		//  Estimated: `71967`
+		// Not measured: `as_multi_complete` plus one `sr25519_verification` of
+		// `frame_benchmarking` per signatory, until the benchmark is run.
+		Weight::from_parts(42_675_615, 0)
+			.saturating_add(Weight::from_parts(0, 71967))
+			.saturating_add(Weight::from_parts(40_681_415, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(1_235, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(12))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:0 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `193 + s * (2 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `12573`
 		// Minimum execution time: 31_278_000 picoseconds.
 		Weight::from_parts(32_075_573, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 12573))
 			// Standard Error: 452
 			.saturating_add(Weight::from_parts(62_018, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `211`
-		//  Estimated: `6811`
+		//  Estimated: `10013`
 		// Minimum execution time: 18_178_000 picoseconds.
 		Weight::from_parts(18_649_867, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 10013))
 			// Standard Error: 293
 			.saturating_add(Weight::from_parts(56_475, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `383 + s * (1 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `10013`
 		// Minimum execution time: 32_265_000 picoseconds.
 		Weight::from_parts(32_984_014, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 10013))
 			// Standard Error: 452
 			.saturating_add(Weight::from_parts(59_934, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Multisig Multisigs (r:1 w:0)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:1)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_expiry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `418 + s * (1 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 19_253_000 picoseconds.
+		Weight::from_parts(20_536_059, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 348
+			.saturating_add(Weight::from_parts(58_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:1 w:1)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:0 w:1)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	fn reap_expired() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `552`
+		//  Estimated: `10013`
+		// Minimum execution time: 30_262_000 picoseconds.
+		Weight::from_parts(31_816_895, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Multisig Multisigs (r:200 w:200)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:100 w:200)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigExpiries (r:100 w:200)
+	/// Proof: Multisig MultisigExpiries (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `c` is `[0, 100]`.
+	fn migrate_multisig(s: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178 + c * (179 ±0)`
+		//  Estimated: `6196 + c * (9023 ±0)`
+		// Minimum execution time: 60_087_000 picoseconds.
+		Weight::from_parts(61_467_500, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 666
+			.saturating_add(Weight::from_parts(12_980, 0).saturating_mul(s.into()))
+			// Standard Error: 1_244
+			.saturating_add(Weight::from_parts(28_322_341, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 9023).saturating_mul(c.into()))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Preimage StatusFor (r:1 w:0)
+	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Preimage PreimageFor (r:0 w:1)
+	/// Proof: Preimage PreimageFor (max_values: None, max_size: Some(4194344), added: 4196819, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigCalls (r:0 w:1)
+	/// Proof: Multisig MultisigCalls (max_values: None, max_size: Some(187), added: 2662, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_store_call(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247 + s * (2 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 41_496_000 picoseconds.
+		Weight::from_parts(42_768_234, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 580
+			.saturating_add(Weight::from_parts(60_405, 0).saturating_mul(s.into()))
+			// Standard Error: 9
+			.saturating_add(Weight::from_parts(1_753, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig CancellationApprovals (r:1 w:1)
+	/// Proof: Multisig CancellationApprovals (max_values: None, max_size: Some(3250), added: 5725, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_multi_by_threshold(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `562 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 32_467_000 picoseconds.
+		Weight::from_parts(34_569_432, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 668
+			.saturating_add(Weight::from_parts(72_531, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(6548), added: 9023, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigMetadata (r:1 w:0)
+	/// Proof: Multisig MultisigMetadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Multisig MultisigCalls (r:1 w:0)
+	/// Proof: Multisig MultisigCalls (max_values: None, max_size: Some(187), added: 2662, mode: MaxEncodedLen)
+	/// Storage: Multisig HeldDeposits (r:1 w:1)
+	/// Proof: Multisig HeldDeposits (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	fn poke_deposit(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `624 + s * (1 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 41_034_000 picoseconds.
+		Weight::from_parts(42_552_579, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 654
+			.saturating_add(Weight::from_parts(62_328, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn revoke_approval(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 18_923_000 picoseconds.
+		Weight::from_parts(20_311_260, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 596
+			.saturating_add(Weight::from_parts(59_400, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::ExecutionDelays` (r:0 w:1)
+	/// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_execution_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_372_000 picoseconds.
+		Weight::from_parts(4_565_115, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(6548), added: 9023, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::HeldDeposits` (r:1 w:1)
+	/// Proof: `Multisig::HeldDeposits` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn reject_as_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `671 + s * (33 ±0)`
+		//  Estimated: `10013`
+		// Minimum execution time: 34_236_000 picoseconds.
+		Weight::from_parts(36_120_007, 0)
+			.saturating_add(Weight::from_parts(0, 10013))
+			// Standard Error: 719
+			.saturating_add(Weight::from_parts(77_020, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::CreatedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::CreatedMultisigs` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `6196`
+		// Minimum execution time: 55_128_000 picoseconds.
+		Weight::from_parts(57_108_028, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 913
+			.saturating_add(Weight::from_parts(65_708, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Multisig::CreatedMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::CreatedMultisigs` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn destroy_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338`
+		//  Estimated: `6196`
+		// Minimum execution time: 42_289_000 picoseconds.
+		Weight::from_parts(43_479_620, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
@@ -163,4 +163,45 @@ impl<T: frame_system::Config> polkadot_runtime_common::claims::WeightInfo for We
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
+	/// Storage: Claims Sunset (r:0 w:1)
+	/// Proof Skipped: Claims Sunset (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_sunset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_955_000 picoseconds.
+		Weight::from_parts(7_245_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Claims Sunset (r:1 w:0)
+	/// Proof Skipped: Claims Sunset (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Claims Claims (r:1001 w:1000)
+	/// Proof Skipped: Claims Claims (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Claims Total (r:1 w:1)
+	/// Proof Skipped: Claims Total (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Claims Statistics (r:1 w:1)
+	/// Proof Skipped: Claims Statistics (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Claims Vesting (r:0 w:1000)
+	/// Proof Skipped: Claims Vesting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Claims Signing (r:0 w:1000)
+	/// Proof Skipped: Claims Signing (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 1000]`.
+	fn sweep_unclaimed(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `278 + n * (86 ±0)`
+		//  Estimated: `3593 + n * (2561 ±0)`
+		// Minimum execution time: 30_595_000 picoseconds.
+		Weight::from_parts(31_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			// Standard Error: 4_483
+			.saturating_add(Weight::from_parts(13_412_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2561).saturating_mul(n.into()))
+	}
 }
@@ -230,10 +230,19 @@ impl<RuntimeCall> XcmWeightInfo<RuntimeCall> for RococoXcmWeight<RuntimeCall> {
 		// Rococo does not currently support universal origin operations
 		Weight::MAX
 	}
+	#[cfg(not(feature = "test-message-exporter"))]
 	fn export_message(_: &NetworkId, _: &Junctions, _: &Xcm<()>) -> Weight {
 		// Rococo relay should not support export message operations
 		Weight::MAX
 	}
+	#[cfg(feature = "test-message-exporter")]
+	fn export_message(_: &NetworkId, _: &Junctions, _: &Xcm<()>) -> Weight {
+		// The test message exporter only records the message, which is not benchmarked as it must
+		// not be used on live networks.
+		use frame_support::traits::Get;
+		XcmGeneric::<Runtime>::clear_transact_status()
+			.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1))
+	}
 	fn lock_asset(_: &Asset, _: &Location) -> Weight {
 		// Rococo does not currently support asset locking operations
 		Weight::MAX
@@ -17,8 +17,9 @@
 //! XCM configuration for Rococo.
 
 use super::{
-	parachains_origin, AccountId, AllPalletsWithSystem, Balances, Dmp, Fellows, ParaId, Runtime,
-	RuntimeCall, RuntimeEvent, RuntimeOrigin, TransactionByteFee, Treasury, WeightToFee, XcmPallet,
+	parachains_inclusion, parachains_origin, AccountId, AllPalletsWithSystem, Balances, Dmp,
+	Fellows, ParaId, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, TransactionByteFee,
+	Treasury, WeightToFee, XcmPallet,
 };
 
 use crate::governance::StakingAdmin;
@@ -201,8 +202,10 @@ impl xcm_executor::Config for XcmConfig {
 		RuntimeCall,
 		MaxInstructions,
 	>;
-	type Trader =
-		UsingComponents<WeightToFee, TokenLocation, AccountId, Balances, ToAuthor<Runtime>>;
+	type Trader = parachains_inclusion::UmpFeeFactorTrader<
+		Runtime,
+		UsingComponents<WeightToFee, TokenLocation, AccountId, Balances, ToAuthor<Runtime>>,
+	>;
 	type ResponseHandler = XcmPallet;
 	type AssetTrap = XcmPallet;
 	type AssetLocker = ();
@@ -215,7 +218,10 @@ impl xcm_executor::Config for XcmConfig {
 		WaivedLocations,
 		XcmFeeToAccount<Self::AssetTransactor, AccountId, TreasuryAccount>,
 	>;
+	#[cfg(not(feature = "test-message-exporter"))]
 	type MessageExporter = ();
+	#[cfg(feature = "test-message-exporter")]
+	type MessageExporter = test_message_exporter::RecordingMessageExporter;
 	type UniversalAliases = Nothing;
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
@@ -288,3 +294,78 @@ impl pallet_xcm::Config for Runtime {
 	type WeightInfo = crate::weights::pallet_xcm::WeightInfo<Runtime>;
 	type AdminOrigin = EnsureRoot<AccountId>;
 }
+
+/// A stub [`ExportXcm`](xcm_executor::traits::ExportXcm) which records the messages exported with
+/// `ExportMessage` in storage instead of handing them to a bridge.
+///
+/// Rococo cannot export messages, so this is only meant to test `ExportMessage` flows end-to-end
+/// against the relay chain, e.g. in zombienet, by asserting on its `ExportedMessages`.
+#[cfg(feature = "test-message-exporter")]
+pub mod test_message_exporter {
+	use super::ThisNetwork;
+	use codec::{Decode, Encode};
+	use frame_support::{pallet_prelude::ValueQuery, traits::Get};
+	use sp_std::vec::Vec;
+	use xcm::latest::prelude::*;
+	use xcm_executor::traits::ExportXcm;
+
+	/// The maximum number of messages kept in [`ExportedMessages`].
+	pub const MAX_EXPORTED_MESSAGES: usize = 100;
+
+	/// A message exported with `ExportMessage`.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+	pub struct ExportedMessage {
+		/// The network the message was exported to.
+		pub network: NetworkId,
+		/// The channel of the export mechanism.
+		pub channel: u32,
+		/// The location the message claims to originate from.
+		pub universal_source: InteriorLocation,
+		/// The destination of the message within `network`.
+		pub destination: InteriorLocation,
+		/// The exported message.
+		pub message: Xcm<()>,
+	}
+
+	/// The last [`MAX_EXPORTED_MESSAGES`] exported messages, oldest first.
+	#[frame_support::storage_alias(verbatim)]
+	pub type ExportedMessages = StorageValue<TestMessageExporter, Vec<ExportedMessage>, ValueQuery>;
+
+	/// Exports messages to any network but this one for free by recording them in
+	/// [`ExportedMessages`].
+	pub struct RecordingMessageExporter;
+
+	impl ExportXcm for RecordingMessageExporter {
+		type Ticket = ExportedMessage;
+
+		fn validate(
+			network: NetworkId,
+			channel: u32,
+			universal_source: &mut Option<InteriorLocation>,
+			destination: &mut Option<InteriorLocation>,
+			message: &mut Option<Xcm<()>>,
+		) -> SendResult<ExportedMessage> {
+			if network == ThisNetwork::get() {
+				return Err(SendError::NotApplicable)
+			}
+			let universal_source = universal_source.take().ok_or(SendError::MissingArgument)?;
+			let destination = destination.take().ok_or(SendError::MissingArgument)?;
+			let message = message.take().ok_or(SendError::MissingArgument)?;
+			Ok((
+				ExportedMessage { network, channel, universal_source, destination, message },
+				Assets::new(),
+			))
+		}
+
+		fn deliver(ticket: ExportedMessage) -> Result<XcmHash, SendError> {
+			let hash = ticket.message.using_encoded(sp_io::hashing::blake2_256);
+			ExportedMessages::mutate(|messages| {
+				if messages.len() >= MAX_EXPORTED_MESSAGES {
+					messages.remove(0);
+				}
+				messages.push(ticket);
+			});
+			Ok(hash)
+		}
+	}
+}
@@ -206,6 +206,7 @@ impl pallet_indices::Config for Runtime {
 	type Currency = Balances;
 	type Deposit = IndexDeposit;
 	type RuntimeEvent = RuntimeEvent;
+	type IdentityProvider = ();
 	type WeightInfo = ();
 }
 
@@ -287,6 +288,7 @@ impl pallet_session::Config for Runtime {
 	type SessionManager = Staking;
 	type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
+	type RequireProofOfPossession = frame_support::traits::ConstBool<false>;
 	type WeightInfo = ();
 }
 
@@ -447,6 +449,7 @@ parameter_types! {
 
 parameter_types! {
 	pub Prefix: &'static [u8] = b"Pay KSMs to the Kusama account:";
+	pub ClaimsUnclaimedBeneficiary: AccountId = AccountId::from([0u8; 32]);
 }
 
 impl claims::Config for Runtime {
@@ -454,6 +457,7 @@ impl claims::Config for Runtime {
 	type VestingSchedule = Vesting;
 	type Prefix = Prefix;
 	type MoveClaimOrigin = frame_system::EnsureRoot<AccountId>;
+	type UnclaimedBeneficiary = ClaimsUnclaimedBeneficiary;
 	type WeightInfo = claims::TestWeightInfo;
 }
 
@@ -481,6 +485,7 @@ impl pallet_sudo::Config for Runtime {
 }
 
 impl parachains_configuration::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = parachains_configuration::TestWeightInfo;
 }
 
@@ -494,6 +499,7 @@ impl parachains_inclusion::Config for Runtime {
 	type RewardValidators = RewardValidatorsWithEraPoints<Runtime>;
 	type MessageQueue = ();
 	type WeightInfo = ();
+	type UmpFeeAdjustment = ();
 }
 
 impl parachains_disputes::Config for Runtime {
@@ -546,6 +552,7 @@ impl parachains_paras::Config for Runtime {
 	type QueueFootprinter = ParaInclusion;
 	type NextSessionRotation = Babe;
 	type OnNewHead = ();
+	type OnUpgradeRestriction = Dmp;
 	type AssignCoretime = ();
 }
 
@@ -557,10 +564,13 @@ parameter_types! {
 	pub const OnDemandTrafficDefaultValue: FixedU128 = FixedU128::from_u32(1);
 }
 
-impl parachains_dmp::Config for Runtime {}
+impl parachains_dmp::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+}
 
 parameter_types! {
 	pub const HrmpChannelSizeAndCapacityWithSystemRatio: Percent = Percent::from_percent(100);
+	pub const HrmpOpenChannelRequestTtl: Option<SessionIndex> = None;
 }
 
 impl parachains_hrmp::Config for Runtime {
@@ -573,6 +583,8 @@ impl parachains_hrmp::Config for Runtime {
 		HrmpChannelSizeAndCapacityWithSystemRatio,
 	>;
 	type VersionWrapper = crate::Xcm;
+	type OpenChannelRequestTtl = HrmpOpenChannelRequestTtl;
+	type OnParaPayment = ();
 	type WeightInfo = parachains_hrmp::TestWeightInfo;
 }
 
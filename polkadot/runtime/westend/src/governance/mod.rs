@@ -92,6 +92,9 @@ impl pallet_referenda::Config for Runtime {
 	type MaxQueued = ConstU32<100>;
 	type UndecidingTimeout = UndecidingTimeout;
 	type AlarmInterval = AlarmInterval;
+	type MaxEnactmentHorizon = MaxSchedulingHorizon;
 	type Tracks = TracksInfo;
 	type Preimages = Preimage;
+	type ProposalValue = ();
+	type ProposalConsideration = ();
 }
@@ -27,9 +27,10 @@ use frame_support::{
 	genesis_builder_helper::{build_state, get_preset},
 	parameter_types,
 	traits::{
-		fungible::HoldConsideration, tokens::UnityOrOuterConversion, ConstU32, Contains, EitherOf,
-		EitherOfDiverse, EverythingBut, FromContains, InstanceFilter, KeyOwnerProofSystem,
-		LinearStoragePrice, ProcessMessage, ProcessMessageError, VariantCountOf, WithdrawReasons,
+		fungible::HoldConsideration, tokens::UnityOrOuterConversion, ConstBool, ConstU32, Contains,
+		EitherOf, EitherOfDiverse, EverythingBut, FromContains, InstanceFilter,
+		KeyOwnerProofSystem, LinearStoragePrice, ProcessMessage, ProcessMessageError,
+		VariantCountOf, WithdrawReasons,
 	},
 	weights::{ConstantMultiplier, WeightMeter, WeightToFee as _},
 	PalletId,
@@ -52,8 +53,8 @@ use polkadot_runtime_common::{
 	elections::OnChainAccuracy,
 	identity_migrator, impl_runtime_weights,
 	impls::{
-		ContainsParts, LocatableAssetConverter, ToAuthor, VersionedLocatableAsset,
-		VersionedLocationConverter,
+		AnyVersionConversion, ContainsParts, LocatableAssetConverter, ToAuthor,
+		VersionedLocatableAsset, VersionedLocationConverter,
 	},
 	paras_registrar, paras_sudo_wrapper, prod_or_fast, slots,
 	traits::{Leaser, OnSwap},
@@ -68,7 +69,8 @@ use polkadot_runtime_parachains::{
 	disputes::slashing as parachains_slashing,
 	dmp as parachains_dmp, hrmp as parachains_hrmp, inclusion as parachains_inclusion,
 	inclusion::{AggregateMessageOrigin, UmpQueueId},
-	initializer as parachains_initializer, origin as parachains_origin, paras as parachains_paras,
+	initializer as parachains_initializer, origin as parachains_origin,
+	para_payments as parachains_para_payments, paras as parachains_paras,
 	paras_inherent as parachains_paras_inherent, reward_points as parachains_reward_points,
 	runtime_api_impl::{
 		v10 as parachains_runtime_api_impl, vstaging as vstaging_parachains_runtime_api_impl,
@@ -216,6 +218,10 @@ impl frame_system::Config for Runtime {
 parameter_types! {
 	pub MaximumSchedulerWeight: frame_support::weights::Weight = Perbill::from_percent(80) *
 		BlockWeights::get().max_block;
+	pub const MaximumSchedulerBlockTime: u64 = 2 * MILLISECS_PER_BLOCK;
+	pub const SchedulerDeposit: Balance = deposit(1, 128);
+	pub const MaxScheduledPerOrigin: Option<u32> = None;
+	pub const MaxSchedulingHorizon: Option<BlockNumber> = Some(365 * DAYS);
 	pub const MaxScheduledPerBlock: u32 = 50;
 	pub const NoPreimagePostponement: Option<u32> = Some(10);
 }
@@ -230,9 +236,22 @@ impl pallet_scheduler::Config for Runtime {
 	// OpenGov to schedule periodic auctions.
 	type ScheduleOrigin = EitherOf<EnsureRoot<AccountId>, AuctionAdmin>;
 	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type BlockNumberProvider = System;
 	type WeightInfo = weights::pallet_scheduler::WeightInfo<Runtime>;
 	type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
 	type Preimages = Preimage;
+	type RejectOverweight = ConstBool<false>;
+	type TimeProvider = Timestamp;
+	type MaximumBlockTime = MaximumSchedulerBlockTime;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type ScheduleDeposit = SchedulerDeposit;
+	type MaxScheduledPerOrigin = MaxScheduledPerOrigin;
+	type MaxSchedulingHorizon = MaxSchedulingHorizon;
+	type MaxMergedAgendas = ConstU32<1>;
+	type MaxIdleWeight = ();
+	type MaxOverflowed = ConstU32<100>;
+	type OnChargeDispatch = ();
 }
 
 parameter_types! {
@@ -250,8 +269,10 @@ impl pallet_preimage::Config for Runtime {
 		AccountId,
 		Balances,
 		PreimageHoldReason,
-		LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+		sp_runtime::traits::Identity,
+		Balance,
 	>;
+	type DepositPrice = LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>;
 }
 
 parameter_types! {
@@ -293,6 +314,7 @@ impl pallet_indices::Config for Runtime {
 	type Currency = Balances;
 	type Deposit = IndexDeposit;
 	type RuntimeEvent = RuntimeEvent;
+	type IdentityProvider = ();
 	type WeightInfo = weights::pallet_indices::WeightInfo<Runtime>;
 }
 
@@ -439,6 +461,7 @@ impl pallet_session::Config for Runtime {
 	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, Staking>;
 	type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
+	type RequireProofOfPossession = ConstBool<false>;
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 }
 
@@ -717,9 +740,12 @@ impl pallet_treasury::Config for Runtime {
 				xcm_builder::IsParentsOnly<ConstU8<1>>,
 			>,
 		>,
-		AssetRate,
+		AnyVersionConversion<AssetRate>,
 	>;
 	type PayoutPeriod = PayoutSpendPeriod;
+	type Clawback = ();
+	type SpendSubmitter = ();
+	type SpendConsideration = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments;
 }
@@ -838,6 +864,7 @@ impl pallet_identity::Config for Runtime {
 	type MaxSubAccounts = MaxSubAccounts;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
+	type MaxJudgementBatch = ConstU32<32>;
 	type ForceOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type RegistrarOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type OffchainSignature = Signature;
@@ -868,9 +895,22 @@ impl pallet_multisig::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type OldCurrency = Balances;
+	type CallFee = pallet_multisig::TransactionPaymentFee<Runtime>;
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = MaxSignatories;
+	type MaxMetadataLen = ConstU32<64>;
+	type Preimages = Preimage;
+	type BlockNumberProvider = System;
+	type Scheduler = Scheduler;
+	type MaxDeadline = ConstU32<{ 30 * DAYS }>;
+	type PalletsOrigin = OriginCaller;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -1076,6 +1116,7 @@ impl pallet_proxy::Config for Runtime {
 impl parachains_origin::Config for Runtime {}
 
 impl parachains_configuration::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::runtime_parachains_configuration::WeightInfo<Runtime>;
 }
 
@@ -1087,12 +1128,27 @@ impl parachains_session_info::Config for Runtime {
 	type ValidatorSet = Historical;
 }
 
+parameter_types! {
+	/// Paras whose upward messages all succeed get 1% off the price of their execution each
+	/// session, down to half the price. Paras with 10 or more failed upward messages in a session
+	/// pay 10% more, up to twice the price.
+	pub const UmpFeeAdjustment: Option<parachains_inclusion::UmpFeeAdjustment> =
+		Some(parachains_inclusion::UmpFeeAdjustment {
+			rebate: FixedU128::from_rational(1, 100),
+			surcharge: FixedU128::from_rational(1, 10),
+			failure_threshold: 10,
+			min_factor: FixedU128::from_rational(1, 2),
+			max_factor: FixedU128::from_u32(2),
+		});
+}
+
 impl parachains_inclusion::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type DisputesHandler = ParasDisputes;
 	type RewardValidators = parachains_reward_points::RewardValidatorsWithEraPoints<Runtime>;
 	type MessageQueue = MessageQueue;
 	type WeightInfo = weights::runtime_parachains_inclusion::WeightInfo<Runtime>;
+	type UmpFeeAdjustment = UmpFeeAdjustment;
 }
 
 parameter_types! {
@@ -1106,6 +1162,7 @@ impl parachains_paras::Config for Runtime {
 	type QueueFootprinter = ParaInclusion;
 	type NextSessionRotation = Babe;
 	type OnNewHead = ();
+	type OnUpgradeRestriction = Dmp;
 	type AssignCoretime = CoretimeAssignmentProvider;
 }
 
@@ -1149,8 +1206,12 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = MessageQueueMaxStale;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type OnPageReaped = ();
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type MessageProcessor = MessageProcessor;
+	type MessageProcessor = parachains_inclusion::RecordUmpOutcome<
+		Runtime,
+		parachains_para_payments::RecordUmpProcessing<Runtime, MessageProcessor>,
+	>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type MessageProcessor =
 		pallet_message_queue::mock_helpers::NoopMessageProcessor<AggregateMessageOrigin>;
@@ -1159,10 +1220,14 @@ impl pallet_message_queue::Config for Runtime {
 	type WeightInfo = weights::pallet_message_queue::WeightInfo<Runtime>;
 }
 
-impl parachains_dmp::Config for Runtime {}
+impl parachains_dmp::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+}
 
 parameter_types! {
 	pub const HrmpChannelSizeAndCapacityWithSystemRatio: Percent = Percent::from_percent(100);
+	/// Unconfirmed HRMP open channel requests expire after roughly a week worth of sessions.
+	pub const HrmpOpenChannelRequestTtl: Option<SessionIndex> = Some(7 * 24);
 }
 
 impl parachains_hrmp::Config for Runtime {
@@ -1175,6 +1240,8 @@ impl parachains_hrmp::Config for Runtime {
 		HrmpChannelSizeAndCapacityWithSystemRatio,
 	>;
 	type VersionWrapper = crate::XcmPallet;
+	type OpenChannelRequestTtl = HrmpOpenChannelRequestTtl;
+	type OnParaPayment = ParaPayments;
 	type WeightInfo = weights::runtime_parachains_hrmp::WeightInfo<Self>;
 }
 
@@ -1232,9 +1299,30 @@ impl parachains_assigner_on_demand::Config for Runtime {
 	type WeightInfo = weights::runtime_parachains_assigner_on_demand::WeightInfo<Runtime>;
 	type MaxHistoricalRevenue = MaxHistoricalRevenue;
 	type PalletId = OnDemandPalletId;
+	type OnParaPayment = ParaPayments;
 }
 
-impl parachains_assigner_coretime::Config for Runtime {}
+parameter_types! {
+	pub const CoretimeTimeslicePeriod: BlockNumber = TIMESLICE_PERIOD;
+	// Keep four weeks of served coretime assignments.
+	pub const CoretimeAssignmentHistoryDepth: u32 = 28 * DAYS / TIMESLICE_PERIOD;
+}
+
+impl parachains_assigner_coretime::Config for Runtime {
+	type TimeslicePeriod = CoretimeTimeslicePeriod;
+	type AssignmentHistoryDepth = CoretimeAssignmentHistoryDepth;
+}
+
+parameter_types! {
+	/// The payments of the paras are aggregated per day and kept for four weeks.
+	pub const ParaPaymentsPeriodLength: BlockNumber = DAYS;
+	pub const ParaPaymentsHistoryDepth: u32 = 28;
+}
+
+impl parachains_para_payments::Config for Runtime {
+	type PeriodLength = ParaPaymentsPeriodLength;
+	type HistoryDepth = ParaPaymentsHistoryDepth;
+}
 
 impl parachains_initializer::Config for Runtime {
 	type Randomness = pallet_babe::RandomnessFromOneEpochAgo<Runtime>;
@@ -1419,6 +1507,7 @@ impl pallet_asset_rate::Config for Runtime {
 	type UpdateOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type AssetKind = <Runtime as pallet_treasury::Config>::AssetKind;
+	type NativeDecimals = ConstU8<12>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::AssetRateArguments;
 }
@@ -1584,6 +1673,8 @@ mod runtime {
 	pub type OnDemandAssignmentProvider = parachains_assigner_on_demand;
 	#[runtime::pallet_index(57)]
 	pub type CoretimeAssignmentProvider = parachains_assigner_coretime;
+	#[runtime::pallet_index(58)]
+	pub type ParaPayments = parachains_para_payments;
 
 	// Parachain Onboarding Pallets. Start indices at 60 to leave room.
 	#[runtime::pallet_index(60)]
@@ -1701,6 +1792,10 @@ pub mod migrations {
 			MaxPoolsToMigrate,
 		>,
 		pallet_staking::migrations::v15::MigrateV14ToV15<Runtime>,
+		parachains_hrmp::migration::MigrateToV1<Runtime>,
+		pallet_asset_rate::migration::v1::MigrateV0ToV1<Runtime, frame_support::traits::GetDefault>,
+		pallet_scheduler::migration::v5::MigrateToV5<Runtime>,
+		pallet_multisig::migrations::v2::MigrateToV2<Runtime>,
 	);
 }
 
@@ -1792,6 +1887,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(4)]
 	impl sp_api::Metadata<Block> for Runtime {
 		fn metadata() -> OpaqueMetadata {
 			OpaqueMetadata::new(Runtime::metadata().into())
@@ -1804,6 +1900,16 @@ sp_api::impl_runtime_apis! {
 		fn metadata_versions() -> sp_std::vec::Vec<u32> {
 			Runtime::metadata_versions()
 		}
+
+		fn module_error_metadata(
+			error: sp_runtime::ModuleError,
+		) -> Option<sp_runtime::ModuleErrorMetadata> {
+			Runtime::module_error_metadata(error)
+		}
+
+		fn storage_layout_hash() -> [u8; 32] {
+			Runtime::storage_layout_hash()
+		}
 	}
 
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
@@ -1843,7 +1949,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
-	#[api_version(11)]
+	#[api_version(12)]
 	impl polkadot_primitives::runtime_api::ParachainHost<Block> for Runtime {
 		fn validators() -> Vec<ValidatorId> {
 			parachains_runtime_api_impl::validators::<Runtime>()
@@ -2007,6 +2113,14 @@ sp_api::impl_runtime_apis! {
 		fn candidates_pending_availability(para_id: ParaId) -> Vec<CommittedCandidateReceipt<Hash>> {
 			vstaging_parachains_runtime_api_impl::candidates_pending_availability::<Runtime>(para_id)
 		}
+
+		fn dmq_contents_page(
+			recipient: ParaId,
+			start: u32,
+			count: u32,
+		) -> Vec<InboundDownwardMessage<BlockNumber>> {
+			vstaging_parachains_runtime_api_impl::dmq_contents_page::<Runtime>(recipient, start, count)
+		}
 	}
 
 	impl sp_consensus_beefy::BeefyApi<Block, BeefyId> for Runtime {
@@ -2187,6 +2301,16 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl sp_consensus_babe::BabeAuthoringStatsApi<Block> for Runtime {
+		fn current_epoch_authoring_stats() -> sp_consensus_babe::EpochAuthoringStats {
+			Babe::current_epoch_authoring_stats()
+		}
+
+		fn previous_epoch_authoring_stats() -> Option<sp_consensus_babe::EpochAuthoringStats> {
+			Babe::previous_epoch_authoring_stats()
+		}
+	}
+
 	impl sp_authority_discovery::AuthorityDiscoveryApi<Block> for Runtime {
 		fn authorities() -> Vec<AuthorityDiscoveryId> {
 			parachains_runtime_api_impl::relevant_authority_ids::<Runtime>()
@@ -2211,6 +2335,72 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl frame_system_rpc_runtime_api::BlockDigestApi<Block, AccountId> for Runtime {
+		fn block_author() -> Option<AccountId> {
+			Babe::block_author()
+		}
+
+		fn pre_runtime_digests() -> Vec<frame_system_rpc_runtime_api::PreRuntimeDigest<AccountId>> {
+			Babe::pre_runtime_digests()
+		}
+	}
+
+	impl pallet_multisig::runtime_api::MultisigApi<Block, AccountId> for Runtime {
+		fn multi_account_id(signatories: Vec<AccountId>, threshold: u16) -> AccountId {
+			Multisig::derive_multi_account_id(signatories, threshold)
+		}
+	}
+
+	impl pallet_proxy::runtime_api::ProxyApi<Block, AccountId, ProxyType, BlockNumber, Balance> for Runtime {
+		fn namespaced_pure_account(
+			spawner: AccountId,
+			namespace: pallet_proxy::PureNamespace,
+			index: u16,
+		) -> AccountId {
+			Proxy::namespaced_pure_account(&spawner, namespace, index)
+		}
+
+		fn pure_proxies(
+			spawner: AccountId,
+			namespace: pallet_proxy::PureNamespace,
+		) -> Vec<(u16, pallet_proxy::PureProxyInfo<AccountId, ProxyType, BlockNumber, Balance>)> {
+			Proxy::pure_proxies(&spawner, namespace)
+		}
+	}
+
+	impl pallet_message_queue::runtime_api::MessageQueueApi<Block, AggregateMessageOrigin> for Runtime {
+		fn reapable_pages(limit: u32) -> Vec<(AggregateMessageOrigin, u32)> {
+			MessageQueue::reapable_pages(limit)
+		}
+	}
+
+	impl pallet_scheduler::runtime_api::SchedulerApi<Block, BlockNumber, Hash> for Runtime {
+		fn lookup(
+			id: frame_support::traits::schedule::v3::TaskName,
+		) -> Option<pallet_scheduler::TaskSummary<BlockNumber, Hash>> {
+			Scheduler::lookup(id)
+		}
+
+		fn agenda(when: BlockNumber) -> Vec<pallet_scheduler::TaskSummary<BlockNumber, Hash>> {
+			Scheduler::agenda(when)
+		}
+
+		fn next_dispatch(
+			id: frame_support::traits::schedule::v3::TaskName,
+		) -> Option<BlockNumber> {
+			Scheduler::next_dispatch(id)
+		}
+
+		fn agenda_occupancy(when: BlockNumber) -> pallet_scheduler::AgendaOccupancy {
+			Scheduler::agenda_occupancy(when)
+		}
+
+		fn total_scheduled() -> u32 {
+			Scheduler::total_scheduled()
+		}
+	}
+
+	#[api_version(5)]
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -2227,6 +2417,14 @@ sp_api::impl_runtime_apis! {
 		fn query_length_to_fee(length: u32) -> Balance {
 			TransactionPayment::length_to_fee(length)
 		}
+		fn query_priority_info(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			tip: Balance,
+			target_priority: sp_runtime::transaction_validity::TransactionPriority,
+		) -> pallet_transaction_payment::PriorityInfo<Balance> {
+			TransactionPayment::query_priority_info(uxt, len, tip, target_priority)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
@@ -2278,6 +2476,51 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl parachains_para_payments::ParaPaymentsApi<Block, BlockNumber> for Runtime {
+		fn para_payment_statement(
+			para: ParaId,
+			from: BlockNumber,
+			to: BlockNumber,
+		) -> Option<parachains_para_payments::ParaPaymentStatement<BlockNumber>> {
+			ParaPayments::payment_statement(para, from, to)
+		}
+	}
+
+	impl parachains_configuration::ConfigurationApi<Block, BlockNumber> for Runtime {
+		fn pending_configs(
+		) -> Vec<(SessionIndex, parachains_configuration::HostConfiguration<BlockNumber>)> {
+			Configuration::pending_configs()
+		}
+	}
+
+	impl parachains_assigner_coretime::CoretimeAssignmentHistoryApi<Block> for Runtime {
+		fn served_assignments(
+			core: CoreIndex,
+			timeslice: parachains_assigner_coretime::Timeslice,
+		) -> Option<Vec<(ParaId, u32)>> {
+			CoretimeAssignmentProvider::served_assignments(core, timeslice)
+		}
+
+		fn served_assignments_key(
+			core: CoreIndex,
+			timeslice: parachains_assigner_coretime::Timeslice,
+			para: ParaId,
+		) -> Vec<u8> {
+			CoretimeAssignmentProvider::served_assignments_key(core, timeslice, para)
+		}
+	}
+
+	impl polkadot_runtime_common::xcm_sender::DeliveryFeeFactorApi<Block> for Runtime {
+		fn delivery_fee_factor(destination: VersionedLocation) -> Option<polkadot_runtime_common::xcm_sender::DeliveryFeeFactor> {
+			let destination = Location::try_from(destination).ok()?;
+			polkadot_runtime_common::xcm_sender::ChildParachainRouter::<
+				Runtime,
+				XcmPallet,
+				xcm_config::PriceForChildParachainDelivery,
+			>::delivery_fee_factor(&destination)
+		}
+	}
+
 	impl xcm_runtime_apis::dry_run::DryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for Runtime {
 		fn dry_run_call(origin: OriginCaller, call: RuntimeCall) -> Result<CallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
 			XcmPallet::dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(origin, call)
@@ -2288,6 +2531,18 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_runtime_apis::transfers::TransferValidationApi<Block> for Runtime {
+		fn validate_transfer(
+			origin: VersionedLocation,
+			dest: VersionedLocation,
+			beneficiary: VersionedLocation,
+			assets: VersionedAssets,
+			fee_asset_item: u32,
+		) -> Vec<xcm_runtime_apis::transfers::TransferFailure> {
+			XcmPallet::validate_transfer(origin, dest, beneficiary, assets, fee_asset_item)
+		}
+	}
+
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
 		fn convert_location(location: VersionedLocation) -> Result<
 			AccountId,
@@ -2348,6 +2603,18 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_delegated_staking::runtime_api::DelegatedStakingApi<Block, AccountId, Balance> for Runtime {
+		fn delegation_voting_balance(
+			who: AccountId,
+		) -> pallet_delegated_staking::DelegationVotingBalance<Balance> {
+			DelegatedStaking::delegation_voting_balance(&who)
+		}
+
+		fn effective_voting_balance(who: AccountId) -> Balance {
+			DelegatedStaking::effective_voting_balance(&who)
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
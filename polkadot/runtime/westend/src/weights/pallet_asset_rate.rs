@@ -73,12 +73,28 @@ impl<T: frame_system::Config> pallet_asset_rate::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: AssetRate ConversionRateToNative (r:1 w:1)
 	/// Proof: AssetRate ConversionRateToNative (max_values: None, max_size: Some(1237), added: 3712, mode: MaxEncodedLen)
+	/// Storage: AssetRate AssetDecimals (r:0 w:1)
+	/// Proof: AssetRate AssetDecimals (max_values: None, max_size: Some(1222), added: 3697, mode: MaxEncodedLen)
 	fn remove() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `110`
 		//  Estimated: `4702`
 		// Minimum execution time: 70_000_000 picoseconds.
 		Weight::from_parts(90_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4702))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: AssetRate ConversionRateToNative (r:1 w:0)
+	/// Proof: AssetRate ConversionRateToNative (max_values: None, max_size: Some(1237), added: 3712, mode: MaxEncodedLen)
+	/// Storage: AssetRate AssetDecimals (r:0 w:1)
+	/// Proof: AssetRate AssetDecimals (max_values: None, max_size: Some(1222), added: 3697, mode: MaxEncodedLen)
+	fn set_decimals() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `110`
+		//  Estimated: `4702`
+		// Minimum execution time: 63_000_000 picoseconds.
+		Weight::from_parts(81_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4702))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
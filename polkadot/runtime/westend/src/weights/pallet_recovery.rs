@@ -124,6 +124,10 @@ impl<T: frame_system::Config> pallet_recovery::WeightInfo for WeightInfo<T> {
 	/// Proof: Recovery ActiveRecoveries (max_values: None, max_size: Some(389), added: 2864, mode: MaxEncodedLen)
 	/// Storage: Recovery Proxy (r:1 w:1)
 	/// Proof: Recovery Proxy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Recovery ContestedRecoveries (r:1 w:0)
+	/// Proof: Recovery ContestedRecoveries (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Recovery RecoveryVetoes (r:1 w:0)
+	/// Proof: Recovery RecoveryVetoes (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 9]`.
 	fn claim_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -134,13 +138,15 @@ impl<T: frame_system::Config> pallet_recovery::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 3854))
 			// Standard Error: 7_089
 			.saturating_add(Weight::from_parts(95_688, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: Recovery ActiveRecoveries (r:1 w:1)
 	/// Proof: Recovery ActiveRecoveries (max_values: None, max_size: Some(389), added: 2864, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Recovery ContestedRecoveries (r:0 w:1)
+	/// Proof: Recovery ContestedRecoveries (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 9]`.
 	fn close_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -152,12 +158,14 @@ impl<T: frame_system::Config> pallet_recovery::WeightInfo for WeightInfo<T> {
 			// Standard Error: 5_883
 			.saturating_add(Weight::from_parts(109_238, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Recovery ActiveRecoveries (r:1 w:0)
 	/// Proof: Recovery ActiveRecoveries (max_values: None, max_size: Some(389), added: 2864, mode: MaxEncodedLen)
 	/// Storage: Recovery Recoverable (r:1 w:1)
 	/// Proof: Recovery Recoverable (max_values: None, max_size: Some(351), added: 2826, mode: MaxEncodedLen)
+	/// Storage: Recovery RecoveryVetoes (r:0 w:1)
+	/// Proof: Recovery RecoveryVetoes (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 9]`.
 	fn remove_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -169,7 +177,7 @@ impl<T: frame_system::Config> pallet_recovery::WeightInfo for WeightInfo<T> {
 			// Standard Error: 8_762
 			.saturating_add(Weight::from_parts(113_056, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Recovery Proxy (r:1 w:1)
 	/// Proof: Recovery Proxy (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
@@ -183,4 +191,39 @@ impl<T: frame_system::Config> pallet_recovery::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Recovery Recoverable (r:1 w:0)
+	/// Proof: Recovery Recoverable (max_values: None, max_size: Some(351), added: 2826, mode: MaxEncodedLen)
+	/// Storage: Recovery RecoveryVetoes (r:0 w:1)
+	/// Proof: Recovery RecoveryVetoes (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	fn set_recovery_veto() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `341`
+		//  Estimated: `3816`
+		// Minimum execution time: 13_602_000 picoseconds.
+		Weight::from_parts(14_102_000, 0)
+			.saturating_add(Weight::from_parts(0, 3816))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Recovery Recoverable (r:1 w:0)
+	/// Proof: Recovery Recoverable (max_values: None, max_size: Some(351), added: 2826, mode: MaxEncodedLen)
+	/// Storage: Recovery RecoveryVetoes (r:1 w:0)
+	/// Proof: Recovery RecoveryVetoes (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	/// Storage: Recovery ActiveRecoveries (r:1 w:0)
+	/// Proof: Recovery ActiveRecoveries (max_values: None, max_size: Some(389), added: 2864, mode: MaxEncodedLen)
+	/// Storage: Recovery ContestedRecoveries (r:1 w:1)
+	/// Proof: Recovery ContestedRecoveries (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 9]`.
+	fn veto_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `497 + n * (64 ±0)`
+		//  Estimated: `3854`
+		// Minimum execution time: 20_713_000 picoseconds.
+		Weight::from_parts(21_384_117, 0)
+			.saturating_add(Weight::from_parts(0, 3854))
+			// Standard Error: 5_917
+			.saturating_add(Weight::from_parts(112_405, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -283,4 +283,324 @@ impl<T: frame_system::Config> pallet_scheduler::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Scheduler::Agenda` (r:1 w:0)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Paused` (r:1 w:1)
+	/// Proof: `Scheduler::Paused` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `90705`
+		//  Estimated: `42428`
+		// Minimum execution time: 14_562_000 picoseconds.
+		Weight::from_parts(14_562_000, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:0)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:0)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Paused` (r:1 w:1)
+	/// Proof: `Scheduler::Paused` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn pause_named() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `91747`
+		//  Estimated: `42428`
+		// Minimum execution time: 16_348_000 picoseconds.
+		Weight::from_parts(16_348_000, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::Agenda` (r:2 w:2)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Paused` (r:1 w:1)
+	/// Proof: `Scheduler::Paused` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:1 w:2)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:0 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 50]`.
+	fn resume(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `81 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 19_870_000 picoseconds.
+		Weight::from_parts(19_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 1_021
+			.saturating_add(Weight::from_parts(412_315, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:2 w:2)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Paused` (r:1 w:1)
+	/// Proof: `Scheduler::Paused` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:1 w:2)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 50]`.
+	fn resume_named(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `709 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 22_148_000 picoseconds.
+		Weight::from_parts(22_148_000, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 1_021
+			.saturating_add(Weight::from_parts(431_902, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `Scheduler::Agenda` (r:1 w:0)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:1 w:0)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::RetryBackoffs` (r:0 w:1)
+	/// Proof: `Scheduler::RetryBackoffs` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	fn set_retry_backoff() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `166 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 9_402_000 picoseconds.
+		Weight::from_parts(9_918_311, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:0)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:0)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:1 w:0)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::RetryBackoffs` (r:0 w:1)
+	/// Proof: `Scheduler::RetryBackoffs` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	fn set_retry_backoff_named() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `226 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 10_247_000 picoseconds.
+		Weight::from_parts(10_766_920, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::Lookup` (r:2 w:0)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::DependsOn` (r:1 w:1)
+	/// Proof: `Scheduler::DependsOn` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::ScheduledCount` (r:1 w:1)
+	/// Proof: `Scheduler::ScheduledCount` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Dependants` (r:0 w:1)
+	/// Proof: `Scheduler::Dependants` (`max_values`: None, `max_size`: Some(364), added: 2839, mode: `MaxEncodedLen`)
+	fn schedule_named_after_task() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `246`
+		//  Estimated: `8559`
+		// Minimum execution time: 12_736_000 picoseconds.
+		Weight::from_parts(13_294_000, 0)
+			.saturating_add(Weight::from_parts(0, 8559))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Scheduler::Overflow` (r:1 w:1)
+	/// Proof: `Scheduler::Overflow` (`max_values`: None, `max_size`: Some(330), added: 2805, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::CounterForOverflow` (r:1 w:1)
+	/// Proof: `Scheduler::CounterForOverflow` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::DependsOn` (r:1 w:0)
+	/// Proof: `Scheduler::DependsOn` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:0 w:1)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::RetryBackoffs` (r:0 w:1)
+	/// Proof: `Scheduler::RetryBackoffs` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 49]`.
+	fn reschedule_overflowed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `344 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 13_107_000 picoseconds.
+		Weight::from_parts(14_262_515, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(197_483, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `Scheduler::Overflow` (r:1 w:1)
+	/// Proof: `Scheduler::Overflow` (`max_values`: None, `max_size`: Some(330), added: 2805, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::CounterForOverflow` (r:1 w:1)
+	/// Proof: `Scheduler::CounterForOverflow` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::ScheduledCount` (r:1 w:1)
+	/// Proof: `Scheduler::ScheduledCount` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	fn cancel_overflowed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3795`
+		// Minimum execution time: 14_381_000 picoseconds.
+		Weight::from_parts(15_027_000, 0)
+			.saturating_add(Weight::from_parts(0, 3795))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Scheduler::Agenda` (r:101 w:100)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Dependants` (r:5000 w:0)
+	/// Proof: `Scheduler::Dependants` (`max_values`: None, `max_size`: Some(339), added: 2814, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Overflow` (r:1 w:0)
+	/// Proof: `Scheduler::Overflow` (`max_values`: None, `max_size`: Some(330), added: 2805, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Reservations` (r:5000 w:5000)
+	/// Proof: `Scheduler::Reservations` (`max_values`: None, `max_size`: Some(155), added: 2630, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:0 w:5000)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:0 w:5000)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::RetryBackoffs` (r:0 w:5000)
+	/// Proof: `Scheduler::RetryBackoffs` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Paused` (r:0 w:5000)
+	/// Proof: `Scheduler::Paused` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::DispatchMoments` (r:0 w:5000)
+	/// Proof: `Scheduler::DispatchMoments` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Recurrences` (r:0 w:5000)
+	/// Proof: `Scheduler::Recurrences` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 100]`.
+	fn cancel_all_for_origin(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + a * (8890 ±0)`
+		//  Estimated: `3795 + a * (41438 ±0)`
+		// Minimum execution time: 10_212_000 picoseconds.
+		Weight::from_parts(11_034_000, 0)
+			.saturating_add(Weight::from_parts(0, 3795))
+			// Standard Error: 48_362
+			.saturating_add(Weight::from_parts(460_700_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((101_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((351_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 41438).saturating_mul(a.into()))
+	}
+	/// Storage: `Scheduler::Agenda` (r:1 w:0)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Recurrences` (r:0 w:1)
+	/// Proof: `Scheduler::Recurrences` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	fn set_recurrence() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `166 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 8_743_000 picoseconds.
+		Weight::from_parts(9_224_029, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:0)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:0)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Recurrences` (r:0 w:1)
+	/// Proof: `Scheduler::Recurrences` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	fn set_recurrence_named() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `226 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 9_529_000 picoseconds.
+		Weight::from_parts(10_013_235, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::Agenda` (r:2 w:2)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::DispatchMoments` (r:1 w:2)
+	/// Proof: `Scheduler::DispatchMoments` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Paused` (r:1 w:1)
+	/// Proof: `Scheduler::Paused` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Reservations` (r:1 w:2)
+	/// Proof: `Scheduler::Reservations` (`max_values`: None, `max_size`: Some(155), added: 2630, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Recurrences` (r:1 w:2)
+	/// Proof: `Scheduler::Recurrences` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:1 w:2)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::RetryBackoffs` (r:1 w:2)
+	/// Proof: `Scheduler::RetryBackoffs` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 50]`.
+	fn reschedule(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `120 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 21_903_000 picoseconds.
+		Weight::from_parts(21_903_000, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 1_084
+			.saturating_add(Weight::from_parts(417_262, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(14))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:2 w:2)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::DispatchMoments` (r:1 w:2)
+	/// Proof: `Scheduler::DispatchMoments` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Paused` (r:1 w:1)
+	/// Proof: `Scheduler::Paused` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Reservations` (r:1 w:2)
+	/// Proof: `Scheduler::Reservations` (`max_values`: None, `max_size`: Some(155), added: 2630, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Recurrences` (r:1 w:2)
+	/// Proof: `Scheduler::Recurrences` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:1 w:2)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::RetryBackoffs` (r:1 w:2)
+	/// Proof: `Scheduler::RetryBackoffs` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Dependants` (r:1 w:0)
+	/// Proof: `Scheduler::Dependants` (`max_values`: None, `max_size`: Some(364), added: 2839, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 50]`.
+	fn reschedule_named(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `748 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 24_315_000 picoseconds.
+		Weight::from_parts(24_315_000, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 1_084
+			.saturating_add(Weight::from_parts(436_958, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(15))
+	}
+	/// Storage: `Scheduler::Agenda` (r:1 w:0)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::ValidUntil` (r:0 w:1)
+	/// Proof: `Scheduler::ValidUntil` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn set_valid_until() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `166 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 8_743_000 picoseconds.
+		Weight::from_parts(9_224_029, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:0)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:0)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::ValidUntil` (r:0 w:1)
+	/// Proof: `Scheduler::ValidUntil` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn set_valid_until_named() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `226 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 9_529_000 picoseconds.
+		Weight::from_parts(10_013_235, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
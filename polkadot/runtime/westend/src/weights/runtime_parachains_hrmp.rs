@@ -254,6 +254,32 @@ impl<T: frame_system::Config> polkadot_runtime_parachains::hrmp::WeightInfo for
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2538).saturating_mul(c.into()))
 	}
+	/// Storage: `ParasShared::CurrentSessionIndex` (r:1 w:0)
+	/// Proof: `ParasShared::CurrentSessionIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpOpenChannelRequestsList` (r:1 w:1)
+	/// Proof: `Hrmp::HrmpOpenChannelRequestsList` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpOpenChannelRequests` (r:128 w:128)
+	/// Proof: `Hrmp::HrmpOpenChannelRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpOpenChannelRequestCount` (r:128 w:128)
+	/// Proof: `Hrmp::HrmpOpenChannelRequestCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:128 w:128)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 128]`.
+	fn clean_expired_open_requests(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297 + c * (166 ±0)`
+		//  Estimated: `1782 + c * (2641 ±0)`
+		// Minimum execution time: 5_102_000 picoseconds.
+		Weight::from_parts(5_377_000, 0)
+			.saturating_add(Weight::from_parts(0, 1782))
+			// Standard Error: 9_874
+			.saturating_add(Weight::from_parts(11_693_518, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2641).saturating_mul(c.into()))
+	}
 	/// Storage: `Hrmp::HrmpOpenChannelRequests` (r:1 w:1)
 	/// Proof: `Hrmp::HrmpOpenChannelRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Hrmp::HrmpOpenChannelRequestsList` (r:1 w:1)
@@ -366,4 +392,29 @@ impl<T: frame_system::Config> polkadot_runtime_parachains::hrmp::WeightInfo for
 			.saturating_add(T::DbWeight::get().reads(21))
 			.saturating_add(T::DbWeight::get().writes(11))
 	}
+	/// Storage: `Hrmp::HrmpIngressChannelsIndex` (r:128 w:128)
+	/// Proof: `Hrmp::HrmpIngressChannelsIndex` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpEgressChannelsIndex` (r:128 w:128)
+	/// Proof: `Hrmp::HrmpEgressChannelsIndex` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hrmp::HrmpChannels` (r:127 w:127)
+	/// Proof: `Hrmp::HrmpChannels` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:128 w:128)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Hrmp::HrmpChannelContents` (r:0 w:127)
+	/// Proof: `Hrmp::HrmpChannelContents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 127]`.
+	fn force_close_para_channels(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `255 + c * (208 ±0)`
+		//  Estimated: `3726 + c * (5236 ±0)`
+		// Minimum execution time: 17_503_000 picoseconds.
+		Weight::from_parts(18_121_000, 0)
+			.saturating_add(Weight::from_parts(0, 3726))
+			// Standard Error: 31_852
+			.saturating_add(Weight::from_parts(41_372_417, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 5236).saturating_mul(c.into()))
+	}
 }
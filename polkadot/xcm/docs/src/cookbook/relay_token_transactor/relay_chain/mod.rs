@@ -91,6 +91,7 @@ impl pallet_message_queue::Config for Runtime {
 	type QueuePausedQuery = ();
 	type WeightInfo = ();
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type OnPageReaped = ();
 }
 
 construct_runtime! {
@@ -386,6 +386,50 @@ benchmarks! {
 		let versioned_assets = VersionedAssets::V4(asset.into());
 	}: _<RuntimeOrigin<T>>(claim_origin.into(), Box::new(versioned_assets), Box::new(VersionedLocation::V4(claim_location)))
 
+	register_fee_sponsor {
+		let sponsor = Location::new(0, [AccountId32 { network: None, id: [1u8; 32] }]);
+	}: _(RawOrigin::Root, Box::new(sponsor), Box::new(AssetId(Here.into())), u128::MAX, 10u32.into())
+
+	deregister_fee_sponsor {
+		let sponsor = Location::new(0, [AccountId32 { network: None, id: [1u8; 32] }]);
+		assert_ok!(crate::Pallet::<T>::register_fee_sponsor(
+			RawOrigin::Root.into(),
+			Box::new(sponsor.clone()),
+			Box::new(AssetId(Here.into())),
+			u128::MAX,
+			10u32.into(),
+		));
+	}: _(RawOrigin::Root, Box::new(sponsor), Box::new(AssetId(Here.into())))
+
+	set_fee_sponsored_origin {
+		let sponsor = Location::new(0, [AccountId32 { network: None, id: [1u8; 32] }]);
+		let sponsored = Location::new(0, [AccountId32 { network: None, id: [2u8; 32] }]);
+		assert_ok!(crate::Pallet::<T>::register_fee_sponsor(
+			RawOrigin::Root.into(),
+			Box::new(sponsor.clone()),
+			Box::new(AssetId(Here.into())),
+			u128::MAX,
+			10u32.into(),
+		));
+	}: _(RawOrigin::Root, Box::new(sponsored), Some(Box::new(sponsor)))
+
+	execute_sandboxed {
+		let caller: T::AccountId = whitelisted_caller();
+		let execute_origin: RuntimeOrigin<T> = RawOrigin::Signed(caller).into();
+		if T::ExecuteXcmOrigin::try_origin(execute_origin.clone()).is_err() {
+			return Err(BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))
+		}
+		assert_ok!(crate::Pallet::<T>::set_sandbox_limits(
+			RawOrigin::Root.into(),
+			Some(SandboxLimits { weight_budget: Weight::MAX, period: 10u32.into() }),
+		));
+		let versioned_msg = VersionedXcm::from(Xcm(vec![ClearOrigin]));
+	}: _<RuntimeOrigin<T>>(execute_origin, Box::new(versioned_msg), Weight::MAX)
+
+	set_sandbox_limits {
+		let limits = SandboxLimits { weight_budget: Weight::MAX, period: 10u32.into() };
+	}: _(RawOrigin::Root, Some(limits))
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext_with_balances(Vec::new()),
@@ -33,6 +33,7 @@ use frame_support::{
 		DispatchErrorWithPostInfo, GetDispatchInfo, PostDispatchInfo, WithPostDispatchInfo,
 	},
 	pallet_prelude::*,
+	storage::{with_transaction, TransactionOutcome},
 	traits::{
 		Contains, ContainsPair, Currency, Defensive, EnsureOrigin, Get, LockableCurrency,
 		OriginTrait, WithdrawReasons,
@@ -67,6 +68,7 @@ use xcm_executor::{
 use xcm_runtime_apis::{
 	dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
 	fees::Error as XcmPaymentApiError,
+	transfers::TransferFailure,
 };
 
 #[cfg(any(feature = "try-runtime", test))]
@@ -93,6 +95,11 @@ pub trait WeightInfo {
 	fn new_query() -> Weight;
 	fn take_response() -> Weight;
 	fn claim_assets() -> Weight;
+	fn register_fee_sponsor() -> Weight;
+	fn deregister_fee_sponsor() -> Weight;
+	fn set_fee_sponsored_origin() -> Weight;
+	fn execute_sandboxed() -> Weight;
+	fn set_sandbox_limits() -> Weight;
 }
 
 /// fallback implementation
@@ -177,6 +184,26 @@ impl WeightInfo for TestWeightInfo {
 	fn claim_assets() -> Weight {
 		Weight::from_parts(100_000_000, 0)
 	}
+
+	fn register_fee_sponsor() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
+
+	fn deregister_fee_sponsor() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
+
+	fn set_fee_sponsored_origin() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
+
+	fn execute_sandboxed() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
+
+	fn set_sandbox_limits() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
 }
 
 #[frame_support::pallet]
@@ -264,6 +291,9 @@ pub mod pallet {
 
 		const VERSION_DISCOVERY_QUEUE_SIZE: u32;
 
+		/// The number of most recently sent topic ids which are kept in [`SentTopics`].
+		const SENT_TOPICS_CAPACITY: u32 = 1_000;
+
 		/// The latest supported version that we advertise. Generally just set it to
 		/// `pallet_xcm::CurrentXcmVersion`.
 		type AdvertisedXcmVersion: Get<XcmVersion>;
@@ -340,7 +370,7 @@ pub mod pallet {
 
 	impl<T: Config> SendControllerWeightInfo for Pallet<T> {
 		fn send() -> Weight {
-			T::WeightInfo::send()
+			T::WeightInfo::send().saturating_add(Self::note_sent_topic_weight())
 		}
 	}
 
@@ -359,7 +389,14 @@ pub mod pallet {
 
 			let message_id = Self::send_xcm(interior, dest.clone(), message.clone())
 				.map_err(Error::<T>::from)?;
-			let e = Event::Sent { origin: origin_location, destination: dest, message, message_id };
+			let topic_id = Self::note_sent_topic(&origin_location, &dest, &message, message_id);
+			let e = Event::Sent {
+				origin: origin_location,
+				destination: dest,
+				message,
+				message_id,
+				topic_id,
+			};
 			Self::deposit_event(e);
 			Ok(message_id)
 		}
@@ -400,7 +437,16 @@ pub mod pallet {
 		/// Execution of an XCM message was attempted.
 		Attempted { outcome: xcm::latest::Outcome },
 		/// A XCM message was sent.
-		Sent { origin: Location, destination: Location, message: Xcm<()>, message_id: XcmHash },
+		///
+		/// `topic_id` is the topic the message was sent with, by which it can be traced on the
+		/// destination, see [`SentTopics`].
+		Sent {
+			origin: Location,
+			destination: Location,
+			message: Xcm<()>,
+			message_id: XcmHash,
+			topic_id: XcmHash,
+		},
 		/// Query response received which does not match a registered query. This may be because a
 		/// matching query was never registered, it may be because it is a duplicate response, or
 		/// because the query timed out.
@@ -450,12 +496,14 @@ pub mod pallet {
 		AssetsTrapped { hash: H256, origin: Location, assets: VersionedAssets },
 		/// An XCM version change notification message has been attempted to be sent.
 		///
-		/// The cost of sending it (borne by the chain) is included.
+		/// The cost of sending it (borne by the chain) is included, as is the `topic_id` it was
+		/// sent with.
 		VersionChangeNotified {
 			destination: Location,
 			result: XcmVersion,
 			cost: Assets,
 			message_id: XcmHash,
+			topic_id: XcmHash,
 		},
 		/// The supported version of a location has been changed. This might be through an
 		/// automatic notification or a manual intervention.
@@ -484,19 +532,50 @@ pub mod pallet {
 			maybe_actual_querier: Option<Location>,
 		},
 		/// A remote has requested XCM version change notification from us and we have honored it.
-		/// A version information message is sent to them and its cost is included.
-		VersionNotifyStarted { destination: Location, cost: Assets, message_id: XcmHash },
+		/// A version information message is sent to them and its cost and topic id are included.
+		VersionNotifyStarted {
+			destination: Location,
+			cost: Assets,
+			message_id: XcmHash,
+			topic_id: XcmHash,
+		},
 		/// We have requested that a remote chain send us XCM version change notifications.
-		VersionNotifyRequested { destination: Location, cost: Assets, message_id: XcmHash },
+		VersionNotifyRequested {
+			destination: Location,
+			cost: Assets,
+			message_id: XcmHash,
+			topic_id: XcmHash,
+		},
 		/// We have requested that a remote chain stops sending us XCM version change
 		/// notifications.
-		VersionNotifyUnrequested { destination: Location, cost: Assets, message_id: XcmHash },
+		VersionNotifyUnrequested {
+			destination: Location,
+			cost: Assets,
+			message_id: XcmHash,
+			topic_id: XcmHash,
+		},
 		/// Fees were paid from a location for an operation (often for using `SendXcm`).
 		FeesPaid { paying: Location, fees: Assets },
 		/// Some assets have been claimed from an asset trap
 		AssetsClaimed { hash: H256, origin: Location, assets: VersionedAssets },
 		/// A XCM version migration finished.
 		VersionMigrationFinished { version: XcmVersion },
+		/// A location has been registered as a sponsor of delivery fees, spending up to `budget`
+		/// of the asset `asset_id` every `period` blocks.
+		FeeSponsorRegistered {
+			sponsor: Location,
+			asset_id: AssetId,
+			budget: u128,
+			period: BlockNumberFor<T>,
+		},
+		/// A location no longer sponsors delivery fees paid in the asset `asset_id`.
+		FeeSponsorDeregistered { sponsor: Location, asset_id: AssetId },
+		/// The delivery fees of messages sent by `origin` are now paid by `sponsor`, or by the
+		/// origin itself if `None`.
+		FeeSponsoredOriginSet { origin: Location, sponsor: Option<Location> },
+		/// The limits of the sandboxed execution of XCM programs have been set, or the sandboxed
+		/// execution has been disabled if `None`.
+		SandboxLimitsSet { limits: Option<SandboxLimits<BlockNumberFor<T>>> },
 	}
 
 	#[pallet::origin]
@@ -570,6 +649,25 @@ pub mod pallet {
 		/// Local XCM execution incomplete.
 		#[codec(index = 24)]
 		LocalExecutionIncomplete,
+		/// The location is not a registered sponsor of delivery fees.
+		#[codec(index = 25)]
+		UnknownFeeSponsor,
+		/// The budget period of a fee sponsor must not be zero.
+		#[codec(index = 26)]
+		ZeroFeeSponsorPeriod,
+		/// The sandboxed execution of XCM programs is disabled.
+		#[codec(index = 27)]
+		SandboxDisabled,
+		/// The XCM program contains an instruction which is not allowed in sandboxed execution.
+		#[codec(index = 28)]
+		SandboxFiltered,
+		/// The weight of the XCM program exceeds what is left of the sandbox budget of the account
+		/// for the current period.
+		#[codec(index = 29)]
+		SandboxBudgetExceeded,
+		/// The budget period of the sandboxed execution must not be zero.
+		#[codec(index = 30)]
+		ZeroSandboxPeriod,
 	}
 
 	impl<T: Config> From<SendError> for Error<T> {
@@ -590,6 +688,25 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> From<Error<T>> for TransferFailure {
+		fn from(e: Error<T>) -> Self {
+			match e {
+				Error::<T>::BadVersion => TransferFailure::VersionedConversionFailed,
+				Error::<T>::TooManyAssets => TransferFailure::TooManyAssets,
+				Error::<T>::Empty => TransferFailure::Empty,
+				Error::<T>::InvalidAssetUnknownReserve => TransferFailure::UnknownReserve,
+				Error::<T>::InvalidAssetUnsupportedReserve | Error::<T>::TooManyReserves =>
+					TransferFailure::UnsupportedReserves,
+				Error::<T>::Filtered => TransferFailure::Filtered,
+				Error::<T>::CannotReanchor => TransferFailure::CannotReanchor,
+				Error::<T>::UnweighableMessage => TransferFailure::UnweighableMessage,
+				Error::<T>::Unreachable | Error::<T>::SendFailure => TransferFailure::Unroutable,
+				Error::<T>::FeesNotMet => TransferFailure::DeliveryFeesNotMet,
+				_ => TransferFailure::Other,
+			}
+		}
+	}
+
 	/// The status of a query.
 	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 	pub enum QueryStatus<BlockNumber> {
@@ -789,6 +906,104 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type RecordedXcm<T: Config> = StorageValue<_, Xcm<()>>;
 
+	/// The budget of a sponsor of delivery fees in a single asset.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct FeeSponsorship<BlockNumber> {
+		/// The total amount of delivery fees in the asset the sponsor pays within a period.
+		pub budget: u128,
+		/// The length of a budget period, in blocks.
+		pub period: BlockNumber,
+		/// The block at which the current budget period started.
+		pub period_start: BlockNumber,
+		/// The amount of delivery fees paid by the sponsor within the current period.
+		pub spent: u128,
+	}
+
+	/// Locations which pay the delivery fees of the messages sent by sponsored origins, with
+	/// their budget in each asset the fees may be paid in.
+	#[pallet::storage]
+	pub(super) type FeeSponsors<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		VersionedLocation,
+		Blake2_128Concat,
+		VersionedAssetId,
+		FeeSponsorship<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The sponsor of the delivery fees of the messages sent by a given origin.
+	#[pallet::storage]
+	pub(super) type FeeSponsoredOrigins<T: Config> =
+		StorageMap<_, Blake2_128Concat, VersionedLocation, VersionedLocation, OptionQuery>;
+
+	/// A message sent with a topic id, as kept in [`SentTopics`].
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct SentTopic<BlockNumber> {
+		/// The origin which sent the message.
+		pub origin: VersionedLocation,
+		/// The destination of the message.
+		pub destination: VersionedLocation,
+		/// The block in which the message was sent.
+		pub block: BlockNumber,
+		/// The slot of [`SentTopicSlots`] holding the topic id.
+		pub slot: u32,
+	}
+
+	/// The origins, destinations and blocks of the most recently sent messages, keyed by their
+	/// topic id.
+	///
+	/// Allows correlating a message sent from here with the reports of its execution on the
+	/// destination. Only the last [`Config::SENT_TOPICS_CAPACITY`] topic ids are kept.
+	#[pallet::storage]
+	pub(super) type SentTopics<T: Config> =
+		StorageMap<_, Blake2_128Concat, XcmHash, SentTopic<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The ring of the topic ids kept in [`SentTopics`], keyed by slot.
+	///
+	/// The topic id in the slot [`NextSentTopicSlot`] is the oldest one and is the next to be
+	/// dropped.
+	#[pallet::storage]
+	pub(super) type SentTopicSlots<T: Config> =
+		StorageMap<_, Twox64Concat, u32, XcmHash, OptionQuery>;
+
+	/// The slot of [`SentTopicSlots`] in which the next topic id is recorded.
+	#[pallet::storage]
+	pub(super) type NextSentTopicSlot<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The limits of the sandboxed execution of XCM programs by signed origins.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct SandboxLimits<BlockNumber> {
+		/// The total weight an account may use for sandboxed execution within a period.
+		pub weight_budget: Weight,
+		/// The length of a budget period, in blocks.
+		pub period: BlockNumber,
+	}
+
+	/// The weight an account used for sandboxed execution within its current budget period.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct SandboxUsage<BlockNumber> {
+		/// The block at which the current budget period started.
+		pub period_start: BlockNumber,
+		/// The weight used within the current period.
+		pub used: Weight,
+	}
+
+	/// The limits of the sandboxed execution of XCM programs. It is disabled if unset.
+	#[pallet::storage]
+	pub(super) type SandboxedExecutionLimits<T: Config> =
+		StorageValue<_, SandboxLimits<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The weight accounts used for sandboxed execution within their current budget period.
+	#[pallet::storage]
+	pub(super) type SandboxedExecutionUsage<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, SandboxUsage<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The raw key of [`SandboxedExecutionUsage`] after which the pruning of expired usage
+	/// continues in the next block.
+	#[pallet::storage]
+	pub(super) type SandboxUsagePruneCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		#[serde(skip)]
@@ -848,6 +1063,10 @@ pub mod pallet {
 			weight_used
 		}
 
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::prune_sandbox_usage(remaining_weight)
+		}
+
 		#[cfg(feature = "try-runtime")]
 		fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
 			Self::do_try_state()
@@ -928,6 +1147,9 @@ pub mod pallet {
 	#[pallet::call(weight(<T as Config>::WeightInfo))]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
+		#[pallet::weight(
+			T::WeightInfo::send().saturating_add(Pallet::<T>::note_sent_topic_weight())
+		)]
 		pub fn send(
 			origin: OriginFor<T>,
 			dest: Box<VersionedLocation>,
@@ -956,6 +1178,9 @@ pub mod pallet {
 		/// - `fee_asset_item`: The index into `assets` of the item which should be used to pay
 		///   fees.
 		#[pallet::call_index(1)]
+		#[pallet::weight(
+			T::WeightInfo::teleport_assets().saturating_add(Pallet::<T>::note_sent_topic_weight())
+		)]
 		#[allow(deprecated)]
 		#[deprecated(
 			note = "This extrinsic uses `WeightLimit::Unlimited`, please migrate to `limited_teleport_assets` or `transfer_assets`"
@@ -1001,6 +1226,10 @@ pub mod pallet {
 		/// - `fee_asset_item`: The index into `assets` of the item which should be used to pay
 		///   fees.
 		#[pallet::call_index(2)]
+		#[pallet::weight(
+			T::WeightInfo::reserve_transfer_assets()
+				.saturating_add(Pallet::<T>::note_sent_topic_weight())
+		)]
 		#[allow(deprecated)]
 		#[deprecated(
 			note = "This extrinsic uses `WeightLimit::Unlimited`, please migrate to `limited_reserve_transfer_assets` or `transfer_assets`"
@@ -1151,7 +1380,10 @@ pub mod pallet {
 		///   fees.
 		/// - `weight_limit`: The remote-side weight limit, if any, for the XCM fee purchase.
 		#[pallet::call_index(8)]
-		#[pallet::weight(T::WeightInfo::reserve_transfer_assets())]
+		#[pallet::weight(
+			T::WeightInfo::reserve_transfer_assets()
+				.saturating_add(Pallet::<T>::note_sent_topic_weight())
+		)]
 		pub fn limited_reserve_transfer_assets(
 			origin: OriginFor<T>,
 			dest: Box<VersionedLocation>,
@@ -1189,7 +1421,9 @@ pub mod pallet {
 		///   fees.
 		/// - `weight_limit`: The remote-side weight limit, if any, for the XCM fee purchase.
 		#[pallet::call_index(9)]
-		#[pallet::weight(T::WeightInfo::teleport_assets())]
+		#[pallet::weight(
+			T::WeightInfo::teleport_assets().saturating_add(Pallet::<T>::note_sent_topic_weight())
+		)]
 		pub fn limited_teleport_assets(
 			origin: OriginFor<T>,
 			dest: Box<VersionedLocation>,
@@ -1253,6 +1487,9 @@ pub mod pallet {
 		///   fees.
 		/// - `weight_limit`: The remote-side weight limit, if any, for the XCM fee purchase.
 		#[pallet::call_index(11)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_assets().saturating_add(Pallet::<T>::note_sent_topic_weight())
+		)]
 		pub fn transfer_assets(
 			origin: OriginFor<T>,
 			dest: Box<VersionedLocation>,
@@ -1382,7 +1619,9 @@ pub mod pallet {
 		///   transfer, which also determines what happens to the assets on the destination chain.
 		/// - `weight_limit`: The remote-side weight limit, if any, for the XCM fee purchase.
 		#[pallet::call_index(13)]
-		#[pallet::weight(T::WeightInfo::transfer_assets())]
+		#[pallet::weight(
+			T::WeightInfo::transfer_assets().saturating_add(Pallet::<T>::note_sent_topic_weight())
+		)]
 		pub fn transfer_assets_using_type_and_then(
 			origin: OriginFor<T>,
 			dest: Box<VersionedLocation>,
@@ -1423,6 +1662,147 @@ pub mod pallet {
 				weight_limit,
 			)
 		}
+
+		/// Register a location as a sponsor of delivery fees paid in a given asset, or update its
+		/// budget in that asset.
+		///
+		/// The sponsor pays the delivery fees of the messages sent by the origins set through
+		/// `set_fee_sponsored_origin`, up to `budget` of the asset `asset_id` every `period`
+		/// blocks. Fees which are not covered by the budgets of the sponsor are paid by the origin
+		/// itself.
+		///
+		/// - `origin`: Must be an origin specified by AdminOrigin.
+		/// - `sponsor`: The location which pays the delivery fees, e.g. a local account.
+		/// - `asset_id`: The asset of the fees the budget applies to.
+		/// - `budget`: The total amount of the asset paid within a period.
+		/// - `period`: The length of a budget period, in blocks.
+		#[pallet::call_index(14)]
+		pub fn register_fee_sponsor(
+			origin: OriginFor<T>,
+			sponsor: Box<Location>,
+			asset_id: Box<AssetId>,
+			budget: u128,
+			period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(!period.is_zero(), Error::<T>::ZeroFeeSponsorPeriod);
+			let (sponsor, asset_id) = (*sponsor, *asset_id);
+			let period_start = frame_system::Pallet::<T>::block_number();
+			FeeSponsors::<T>::insert(
+				LatestVersionedLocation(&sponsor),
+				VersionedAssetId::from(asset_id.clone()),
+				FeeSponsorship { budget, period, period_start, spent: 0 },
+			);
+			Self::deposit_event(Event::FeeSponsorRegistered { sponsor, asset_id, budget, period });
+			Ok(())
+		}
+
+		/// Deregister a sponsor of delivery fees paid in a given asset.
+		///
+		/// The origins sponsored by it pay their own delivery fees in that asset from now on.
+		///
+		/// - `origin`: Must be an origin specified by AdminOrigin.
+		/// - `sponsor`: The location which no longer sponsors fees in the asset.
+		/// - `asset_id`: The asset of the fees which are no longer sponsored.
+		#[pallet::call_index(15)]
+		pub fn deregister_fee_sponsor(
+			origin: OriginFor<T>,
+			sponsor: Box<Location>,
+			asset_id: Box<AssetId>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let (sponsor, asset_id) = (*sponsor, *asset_id);
+			FeeSponsors::<T>::take(
+				LatestVersionedLocation(&sponsor),
+				VersionedAssetId::from(asset_id.clone()),
+			)
+			.ok_or(Error::<T>::UnknownFeeSponsor)?;
+			Self::deposit_event(Event::FeeSponsorDeregistered { sponsor, asset_id });
+			Ok(())
+		}
+
+		/// Set the sponsor of the delivery fees of the messages sent by a given origin.
+		///
+		/// - `origin`: Must be an origin specified by AdminOrigin.
+		/// - `sponsored`: The origin whose delivery fees are sponsored.
+		/// - `maybe_sponsor`: A registered sponsor of delivery fees, or `None` to stop sponsoring
+		///   `sponsored`.
+		#[pallet::call_index(16)]
+		pub fn set_fee_sponsored_origin(
+			origin: OriginFor<T>,
+			sponsored: Box<Location>,
+			maybe_sponsor: Option<Box<Location>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let sponsored = *sponsored;
+			let maybe_sponsor = maybe_sponsor.map(|sponsor| *sponsor);
+			match &maybe_sponsor {
+				Some(sponsor) => {
+					ensure!(
+						FeeSponsors::<T>::contains_prefix(LatestVersionedLocation(sponsor)),
+						Error::<T>::UnknownFeeSponsor
+					);
+					FeeSponsoredOrigins::<T>::insert(
+						LatestVersionedLocation(&sponsored),
+						VersionedLocation::from(sponsor.clone()),
+					);
+				},
+				None => FeeSponsoredOrigins::<T>::remove(LatestVersionedLocation(&sponsored)),
+			}
+			Self::deposit_event(Event::FeeSponsoredOriginSet {
+				origin: sponsored,
+				sponsor: maybe_sponsor,
+			});
+			Ok(())
+		}
+
+		/// Execute an XCM message from a local, signed, origin within the sandbox.
+		///
+		/// Unlike `execute`, the message is not subject to `XcmExecuteFilter`. Instead, it may
+		/// only contain instructions which act locally on the assets of the origin, e.g. to
+		/// withdraw, exchange and deposit assets, but no `Transact` and nothing which sends or
+		/// exports messages. The weight of the message is charged against the budget of the
+		/// account, which is reset every budget period, as set by `set_sandbox_limits`.
+		///
+		/// An event is deposited indicating whether `msg` could be executed completely or only
+		/// partially.
+		///
+		/// No more than `max_weight` will be used in its attempted execution. If this is less than
+		/// the maximum amount of weight that the message could take to be executed, then no
+		/// execution attempt will be made.
+		#[pallet::call_index(17)]
+		#[pallet::weight(max_weight.saturating_add(T::WeightInfo::execute_sandboxed()))]
+		pub fn execute_sandboxed(
+			origin: OriginFor<T>,
+			message: Box<VersionedXcm<<T as Config>::RuntimeCall>>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+			let weight_used = Self::do_execute_sandboxed(origin, who, message, max_weight)?;
+			Ok(Some(weight_used.saturating_add(T::WeightInfo::execute_sandboxed())).into())
+		}
+
+		/// Set the limits of the sandboxed execution of XCM programs.
+		///
+		/// - `origin`: Must be an origin specified by AdminOrigin.
+		/// - `maybe_limits`: The weight budget of each account and the length of a budget period,
+		///   or `None` to disable the sandboxed execution.
+		#[pallet::call_index(18)]
+		pub fn set_sandbox_limits(
+			origin: OriginFor<T>,
+			maybe_limits: Option<SandboxLimits<BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			match &maybe_limits {
+				Some(limits) => {
+					ensure!(!limits.period.is_zero(), Error::<T>::ZeroSandboxPeriod);
+					SandboxedExecutionLimits::<T>::put(limits);
+				},
+				None => SandboxedExecutionLimits::<T>::kill(),
+			}
+			Self::deposit_event(Event::SandboxLimitsSet { limits: maybe_limits });
+			Ok(())
+		}
 	}
 }
 
@@ -1645,12 +2025,37 @@ impl<T: Config> Pallet<T> {
 		origin: Location,
 		dest: Location,
 		beneficiary: Either<Location, Xcm<()>>,
-		mut assets: Vec<Asset>,
+		assets: Vec<Asset>,
 		assets_transfer_type: TransferType,
 		fee_asset_index: usize,
 		fees_transfer_type: TransferType,
 		weight_limit: WeightLimit,
 	) -> DispatchResult {
+		let (local_xcm, remote_xcm) = Self::build_transfer_programs(
+			origin.clone(),
+			dest.clone(),
+			beneficiary,
+			assets,
+			assets_transfer_type,
+			fee_asset_index,
+			fees_transfer_type,
+			weight_limit,
+		)?;
+		Self::execute_xcm_transfer(origin, dest, local_xcm, remote_xcm)
+	}
+
+	/// Build the local and remote XCM programs of a transfer, handling the fees separately from
+	/// the assets if they have different transfer types.
+	fn build_transfer_programs(
+		origin: Location,
+		dest: Location,
+		beneficiary: Either<Location, Xcm<()>>,
+		mut assets: Vec<Asset>,
+		assets_transfer_type: TransferType,
+		fee_asset_index: usize,
+		fees_transfer_type: TransferType,
+		weight_limit: WeightLimit,
+	) -> Result<(Xcm<<T as Config>::RuntimeCall>, Option<Xcm<()>>), Error<T>> {
 		// local and remote XCM programs to potentially handle fees separately
 		let fees = if fees_transfer_type == assets_transfer_type {
 			let fees = assets.get(fee_asset_index).ok_or(Error::<T>::Empty)?.clone();
@@ -1690,21 +2095,20 @@ impl<T: Config> Pallet<T> {
 					weight_limit,
 				)?,
 				TransferType::RemoteReserve(_) =>
-					return Err(Error::<T>::InvalidAssetUnsupportedReserve.into()),
+					return Err(Error::<T>::InvalidAssetUnsupportedReserve),
 			};
 			FeesHandling::Separate { local_xcm, remote_xcm }
 		};
 
-		let (local_xcm, remote_xcm) = Self::build_xcm_transfer_type(
-			origin.clone(),
-			dest.clone(),
+		Self::build_xcm_transfer_type(
+			origin,
+			dest,
 			beneficiary,
 			assets,
 			assets_transfer_type,
 			fees,
 			weight_limit,
-		)?;
-		Self::execute_xcm_transfer(origin, dest, local_xcm, remote_xcm)
+		)
 	}
 
 	fn build_xcm_transfer_type(
@@ -1813,8 +2217,15 @@ impl<T: Config> Pallet<T> {
 				})?;
 			}
 			let message_id = T::XcmRouter::deliver(ticket).map_err(Error::<T>::from)?;
+			let topic_id = Self::note_sent_topic(&origin, &dest, &remote_xcm, message_id);
 
-			let e = Event::Sent { origin, destination: dest, message: remote_xcm, message_id };
+			let e = Event::Sent {
+				origin,
+				destination: dest,
+				message: remote_xcm,
+				message_id,
+				topic_id,
+			};
 			Self::deposit_event(e);
 		}
 		Ok(())
@@ -2290,6 +2701,8 @@ impl<T: Config> Pallet<T> {
 							result: xcm_version,
 							cost,
 							message_id,
+							// The message carries no `SetTopic`, so its id is its topic id.
+							topic_id: message_id,
 						}
 					},
 					Err(e) => {
@@ -2350,6 +2763,7 @@ impl<T: Config> Pallet<T> {
 									result: xcm_version,
 									cost,
 									message_id,
+									topic_id: message_id,
 								}
 							},
 							Err(e) => Event::NotifyTargetSendFail {
@@ -2384,7 +2798,12 @@ impl<T: Config> Pallet<T> {
 		// TODO #3735: Correct weight.
 		let instruction = SubscribeVersion { query_id, max_response_weight: Weight::zero() };
 		let (message_id, cost) = send_xcm::<T::XcmRouter>(dest.clone(), Xcm(vec![instruction]))?;
-		Self::deposit_event(Event::VersionNotifyRequested { destination: dest, cost, message_id });
+		Self::deposit_event(Event::VersionNotifyRequested {
+			destination: dest,
+			cost,
+			message_id,
+			topic_id: message_id,
+		});
 		VersionNotifiers::<T>::insert(XCM_VERSION, &versioned_dest, query_id);
 		let query_status =
 			QueryStatus::VersionNotifier { origin: versioned_dest, is_active: false };
@@ -2404,6 +2823,7 @@ impl<T: Config> Pallet<T> {
 			destination: dest,
 			cost,
 			message_id,
+			topic_id: message_id,
 		});
 		Queries::<T>::remove(query_id);
 		Ok(())
@@ -2433,6 +2853,68 @@ impl<T: Config> Pallet<T> {
 		T::XcmRouter::deliver(ticket)
 	}
 
+	/// Record the topic id of a `message` sent from `origin` to `dest` in [`SentTopics`],
+	/// dropping the oldest topic id once [`Config::SENT_TOPICS_CAPACITY`] is reached. The weight
+	/// of this is [`Self::note_sent_topic_weight`].
+	///
+	/// The topic id is the one set by a trailing `SetTopic` of the message. Otherwise it is the
+	/// `message_id` returned by the router, which is the unique topic id it appended to the
+	/// message if it is wrapped in `WithUniqueTopic`. Returns the topic id.
+	fn note_sent_topic(
+		origin: &Location,
+		dest: &Location,
+		message: &Xcm<()>,
+		message_id: XcmHash,
+	) -> XcmHash {
+		let topic_id = Self::sent_topic_id(message, message_id);
+		if T::SENT_TOPICS_CAPACITY == 0 {
+			return topic_id
+		}
+
+		let slot = NextSentTopicSlot::<T>::get() % T::SENT_TOPICS_CAPACITY;
+		if let Some(oldest) = SentTopicSlots::<T>::get(slot) {
+			// The oldest topic id may have been sent again since, in which case it is kept in
+			// another slot.
+			if SentTopics::<T>::get(oldest).map_or(false, |sent| sent.slot == slot) {
+				SentTopics::<T>::remove(oldest);
+			}
+		}
+		SentTopicSlots::<T>::insert(slot, topic_id);
+		SentTopics::<T>::insert(
+			topic_id,
+			SentTopic {
+				origin: origin.clone().into(),
+				destination: dest.clone().into(),
+				block: frame_system::Pallet::<T>::block_number(),
+				slot,
+			},
+		);
+		NextSentTopicSlot::<T>::put((slot + 1) % T::SENT_TOPICS_CAPACITY);
+		topic_id
+	}
+
+	/// The weight of [`Self::note_sent_topic`].
+	pub fn note_sent_topic_weight() -> Weight {
+		if T::SENT_TOPICS_CAPACITY == 0 {
+			return Weight::zero()
+		}
+		T::DbWeight::get().reads_writes(3, 4)
+	}
+
+	/// The topic id of a `message` sent with the given `message_id`: the one set by a trailing
+	/// `SetTopic` of the message, otherwise the `message_id`.
+	fn sent_topic_id(message: &Xcm<()>, message_id: XcmHash) -> XcmHash {
+		match message.last() {
+			Some(SetTopic(topic_id)) => *topic_id,
+			_ => message_id,
+		}
+	}
+
+	/// The origin, destination and block of a recently sent message with the given `topic_id`.
+	pub fn sent_topic(topic_id: XcmHash) -> Option<SentTopic<BlockNumberFor<T>>> {
+		SentTopics::<T>::get(topic_id)
+	}
+
 	pub fn check_account() -> T::AccountId {
 		const ID: PalletId = PalletId(*b"py/xcmch");
 		AccountIdConversion::<T::AccountId>::into_account_truncating(&ID)
@@ -2565,6 +3047,120 @@ impl<T: Config> Pallet<T> {
 			.map_err(|_| XcmPaymentApiError::VersionedConversionFailed)
 	}
 
+	/// Runs the local checks of transferring `assets` from `origin` to `beneficiary` on `dest`
+	/// with [`Pallet::transfer_assets`], without keeping any effect of the transfer.
+	///
+	/// The local XCM program of the transfer is executed and rolled back, so the permission of
+	/// `origin` to execute it, as well as the routing and the delivery fees of the message to
+	/// `dest`, are checked for every transfer type.
+	///
+	/// Returns the reasons for which the transfer would fail, see [`TransferFailure`]. Checks which
+	/// depend on a previous check passing are skipped if it fails.
+	/// Meant to be used in the `xcm_runtime_apis::transfers::TransferValidationApi` runtime API.
+	pub fn validate_transfer(
+		origin: VersionedLocation,
+		dest: VersionedLocation,
+		beneficiary: VersionedLocation,
+		assets: VersionedAssets,
+		fee_asset_item: u32,
+	) -> Vec<TransferFailure> {
+		let mut failures = Vec::new();
+		let (Ok(origin), Ok(dest), Ok(beneficiary), Ok(assets)) = (
+			Location::try_from(origin),
+			Location::try_from(dest),
+			Location::try_from(beneficiary),
+			Assets::try_from(assets),
+		) else {
+			failures.push(TransferFailure::VersionedConversionFailed);
+			return failures
+		};
+
+		if Self::get_version_for(&dest).or_else(SafeXcmVersion::<T>::get).is_none() {
+			failures.push(TransferFailure::UnknownDestinationVersion);
+		}
+		if assets.len() > MAX_ASSETS_FOR_TRANSFER {
+			failures.push(TransferFailure::TooManyAssets);
+		}
+		let assets = assets.into_inner();
+		let fee_asset_item = fee_asset_item as usize;
+		if fee_asset_item >= assets.len() {
+			failures.push(TransferFailure::InvalidFeeAssetItem);
+			return failures
+		}
+
+		// Building the programs applies the transfer filters, as for an actual transfer.
+		let programs = Self::find_fee_and_assets_transfer_types(&assets, fee_asset_item, &dest)
+			.and_then(|(fees_transfer_type, assets_transfer_type)| {
+				Self::build_transfer_programs(
+					origin.clone(),
+					dest.clone(),
+					Either::Left(beneficiary),
+					assets,
+					assets_transfer_type,
+					fee_asset_item,
+					fees_transfer_type,
+					Unlimited,
+				)
+			});
+		let (mut local_xcm, remote_xcm) = match programs {
+			Ok(programs) => programs,
+			Err(error) => {
+				failures.push(error.into());
+				return failures
+			},
+		};
+
+		let Ok(weight) = T::Weigher::weight(&mut local_xcm) else {
+			failures.push(TransferFailure::UnweighableMessage);
+			return failures
+		};
+		// Teleports and reserve transfers send their message to `dest` while executing the local
+		// program, remote reserve transfers and separately transferred fees send it afterwards.
+		let attempted = with_transaction(|| {
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>(Self::attempt_transfer(
+				origin, dest, local_xcm, remote_xcm, weight,
+			)))
+		});
+		match attempted {
+			Ok(Ok(())) => {},
+			Ok(Err(failure)) => failures.push(failure),
+			Err(_) => failures.push(TransferFailure::Other),
+		}
+		failures
+	}
+
+	/// Execute the local XCM program of a transfer and send its remote XCM program like
+	/// `execute_xcm_transfer`, for [`Self::validate_transfer`]. The caller has to roll back the
+	/// changes.
+	fn attempt_transfer(
+		origin: Location,
+		dest: Location,
+		local_xcm: Xcm<<T as Config>::RuntimeCall>,
+		remote_xcm: Option<Xcm<()>>,
+		weight: Weight,
+	) -> Result<(), TransferFailure> {
+		let mut hash = local_xcm.using_encoded(sp_io::hashing::blake2_256);
+		T::XcmExecutor::prepare_and_execute(origin.clone(), local_xcm, &mut hash, weight, weight)
+			.ensure_complete()
+			.map_err(|error| match error {
+				XcmError::Barrier => TransferFailure::ExecutionNotPermitted,
+				XcmError::Unroutable | XcmError::Transport(_) => TransferFailure::Unroutable,
+				XcmError::NotHoldingFees | XcmError::FeesNotMet =>
+					TransferFailure::DeliveryFeesNotMet,
+				_ => TransferFailure::LocalExecutionFailed,
+			})?;
+
+		if let Some(remote_xcm) = remote_xcm {
+			let (_, price) = validate_send::<T::XcmRouter>(dest, remote_xcm)
+				.map_err(|_| TransferFailure::Unroutable)?;
+			if origin != Here.into_location() {
+				Self::charge_fees(origin, price)
+					.map_err(|_| TransferFailure::DeliveryFeesNotMet)?;
+			}
+		}
+		Ok(())
+	}
+
 	/// Create a new expectation of a query response with the querier being here.
 	fn do_new_query(
 		responder: impl Into<Location>,
@@ -2668,12 +3264,180 @@ impl<T: Config> Pallet<T> {
 	/// - the `assets` are not known on this chain;
 	/// - the `assets` cannot be withdrawn with that location as the Origin.
 	fn charge_fees(location: Location, assets: Assets) -> DispatchResult {
+		if Self::charge_sponsored_fees(&location, &assets) {
+			return Ok(())
+		}
 		T::XcmExecutor::charge_fees(location.clone(), assets.clone())
 			.map_err(|_| Error::<T>::FeesNotMet)?;
 		Self::deposit_event(Event::FeesPaid { paying: location, fees: assets });
 		Ok(())
 	}
 
+	/// Try to pay the fees of `location` from its sponsor, if any.
+	///
+	/// Returns `false` if `location` is not sponsored, the fees are not fungible or do not fit
+	/// into the remaining budget of the sponsor in their asset, or the sponsor could not pay them.
+	fn charge_sponsored_fees(location: &Location, assets: &Assets) -> bool {
+		let Some(versioned_sponsor) =
+			FeeSponsoredOrigins::<T>::get(LatestVersionedLocation(location))
+		else {
+			return false
+		};
+		let Ok(sponsor) = Location::try_from(versioned_sponsor.clone()) else { return false };
+
+		// Every asset of the fees is charged against the budget of the sponsor in that asset.
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut sponsorships = Vec::with_capacity(assets.len());
+		for asset in assets.inner() {
+			let Fungible(amount) = asset.fun else { return false };
+			let asset_id = VersionedAssetId::from(asset.id.clone());
+			let Some(mut sponsorship) = FeeSponsors::<T>::get(&versioned_sponsor, &asset_id) else {
+				return false
+			};
+			if now >= sponsorship.period_start.saturating_add(sponsorship.period) {
+				sponsorship.period_start = now;
+				sponsorship.spent = 0;
+			}
+			let Some(spent) = sponsorship
+				.spent
+				.checked_add(amount)
+				.filter(|spent| *spent <= sponsorship.budget)
+			else {
+				return false
+			};
+			sponsorship.spent = spent;
+			sponsorships.push((asset_id, sponsorship));
+		}
+
+		let charged: Result<(), DispatchError> = with_transaction(|| {
+			match T::XcmExecutor::charge_fees(sponsor.clone(), assets.clone()) {
+				Ok(()) => TransactionOutcome::Commit(Ok(())),
+				Err(_) => TransactionOutcome::Rollback(Err(Error::<T>::FeesNotMet.into())),
+			}
+		});
+		if charged.is_err() {
+			return false
+		}
+		for (asset_id, sponsorship) in sponsorships {
+			FeeSponsors::<T>::insert(&versioned_sponsor, asset_id, sponsorship);
+		}
+		Self::deposit_event(Event::FeesPaid { paying: sponsor, fees: assets.clone() });
+		true
+	}
+
+	/// Execute `message` from the signed `origin` of `who` within the sandbox, charging the weight
+	/// used against the sandbox budget of `who`.
+	fn do_execute_sandboxed(
+		origin: OriginFor<T>,
+		who: T::AccountId,
+		message: Box<VersionedXcm<<T as Config>::RuntimeCall>>,
+		max_weight: Weight,
+	) -> Result<Weight, DispatchErrorWithPostInfo> {
+		log::trace!(target: "xcm::pallet_xcm::execute_sandboxed", "message {:?}, max_weight {:?}", message, max_weight);
+		let outcome = (|| {
+			let limits = SandboxedExecutionLimits::<T>::get().ok_or(Error::<T>::SandboxDisabled)?;
+			let origin_location = T::ExecuteXcmOrigin::ensure_origin(origin)?;
+			let mut hash = message.using_encoded(sp_io::hashing::blake2_256);
+			let message: Xcm<<T as Config>::RuntimeCall> =
+				(*message).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			ensure!(Self::is_sandboxed(&message), Error::<T>::SandboxFiltered);
+			let weight = T::Weigher::weight(&mut message.clone())
+				.map_err(|()| Error::<T>::UnweighableMessage)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut usage = SandboxedExecutionUsage::<T>::get(&who)
+				.filter(|usage| now < usage.period_start.saturating_add(limits.period))
+				.unwrap_or(SandboxUsage { period_start: now, used: Weight::zero() });
+			ensure!(
+				usage.used.saturating_add(weight).all_lte(limits.weight_budget),
+				Error::<T>::SandboxBudgetExceeded
+			);
+
+			let outcome = T::XcmExecutor::prepare_and_execute(
+				origin_location,
+				message,
+				&mut hash,
+				max_weight,
+				max_weight,
+			);
+			usage.used.saturating_accrue(outcome.weight_used());
+			SandboxedExecutionUsage::<T>::insert(&who, usage);
+			Ok(outcome)
+		})()
+		.map_err(|e: DispatchError| e.with_weight(T::WeightInfo::execute_sandboxed()))?;
+
+		Self::deposit_event(Event::Attempted { outcome: outcome.clone() });
+		let weight_used = outcome.weight_used();
+		outcome.ensure_complete().map_err(|error| {
+			log::error!(target: "xcm::pallet_xcm::execute_sandboxed", "XCM execution failed with error {:?}", error);
+			Error::<T>::LocalExecutionIncomplete
+				.with_weight(weight_used.saturating_add(T::WeightInfo::execute_sandboxed()))
+		})?;
+		Ok(weight_used)
+	}
+
+	/// Remove the sandbox usage of the accounts whose budget period is over, or all of it if
+	/// sandboxed execution is disabled, using at most `limit` weight.
+	///
+	/// Continues from where the previous call stopped and starts over once all accounts are
+	/// visited.
+	fn prune_sandbox_usage(limit: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		// Reading the limits and the cursor, and storing the cursor again.
+		let mut weight_used = db_weight.reads_writes(2, 1);
+		let per_account = db_weight.reads_writes(1, 1);
+		if !weight_used.saturating_add(per_account).all_lte(limit) {
+			return Weight::zero()
+		}
+
+		let period = SandboxedExecutionLimits::<T>::get().map(|limits| limits.period);
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut iter = match SandboxUsagePruneCursor::<T>::take() {
+			Some(cursor) => SandboxedExecutionUsage::<T>::iter_from(cursor),
+			None => SandboxedExecutionUsage::<T>::iter(),
+		};
+		loop {
+			if !weight_used.saturating_add(per_account).all_lte(limit) {
+				SandboxUsagePruneCursor::<T>::put(iter.last_raw_key().to_vec());
+				break
+			}
+			let Some((who, usage)) = iter.next() else { break };
+			weight_used.saturating_accrue(db_weight.reads(1));
+			if period.map_or(true, |period| now >= usage.period_start.saturating_add(period)) {
+				SandboxedExecutionUsage::<T>::remove(&who);
+				weight_used.saturating_accrue(db_weight.writes(1));
+			}
+		}
+		weight_used
+	}
+
+	/// Whether `message` only contains instructions allowed in sandboxed execution, i.e. which act
+	/// locally on the holding register and the assets of the origin.
+	fn is_sandboxed(message: &Xcm<<T as Config>::RuntimeCall>) -> bool {
+		message.0.iter().all(|instruction| match instruction {
+			WithdrawAsset(..) |
+			BurnAsset(..) |
+			BuyExecution { .. } |
+			RefundSurplus |
+			ExchangeAsset { .. } |
+			DepositAsset { .. } |
+			TransferAsset { .. } |
+			ClearOrigin |
+			ClearError |
+			ClearTransactStatus |
+			SetFeesMode { .. } |
+			SetTopic(..) |
+			ClearTopic |
+			Trap(..) |
+			ExpectAsset(..) |
+			ExpectOrigin(..) |
+			ExpectError(..) |
+			ExpectTransactStatus(..) => true,
+			SetErrorHandler(xcm) | SetAppendix(xcm) => Self::is_sandboxed(xcm),
+			_ => false,
+		})
+	}
+
 	/// Ensure the correctness of the state of this pallet.
 	///
 	/// This should be valid before and after each state transition of this pallet.
@@ -2961,6 +3725,7 @@ impl<T: Config> VersionChangeNotifier for Pallet<T> {
 			destination: dest.clone(),
 			cost,
 			message_id,
+			topic_id: message_id,
 		});
 
 		let value = (query_id, max_weight, xcm_version);
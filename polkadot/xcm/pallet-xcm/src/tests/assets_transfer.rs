@@ -30,6 +30,7 @@ use polkadot_parachain_primitives::primitives::Id as ParaId;
 use sp_runtime::traits::AccountIdConversion;
 use xcm::prelude::*;
 use xcm_executor::traits::ConvertLocation;
+use xcm_runtime_apis::transfers::TransferFailure;
 
 /// Test `limited_teleport_assets`
 ///
@@ -2596,3 +2597,60 @@ fn limited_reserve_transfer_assets_with_remote_asset_reserve_and_remote_asset_fe
 		expected_result,
 	);
 }
+
+/// Test `validate_transfer` reports the failures of a transfer without executing it.
+#[test]
+fn validate_transfer_works() {
+	let origin: Location = AccountId32 { network: None, id: ALICE.into() }.into();
+	let beneficiary: Location = AccountId32 { network: None, id: BOB.into() }.into();
+	let validate = |dest: Location, assets: Assets, fee_asset_item| {
+		XcmPallet::validate_transfer(
+			origin.clone().into(),
+			dest.into(),
+			beneficiary.clone().into(),
+			assets.into(),
+			fee_asset_item,
+		)
+	};
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances.clone()).execute_with(|| {
+		// a valid teleport passes all checks, but none of its effects are kept.
+		assert_eq!(validate(RelayLocation::get(), (Here, SEND_AMOUNT).into(), 0), vec![]);
+		assert_eq!(Balances::total_balance(&ALICE), INITIAL_BALANCE);
+		// the test router does not roll back the messages it delivered.
+		assert_eq!(take_sent_xcm().len(), 1);
+
+		// the teleport is executed, so the origin must hold the assets.
+		assert_eq!(
+			validate(RelayLocation::get(), (Here, INITIAL_BALANCE * 2).into(), 0),
+			vec![TransferFailure::LocalExecutionFailed]
+		);
+		// and its message must be routable.
+		set_send_xcm_artificial_failure(true);
+		assert_eq!(
+			validate(RelayLocation::get(), (Here, SEND_AMOUNT).into(), 0),
+			vec![TransferFailure::Unroutable]
+		);
+		set_send_xcm_artificial_failure(false);
+
+		assert_eq!(
+			validate(RelayLocation::get(), (Here, SEND_AMOUNT).into(), 1),
+			vec![TransferFailure::InvalidFeeAssetItem]
+		);
+		assert_eq!(
+			validate(RelayLocation::get(), (Here, 0u128).into(), 0),
+			vec![TransferFailure::Empty]
+		);
+		assert_eq!(
+			validate(FilteredTeleportLocation::get(), FilteredTeleportAsset::get().into(), 0),
+			vec![TransferFailure::Filtered]
+		);
+	});
+	// without a safe XCM version, the version of the destination must be known.
+	new_test_ext_with_balances_and_xcm_version(balances, None).execute_with(|| {
+		assert_eq!(
+			validate(RelayLocation::get(), (Here, SEND_AMOUNT).into(), 0),
+			vec![TransferFailure::UnknownDestinationVersion]
+		);
+	});
+}
@@ -21,7 +21,8 @@ pub(crate) mod assets_transfer;
 use crate::{
 	mock::*, pallet::SupportedVersion, AssetTraps, Config, CurrentMigration, Error,
 	ExecuteControllerWeightInfo, LatestVersionedLocation, Pallet, Queries, QueryStatus,
-	RecordedXcm, ShouldRecordXcm, VersionDiscoveryQueue, VersionMigrationStage, VersionNotifiers,
+	RecordedXcm, SandboxLimits, SandboxUsage, SandboxUsagePruneCursor, SandboxedExecutionUsage,
+	ShouldRecordXcm, VersionDiscoveryQueue, VersionMigrationStage, VersionNotifiers,
 	VersionNotifyTargets, WeightInfo,
 };
 use frame_support::{
@@ -320,12 +321,76 @@ fn send_works() {
 		assert_eq!(
 			last_event(),
 			RuntimeEvent::XcmPallet(crate::Event::Sent {
-				origin: sender,
+				origin: sender.clone(),
 				destination: RelayLocation::get(),
 				message,
 				message_id: id,
+				topic_id: id,
 			})
 		);
+		assert_eq!(
+			XcmPallet::sent_topic(id),
+			Some(crate::SentTopic {
+				origin: sender.into(),
+				destination: RelayLocation::get().into(),
+				block: 1,
+				slot: 0,
+			})
+		);
+	});
+}
+
+/// Test that the topic ids of sent messages are kept
+///
+/// Asserts that a trailing `SetTopic` is used as the topic id, that only the most recent topic
+/// ids are kept and that a topic id sent again is not dropped with its older slot
+#[test]
+fn sent_topics_are_kept() {
+	let balances = vec![
+		(ALICE, INITIAL_BALANCE),
+		(ParaId::from(OTHER_PARA_ID).into_account_truncating(), INITIAL_BALANCE),
+	];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let sender: Location = AccountId32 { network: None, id: ALICE.into() }.into();
+		for topic in 1..=3u8 {
+			let message = Xcm(vec![ClearOrigin, SetTopic([topic; 32])]);
+			assert_ok!(XcmPallet::send(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(RelayLocation::get().into()),
+				Box::new(VersionedXcm::from(message))
+			));
+			assert!(matches!(
+				last_event(),
+				RuntimeEvent::XcmPallet(crate::Event::Sent { topic_id, .. }) if topic_id == [topic; 32]
+			));
+		}
+
+		// The oldest topic id was dropped.
+		assert_eq!(XcmPallet::sent_topic([1; 32]), None);
+		assert_eq!(
+			XcmPallet::sent_topic([3; 32]),
+			Some(crate::SentTopic {
+				origin: sender.into(),
+				destination: RelayLocation::get().into(),
+				block: 1,
+				slot: 0,
+			})
+		);
+		assert_eq!(crate::SentTopicSlots::<Test>::get(1), Some([2; 32]));
+		assert_eq!(crate::NextSentTopicSlot::<Test>::get(), 1);
+
+		// Sending the topic id in slot 0 again moves it to slot 1, so it is kept when slot 0 is
+		// reused.
+		for topic in [3u8, 4] {
+			assert_ok!(XcmPallet::send(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(RelayLocation::get().into()),
+				Box::new(VersionedXcm::from(Xcm(vec![ClearOrigin, SetTopic([topic; 32])])))
+			));
+		}
+		assert_eq!(XcmPallet::sent_topic([2; 32]), None);
+		assert_eq!(XcmPallet::sent_topic([3; 32]).map(|sent| sent.slot), Some(1));
+		assert_eq!(XcmPallet::sent_topic([4; 32]).map(|sent| sent.slot), Some(0));
 	});
 }
 
@@ -357,6 +422,108 @@ fn send_fails_when_xcm_router_blocks() {
 	});
 }
 
+/// Test that delivery fees of sponsored origins are paid by their sponsor
+///
+/// Asserts that the sponsor pays the fees up to its budget per period, after which the sender
+/// pays them itself until the next period starts.
+#[test]
+fn send_with_sponsored_delivery_fees_works() {
+	let user_account = AccountId::from(XCM_FEES_NOT_WAIVED_USER_ACCOUNT);
+	let balances = vec![(user_account.clone(), INITIAL_BALANCE), (ALICE, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let fee_amount = Para3000PaymentAmount::get();
+		let fee_asset = AssetId(Here.into());
+		let sender: Location =
+			Junction::AccountId32 { network: None, id: user_account.clone().into() }.into();
+		let sponsor: Location = Junction::AccountId32 { network: None, id: ALICE.into() }.into();
+		let send = || {
+			XcmPallet::send(
+				RuntimeOrigin::signed(user_account.clone()),
+				Box::new(Para3000Location::get().into()),
+				Box::new(VersionedXcm::from(Xcm(vec![ClearOrigin]))),
+			)
+		};
+
+		// Only registered sponsors can sponsor an origin.
+		assert_noop!(
+			XcmPallet::set_fee_sponsored_origin(
+				RuntimeOrigin::root(),
+				Box::new(sender.clone()),
+				Some(Box::new(sponsor.clone())),
+			),
+			Error::<Test>::UnknownFeeSponsor
+		);
+		assert_noop!(
+			XcmPallet::register_fee_sponsor(
+				RuntimeOrigin::root(),
+				Box::new(sponsor.clone()),
+				Box::new(fee_asset.clone()),
+				fee_amount,
+				0
+			),
+			Error::<Test>::ZeroFeeSponsorPeriod
+		);
+
+		// A budget in another asset does not cover the fees.
+		let other_asset = AssetId(Parent.into());
+		assert_ok!(XcmPallet::register_fee_sponsor(
+			RuntimeOrigin::root(),
+			Box::new(sponsor.clone()),
+			Box::new(other_asset.clone()),
+			10 * fee_amount,
+			10
+		));
+		assert_ok!(XcmPallet::set_fee_sponsored_origin(
+			RuntimeOrigin::root(),
+			Box::new(sender.clone()),
+			Some(Box::new(sponsor.clone())),
+		));
+		assert_ok!(send());
+		assert_eq!(Balances::free_balance(&user_account), INITIAL_BALANCE - fee_amount);
+		assert_ok!(XcmPallet::deregister_fee_sponsor(
+			RuntimeOrigin::root(),
+			Box::new(sponsor.clone()),
+			Box::new(other_asset),
+		));
+
+		assert_ok!(XcmPallet::register_fee_sponsor(
+			RuntimeOrigin::root(),
+			Box::new(sponsor.clone()),
+			Box::new(fee_asset.clone()),
+			fee_amount,
+			10
+		));
+
+		// The sponsor pays the fees within its budget.
+		assert_ok!(send());
+		assert_eq!(Balances::free_balance(&user_account), INITIAL_BALANCE - fee_amount);
+		assert!(System::events().iter().any(|r| r.event ==
+			RuntimeEvent::XcmPallet(crate::Event::FeesPaid {
+				paying: sponsor.clone(),
+				fees: Para3000PaymentAssets::get(),
+			})));
+
+		// The budget is exhausted, so the sender pays the fees.
+		assert_ok!(send());
+		assert_eq!(Balances::free_balance(&user_account), INITIAL_BALANCE - 2 * fee_amount);
+
+		// The budget is renewed in the next period.
+		System::set_block_number(System::block_number() + 10);
+		assert_ok!(send());
+		assert_eq!(Balances::free_balance(&user_account), INITIAL_BALANCE - 2 * fee_amount);
+
+		// Once the sponsor is deregistered, the sender pays the fees.
+		assert_ok!(XcmPallet::deregister_fee_sponsor(
+			RuntimeOrigin::root(),
+			Box::new(sponsor),
+			Box::new(fee_asset),
+		));
+		System::set_block_number(System::block_number() + 10);
+		assert_ok!(send());
+		assert_eq!(Balances::free_balance(&user_account), INITIAL_BALANCE - 3 * fee_amount);
+	});
+}
+
 /// Test local execution of XCM
 ///
 /// Asserts that the sender's balance is decreased and the beneficiary's balance
@@ -391,6 +558,94 @@ fn execute_withdraw_to_deposit_works() {
 	});
 }
 
+/// Test sandboxed execution within the limits and the budget of an account.
+#[test]
+fn execute_sandboxed_works() {
+	let balances = vec![(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let weight = BaseXcmWeight::get() * 3;
+		let dest: Location = Junction::AccountId32 { network: None, id: BOB.into() }.into();
+		let message = Xcm(vec![
+			WithdrawAsset((Here, SEND_AMOUNT).into()),
+			buy_execution((Here, SEND_AMOUNT)),
+			DepositAsset { assets: AllCounted(1).into(), beneficiary: dest },
+		]);
+		let execute_sandboxed = |message: Xcm<RuntimeCall>| {
+			XcmPallet::execute_sandboxed(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(VersionedXcm::from(message)),
+				weight,
+			)
+		};
+
+		// Disabled until limits are set.
+		assert_err_ignore_postinfo!(
+			execute_sandboxed(message.clone()),
+			Error::<Test>::SandboxDisabled
+		);
+		assert_ok!(XcmPallet::set_sandbox_limits(
+			RuntimeOrigin::root(),
+			Some(SandboxLimits { weight_budget: weight * 2, period: 10 }),
+		));
+
+		assert_ok!(execute_sandboxed(message.clone()));
+		assert_ok!(execute_sandboxed(message.clone()));
+		assert_eq!(Balances::total_balance(&ALICE), INITIAL_BALANCE - 2 * SEND_AMOUNT);
+		assert_eq!(Balances::total_balance(&BOB), INITIAL_BALANCE + 2 * SEND_AMOUNT);
+
+		// The budget of the period is used up.
+		assert_err_ignore_postinfo!(
+			execute_sandboxed(message.clone()),
+			Error::<Test>::SandboxBudgetExceeded
+		);
+		// Instructions which do not act locally are not allowed, even nested ones.
+		let transact = Transact {
+			origin_kind: OriginKind::Native,
+			require_weight_at_most: Weight::zero(),
+			call: Vec::new().into(),
+		};
+		assert_err_ignore_postinfo!(
+			execute_sandboxed(Xcm(vec![transact.clone()])),
+			Error::<Test>::SandboxFiltered
+		);
+		assert_err_ignore_postinfo!(
+			execute_sandboxed(Xcm(vec![ClearOrigin, SetAppendix(Xcm(vec![transact]))])),
+			Error::<Test>::SandboxFiltered
+		);
+
+		// The budget is reset in the next period.
+		System::set_block_number(11);
+		assert_ok!(execute_sandboxed(message));
+		assert_eq!(Balances::total_balance(&BOB), INITIAL_BALANCE + 3 * SEND_AMOUNT);
+	});
+}
+
+/// Test that the sandbox usage of accounts is removed once their budget period is over.
+#[test]
+fn expired_sandbox_usage_is_pruned() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let usage = |period_start| SandboxUsage { period_start, used: BaseXcmWeight::get() };
+		SandboxedExecutionUsage::<Test>::insert(ALICE, usage(1));
+		SandboxedExecutionUsage::<Test>::insert(BOB, usage(5));
+		assert_ok!(XcmPallet::set_sandbox_limits(
+			RuntimeOrigin::root(),
+			Some(SandboxLimits { weight_budget: BaseXcmWeight::get() * 2, period: 10 }),
+		));
+
+		// Only the usage of the account whose period is over is removed.
+		System::set_block_number(11);
+		XcmPallet::on_idle(11, Weight::MAX);
+		assert_eq!(SandboxedExecutionUsage::<Test>::get(ALICE), None);
+		assert_eq!(SandboxedExecutionUsage::<Test>::get(BOB), Some(usage(5)));
+		assert_eq!(SandboxUsagePruneCursor::<Test>::get(), None);
+
+		// All usage is removed once sandboxed execution is disabled.
+		assert_ok!(XcmPallet::set_sandbox_limits(RuntimeOrigin::root(), None));
+		XcmPallet::on_idle(11, Weight::MAX);
+		assert_eq!(SandboxedExecutionUsage::<Test>::iter().count(), 0);
+	});
+}
+
 /// Test drop/claim assets.
 #[test]
 fn trapped_assets_can_be_claimed() {
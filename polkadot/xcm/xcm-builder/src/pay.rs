@@ -64,6 +64,19 @@ pub struct PayOverXcm<
 		BeneficiaryRefToLocation,
 	)>,
 );
+
+/// The resolved parties of a payment made by [`PayOverXcm`].
+struct PayoutTarget {
+	/// The asset to be paid.
+	asset_id: AssetId,
+	/// The location at which the asset resides and to which the message is sent.
+	asset_location: Location,
+	/// The location of the local chain as seen from `asset_location`.
+	destination: Location,
+	/// The beneficiary as seen from `asset_location`.
+	beneficiary: Location,
+}
+
 impl<
 		Interior: Get<InteriorLocation>,
 		Router: SendXcm,
@@ -73,8 +86,8 @@ impl<
 		AssetKind,
 		AssetKindToLocatableAsset: TryConvert<AssetKind, LocatableAssetId>,
 		BeneficiaryRefToLocation: for<'a> TryConvert<&'a Beneficiary, Location>,
-	> Pay
-	for PayOverXcm<
+	>
+	PayOverXcm<
 		Interior,
 		Router,
 		Querier,
@@ -85,29 +98,43 @@ impl<
 		BeneficiaryRefToLocation,
 	>
 {
-	type Beneficiary = Beneficiary;
-	type AssetKind = AssetKind;
-	type Balance = u128;
-	type Id = QueryId;
-	type Error = xcm::latest::Error;
+	/// Simulate a [`Pay::pay`] of `amount` of `asset_kind` to `who` without registering a query or
+	/// sending the message.
+	///
+	/// Returns the location the message would be sent to, the message itself and the fees
+	/// charged for delivering it. As no query is registered, the message reports to the query
+	/// with ID `0` instead.
+	pub fn dry_run_pay(
+		who: &Beneficiary,
+		asset_kind: AssetKind,
+		amount: u128,
+	) -> Result<(Location, Xcm<()>, Assets), xcm::latest::Error> {
+		let target = Self::target(who, asset_kind)?;
+		let asset_location = target.asset_location.clone();
+		let message = Self::message(target, amount, 0);
+		let (_, fees) =
+			Router::validate(&mut Some(asset_location.clone()), &mut Some(message.clone()))?;
+		Ok((asset_location, message, fees))
+	}
 
-	fn pay(
-		who: &Self::Beneficiary,
-		asset_kind: Self::AssetKind,
-		amount: Self::Balance,
-	) -> Result<Self::Id, Self::Error> {
+	fn target(
+		who: &Beneficiary,
+		asset_kind: AssetKind,
+	) -> Result<PayoutTarget, xcm::latest::Error> {
 		let locatable = AssetKindToLocatableAsset::try_convert(asset_kind)
 			.map_err(|_| xcm::latest::Error::InvalidLocation)?;
 		let LocatableAssetId { asset_id, location: asset_location } = locatable;
 		let destination = Querier::UniversalLocation::get()
 			.invert_target(&asset_location)
-			.map_err(|()| Self::Error::LocationNotInvertible)?;
+			.map_err(|()| xcm::latest::Error::LocationNotInvertible)?;
 		let beneficiary = BeneficiaryRefToLocation::try_convert(&who)
 			.map_err(|_| xcm::latest::Error::InvalidLocation)?;
+		Ok(PayoutTarget { asset_id, asset_location, destination, beneficiary })
+	}
 
-		let query_id = Querier::new_query(asset_location.clone(), Timeout::get(), Interior::get());
-
-		let message = Xcm(vec![
+	fn message(target: PayoutTarget, amount: u128, query_id: QueryId) -> Xcm<()> {
+		let PayoutTarget { asset_id, destination, beneficiary, .. } = target;
+		Xcm(vec![
 			DescendOrigin(Interior::get()),
 			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
 			SetAppendix(Xcm(vec![
@@ -122,7 +149,48 @@ impl<
 				beneficiary,
 				assets: vec![Asset { id: asset_id, fun: Fungibility::Fungible(amount) }].into(),
 			},
-		]);
+		])
+	}
+}
+
+impl<
+		Interior: Get<InteriorLocation>,
+		Router: SendXcm,
+		Querier: QueryHandler,
+		Timeout: Get<Querier::BlockNumber>,
+		Beneficiary: Clone,
+		AssetKind,
+		AssetKindToLocatableAsset: TryConvert<AssetKind, LocatableAssetId>,
+		BeneficiaryRefToLocation: for<'a> TryConvert<&'a Beneficiary, Location>,
+	> Pay
+	for PayOverXcm<
+		Interior,
+		Router,
+		Querier,
+		Timeout,
+		Beneficiary,
+		AssetKind,
+		AssetKindToLocatableAsset,
+		BeneficiaryRefToLocation,
+	>
+{
+	type Beneficiary = Beneficiary;
+	type AssetKind = AssetKind;
+	type Balance = u128;
+	type Id = QueryId;
+	type Error = xcm::latest::Error;
+
+	fn pay(
+		who: &Self::Beneficiary,
+		asset_kind: Self::AssetKind,
+		amount: Self::Balance,
+	) -> Result<Self::Id, Self::Error> {
+		let target = Self::target(who, asset_kind)?;
+		let asset_location = target.asset_location.clone();
+
+		let query_id = Querier::new_query(asset_location.clone(), Timeout::get(), Interior::get());
+
+		let message = Self::message(target, amount, query_id);
 
 		let (ticket, _) = Router::validate(&mut Some(asset_location), &mut Some(message))?;
 		Router::deliver(ticket)?;
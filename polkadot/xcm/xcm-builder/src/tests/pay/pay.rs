@@ -174,3 +174,47 @@ fn pay_over_xcm_governance_body() {
 		assert_eq!(mock::Assets::balance(relay_asset_index, &recipient), amount);
 	});
 }
+
+/// [`PayOverXcm::dry_run_pay`] returns the message [`PayOverXcm::pay`] would send without sending
+/// it or registering a query.
+#[test]
+fn dry_run_pay_over_xcm_sends_nothing() {
+	let recipient = AccountId::new([5u8; 32]);
+	let asset_kind =
+		AssetKind { destination: (Parent, Parachain(2)).into(), asset_id: Here.into() };
+	let amount = 10 * UNITS;
+
+	new_test_ext().execute_with(|| {
+		let (destination, message, _) = PayOverXcm::<
+			InteriorAccount,
+			TestMessageSender,
+			TestQueryHandler<TestConfig, BlockNumber>,
+			Timeout,
+			AccountId,
+			AssetKind,
+			LocatableAssetKindConverter,
+			AliasesIntoAccountId32<AnyNetwork, AccountId>,
+		>::dry_run_pay(&recipient, asset_kind, amount)
+		.unwrap();
+
+		let expected_message = Xcm(vec![
+			DescendOrigin(AccountId32 { id: SenderAccount::get().into(), network: None }.into()),
+			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+			SetAppendix(Xcm(vec![
+				SetFeesMode { jit_withdraw: true },
+				ReportError(QueryResponseInfo {
+					destination: (Parent, Parachain(42)).into(),
+					query_id: 0,
+					max_weight: Weight::zero(),
+				}),
+			])),
+			TransferAsset {
+				assets: (Here, amount).into(),
+				beneficiary: AccountId32 { id: recipient.clone().into(), network: None }.into(),
+			},
+		]);
+		assert_eq!(destination, (Parent, Parachain(2)).into());
+		assert_eq!(message, expected_message);
+		assert!(sent_xcm().is_empty());
+	});
+}
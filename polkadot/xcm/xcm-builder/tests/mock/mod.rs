@@ -117,6 +117,7 @@ impl shared::Config for Runtime {
 }
 
 impl configuration::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = configuration::TestWeightInfo;
 }
 
@@ -237,6 +238,7 @@ construct_runtime!(
 		Balances: pallet_balances,
 		ParasOrigin: origin,
 		XcmPallet: pallet_xcm,
+		Configuration: configuration,
 	}
 );
 
@@ -28,3 +28,7 @@ pub mod dry_run;
 /// Fee estimation API.
 /// Given an XCM program, it will return the fees needed to execute it properly or send it.
 pub mod fees;
+
+/// Transfer validation API.
+/// Given an asset transfer, it returns the reasons for which it would fail on the local chain.
+pub mod transfers;
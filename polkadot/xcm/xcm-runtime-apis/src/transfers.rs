@@ -0,0 +1,110 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for validating asset transfers before submitting them.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+use xcm::{VersionedAssets, VersionedLocation};
+
+sp_api::decl_runtime_apis! {
+	/// API for checking whether an asset transfer would pass the checks of the local chain.
+	///
+	/// The checks mirror those of the `transfer_assets` extrinsic of `pallet-xcm`. The transfer is
+	/// executed to check it, but none of its effects are kept. Use the
+	/// [`crate::dry_run::DryRunApi`] to inspect the effects of a transfer.
+	pub trait TransferValidationApi {
+		/// Validate a transfer of `assets` from `origin` to `beneficiary` on `dest`, paying the
+		/// fees with the asset at index `fee_asset_item` of `assets`.
+		///
+		/// Returns all reasons for which the transfer would fail, which is empty if it passes all
+		/// checks.
+		fn validate_transfer(
+			origin: VersionedLocation,
+			dest: VersionedLocation,
+			beneficiary: VersionedLocation,
+			assets: VersionedAssets,
+			fee_asset_item: u32,
+		) -> Vec<TransferFailure>;
+	}
+}
+
+/// A reason for which an asset transfer would fail.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub enum TransferFailure {
+	/// Converting a versioned data structure from one version to another failed.
+	#[codec(index = 0)]
+	VersionedConversionFailed,
+
+	/// The XCM version of the destination is unknown and there is no safe default version.
+	#[codec(index = 1)]
+	UnknownDestinationVersion,
+
+	/// More assets than supported are transferred.
+	#[codec(index = 2)]
+	TooManyAssets,
+
+	/// The fee asset item does not refer to one of the assets.
+	#[codec(index = 3)]
+	InvalidFeeAssetItem,
+
+	/// No assets are transferred or a fungible asset has an amount of zero.
+	#[codec(index = 4)]
+	Empty,
+
+	/// The reserve of an asset could not be determined.
+	#[codec(index = 5)]
+	UnknownReserve,
+
+	/// The assets have different reserves, or a combination of the reserves of the assets and
+	/// of the fees is not supported.
+	#[codec(index = 6)]
+	UnsupportedReserves,
+
+	/// Transferring the assets to the destination is forbidden by the local filters.
+	#[codec(index = 7)]
+	Filtered,
+
+	/// The assets could not be expressed from the point of view of the destination.
+	#[codec(index = 8)]
+	CannotReanchor,
+
+	/// The weight of the local XCM program could not be determined.
+	#[codec(index = 9)]
+	UnweighableMessage,
+
+	/// The message to the destination could not be routed.
+	#[codec(index = 10)]
+	Unroutable,
+
+	/// The origin can not pay the fees for delivering the message to the destination.
+	#[codec(index = 11)]
+	DeliveryFeesNotMet,
+
+	/// Any other reason.
+	#[codec(index = 12)]
+	Other,
+
+	/// The origin is not permitted to execute the local XCM program of the transfer.
+	#[codec(index = 13)]
+	ExecutionNotPermitted,
+
+	/// The execution of the local XCM program of the transfer failed, e.g. since the origin does
+	/// not hold the assets.
+	#[codec(index = 14)]
+	LocalExecutionFailed,
+}
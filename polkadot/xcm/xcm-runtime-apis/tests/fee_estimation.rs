@@ -102,6 +102,10 @@ fn fee_estimation_for_teleport() {
 					account: 8660274132218572653,
 					free_balance: 100
 				}),
+				RuntimeEvent::Balances(pallet_balances::Event::IssuanceIncreased {
+					amount: 100,
+					reason: pallet_balances::IssuanceChangeReason::Minted
+				}),
 				RuntimeEvent::Balances(pallet_balances::Event::Minted {
 					who: 8660274132218572653,
 					amount: 100
@@ -111,10 +115,18 @@ fn fee_estimation_for_teleport() {
 					owner: 1,
 					balance: 20
 				}),
+				RuntimeEvent::Balances(pallet_balances::Event::IssuanceDecreased {
+					amount: 100,
+					reason: pallet_balances::IssuanceChangeReason::Burned
+				}),
 				RuntimeEvent::Balances(pallet_balances::Event::Burned { who: 1, amount: 100 }),
 				RuntimeEvent::XcmPallet(pallet_xcm::Event::Attempted {
 					outcome: Outcome::Complete { used: Weight::from_parts(400, 40) },
 				}),
+				RuntimeEvent::Balances(pallet_balances::Event::IssuanceDecreased {
+					amount: 20,
+					reason: pallet_balances::IssuanceChangeReason::Burned
+				}),
 				RuntimeEvent::Balances(pallet_balances::Event::Burned { who: 1, amount: 20 }),
 				RuntimeEvent::XcmPallet(pallet_xcm::Event::FeesPaid {
 					paying: AccountIndex64 { index: 1, network: None }.into(),
@@ -125,6 +137,7 @@ fn fee_estimation_for_teleport() {
 					destination: (Parent, Parachain(1000)).into(),
 					message: send_message.clone(),
 					message_id: fake_message_hash(&send_message),
+					topic_id: fake_message_hash(&send_message),
 				}),
 			]
 		);
@@ -256,6 +269,10 @@ fn dry_run_reserve_asset_transfer() {
 				RuntimeEvent::XcmPallet(pallet_xcm::Event::Attempted {
 					outcome: Outcome::Complete { used: Weight::from_parts(200, 20) }
 				}),
+				RuntimeEvent::Balances(pallet_balances::Event::IssuanceDecreased {
+					amount: 20,
+					reason: pallet_balances::IssuanceChangeReason::Burned
+				}),
 				RuntimeEvent::Balances(pallet_balances::Event::Burned { who: 1, amount: 20 }),
 				RuntimeEvent::XcmPallet(pallet_xcm::Event::FeesPaid {
 					paying: AccountIndex64 { index: 1, network: None }.into(),
@@ -266,6 +283,7 @@ fn dry_run_reserve_asset_transfer() {
 					destination: send_destination.clone(),
 					message: send_message.clone(),
 					message_id: fake_message_hash(&send_message),
+					topic_id: fake_message_hash(&send_message),
 				}),
 			]
 		);
@@ -342,12 +360,20 @@ fn dry_run_xcm() {
 		assert_eq!(
 			dry_run_effects.emitted_events,
 			vec![
+				RuntimeEvent::Balances(pallet_balances::Event::IssuanceDecreased {
+					amount: 540,
+					reason: pallet_balances::IssuanceChangeReason::Burned
+				}),
 				RuntimeEvent::Balances(pallet_balances::Event::Burned { who: 1, amount: 540 }),
 				RuntimeEvent::System(frame_system::Event::NewAccount { account: 2100 }),
 				RuntimeEvent::Balances(pallet_balances::Event::Endowed {
 					account: 2100,
 					free_balance: 520
 				}),
+				RuntimeEvent::Balances(pallet_balances::Event::IssuanceIncreased {
+					amount: 520,
+					reason: pallet_balances::IssuanceChangeReason::Minted
+				}),
 				RuntimeEvent::Balances(pallet_balances::Event::Minted { who: 2100, amount: 520 }),
 			]
 		);
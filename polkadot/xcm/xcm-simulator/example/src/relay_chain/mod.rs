@@ -88,6 +88,7 @@ impl shared::Config for Runtime {
 }
 
 impl configuration::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = configuration::TestWeightInfo;
 }
 
@@ -161,6 +162,7 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = MessageQueueMaxStale;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = ();
+	type OnPageReaped = ();
 	type MessageProcessor = MessageProcessor;
 	type QueueChangeHandler = ();
 	type QueuePausedQuery = ();
@@ -176,5 +178,6 @@ construct_runtime!(
 		XcmPallet: pallet_xcm,
 		Uniques: pallet_uniques,
 		MessageQueue: pallet_message_queue,
+		Configuration: configuration,
 	}
 );
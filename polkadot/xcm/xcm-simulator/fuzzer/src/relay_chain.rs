@@ -86,6 +86,7 @@ impl shared::Config for Runtime {
 }
 
 impl configuration::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = configuration::TestWeightInfo;
 }
 
@@ -219,6 +220,7 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = MessageQueueMaxStale;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = ();
+	type OnPageReaped = ();
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type MessageProcessor = MessageProcessor;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -237,5 +239,6 @@ construct_runtime!(
 		ParasOrigin: origin,
 		XcmPallet: pallet_xcm,
 		MessageQueue: pallet_message_queue,
+		Configuration: configuration,
 	}
 );
@@ -79,6 +79,29 @@ impl IdentityVerifier<AccountId> for AllianceIdentityVerifier {
 	}
 }
 
+/// Provides the display of identities to the indices pallet.
+///
+/// Only identities with a `KnownGood` or `Reasonable` judgement are provided, as anyone can set
+/// any display name on an identity without one.
+pub struct IndicesIdentityProvider;
+impl pallet_indices::IdentityProvider<AccountId> for IndicesIdentityProvider {
+	fn display(who: &AccountId) -> Option<Vec<u8>> {
+		use pallet_identity::Judgement;
+		let (registration, _) = crate::Identity::identity(who)?;
+		if !registration
+			.judgements
+			.iter()
+			.any(|(_, j)| matches!(j, Judgement::KnownGood | Judgement::Reasonable))
+		{
+			return None
+		}
+		match registration.info.display {
+			pallet_identity::Data::Raw(display) => Some(display.into_inner()),
+			_ => None,
+		}
+	}
+}
+
 pub struct AllianceProposalProvider;
 impl ProposalProvider<AccountId, Hash, RuntimeCall> for AllianceProposalProvider {
 	fn propose_proposal(
@@ -43,11 +43,13 @@ use frame_support::{
 			Balanced, Credit, HoldConsideration, ItemOf, NativeFromLeft, NativeOrWithId, UnionOf,
 		},
 		tokens::{
-			imbalance::ResolveAssetTo, nonfungibles_v2::Inspect, pay::PayAssetFromAccount,
+			imbalance::{ResolveAssetTo, ResolveTo},
+			nonfungibles_v2::Inspect,
+			pay::PayAssetFromAccount,
 			GetSalary, PayFromAccount,
 		},
-		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU16, ConstU32, Contains, Currency,
-		EitherOfDiverse, EnsureOriginWithArg, EqualPrivilegeOnly, Imbalance, InsideBoth,
+		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU16, ConstU32, ConstU8, Contains,
+		Currency, EitherOfDiverse, EnsureOriginWithArg, EqualPrivilegeOnly, Imbalance, InsideBoth,
 		InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice, LockIdentifier, Nothing,
 		OnUnbalanced, VariantCountOf, WithdrawReasons,
 	},
@@ -345,9 +347,22 @@ impl pallet_multisig::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type OldCurrency = Balances;
+	type CallFee = pallet_multisig::TransactionPaymentFee<Runtime>;
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU32<100>;
+	type MaxMetadataLen = ConstU32<64>;
+	type Preimages = Preimage;
+	type BlockNumberProvider = System;
+	type Scheduler = Scheduler;
+	type MaxDeadline = ConstU32<{ 30 * DAYS }>;
+	type PalletsOrigin = OriginCaller;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as traits::Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
 }
 
@@ -441,6 +456,11 @@ impl pallet_proxy::Config for Runtime {
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
 		RuntimeBlockWeights::get().max_block;
+	pub const MaximumSchedulerBlockTime: u64 = 2 * MILLISECS_PER_BLOCK;
+	pub const SchedulerDeposit: Balance = deposit(1, 128);
+	pub const MaxScheduledPerOrigin: Option<u32> = None;
+	pub const MaxSchedulingHorizon: Option<BlockNumber> = Some(365 * DAYS);
+	pub MaxIdleSchedulerWeight: Option<Weight> = Some(MaximumSchedulerWeight::get());
 }
 
 impl pallet_scheduler::Config for Runtime {
@@ -454,9 +474,22 @@ impl pallet_scheduler::Config for Runtime {
 	type MaxScheduledPerBlock = ConstU32<512>;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type MaxScheduledPerBlock = ConstU32<50>;
+	type BlockNumberProvider = System;
 	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
+	type RejectOverweight = ConstBool<false>;
+	type TimeProvider = Timestamp;
+	type MaximumBlockTime = MaximumSchedulerBlockTime;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type ScheduleDeposit = SchedulerDeposit;
+	type MaxScheduledPerOrigin = MaxScheduledPerOrigin;
+	type MaxSchedulingHorizon = MaxSchedulingHorizon;
+	type MaxMergedAgendas = ConstU32<1>;
+	type MaxIdleWeight = MaxIdleSchedulerWeight;
+	type MaxOverflowed = ConstU32<512>;
+	type OnChargeDispatch = ();
 }
 
 impl pallet_glutton::Config for Runtime {
@@ -474,15 +507,12 @@ impl pallet_preimage::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type ManagerOrigin = EnsureRoot<AccountId>;
-	type Consideration = HoldConsideration<
-		AccountId,
-		Balances,
-		PreimageHoldReason,
-		LinearStoragePrice<
-			dynamic_params::storage::BaseDeposit,
-			dynamic_params::storage::ByteDeposit,
-			Balance,
-		>,
+	type Consideration =
+		HoldConsideration<AccountId, Balances, PreimageHoldReason, traits::Identity, Balance>;
+	type DepositPrice = LinearStoragePrice<
+		dynamic_params::storage::BaseDeposit,
+		dynamic_params::storage::ByteDeposit,
+		Balance,
 	>;
 }
 
@@ -518,6 +548,7 @@ impl pallet_indices::Config for Runtime {
 	type Currency = Balances;
 	type Deposit = IndexDeposit;
 	type RuntimeEvent = RuntimeEvent;
+	type IdentityProvider = impls::IndicesIdentityProvider;
 	type WeightInfo = pallet_indices::weights::SubstrateWeight<Runtime>;
 }
 
@@ -631,6 +662,7 @@ impl pallet_session::Config for Runtime {
 	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, Staking>;
 	type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
+	type RequireProofOfPossession = ConstBool<false>;
 	type WeightInfo = pallet_session::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1002,8 +1034,11 @@ impl pallet_referenda::Config for Runtime {
 	type MaxQueued = ConstU32<100>;
 	type UndecidingTimeout = UndecidingTimeout;
 	type AlarmInterval = AlarmInterval;
+	type MaxEnactmentHorizon = MaxSchedulingHorizon;
 	type Tracks = TracksInfo;
 	type Preimages = Preimage;
+	type ProposalValue = ();
+	type ProposalConsideration = ();
 }
 
 impl pallet_referenda::Config<pallet_referenda::Instance2> for Runtime {
@@ -1022,8 +1057,11 @@ impl pallet_referenda::Config<pallet_referenda::Instance2> for Runtime {
 	type MaxQueued = ConstU32<100>;
 	type UndecidingTimeout = UndecidingTimeout;
 	type AlarmInterval = AlarmInterval;
+	type MaxEnactmentHorizon = MaxSchedulingHorizon;
 	type Tracks = TracksInfo;
 	type Preimages = Preimage;
+	type ProposalValue = ();
+	type ProposalConsideration = ();
 }
 
 impl pallet_ranked_collective::Config for Runtime {
@@ -1247,6 +1285,9 @@ impl pallet_treasury::Config for Runtime {
 	type Paymaster = PayAssetFromAccount<Assets, TreasuryAccount>;
 	type BalanceConverter = AssetRate;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type Clawback = ();
+	type SpendSubmitter = ();
+	type SpendConsideration = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -1257,6 +1298,7 @@ impl pallet_asset_rate::Config for Runtime {
 	type UpdateOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type AssetKind = u32;
+	type NativeDecimals = ConstU8<14>;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_asset_rate::weights::SubstrateWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1272,6 +1314,10 @@ parameter_types! {
 	pub const CuratorDepositMax: Balance = 100 * DOLLARS;
 	pub const BountyDepositPayoutDelay: BlockNumber = 1 * DAYS;
 	pub const BountyUpdatePeriod: BlockNumber = 14 * DAYS;
+	pub const BountyFundedExpiry: BlockNumber = 30 * DAYS;
+	pub const BountyWithdrawalFee: Permill = Permill::from_percent(5);
+	pub const CuratorSlashReporterShare: Permill = Permill::from_percent(10);
+	pub const CuratorSlashAppealPeriod: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_bounties::Config for Runtime {
@@ -1279,15 +1325,26 @@ impl pallet_bounties::Config for Runtime {
 	type BountyDepositBase = BountyDepositBase;
 	type BountyDepositPayoutDelay = BountyDepositPayoutDelay;
 	type BountyUpdatePeriod = BountyUpdatePeriod;
+	type FundedExpiry = BountyFundedExpiry;
 	type CuratorDepositMultiplier = CuratorDepositMultiplier;
 	type CuratorDepositMin = CuratorDepositMin;
 	type CuratorDepositMax = CuratorDepositMax;
 	type BountyValueMinimum = BountyValueMinimum;
 	type DataDepositPerByte = DataDepositPerByte;
+	type BountyWithdrawalFee = BountyWithdrawalFee;
+	type CuratorSlashReporterShare = CuratorSlashReporterShare;
+	type CuratorSlashAppealPeriod = CuratorSlashAppealPeriod;
 	type MaximumReasonLength = MaximumReasonLength;
 	type WeightInfo = pallet_bounties::weights::SubstrateWeight<Runtime>;
 	type ChildBountyManager = ChildBounties;
+	type PayoutGuard = ();
 	type OnSlash = Treasury;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type NativeBalance = Balances;
+	type OnDepositSlash = ResolveTo<TreasuryAccount, Balances>;
+	type BlockNumberProvider = System;
+	type BeneficiaryAccount = traits::Identity;
+	type NativeAssetKind = ();
 }
 
 parameter_types! {
@@ -1309,6 +1366,7 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = ConstU32<128>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = ();
+	type OnPageReaped = ();
 }
 
 parameter_types! {
@@ -1319,6 +1377,7 @@ impl pallet_child_bounties::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = ConstU32<5>;
 	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type MaxDepth = ConstU32<3>;
 	type WeightInfo = pallet_child_bounties::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1516,6 +1575,7 @@ impl pallet_identity::Config for Runtime {
 	type MaxSubAccounts = MaxSubAccounts;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
+	type MaxJudgementBatch = ConstU32<32>;
 	type Slashed = Treasury;
 	type ForceOrigin = EnsureRootOrHalfCouncil;
 	type RegistrarOrigin = EnsureRootOrHalfCouncil;
@@ -1555,6 +1615,8 @@ parameter_types! {
 	pub const ChallengePeriod: BlockNumber = 7 * DAYS;
 	pub const MaxPayouts: u32 = 10;
 	pub const MaxBids: u32 = 10;
+	pub const PayoutSmoothingThreshold: Option<Balance> = Some(5_000 * DOLLARS);
+	pub const PayoutSmoothingPeriods: u32 = 4;
 	pub const SocietyPalletId: PalletId = PalletId(*b"py/socie");
 }
 
@@ -1573,6 +1635,8 @@ impl pallet_society::Config for Runtime {
 	type ChallengePeriod = ChallengePeriod;
 	type MaxPayouts = MaxPayouts;
 	type MaxBids = MaxBids;
+	type PayoutSmoothingThreshold = PayoutSmoothingThreshold;
+	type PayoutSmoothingPeriods = PayoutSmoothingPeriods;
 	type WeightInfo = pallet_society::weights::SubstrateWeight<Runtime>;
 }
 
@@ -2523,6 +2587,10 @@ pub type Executive = frame_executive::Executive<
 // We don't have a limit in the Relay Chain.
 const IDENTITY_MIGRATION_KEY_LIMIT: u64 = u64::MAX;
 
+parameter_types! {
+	pub const ChildBountiesPalletName: &'static str = "ChildBounties";
+}
+
 // All migrations executed on runtime upgrade as a nested tuple of types implementing
 // `OnRuntimeUpgrade`. Note: These are examples and do not need to be run directly
 // after the genesis block.
@@ -2531,6 +2599,19 @@ type Migrations = (
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
 	pallet_identity::migration::versioned::V0ToV1<Runtime, IDENTITY_MIGRATION_KEY_LIMIT>,
+	pallet_bounties::migrations::v5::MigrateV4ToV5<
+		Runtime,
+		(),
+		System,
+		sp_runtime::traits::Identity,
+		ChildBountiesPalletName,
+	>,
+	pallet_bounties::migrations::v6::MigrateV5ToV6<Runtime, ()>,
+	pallet_bounties::migrations::v7::MigrateV6ToV7<Runtime, (), sp_runtime::traits::Identity>,
+	pallet_bounties::migrations::v8::MigrateV7ToV8<Runtime, ()>,
+	pallet_bounties::migrations::v9::MigrateV8ToV9<Runtime, ()>,
+	pallet_asset_rate::migration::v1::MigrateV0ToV1<Runtime, frame_support::traits::GetDefault>,
+	pallet_multisig::migrations::v2::MigrateToV2<Runtime>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -2848,6 +2929,117 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl frame_system_rpc_runtime_api::BlockDigestApi<Block, AccountId> for Runtime {
+		fn block_author() -> Option<AccountId> {
+			Babe::block_author()
+		}
+
+		fn pre_runtime_digests() -> Vec<frame_system_rpc_runtime_api::PreRuntimeDigest<AccountId>> {
+			Babe::pre_runtime_digests()
+		}
+	}
+
+	impl pallet_indices::runtime_api::IndicesApi<Block, AccountIndex, AccountId> for Runtime {
+		fn resolve_index(index: AccountIndex) -> Option<(AccountId, Option<Vec<u8>>)> {
+			Indices::resolve_index(index)
+		}
+	}
+
+	impl pallet_bounties::runtime_api::BountiesApi<
+		Block,
+		AccountId,
+		Balance,
+		BlockNumber,
+		u32,
+		Balance,
+	> for Runtime {
+		fn bounty(
+			index: pallet_bounties::BountyIndex,
+		) -> Option<pallet_bounties::BountySummaryOf<Runtime>> {
+			Bounties::bounty_summary(index)
+		}
+
+		fn bounties(
+			after: Option<pallet_bounties::BountyCursor>,
+			limit: u32,
+		) -> Vec<pallet_bounties::BountySummaryOf<Runtime>> {
+			Bounties::bounty_summaries(after, limit)
+		}
+
+		fn curator_deposit(fee: Balance) -> Balance {
+			Bounties::calculate_curator_deposit(&fee)
+		}
+
+		fn approvals_capacity() -> u32 {
+			Bounties::approvals_capacity()
+		}
+
+		fn bounty_ledger(
+			index: pallet_bounties::BountyIndex,
+		) -> Option<pallet_bounties::BountyLedger<Balance>> {
+			pallet_bounties::BountyLedgers::<Runtime>::get(index)
+		}
+
+		fn total_encumbered() -> Balance {
+			pallet_bounties::TotalEncumbered::<Runtime>::get()
+		}
+	}
+
+	impl pallet_multisig::runtime_api::MultisigApi<Block, AccountId> for Runtime {
+		fn multi_account_id(signatories: Vec<AccountId>, threshold: u16) -> AccountId {
+			Multisig::derive_multi_account_id(signatories, threshold)
+		}
+	}
+
+	impl pallet_proxy::runtime_api::ProxyApi<Block, AccountId, ProxyType, BlockNumber, Balance> for Runtime {
+		fn namespaced_pure_account(
+			spawner: AccountId,
+			namespace: pallet_proxy::PureNamespace,
+			index: u16,
+		) -> AccountId {
+			Proxy::namespaced_pure_account(&spawner, namespace, index)
+		}
+
+		fn pure_proxies(
+			spawner: AccountId,
+			namespace: pallet_proxy::PureNamespace,
+		) -> Vec<(u16, pallet_proxy::PureProxyInfo<AccountId, ProxyType, BlockNumber, Balance>)> {
+			Proxy::pure_proxies(&spawner, namespace)
+		}
+	}
+
+	impl pallet_message_queue::runtime_api::MessageQueueApi<Block, u32> for Runtime {
+		fn reapable_pages(limit: u32) -> Vec<(u32, u32)> {
+			MessageQueue::reapable_pages(limit)
+		}
+	}
+
+	impl pallet_scheduler::runtime_api::SchedulerApi<Block, BlockNumber, Hash> for Runtime {
+		fn lookup(
+			id: frame_support::traits::schedule::v3::TaskName,
+		) -> Option<pallet_scheduler::TaskSummary<BlockNumber, Hash>> {
+			Scheduler::lookup(id)
+		}
+
+		fn agenda(when: BlockNumber) -> Vec<pallet_scheduler::TaskSummary<BlockNumber, Hash>> {
+			Scheduler::agenda(when)
+		}
+
+		fn next_dispatch(
+			id: frame_support::traits::schedule::v3::TaskName,
+		) -> Option<BlockNumber> {
+			Scheduler::next_dispatch(id)
+		}
+
+		fn agenda_occupancy(when: BlockNumber) -> pallet_scheduler::AgendaOccupancy {
+			Scheduler::agenda_occupancy(when)
+		}
+
+		fn total_scheduled() -> u32 {
+			Scheduler::total_scheduled()
+		}
+	}
+
 	impl assets_api::AssetsApi<
 		Block,
 		AccountId,
@@ -2934,6 +3126,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(5)]
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -2950,6 +3143,14 @@ impl_runtime_apis! {
 		fn query_length_to_fee(length: u32) -> Balance {
 			TransactionPayment::length_to_fee(length)
 		}
+		fn query_priority_info(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			tip: Balance,
+			target_priority: sp_runtime::transaction_validity::TransactionPriority,
+		) -> pallet_transaction_payment::PriorityInfo<Balance> {
+			TransactionPayment::query_priority_info(uxt, len, tip, target_priority)
+		}
 	}
 
 	impl pallet_asset_conversion::AssetConversionApi<
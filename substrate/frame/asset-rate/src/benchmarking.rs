@@ -97,5 +97,21 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_decimals() -> Result<(), BenchmarkError> {
+		let asset_kind: T::AssetKind = T::BenchmarkHelper::create_asset_kind(SEED);
+		assert_ok!(AssetRate::<T>::create(
+			RawOrigin::Root.into(),
+			Box::new(asset_kind.clone()),
+			default_conversion_rate()
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, Box::new(asset_kind.clone()), Some(6));
+
+		assert_eq!(pallet_asset_rate::AssetDecimals::<T>::get(asset_kind), Some(6));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! { AssetRate, crate::mock::new_test_ext(), crate::mock::Test }
 }
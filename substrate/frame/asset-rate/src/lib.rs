@@ -47,6 +47,7 @@
 //! * `create`: Creates a new asset conversion rate.
 //! * `remove`: Removes an existing asset conversion rate.
 //! * `update`: Overwrites an existing assert conversion rate.
+//! * `set_decimals`: Sets or clears the decimals of an asset with a conversion rate.
 //!
 //! Please refer to the [`Call`] enum and its associated variants for documentation on each
 //! function.
@@ -56,15 +57,19 @@
 //! * Conversion rates are only used as estimates, and are not designed to be precise or closely
 //!   tracking real world values.
 //! * All conversion rates reflect the ration of some asset to native, e.g. native = asset * rate.
+//! * Rates are given between whole units of the asset and the native token. The balances are scaled
+//!   by the difference between [`AssetDecimals`] of the asset and [`Config::NativeDecimals`] when
+//!   converted. Assets without decimals are assumed to have as many as the native token.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::traits::{
 	fungible::Inspect,
 	tokens::{ConversionFromAssetBalance, ConversionToAssetBalance},
+	Get,
 };
 use sp_runtime::{
-	traits::{CheckedDiv, Zero},
+	traits::{CheckedDiv, Saturating, Zero},
 	FixedPointNumber, FixedU128,
 };
 use sp_std::boxed::Box;
@@ -74,6 +79,7 @@ pub use weights::WeightInfo;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migration;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -95,7 +101,11 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -121,6 +131,10 @@ pub mod pallet {
 		/// The type for asset kinds for which the conversion rate to native balance is set.
 		type AssetKind: Parameter + MaxEncodedLen;
 
+		/// The number of decimals of the native balance.
+		#[pallet::constant]
+		type NativeDecimals: Get<u8>;
+
 		/// Helper type for benchmarks.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: crate::AssetKindFactory<Self::AssetKind>;
@@ -133,6 +147,13 @@ pub mod pallet {
 	pub type ConversionRateToNative<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssetKind, FixedU128, OptionQuery>;
 
+	/// Maps an asset to the number of decimals of its balance.
+	///
+	/// Assets without an entry are assumed to have [`Config::NativeDecimals`].
+	#[pallet::storage]
+	pub type AssetDecimals<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetKind, u8, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -142,6 +163,8 @@ pub mod pallet {
 		AssetRateRemoved { asset_kind: T::AssetKind },
 		// Some existing `asset_kind` conversion rate was updated from `old` to `new`.
 		AssetRateUpdated { asset_kind: T::AssetKind, old: FixedU128, new: FixedU128 },
+		// The decimals of some `asset_kind` were set, or cleared if `None`.
+		AssetDecimalsSet { asset_kind: T::AssetKind, decimals: Option<u8> },
 	}
 
 	#[pallet::error]
@@ -226,10 +249,55 @@ pub mod pallet {
 				Error::<T>::UnknownAssetKind
 			);
 			ConversionRateToNative::<T>::remove(asset_kind.as_ref());
+			AssetDecimals::<T>::remove(asset_kind.as_ref());
 
 			Self::deposit_event(Event::AssetRateRemoved { asset_kind: *asset_kind });
 			Ok(())
 		}
+
+		/// Set the number of decimals of the given asset, or clear it to assume the decimals of
+		/// the native balance.
+		///
+		/// The asset must have a conversion rate, and its decimals are removed along with it.
+		///
+		/// ## Complexity
+		/// - O(1)
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_decimals())]
+		pub fn set_decimals(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			decimals: Option<u8>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				ConversionRateToNative::<T>::contains_key(asset_kind.as_ref()),
+				Error::<T>::UnknownAssetKind
+			);
+			AssetDecimals::<T>::set(asset_kind.as_ref(), decimals);
+
+			Self::deposit_event(Event::AssetDecimalsSet { asset_kind: *asset_kind, decimals });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The number of decimals of the balance of `asset_kind`.
+	pub fn decimals(asset_kind: &T::AssetKind) -> u8 {
+		AssetDecimals::<T>::get(asset_kind).unwrap_or_else(T::NativeDecimals::get)
+	}
+
+	/// Apply `rate` to `balance` and scale it from `from` to `to` decimals, rounding down.
+	fn convert(balance: BalanceOf<T>, rate: FixedU128, from: u8, to: u8) -> BalanceOf<T> {
+		let ten = BalanceOf::<T>::from(10u32);
+		// Scale up before and down after applying the rate so no precision is lost.
+		if to >= from {
+			rate.saturating_mul_int(balance.saturating_mul(ten.saturating_pow((to - from).into())))
+		} else {
+			rate.saturating_mul_int(balance) / ten.saturating_pow((from - to).into())
+		}
 	}
 }
 
@@ -244,9 +312,9 @@ where
 		balance: BalanceOf<T>,
 		asset_kind: AssetKindOf<T>,
 	) -> Result<BalanceOf<T>, pallet::Error<T>> {
-		let rate = pallet::ConversionRateToNative::<T>::get(asset_kind)
+		let rate = pallet::ConversionRateToNative::<T>::get(&asset_kind)
 			.ok_or(pallet::Error::<T>::UnknownAssetKind.into())?;
-		Ok(rate.saturating_mul_int(balance))
+		Ok(Self::convert(balance, rate, Self::decimals(&asset_kind), T::NativeDecimals::get()))
 	}
 	/// Set a conversion rate to `1` for the `asset_id`.
 	#[cfg(feature = "runtime-benchmarks")]
@@ -266,13 +334,13 @@ where
 		balance: BalanceOf<T>,
 		asset_kind: AssetKindOf<T>,
 	) -> Result<BalanceOf<T>, pallet::Error<T>> {
-		let rate = pallet::ConversionRateToNative::<T>::get(asset_kind)
+		let rate = pallet::ConversionRateToNative::<T>::get(&asset_kind)
 			.ok_or(pallet::Error::<T>::UnknownAssetKind.into())?;
 
 		// We cannot use `saturating_div` here so we use `checked_div`.
-		Ok(FixedU128::from_u32(1)
+		let inverse = FixedU128::from_u32(1)
 			.checked_div(&rate)
-			.ok_or(pallet::Error::<T>::Overflow.into())?
-			.saturating_mul_int(balance))
+			.ok_or(pallet::Error::<T>::Overflow.into())?;
+		Ok(Self::convert(balance, inverse, T::NativeDecimals::get(), Self::decimals(&asset_kind)))
 	}
 }
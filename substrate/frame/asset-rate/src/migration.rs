@@ -0,0 +1,78 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the asset rate pallet.
+
+use super::*;
+use frame_support::{
+	migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade, weights::Weight,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub mod v1 {
+	use super::*;
+
+	/// Actual implementation of [`MigrateV0ToV1`].
+	///
+	/// Before V1 the decimals of an asset had to be baked into its rate. For every asset kind
+	/// and its number of decimals given by `Decimals`, the decimals are stored in
+	/// [`AssetDecimals`] and taken out of its rate, which then relates whole units of the asset
+	/// and the native token. Rates of the assets which are not given are left untouched, as their
+	/// decimals are assumed to match those of the native token.
+	pub struct UncheckedMigrateToV1<T, Decimals>(PhantomData<(T, Decimals)>);
+
+	impl<T, Decimals> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T, Decimals>
+	where
+		T: Config,
+		Decimals: Get<Vec<(T::AssetKind, u8)>>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let native_decimals = T::NativeDecimals::get();
+			let ten = FixedU128::saturating_from_integer(10u32);
+			let mut weight = Weight::zero();
+
+			for (asset_kind, decimals) in Decimals::get() {
+				weight.saturating_accrue(T::DbWeight::get().reads(1));
+				let Some(rate) = ConversionRateToNative::<T>::get(&asset_kind) else { continue };
+
+				// The old rate already contains the scaling of `10^(native - asset)`.
+				let rate = if decimals >= native_decimals {
+					rate.saturating_mul(ten.saturating_pow((decimals - native_decimals).into()))
+				} else {
+					rate.checked_div(&ten.saturating_pow((native_decimals - decimals).into()))
+						.unwrap_or(rate)
+				};
+				ConversionRateToNative::<T>::insert(&asset_kind, rate);
+				AssetDecimals::<T>::insert(&asset_kind, decimals);
+				weight.saturating_accrue(T::DbWeight::get().writes(2));
+			}
+
+			weight
+		}
+	}
+
+	/// Migrate the pallet storage from V0 to V1.
+	///
+	/// See [`UncheckedMigrateToV1`].
+	pub type MigrateV0ToV1<T, Decimals> = VersionedMigration<
+		0,
+		1,
+		UncheckedMigrateToV1<T, Decimals>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
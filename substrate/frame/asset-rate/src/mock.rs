@@ -18,7 +18,7 @@
 //! The crate's mock.
 
 use crate as pallet_asset_rate;
-use frame_support::derive_impl;
+use frame_support::{derive_impl, traits::ConstU8};
 use sp_runtime::BuildStorage;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -51,6 +51,7 @@ impl pallet_asset_rate::Config for Test {
 	type UpdateOrigin = frame_system::EnsureRoot<u64>;
 	type Currency = Balances;
 	type AssetKind = u32;
+	type NativeDecimals = ConstU8<12>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -176,3 +176,102 @@ fn convert_overflow_throws() {
 		assert!(conversion.is_err());
 	});
 }
+
+#[test]
+fn set_decimals_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetRate::set_decimals(RuntimeOrigin::root(), Box::new(ASSET_ID), Some(6)),
+			Error::<Test>::UnknownAssetKind
+		);
+		assert_ok!(AssetRate::create(
+			RuntimeOrigin::root(),
+			Box::new(ASSET_ID),
+			FixedU128::from_float(0.1)
+		));
+		assert_eq!(AssetRate::decimals(&ASSET_ID), 12);
+
+		assert_ok!(AssetRate::set_decimals(RuntimeOrigin::root(), Box::new(ASSET_ID), Some(6)));
+		assert_eq!(pallet_asset_rate::AssetDecimals::<Test>::get(ASSET_ID), Some(6));
+		assert_eq!(AssetRate::decimals(&ASSET_ID), 6);
+
+		// The decimals are removed along with the rate.
+		assert_ok!(AssetRate::remove(RuntimeOrigin::root(), Box::new(ASSET_ID)));
+		assert!(pallet_asset_rate::AssetDecimals::<Test>::get(ASSET_ID).is_none());
+	});
+}
+
+#[test]
+fn convert_scales_by_decimals() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetRate::create(
+			RuntimeOrigin::root(),
+			Box::new(ASSET_ID),
+			FixedU128::from_u32(2)
+		));
+		let from_asset = |balance| {
+			<AssetRate as ConversionFromAssetBalance<
+				BalanceOf<Test>,
+				<Test as pallet_asset_rate::Config>::AssetKind,
+				BalanceOf<Test>,
+			>>::from_asset_balance(balance, ASSET_ID)
+			.unwrap()
+		};
+		let to_asset = |balance| {
+			<AssetRate as ConversionToAssetBalance<
+				BalanceOf<Test>,
+				<Test as pallet_asset_rate::Config>::AssetKind,
+				BalanceOf<Test>,
+			>>::to_asset_balance(balance, ASSET_ID)
+			.unwrap()
+		};
+
+		// The asset has fewer decimals than the native balance, which has 12.
+		assert_ok!(AssetRate::set_decimals(RuntimeOrigin::root(), Box::new(ASSET_ID), Some(6)));
+		assert_eq!(from_asset(10), 20_000_000);
+		assert_eq!(to_asset(20_000_000), 10);
+		assert_eq!(to_asset(1_999_999), 0);
+
+		// The asset has more decimals than the native balance.
+		assert_ok!(AssetRate::set_decimals(RuntimeOrigin::root(), Box::new(ASSET_ID), Some(18)));
+		assert_eq!(from_asset(1_000_000), 2);
+		assert_eq!(to_asset(2), 1_000_000);
+	});
+}
+
+#[test]
+fn migration_to_v1_takes_decimals_out_of_rates() {
+	use frame_support::traits::UncheckedOnRuntimeUpgrade;
+
+	frame_support::parameter_types! {
+		pub Decimals: Vec<(u32, u8)> = vec![(ASSET_ID, 6), (ASSET_ID + 2, 18)];
+	}
+
+	new_test_ext().execute_with(|| {
+		// One unit of the asset with 6 decimals is worth two native units.
+		pallet_asset_rate::ConversionRateToNative::<Test>::insert(
+			ASSET_ID,
+			FixedU128::from_u32(2_000_000),
+		);
+		// The decimals of this asset are not known, so its rate is left untouched.
+		pallet_asset_rate::ConversionRateToNative::<Test>::insert(
+			ASSET_ID + 1,
+			FixedU128::from_float(0.5),
+		);
+
+		migration::v1::UncheckedMigrateToV1::<Test, Decimals>::on_runtime_upgrade();
+
+		assert_eq!(
+			pallet_asset_rate::ConversionRateToNative::<Test>::get(ASSET_ID),
+			Some(FixedU128::from_u32(2))
+		);
+		assert_eq!(pallet_asset_rate::AssetDecimals::<Test>::get(ASSET_ID), Some(6));
+		assert_eq!(
+			pallet_asset_rate::ConversionRateToNative::<Test>::get(ASSET_ID + 1),
+			Some(FixedU128::from_float(0.5))
+		);
+		assert!(pallet_asset_rate::AssetDecimals::<Test>::get(ASSET_ID + 1).is_none());
+		// Assets without a rate are skipped.
+		assert!(pallet_asset_rate::AssetDecimals::<Test>::get(ASSET_ID + 2).is_none());
+	});
+}
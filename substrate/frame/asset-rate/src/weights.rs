@@ -54,6 +54,7 @@ pub trait WeightInfo {
 	fn create() -> Weight;
 	fn update() -> Weight;
 	fn remove() -> Weight;
+	fn set_decimals() -> Weight;
 }
 
 /// Weights for `pallet_asset_rate` using the Substrate node and recommended hardware.
@@ -83,12 +84,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:1)
 	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRate::AssetDecimals` (r:0 w:1)
+	/// Proof: `AssetRate::AssetDecimals` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	fn remove() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `137`
 		//  Estimated: `3501`
 		// Minimum execution time: 10_837_000 picoseconds.
 		Weight::from_parts(11_050_000, 3501)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:0)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRate::AssetDecimals` (r:0 w:1)
+	/// Proof: `AssetRate::AssetDecimals` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_decimals() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `137`
+		//  Estimated: `3501`
+		// Minimum execution time: 9_753_000 picoseconds.
+		Weight::from_parts(9_945_000, 3501)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -120,12 +136,27 @@ impl WeightInfo for () {
 	}
 	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:1)
 	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRate::AssetDecimals` (r:0 w:1)
+	/// Proof: `AssetRate::AssetDecimals` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	fn remove() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `137`
 		//  Estimated: `3501`
 		// Minimum execution time: 10_837_000 picoseconds.
 		Weight::from_parts(11_050_000, 3501)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:0)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRate::AssetDecimals` (r:0 w:1)
+	/// Proof: `AssetRate::AssetDecimals` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_decimals() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `137`
+		//  Estimated: `3501`
+		// Minimum execution time: 9_753_000 picoseconds.
+		Weight::from_parts(9_945_000, 3501)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -201,6 +201,7 @@ mod tests {
 	impl pallet_session::Config for Test {
 		type SessionManager = ();
 		type Keys = UintAuthorityId;
+		type RequireProofOfPossession = frame_support::traits::ConstBool<false>;
 		type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
 		type SessionHandler = TestSessionHandler;
 		type RuntimeEvent = RuntimeEvent;
@@ -30,13 +30,14 @@ use frame_support::{
 	BoundedVec, WeakBoundedVec,
 };
 use frame_system::pallet_prelude::{BlockNumberFor, HeaderFor};
+use frame_system_rpc_runtime_api::PreRuntimeDigest;
 use sp_consensus_babe::{
 	digests::{NextConfigDescriptor, NextEpochDescriptor, PreDigest},
-	AllowedSlots, BabeAuthorityWeight, BabeEpochConfiguration, ConsensusLog, Epoch,
-	EquivocationProof, Randomness as BabeRandomness, Slot, BABE_ENGINE_ID, RANDOMNESS_LENGTH,
-	RANDOMNESS_VRF_CONTEXT,
+	AllowedSlots, AuthoringStats, BabeAuthorityWeight, BabeEpochConfiguration, ConsensusLog, Epoch,
+	EpochAuthoringStats, EquivocationProof, Randomness as BabeRandomness, Slot, BABE_ENGINE_ID,
+	RANDOMNESS_LENGTH, RANDOMNESS_VRF_CONTEXT,
 };
-use sp_core::crypto::Wraps;
+use sp_core::{crypto::Wraps, U256};
 use sp_runtime::{
 	generic::DigestItem,
 	traits::{IsMember, One, SaturatedConversion, Saturating, Zero},
@@ -319,6 +320,26 @@ pub mod pallet {
 	pub(super) type SkippedEpochs<T> =
 		StorageValue<_, BoundedVec<(u64, SessionIndex), ConstU32<100>>, ValueQuery>;
 
+	/// The authoring statistics of the current epoch so far, in the order of [`Authorities`].
+	///
+	/// Authorities which neither authored nor missed a slot yet may not have an entry.
+	#[pallet::storage]
+	pub(super) type CurrentAuthoringStats<T: Config> =
+		StorageValue<_, BoundedVec<AuthoringStats, T::MaxAuthorities>, ValueQuery>;
+
+	/// The number of slots without a block in the current epoch which could not be attributed
+	/// to an authority, since the epoch did not allow secondary slots.
+	#[pallet::storage]
+	pub(super) type UnattributedMissedSlots<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// The authoring statistics of the previous epoch: its index, its authorities with their
+	/// statistics and the number of its missed slots which could not be attributed.
+	#[pallet::storage]
+	pub(super) type PreviousAuthoringStats<T: Config> = StorageValue<
+		_,
+		(u64, WeakBoundedVec<(AuthorityId, AuthoringStats), T::MaxAuthorities>, u32),
+	>;
+
 	#[derive(frame_support::DefaultNoBound)]
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
@@ -362,6 +383,16 @@ pub mod pallet {
 					);
 				}
 
+				CurrentAuthoringStats::<T>::mutate(|stats| {
+					if let Some(stats) = Self::authoring_stats_entry(stats, authority_index) {
+						if pre_digest.is_primary() {
+							stats.primary.saturating_inc();
+						} else {
+							stats.secondary.saturating_inc();
+						}
+					}
+				});
+
 				if let Some(signature) = pre_digest.vrf_signature() {
 					let randomness: Option<BabeRandomness> = Authorities::<T>::get()
 						.get(authority_index as usize)
@@ -497,10 +528,9 @@ impl<T: Config> FindAuthor<u32> for Pallet<T> {
 	where
 		I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
 	{
-		for (id, mut data) in digests.into_iter() {
+		for (id, data) in digests.into_iter() {
 			if id == BABE_ENGINE_ID {
-				let pre_digest: PreDigest = PreDigest::decode(&mut data).ok()?;
-				return Some(pre_digest.authority_index())
+				return Self::decode_pre_digest(data).map(|(_, authority_index)| authority_index)
 			}
 		}
 
@@ -508,6 +538,52 @@ impl<T: Config> FindAuthor<u32> for Pallet<T> {
 	}
 }
 
+impl<T: Config + pallet_session::Config> Pallet<T> {
+	/// The validator owning the key of the BABE authority `authority_index` of the current epoch.
+	///
+	/// After a block is executed, the authorities are those of the epoch the block was authored
+	/// in, also if the block started a new epoch, so this finds the author of the block. Keys
+	/// replaced during the current session have no owner anymore, for them the validator at the
+	/// same index of the session is returned.
+	pub fn authority_owner(authority_index: u32) -> Option<T::ValidatorId> {
+		let authorities = Authorities::<T>::get();
+		let (authority, _) = authorities.get(authority_index as usize)?;
+		pallet_session::Pallet::<T>::key_owner(sp_consensus_babe::KEY_TYPE, authority.as_ref())
+			.or_else(|| {
+				pallet_session::Pallet::<T>::validators().get(authority_index as usize).cloned()
+			})
+	}
+
+	/// The validator who authored the current block, see [`Self::authority_owner`].
+	pub fn block_author() -> Option<T::ValidatorId> {
+		let digest = frame_system::Pallet::<T>::digest();
+		let pre_runtime_digests = digest.logs.iter().filter_map(|d| d.as_pre_runtime());
+		Self::find_author(pre_runtime_digests).and_then(Self::authority_owner)
+	}
+
+	/// The pre-runtime digests of the current block, with the BABE pre-digests decoded and their
+	/// authors found like [`Self::block_author`] does.
+	pub fn pre_runtime_digests() -> Vec<PreRuntimeDigest<T::ValidatorId>> {
+		frame_system::Pallet::<T>::digest()
+			.logs()
+			.iter()
+			.filter_map(|log| log.as_pre_runtime())
+			.map(|(engine, data)| {
+				let babe =
+					(engine == BABE_ENGINE_ID).then(|| Self::decode_pre_digest(data)).flatten();
+				match babe {
+					Some((slot, authority_index)) => PreRuntimeDigest::Babe {
+						slot: slot.into(),
+						authority_index,
+						author: Self::authority_owner(authority_index),
+					},
+					None => PreRuntimeDigest::Other { engine, data: data.to_vec() },
+				}
+			})
+			.collect()
+	}
+}
+
 impl<T: Config> IsMember<AuthorityId> for Pallet<T> {
 	fn is_member(authority_id: &AuthorityId) -> bool {
 		<Pallet<T>>::authorities().iter().any(|id| &id.0 == authority_id)
@@ -533,6 +609,13 @@ impl<T: Config> Pallet<T> {
 		<T as pallet_timestamp::Config>::MinimumPeriod::get().saturating_mul(2u32.into())
 	}
 
+	/// Decode the BABE pre-runtime digest `data` of a block into the slot of the block and the
+	/// authority index of its author.
+	pub fn decode_pre_digest(mut data: &[u8]) -> Option<(Slot, u32)> {
+		let pre_digest = PreDigest::decode(&mut data).ok()?;
+		Some((pre_digest.slot(), pre_digest.authority_index()))
+	}
+
 	/// Determine whether an epoch change should take place at this block.
 	/// Assumes that initialization has already taken place.
 	pub fn should_epoch_change(now: BlockNumberFor<T>) -> bool {
@@ -638,6 +721,14 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
+		// Keep the authoring statistics of the ending epoch before they are reset.
+		let previous_stats = Self::authorities_with_stats(&CurrentAuthoringStats::<T>::take());
+		PreviousAuthoringStats::<T>::put((
+			current_epoch_index,
+			WeakBoundedVec::<_, T::MaxAuthorities>::force_from(previous_stats, None),
+			UnattributedMissedSlots::<T>::take(),
+		));
+
 		EpochIndex::<T>::put(epoch_index);
 		Authorities::<T>::put(authorities);
 
@@ -823,6 +914,7 @@ impl<T: Config> Pallet<T> {
 			let lateness = current_slot.saturating_sub(CurrentSlot::<T>::get() + 1);
 			let lateness = BlockNumberFor::<T>::from(*lateness as u32);
 
+			Self::note_missed_slots(current_slot);
 			Lateness::<T>::put(lateness);
 			CurrentSlot::<T>::put(current_slot);
 		}
@@ -833,6 +925,95 @@ impl<T: Config> Pallet<T> {
 		T::EpochChangeTrigger::trigger::<T>(now);
 	}
 
+	/// Accounts the slots between the last block and the one at `current_slot` in which no block
+	/// was authored to the secondary slot authors of the current epoch.
+	///
+	/// Only slots of the current epoch are accounted, the epoch change has not been enacted yet.
+	fn note_missed_slots(current_slot: Slot) {
+		let last_slot = CurrentSlot::<T>::get();
+		if *last_slot == 0 {
+			// this is the first block of the chain.
+			return
+		}
+
+		let epoch_end = Self::current_epoch_start().saturating_add(T::EpochDuration::get());
+		let missed = (*last_slot + 1)..(*current_slot).min(*epoch_end);
+		if missed.is_empty() {
+			return
+		}
+
+		let secondary_slots = EpochConfig::<T>::get().map_or(false, |config| {
+			config.allowed_slots.is_secondary_plain_slots_allowed() ||
+				config.allowed_slots.is_secondary_vrf_slots_allowed()
+		});
+		let authorities_len = Authorities::<T>::decode_len().unwrap_or_default();
+		if !secondary_slots || authorities_len == 0 {
+			let missed = (missed.end - missed.start).saturated_into::<u32>();
+			UnattributedMissedSlots::<T>::mutate(|unattributed| {
+				unattributed.saturating_accrue(missed)
+			});
+			return
+		}
+
+		let randomness = Randomness::<T>::get();
+		CurrentAuthoringStats::<T>::mutate(|stats| {
+			for slot in missed {
+				let authority_index =
+					secondary_slot_author_index(randomness, slot.into(), authorities_len);
+				if let Some(stats) = Self::authoring_stats_entry(stats, authority_index) {
+					stats.missed.saturating_inc();
+				}
+			}
+		});
+	}
+
+	/// Pairs the current authorities with their entries in `stats`.
+	fn authorities_with_stats(stats: &[AuthoringStats]) -> Vec<(AuthorityId, AuthoringStats)> {
+		Authorities::<T>::get()
+			.into_iter()
+			.enumerate()
+			.map(|(index, (authority, _))| {
+				(authority, stats.get(index).copied().unwrap_or_default())
+			})
+			.collect()
+	}
+
+	/// Returns the authoring statistics of the authority at `authority_index`, adding the missing
+	/// entries up to it. Returns `None` if the index is out of bounds.
+	fn authoring_stats_entry(
+		stats: &mut BoundedVec<AuthoringStats, T::MaxAuthorities>,
+		authority_index: u32,
+	) -> Option<&mut AuthoringStats> {
+		let index = authority_index as usize;
+		while stats.len() <= index {
+			stats.try_push(Default::default()).ok()?;
+		}
+		stats.get_mut(index)
+	}
+
+	/// Returns the authoring statistics of the current epoch so far.
+	pub fn current_epoch_authoring_stats() -> EpochAuthoringStats {
+		let epoch_index = EpochIndex::<T>::get();
+		EpochAuthoringStats {
+			epoch_index,
+			session_index: Self::session_index_for_epoch(epoch_index),
+			authorities: Self::authorities_with_stats(&CurrentAuthoringStats::<T>::get()),
+			unattributed_missed: UnattributedMissedSlots::<T>::get(),
+		}
+	}
+
+	/// Returns the authoring statistics of the previous epoch, if any.
+	pub fn previous_epoch_authoring_stats() -> Option<EpochAuthoringStats> {
+		PreviousAuthoringStats::<T>::get().map(|(epoch_index, authorities, unattributed_missed)| {
+			EpochAuthoringStats {
+				epoch_index,
+				session_index: Self::session_index_for_epoch(epoch_index),
+				authorities: authorities.into_inner(),
+				unattributed_missed,
+			}
+		})
+	}
+
 	/// Call this function exactly once when an epoch changes, to update the
 	/// randomness. Returns the new randomness.
 	fn randomness_change_epoch(next_epoch_index: u64) -> BabeRandomness {
@@ -994,6 +1175,17 @@ where
 	}
 }
 
+// the index of the authority that may claim `slot` as a secondary slot, the same way the
+// client picks the secondary slot author.
+fn secondary_slot_author_index(
+	randomness: BabeRandomness,
+	slot: Slot,
+	authorities_len: usize,
+) -> u32 {
+	let rand = U256::from((randomness, slot).using_encoded(sp_io::hashing::blake2_256));
+	(rand % U256::from(authorities_len)).as_u32()
+}
+
 // compute randomness for a new epoch. rho is the concatenation of all
 // VRF outputs in the prior epoch.
 //
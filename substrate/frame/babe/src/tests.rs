@@ -26,7 +26,7 @@ use frame_support::{
 use mock::*;
 use pallet_session::ShouldEndSession;
 use sp_consensus_babe::{
-	AllowedSlots, BabeEpochConfiguration, Slot, VrfSignature, RANDOMNESS_LENGTH,
+	AllowedSlots, AuthoringStats, BabeEpochConfiguration, Slot, VrfSignature, RANDOMNESS_LENGTH,
 };
 use sp_core::crypto::Pair;
 
@@ -204,6 +204,40 @@ fn authority_index() {
 	})
 }
 
+#[test]
+fn pre_digest_is_decoded() {
+	new_test_ext(4).execute_with(|| {
+		let digest = make_secondary_plain_pre_digest(2, Slot::from(42));
+		let (_, data) = digest.logs()[0].as_pre_runtime().unwrap();
+		assert_eq!(Babe::decode_pre_digest(data), Some((Slot::from(42), 2)));
+		assert_eq!(Babe::decode_pre_digest(&[]), None);
+	})
+}
+
+#[test]
+fn block_digest_is_described_at_session_change() {
+	let (pairs, mut ext) = new_test_ext_with_pairs(4);
+
+	ext.execute_with(|| {
+		start_era(1);
+		// this block started a new session, its author is the first authority of the new epoch.
+		let (authority, _) = Babe::authorities()[0].clone();
+		let stash = pairs.iter().position(|pair| pair.public() == authority);
+		let author = stash.map(|stash| stash as u64);
+		assert!(author.is_some());
+
+		assert_eq!(Babe::block_author(), author);
+		assert_eq!(
+			Babe::pre_runtime_digests(),
+			vec![frame_system_rpc_runtime_api::PreRuntimeDigest::Babe {
+				slot: Babe::current_slot().into(),
+				authority_index: 0,
+				author,
+			}],
+		);
+	})
+}
+
 #[test]
 fn can_predict_next_epoch_change() {
 	new_test_ext(1).execute_with(|| {
@@ -1044,3 +1078,46 @@ fn skipping_over_epochs_works() {
 		assert_eq!(Babe::session_index_for_epoch(5), 3);
 	});
 }
+
+#[test]
+fn tracks_authoring_stats_per_epoch() {
+	new_test_ext(3).execute_with(|| {
+		let genesis_slot = 100;
+		go_to_block(1, genesis_slot);
+		go_to_block(2, genesis_slot + 1);
+
+		let stats = Babe::current_epoch_authoring_stats();
+		assert_eq!((stats.epoch_index, stats.session_index), (0, 0));
+		assert_eq!(stats.authorities[0].1, AuthoringStats { secondary: 1, ..Default::default() });
+
+		// the last slot of epoch #0 is skipped, it is missed by its secondary slot author.
+		let missed_author =
+			secondary_slot_author_index(Babe::randomness(), Slot::from(genesis_slot + 2), 3);
+		go_to_block(3, genesis_slot + 3);
+		assert_eq!(EpochIndex::<Test>::get(), 1);
+
+		let mut expected = vec![AuthoringStats::default(); 3];
+		expected[0].secondary = 2;
+		expected[missed_author as usize].missed = 1;
+		let previous = Babe::previous_epoch_authoring_stats().unwrap();
+		assert_eq!(previous.epoch_index, 0);
+		assert_eq!(previous.authorities.into_iter().map(|(_, s)| s).collect::<Vec<_>>(), expected);
+		assert_eq!(previous.unattributed_missed, 0);
+
+		// the statistics of the new epoch start from scratch.
+		let stats = Babe::current_epoch_authoring_stats();
+		assert_eq!(stats.epoch_index, 1);
+		assert!(stats.authorities.iter().all(|(_, s)| *s == AuthoringStats::default()));
+
+		// without secondary slots missed slots can't be attributed.
+		EpochConfig::<Test>::put(BabeEpochConfiguration {
+			c: (1, 4),
+			allowed_slots: AllowedSlots::PrimarySlots,
+		});
+		go_to_block(4, genesis_slot + 5);
+
+		let stats = Babe::current_epoch_authoring_stats();
+		assert_eq!(stats.authorities[0].1, AuthoringStats { secondary: 1, ..Default::default() });
+		assert_eq!(stats.unattributed_missed, 1);
+	});
+}
@@ -38,7 +38,10 @@ pub use imbalances::{NegativeImbalance, PositiveImbalance};
 // wrapping these imbalances in a private module is necessary to ensure absolute privacy
 // of the inner member.
 mod imbalances {
-	use super::{result, Config, Imbalance, RuntimeDebug, Saturating, TryDrop, Zero};
+	use super::{
+		result, Config, Imbalance, IssuanceChangeReason, Pallet, RuntimeDebug, Saturating, TryDrop,
+		Zero,
+	};
 	use frame_support::traits::SameOrOther;
 	use sp_std::mem;
 
@@ -199,14 +202,24 @@ mod imbalances {
 	impl<T: Config<I>, I: 'static> Drop for PositiveImbalance<T, I> {
 		/// Basic drop handler will just square up the total issuance.
 		fn drop(&mut self) {
-			<super::TotalIssuance<T, I>>::mutate(|v| *v = v.saturating_add(self.0));
+			let (old, new) = <super::TotalIssuance<T, I>>::mutate(|v| {
+				let old = *v;
+				*v = v.saturating_add(self.0);
+				(old, *v)
+			});
+			Pallet::<T, I>::note_issuance_change(old, new, IssuanceChangeReason::Unbalanced);
 		}
 	}
 
 	impl<T: Config<I>, I: 'static> Drop for NegativeImbalance<T, I> {
 		/// Basic drop handler will just square up the total issuance.
 		fn drop(&mut self) {
-			<super::TotalIssuance<T, I>>::mutate(|v| *v = v.saturating_sub(self.0));
+			let (old, new) = <super::TotalIssuance<T, I>>::mutate(|v| {
+				let old = *v;
+				*v = v.saturating_sub(self.0);
+				(old, *v)
+			});
+			Pallet::<T, I>::note_issuance_change(old, new, IssuanceChangeReason::Unbalanced);
 		}
 	}
 }
@@ -263,6 +276,7 @@ where
 				Zero::zero()
 			});
 		});
+		Self::note_issuance_decrease(amount, IssuanceChangeReason::Rescinded);
 		PositiveImbalance::new(amount)
 	}
 
@@ -279,6 +293,7 @@ where
 				Self::Balance::max_value()
 			})
 		});
+		Self::note_issuance_increase(amount, IssuanceChangeReason::Issued);
 		NegativeImbalance::new(amount)
 	}
 
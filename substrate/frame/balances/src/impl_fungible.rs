@@ -25,6 +25,8 @@ use frame_support::traits::{
 	},
 	AccountTouch,
 };
+use fungible::HandleImbalanceDrop;
+use sp_std::marker::PhantomData;
 
 impl<T: Config<I>, I: 'static> fungible::Inspect<T::AccountId> for Pallet<T, I> {
 	type Balance = T::Balance;
@@ -190,15 +192,19 @@ impl<T: Config<I>, I: 'static> fungible::Unbalanced<T::AccountId> for Pallet<T,
 
 impl<T: Config<I>, I: 'static> fungible::Mutate<T::AccountId> for Pallet<T, I> {
 	fn done_mint_into(who: &T::AccountId, amount: Self::Balance) {
+		Self::note_issuance_increase(amount, IssuanceChangeReason::Minted);
 		Self::deposit_event(Event::<T, I>::Minted { who: who.clone(), amount });
 	}
 	fn done_burn_from(who: &T::AccountId, amount: Self::Balance) {
+		Self::note_issuance_decrease(amount, IssuanceChangeReason::Burned);
 		Self::deposit_event(Event::<T, I>::Burned { who: who.clone(), amount });
 	}
 	fn done_shelve(who: &T::AccountId, amount: Self::Balance) {
+		Self::note_issuance_decrease(amount, IssuanceChangeReason::Suspended);
 		Self::deposit_event(Event::<T, I>::Suspended { who: who.clone(), amount });
 	}
 	fn done_restore(who: &T::AccountId, amount: Self::Balance) {
+		Self::note_issuance_increase(amount, IssuanceChangeReason::Restored);
 		Self::deposit_event(Event::<T, I>::Restored { who: who.clone(), amount });
 	}
 	fn done_transfer(source: &T::AccountId, dest: &T::AccountId, amount: Self::Balance) {
@@ -210,7 +216,11 @@ impl<T: Config<I>, I: 'static> fungible::Mutate<T::AccountId> for Pallet<T, I> {
 	}
 }
 
-impl<T: Config<I>, I: 'static> fungible::MutateHold<T::AccountId> for Pallet<T, I> {}
+impl<T: Config<I>, I: 'static> fungible::MutateHold<T::AccountId> for Pallet<T, I> {
+	fn done_burn_held(_reason: &Self::Reason, _who: &T::AccountId, amount: Self::Balance) {
+		Self::note_issuance_decrease(amount, IssuanceChangeReason::Burned);
+	}
+}
 
 impl<T: Config<I>, I: 'static> fungible::InspectHold<T::AccountId> for Pallet<T, I> {
 	type Reason = T::RuntimeHoldReason;
@@ -344,8 +354,8 @@ impl<T: Config<I>, I: 'static> fungible::MutateFreeze<T::AccountId> for Pallet<T
 }
 
 impl<T: Config<I>, I: 'static> fungible::Balanced<T::AccountId> for Pallet<T, I> {
-	type OnDropCredit = fungible::DecreaseIssuance<T::AccountId, Self>;
-	type OnDropDebt = fungible::IncreaseIssuance<T::AccountId, Self>;
+	type OnDropCredit = DecreaseIssuance<T, I>;
+	type OnDropDebt = IncreaseIssuance<T, I>;
 
 	fn done_deposit(who: &T::AccountId, amount: Self::Balance) {
 		Self::deposit_event(Event::<T, I>::Deposit { who: who.clone(), amount });
@@ -354,15 +364,43 @@ impl<T: Config<I>, I: 'static> fungible::Balanced<T::AccountId> for Pallet<T, I>
 		Self::deposit_event(Event::<T, I>::Withdraw { who: who.clone(), amount });
 	}
 	fn done_issue(amount: Self::Balance) {
+		Self::note_issuance_increase(amount, IssuanceChangeReason::Issued);
 		Self::deposit_event(Event::<T, I>::Issued { amount });
 	}
 	fn done_rescind(amount: Self::Balance) {
+		Self::note_issuance_decrease(amount, IssuanceChangeReason::Rescinded);
 		Self::deposit_event(Event::<T, I>::Rescinded { amount });
 	}
 }
 
 impl<T: Config<I>, I: 'static> fungible::BalancedHold<T::AccountId> for Pallet<T, I> {}
 
+/// Handler for a dropped debt, which increases the total issuance by the amount of the debt.
+pub struct IncreaseIssuance<T, I = ()>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> HandleImbalanceDrop<T::Balance> for IncreaseIssuance<T, I> {
+	fn handle(amount: T::Balance) {
+		let (old, new) = TotalIssuance::<T, I>::mutate(|t| {
+			let old = *t;
+			*t = t.saturating_add(amount);
+			(old, *t)
+		});
+		Pallet::<T, I>::note_issuance_change(old, new, IssuanceChangeReason::Unbalanced);
+	}
+}
+
+/// Handler for a dropped credit, which decreases the total issuance by the amount of the credit.
+pub struct DecreaseIssuance<T, I = ()>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> HandleImbalanceDrop<T::Balance> for DecreaseIssuance<T, I> {
+	fn handle(amount: T::Balance) {
+		let (old, new) = TotalIssuance::<T, I>::mutate(|t| {
+			let old = *t;
+			*t = t.saturating_sub(amount);
+			(old, *t)
+		});
+		Pallet::<T, I>::note_issuance_change(old, new, IssuanceChangeReason::Unbalanced);
+	}
+}
+
 impl<T: Config<I>, I: 'static> AccountTouch<(), T::AccountId> for Pallet<T, I> {
 	type Balance = T::Balance;
 	fn deposit_required(_: ()) -> Self::Balance {
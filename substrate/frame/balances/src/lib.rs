@@ -168,6 +168,7 @@ use frame_support::{
 };
 use frame_system as system;
 pub use impl_currency::{NegativeImbalance, PositiveImbalance};
+pub use impl_fungible::{DecreaseIssuance, IncreaseIssuance};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
@@ -178,7 +179,8 @@ use sp_runtime::{
 };
 use sp_std::{cmp, fmt::Debug, mem, prelude::*, result};
 pub use types::{
-	AccountData, AdjustmentDirection, BalanceLock, DustCleaner, ExtraFlags, Reasons, ReserveData,
+	AccountData, AdjustmentDirection, BalanceLock, DustCleaner, ExtraFlags, IssuanceChangeReason,
+	Reasons, ReserveData,
 };
 pub use weights::WeightInfo;
 
@@ -373,6 +375,10 @@ pub mod pallet {
 		Thawed { who: T::AccountId, amount: T::Balance },
 		/// The `TotalIssuance` was forcefully changed.
 		TotalIssuanceForced { old: T::Balance, new: T::Balance },
+		/// The `TotalIssuance` was increased by `amount`.
+		IssuanceIncreased { amount: T::Balance, reason: IssuanceChangeReason },
+		/// The `TotalIssuance` was decreased by `amount`.
+		IssuanceDecreased { amount: T::Balance, reason: IssuanceChangeReason },
 	}
 
 	#[pallet::error]
@@ -576,6 +582,10 @@ pub mod pallet {
 				}
 			})?;
 
+			// Iterating all accounts is too expensive for the state of a live chain.
+			#[cfg(test)]
+			Self::check_total_issuance()?;
+
 			Ok(())
 		}
 	}
@@ -786,6 +796,7 @@ pub mod pallet {
 			ensure!(InactiveIssuance::<T, I>::get() <= new, Error::<T, I>::IssuanceDeactivated);
 			TotalIssuance::<T, I>::set(new);
 
+			Self::note_issuance_change(old, new, IssuanceChangeReason::Forced);
 			Self::deposit_event(Event::<T, I>::TotalIssuanceForced { old, new });
 
 			Ok(())
@@ -822,6 +833,49 @@ pub mod pallet {
 		fn ed() -> T::Balance {
 			T::ExistentialDeposit::get()
 		}
+
+		/// Deposit the event for a change of the total issuance from `old` to `new`, if any.
+		pub(crate) fn note_issuance_change(
+			old: T::Balance,
+			new: T::Balance,
+			reason: IssuanceChangeReason,
+		) {
+			if new > old {
+				Self::note_issuance_increase(new - old, reason);
+			} else {
+				Self::note_issuance_decrease(old - new, reason);
+			}
+		}
+
+		/// Deposit the event for an increase of the total issuance by `amount`, if any.
+		pub(crate) fn note_issuance_increase(amount: T::Balance, reason: IssuanceChangeReason) {
+			if !amount.is_zero() {
+				Self::deposit_event(Event::<T, I>::IssuanceIncreased { amount, reason });
+			}
+		}
+
+		/// Deposit the event for a decrease of the total issuance by `amount`, if any.
+		pub(crate) fn note_issuance_decrease(amount: T::Balance, reason: IssuanceChangeReason) {
+			if !amount.is_zero() {
+				Self::deposit_event(Event::<T, I>::IssuanceDecreased { amount, reason });
+			}
+		}
+
+		/// Ensure that the total issuance equals the sum of the total balances of all accounts.
+		#[cfg(all(test, feature = "try-runtime"))]
+		pub(crate) fn check_total_issuance() -> Result<(), sp_runtime::TryRuntimeError> {
+			let mut accounts = frame_system::Account::<T>::iter_keys()
+				.collect::<sp_std::collections::btree_set::BTreeSet<_>>();
+			accounts.extend(Account::<T, I>::iter_keys());
+			let total = accounts.iter().fold(T::Balance::zero(), |total, who| {
+				total.saturating_add(Self::account(who).total())
+			});
+			ensure!(
+				TotalIssuance::<T, I>::get() == total,
+				"The total issuance does not match the sum of all account balances"
+			);
+			Ok(())
+		}
 		/// Ensure the account `who` is using the new logic.
 		///
 		/// Returns `true` if the account did get upgraded, `false` if it didn't need upgrading.
@@ -399,10 +399,14 @@ fn reward_should_work() {
 	ExtBuilder::default().monied(true).build_and_execute_with(|| {
 		assert_eq!(Balances::total_balance(&1), 10);
 		assert_ok!(Balances::deposit_into_existing(&1, 10).map(drop));
-		System::assert_last_event(RuntimeEvent::Balances(crate::Event::Deposit {
+		System::assert_has_event(RuntimeEvent::Balances(crate::Event::Deposit {
 			who: 1,
 			amount: 10,
 		}));
+		System::assert_last_event(RuntimeEvent::Balances(crate::Event::IssuanceIncreased {
+			amount: 10,
+			reason: IssuanceChangeReason::Unbalanced,
+		}));
 		assert_eq!(Balances::total_balance(&1), 20);
 		assert_eq!(Balances::total_issuance(), 120);
 	});
@@ -480,10 +484,14 @@ fn withdrawing_balance_should_work() {
 		let _ = Balances::deposit_creating(&2, 111);
 		let _ =
 			Balances::withdraw(&2, 11, WithdrawReasons::TRANSFER, ExistenceRequirement::KeepAlive);
-		System::assert_last_event(RuntimeEvent::Balances(crate::Event::Withdraw {
+		System::assert_has_event(RuntimeEvent::Balances(crate::Event::Withdraw {
 			who: 2,
 			amount: 11,
 		}));
+		System::assert_last_event(RuntimeEvent::Balances(crate::Event::IssuanceDecreased {
+			amount: 11,
+			reason: IssuanceChangeReason::Unbalanced,
+		}));
 		assert_eq!(Balances::free_balance(2), 100);
 		assert_eq!(Balances::total_issuance(), 100);
 	});
@@ -889,6 +897,10 @@ fn emit_events_with_existential_deposit() {
 			[
 				RuntimeEvent::System(system::Event::NewAccount { account: 1 }),
 				RuntimeEvent::Balances(crate::Event::Endowed { account: 1, free_balance: 100 }),
+				RuntimeEvent::Balances(crate::Event::IssuanceIncreased {
+					amount: 100,
+					reason: IssuanceChangeReason::Unbalanced
+				}),
 				RuntimeEvent::Balances(crate::Event::BalanceSet { who: 1, free: 100 }),
 			]
 		);
@@ -901,6 +913,10 @@ fn emit_events_with_existential_deposit() {
 			[
 				RuntimeEvent::System(system::Event::KilledAccount { account: 1 }),
 				RuntimeEvent::Balances(crate::Event::DustLost { account: 1, amount: 99 }),
+				RuntimeEvent::Balances(crate::Event::IssuanceDecreased {
+					amount: 99,
+					reason: IssuanceChangeReason::Unbalanced
+				}),
 				RuntimeEvent::Balances(crate::Event::Slashed { who: 1, amount: 1 }),
 			]
 		);
@@ -918,6 +934,10 @@ fn emit_events_with_no_existential_deposit_suicide() {
 				RuntimeEvent::Balances(crate::Event::BalanceSet { who: 1, free: 100 }),
 				RuntimeEvent::System(system::Event::NewAccount { account: 1 }),
 				RuntimeEvent::Balances(crate::Event::Endowed { account: 1, free_balance: 100 }),
+				RuntimeEvent::Balances(crate::Event::IssuanceIncreased {
+					amount: 100,
+					reason: IssuanceChangeReason::Unbalanced
+				}),
 			]
 		);
 
@@ -954,10 +974,15 @@ fn slash_full_works() {
 		assert_eq!(Balances::slash(&1, 1_000), (NegativeImbalance::new(1000), 0));
 		// Account is still alive
 		assert!(!System::account_exists(&1));
-		System::assert_last_event(RuntimeEvent::Balances(crate::Event::Slashed {
+		System::assert_has_event(RuntimeEvent::Balances(crate::Event::Slashed {
 			who: 1,
 			amount: 1000,
 		}));
+		// the slashed imbalance is dropped.
+		System::assert_last_event(RuntimeEvent::Balances(crate::Event::IssuanceDecreased {
+			amount: 1000,
+			reason: IssuanceChangeReason::Unbalanced,
+		}));
 	});
 }
 
@@ -969,10 +994,15 @@ fn slash_partial_works() {
 		assert_eq!(Balances::slash(&1, 900), (NegativeImbalance::new(900), 0));
 		// Account is still alive
 		assert!(System::account_exists(&1));
-		System::assert_last_event(RuntimeEvent::Balances(crate::Event::Slashed {
+		System::assert_has_event(RuntimeEvent::Balances(crate::Event::Slashed {
 			who: 1,
 			amount: 900,
 		}));
+		// the slashed imbalance is dropped.
+		System::assert_last_event(RuntimeEvent::Balances(crate::Event::IssuanceDecreased {
+			amount: 900,
+			reason: IssuanceChangeReason::Unbalanced,
+		}));
 	});
 }
 
@@ -983,10 +1013,15 @@ fn slash_dusting_works() {
 		// Slashed completed in full
 		assert_eq!(Balances::slash(&1, 950), (NegativeImbalance::new(950), 0));
 		assert!(!System::account_exists(&1));
-		System::assert_last_event(RuntimeEvent::Balances(crate::Event::Slashed {
+		System::assert_has_event(RuntimeEvent::Balances(crate::Event::Slashed {
 			who: 1,
 			amount: 950,
 		}));
+		// the slashed imbalance is dropped.
+		System::assert_last_event(RuntimeEvent::Balances(crate::Event::IssuanceDecreased {
+			amount: 950,
+			reason: IssuanceChangeReason::Unbalanced,
+		}));
 	});
 }
 
@@ -998,10 +1033,15 @@ fn slash_does_not_take_from_reserve() {
 		// Slashed completed in full
 		assert_eq!(Balances::slash(&1, 900), (NegativeImbalance::new(800), 100));
 		assert_eq!(Balances::reserved_balance(&1), 100);
-		System::assert_last_event(RuntimeEvent::Balances(crate::Event::Slashed {
+		System::assert_has_event(RuntimeEvent::Balances(crate::Event::Slashed {
 			who: 1,
 			amount: 800,
 		}));
+		// the slashed imbalance is dropped.
+		System::assert_last_event(RuntimeEvent::Balances(crate::Event::IssuanceDecreased {
+			amount: 800,
+			reason: IssuanceChangeReason::Unbalanced,
+		}));
 	});
 }
 
@@ -1399,6 +1439,8 @@ fn self_transfer_noop() {
 				Event::Deposit { who: 1, amount: 100 }.into(),
 				SysEvent::NewAccount { account: 1 }.into(),
 				Event::Endowed { account: 1, free_balance: 100 }.into(),
+				Event::IssuanceIncreased { amount: 100, reason: IssuanceChangeReason::Unbalanced }
+					.into(),
 			]
 		);
 		assert_eq!(Balances::free_balance(1), 100);
@@ -20,15 +20,17 @@
 use crate::{
 	system::AccountInfo,
 	tests::{ensure_ti_valid, Balances, ExtBuilder, System, Test, TestId, UseSystem},
-	AccountData, ExtraFlags, TotalIssuance,
+	AccountData, AdjustmentDirection, Event, ExtraFlags, IssuanceChangeReason, TotalIssuance,
 };
 use frame_support::{
 	assert_noop, assert_ok, hypothetically,
 	traits::{
-		fungible::{Mutate, MutateHold},
-		tokens::Precision,
+		fungible::{Balanced, Mutate, MutateHold},
+		tokens::{Fortitude, Precision, Preservation},
+		Currency, ExistenceRequirement, WithdrawReasons,
 	},
 };
+use frame_system::RawOrigin;
 use sp_runtime::DispatchError;
 
 /// There are some accounts that have one consumer ref too few. These accounts are at risk of losing
@@ -141,3 +143,79 @@ fn try_state_works() {
 			.contains("Found `Freeze` with too many elements"));
 	});
 }
+
+#[test]
+fn issuance_changes_emit_events() {
+	ExtBuilder::default().build_and_execute_with(|| {
+		let increased = |amount, reason| {
+			System::assert_has_event(Event::<Test>::IssuanceIncreased { amount, reason }.into())
+		};
+		let decreased = |amount, reason| {
+			System::assert_has_event(Event::<Test>::IssuanceDecreased { amount, reason }.into())
+		};
+
+		assert_ok!(Balances::mint_into(&1, 100));
+		increased(100, IssuanceChangeReason::Minted);
+		assert_ok!(Balances::burn_from(
+			&1,
+			30,
+			Preservation::Expendable,
+			Precision::Exact,
+			Fortitude::Polite
+		));
+		decreased(30, IssuanceChangeReason::Burned);
+		assert_ok!(Balances::hold(&TestId::Foo, &1, 10));
+		assert_ok!(Balances::burn_held(&TestId::Foo, &1, 4, Precision::Exact, Fortitude::Polite));
+		decreased(4, IssuanceChangeReason::Burned);
+		assert_ok!(Balances::shelve(&1, 6));
+		decreased(6, IssuanceChangeReason::Suspended);
+		assert_ok!(Balances::restore(&1, 6));
+		increased(6, IssuanceChangeReason::Restored);
+
+		// the imbalances of `issue` and `burn` are resolved.
+		let issued = <Balances as Currency<_>>::issue(20);
+		increased(20, IssuanceChangeReason::Issued);
+		<Balances as Currency<_>>::resolve_creating(&2, issued);
+		let burned = <Balances as Currency<_>>::burn(5);
+		decreased(5, IssuanceChangeReason::Rescinded);
+		assert_ok!(<Balances as Currency<_>>::settle(
+			&2,
+			burned,
+			WithdrawReasons::all(),
+			ExistenceRequirement::AllowDeath
+		)
+		.map_err(|_| ()));
+
+		drop(<Balances as Currency<_>>::deposit_creating(&3, 40));
+		increased(40, IssuanceChangeReason::Unbalanced);
+
+		// a dropped credit burns its funds.
+		drop(<Balances as Balanced<_>>::issue(3));
+		increased(3, IssuanceChangeReason::Issued);
+		decreased(3, IssuanceChangeReason::Unbalanced);
+
+		let ti = Balances::total_issuance();
+		assert_ok!(Balances::force_adjust_total_issuance(
+			RawOrigin::Root.into(),
+			AdjustmentDirection::Decrease,
+			7
+		));
+		decreased(7, IssuanceChangeReason::Forced);
+		assert_eq!(Balances::total_issuance(), ti - 7);
+	});
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn try_state_checks_total_issuance() {
+	use frame_support::traits::Hooks;
+
+	ExtBuilder::default().build_and_execute_with(|| {
+		assert_ok!(Balances::mint_into(&1, 100));
+		assert_ok!(Balances::try_state(0));
+
+		TotalIssuance::<Test>::mutate(|issuance| *issuance += 1);
+		assert!(format!("{:?}", Balances::try_state(0).unwrap_err())
+			.contains("The total issuance does not match the sum of all account balances"));
+	});
+}
@@ -52,7 +52,7 @@ fn transfer_dust_removal_tst1_should_work() {
 			assert_eq!(Balances::free_balance(&1), 1050);
 
 			// Verify the events
-			assert_eq!(System::events().len(), 12);
+			assert_eq!(System::events().len(), 14);
 
 			System::assert_has_event(RuntimeEvent::Balances(crate::Event::Transfer {
 				from: 2,
@@ -93,7 +93,7 @@ fn transfer_dust_removal_tst2_should_work() {
 			assert_eq!(Balances::free_balance(&1), 1500);
 
 			// Verify the events
-			assert_eq!(System::events().len(), 10);
+			assert_eq!(System::events().len(), 12);
 
 			System::assert_has_event(RuntimeEvent::Balances(crate::Event::Transfer {
 				from: 2,
@@ -139,7 +139,7 @@ fn repatriating_reserved_balance_dust_removal_should_work() {
 			assert_eq!(Balances::free_balance(1), 1500);
 
 			// Verify the events
-			assert_eq!(System::events().len(), 10);
+			assert_eq!(System::events().len(), 12);
 
 			System::assert_has_event(RuntimeEvent::Balances(crate::Event::Transfer {
 				from: 2,
@@ -167,6 +167,10 @@ fn emit_events_with_no_existential_deposit_suicide_with_dust() {
 			[
 				RuntimeEvent::System(system::Event::NewAccount { account: 1 }),
 				RuntimeEvent::Balances(crate::Event::Endowed { account: 1, free_balance: 100 }),
+				RuntimeEvent::Balances(crate::Event::IssuanceIncreased {
+					amount: 100,
+					reason: IssuanceChangeReason::Unbalanced
+				}),
 				RuntimeEvent::Balances(crate::Event::BalanceSet { who: 1, free: 100 }),
 			]
 		);
@@ -174,10 +178,16 @@ fn emit_events_with_no_existential_deposit_suicide_with_dust() {
 		let res = Balances::withdraw(&1, 98, BestEffort, Protect, Force);
 		assert_eq!(res.unwrap().peek(), 98);
 
-		// no events
+		// the withdrawn credit is dropped.
 		assert_eq!(
 			events(),
-			[RuntimeEvent::Balances(crate::Event::Withdraw { who: 1, amount: 98 })]
+			[
+				RuntimeEvent::Balances(crate::Event::Withdraw { who: 1, amount: 98 }),
+				RuntimeEvent::Balances(crate::Event::IssuanceDecreased {
+					amount: 98,
+					reason: IssuanceChangeReason::Unbalanced
+				}),
+			]
 		);
 
 		let res = Balances::withdraw(&1, 1, BestEffort, Expendable, Force);
@@ -188,7 +198,15 @@ fn emit_events_with_no_existential_deposit_suicide_with_dust() {
 			[
 				RuntimeEvent::System(system::Event::KilledAccount { account: 1 }),
 				RuntimeEvent::Balances(crate::Event::DustLost { account: 1, amount: 1 }),
-				RuntimeEvent::Balances(crate::Event::Withdraw { who: 1, amount: 1 })
+				RuntimeEvent::Balances(crate::Event::IssuanceDecreased {
+					amount: 1,
+					reason: IssuanceChangeReason::Unbalanced
+				}),
+				RuntimeEvent::Balances(crate::Event::Withdraw { who: 1, amount: 1 }),
+				RuntimeEvent::Balances(crate::Event::IssuanceDecreased {
+					amount: 1,
+					reason: IssuanceChangeReason::Unbalanced
+				}),
 			]
 		);
 	});
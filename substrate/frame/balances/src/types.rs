@@ -152,3 +152,25 @@ pub enum AdjustmentDirection {
 	/// Decrease the amount.
 	Decrease,
 }
+
+/// Why the total issuance changed.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum IssuanceChangeReason {
+	/// Funds were minted into an account.
+	Minted,
+	/// Funds were burned from the free or held balance of an account.
+	Burned,
+	/// Funds were suspended from an account.
+	Suspended,
+	/// Suspended funds were restored into an account.
+	Restored,
+	/// Funds were issued without being credited to any account yet.
+	Issued,
+	/// Funds were rescinded without being debited from any account yet.
+	Rescinded,
+	/// Funds were added to or removed from an account without being offset elsewhere, e.g. a
+	/// slash, a withdrawn fee or dust that nobody took.
+	Unbalanced,
+	/// The total issuance was forcefully adjusted by root.
+	Forced,
+}
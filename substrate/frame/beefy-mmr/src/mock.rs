@@ -71,6 +71,7 @@ impl pallet_session::Config for Test {
 	type SessionManager = MockSessionManager;
 	type SessionHandler = <MockSessionKeys as OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = MockSessionKeys;
+	type RequireProofOfPossession = frame_support::traits::ConstBool<false>;
 	type WeightInfo = ();
 }
 
@@ -107,6 +107,7 @@ impl pallet_session::Config for Test {
 	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, Staking>;
 	type SessionHandler = <MockSessionKeys as OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = MockSessionKeys;
+	type RequireProofOfPossession = frame_support::traits::ConstBool<false>;
 	type WeightInfo = ();
 }
 
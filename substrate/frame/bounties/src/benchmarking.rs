@@ -28,7 +28,7 @@ use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_runtime::traits::Bounded;
 
 use crate::Pallet as Bounties;
-use pallet_treasury::Pallet as Treasury;
+use pallet_treasury::{ArgumentsFactory, Pallet as Treasury};
 
 const SEED: u32 = 0;
 
@@ -83,6 +83,71 @@ fn create_bounty<T: Config<I>, I: 'static>(
 	Ok((curator_lookup, bounty_id))
 }
 
+// Create the pre-requisite information needed to create a `propose_asset_bounty`.
+fn setup_asset_bounty<T: Config<I>, I: 'static>(
+	u: u32,
+	d: u32,
+) -> (T::AccountId, T::AccountId, T::AssetKind, AssetBalanceOf<T, I>, Vec<u8>) {
+	let (caller, curator, _fee, value, reason) = setup_bounty::<T, I>(u, d);
+	let asset_kind = T::BenchmarkHelper::create_asset_kind(u);
+	T::BalanceConverter::ensure_successful(asset_kind.clone());
+	(caller, curator, asset_kind, value.saturated_into::<u128>().saturated_into(), reason)
+}
+
+fn create_asset_bounty<T: Config<I>, I: 'static>(
+) -> Result<(T::AccountId, BountyIndex, T::AssetKind, AssetBalanceOf<T, I>), BenchmarkError> {
+	let (caller, curator, asset_kind, value, reason) =
+		setup_asset_bounty::<T, I>(0, T::MaximumReasonLength::get());
+	Bounties::<T, I>::propose_asset_bounty(
+		RawOrigin::Signed(caller).into(),
+		Box::new(asset_kind.clone()),
+		value,
+		reason,
+	)?;
+	let bounty_id = BountyCount::<T, I>::get() - 1;
+	let approve_origin =
+		T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let fee = value / 2u32.into();
+	let curator_lookup = T::Lookup::unlookup(curator.clone());
+	Bounties::<T, I>::approve_asset_bounty(approve_origin, bounty_id, curator_lookup, fee)?;
+	fund_asset_curator::<T, I>(&curator, asset_kind.clone(), fee);
+	Bounties::<T, I>::accept_asset_curator(RawOrigin::Signed(curator.clone()).into(), bounty_id)?;
+	Ok((curator, bounty_id, asset_kind, value))
+}
+
+// Fund the curator with the deposit for a curator fee of `fee` in `asset_kind`.
+fn fund_asset_curator<T: Config<I>, I: 'static>(
+	curator: &T::AccountId,
+	asset_kind: T::AssetKind,
+	fee: AssetBalanceOf<T, I>,
+) {
+	let fee = T::BalanceConverter::from_asset_balance(fee, asset_kind).unwrap_or_default();
+	let deposit = Bounties::<T, I>::calculate_curator_deposit(&fee);
+	let _ = T::Currency::make_free_balance_be(curator, deposit + T::Currency::minimum_balance());
+}
+
+// Award an active asset bounty and prepare the payments of its payout.
+fn award_asset_bounty<T: Config<I>, I: 'static>(
+	curator: T::AccountId,
+	bounty_id: BountyIndex,
+	asset_kind: T::AssetKind,
+	value: AssetBalanceOf<T, I>,
+) -> Result<(), BenchmarkError> {
+	let fee = value / 2u32.into();
+	let beneficiary = T::BenchmarkHelper::create_beneficiary([1; 32]);
+	let fee_beneficiary = T::BenchmarkHelper::create_beneficiary([2; 32]);
+	T::Paymaster::ensure_successful(&beneficiary, asset_kind.clone(), value - fee);
+	T::Paymaster::ensure_successful(&fee_beneficiary, asset_kind, fee);
+	Bounties::<T, I>::award_asset_bounty(
+		RawOrigin::Signed(curator).into(),
+		bounty_id,
+		Box::new(T::BeneficiaryLookup::unlookup(beneficiary)),
+		Box::new(T::BeneficiaryLookup::unlookup(fee_beneficiary)),
+	)?;
+	T::BlockNumberProvider::set_block_number(T::BountyDepositPayoutDelay::get() + 1u32.into());
+	Ok(())
+}
+
 fn setup_pot_account<T: Config<I>, I: 'static>() {
 	let pot_account = Bounties::<T, I>::account_id();
 	let value = T::Currency::minimum_balance().saturating_mul(1_000_000_000u32.into());
@@ -124,7 +189,7 @@ benchmarks_instance_pallet! {
 		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
 		Treasury::<T, I>::on_initialize(BlockNumberFor::<T>::zero());
 		let bounty_id = BountyCount::<T, I>::get() - 1;
-		frame_system::Pallet::<T>::set_block_number(T::BountyUpdatePeriod::get() + 2u32.into());
+		T::BlockNumberProvider::set_block_number(T::BountyUpdatePeriod::get() + 2u32.into());
 		let caller = whitelisted_caller();
 	}: _(RawOrigin::Signed(caller), bounty_id)
 
@@ -148,7 +213,8 @@ benchmarks_instance_pallet! {
 		let bounty_id = BountyCount::<T, I>::get() - 1;
 		let curator = T::Lookup::lookup(curator_lookup).map_err(<&str>::from)?;
 
-		let beneficiary = T::Lookup::unlookup(account("beneficiary", 0, SEED));
+		let beneficiary =
+			T::BeneficiaryLookup::unlookup(T::BenchmarkHelper::create_beneficiary([3; 32]));
 	}: _(RawOrigin::Signed(curator), bounty_id, beneficiary)
 
 	claim_bounty {
@@ -159,16 +225,54 @@ benchmarks_instance_pallet! {
 		let bounty_id = BountyCount::<T, I>::get() - 1;
 		let curator = T::Lookup::lookup(curator_lookup).map_err(<&str>::from)?;
 
-		let beneficiary_account: T::AccountId = account("beneficiary", 0, SEED);
-		let beneficiary = T::Lookup::unlookup(beneficiary_account.clone());
-		Bounties::<T, I>::award_bounty(RawOrigin::Signed(curator.clone()).into(), bounty_id, beneficiary)?;
+		let beneficiary = T::BenchmarkHelper::create_beneficiary([3; 32]);
+		// The payouts to beneficiaries which are not local accounts are made by the `Paymaster`.
+		let beneficiary_account = T::BeneficiaryAccount::try_convert(beneficiary.clone()).ok();
+		if let (None, Some(asset_kind)) = (&beneficiary_account, T::NativeAssetKind::get()) {
+			let value = crate::Bounties::<T, I>::get(bounty_id).ok_or("Bounty not found")?.value;
+			let amount: AssetBalanceOf<T, I> = value.saturated_into::<u128>().saturated_into();
+			T::Paymaster::ensure_successful(&beneficiary, asset_kind, amount);
+		}
+		let beneficiary_lookup = T::BeneficiaryLookup::unlookup(beneficiary);
+		Bounties::<T, I>::award_bounty(RawOrigin::Signed(curator.clone()).into(), bounty_id, beneficiary_lookup)?;
 
-		frame_system::Pallet::<T>::set_block_number(T::BountyDepositPayoutDelay::get() + 1u32.into());
-		ensure!(T::Currency::free_balance(&beneficiary_account).is_zero(), "Beneficiary already has balance");
+		T::BlockNumberProvider::set_block_number(T::BountyDepositPayoutDelay::get() + 1u32.into());
+		if let Some(account) = &beneficiary_account {
+			ensure!(T::Currency::free_balance(account).is_zero(), "Beneficiary already has balance");
+		}
 
 	}: _(RawOrigin::Signed(curator), bounty_id)
 	verify {
-		ensure!(!T::Currency::free_balance(&beneficiary_account).is_zero(), "Beneficiary didn't get paid");
+		ensure!(crate::Bounties::<T, I>::get(bounty_id).is_none(), "Bounty wasn't claimed");
+		if let Some(account) = &beneficiary_account {
+			ensure!(!T::Currency::free_balance(account).is_zero(), "Beneficiary didn't get paid");
+		}
+	}
+
+	claim_bounty_remote {
+		let asset_kind = T::NativeAssetKind::get().ok_or(BenchmarkError::Weightless)?;
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Treasury::<T, I>::on_initialize(BlockNumberFor::<T>::zero());
+
+		let bounty_id = BountyCount::<T, I>::get() - 1;
+		let curator = T::Lookup::lookup(curator_lookup).map_err(<&str>::from)?;
+
+		// The payout to a beneficiary which is not a local account is made by the `Paymaster`.
+		let beneficiary = (0..=u8::MAX)
+			.map(|seed| T::BenchmarkHelper::create_beneficiary([seed; 32]))
+			.find(|beneficiary| T::BeneficiaryAccount::try_convert(beneficiary.clone()).is_err())
+			.ok_or(BenchmarkError::Weightless)?;
+		let value = crate::Bounties::<T, I>::get(bounty_id).ok_or("Bounty not found")?.value;
+		let amount: AssetBalanceOf<T, I> = value.saturated_into::<u128>().saturated_into();
+		T::Paymaster::ensure_successful(&beneficiary, asset_kind, amount);
+		let beneficiary_lookup = T::BeneficiaryLookup::unlookup(beneficiary);
+		Bounties::<T, I>::award_bounty(RawOrigin::Signed(curator.clone()).into(), bounty_id, beneficiary_lookup)?;
+
+		T::BlockNumberProvider::set_block_number(T::BountyDepositPayoutDelay::get() + 1u32.into());
+	}: claim_bounty(RawOrigin::Signed(curator), bounty_id)
+	verify {
+		ensure!(crate::Bounties::<T, I>::get(bounty_id).is_none(), "Bounty wasn't claimed");
 	}
 
 	close_bounty_proposed {
@@ -192,6 +296,18 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::BountyCanceled { index: bounty_id }.into())
 	}
 
+	withdraw_bounty {
+		setup_pot_account::<T, I>();
+		let (caller, curator, fee, value, reason) = setup_bounty::<T, I>(0, 0);
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller.clone()).into(), value, reason)?;
+		let bounty_id = BountyCount::<T, I>::get() - 1;
+		let bond = crate::Bounties::<T, I>::get(bounty_id).ok_or("Bounty not found")?.bond;
+		let fee = T::BountyWithdrawalFee::get() * bond;
+	}: _(RawOrigin::Signed(caller), bounty_id)
+	verify {
+		assert_last_event::<T, I>(Event::BountyWithdrawn { index: bounty_id, fee }.into())
+	}
+
 	extend_bounty_expiry {
 		setup_pot_account::<T, I>();
 		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
@@ -225,11 +341,258 @@ benchmarks_instance_pallet! {
 		ensure!(!missed_any, "Missed some");
 		if b > 0 {
 			ensure!(budget_remaining < BalanceOf::<T, I>::max_value(), "Budget not used");
-			assert_last_event::<T, I>(Event::BountyBecameActive { index: b - 1 }.into())
+			let index = b - 1;
+			let account = Bounties::<T, I>::bounty_account_id(index);
+			let value = crate::Bounties::<T, I>::get(index).ok_or("Bounty not found")?.value;
+			assert_last_event::<T, I>(Event::BountyBecameActive { index, account, value }.into())
 		} else {
 			ensure!(budget_remaining == BalanceOf::<T, I>::max_value(), "Budget used");
 		}
 	}
 
+	propose_asset_bounty {
+		let d in 0 .. T::MaximumReasonLength::get();
+
+		let (caller, _, asset_kind, value, description) = setup_asset_bounty::<T, I>(0, d);
+	}: _(RawOrigin::Signed(caller), Box::new(asset_kind), value, description)
+
+	approve_asset_bounty {
+		let (caller, curator, asset_kind, value, reason) = setup_asset_bounty::<T, I>(0, T::MaximumReasonLength::get());
+		Bounties::<T, I>::propose_asset_bounty(RawOrigin::Signed(caller).into(), Box::new(asset_kind), value, reason)?;
+		let bounty_id = BountyCount::<T, I>::get() - 1;
+		let approve_origin = T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let curator_lookup = T::Lookup::unlookup(curator);
+	}: _<T::RuntimeOrigin>(approve_origin, bounty_id, curator_lookup, value / 2u32.into())
+
+	accept_asset_curator {
+		let (caller, curator, asset_kind, value, reason) = setup_asset_bounty::<T, I>(0, T::MaximumReasonLength::get());
+		Bounties::<T, I>::propose_asset_bounty(RawOrigin::Signed(caller).into(), Box::new(asset_kind.clone()), value, reason)?;
+		let bounty_id = BountyCount::<T, I>::get() - 1;
+		let approve_origin = T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let curator_lookup = T::Lookup::unlookup(curator.clone());
+		let fee = value / 2u32.into();
+		Bounties::<T, I>::approve_asset_bounty(approve_origin, bounty_id, curator_lookup, fee)?;
+		fund_asset_curator::<T, I>(&curator, asset_kind, fee);
+	}: _(RawOrigin::Signed(curator), bounty_id)
+
+	extend_asset_bounty_expiry {
+		let (curator, bounty_id, _, _) = create_asset_bounty::<T, I>()?;
+	}: _(RawOrigin::Signed(curator), bounty_id, Vec::new())
+	verify {
+		assert_last_event::<T, I>(Event::BountyExtended { index: bounty_id }.into())
+	}
+
+	unassign_asset_curator {
+		let (_, bounty_id, _, _) = create_asset_bounty::<T, I>()?;
+		T::BlockNumberProvider::set_block_number(T::BountyUpdatePeriod::get() + 2u32.into());
+		let caller = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), bounty_id)
+	verify {
+		assert_last_event::<T, I>(Event::CuratorUnassigned { bounty_id }.into())
+	}
+
+	propose_asset_curator {
+		let (curator, bounty_id, _, value) = create_asset_bounty::<T, I>()?;
+		Bounties::<T, I>::unassign_asset_curator(RawOrigin::Signed(curator.clone()).into(), bounty_id)?;
+		let approve_origin = T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let curator_lookup = T::Lookup::unlookup(curator);
+	}: _<T::RuntimeOrigin>(approve_origin, bounty_id, curator_lookup, value / 2u32.into())
+
+	award_asset_bounty {
+		let (curator, bounty_id, _, _) = create_asset_bounty::<T, I>()?;
+		let beneficiary = T::BeneficiaryLookup::unlookup(T::BenchmarkHelper::create_beneficiary([1; 32]));
+	}: _(RawOrigin::Signed(curator), bounty_id, Box::new(beneficiary.clone()), Box::new(beneficiary))
+
+	payout_asset_bounty {
+		let (curator, bounty_id, asset_kind, value) = create_asset_bounty::<T, I>()?;
+		award_asset_bounty::<T, I>(curator, bounty_id, asset_kind, value)?;
+		let caller = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), bounty_id)
+	verify {
+		assert_last_event::<T, I>(Event::AssetBountyPayoutAttempted { index: bounty_id }.into())
+	}
+
+	check_asset_bounty_payout {
+		let (curator, bounty_id, asset_kind, value) = create_asset_bounty::<T, I>()?;
+		award_asset_bounty::<T, I>(curator, bounty_id, asset_kind, value)?;
+		let caller: T::AccountId = whitelisted_caller();
+		Bounties::<T, I>::payout_asset_bounty(RawOrigin::Signed(caller.clone()).into(), bounty_id)?;
+		let bounty = AssetBounties::<T, I>::get(bounty_id).ok_or("Bounty not found")?;
+		if let AssetBountyStatus::PayingOut { payout, fee_payout, .. } = bounty.status {
+			for state in [payout, fee_payout] {
+				if let AssetPayoutState::Attempted { id } = state {
+					T::Paymaster::ensure_concluded(id);
+				}
+			}
+		}
+	}: _(RawOrigin::Signed(caller), bounty_id)
+	verify {
+		ensure!(AssetBounties::<T, I>::get(bounty_id).is_none(), "Bounty not paid out");
+	}
+
+	close_asset_bounty {
+		let (_, bounty_id, _, _) = create_asset_bounty::<T, I>()?;
+		let reject_origin =
+			T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(reject_origin, bounty_id)
+	verify {
+		assert_last_event::<T, I>(Event::BountyCanceled { index: bounty_id }.into())
+	}
+
+	set_curator_committee {
+		let m in 0 .. (MAX_CURATOR_COMMITTEE_SIZE - 1);
+		setup_pot_account::<T, I>();
+		let (_, bounty_id) = create_bounty::<T, I>()?;
+		let members: Vec<AccountIdLookupOf<T>> = (0..m).map(|i| T::Lookup::unlookup(account("member", i, SEED))).collect();
+		let spend_origin =
+			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(spend_origin, bounty_id, members, 1)
+	verify {
+		assert_last_event::<T, I>(
+			Event::CuratorCommitteeSet { bounty_id, members: m + 1, threshold: 1 }.into()
+		)
+	}
+
+	sweep_expired_bounty {
+		setup_pot_account::<T, I>();
+		let (caller, _curator, _fee, value, reason) = setup_bounty::<T, I>(0, T::MaximumReasonLength::get());
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller.clone()).into(), value, reason)?;
+		let bounty_id = BountyCount::<T, I>::get() - 1;
+		let approve_origin = T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Bounties::<T, I>::approve_bounty(approve_origin, bounty_id)?;
+		Treasury::<T, I>::on_initialize(BlockNumberFor::<T>::zero());
+		let expires_at = FundedExpiries::<T, I>::get(bounty_id).ok_or("Bounty not funded")?;
+		T::BlockNumberProvider::set_block_number(expires_at);
+	}: _(RawOrigin::Signed(caller), bounty_id)
+	verify {
+		assert_last_event::<T, I>(Event::BountyExpired { index: bounty_id, value }.into())
+	}
+
+	update_curator_fee {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		let curator = T::Lookup::lookup(curator_lookup).map_err(<&str>::from)?;
+		let bounty = crate::Bounties::<T, I>::get(bounty_id).ok_or("Bounty not found")?;
+		let _ = T::Currency::make_free_balance_be(&curator, bounty.value);
+		// raising the fee reserves more of the curator deposit.
+		let old_fee = bounty.fee;
+		let new_fee = old_fee.saturating_add(old_fee / 2u32.into());
+		let spend_origin =
+			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Bounties::<T, I>::update_curator_fee(spend_origin, bounty_id, new_fee)?;
+	}: _(RawOrigin::Signed(curator), bounty_id, new_fee)
+	verify {
+		assert_last_event::<T, I>(Event::CuratorFeeUpdated { bounty_id, old_fee, new_fee }.into())
+	}
+
+	check_bounty_payout {
+		let asset_kind = T::NativeAssetKind::get().ok_or(BenchmarkError::Weightless)?;
+		let beneficiary = T::BenchmarkHelper::create_beneficiary([3; 32]);
+		let amount: AssetBalanceOf<T, I> = 100u128.saturated_into();
+		T::Paymaster::ensure_successful(&beneficiary, asset_kind.clone(), amount);
+		let id = T::Paymaster::pay(&beneficiary, asset_kind.clone(), amount)
+			.map_err(|_| BenchmarkError::Weightless)?;
+		T::Paymaster::ensure_concluded(id);
+		let bounty_id = 0;
+		RemotePayouts::<T, I>::insert(bounty_id, RemotePayout {
+			beneficiary,
+			asset_kind,
+			amount,
+			state: AssetPayoutState::Attempted { id },
+		});
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), bounty_id)
+	verify {
+		assert_last_event::<T, I>(Event::BountyPayoutSucceeded { index: bounty_id }.into())
+	}
+
+	set_bounty_recurring {
+		setup_pot_account::<T, I>();
+		let (_curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		let spend_origin =
+			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(spend_origin, bounty_id, true)
+	verify {
+		assert_last_event::<T, I>(
+			Event::BountyRecurringSet { index: bounty_id, recurring: true }.into()
+		)
+	}
+
+	poke_deposit {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		let curator = T::Lookup::lookup(curator_lookup).map_err(<&str>::from)?;
+		let mut bounty = crate::Bounties::<T, I>::get(bounty_id).ok_or("Bounty not found")?;
+		let _ = T::Currency::make_free_balance_be(&curator, bounty.value);
+		// the worst case moves a reserved curator deposit on hold and holds more of it.
+		let old_deposit = bounty.curator_deposit;
+		T::NativeBalance::release(
+			&HoldReason::<I>::CuratorDeposit.into(),
+			&curator,
+			old_deposit,
+			Precision::Exact,
+		)?;
+		T::Currency::reserve(&curator, old_deposit)?;
+		bounty.fee = bounty.fee.saturating_add(bounty.fee / 2u32.into());
+		let new_deposit = Bounties::<T, I>::calculate_curator_deposit(&bounty.fee);
+		crate::Bounties::<T, I>::insert(bounty_id, &bounty);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), bounty_id)
+	verify {
+		assert_last_event::<T, I>(
+			Event::CuratorDepositPoked { bounty_id, old_deposit, new_deposit }.into()
+		)
+	}
+
+	appeal_curator_slash {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		let curator = T::Lookup::lookup(curator_lookup).map_err(<&str>::from)?;
+		T::BlockNumberProvider::set_block_number(T::BountyUpdatePeriod::get() + 2u32.into());
+		let reporter: T::AccountId = account("reporter", 0, SEED);
+		Bounties::<T, I>::unassign_curator(RawOrigin::Signed(reporter).into(), bounty_id)?;
+		let amount = SlashedCuratorDeposits::<T, I>::get(bounty_id, &curator)
+			.ok_or("Slash not recorded")?
+			.amount;
+	}: _(RawOrigin::Signed(curator.clone()), bounty_id)
+	verify {
+		assert_last_event::<T, I>(Event::CuratorSlashAppealed { bounty_id, curator, amount }.into())
+	}
+
+	// Worst case when the appeal is upheld and the deposit reimbursed.
+	resolve_curator_slash_appeal {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		let curator = T::Lookup::lookup(curator_lookup.clone()).map_err(<&str>::from)?;
+		T::BlockNumberProvider::set_block_number(T::BountyUpdatePeriod::get() + 2u32.into());
+		let reporter: T::AccountId = account("reporter", 0, SEED);
+		Bounties::<T, I>::unassign_curator(RawOrigin::Signed(reporter).into(), bounty_id)?;
+		Bounties::<T, I>::appeal_curator_slash(RawOrigin::Signed(curator.clone()).into(), bounty_id)?;
+		let amount = SlashedCuratorDeposits::<T, I>::get(bounty_id, &curator)
+			.ok_or("Slash not recorded")?
+			.amount;
+		let approve_origin =
+			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(approve_origin, bounty_id, curator_lookup, true)
+	verify {
+		assert_last_event::<T, I>(Event::CuratorSlashReimbursed { bounty_id, curator, amount }.into())
+	}
+
+	remove_expired_curator_slash {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		let curator = T::Lookup::lookup(curator_lookup.clone()).map_err(<&str>::from)?;
+		T::BlockNumberProvider::set_block_number(T::BountyUpdatePeriod::get() + 2u32.into());
+		let reporter: T::AccountId = account("reporter", 0, SEED);
+		Bounties::<T, I>::unassign_curator(RawOrigin::Signed(reporter.clone()).into(), bounty_id)?;
+		let appeal_until = SlashedCuratorDeposits::<T, I>::get(bounty_id, &curator)
+			.ok_or("Slash not recorded")?
+			.appeal_until;
+		T::BlockNumberProvider::set_block_number(appeal_until + 1u32.into());
+	}: _(RawOrigin::Signed(reporter), bounty_id, curator_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::CuratorSlashExpired { bounty_id, curator }.into())
+	}
+
 	impl_benchmark_test_suite!(Bounties, crate::tests::new_test_ext(), crate::tests::Test)
 }
@@ -57,7 +57,9 @@
 //!   deposit is returned when/if the bounty is completed.
 //! - **Bounty value:** The total amount that should be paid to the Payout Address if the bounty is
 //!   rewarded.
-//! - **Payout address:** The account to which the total or part of the bounty is assigned to.
+//! - **Payout address:** The beneficiary of the treasury to which the total or part of the bounty
+//!   is assigned to. The payouts to beneficiaries which are not local accounts, e.g. accounts on
+//!   other chains, are made through the `Paymaster` of the treasury.
 //! - **Payout Delay:** The delay period for which a bounty beneficiary needs to wait before
 //!   claiming.
 //! - **Curator fee:** The reserved upfront payment for a curator for work related to the bounty.
@@ -72,32 +74,99 @@
 //!   tasks and stake the required deposit.
 //! - `approve_bounty` - Accept a specific treasury amount to be earmarked for a predefined body of
 //!   work.
+//! - `approve_bounty_with_update_period` - Approve a bounty like `approve_bounty`, with an update
+//!   period of its own instead of the global [`Config::BountyUpdatePeriod`].
 //! - `propose_curator` - Assign an account to a bounty as candidate curator.
 //! - `accept_curator` - Accept a bounty assignment from the Council, setting a curator deposit.
+//! - `set_curator_committee` - Share the curation of an active bounty with a committee, whose
+//!   threshold of members must approve awarding the bounty, extending its expiry and the operations
+//!   of the curator on its child bounties.
 //! - `extend_bounty_expiry` - Extend the expiry block number of the bounty and stay active.
 //! - `award_bounty` - Close and pay out the specified amount for the completed work.
-//! - `claim_bounty` - Claim a specific bounty amount from the Payout Address.
-//! - `unassign_curator` - Unassign an accepted curator from a specific earmark.
+//! - `claim_bounty` - Claim a specific bounty amount from the Payout Address, unless the
+//!   [`Config::PayoutGuard`] denies or delays the payout.
+//! - `unassign_curator` - Unassign an accepted curator from a specific earmark. Anyone reporting an
+//!   inactive curator is paid the [`Config::CuratorSlashReporterShare`] of their slashed deposit.
 //! - `close_bounty` - Cancel the earmark for a specific treasury amount and close the bounty.
+//! - `withdraw_bounty` - Withdraw a bounty or asset bounty proposal which is not approved yet,
+//!   getting the bond back less the [`Config::BountyWithdrawalFee`].
+//! - `update_curator_fee` - Approve a change of the curator fee of an active bounty, which takes
+//!   effect once both its curator and the spend origin approved it.
+//! - `check_bounty_payout` - Check the payout of a claimed bounty to a beneficiary which is not a
+//!   local account, or retry it if it failed.
+//! - `sweep_expired_bounty` - Return the funds of a bounty which expired without an active curator
+//!   to the treasury.
+//! - `set_bounty_recurring` - Make a bounty recurring, so that it is approved again with the same
+//!   value after each claim instead of being removed, e.g. for standing bug-bounty programs.
+//! - `poke_deposit` - Recalculate the curator deposit of a bounty and hold it.
+//! - `appeal_curator_slash` - Appeal the slash of a curator deposit by `unassign_curator`.
+//! - `resolve_curator_slash_appeal` - Uphold or reject the appeal of a slashed curator. An upheld
+//!   appeal reimburses the deposit from the treasury.
+//! - `remove_expired_curator_slash` - Remove a slash which was not appealed in time.
+//!
+//! Asset bounty protocol:
+//!
+//! Asset bounties are denominated in an asset of the treasury and paid out through its
+//! `Paymaster`, like the spends of the treasury. Their funds stay with the treasury until the
+//! payout.
+//!
+//! - `propose_asset_bounty` - Propose an amount of an asset of the treasury to be paid for a
+//!   predefined set of tasks and stake the required deposit.
+//! - `approve_asset_bounty` - Approve an asset bounty and assign an account as candidate curator.
+//! - `accept_asset_curator` - Accept an asset bounty assignment, setting a curator deposit.
+//! - `extend_asset_bounty_expiry` - Extend the expiry block of an active asset bounty.
+//! - `unassign_asset_curator` - Unassign the curator of an asset bounty, e.g. an inactive one.
+//! - `propose_asset_curator` - Assign a new candidate curator to an asset bounty whose curator was
+//!   unassigned.
+//! - `award_asset_bounty` - Award the asset bounty and its curator fee to beneficiaries.
+//! - `payout_asset_bounty` - Pay out an awarded asset bounty after the payout delay.
+//! - `check_asset_bounty_payout` - Check the payout of an asset bounty and remove it once paid.
+//! - `close_asset_bounty` - Cancel an asset bounty before it is awarded.
+//!
+//! ### Deposits
+//!
+//! The bonds of proposers and the deposits of curators are held in the [`Config::NativeBalance`]
+//! with a [`HoldReason`]. Deposits reserved before the pallet held them remain reserved: releasing
+//! or slashing a deposit falls back to the reserve once the balance held from the account is
+//! exhausted, and `poke_deposit` moves a reserved curator deposit on hold.
+//!
+//! ### Funding ledger
+//!
+//! The funds moved into the account of each bounty when it is funded are tracked in a
+//! [`BountyLedger`] until the bounty is claimed, closed or swept, together with the part
+//! committed to its child bounties and the part already paid out through them. The
+//! [`TotalEncumbered`] funds of all bounties can be used to plan the spending of the treasury.
+//! Asset bounties are tracked from their approval until they are paid out or closed, with their
+//! value converted to the native currency at approval.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
 pub mod migrations;
+pub mod runtime_api;
 mod tests;
 pub mod weights;
 
 use sp_std::prelude::*;
 
 use frame_support::traits::{
-	Currency, ExistenceRequirement::AllowDeath, Get, Imbalance, OnUnbalanced, ReservableCurrency,
+	fungible::{self, BalancedHold, InspectHold, MutateHold},
+	tokens::{ConversionFromAssetBalance, Fortitude, Pay, PaymentStatus, Precision, Restriction},
+	BalanceStatus, Currency,
+	ExistenceRequirement::{AllowDeath, KeepAlive},
+	Get, Imbalance, OnUnbalanced, ReservableCurrency, UnixTime,
 };
 
 use sp_runtime::{
-	traits::{AccountIdConversion, BadOrigin, Saturating, StaticLookup, Zero},
-	DispatchResult, Permill, RuntimeDebug,
+	traits::{
+		AccountIdConversion, BadOrigin, BlockNumberProvider, Saturating, StaticLookup, TryConvert,
+		Zero,
+	},
+	DispatchResult, Permill, RuntimeDebug, SaturatedConversion,
 };
 
+use sp_core::H256;
+
 use frame_support::{dispatch::DispatchResultWithPostInfo, traits::EnsureOrigin};
 
 use frame_support::pallet_prelude::*;
@@ -111,6 +180,41 @@ type BalanceOf<T, I = ()> = pallet_treasury::BalanceOf<T, I>;
 
 type PositiveImbalanceOf<T, I = ()> = pallet_treasury::PositiveImbalanceOf<T, I>;
 
+type AssetBalanceOf<T, I = ()> = pallet_treasury::AssetBalanceOf<T, I>;
+
+type PaymentIdOf<T, I = ()> = <<T as pallet_treasury::Config<I>>::Paymaster as Pay>::Id;
+
+type BeneficiaryLookupOf<T, I = ()> =
+	<<T as pallet_treasury::Config<I>>::BeneficiaryLookup as StaticLookup>::Source;
+
+/// An [`AssetBounty`] of the runtime.
+pub type AssetBountyOf<T, I = ()> = AssetBounty<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T, I>,
+	<T as pallet_treasury::Config<I>>::AssetKind,
+	AssetBalanceOf<T, I>,
+	<T as pallet_treasury::Config<I>>::Beneficiary,
+	BlockNumberFor<T>,
+	PaymentIdOf<T, I>,
+>;
+
+/// A [`RemotePayout`] of the runtime.
+pub type RemotePayoutOf<T, I = ()> = RemotePayout<
+	<T as pallet_treasury::Config<I>>::Beneficiary,
+	<T as pallet_treasury::Config<I>>::AssetKind,
+	AssetBalanceOf<T, I>,
+	PaymentIdOf<T, I>,
+>;
+
+/// A [`BountySummary`] of the runtime.
+pub type BountySummaryOf<T, I = ()> = BountySummary<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T, I>,
+	BlockNumberFor<T>,
+	<T as pallet_treasury::Config<I>>::AssetKind,
+	AssetBalanceOf<T, I>,
+>;
+
 /// An index of a bounty. Just a `u32`.
 pub type BountyIndex = u32;
 
@@ -118,7 +222,7 @@ type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup
 
 /// A bounty proposal.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct Bounty<AccountId, Balance, BlockNumber> {
+pub struct Bounty<AccountId, Balance, BlockNumber, Beneficiary = AccountId> {
 	/// The account proposing it.
 	proposer: AccountId,
 	/// The (total) amount that should be paid if the bounty is rewarded.
@@ -127,24 +231,40 @@ pub struct Bounty<AccountId, Balance, BlockNumber> {
 	fee: Balance,
 	/// The deposit of curator.
 	curator_deposit: Balance,
-	/// The amount held on deposit (reserved) for making this proposal.
+	/// The amount held on deposit for making this proposal.
 	bond: Balance,
 	/// The status of this bounty.
-	status: BountyStatus<AccountId, BlockNumber>,
+	status: BountyStatus<AccountId, BlockNumber, Beneficiary>,
+	/// The update period of this bounty overriding [`Config::BountyUpdatePeriod`], if any.
+	update_period: Option<BlockNumber>,
 }
 
-impl<AccountId: PartialEq + Clone + Ord, Balance, BlockNumber: Clone>
-	Bounty<AccountId, Balance, BlockNumber>
+impl<
+		AccountId: PartialEq + Clone + Ord,
+		Balance: Clone,
+		BlockNumber: Clone,
+		Beneficiary: Clone,
+	> Bounty<AccountId, Balance, BlockNumber, Beneficiary>
 {
 	/// Getter for bounty status, to be used for child bounties.
-	pub fn get_status(&self) -> BountyStatus<AccountId, BlockNumber> {
+	pub fn get_status(&self) -> BountyStatus<AccountId, BlockNumber, Beneficiary> {
 		self.status.clone()
 	}
+
+	/// Getter for bounty value, to be used for child bounties.
+	pub fn get_value(&self) -> Balance {
+		self.value.clone()
+	}
+
+	/// Getter for bounty curator fee, to be used for child bounties.
+	pub fn get_fee(&self) -> Balance {
+		self.fee.clone()
+	}
 }
 
 /// The status of a bounty proposal.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub enum BountyStatus<AccountId, BlockNumber> {
+pub enum BountyStatus<AccountId, BlockNumber, Beneficiary = AccountId> {
 	/// The bounty is proposed and waiting for approval.
 	Proposed,
 	/// The bounty is approved and waiting to become active at next spend period.
@@ -162,18 +282,317 @@ pub enum BountyStatus<AccountId, BlockNumber> {
 		curator: AccountId,
 		/// An update from the curator is due by this block, else they are considered inactive.
 		update_due: BlockNumber,
+		/// The committee sharing the curation of this bounty, if the curator does not act alone.
+		committee: Option<CuratorCommittee<AccountId>>,
 	},
 	/// The bounty is awarded and waiting to released after a delay.
 	PendingPayout {
 		/// The curator of this bounty.
 		curator: AccountId,
 		/// The beneficiary of the bounty.
-		beneficiary: AccountId,
+		beneficiary: Beneficiary,
 		/// When the bounty can be claimed.
 		unlock_at: BlockNumber,
 	},
 }
 
+/// The maximum number of members of a [`CuratorCommittee`].
+pub const MAX_CURATOR_COMMITTEE_SIZE: u32 = 16;
+
+/// A committee of curators sharing the curation of an active bounty.
+///
+/// Awarding the bounty, extending its expiry and the operations of the curator on its child
+/// bounties take effect once `threshold` members approved the same action. The curator, who holds
+/// the curator deposit and receives the fee, is always a member.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CuratorCommittee<AccountId> {
+	/// The members of the committee.
+	pub members: BoundedVec<AccountId, ConstU32<MAX_CURATOR_COMMITTEE_SIZE>>,
+	/// The number of members which must approve an action.
+	pub threshold: u32,
+}
+
+/// An action of the curator committee of a bounty, which its members approve.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CommitteeAction<Beneficiary> {
+	/// Award the bounty to `beneficiary`.
+	Award { beneficiary: Beneficiary },
+	/// Extend the expiry of the bounty.
+	ExtendExpiry,
+	/// Operate on the child bounties of the bounty with the call of `call_hash`, e.g. adding a
+	/// child bounty or proposing its curator.
+	ChildBounty { call_hash: H256 },
+}
+
+/// A change of the curator fee of an active bounty, which takes effect once both the curator and
+/// the `SpendOrigin` approved it.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CuratorFeeUpdate<Balance> {
+	/// The new curator fee.
+	pub fee: Balance,
+	/// Whether the curator approved the new fee.
+	pub curator_approved: bool,
+	/// Whether the `SpendOrigin` approved the new fee.
+	pub spend_approved: bool,
+}
+
+/// A curator deposit slashed by `unassign_curator`, which the curator may appeal.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SlashedCuratorDeposit<Balance, BlockNumber> {
+	/// The amount slashed to the treasury, excluding the share paid to the reporter.
+	pub amount: Balance,
+	/// Whether the curator appealed the slash, awaiting the decision of the `SpendOrigin`.
+	pub appealed: bool,
+	/// The last block at which the curator may appeal the slash. Once it passed, anyone may
+	/// remove a slash which was not appealed.
+	pub appeal_until: BlockNumber,
+}
+
+/// The stage of a bounty in a [`BountySummary`].
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum BountySummaryStatus {
+	/// The bounty is proposed and waiting for approval.
+	Proposed,
+	/// The bounty is approved and waiting to become active at next spend period.
+	Approved,
+	/// The bounty is funded and waiting for curator assignment.
+	Funded,
+	/// A curator has been proposed and waits to accept.
+	CuratorProposed,
+	/// The bounty is active and waiting to be awarded.
+	Active,
+	/// The bounty is awarded and waiting to be claimed.
+	PendingPayout,
+	/// The payout of the awarded asset bounty was attempted.
+	PayingOut,
+}
+
+/// The amounts of a bounty in a [`BountySummary`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum BountySummaryValue<Balance, AssetKind, AssetBalance> {
+	/// A bounty paid in the native currency.
+	Native {
+		/// The amount paid out if the bounty is awarded, including the curator fee.
+		value: Balance,
+		/// The curator fee.
+		fee: Balance,
+	},
+	/// A bounty paid in the asset `asset_kind`.
+	Asset {
+		/// The kind of asset the bounty is paid in.
+		asset_kind: AssetKind,
+		/// The amount of the asset paid out if the bounty is awarded, including the curator fee.
+		value: AssetBalance,
+		/// The curator fee in the asset.
+		fee: AssetBalance,
+	},
+}
+
+/// The position after which [`Pallet::bounty_summaries`] continues to page through the bounties.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum BountyCursor {
+	/// After the native bounty with the given index.
+	Native(BountyIndex),
+	/// After the asset bounty with the given index.
+	Asset(BountyIndex),
+}
+
+/// A summary of a bounty, as returned by the [`runtime_api::BountiesApi`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BountySummary<AccountId, Balance, BlockNumber, AssetKind, AssetBalance> {
+	/// The index of the bounty.
+	pub index: BountyIndex,
+	/// The account which proposed the bounty.
+	pub proposer: AccountId,
+	/// The value and curator fee of the bounty.
+	pub value: BountySummaryValue<Balance, AssetKind, AssetBalance>,
+	/// The stage of the bounty.
+	pub status: BountySummaryStatus,
+	/// The proposed or assigned curator of the bounty.
+	pub curator: Option<AccountId>,
+	/// The block by which an update from the curator of an active bounty is due.
+	pub update_due: Option<BlockNumber>,
+	/// The block from which an awarded bounty can be claimed.
+	pub unlock_at: Option<BlockNumber>,
+	/// The BLAKE2-256 hash of the bounty description, if it is still stored.
+	pub description_hash: Option<H256>,
+}
+
+impl<AccountId, Balance, BlockNumber, AssetKind, AssetBalance>
+	BountySummary<AccountId, Balance, BlockNumber, AssetKind, AssetBalance>
+{
+	/// The cursor from which the page following this bounty starts.
+	pub fn cursor(&self) -> BountyCursor {
+		match self.value {
+			BountySummaryValue::Native { .. } => BountyCursor::Native(self.index),
+			BountySummaryValue::Asset { .. } => BountyCursor::Asset(self.index),
+		}
+	}
+}
+
+/// The funding of a bounty, tracked from the moment it is funded until it is claimed, closed or
+/// swept.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct BountyLedger<Balance> {
+	/// The amount moved into the bounty account when the bounty was funded.
+	pub funded: Balance,
+	/// The part of the funded amount committed to child bounties which are not paid out yet.
+	pub child_bounties: Balance,
+	/// The part of the funded amount paid out through child bounties.
+	pub paid: Balance,
+}
+
+impl<Balance: Saturating + Copy> BountyLedger<Balance> {
+	/// The part of the funded amount which is still locked in the bounty or its child bounties.
+	pub fn encumbered(&self) -> Balance {
+		self.funded.saturating_sub(self.paid)
+	}
+}
+
+/// A bounty denominated in an asset of the treasury, which is paid through the treasury's
+/// `Paymaster`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AssetBounty<
+	AccountId,
+	Balance,
+	AssetKind,
+	AssetBalance,
+	Beneficiary,
+	BlockNumber,
+	PaymentId,
+> {
+	/// The account proposing it.
+	proposer: AccountId,
+	/// The kind of asset the bounty is paid in.
+	asset_kind: AssetKind,
+	/// The (total) amount of the asset that should be paid if the bounty is rewarded.
+	value: AssetBalance,
+	/// The curator fee in the asset. Included in value.
+	fee: AssetBalance,
+	/// The deposit of curator, in the native currency.
+	curator_deposit: Balance,
+	/// The amount held on deposit for making this proposal.
+	bond: Balance,
+	/// The status of this bounty.
+	status: AssetBountyStatus<AccountId, Beneficiary, BlockNumber, PaymentId>,
+}
+
+impl<
+		AccountId,
+		Balance,
+		AssetKind: Clone,
+		AssetBalance: Copy,
+		Beneficiary,
+		BlockNumber,
+		PaymentId,
+	> AssetBounty<AccountId, Balance, AssetKind, AssetBalance, Beneficiary, BlockNumber, PaymentId>
+{
+	/// Getter for the kind of asset the bounty is paid in.
+	pub fn get_asset_kind(&self) -> AssetKind {
+		self.asset_kind.clone()
+	}
+
+	/// Getter for the bounty value.
+	pub fn get_value(&self) -> AssetBalance {
+		self.value
+	}
+
+	/// Getter for the bounty curator fee.
+	pub fn get_fee(&self) -> AssetBalance {
+		self.fee
+	}
+}
+
+/// The status of an asset bounty.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AssetBountyStatus<AccountId, Beneficiary, BlockNumber, PaymentId> {
+	/// The bounty is proposed and waiting for approval.
+	Proposed,
+	/// The bounty is approved and a curator has been proposed. Waiting for acceptance from the
+	/// curator.
+	CuratorProposed {
+		/// The assigned curator of this bounty.
+		curator: AccountId,
+	},
+	/// The bounty is active and waiting to be awarded.
+	Active {
+		/// The curator of this bounty.
+		curator: AccountId,
+		/// An update from the curator is due by this block, else they are considered inactive.
+		update_due: BlockNumber,
+	},
+	/// The bounty is awarded and waiting to be paid out after a delay.
+	PendingPayout {
+		/// The curator of this bounty.
+		curator: AccountId,
+		/// The beneficiary of the bounty.
+		beneficiary: Beneficiary,
+		/// The beneficiary of the curator fee.
+		fee_beneficiary: Beneficiary,
+		/// When the bounty can be paid out.
+		unlock_at: BlockNumber,
+	},
+	/// The payout of the bounty was attempted.
+	PayingOut {
+		/// The beneficiary of the bounty.
+		beneficiary: Beneficiary,
+		/// The beneficiary of the curator fee.
+		fee_beneficiary: Beneficiary,
+		/// The state of the payment to the beneficiary.
+		payout: AssetPayoutState<PaymentId>,
+		/// The state of the payment of the curator fee.
+		fee_payout: AssetPayoutState<PaymentId>,
+	},
+	/// The bounty is approved and its curator was unassigned. Waiting for a new curator to be
+	/// proposed.
+	Approved,
+}
+
+/// The state of a payment of an asset bounty.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AssetPayoutState<Id> {
+	/// The payment was not attempted yet.
+	Pending,
+	/// The payment was attempted with the payment identifier.
+	Attempted { id: Id },
+	/// The payment failed and has to be attempted again.
+	Failed,
+	/// The payment succeeded.
+	Succeeded,
+}
+
+/// The payout of a claimed bounty to a beneficiary which is not a local account, e.g. an XCM
+/// location, made through the treasury's `Paymaster`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RemotePayout<Beneficiary, AssetKind, AssetBalance, PaymentId> {
+	/// The beneficiary of the bounty.
+	pub beneficiary: Beneficiary,
+	/// The kind of the native asset in which the payout is made.
+	pub asset_kind: AssetKind,
+	/// The amount paid out.
+	pub amount: AssetBalance,
+	/// The state of the payment.
+	pub state: AssetPayoutState<PaymentId>,
+}
+
+/// A [`BlockNumberProvider`] counting the time passed since the Unix epoch in units of `Period`
+/// milliseconds.
+///
+/// Using it as [`Config::BlockNumberProvider`] makes the deadlines of bounties follow the wall
+/// clock, independently of the rate at which blocks are produced.
+pub struct TimestampBlockNumberProvider<T, Time, Period>(PhantomData<(T, Time, Period)>);
+
+impl<T: frame_system::Config, Time: UnixTime, Period: Get<u64>> BlockNumberProvider
+	for TimestampBlockNumberProvider<T, Time, Period>
+{
+	type BlockNumber = BlockNumberFor<T>;
+
+	fn current_block_number() -> Self::BlockNumber {
+		let millis: u64 = Time::now().as_millis().saturated_into();
+		millis.checked_div(Period::get()).unwrap_or_default().saturated_into()
+	}
+}
+
 /// The child bounty manager.
 pub trait ChildBountyManager<Balance> {
 	/// Get the active child bounties for a parent bounty.
@@ -181,13 +600,55 @@ pub trait ChildBountyManager<Balance> {
 
 	/// Get total curator fees of children-bounty curators.
 	fn children_curator_fees(bounty_id: BountyIndex) -> Balance;
+
+	/// Get total curator fees of children-bounty curators, without clearing them like
+	/// [`Self::children_curator_fees`] does once the parent bounty is claimed.
+	fn peek_children_curator_fees(bounty_id: BountyIndex) -> Balance;
+}
+
+/// The verdict of a [`PayoutGuard`] on the claim of an awarded bounty.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum PayoutVerdict<BlockNumber> {
+	/// The bounty may be paid out.
+	Allow,
+	/// The payout is delayed until the given block, e.g. until a dispute of the award is
+	/// decided.
+	Delay(BlockNumber),
+	/// The bounty may not be paid out for now.
+	Deny,
+}
+
+/// Hook checking the claim of an awarded bounty before it is paid out.
+pub trait PayoutGuard<Beneficiary, Balance, BlockNumber> {
+	/// Check the payout of `value` of the bounty `bounty_id` to `beneficiary`.
+	fn check_payout(
+		bounty_id: BountyIndex,
+		beneficiary: &Beneficiary,
+		value: Balance,
+	) -> PayoutVerdict<BlockNumber>;
+
+	/// The weight of [`Self::check_payout`].
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Allows all payouts.
+impl<Beneficiary, Balance, BlockNumber> PayoutGuard<Beneficiary, Balance, BlockNumber> for () {
+	fn check_payout(
+		_bounty_id: BountyIndex,
+		_beneficiary: &Beneficiary,
+		_value: Balance,
+	) -> PayoutVerdict<BlockNumber> {
+		PayoutVerdict::Allow
+	}
 }
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(9);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -207,6 +668,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type BountyUpdatePeriod: Get<BlockNumberFor<Self>>;
 
+		/// The period in blocks after which a funded bounty without an active curator expires, and
+		/// anyone may sweep its funds back to the treasury.
+		#[pallet::constant]
+		type FundedExpiry: Get<BlockNumberFor<Self>>;
+
 		/// The curator deposit is calculated as a percentage of the curator fee.
 		///
 		/// This deposit has optional upper and lower bounds with `CuratorDepositMax` and
@@ -230,6 +696,20 @@ pub mod pallet {
 		#[pallet::constant]
 		type DataDepositPerByte: Get<BalanceOf<Self, I>>;
 
+		/// The part of the bond which is slashed when a proposer withdraws their bounty proposal.
+		#[pallet::constant]
+		type BountyWithdrawalFee: Get<Permill>;
+
+		/// The part of the slashed deposit of an inactive curator which is paid to the account
+		/// reporting them with `unassign_curator`. The rest is slashed as usual.
+		#[pallet::constant]
+		type CuratorSlashReporterShare: Get<Permill>;
+
+		/// The period in blocks during which a curator may appeal the slash of their deposit by
+		/// `unassign_curator`.
+		#[pallet::constant]
+		type CuratorSlashAppealPeriod: Get<BlockNumberFor<Self>>;
+
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self, I>>
 			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -246,8 +726,63 @@ pub mod pallet {
 		/// The child bounty manager.
 		type ChildBountyManager: ChildBountyManager<BalanceOf<Self, I>>;
 
-		/// Handler for the unbalanced decrease when slashing for a rejected bounty.
+		/// Hook which can deny or delay the payout of an awarded bounty when it is claimed, e.g.
+		/// while a referendum disputing the award is pending.
+		type PayoutGuard: PayoutGuard<Self::Beneficiary, BalanceOf<Self, I>, BlockNumberFor<Self>>;
+
+		/// Handler for the unbalanced decrease when slashing deposits which are still reserved,
+		/// i.e. were taken before the deposits of bounties were held, and child bounty deposits.
 		type OnSlash: OnUnbalanced<pallet_treasury::NegativeImbalanceOf<Self, I>>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason<I>>;
+
+		/// The native currency, in which the bonds of proposers and the deposits of curators are
+		/// held.
+		///
+		/// Must be the same currency as the treasury's `Currency`, from which deposits reserved
+		/// before they were held are still released.
+		type NativeBalance: MutateHold<
+				Self::AccountId,
+				Reason = Self::RuntimeHoldReason,
+				Balance = BalanceOf<Self, I>,
+			> + BalancedHold<Self::AccountId>;
+
+		/// Handler for the unbalanced decrease when slashing held deposits, e.g. for a rejected
+		/// bounty.
+		type OnDepositSlash: OnUnbalanced<fungible::Credit<Self::AccountId, Self::NativeBalance>>;
+
+		/// Provider for the block number which the deadlines of bounties refer to.
+		///
+		/// The deadlines of existing bounties must be migrated when the provider is changed, as
+		/// done by [`migrations::v5::MigrateDeadlines`] for the bounties stored in version 5.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// Converts the beneficiary of a bounty into a local account, to which the payout is
+		/// transferred from the bounty account.
+		///
+		/// The payouts to any other beneficiaries, e.g. accounts on other chains, are made by the
+		/// treasury's `Paymaster` in [`Config::NativeAssetKind`].
+		type BeneficiaryAccount: TryConvert<Self::Beneficiary, Self::AccountId>;
+
+		/// The kind of asset of the native currency, in which the treasury's `Paymaster` pays out
+		/// bounties to beneficiaries which are not local accounts.
+		///
+		/// The payout is made in the same amount as it is held in the bounty account, so the
+		/// asset must match the native currency. Bounties may only be awarded to local accounts
+		/// if `None`.
+		type NativeAssetKind: Get<Option<Self::AssetKind>>;
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason<I: 'static = ()> {
+		/// The bond of the proposer of a bounty.
+		#[codec(index = 0)]
+		ProposerBond,
+		/// The deposit of the curator of a bounty.
+		#[codec(index = 1)]
+		CuratorDeposit,
 	}
 
 	#[pallet::error]
@@ -275,6 +810,30 @@ pub mod pallet {
 		HasActiveChildBounty,
 		/// Too many approvals are already queued.
 		TooManyQueued,
+		/// The payout of the bounty could not be initiated.
+		PayoutError,
+		/// The payout of the bounty is still in progress.
+		PayoutInProgress,
+		/// The curator committee has too many members or its threshold can not be met.
+		InvalidCommittee,
+		/// The beneficiary is not a local account and the bounty can not be paid out to it.
+		InvalidBeneficiary,
+		/// Require bounty proposer.
+		RequireProposer,
+		/// The payout of the bounty is denied by the `PayoutGuard`.
+		PayoutDenied,
+		/// No slashed curator deposit of the account is recorded for the bounty.
+		NoSlashedDeposit,
+		/// The slash of the curator deposit is already appealed.
+		AlreadyAppealed,
+		/// The slash of the curator deposit is not appealed.
+		NotAppealed,
+		/// The period to appeal the slash of the curator deposit is over.
+		AppealPeriodOver,
+		/// The period to appeal the slash of the curator deposit is not over yet.
+		AppealPeriodNotOver,
+		/// The update period of a bounty must not be zero.
+		ZeroUpdatePeriod,
 	}
 
 	#[pallet::event]
@@ -284,24 +843,121 @@ pub mod pallet {
 		BountyProposed { index: BountyIndex },
 		/// A bounty proposal was rejected; funds were slashed.
 		BountyRejected { index: BountyIndex, bond: BalanceOf<T, I> },
-		/// A bounty proposal is funded and became active.
-		BountyBecameActive { index: BountyIndex },
-		/// A bounty is awarded to a beneficiary.
-		BountyAwarded { index: BountyIndex, beneficiary: T::AccountId },
+		/// A bounty proposal was withdrawn by its proposer; the fee was slashed from the bond and
+		/// the rest released.
+		BountyWithdrawn { index: BountyIndex, fee: BalanceOf<T, I> },
+		/// A bounty proposal is funded with `value` in its bounty `account` and became active.
+		BountyBecameActive { index: BountyIndex, account: T::AccountId, value: BalanceOf<T, I> },
+		/// A bounty is awarded to a beneficiary, who can claim it from the block `unlock_at` on.
+		BountyAwarded {
+			index: BountyIndex,
+			beneficiary: T::Beneficiary,
+			unlock_at: BlockNumberFor<T>,
+		},
+		/// The `PayoutGuard` delayed the payout of a bounty, which can be claimed from the block
+		/// `unlock_at` on.
+		BountyPayoutDelayed { index: BountyIndex, unlock_at: BlockNumberFor<T> },
 		/// A bounty is claimed by beneficiary.
-		BountyClaimed { index: BountyIndex, payout: BalanceOf<T, I>, beneficiary: T::AccountId },
+		BountyClaimed { index: BountyIndex, payout: BalanceOf<T, I>, beneficiary: T::Beneficiary },
+		/// The payout of a bounty to a beneficiary which is not a local account was handed to the
+		/// treasury's `Paymaster`.
+		BountyPayoutAttempted { index: BountyIndex, payment_id: PaymentIdOf<T, I> },
+		/// The payout of a bounty to a beneficiary which is not a local account succeeded.
+		BountyPayoutSucceeded { index: BountyIndex },
+		/// The payout of a bounty to a beneficiary which is not a local account failed and has
+		/// to be retried with `check_bounty_payout`.
+		BountyPayoutFailed { index: BountyIndex },
 		/// A bounty is cancelled.
 		BountyCanceled { index: BountyIndex },
 		/// A bounty expiry is extended.
 		BountyExtended { index: BountyIndex },
+		/// A funded bounty expired without an active curator and its funds were returned to the
+		/// treasury.
+		BountyExpired { index: BountyIndex, value: BalanceOf<T, I> },
+		/// A change of the curator fee of a bounty was approved by its curator or the
+		/// `SpendOrigin`, and awaits the approval of the other party.
+		CuratorFeeUpdateApproved { bounty_id: BountyIndex, fee: BalanceOf<T, I>, by_curator: bool },
+		/// The curator fee of a bounty was changed.
+		CuratorFeeUpdated {
+			bounty_id: BountyIndex,
+			old_fee: BalanceOf<T, I>,
+			new_fee: BalanceOf<T, I>,
+		},
+		/// A bounty was made recurring or one-off.
+		BountyRecurringSet { index: BountyIndex, recurring: bool },
+		/// A claimed recurring bounty was approved again, to be funded with the same value at the
+		/// next spend period.
+		BountyRenewed { index: BountyIndex },
+		/// The curator deposit of a bounty was recalculated and is held.
+		CuratorDepositPoked {
+			bounty_id: BountyIndex,
+			old_deposit: BalanceOf<T, I>,
+			new_deposit: BalanceOf<T, I>,
+		},
+		/// A curator committee was assigned to a bounty.
+		CuratorCommitteeSet { bounty_id: BountyIndex, members: u32, threshold: u32 },
+		/// A member of the curator committee of a bounty approved an action, which takes effect
+		/// once the threshold of the committee is reached.
+		CommitteeActionApproved {
+			bounty_id: BountyIndex,
+			member: T::AccountId,
+			action: CommitteeAction<T::Beneficiary>,
+			approvals: u32,
+		},
 		/// A bounty is approved.
 		BountyApproved { index: BountyIndex },
 		/// A bounty curator is proposed.
 		CuratorProposed { bounty_id: BountyIndex, curator: T::AccountId },
 		/// A bounty curator is unassigned.
 		CuratorUnassigned { bounty_id: BountyIndex },
+		/// The deposit of a bounty curator was slashed, which the curator may appeal.
+		CuratorDepositSlashed {
+			bounty_id: BountyIndex,
+			curator: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// A curator appealed the slash of their deposit.
+		CuratorSlashAppealed {
+			bounty_id: BountyIndex,
+			curator: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// The appeal of a slashed curator was upheld and the deposit reimbursed from the
+		/// treasury.
+		CuratorSlashReimbursed {
+			bounty_id: BountyIndex,
+			curator: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// The appeal of a slashed curator was rejected.
+		CuratorSlashAppealRejected { bounty_id: BountyIndex, curator: T::AccountId },
+		/// The slash of a curator deposit was not appealed in time and its record removed.
+		CuratorSlashExpired { bounty_id: BountyIndex, curator: T::AccountId },
 		/// A bounty curator is accepted.
 		CuratorAccepted { bounty_id: BountyIndex, curator: T::AccountId },
+		/// New asset bounty proposal.
+		AssetBountyProposed {
+			index: BountyIndex,
+			asset_kind: T::AssetKind,
+			value: AssetBalanceOf<T, I>,
+		},
+		/// An asset bounty is awarded to a beneficiary, and can be paid out from the block
+		/// `unlock_at` on.
+		AssetBountyAwarded {
+			index: BountyIndex,
+			beneficiary: T::Beneficiary,
+			unlock_at: BlockNumberFor<T>,
+		},
+		/// The payout of an asset bounty was attempted.
+		AssetBountyPayoutAttempted { index: BountyIndex },
+		/// The payout of an asset bounty failed and has to be attempted again.
+		AssetBountyPayoutFailed { index: BountyIndex },
+		/// An asset bounty is paid out to its beneficiary.
+		AssetBountyClaimed {
+			index: BountyIndex,
+			payout: AssetBalanceOf<T, I>,
+			beneficiary: T::Beneficiary,
+		},
 	}
 
 	/// Number of bounty proposals that have been made.
@@ -314,7 +970,7 @@ pub mod pallet {
 		_,
 		Twox64Concat,
 		BountyIndex,
-		Bounty<T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>>,
+		Bounty<T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>, T::Beneficiary>,
 	>;
 
 	/// The description of each bounty.
@@ -327,14 +983,78 @@ pub mod pallet {
 	pub type BountyApprovals<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, BoundedVec<BountyIndex, T::MaxApprovals>, ValueQuery>;
 
+	/// Bounties denominated in an asset of the treasury.
+	///
+	/// They share their indices and descriptions with the native [`Bounties`].
+	#[pallet::storage]
+	pub type AssetBounties<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, BountyIndex, AssetBountyOf<T, I>>;
+
+	/// The actions approved by the members of the curator committee of a bounty, which did not
+	/// reach the threshold of the committee yet.
+	#[pallet::storage]
+	pub type CommitteeApprovals<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BountyIndex,
+		Twox64Concat,
+		T::AccountId,
+		CommitteeAction<T::Beneficiary>,
+	>;
+
+	/// The block numbers from which on the funded bounties without an active curator expire, see
+	/// [`Config::FundedExpiry`].
+	#[pallet::storage]
+	pub type FundedExpiries<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, BountyIndex, BlockNumberFor<T>>;
+
+	/// The changes of the curator fees of active bounties which await the approval of either the
+	/// curator or the `SpendOrigin`.
+	#[pallet::storage]
+	pub type CuratorFeeUpdates<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, BountyIndex, CuratorFeeUpdate<BalanceOf<T, I>>>;
+
+	/// The payouts of claimed bounties to beneficiaries which are not local accounts, which were
+	/// not confirmed yet.
+	#[pallet::storage]
+	pub type RemotePayouts<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, BountyIndex, RemotePayoutOf<T, I>>;
+
+	/// The bounties which are approved again after being claimed, see `set_bounty_recurring`.
+	#[pallet::storage]
+	pub type RecurringBounties<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, BountyIndex, ()>;
+
+	/// The funding ledgers of the funded bounties and the approved asset bounties.
+	#[pallet::storage]
+	pub type BountyLedgers<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, BountyIndex, BountyLedger<BalanceOf<T, I>>>;
+
+	/// The sum of the funds still locked in all [`BountyLedgers`].
+	#[pallet::storage]
+	pub type TotalEncumbered<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BalanceOf<T, I>, ValueQuery>;
+
+	/// The curator deposits slashed by `unassign_curator`, by bounty and curator, which may still
+	/// be appealed or whose appeal was not decided yet.
+	#[pallet::storage]
+	pub type SlashedCuratorDeposits<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BountyIndex,
+		Twox64Concat,
+		T::AccountId,
+		SlashedCuratorDeposit<BalanceOf<T, I>, BlockNumberFor<T>>,
+	>;
+
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Propose a new bounty.
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
-		/// Payment: `TipReportDepositBase` will be reserved from the origin account, as well as
-		/// `DataDepositPerByte` for each byte in `reason`. It will be unreserved upon approval,
+		/// Payment: `TipReportDepositBase` will be held from the origin account, as well as
+		/// `DataDepositPerByte` for each byte in `reason`. It will be released upon approval,
 		/// or slashed when rejected.
 		///
 		/// - `curator`: The curator account whom will manage this bounty.
@@ -367,24 +1087,7 @@ pub mod pallet {
 			#[pallet::compact] bounty_id: BountyIndex,
 		) -> DispatchResult {
 			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
-			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
-				ensure!(
-					bounty.value <= max_amount,
-					pallet_treasury::Error::<T, I>::InsufficientPermission
-				);
-				ensure!(bounty.status == BountyStatus::Proposed, Error::<T, I>::UnexpectedStatus);
-
-				bounty.status = BountyStatus::Approved;
-
-				BountyApprovals::<T, I>::try_append(bounty_id)
-					.map_err(|()| Error::<T, I>::TooManyQueued)?;
-
-				Ok(())
-			})?;
-
-			Self::deposit_event(Event::<T, I>::BountyApproved { index: bounty_id });
-			Ok(())
+			Self::do_approve_bounty(max_amount, bounty_id, None)
 		}
 
 		/// Propose a curator to a funded bounty.
@@ -440,12 +1143,14 @@ pub mod pallet {
 		///
 		/// Finally, the origin can be anyone if and only if the curator is "inactive". This allows
 		/// anyone in the community to call out that a curator is not doing their due diligence, and
-		/// we should pick a new curator. In this case the curator should also be slashed.
+		/// we should pick a new curator. In this case the curator should also be slashed, and the
+		/// caller is paid the [`Config::CuratorSlashReporterShare`] of the slashed deposit.
 		///
 		/// ## Complexity
 		/// - O(1).
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config<I>>::WeightInfo::unassign_curator())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::unassign_curator()
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
 		pub fn unassign_curator(
 			origin: OriginFor<T>,
 			#[pallet::compact] bounty_id: BountyIndex,
@@ -457,13 +1162,6 @@ pub mod pallet {
 			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
 				let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
 
-				let slash_curator = |curator: &T::AccountId,
-				                     curator_deposit: &mut BalanceOf<T, I>| {
-					let imbalance = T::Currency::slash_reserved(curator, *curator_deposit).0;
-					T::OnSlash::on_unbalanced(imbalance);
-					*curator_deposit = Zero::zero();
-				};
-
 				match bounty.status {
 					BountyStatus::Proposed | BountyStatus::Approved | BountyStatus::Funded => {
 						// No curator to unassign at this point.
@@ -474,21 +1172,32 @@ pub mod pallet {
 						// Either `RejectOrigin` or the proposed curator can unassign the curator.
 						ensure!(maybe_sender.map_or(true, |sender| sender == *curator), BadOrigin);
 					},
-					BountyStatus::Active { ref curator, ref update_due } => {
+					BountyStatus::Active { ref curator, ref update_due, .. } => {
 						// The bounty is active.
 						match maybe_sender {
 							// If the `RejectOrigin` is calling this function, slash the curator.
 							None => {
-								slash_curator(curator, &mut bounty.curator_deposit);
+								Self::slash_curator(
+									bounty_id,
+									curator,
+									&mut bounty.curator_deposit,
+									None,
+								);
 								// Continue to change bounty status below...
 							},
 							Some(sender) => {
 								// If the sender is not the curator, and the curator is inactive,
 								// slash the curator.
 								if sender != *curator {
-									let block_number = frame_system::Pallet::<T>::block_number();
+									let block_number =
+										T::BlockNumberProvider::current_block_number();
 									if *update_due < block_number {
-										slash_curator(curator, &mut bounty.curator_deposit);
+										Self::slash_curator(
+											bounty_id,
+											curator,
+											&mut bounty.curator_deposit,
+											Some(&sender),
+										);
 									// Continue to change bounty status below...
 									} else {
 										// Curator has more time to give an update.
@@ -497,8 +1206,11 @@ pub mod pallet {
 								} else {
 									// Else this is the curator, willingly giving up their role.
 									// Give back their deposit.
-									let err_amount =
-										T::Currency::unreserve(curator, bounty.curator_deposit);
+									let err_amount = Self::release_deposit(
+										HoldReason::CuratorDeposit,
+										curator,
+										bounty.curator_deposit,
+									);
 									debug_assert!(err_amount.is_zero());
 									bounty.curator_deposit = Zero::zero();
 									// Continue to change bounty status below...
@@ -511,21 +1223,28 @@ pub mod pallet {
 						// By doing so, they are claiming the curator is acting maliciously, so
 						// we slash the curator.
 						ensure!(maybe_sender.is_none(), BadOrigin);
-						slash_curator(curator, &mut bounty.curator_deposit);
+						Self::slash_curator(bounty_id, curator, &mut bounty.curator_deposit, None);
 						// Continue to change bounty status below...
 					},
 				};
 
+				// A bounty which was worked on expires anew, while the expiry of a bounty whose
+				// curator was only proposed keeps running.
+				if !matches!(bounty.status, BountyStatus::CuratorProposed { .. }) {
+					Self::set_funded_expiry(bounty_id);
+				}
 				bounty.status = BountyStatus::Funded;
 				Ok(())
 			})?;
+			let _ = CommitteeApprovals::<T, I>::clear_prefix(bounty_id, u32::MAX, None);
+			CuratorFeeUpdates::<T, I>::remove(bounty_id);
 
 			Self::deposit_event(Event::<T, I>::CuratorUnassigned { bounty_id });
 			Ok(())
 		}
 
 		/// Accept the curator role for a bounty.
-		/// A deposit will be reserved from curator and refund upon successful payout.
+		/// A deposit will be held from curator and refund upon successful payout.
 		///
 		/// May only be called from the curator.
 		///
@@ -547,13 +1266,19 @@ pub mod pallet {
 						ensure!(signer == *curator, Error::<T, I>::RequireCurator);
 
 						let deposit = Self::calculate_curator_deposit(&bounty.fee);
-						T::Currency::reserve(curator, deposit)?;
+						Self::hold_deposit(HoldReason::CuratorDeposit, curator, deposit)?;
 						bounty.curator_deposit = deposit;
 
-						let update_due = frame_system::Pallet::<T>::block_number() +
-							T::BountyUpdatePeriod::get();
-						bounty.status =
-							BountyStatus::Active { curator: curator.clone(), update_due };
+						let update_due = T::BlockNumberProvider::current_block_number()
+							.saturating_add(
+								bounty.update_period.unwrap_or_else(T::BountyUpdatePeriod::get),
+							);
+						bounty.status = BountyStatus::Active {
+							curator: curator.clone(),
+							update_due,
+							committee: None,
+						};
+						FundedExpiries::<T, I>::remove(bounty_id);
 
 						Self::deposit_event(Event::<T, I>::CuratorAccepted {
 							bounty_id,
@@ -570,49 +1295,71 @@ pub mod pallet {
 		/// Award bounty to a beneficiary account. The beneficiary will be able to claim the funds
 		/// after a delay.
 		///
-		/// The dispatch origin for this call must be the curator of this bounty.
+		/// The dispatch origin for this call must be the curator of this bounty. If the bounty has
+		/// a curator committee, it may be any member and the bounty is awarded once the threshold
+		/// of the committee approved the same beneficiary.
 		///
 		/// - `bounty_id`: Bounty ID to award.
-		/// - `beneficiary`: The beneficiary account whom will receive the payout.
+		/// - `beneficiary`: The beneficiary whom will receive the payout. Beneficiaries which are
+		///   not local accounts, e.g. XCM locations on other chains, are paid out by the treasury's
+		///   `Paymaster`, see `check_bounty_payout`.
 		///
 		/// ## Complexity
-		/// - O(1).
+		/// - O(M) where M is the size of the curator committee.
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as Config<I>>::WeightInfo::award_bounty())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::award_bounty()
+			.saturating_add(Pallet::<T, I>::committee_approval_weight()))]
 		pub fn award_bounty(
 			origin: OriginFor<T>,
 			#[pallet::compact] bounty_id: BountyIndex,
-			beneficiary: AccountIdLookupOf<T>,
+			beneficiary: BeneficiaryLookupOf<T, I>,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
-			let beneficiary = T::Lookup::lookup(beneficiary)?;
-
-			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
-
-				// Ensure no active child bounties before processing the call.
-				ensure!(
-					T::ChildBountyManager::child_bounties_count(bounty_id) == 0,
-					Error::<T, I>::HasActiveChildBounty
-				);
+			let beneficiary = T::BeneficiaryLookup::lookup(beneficiary)?;
+			ensure!(
+				T::NativeAssetKind::get().is_some() ||
+					T::BeneficiaryAccount::try_convert(beneficiary.clone()).is_ok(),
+				Error::<T, I>::InvalidBeneficiary
+			);
+			let unlock_at = T::BlockNumberProvider::current_block_number()
+				.saturating_add(T::BountyDepositPayoutDelay::get());
+
+			let awarded = Bounties::<T, I>::try_mutate_exists(
+				bounty_id,
+				|maybe_bounty| -> Result<bool, DispatchError> {
+					let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
 
-				match &bounty.status {
-					BountyStatus::Active { curator, .. } => {
-						ensure!(signer == *curator, Error::<T, I>::RequireCurator);
-					},
-					_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
-				}
-				bounty.status = BountyStatus::PendingPayout {
-					curator: signer,
-					beneficiary: beneficiary.clone(),
-					unlock_at: frame_system::Pallet::<T>::block_number() +
-						T::BountyDepositPayoutDelay::get(),
-				};
+					// Ensure no active child bounties before processing the call.
+					ensure!(
+						T::ChildBountyManager::child_bounties_count(bounty_id) == 0,
+						Error::<T, I>::HasActiveChildBounty
+					);
 
-				Ok(())
-			})?;
+					let BountyStatus::Active { curator, committee, .. } = &bounty.status else {
+						return Err(Error::<T, I>::UnexpectedStatus.into())
+					};
+					let action = CommitteeAction::Award { beneficiary: beneficiary.clone() };
+					if !Self::approve_action(bounty_id, curator, committee, &signer, action)? {
+						return Ok(false)
+					}
+					bounty.status = BountyStatus::PendingPayout {
+						curator: curator.clone(),
+						beneficiary: beneficiary.clone(),
+						unlock_at,
+					};
 
-			Self::deposit_event(Event::<T, I>::BountyAwarded { index: bounty_id, beneficiary });
+					Ok(true)
+				},
+			)?;
+
+			if awarded {
+				CuratorFeeUpdates::<T, I>::remove(bounty_id);
+				Self::deposit_event(Event::<T, I>::BountyAwarded {
+					index: bounty_id,
+					beneficiary,
+					unlock_at,
+				});
+			}
 			Ok(())
 		}
 
@@ -620,12 +1367,23 @@ pub mod pallet {
 		///
 		/// The dispatch origin for this call must be the beneficiary of this bounty.
 		///
+		/// A recurring bounty is approved again with the same value instead of being removed, and
+		/// is funded at the next spend period. It can only be claimed once the payout of its
+		/// previous claim to a beneficiary which is not a local account succeeded.
+		///
+		/// The [`Config::PayoutGuard`] can deny the payout, or delay it by postponing the block
+		/// from which the bounty can be claimed.
+		///
 		/// - `bounty_id`: Bounty ID to claim.
 		///
 		/// ## Complexity
 		/// - O(1).
 		#[pallet::call_index(6)]
-		#[pallet::weight(<T as Config<I>>::WeightInfo::claim_bounty())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::claim_bounty()
+			.max(<T as Config<I>>::WeightInfo::claim_bounty_remote())
+			// Reading `RemotePayouts` and renewing a recurring bounty.
+			.saturating_add(T::DbWeight::get().reads_writes(3, 2))
+			.saturating_add(T::PayoutGuard::weight()))]
 		pub fn claim_bounty(
 			origin: OriginFor<T>,
 			#[pallet::compact] bounty_id: BountyIndex,
@@ -638,14 +1396,43 @@ pub mod pallet {
 					bounty.status
 				{
 					ensure!(
-						frame_system::Pallet::<T>::block_number() >= unlock_at,
+						T::BlockNumberProvider::current_block_number() >= unlock_at,
 						Error::<T, I>::Premature
 					);
+					ensure!(
+						!RemotePayouts::<T, I>::contains_key(bounty_id),
+						Error::<T, I>::PayoutInProgress
+					);
 					let bounty_account = Self::bounty_account_id(bounty_id);
 					let balance = T::Currency::free_balance(&bounty_account);
 					let fee = bounty.fee.min(balance); // just to be safe
 					let payout = balance.saturating_sub(fee);
-					let err_amount = T::Currency::unreserve(&curator, bounty.curator_deposit);
+					match T::PayoutGuard::check_payout(bounty_id, &beneficiary, payout) {
+						PayoutVerdict::Allow => {},
+						PayoutVerdict::Deny => return Err(Error::<T, I>::PayoutDenied.into()),
+						PayoutVerdict::Delay(unlock_at) => {
+							if unlock_at > T::BlockNumberProvider::current_block_number() {
+								*maybe_bounty = Some(Bounty {
+									status: BountyStatus::PendingPayout {
+										curator,
+										beneficiary,
+										unlock_at,
+									},
+									..bounty
+								});
+								Self::deposit_event(Event::<T, I>::BountyPayoutDelayed {
+									index: bounty_id,
+									unlock_at,
+								});
+								return Ok(())
+							}
+						},
+					}
+					let err_amount = Self::release_deposit(
+						HoldReason::CuratorDeposit,
+						&curator,
+						bounty.curator_deposit,
+					);
 					debug_assert!(err_amount.is_zero());
 
 					// Get total child bounties curator fees, and subtract it from the parent
@@ -657,19 +1444,34 @@ pub mod pallet {
 					let res =
 						T::Currency::transfer(&bounty_account, &curator, final_fee, AllowDeath); // should not fail
 					debug_assert!(res.is_ok());
-					let res =
-						T::Currency::transfer(&bounty_account, &beneficiary, payout, AllowDeath); // should not fail
-					debug_assert!(res.is_ok());
-
-					*maybe_bounty = None;
-
-					BountyDescriptions::<T, I>::remove(bounty_id);
+					Self::pay_out(bounty_id, &bounty_account, &beneficiary, payout)?;
+					Self::close_ledger(bounty_id);
+
+					// A recurring bounty is removed as well if the approval queue is full.
+					let renewed = RecurringBounties::<T, I>::contains_key(bounty_id) &&
+						BountyApprovals::<T, I>::try_append(bounty_id).is_ok();
+					if renewed {
+						*maybe_bounty = Some(Bounty {
+							fee: Zero::zero(),
+							curator_deposit: Zero::zero(),
+							bond: Zero::zero(),
+							status: BountyStatus::Approved,
+							..bounty
+						});
+					} else {
+						*maybe_bounty = None;
+						BountyDescriptions::<T, I>::remove(bounty_id);
+						RecurringBounties::<T, I>::remove(bounty_id);
+					}
 
 					Self::deposit_event(Event::<T, I>::BountyClaimed {
 						index: bounty_id,
 						payout,
 						beneficiary,
 					});
+					if renewed {
+						Self::deposit_event(Event::<T, I>::BountyRenewed { index: bounty_id });
+					}
 					Ok(())
 				} else {
 					Err(Error::<T, I>::UnexpectedStatus.into())
@@ -679,7 +1481,7 @@ pub mod pallet {
 		}
 
 		/// Cancel a proposed or active bounty. All the funds will be sent to treasury and
-		/// the curator deposit will be unreserved if possible.
+		/// the curator deposit will be released if possible.
 		///
 		/// Only `T::RejectOrigin` is able to cancel a bounty.
 		///
@@ -711,9 +1513,9 @@ pub mod pallet {
 						BountyStatus::Proposed => {
 							// The reject origin would like to cancel a proposed bounty.
 							BountyDescriptions::<T, I>::remove(bounty_id);
+							RecurringBounties::<T, I>::remove(bounty_id);
 							let value = bounty.bond;
-							let imbalance = T::Currency::slash_reserved(&bounty.proposer, value).0;
-							T::OnSlash::on_unbalanced(imbalance);
+							Self::slash_deposit(HoldReason::ProposerBond, &bounty.proposer, value);
 							*maybe_bounty = None;
 
 							Self::deposit_event(Event::<T, I>::BountyRejected {
@@ -735,9 +1537,15 @@ pub mod pallet {
 						},
 						BountyStatus::Active { curator, .. } => {
 							// Cancelled by council, refund deposit of the working curator.
-							let err_amount =
-								T::Currency::unreserve(curator, bounty.curator_deposit);
+							let err_amount = Self::release_deposit(
+								HoldReason::CuratorDeposit,
+								curator,
+								bounty.curator_deposit,
+							);
 							debug_assert!(err_amount.is_zero());
+							let _ =
+								CommitteeApprovals::<T, I>::clear_prefix(bounty_id, u32::MAX, None);
+							CuratorFeeUpdates::<T, I>::remove(bounty_id);
 							// Then execute removal of the bounty below.
 						},
 						BountyStatus::PendingPayout { .. } => {
@@ -752,6 +1560,8 @@ pub mod pallet {
 					let bounty_account = Self::bounty_account_id(bounty_id);
 
 					BountyDescriptions::<T, I>::remove(bounty_id);
+					FundedExpiries::<T, I>::remove(bounty_id);
+					RecurringBounties::<T, I>::remove(bounty_id);
 
 					let balance = T::Currency::free_balance(&bounty_account);
 					let res = T::Currency::transfer(
@@ -761,6 +1571,7 @@ pub mod pallet {
 						AllowDeath,
 					); // should not fail
 					debug_assert!(res.is_ok());
+					Self::close_ledger(bounty_id);
 					*maybe_bounty = None;
 
 					Self::deposit_event(Event::<T, I>::BountyCanceled { index: bounty_id });
@@ -771,15 +1582,18 @@ pub mod pallet {
 
 		/// Extend the expiry time of an active bounty.
 		///
-		/// The dispatch origin for this call must be the curator of this bounty.
+		/// The dispatch origin for this call must be the curator of this bounty. If the bounty has
+		/// a curator committee, it may be any member and the expiry is extended once the
+		/// threshold of the committee approved it.
 		///
 		/// - `bounty_id`: Bounty ID to extend.
 		/// - `remark`: additional information.
 		///
 		/// ## Complexity
-		/// - O(1).
+		/// - O(M) where M is the size of the curator committee.
 		#[pallet::call_index(8)]
-		#[pallet::weight(<T as Config<I>>::WeightInfo::extend_bounty_expiry())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::extend_bounty_expiry()
+			.saturating_add(Pallet::<T, I>::committee_approval_weight()))]
 		pub fn extend_bounty_expiry(
 			origin: OriginFor<T>,
 			#[pallet::compact] bounty_id: BountyIndex,
@@ -787,31 +1601,1301 @@ pub mod pallet {
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
 
-			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
-
-				match bounty.status {
-					BountyStatus::Active { ref curator, ref mut update_due } => {
-						ensure!(*curator == signer, Error::<T, I>::RequireCurator);
-						*update_due = (frame_system::Pallet::<T>::block_number() +
-							T::BountyUpdatePeriod::get())
-						.max(*update_due);
-					},
-					_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
-				}
+			let extended = Bounties::<T, I>::try_mutate_exists(
+				bounty_id,
+				|maybe_bounty| -> Result<bool, DispatchError> {
+					let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+
+					match bounty.status {
+						BountyStatus::Active { ref curator, ref mut update_due, ref committee } => {
+							let action = CommitteeAction::ExtendExpiry;
+							if !Self::approve_action(
+								bounty_id, curator, committee, &signer, action,
+							)? {
+								return Ok(false)
+							}
+							*update_due = T::BlockNumberProvider::current_block_number()
+								.saturating_add(
+									bounty.update_period.unwrap_or_else(T::BountyUpdatePeriod::get),
+								)
+								.max(*update_due);
+						},
+						_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+					}
 
-				Ok(())
-			})?;
+					Ok(true)
+				},
+			)?;
 
-			Self::deposit_event(Event::<T, I>::BountyExtended { index: bounty_id });
+			if extended {
+				Self::deposit_event(Event::<T, I>::BountyExtended { index: bounty_id });
+			}
 			Ok(())
 		}
-	}
-}
 
-impl<T: Config<I>, I: 'static> Pallet<T, I> {
-	pub fn calculate_curator_deposit(fee: &BalanceOf<T, I>) -> BalanceOf<T, I> {
-		let mut deposit = T::CuratorDepositMultiplier::get() * *fee;
+		/// Propose a new bounty denominated in an asset of the treasury.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Payment: `BountyDepositBase` will be held from the origin account, as well as
+		/// `DataDepositPerByte` for each byte in `description`. It will be released upon
+		/// approval, or slashed when rejected.
+		///
+		/// - `asset_kind`: The asset the bounty is paid in.
+		/// - `value`: The total payment amount of this bounty in the asset, curator fee included.
+		/// - `description`: The description of this bounty.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::propose_asset_bounty(description.len() as u32))]
+		pub fn propose_asset_bounty(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			#[pallet::compact] value: AssetBalanceOf<T, I>,
+			description: Vec<u8>,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			let native_value = Self::native_amount(*asset_kind.clone(), value)?;
+			ensure!(native_value >= T::BountyValueMinimum::get(), Error::<T, I>::InvalidValue);
+
+			let (index, bond) = Self::hold_proposal_bond(&proposer, description)?;
+			let bounty = AssetBounty {
+				proposer,
+				asset_kind: *asset_kind.clone(),
+				value,
+				fee: Zero::zero(),
+				curator_deposit: Zero::zero(),
+				bond,
+				status: AssetBountyStatus::Proposed,
+			};
+			AssetBounties::<T, I>::insert(index, &bounty);
+
+			Self::deposit_event(Event::<T, I>::AssetBountyProposed {
+				index,
+				asset_kind: *asset_kind,
+				value,
+			});
+			Ok(())
+		}
+
+		/// Approve an asset bounty proposal and propose a curator for it. The original deposit
+		/// will be returned.
+		///
+		/// May only be called from `T::SpendOrigin`, with the value of the bounty converted to
+		/// the native currency by the treasury's `BalanceConverter`.
+		///
+		/// - `bounty_id`: Bounty ID to approve.
+		/// - `curator`: The curator account whom will manage this bounty.
+		/// - `fee`: The curator fee in the asset of the bounty.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::approve_asset_bounty())]
+		pub fn approve_asset_bounty(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+			curator: AccountIdLookupOf<T>,
+			#[pallet::compact] fee: AssetBalanceOf<T, I>,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let curator = T::Lookup::lookup(curator)?;
+
+			AssetBounties::<T, I>::try_mutate_exists(
+				bounty_id,
+				|maybe_bounty| -> DispatchResult {
+					let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+					ensure!(
+						matches!(bounty.status, AssetBountyStatus::Proposed),
+						Error::<T, I>::UnexpectedStatus
+					);
+					let native_value =
+						Self::native_amount(bounty.asset_kind.clone(), bounty.value)?;
+					ensure!(
+						native_value <= max_amount,
+						pallet_treasury::Error::<T, I>::InsufficientPermission
+					);
+					ensure!(fee < bounty.value, Error::<T, I>::InvalidFee);
+
+					// return their deposit.
+					let err_amount = Self::release_deposit(
+						HoldReason::ProposerBond,
+						&bounty.proposer,
+						bounty.bond,
+					);
+					debug_assert!(err_amount.is_zero());
+
+					bounty.fee = fee;
+					bounty.status = AssetBountyStatus::CuratorProposed { curator: curator.clone() };
+					Self::open_ledger(bounty_id, native_value);
+					Ok(())
+				},
+			)?;
+
+			Self::deposit_event(Event::<T, I>::BountyApproved { index: bounty_id });
+			Self::deposit_event(Event::<T, I>::CuratorProposed { bounty_id, curator });
+			Ok(())
+		}
+
+		/// Accept the curator role for an asset bounty.
+		/// A deposit based on the curator fee converted to the native currency will be held
+		/// from the curator and refunded upon payout.
+		///
+		/// May only be called from the curator.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::accept_asset_curator())]
+		pub fn accept_asset_curator(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+
+			AssetBounties::<T, I>::try_mutate_exists(
+				bounty_id,
+				|maybe_bounty| -> DispatchResult {
+					let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+
+					match bounty.status {
+						AssetBountyStatus::CuratorProposed { ref curator } => {
+							ensure!(signer == *curator, Error::<T, I>::RequireCurator);
+
+							let fee = Self::native_amount(bounty.asset_kind.clone(), bounty.fee)?;
+							let deposit = Self::calculate_curator_deposit(&fee);
+							Self::hold_deposit(HoldReason::CuratorDeposit, curator, deposit)?;
+							bounty.curator_deposit = deposit;
+
+							let update_due = T::BlockNumberProvider::current_block_number() +
+								T::BountyUpdatePeriod::get();
+							bounty.status =
+								AssetBountyStatus::Active { curator: curator.clone(), update_due };
+							Ok(())
+						},
+						_ => Err(Error::<T, I>::UnexpectedStatus.into()),
+					}
+				},
+			)?;
+
+			Self::deposit_event(Event::<T, I>::CuratorAccepted { bounty_id, curator: signer });
+			Ok(())
+		}
+
+		/// Award an asset bounty to a beneficiary. The bounty can be paid out after a delay.
+		///
+		/// The dispatch origin for this call must be the curator of this bounty.
+		///
+		/// - `bounty_id`: Bounty ID to award.
+		/// - `beneficiary`: The beneficiary of the bounty value without the curator fee.
+		/// - `fee_beneficiary`: The beneficiary of the curator fee.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::award_asset_bounty())]
+		pub fn award_asset_bounty(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+			beneficiary: Box<BeneficiaryLookupOf<T, I>>,
+			fee_beneficiary: Box<BeneficiaryLookupOf<T, I>>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
+			let fee_beneficiary = T::BeneficiaryLookup::lookup(*fee_beneficiary)?;
+			let unlock_at = T::BlockNumberProvider::current_block_number()
+				.saturating_add(T::BountyDepositPayoutDelay::get());
+
+			AssetBounties::<T, I>::try_mutate_exists(
+				bounty_id,
+				|maybe_bounty| -> DispatchResult {
+					let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+
+					match &bounty.status {
+						AssetBountyStatus::Active { curator, .. } => {
+							ensure!(signer == *curator, Error::<T, I>::RequireCurator);
+						},
+						_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+					}
+					bounty.status = AssetBountyStatus::PendingPayout {
+						curator: signer,
+						beneficiary: beneficiary.clone(),
+						fee_beneficiary,
+						unlock_at,
+					};
+
+					Ok(())
+				},
+			)?;
+
+			Self::deposit_event(Event::<T, I>::AssetBountyAwarded {
+				index: bounty_id,
+				beneficiary,
+				unlock_at,
+			});
+			Ok(())
+		}
+
+		/// Pay out an awarded asset bounty after the payout delay, or retry its failed payments.
+		///
+		/// The bounty value without the curator fee and the curator fee are paid through the
+		/// treasury's `Paymaster` and the curator deposit is refunded. The payments must be
+		/// checked with `check_asset_bounty_payout`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `bounty_id`: Bounty ID to pay out.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::payout_asset_bounty())]
+		pub fn payout_asset_bounty(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?; // anyone can trigger the payout
+
+			AssetBounties::<T, I>::try_mutate_exists(
+				bounty_id,
+				|maybe_bounty| -> DispatchResult {
+					let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+
+					let (beneficiary, fee_beneficiary, mut payout, mut fee_payout) =
+						match &bounty.status {
+							AssetBountyStatus::PendingPayout {
+								curator,
+								beneficiary,
+								fee_beneficiary,
+								unlock_at,
+							} => {
+								ensure!(
+									T::BlockNumberProvider::current_block_number() >= *unlock_at,
+									Error::<T, I>::Premature
+								);
+								let err_amount = Self::release_deposit(
+									HoldReason::CuratorDeposit,
+									curator,
+									bounty.curator_deposit,
+								);
+								debug_assert!(err_amount.is_zero());
+								bounty.curator_deposit = Zero::zero();
+								(
+									beneficiary.clone(),
+									fee_beneficiary.clone(),
+									AssetPayoutState::Pending,
+									AssetPayoutState::Pending,
+								)
+							},
+							AssetBountyStatus::PayingOut {
+								beneficiary,
+								fee_beneficiary,
+								payout,
+								fee_payout,
+							} => (
+								beneficiary.clone(),
+								fee_beneficiary.clone(),
+								payout.clone(),
+								fee_payout.clone(),
+							),
+							_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+						};
+
+					let asset_kind = bounty.asset_kind.clone();
+					let value = bounty.value.saturating_sub(bounty.fee);
+					let attempted = Self::attempt_payout(
+						&mut payout,
+						&beneficiary,
+						asset_kind.clone(),
+						value,
+					)? | Self::attempt_payout(
+						&mut fee_payout,
+						&fee_beneficiary,
+						asset_kind,
+						bounty.fee,
+					)?;
+					ensure!(attempted, Error::<T, I>::PayoutInProgress);
+
+					bounty.status = AssetBountyStatus::PayingOut {
+						beneficiary,
+						fee_beneficiary,
+						payout,
+						fee_payout,
+					};
+					Ok(())
+				},
+			)?;
+
+			Self::deposit_event(Event::<T, I>::AssetBountyPayoutAttempted { index: bounty_id });
+			Ok(())
+		}
+
+		/// Check the payments of an asset bounty and remove the bounty once they succeeded.
+		///
+		/// Failed payments have to be retried with `payout_asset_bounty`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `bounty_id`: Bounty ID to check.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::check_asset_bounty_payout())]
+		pub fn check_asset_bounty_payout(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+
+			let mut bounty =
+				AssetBounties::<T, I>::get(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			let AssetBountyStatus::PayingOut {
+				beneficiary,
+				fee_beneficiary,
+				mut payout,
+				mut fee_payout,
+			} = bounty.status
+			else {
+				return Err(Error::<T, I>::UnexpectedStatus.into())
+			};
+
+			let progressed = Self::check_payout(&mut payout) | Self::check_payout(&mut fee_payout);
+			if payout == AssetPayoutState::Succeeded && fee_payout == AssetPayoutState::Succeeded {
+				AssetBounties::<T, I>::remove(bounty_id);
+				BountyDescriptions::<T, I>::remove(bounty_id);
+				Self::close_ledger(bounty_id);
+
+				Self::deposit_event(Event::<T, I>::AssetBountyClaimed {
+					index: bounty_id,
+					payout: bounty.value.saturating_sub(bounty.fee),
+					beneficiary,
+				});
+				return Ok(Pays::No.into())
+			}
+			ensure!(progressed, Error::<T, I>::PayoutInProgress);
+
+			if payout == AssetPayoutState::Failed || fee_payout == AssetPayoutState::Failed {
+				Self::deposit_event(Event::<T, I>::AssetBountyPayoutFailed { index: bounty_id });
+			}
+			bounty.status =
+				AssetBountyStatus::PayingOut { beneficiary, fee_beneficiary, payout, fee_payout };
+			AssetBounties::<T, I>::insert(bounty_id, bounty);
+			Ok(Pays::Yes.into())
+		}
+
+		/// Cancel a proposed or active asset bounty. A proposed bounty's deposit is slashed and
+		/// the curator deposit of an active bounty is released.
+		///
+		/// Only `T::RejectOrigin` is able to cancel a bounty. Awarded bounties can not be
+		/// cancelled.
+		///
+		/// - `bounty_id`: Bounty ID to cancel.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::close_asset_bounty())]
+		pub fn close_asset_bounty(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+		) -> DispatchResult {
+			T::RejectOrigin::ensure_origin(origin)?;
+
+			let bounty =
+				AssetBounties::<T, I>::get(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			match &bounty.status {
+				AssetBountyStatus::Proposed => {
+					Self::slash_deposit(HoldReason::ProposerBond, &bounty.proposer, bounty.bond);
+					AssetBounties::<T, I>::remove(bounty_id);
+					BountyDescriptions::<T, I>::remove(bounty_id);
+
+					Self::deposit_event(Event::<T, I>::BountyRejected {
+						index: bounty_id,
+						bond: bounty.bond,
+					});
+					return Ok(())
+				},
+				AssetBountyStatus::CuratorProposed { .. } | AssetBountyStatus::Approved => {},
+				AssetBountyStatus::Active { curator, .. } => {
+					// Refund deposit of the working curator.
+					let err_amount = Self::release_deposit(
+						HoldReason::CuratorDeposit,
+						curator,
+						bounty.curator_deposit,
+					);
+					debug_assert!(err_amount.is_zero());
+				},
+				AssetBountyStatus::PendingPayout { .. } | AssetBountyStatus::PayingOut { .. } =>
+					return Err(Error::<T, I>::PendingPayout.into()),
+			}
+
+			AssetBounties::<T, I>::remove(bounty_id);
+			BountyDescriptions::<T, I>::remove(bounty_id);
+			Self::close_ledger(bounty_id);
+
+			Self::deposit_event(Event::<T, I>::BountyCanceled { index: bounty_id });
+			Ok(())
+		}
+
+		/// Assign a committee of curators to an active bounty, replacing its current committee.
+		///
+		/// May only be called from `T::SpendOrigin`.
+		///
+		/// Awarding the bounty, extending its expiry and operating on its child bounties then
+		/// require the approval of `threshold` members of the committee. The curator of the bounty
+		/// is always a member, and remains responsible for the curator deposit and receives the
+		/// curator fee. If the curator is the only member, the committee is removed and the
+		/// curator acts alone again.
+		///
+		/// - `bounty_id`: Bounty ID to assign the committee to.
+		/// - `members`: The members of the committee besides the curator.
+		/// - `threshold`: The number of members which must approve an action.
+		///
+		/// ## Complexity
+		/// - O(M) where M is the number of `members`.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_curator_committee(members.len() as u32))]
+		pub fn set_curator_committee(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+			members: Vec<AccountIdLookupOf<T>>,
+			threshold: u32,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			ensure!(
+				members.len() < MAX_CURATOR_COMMITTEE_SIZE as usize,
+				Error::<T, I>::InvalidCommittee
+			);
+			let members =
+				members.into_iter().map(T::Lookup::lookup).collect::<Result<Vec<_>, _>>()?;
+
+			let size = Bounties::<T, I>::try_mutate_exists(
+				bounty_id,
+				|maybe_bounty| -> Result<u32, DispatchError> {
+					let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+					ensure!(
+						bounty.value <= max_amount,
+						pallet_treasury::Error::<T, I>::InsufficientPermission
+					);
+					let BountyStatus::Active { ref curator, ref mut committee, .. } = bounty.status
+					else {
+						return Err(Error::<T, I>::UnexpectedStatus.into())
+					};
+
+					let mut all_members = members;
+					all_members.push(curator.clone());
+					all_members.sort();
+					all_members.dedup();
+					let size = all_members.len() as u32;
+					ensure!(threshold > 0 && threshold <= size, Error::<T, I>::InvalidCommittee);
+
+					*committee = if size > 1 {
+						let members =
+							all_members.try_into().map_err(|_| Error::<T, I>::InvalidCommittee)?;
+						Some(CuratorCommittee { members, threshold })
+					} else {
+						None
+					};
+					Ok(size)
+				},
+			)?;
+			let _ = CommitteeApprovals::<T, I>::clear_prefix(bounty_id, u32::MAX, None);
+
+			Self::deposit_event(Event::<T, I>::CuratorCommitteeSet {
+				bounty_id,
+				members: size,
+				threshold,
+			});
+			Ok(())
+		}
+
+		/// Sweep an expired bounty without an active curator back to the treasury.
+		///
+		/// The dispatch origin for this call must be _Signed_ and may be anyone.
+		///
+		/// A bounty expires [`Config::FundedExpiry`] blocks after it was funded or after its
+		/// active curator was unassigned, unless a curator accepted it in the meantime. Its funds
+		/// are returned to the treasury and the bounty is removed. The bond of the proposer was
+		/// already returned when the bounty was funded.
+		///
+		/// - `bounty_id`: Bounty ID to sweep.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::sweep_expired_bounty())]
+		pub fn sweep_expired_bounty(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			let bounty = Bounties::<T, I>::get(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			ensure!(
+				matches!(
+					bounty.status,
+					BountyStatus::Funded | BountyStatus::CuratorProposed { .. }
+				),
+				Error::<T, I>::UnexpectedStatus
+			);
+			let expires_at =
+				FundedExpiries::<T, I>::get(bounty_id).ok_or(Error::<T, I>::Premature)?;
+			ensure!(
+				T::BlockNumberProvider::current_block_number() >= expires_at,
+				Error::<T, I>::Premature
+			);
+			ensure!(
+				T::ChildBountyManager::child_bounties_count(bounty_id) == 0,
+				Error::<T, I>::HasActiveChildBounty
+			);
+
+			let bounty_account = Self::bounty_account_id(bounty_id);
+			let value = T::Currency::free_balance(&bounty_account);
+			let res =
+				T::Currency::transfer(&bounty_account, &Self::account_id(), value, AllowDeath); // should not fail
+			debug_assert!(res.is_ok());
+
+			Bounties::<T, I>::remove(bounty_id);
+			BountyDescriptions::<T, I>::remove(bounty_id);
+			FundedExpiries::<T, I>::remove(bounty_id);
+			RecurringBounties::<T, I>::remove(bounty_id);
+			Self::close_ledger(bounty_id);
+
+			Self::deposit_event(Event::<T, I>::BountyExpired { index: bounty_id, value });
+			Ok(())
+		}
+
+		/// Approve a change of the curator fee of an active bounty.
+		///
+		/// The dispatch origin for this call must be either the curator of the bounty or
+		/// `T::SpendOrigin`, in which case the bounty value must be within its spend limit. The
+		/// fee is changed once both approved the same `new_fee`, while an approval of a different
+		/// fee replaces the pending change.
+		///
+		/// The new fee must be less than the bounty value and cover the curator fees of its child
+		/// bounties. The curator deposit is recalculated for the new fee, reserving or releasing
+		/// the difference from the curator.
+		///
+		/// - `bounty_id`: Bounty ID to change the curator fee of.
+		/// - `new_fee`: The new curator fee.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::update_curator_fee())]
+		pub fn update_curator_fee(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+			#[pallet::compact] new_fee: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let bounty = Bounties::<T, I>::get(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			let BountyStatus::Active { curator, .. } = &bounty.status else {
+				return Err(Error::<T, I>::UnexpectedStatus.into())
+			};
+			let by_curator = match ensure_signed(origin.clone()) {
+				Ok(signer) if signer == *curator => true,
+				_ => {
+					let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+					ensure!(
+						bounty.value <= max_amount,
+						pallet_treasury::Error::<T, I>::InsufficientPermission
+					);
+					false
+				},
+			};
+			ensure!(new_fee < bounty.value, Error::<T, I>::InvalidFee);
+			ensure!(
+				new_fee >= T::ChildBountyManager::peek_children_curator_fees(bounty_id),
+				Error::<T, I>::InvalidFee
+			);
+
+			let mut update = CuratorFeeUpdates::<T, I>::get(bounty_id)
+				.filter(|update| update.fee == new_fee)
+				.unwrap_or(CuratorFeeUpdate {
+					fee: new_fee,
+					curator_approved: false,
+					spend_approved: false,
+				});
+			if by_curator {
+				update.curator_approved = true;
+			} else {
+				update.spend_approved = true;
+			}
+
+			if update.curator_approved && update.spend_approved {
+				CuratorFeeUpdates::<T, I>::remove(bounty_id);
+				Self::do_update_curator_fee(bounty_id, new_fee)?;
+				Self::deposit_event(Event::<T, I>::CuratorFeeUpdated {
+					bounty_id,
+					old_fee: bounty.fee,
+					new_fee,
+				});
+			} else {
+				CuratorFeeUpdates::<T, I>::insert(bounty_id, update);
+				Self::deposit_event(Event::<T, I>::CuratorFeeUpdateApproved {
+					bounty_id,
+					fee: new_fee,
+					by_curator,
+				});
+			}
+			Ok(())
+		}
+
+		/// Check the payout of a claimed bounty to a beneficiary which is not a local account,
+		/// or retry it if it failed.
+		///
+		/// Bounties awarded to such beneficiaries, e.g. XCM locations on other chains, are paid
+		/// out in the native asset through the treasury's `Paymaster` once claimed. The payout is
+		/// removed once the payment succeeded.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `bounty_id`: Bounty ID of the claimed bounty.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::check_bounty_payout())]
+		pub fn check_bounty_payout(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+
+			let mut payout =
+				RemotePayouts::<T, I>::get(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			if Self::attempt_payout(
+				&mut payout.state,
+				&payout.beneficiary,
+				payout.asset_kind.clone(),
+				payout.amount,
+			)? {
+				Self::note_remote_payout(bounty_id, payout);
+				return Ok(Pays::Yes.into())
+			}
+
+			ensure!(Self::check_payout(&mut payout.state), Error::<T, I>::PayoutInProgress);
+			if payout.state == AssetPayoutState::Succeeded {
+				RemotePayouts::<T, I>::remove(bounty_id);
+				Self::deposit_event(Event::<T, I>::BountyPayoutSucceeded { index: bounty_id });
+				return Ok(Pays::No.into())
+			}
+
+			RemotePayouts::<T, I>::insert(bounty_id, payout);
+			Self::deposit_event(Event::<T, I>::BountyPayoutFailed { index: bounty_id });
+			Ok(Pays::Yes.into())
+		}
+
+		/// Make a bounty recurring or one-off.
+		///
+		/// A recurring bounty is approved again with the same value after each claim instead of
+		/// being removed, so that it is funded at the next spend period and a curator can be
+		/// proposed again. If the approval queue is full when it is claimed, it is removed like a
+		/// one-off bounty.
+		///
+		/// May only be called from `T::SpendOrigin`, whose spend limit must cover the value of
+		/// the bounty.
+		///
+		/// - `bounty_id`: Bounty ID to update.
+		/// - `recurring`: Whether the bounty is recurring.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_bounty_recurring())]
+		pub fn set_bounty_recurring(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+			recurring: bool,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let bounty = Bounties::<T, I>::get(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			ensure!(
+				bounty.value <= max_amount,
+				pallet_treasury::Error::<T, I>::InsufficientPermission
+			);
+
+			if recurring {
+				RecurringBounties::<T, I>::insert(bounty_id, ());
+			} else {
+				RecurringBounties::<T, I>::remove(bounty_id);
+			}
+
+			Self::deposit_event(Event::<T, I>::BountyRecurringSet { index: bounty_id, recurring });
+			Ok(())
+		}
+
+		/// Recalculate the curator deposit of an active or awarded bounty for its curator fee and
+		/// hold it.
+		///
+		/// The dispatch origin for this call must be _Signed_ and may be anyone.
+		///
+		/// The difference to the current deposit is held from or released to the curator. The
+		/// part of the deposit which is still reserved, as it was taken before the deposits of
+		/// bounties were held, is moved on hold. The call is free if the deposit changed.
+		///
+		/// - `bounty_id`: Bounty ID of the bounty.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::poke_deposit())]
+		pub fn poke_deposit(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+
+			let (old_deposit, new_deposit, moved) = Bounties::<T, I>::try_mutate_exists(
+				bounty_id,
+				|maybe_bounty| -> Result<_, DispatchError> {
+					let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+					let curator = match &bounty.status {
+						BountyStatus::Active { curator, .. } |
+						BountyStatus::PendingPayout { curator, .. } => curator.clone(),
+						_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+					};
+
+					let old_deposit = bounty.curator_deposit;
+					let new_deposit = Self::calculate_curator_deposit(&bounty.fee);
+					let moved = Self::hold_reserved_deposit(
+						HoldReason::CuratorDeposit,
+						&curator,
+						old_deposit,
+					)?;
+					Self::rejig_deposit(
+						HoldReason::CuratorDeposit,
+						&curator,
+						old_deposit,
+						new_deposit,
+					)?;
+					bounty.curator_deposit = new_deposit;
+					Ok((old_deposit, new_deposit, moved))
+				},
+			)?;
+
+			Self::deposit_event(Event::<T, I>::CuratorDepositPoked {
+				bounty_id,
+				old_deposit,
+				new_deposit,
+			});
+			if old_deposit == new_deposit && moved.is_zero() {
+				return Ok(Pays::Yes.into())
+			}
+			Ok(Pays::No.into())
+		}
+
+		/// Withdraw a bounty or asset bounty proposal which is not approved yet.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the proposer of the bounty.
+		///
+		/// The [`Config::BountyWithdrawalFee`] of the bond is slashed and the rest is released.
+		///
+		/// - `bounty_id`: Bounty ID to withdraw.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::withdraw_bounty())]
+		pub fn withdraw_bounty(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+
+			let (proposer, bond) = if let Some(bounty) = Bounties::<T, I>::get(bounty_id) {
+				ensure!(bounty.status == BountyStatus::Proposed, Error::<T, I>::UnexpectedStatus);
+				(bounty.proposer, bounty.bond)
+			} else {
+				let bounty =
+					AssetBounties::<T, I>::get(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+				ensure!(
+					bounty.status == AssetBountyStatus::Proposed,
+					Error::<T, I>::UnexpectedStatus
+				);
+				(bounty.proposer, bounty.bond)
+			};
+			ensure!(signer == proposer, Error::<T, I>::RequireProposer);
+
+			let fee = T::BountyWithdrawalFee::get() * bond;
+			Self::slash_deposit(HoldReason::ProposerBond, &proposer, fee);
+			let err_amount = Self::release_deposit(
+				HoldReason::ProposerBond,
+				&proposer,
+				bond.saturating_sub(fee),
+			);
+			debug_assert!(err_amount.is_zero());
+
+			Bounties::<T, I>::remove(bounty_id);
+			AssetBounties::<T, I>::remove(bounty_id);
+			BountyDescriptions::<T, I>::remove(bounty_id);
+			RecurringBounties::<T, I>::remove(bounty_id);
+
+			Self::deposit_event(Event::<T, I>::BountyWithdrawn { index: bounty_id, fee });
+			Ok(())
+		}
+
+		/// Approve a bounty proposal like `approve_bounty`, with an update period overriding
+		/// [`Config::BountyUpdatePeriod`] for this bounty.
+		///
+		/// The curator of the bounty then has to extend its expiry every `update_period` blocks,
+		/// e.g. a short period for quick tasks or a practically infinite one for standing
+		/// bounties.
+		///
+		/// May only be called from `T::SpendOrigin`.
+		///
+		/// - `bounty_id`: Bounty ID to approve.
+		/// - `update_period`: The update period of the bounty in blocks, which must not be zero.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::approve_bounty())]
+		pub fn approve_bounty_with_update_period(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+			update_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			ensure!(!update_period.is_zero(), Error::<T, I>::ZeroUpdatePeriod);
+			Self::do_approve_bounty(max_amount, bounty_id, Some(update_period))
+		}
+
+		/// Appeal the slash of the curator deposit of the sender for a bounty, e.g. because they
+		/// were unassigned as inactive although they kept working on the bounty.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the slashed curator, within the
+		/// [`Config::CuratorSlashAppealPeriod`] after the slash.
+		///
+		/// The `SpendOrigin` decides the appeal with `resolve_curator_slash_appeal`.
+		///
+		/// - `bounty_id`: Bounty ID for which the deposit was slashed.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::appeal_curator_slash())]
+		pub fn appeal_curator_slash(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+		) -> DispatchResult {
+			let curator = ensure_signed(origin)?;
+			let now = T::BlockNumberProvider::current_block_number();
+
+			let amount = SlashedCuratorDeposits::<T, I>::try_mutate(
+				bounty_id,
+				&curator,
+				|maybe_slash| -> Result<BalanceOf<T, I>, DispatchError> {
+					let slash = maybe_slash.as_mut().ok_or(Error::<T, I>::NoSlashedDeposit)?;
+					ensure!(!slash.appealed, Error::<T, I>::AlreadyAppealed);
+					ensure!(now <= slash.appeal_until, Error::<T, I>::AppealPeriodOver);
+					slash.appealed = true;
+					Ok(slash.amount)
+				},
+			)?;
+
+			Self::deposit_event(Event::<T, I>::CuratorSlashAppealed { bounty_id, curator, amount });
+			Ok(())
+		}
+
+		/// Decide the appeal of a slashed curator.
+		///
+		/// If the appeal is `upheld`, the amount slashed to the treasury is reimbursed to the
+		/// curator. Either way, the slash can not be appealed again.
+		///
+		/// May only be called from `T::SpendOrigin`, whose spend limit must cover the slashed
+		/// deposit if the appeal is upheld.
+		///
+		/// - `bounty_id`: Bounty ID for which the deposit was slashed.
+		/// - `curator`: The slashed curator who appealed.
+		/// - `upheld`: Whether the slash was wrongful and the deposit is reimbursed.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::resolve_curator_slash_appeal())]
+		pub fn resolve_curator_slash_appeal(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+			curator: AccountIdLookupOf<T>,
+			upheld: bool,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let curator = T::Lookup::lookup(curator)?;
+
+			let slash = SlashedCuratorDeposits::<T, I>::get(bounty_id, &curator)
+				.ok_or(Error::<T, I>::NoSlashedDeposit)?;
+			ensure!(slash.appealed, Error::<T, I>::NotAppealed);
+
+			if upheld {
+				ensure!(
+					slash.amount <= max_amount,
+					pallet_treasury::Error::<T, I>::InsufficientPermission
+				);
+				T::Currency::transfer(&Self::account_id(), &curator, slash.amount, KeepAlive)?;
+				Self::deposit_event(Event::<T, I>::CuratorSlashReimbursed {
+					bounty_id,
+					curator: curator.clone(),
+					amount: slash.amount,
+				});
+			} else {
+				Self::deposit_event(Event::<T, I>::CuratorSlashAppealRejected {
+					bounty_id,
+					curator: curator.clone(),
+				});
+			}
+			SlashedCuratorDeposits::<T, I>::remove(bounty_id, &curator);
+			Ok(())
+		}
+
+		/// Remove the record of a slashed curator deposit which was not appealed within the
+		/// [`Config::CuratorSlashAppealPeriod`].
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `bounty_id`: Bounty ID for which the deposit was slashed.
+		/// - `curator`: The slashed curator.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::remove_expired_curator_slash())]
+		pub fn remove_expired_curator_slash(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+			curator: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let curator = T::Lookup::lookup(curator)?;
+
+			let slash = SlashedCuratorDeposits::<T, I>::get(bounty_id, &curator)
+				.ok_or(Error::<T, I>::NoSlashedDeposit)?;
+			ensure!(!slash.appealed, Error::<T, I>::AlreadyAppealed);
+			ensure!(
+				T::BlockNumberProvider::current_block_number() > slash.appeal_until,
+				Error::<T, I>::AppealPeriodNotOver
+			);
+
+			SlashedCuratorDeposits::<T, I>::remove(bounty_id, &curator);
+			Self::deposit_event(Event::<T, I>::CuratorSlashExpired { bounty_id, curator });
+			Ok(())
+		}
+
+		/// Propose a curator to an asset bounty whose curator was unassigned.
+		///
+		/// May only be called from `T::SpendOrigin`, with the value of the bounty converted to
+		/// the native currency by the treasury's `BalanceConverter`.
+		///
+		/// - `bounty_id`: Bounty ID to propose the curator for.
+		/// - `curator`: The curator account whom will manage this bounty.
+		/// - `fee`: The curator fee in the asset of the bounty.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::propose_asset_curator())]
+		pub fn propose_asset_curator(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+			curator: AccountIdLookupOf<T>,
+			#[pallet::compact] fee: AssetBalanceOf<T, I>,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let curator = T::Lookup::lookup(curator)?;
+
+			AssetBounties::<T, I>::try_mutate_exists(
+				bounty_id,
+				|maybe_bounty| -> DispatchResult {
+					let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+					ensure!(
+						matches!(bounty.status, AssetBountyStatus::Approved),
+						Error::<T, I>::UnexpectedStatus
+					);
+					let native_value =
+						Self::native_amount(bounty.asset_kind.clone(), bounty.value)?;
+					ensure!(
+						native_value <= max_amount,
+						pallet_treasury::Error::<T, I>::InsufficientPermission
+					);
+					ensure!(fee < bounty.value, Error::<T, I>::InvalidFee);
+
+					bounty.fee = fee;
+					bounty.status = AssetBountyStatus::CuratorProposed { curator: curator.clone() };
+					Ok(())
+				},
+			)?;
+
+			Self::deposit_event(Event::<T, I>::CuratorProposed { bounty_id, curator });
+			Ok(())
+		}
+
+		/// Unassign the curator from an asset bounty, which then waits for a new curator to be
+		/// proposed with `propose_asset_curator`.
+		///
+		/// The origin rules are those of `unassign_curator`: `T::RejectOrigin` slashes the
+		/// curator, the curator may give up and recover their deposit, and anyone may unassign an
+		/// inactive curator whose update is overdue, receiving the
+		/// [`Config::CuratorSlashReporterShare`] of the slashed deposit.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::unassign_asset_curator())]
+		pub fn unassign_asset_curator(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+		) -> DispatchResult {
+			let maybe_sender = ensure_signed(origin.clone())
+				.map(Some)
+				.or_else(|_| T::RejectOrigin::ensure_origin(origin).map(|_| None))?;
+
+			AssetBounties::<T, I>::try_mutate_exists(
+				bounty_id,
+				|maybe_bounty| -> DispatchResult {
+					let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+
+					match bounty.status {
+						AssetBountyStatus::CuratorProposed { ref curator } => {
+							ensure!(
+								maybe_sender.map_or(true, |sender| sender == *curator),
+								BadOrigin
+							);
+						},
+						AssetBountyStatus::Active { ref curator, update_due } => {
+							match maybe_sender {
+								None => Self::slash_curator(
+									bounty_id,
+									curator,
+									&mut bounty.curator_deposit,
+									None,
+								),
+								Some(sender) if sender != *curator => {
+									// Only an inactive curator can be unassigned by anyone.
+									ensure!(
+										update_due < T::BlockNumberProvider::current_block_number(),
+										Error::<T, I>::Premature
+									);
+									Self::slash_curator(
+										bounty_id,
+										curator,
+										&mut bounty.curator_deposit,
+										Some(&sender),
+									);
+								},
+								Some(_) => {
+									// The curator willingly gives up their role.
+									let err_amount = Self::release_deposit(
+										HoldReason::CuratorDeposit,
+										curator,
+										bounty.curator_deposit,
+									);
+									debug_assert!(err_amount.is_zero());
+									bounty.curator_deposit = Zero::zero();
+								},
+							}
+						},
+						AssetBountyStatus::PendingPayout { ref curator, .. } => {
+							// Only the council can claim that the curator of an awarded bounty
+							// acts maliciously.
+							ensure!(maybe_sender.is_none(), BadOrigin);
+							Self::slash_curator(
+								bounty_id,
+								curator,
+								&mut bounty.curator_deposit,
+								None,
+							);
+						},
+						_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+					}
+
+					bounty.status = AssetBountyStatus::Approved;
+					Ok(())
+				},
+			)?;
+
+			Self::deposit_event(Event::<T, I>::CuratorUnassigned { bounty_id });
+			Ok(())
+		}
+
+		/// Extend the expiry time of an active asset bounty.
+		///
+		/// The dispatch origin for this call must be the curator of this bounty.
+		///
+		/// - `bounty_id`: Bounty ID to extend.
+		/// - `remark`: additional information.
+		///
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::extend_asset_bounty_expiry())]
+		pub fn extend_asset_bounty_expiry(
+			origin: OriginFor<T>,
+			#[pallet::compact] bounty_id: BountyIndex,
+			_remark: Vec<u8>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+
+			AssetBounties::<T, I>::try_mutate_exists(
+				bounty_id,
+				|maybe_bounty| -> DispatchResult {
+					let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+
+					match bounty.status {
+						AssetBountyStatus::Active { ref curator, ref mut update_due } => {
+							ensure!(signer == *curator, Error::<T, I>::RequireCurator);
+							*update_due = T::BlockNumberProvider::current_block_number()
+								.saturating_add(T::BountyUpdatePeriod::get())
+								.max(*update_due);
+						},
+						_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+					}
+					Ok(())
+				},
+			)?;
+
+			Self::deposit_event(Event::<T, I>::BountyExtended { index: bounty_id });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Record the approval of `action` by `signer` for the active bounty `bounty_id` with the
+	/// given `curator` and `committee`.
+	///
+	/// Returns whether the action may be taken, in which case all pending approvals of the
+	/// committee are cleared. Without a committee, only the curator may take the action.
+	fn approve_action(
+		bounty_id: BountyIndex,
+		curator: &T::AccountId,
+		committee: &Option<CuratorCommittee<T::AccountId>>,
+		signer: &T::AccountId,
+		action: CommitteeAction<T::Beneficiary>,
+	) -> Result<bool, DispatchError> {
+		let Some(committee) = committee else {
+			ensure!(signer == curator, Error::<T, I>::RequireCurator);
+			return Ok(true)
+		};
+		ensure!(committee.members.contains(signer), Error::<T, I>::RequireCurator);
+
+		CommitteeApprovals::<T, I>::insert(bounty_id, signer, &action);
+		let approvals = CommitteeApprovals::<T, I>::iter_prefix_values(bounty_id)
+			.filter(|approved| *approved == action)
+			.count() as u32;
+		if approvals >= committee.threshold {
+			let _ = CommitteeApprovals::<T, I>::clear_prefix(bounty_id, u32::MAX, None);
+			return Ok(true)
+		}
+
+		Self::deposit_event(Event::<T, I>::CommitteeActionApproved {
+			bounty_id,
+			member: signer.clone(),
+			action,
+			approvals,
+		});
+		Ok(false)
+	}
+
+	/// Approve the proposed bounty `bounty_id` with an optional `update_period` overriding
+	/// [`Config::BountyUpdatePeriod`], given the spend limit `max_amount` of the origin.
+	fn do_approve_bounty(
+		max_amount: BalanceOf<T, I>,
+		bounty_id: BountyIndex,
+		update_period: Option<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+			let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+			ensure!(
+				bounty.value <= max_amount,
+				pallet_treasury::Error::<T, I>::InsufficientPermission
+			);
+			ensure!(bounty.status == BountyStatus::Proposed, Error::<T, I>::UnexpectedStatus);
+
+			bounty.status = BountyStatus::Approved;
+			bounty.update_period = update_period;
+
+			BountyApprovals::<T, I>::try_append(bounty_id)
+				.map_err(|()| Error::<T, I>::TooManyQueued)?;
+
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::<T, I>::BountyApproved { index: bounty_id });
+		Ok(())
+	}
+
+	/// The update period of the bounty `bounty_id`, which defaults to
+	/// [`Config::BountyUpdatePeriod`].
+	pub fn update_period(bounty_id: BountyIndex) -> BlockNumberFor<T> {
+		Bounties::<T, I>::get(bounty_id)
+			.and_then(|bounty| bounty.update_period)
+			.unwrap_or_else(T::BountyUpdatePeriod::get)
+	}
+
+	/// Let the funded bounty `bounty_id` expire [`Config::FundedExpiry`] blocks from now.
+	fn set_funded_expiry(bounty_id: BountyIndex) {
+		let expires_at =
+			T::BlockNumberProvider::current_block_number().saturating_add(T::FundedExpiry::get());
+		FundedExpiries::<T, I>::insert(bounty_id, expires_at);
+	}
+
+	/// Pay out `payout` from the `bounty_account` of the bounty `bounty_id` to `beneficiary`.
+	///
+	/// The payout to a local account is transferred directly. Otherwise it is moved to the
+	/// treasury account and paid by the treasury's `Paymaster`. Such payouts are tracked in
+	/// [`RemotePayouts`] until the payment succeeded, and failed payments are retried through
+	/// `check_bounty_payout`.
+	fn pay_out(
+		bounty_id: BountyIndex,
+		bounty_account: &T::AccountId,
+		beneficiary: &T::Beneficiary,
+		payout: BalanceOf<T, I>,
+	) -> DispatchResult {
+		if let Ok(account) = T::BeneficiaryAccount::try_convert(beneficiary.clone()) {
+			let res = T::Currency::transfer(bounty_account, &account, payout, AllowDeath); // should not fail
+			debug_assert!(res.is_ok());
+			return Ok(())
+		}
+
+		let asset_kind = T::NativeAssetKind::get().ok_or(Error::<T, I>::InvalidBeneficiary)?;
+		T::Currency::transfer(bounty_account, &Self::account_id(), payout, AllowDeath)?;
+		let mut payout = RemotePayout {
+			beneficiary: beneficiary.clone(),
+			asset_kind,
+			amount: payout.saturated_into::<u128>().saturated_into(),
+			state: AssetPayoutState::Pending,
+		};
+		Self::attempt_payout(
+			&mut payout.state,
+			&payout.beneficiary,
+			payout.asset_kind.clone(),
+			payout.amount,
+		)?;
+		Self::note_remote_payout(bounty_id, payout);
+		Ok(())
+	}
+
+	/// Keep track of the attempted remote `payout` of the bounty `bounty_id`, unless it already
+	/// succeeded.
+	fn note_remote_payout(bounty_id: BountyIndex, payout: RemotePayoutOf<T, I>) {
+		if let AssetPayoutState::Attempted { id } = payout.state {
+			Self::deposit_event(Event::<T, I>::BountyPayoutAttempted {
+				index: bounty_id,
+				payment_id: id,
+			});
+			RemotePayouts::<T, I>::insert(bounty_id, payout);
+		}
+	}
+
+	/// The weight of recording an approval of the curator committee of a bounty, on top of
+	/// acting as the only curator.
+	pub fn committee_approval_weight() -> Weight {
+		let max_members = u64::from(MAX_CURATOR_COMMITTEE_SIZE);
+		T::DbWeight::get().reads_writes(max_members, max_members.saturating_add(1))
+	}
+
+	/// The deposit a curator has to place for a bounty with the curator fee `fee`.
+	pub fn calculate_curator_deposit(fee: &BalanceOf<T, I>) -> BalanceOf<T, I> {
+		let mut deposit = T::CuratorDepositMultiplier::get() * *fee;
 
 		if let Some(max_deposit) = T::CuratorDepositMax::get() {
 			deposit = deposit.min(max_deposit)
@@ -824,6 +2908,347 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		deposit
 	}
 
+	/// Record the approval of the call with `call_hash` on the child bounties of the active bounty
+	/// `bounty_id` by `signer`, to be used for child bounties.
+	///
+	/// Returns whether the call may take effect. Without a curator committee, only the curator
+	/// may make the call, otherwise it takes effect once the threshold of the committee approved
+	/// it.
+	pub fn approve_child_bounty_call(
+		bounty_id: BountyIndex,
+		signer: &T::AccountId,
+		call_hash: H256,
+	) -> Result<bool, DispatchError> {
+		let bounty = Bounties::<T, I>::get(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+		let BountyStatus::Active { curator, committee, .. } = &bounty.status else {
+			return Err(Error::<T, I>::UnexpectedStatus.into())
+		};
+		let action = CommitteeAction::ChildBounty { call_hash };
+		Self::approve_action(bounty_id, curator, committee, signer, action)
+	}
+
+	/// Update the curator fee of an active bounty, to be used for child bounties.
+	///
+	/// The fee must be less than the bounty value. The curator deposit is recalculated for the
+	/// new fee, holding or releasing the difference from the curator.
+	pub fn do_update_curator_fee(bounty_id: BountyIndex, fee: BalanceOf<T, I>) -> DispatchResult {
+		Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+			let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+			let curator = match &bounty.status {
+				BountyStatus::Active { curator, .. } => curator.clone(),
+				_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+			};
+			ensure!(fee < bounty.value, Error::<T, I>::InvalidFee);
+
+			let new_deposit = Self::calculate_curator_deposit(&fee);
+			Self::rejig_deposit(
+				HoldReason::CuratorDeposit,
+				&curator,
+				bounty.curator_deposit,
+				new_deposit,
+			)?;
+			bounty.curator_deposit = new_deposit;
+			bounty.fee = fee;
+
+			Ok(())
+		})
+	}
+
+	/// Hold a deposit of `amount` from `who` for `reason`.
+	fn hold_deposit(
+		reason: HoldReason<I>,
+		who: &T::AccountId,
+		amount: BalanceOf<T, I>,
+	) -> DispatchResult {
+		T::NativeBalance::hold(&reason.into(), who, amount)
+	}
+
+	/// Release a deposit of `amount` held from `who` for `reason`.
+	///
+	/// Deposits taken before the deposits of bounties were held are still reserved, so the part
+	/// exceeding the balance held for `reason` is unreserved instead. Returns the amount which
+	/// could not be released.
+	fn release_deposit(
+		reason: HoldReason<I>,
+		who: &T::AccountId,
+		amount: BalanceOf<T, I>,
+	) -> BalanceOf<T, I> {
+		let released =
+			T::NativeBalance::release(&reason.into(), who, amount, Precision::BestEffort)
+				.unwrap_or_default();
+		T::Currency::unreserve(who, amount.saturating_sub(released))
+	}
+
+	/// Slash a deposit of `amount` held from `who` for `reason`.
+	///
+	/// Like [`Self::release_deposit`], the part exceeding the balance held for `reason` is
+	/// slashed from the reserve.
+	fn slash_deposit(reason: HoldReason<I>, who: &T::AccountId, amount: BalanceOf<T, I>) {
+		let (credit, missing) = T::NativeBalance::slash(&reason.into(), who, amount);
+		T::OnDepositSlash::on_unbalanced(credit);
+		if !missing.is_zero() {
+			let imbalance = T::Currency::slash_reserved(who, missing).0;
+			T::OnSlash::on_unbalanced(imbalance);
+		}
+	}
+
+	/// Record the slash of `amount` of the deposit of `curator` for `bounty_id`, so the curator
+	/// may appeal it.
+	/// Slash the deposit of a curator which is unassigned from the bounty `bounty_id`, paying the
+	/// [`Config::CuratorSlashReporterShare`] of it to the `reporter` of an inactive curator.
+	fn slash_curator(
+		bounty_id: BountyIndex,
+		curator: &T::AccountId,
+		curator_deposit: &mut BalanceOf<T, I>,
+		reporter: Option<&T::AccountId>,
+	) {
+		let mut slashed = *curator_deposit;
+		if let Some(reporter) = reporter {
+			// Reward the reporter of an inactive curator with a part of the deposit.
+			let share = T::CuratorSlashReporterShare::get() * slashed;
+			let unpaid = Self::pay_deposit(HoldReason::CuratorDeposit, curator, reporter, share);
+			slashed = slashed.saturating_sub(share).saturating_add(unpaid);
+		}
+		Self::slash_deposit(HoldReason::CuratorDeposit, curator, slashed);
+		Self::record_curator_slash(bounty_id, curator, slashed);
+		*curator_deposit = Zero::zero();
+	}
+
+	fn record_curator_slash(
+		bounty_id: BountyIndex,
+		curator: &T::AccountId,
+		amount: BalanceOf<T, I>,
+	) {
+		if amount.is_zero() {
+			return
+		}
+		let appeal_until = T::BlockNumberProvider::current_block_number()
+			.saturating_add(T::CuratorSlashAppealPeriod::get());
+		SlashedCuratorDeposits::<T, I>::mutate(bounty_id, curator, |maybe_slash| {
+			let slash = maybe_slash.get_or_insert(SlashedCuratorDeposit {
+				amount: Zero::zero(),
+				appealed: false,
+				appeal_until,
+			});
+			slash.amount.saturating_accrue(amount);
+			// A further slash may be appealed for the full period as well.
+			slash.appeal_until = appeal_until;
+		});
+		Self::deposit_event(Event::<T, I>::CuratorDepositSlashed {
+			bounty_id,
+			curator: curator.clone(),
+			amount,
+		});
+	}
+
+	/// Pay `amount` of a deposit held from `who` for `reason` to `dest`.
+	///
+	/// Like [`Self::release_deposit`], the part exceeding the balance held for `reason` is
+	/// repatriated from the reserve. Returns the amount which could not be paid, e.g. because it
+	/// is below the existential deposit of a new `dest`.
+	fn pay_deposit(
+		reason: HoldReason<I>,
+		who: &T::AccountId,
+		dest: &T::AccountId,
+		amount: BalanceOf<T, I>,
+	) -> BalanceOf<T, I> {
+		let paid = T::NativeBalance::transfer_on_hold(
+			&reason.into(),
+			who,
+			dest,
+			amount,
+			Precision::BestEffort,
+			Restriction::Free,
+			Fortitude::Force,
+		)
+		.unwrap_or_default();
+		let missing = amount.saturating_sub(paid);
+		if missing.is_zero() {
+			return missing
+		}
+		T::Currency::repatriate_reserved(who, dest, missing, BalanceStatus::Free).unwrap_or(missing)
+	}
+
+	/// Change a deposit held from `who` for `reason` from `old` to `new`, holding or releasing
+	/// the difference.
+	fn rejig_deposit(
+		reason: HoldReason<I>,
+		who: &T::AccountId,
+		old: BalanceOf<T, I>,
+		new: BalanceOf<T, I>,
+	) -> DispatchResult {
+		if new > old {
+			Self::hold_deposit(reason, who, new - old)?;
+		} else {
+			let err_amount = Self::release_deposit(reason, who, old - new);
+			debug_assert!(err_amount.is_zero());
+		}
+		Ok(())
+	}
+
+	/// Move the part of a deposit of `amount` from `who` for `reason` which is still reserved on
+	/// hold.
+	///
+	/// As the deposits of `who` for `reason` are either held or reserved, at least the part of
+	/// `amount` exceeding the balance held for `reason` is reserved. Returns the amount moved.
+	fn hold_reserved_deposit(
+		reason: HoldReason<I>,
+		who: &T::AccountId,
+		amount: BalanceOf<T, I>,
+	) -> Result<BalanceOf<T, I>, DispatchError> {
+		let held = T::NativeBalance::balance_on_hold(&reason.into(), who);
+		let reserved = amount.saturating_sub(held);
+		if reserved.is_zero() {
+			return Ok(Zero::zero())
+		}
+		let moved = reserved.saturating_sub(T::Currency::unreserve(who, reserved));
+		Self::hold_deposit(reason, who, moved)?;
+		Ok(moved)
+	}
+
+	/// The summary of the native or asset bounty `bounty_id`, if it exists.
+	pub fn bounty_summary(bounty_id: BountyIndex) -> Option<BountySummaryOf<T, I>> {
+		let Some(bounty) = Bounties::<T, I>::get(bounty_id) else {
+			return Self::asset_bounty_summary(bounty_id)
+		};
+		let (status, curator, update_due, unlock_at) = match bounty.status {
+			BountyStatus::Proposed => (BountySummaryStatus::Proposed, None, None, None),
+			BountyStatus::Approved => (BountySummaryStatus::Approved, None, None, None),
+			BountyStatus::Funded => (BountySummaryStatus::Funded, None, None, None),
+			BountyStatus::CuratorProposed { curator } =>
+				(BountySummaryStatus::CuratorProposed, Some(curator), None, None),
+			BountyStatus::Active { curator, update_due, .. } =>
+				(BountySummaryStatus::Active, Some(curator), Some(update_due), None),
+			BountyStatus::PendingPayout { curator, unlock_at, .. } =>
+				(BountySummaryStatus::PendingPayout, Some(curator), None, Some(unlock_at)),
+		};
+
+		Some(BountySummary {
+			index: bounty_id,
+			proposer: bounty.proposer,
+			value: BountySummaryValue::Native { value: bounty.value, fee: bounty.fee },
+			status,
+			curator,
+			update_due,
+			unlock_at,
+			description_hash: Self::description_hash(bounty_id),
+		})
+	}
+
+	/// The summary of the asset bounty `bounty_id`, if it exists.
+	fn asset_bounty_summary(bounty_id: BountyIndex) -> Option<BountySummaryOf<T, I>> {
+		let bounty = AssetBounties::<T, I>::get(bounty_id)?;
+		let (status, curator, update_due, unlock_at) = match bounty.status {
+			AssetBountyStatus::Proposed => (BountySummaryStatus::Proposed, None, None, None),
+			AssetBountyStatus::Approved => (BountySummaryStatus::Approved, None, None, None),
+			AssetBountyStatus::CuratorProposed { curator } =>
+				(BountySummaryStatus::CuratorProposed, Some(curator), None, None),
+			AssetBountyStatus::Active { curator, update_due } =>
+				(BountySummaryStatus::Active, Some(curator), Some(update_due), None),
+			AssetBountyStatus::PendingPayout { curator, unlock_at, .. } =>
+				(BountySummaryStatus::PendingPayout, Some(curator), None, Some(unlock_at)),
+			AssetBountyStatus::PayingOut { .. } =>
+				(BountySummaryStatus::PayingOut, None, None, None),
+		};
+
+		Some(BountySummary {
+			index: bounty_id,
+			proposer: bounty.proposer,
+			value: BountySummaryValue::Asset {
+				asset_kind: bounty.asset_kind,
+				value: bounty.value,
+				fee: bounty.fee,
+			},
+			status,
+			curator,
+			update_due,
+			unlock_at,
+			description_hash: Self::description_hash(bounty_id),
+		})
+	}
+
+	/// The BLAKE2-256 hash of the description of the bounty `bounty_id`, if it is still stored.
+	fn description_hash(bounty_id: BountyIndex) -> Option<H256> {
+		BountyDescriptions::<T, I>::get(bounty_id)
+			.map(|description| H256(sp_io::hashing::blake2_256(&description)))
+	}
+
+	/// The summaries of at most `limit` bounties following the cursor `after`, or starting from
+	/// the first bounty if it is `None`.
+	///
+	/// The native bounties are returned first, followed by the asset bounties, each in storage
+	/// order rather than by index. The next page continues after the
+	/// [`cursor`](BountySummary::cursor) of the last returned bounty.
+	pub fn bounty_summaries(after: Option<BountyCursor>, limit: u32) -> Vec<BountySummaryOf<T, I>> {
+		let native = match after {
+			None => Some(Bounties::<T, I>::iter_keys()),
+			Some(BountyCursor::Native(index)) =>
+				Some(Bounties::<T, I>::iter_keys_from(Bounties::<T, I>::hashed_key_for(index))),
+			Some(BountyCursor::Asset(_)) => None,
+		};
+		let asset = match after {
+			Some(BountyCursor::Asset(index)) =>
+				AssetBounties::<T, I>::iter_keys_from(AssetBounties::<T, I>::hashed_key_for(index)),
+			_ => AssetBounties::<T, I>::iter_keys(),
+		};
+		native
+			.into_iter()
+			.flatten()
+			.chain(asset)
+			.take(limit as usize)
+			.filter_map(Self::bounty_summary)
+			.collect()
+	}
+
+	/// Start the funding ledger of the bounty `bounty_id`, which was funded with `value`.
+	fn open_ledger(bounty_id: BountyIndex, value: BalanceOf<T, I>) {
+		BountyLedgers::<T, I>::insert(
+			bounty_id,
+			BountyLedger { funded: value, ..Default::default() },
+		);
+		TotalEncumbered::<T, I>::mutate(|total| *total = total.saturating_add(value));
+	}
+
+	/// Remove the funding ledger of the bounty `bounty_id` once its remaining funds left the
+	/// bounty account.
+	fn close_ledger(bounty_id: BountyIndex) {
+		if let Some(ledger) = BountyLedgers::<T, I>::take(bounty_id) {
+			TotalEncumbered::<T, I>::mutate(|total| {
+				*total = total.saturating_sub(ledger.encumbered())
+			});
+		}
+	}
+
+	/// Note that `value` of the bounty `bounty_id` was committed to a new child bounty.
+	pub fn note_child_bounty_funded(bounty_id: BountyIndex, value: BalanceOf<T, I>) {
+		BountyLedgers::<T, I>::mutate(bounty_id, |maybe_ledger| {
+			if let Some(ledger) = maybe_ledger {
+				ledger.child_bounties = ledger.child_bounties.saturating_add(value);
+			}
+		});
+	}
+
+	/// Note that `value` committed to a child bounty of the bounty `bounty_id` was returned to
+	/// the bounty, if `paid` is false, or paid out.
+	pub fn note_child_bounty_ended(bounty_id: BountyIndex, value: BalanceOf<T, I>, paid: bool) {
+		BountyLedgers::<T, I>::mutate(bounty_id, |maybe_ledger| {
+			if let Some(ledger) = maybe_ledger {
+				ledger.child_bounties = ledger.child_bounties.saturating_sub(value);
+				if paid {
+					ledger.paid = ledger.paid.saturating_add(value);
+					TotalEncumbered::<T, I>::mutate(|total| *total = total.saturating_sub(value));
+				}
+			}
+		});
+	}
+
+	/// The number of bounties which can still be queued for funding by `approve_bounty` before
+	/// it fails with [`Error::TooManyQueued`].
+	pub fn approvals_capacity() -> u32 {
+		let queued = BountyApprovals::<T, I>::decode_len().unwrap_or(0) as u32;
+		T::MaxApprovals::get().saturating_sub(queued)
+	}
+
 	/// The account ID of the treasury pot.
 	///
 	/// This actually does computation. If you need to keep using it, then make sure you cache the
@@ -844,19 +3269,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		description: Vec<u8>,
 		value: BalanceOf<T, I>,
 	) -> DispatchResult {
-		let bounded_description: BoundedVec<_, _> =
-			description.try_into().map_err(|_| Error::<T, I>::ReasonTooBig)?;
 		ensure!(value >= T::BountyValueMinimum::get(), Error::<T, I>::InvalidValue);
 
-		let index = BountyCount::<T, I>::get();
-
-		// reserve deposit for new bounty
-		let bond = T::BountyDepositBase::get() +
-			T::DataDepositPerByte::get() * (bounded_description.len() as u32).into();
-		T::Currency::reserve(&proposer, bond)
-			.map_err(|_| Error::<T, I>::InsufficientProposersBalance)?;
-
-		BountyCount::<T, I>::put(index + 1);
+		let (index, bond) = Self::hold_proposal_bond(&proposer, description)?;
 
 		let bounty = Bounty {
 			proposer,
@@ -865,15 +3280,90 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			curator_deposit: 0u32.into(),
 			bond,
 			status: BountyStatus::Proposed,
+			update_period: None,
 		};
 
 		Bounties::<T, I>::insert(index, &bounty);
-		BountyDescriptions::<T, I>::insert(index, bounded_description);
 
 		Self::deposit_event(Event::<T, I>::BountyProposed { index });
 
 		Ok(())
 	}
+
+	/// Hold the deposit for proposing a bounty with `description` and store the description
+	/// under a new bounty index.
+	///
+	/// Returns the index of the new bounty and the held deposit.
+	fn hold_proposal_bond(
+		proposer: &T::AccountId,
+		description: Vec<u8>,
+	) -> Result<(BountyIndex, BalanceOf<T, I>), DispatchError> {
+		let bounded_description: BoundedVec<_, _> =
+			description.try_into().map_err(|_| Error::<T, I>::ReasonTooBig)?;
+
+		let index = BountyCount::<T, I>::get();
+
+		// hold deposit for new bounty
+		let bond = T::BountyDepositBase::get() +
+			T::DataDepositPerByte::get() * (bounded_description.len() as u32).into();
+		Self::hold_deposit(HoldReason::ProposerBond, proposer, bond)
+			.map_err(|_| Error::<T, I>::InsufficientProposersBalance)?;
+
+		BountyCount::<T, I>::put(index + 1);
+		BountyDescriptions::<T, I>::insert(index, bounded_description);
+
+		Ok((index, bond))
+	}
+
+	/// Convert `amount` of `asset_kind` to the native currency.
+	fn native_amount(
+		asset_kind: T::AssetKind,
+		amount: AssetBalanceOf<T, I>,
+	) -> Result<BalanceOf<T, I>, DispatchError> {
+		T::BalanceConverter::from_asset_balance(amount, asset_kind)
+			.map_err(|_| pallet_treasury::Error::<T, I>::FailedToConvertBalance.into())
+	}
+
+	/// Pay `amount` of `asset_kind` to `beneficiary` if the payment `state` was not attempted
+	/// yet or failed.
+	///
+	/// Returns whether a payment was attempted. Payments of nothing succeed right away.
+	fn attempt_payout(
+		state: &mut AssetPayoutState<PaymentIdOf<T, I>>,
+		beneficiary: &T::Beneficiary,
+		asset_kind: T::AssetKind,
+		amount: AssetBalanceOf<T, I>,
+	) -> Result<bool, DispatchError> {
+		if !matches!(state, AssetPayoutState::Pending | AssetPayoutState::Failed) {
+			return Ok(false)
+		}
+		*state = if amount.is_zero() {
+			AssetPayoutState::Succeeded
+		} else {
+			let id = T::Paymaster::pay(beneficiary, asset_kind, amount)
+				.map_err(|_| Error::<T, I>::PayoutError)?;
+			AssetPayoutState::Attempted { id }
+		};
+		Ok(true)
+	}
+
+	/// Update an attempted payment `state` with the status reported by the `Paymaster`.
+	///
+	/// Returns whether the payment concluded.
+	fn check_payout(state: &mut AssetPayoutState<PaymentIdOf<T, I>>) -> bool {
+		let AssetPayoutState::Attempted { id } = state else { return false };
+		match T::Paymaster::check_payment(*id) {
+			PaymentStatus::Success | PaymentStatus::Unknown => {
+				*state = AssetPayoutState::Succeeded;
+				true
+			},
+			PaymentStatus::Failure => {
+				*state = AssetPayoutState::Failed;
+				true
+			},
+			PaymentStatus::InProgress => false,
+		}
+	}
 }
 
 impl<T: Config<I>, I: 'static> pallet_treasury::SpendFunds<T, I> for Pallet<T, I> {
@@ -893,9 +3383,14 @@ impl<T: Config<I>, I: 'static> pallet_treasury::SpendFunds<T, I> for Pallet<T, I
 							*budget_remaining -= bounty.value;
 
 							bounty.status = BountyStatus::Funded;
+							Self::set_funded_expiry(index);
 
 							// return their deposit.
-							let err_amount = T::Currency::unreserve(&bounty.proposer, bounty.bond);
+							let err_amount = Self::release_deposit(
+								HoldReason::ProposerBond,
+								&bounty.proposer,
+								bounty.bond,
+							);
 							debug_assert!(err_amount.is_zero());
 
 							// fund the bounty account
@@ -903,8 +3398,13 @@ impl<T: Config<I>, I: 'static> pallet_treasury::SpendFunds<T, I> for Pallet<T, I
 								&Self::bounty_account_id(index),
 								bounty.value,
 							));
+							Self::open_ledger(index, bounty.value);
 
-							Self::deposit_event(Event::<T, I>::BountyBecameActive { index });
+							Self::deposit_event(Event::<T, I>::BountyBecameActive {
+								index,
+								account: Self::bounty_account_id(index),
+								value: bounty.value,
+							});
 							false
 						} else {
 							*missed_any = true;
@@ -931,4 +3431,8 @@ impl<Balance: Zero> ChildBountyManager<Balance> for () {
 	fn children_curator_fees(_bounty_id: BountyIndex) -> Balance {
 		Zero::zero()
 	}
+
+	fn peek_children_curator_fees(_bounty_id: BountyIndex) -> Balance {
+		Zero::zero()
+	}
 }
@@ -17,3 +17,13 @@
 
 /// Version 4.
 pub mod v4;
+/// Version 5.
+pub mod v5;
+/// Version 6.
+pub mod v6;
+/// Version 7.
+pub mod v7;
+/// Version 8.
+pub mod v8;
+/// Version 9.
+pub mod v9;
@@ -0,0 +1,233 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{BalanceOf, BountyIndex, Config, Pallet};
+use frame_support::{
+	migrations::VersionedMigration,
+	storage_alias,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+	weights::Weight,
+	Twox64Concat,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use old::BountyStatus;
+use sp_runtime::traits::{BlockNumberProvider, Convert, Saturating};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// The types of the bounties as stored in versions 4 and 5.
+pub(crate) mod old {
+	use codec::{Decode, Encode, MaxEncodedLen};
+	use scale_info::TypeInfo;
+	use sp_runtime::RuntimeDebug;
+
+	/// A bounty as stored in versions 4 and 5.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Bounty<AccountId, Balance, BlockNumber> {
+		pub proposer: AccountId,
+		pub value: Balance,
+		pub fee: Balance,
+		pub curator_deposit: Balance,
+		pub bond: Balance,
+		pub status: BountyStatus<AccountId, BlockNumber>,
+	}
+
+	/// A child bounty as stored by the child-bounties pallet next to bounties of version 4.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ChildBounty<AccountId, Balance, BlockNumber> {
+		pub parent_bounty: super::BountyIndex,
+		pub value: Balance,
+		pub fee: Balance,
+		pub curator_deposit: Balance,
+		pub status: ChildBountyStatus<AccountId, BlockNumber>,
+	}
+
+	/// The status of a child bounty as stored next to bounties of version 4.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum ChildBountyStatus<AccountId, BlockNumber> {
+		Added,
+		CuratorProposed { curator: AccountId },
+		Active { curator: AccountId },
+		PendingPayout { curator: AccountId, beneficiary: AccountId, unlock_at: BlockNumber },
+	}
+
+	/// The status of a bounty as stored in versions 4 and 5, without curator committees.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum BountyStatus<AccountId, BlockNumber> {
+		Proposed,
+		Approved,
+		Funded,
+		CuratorProposed { curator: AccountId },
+		Active { curator: AccountId, update_due: BlockNumber },
+		PendingPayout { curator: AccountId, beneficiary: AccountId, unlock_at: BlockNumber },
+	}
+}
+
+/// The bounties as stored in versions 4 and 5.
+#[storage_alias]
+pub(crate) type Bounties<T: Config<I>, I: 'static> = StorageMap<
+	Pallet<T, I>,
+	Twox64Concat,
+	BountyIndex,
+	old::Bounty<<T as frame_system::Config>::AccountId, BalanceOf<T, I>, BlockNumberFor<T>>,
+>;
+
+/// The child bounties of the child-bounties pallet named `ChildBountiesPallet`, whose deadlines
+/// follow those of the bounties.
+#[storage_alias(dynamic)]
+pub(crate) type ChildBounties<ChildBountiesPallet: Get<&'static str>, T: Config<I>, I: 'static> =
+	StorageDoubleMap<
+		ChildBountiesPallet,
+		Twox64Concat,
+		BountyIndex,
+		Twox64Concat,
+		BountyIndex,
+		old::ChildBounty<
+			<T as frame_system::Config>::AccountId,
+			BalanceOf<T, I>,
+			BlockNumberFor<T>,
+		>,
+	>;
+
+/// Actual implementation of [`MigrateV4ToV5`].
+///
+/// Translates the deadlines of all bounties, and the unlock blocks of the child bounties stored
+/// by the child-bounties pallet named `ChildBountiesPallet`, from the block numbers of
+/// `OldProvider` to those of [`Config::BlockNumberProvider`]. The time remaining until a deadline
+/// is measured with `OldProvider` and converted into the unit of the new provider with
+/// `Conversion`. Deadlines which already passed are set to the current block number of the new
+/// provider.
+pub struct MigrateDeadlines<T, I, OldProvider, Conversion, ChildBountiesPallet>(
+	PhantomData<(T, I, OldProvider, Conversion, ChildBountiesPallet)>,
+);
+
+impl<T, I, OldProvider, Conversion, ChildBountiesPallet>
+	MigrateDeadlines<T, I, OldProvider, Conversion, ChildBountiesPallet>
+where
+	T: Config<I>,
+	I: 'static,
+	OldProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<T>>,
+	Conversion: Convert<BlockNumberFor<T>, BlockNumberFor<T>>,
+	ChildBountiesPallet: Get<&'static str>,
+{
+	fn translate(
+		deadline: BlockNumberFor<T>,
+		old_now: BlockNumberFor<T>,
+		new_now: BlockNumberFor<T>,
+	) -> BlockNumberFor<T> {
+		new_now.saturating_add(Conversion::convert(deadline.saturating_sub(old_now)))
+	}
+}
+
+impl<T, I, OldProvider, Conversion, ChildBountiesPallet> UncheckedOnRuntimeUpgrade
+	for MigrateDeadlines<T, I, OldProvider, Conversion, ChildBountiesPallet>
+where
+	T: Config<I>,
+	I: 'static,
+	OldProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<T>>,
+	Conversion: Convert<BlockNumberFor<T>, BlockNumberFor<T>>,
+	ChildBountiesPallet: Get<&'static str>,
+{
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		use codec::Encode;
+
+		let bounties = Bounties::<T, I>::iter().count() as u32;
+		let child_bounties = ChildBounties::<ChildBountiesPallet, T, I>::iter().count() as u32;
+		Ok((bounties, child_bounties).encode())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		let old_now = OldProvider::current_block_number();
+		let new_now = T::BlockNumberProvider::current_block_number();
+
+		let mut count = 0u64;
+		Bounties::<T, I>::translate_values::<
+			old::Bounty<T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>>,
+			_,
+		>(|mut bounty| {
+			count.saturating_inc();
+			match bounty.status {
+				BountyStatus::Active { ref mut update_due, .. } => {
+					*update_due = Self::translate(*update_due, old_now, new_now);
+				},
+				BountyStatus::PendingPayout { ref mut unlock_at, .. } => {
+					*unlock_at = Self::translate(*unlock_at, old_now, new_now);
+				},
+				_ => {},
+			}
+			Some(bounty)
+		});
+
+		let mut child_count = 0u64;
+		ChildBounties::<ChildBountiesPallet, T, I>::translate_values::<
+			old::ChildBounty<T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>>,
+			_,
+		>(|mut child_bounty| {
+			child_count.saturating_inc();
+			if let old::ChildBountyStatus::PendingPayout { ref mut unlock_at, .. } =
+				child_bounty.status
+			{
+				*unlock_at = Self::translate(*unlock_at, old_now, new_now);
+			}
+			Some(child_bounty)
+		});
+
+		log::info!(
+			target: "runtime::bounties",
+			"Migrated the deadlines of {} bounties and {} child bounties to the new block number \
+			provider",
+			count,
+			child_count,
+		);
+
+		let count = count.saturating_add(child_count);
+		T::DbWeight::get().reads_writes(count, count)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use codec::Decode;
+
+		let (count, child_count) =
+			<(u32, u32)>::decode(&mut &state[..]).map_err(|_| "Bounties: invalid state")?;
+		frame_support::ensure!(
+			count == Bounties::<T, I>::iter().count() as u32,
+			"Bounties: the number of bounties changed during the migration"
+		);
+		frame_support::ensure!(
+			child_count == ChildBounties::<ChildBountiesPallet, T, I>::iter().count() as u32,
+			"Bounties: the number of child bounties changed during the migration"
+		);
+
+		Ok(())
+	}
+}
+
+/// Migrate the storage from V4 to V5.
+///
+/// Translates the deadlines of all bounties and child bounties to the block numbers of
+/// [`Config::BlockNumberProvider`]; see [`MigrateDeadlines`].
+pub type MigrateV4ToV5<T, I, OldProvider, Conversion, ChildBountiesPallet> = VersionedMigration<
+	4,
+	5,
+	MigrateDeadlines<T, I, OldProvider, Conversion, ChildBountiesPallet>,
+	Pallet<T, I>,
+	<T as frame_system::Config>::DbWeight,
+>;
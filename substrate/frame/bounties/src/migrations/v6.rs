@@ -0,0 +1,126 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::v5::old;
+use crate::{BalanceOf, Bounty, BountyIndex, BountyStatus, CommitteeAction, Config, Pallet};
+use frame_support::{
+	migrations::VersionedMigration,
+	storage_alias,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+	weights::Weight,
+	Twox64Concat,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::Saturating;
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// The bounties as stored in version 6, which are paid out to local accounts only.
+#[storage_alias]
+pub(crate) type Bounties<T: Config<I>, I: 'static> = StorageMap<
+	Pallet<T, I>,
+	Twox64Concat,
+	BountyIndex,
+	Bounty<<T as frame_system::Config>::AccountId, BalanceOf<T, I>, BlockNumberFor<T>>,
+>;
+
+/// The approvals of the curator committees as stored in version 6, which award bounties to local
+/// accounts only.
+#[storage_alias]
+pub(crate) type CommitteeApprovals<T: Config<I>, I: 'static> = StorageDoubleMap<
+	Pallet<T, I>,
+	Twox64Concat,
+	BountyIndex,
+	Twox64Concat,
+	<T as frame_system::Config>::AccountId,
+	CommitteeAction<<T as frame_system::Config>::AccountId>,
+>;
+
+/// Actual implementation of [`MigrateV5ToV6`].
+///
+/// Adds the curator committee to the status of active bounties, which are curated by their
+/// curator alone, and the update period to all bounties, which follow the default of the pallet.
+pub struct AddCuratorCommittees<T, I>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for AddCuratorCommittees<T, I> {
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		use codec::Encode;
+
+		Ok((super::v5::Bounties::<T, I>::iter().count() as u32).encode())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		let mut count = 0u64;
+		Bounties::<T, I>::translate_values::<
+			old::Bounty<T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>>,
+			_,
+		>(|bounty| {
+			count.saturating_inc();
+			let status = match bounty.status {
+				old::BountyStatus::Proposed => BountyStatus::Proposed,
+				old::BountyStatus::Approved => BountyStatus::Approved,
+				old::BountyStatus::Funded => BountyStatus::Funded,
+				old::BountyStatus::CuratorProposed { curator } =>
+					BountyStatus::CuratorProposed { curator },
+				old::BountyStatus::Active { curator, update_due } =>
+					BountyStatus::Active { curator, update_due, committee: None },
+				old::BountyStatus::PendingPayout { curator, beneficiary, unlock_at } =>
+					BountyStatus::PendingPayout { curator, beneficiary, unlock_at },
+			};
+			Some(Bounty {
+				proposer: bounty.proposer,
+				value: bounty.value,
+				fee: bounty.fee,
+				curator_deposit: bounty.curator_deposit,
+				bond: bounty.bond,
+				status,
+				update_period: None,
+			})
+		});
+
+		log::info!(target: "runtime::bounties", "Added curator committees to {} bounties", count);
+
+		T::DbWeight::get().reads_writes(count, count)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use codec::Decode;
+
+		let count = u32::decode(&mut &state[..]).map_err(|_| "Bounties: invalid state")?;
+		frame_support::ensure!(
+			count == Bounties::<T, I>::iter().count() as u32,
+			"Bounties: the number of bounties changed during the migration"
+		);
+
+		Ok(())
+	}
+}
+
+/// Migrate the storage from V5 to V6.
+///
+/// Adds the curator committee to the status of active bounties; see [`AddCuratorCommittees`].
+pub type MigrateV5ToV6<T, I> = VersionedMigration<
+	5,
+	6,
+	AddCuratorCommittees<T, I>,
+	Pallet<T, I>,
+	<T as frame_system::Config>::DbWeight,
+>;
@@ -0,0 +1,133 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+	BalanceOf, Bounties, Bounty, BountyStatus, CommitteeAction, CommitteeApprovals, Config, Pallet,
+};
+use frame_support::{
+	migrations::VersionedMigration,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+	weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{Convert, Saturating};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Actual implementation of [`MigrateV6ToV7`].
+///
+/// Converts the local accounts which bounties are awarded to, or which the curator committees
+/// approved awards to, into the beneficiary type of the treasury with `Conversion`.
+pub struct MigrateBeneficiaries<T, I, Conversion>(PhantomData<(T, I, Conversion)>);
+
+impl<T, I, Conversion> UncheckedOnRuntimeUpgrade for MigrateBeneficiaries<T, I, Conversion>
+where
+	T: Config<I>,
+	I: 'static,
+	Conversion: Convert<T::AccountId, T::Beneficiary>,
+{
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		use codec::Encode;
+
+		let bounties = super::v6::Bounties::<T, I>::iter().count() as u32;
+		let approvals = super::v6::CommitteeApprovals::<T, I>::iter().count() as u32;
+		Ok((bounties, approvals).encode())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		let mut count = 0u64;
+		Bounties::<T, I>::translate_values::<
+			Bounty<T::AccountId, BalanceOf<T, I>, BlockNumberFor<T>>,
+			_,
+		>(|bounty| {
+			count.saturating_inc();
+			let status = match bounty.status {
+				BountyStatus::Proposed => BountyStatus::Proposed,
+				BountyStatus::Approved => BountyStatus::Approved,
+				BountyStatus::Funded => BountyStatus::Funded,
+				BountyStatus::CuratorProposed { curator } =>
+					BountyStatus::CuratorProposed { curator },
+				BountyStatus::Active { curator, update_due, committee } =>
+					BountyStatus::Active { curator, update_due, committee },
+				BountyStatus::PendingPayout { curator, beneficiary, unlock_at } =>
+					BountyStatus::PendingPayout {
+						curator,
+						beneficiary: Conversion::convert(beneficiary),
+						unlock_at,
+					},
+			};
+			Some(Bounty {
+				proposer: bounty.proposer,
+				value: bounty.value,
+				fee: bounty.fee,
+				curator_deposit: bounty.curator_deposit,
+				bond: bounty.bond,
+				status,
+				update_period: bounty.update_period,
+			})
+		});
+		CommitteeApprovals::<T, I>::translate_values::<CommitteeAction<T::AccountId>, _>(
+			|action| {
+				count.saturating_inc();
+				Some(match action {
+					CommitteeAction::Award { beneficiary } =>
+						CommitteeAction::Award { beneficiary: Conversion::convert(beneficiary) },
+					CommitteeAction::ExtendExpiry => CommitteeAction::ExtendExpiry,
+					CommitteeAction::ChildBounty { call_hash } =>
+						CommitteeAction::ChildBounty { call_hash },
+				})
+			},
+		);
+
+		log::info!(target: "runtime::bounties", "Migrated the beneficiaries of {} entries", count);
+
+		T::DbWeight::get().reads_writes(count, count)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use codec::Decode;
+
+		let (bounties, approvals) =
+			<(u32, u32)>::decode(&mut &state[..]).map_err(|_| "Bounties: invalid state")?;
+		frame_support::ensure!(
+			bounties == Bounties::<T, I>::iter().count() as u32,
+			"Bounties: the number of bounties changed during the migration"
+		);
+		frame_support::ensure!(
+			approvals == CommitteeApprovals::<T, I>::iter().count() as u32,
+			"Bounties: the number of committee approvals changed during the migration"
+		);
+
+		Ok(())
+	}
+}
+
+/// Migrate the storage from V6 to V7.
+///
+/// Converts the beneficiaries of bounties into the beneficiary type of the treasury; see
+/// [`MigrateBeneficiaries`].
+pub type MigrateV6ToV7<T, I, Conversion> = VersionedMigration<
+	6,
+	7,
+	MigrateBeneficiaries<T, I, Conversion>,
+	Pallet<T, I>,
+	<T as frame_system::Config>::DbWeight,
+>;
@@ -0,0 +1,95 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Bounties, BountyStatus, Config, FundedExpiries, Pallet};
+use frame_support::{
+	migrations::VersionedMigration,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_runtime::traits::{BlockNumberProvider, Saturating};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Actual implementation of [`MigrateV7ToV8`].
+///
+/// Lets all funded bounties without an active curator expire [`Config::FundedExpiry`] blocks
+/// after the upgrade.
+pub struct SetFundedExpiries<T, I>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for SetFundedExpiries<T, I> {
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		use codec::Encode;
+
+		let funded = Bounties::<T, I>::iter_values()
+			.filter(|bounty| {
+				matches!(
+					bounty.get_status(),
+					BountyStatus::Funded | BountyStatus::CuratorProposed { .. }
+				)
+			})
+			.count() as u32;
+		Ok(funded.encode())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		let expires_at =
+			T::BlockNumberProvider::current_block_number().saturating_add(T::FundedExpiry::get());
+		let (mut reads, mut writes) = (0u64, 0u64);
+		for (index, bounty) in Bounties::<T, I>::iter() {
+			reads.saturating_inc();
+			if matches!(
+				bounty.get_status(),
+				BountyStatus::Funded | BountyStatus::CuratorProposed { .. }
+			) {
+				FundedExpiries::<T, I>::insert(index, expires_at);
+				writes.saturating_inc();
+			}
+		}
+
+		log::info!(target: "runtime::bounties", "Set the expiry of {} funded bounties", writes);
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use codec::Decode;
+
+		let funded = u32::decode(&mut &state[..]).map_err(|_| "Bounties: invalid state")?;
+		frame_support::ensure!(
+			funded == FundedExpiries::<T, I>::iter().count() as u32,
+			"Bounties: not all funded bounties expire"
+		);
+
+		Ok(())
+	}
+}
+
+/// Migrate the storage from V7 to V8.
+///
+/// Sets the expiry of the funded bounties; see [`SetFundedExpiries`].
+pub type MigrateV7ToV8<T, I> = VersionedMigration<
+	7,
+	8,
+	SetFundedExpiries<T, I>,
+	Pallet<T, I>,
+	<T as frame_system::Config>::DbWeight,
+>;
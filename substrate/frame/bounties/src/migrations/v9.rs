@@ -0,0 +1,145 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{
+	AssetBounties, AssetBountyStatus, Bounties, BountyLedger, BountyLedgers, BountyStatus,
+	ChildBountyManager, Config, Pallet, TotalEncumbered,
+};
+use frame_support::{
+	migrations::VersionedMigration,
+	traits::{Currency, Get, UncheckedOnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Actual implementation of [`MigrateV8ToV9`].
+///
+/// Opens the [`BountyLedgers`] of the bounties funded and the asset bounties approved before the
+/// ledgers were introduced, and sums them up in [`TotalEncumbered`].
+///
+/// The funds which a bounty paid out through its child bounties are only known if it has no
+/// active child bounties, otherwise its whole value is counted as encumbered.
+pub struct OpenFundingLedgers<T, I>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for OpenFundingLedgers<T, I> {
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		use codec::Encode;
+
+		let funded = Bounties::<T, I>::iter_values()
+			.filter(|bounty| {
+				!matches!(bounty.get_status(), BountyStatus::Proposed | BountyStatus::Approved)
+			})
+			.count() as u32;
+		let approved = AssetBounties::<T, I>::iter_values()
+			.filter(|bounty| bounty.status != AssetBountyStatus::Proposed)
+			.count() as u32;
+		Ok(funded.saturating_add(approved).encode())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		let (mut reads, mut writes) = (0u64, 0u64);
+		for (index, bounty) in Bounties::<T, I>::iter() {
+			reads.saturating_inc();
+			if matches!(bounty.get_status(), BountyStatus::Proposed | BountyStatus::Approved) ||
+				BountyLedgers::<T, I>::contains_key(index)
+			{
+				continue
+			}
+			let paid = if T::ChildBountyManager::child_bounties_count(index).is_zero() {
+				let balance = T::Currency::free_balance(&Pallet::<T, I>::bounty_account_id(index));
+				bounty.value.saturating_sub(balance)
+			} else {
+				Zero::zero()
+			};
+			BountyLedgers::<T, I>::insert(
+				index,
+				BountyLedger { funded: bounty.value, paid, ..Default::default() },
+			);
+			reads.saturating_accrue(3);
+			writes.saturating_inc();
+		}
+
+		for (index, bounty) in AssetBounties::<T, I>::iter() {
+			reads.saturating_inc();
+			if bounty.status == AssetBountyStatus::Proposed ||
+				BountyLedgers::<T, I>::contains_key(index)
+			{
+				continue
+			}
+			reads.saturating_inc();
+			match Pallet::<T, I>::native_amount(bounty.asset_kind, bounty.value) {
+				Ok(funded) => {
+					BountyLedgers::<T, I>::insert(
+						index,
+						BountyLedger { funded, ..Default::default() },
+					);
+					writes.saturating_inc();
+				},
+				Err(_) => log::warn!(
+					target: "runtime::bounties",
+					"Failed to convert the value of asset bounty {}, not tracking it", index
+				),
+			}
+		}
+
+		let mut total = Zero::zero();
+		for ledger in BountyLedgers::<T, I>::iter_values() {
+			reads.saturating_inc();
+			total = ledger.encumbered().saturating_add(total);
+		}
+		TotalEncumbered::<T, I>::put(total);
+		writes.saturating_inc();
+
+		log::info!(target: "runtime::bounties", "Opened {} bounty ledgers", writes - 1);
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use codec::Decode;
+
+		let tracked = u32::decode(&mut &state[..]).map_err(|_| "Bounties: invalid state")?;
+		frame_support::ensure!(
+			tracked >= BountyLedgers::<T, I>::iter().count() as u32,
+			"Bounties: ledgers of unfunded bounties"
+		);
+		let total = BountyLedgers::<T, I>::iter_values()
+			.fold(Zero::zero(), |total, ledger| ledger.encumbered().saturating_add(total));
+		frame_support::ensure!(
+			total == TotalEncumbered::<T, I>::get(),
+			"Bounties: the encumbered funds are not the sum of the ledgers"
+		);
+
+		Ok(())
+	}
+}
+
+/// Migrate the storage from V8 to V9.
+///
+/// Opens the ledgers of the funded bounties; see [`OpenFundingLedgers`].
+pub type MigrateV8ToV9<T, I> = VersionedMigration<
+	8,
+	9,
+	OpenFundingLedgers<T, I>,
+	Pallet<T, I>,
+	<T as frame_system::Config>::DbWeight,
+>;
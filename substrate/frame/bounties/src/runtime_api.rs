@@ -0,0 +1,60 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the bounties pallet.
+
+use crate::{BountyCursor, BountyIndex, BountyLedger, BountySummary};
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait BountiesApi<AccountId, Balance, BlockNumber, AssetKind, AssetBalance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		AssetKind: Codec,
+		AssetBalance: Codec,
+	{
+		/// Returns the summary of the native or asset bounty `index`, if it exists.
+		fn bounty(
+			index: BountyIndex,
+		) -> Option<BountySummary<AccountId, Balance, BlockNumber, AssetKind, AssetBalance>>;
+
+		/// Returns the summaries of at most `limit` native and asset bounties following the
+		/// cursor `after`, or starting from the first bounty if it is `None`.
+		///
+		/// The next page continues after the cursor of the last returned bounty.
+		fn bounties(
+			after: Option<BountyCursor>,
+			limit: u32,
+		) -> Vec<BountySummary<AccountId, Balance, BlockNumber, AssetKind, AssetBalance>>;
+
+		/// Returns the deposit a curator has to place for a bounty with the curator fee `fee`.
+		fn curator_deposit(fee: Balance) -> Balance;
+
+		/// Returns the number of bounties which can still be approved before the approvals queue
+		/// is full and `approve_bounty` fails with `TooManyQueued`.
+		fn approvals_capacity() -> u32;
+
+		/// Returns the funding ledger of the bounty `index`, if it is funded.
+		fn bounty_ledger(index: BountyIndex) -> Option<BountyLedger<Balance>>;
+
+		/// Returns the funds still locked in all funded bounties and their child bounties.
+		fn total_encumbered() -> Balance;
+	}
+}
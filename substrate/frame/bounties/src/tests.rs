@@ -32,7 +32,7 @@ use frame_support::{
 };
 
 use sp_runtime::{
-	traits::{BadOrigin, IdentityLookup},
+	traits::{BadOrigin, Convert, IdentityLookup},
 	BuildStorage, Perbill, Storage,
 };
 
@@ -98,6 +98,9 @@ impl pallet_treasury::Config for Test {
 	type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
 	type BalanceConverter = UnityAssetBalanceConversion;
 	type PayoutPeriod = ConstU64<10>;
+	type Clawback = ();
+	type SpendSubmitter = ();
+	type SpendConsideration = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -120,6 +123,9 @@ impl pallet_treasury::Config<Instance1> for Test {
 	type Paymaster = PayFromAccount<Balances, TreasuryInstance1Account>;
 	type BalanceConverter = UnityAssetBalanceConversion;
 	type PayoutPeriod = ConstU64<10>;
+	type Clawback = ();
+	type SpendSubmitter = ();
+	type SpendConsideration = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -127,9 +133,42 @@ impl pallet_treasury::Config<Instance1> for Test {
 parameter_types! {
 	// This will be 50% of the bounty fee.
 	pub const CuratorDepositMultiplier: Permill = Permill::from_percent(50);
+	pub const BountyWithdrawalFee: Permill = Permill::from_percent(10);
+	pub const CuratorSlashReporterShare: Permill = Permill::from_percent(50);
 	pub const CuratorDepositMax: Balance = 1_000;
 	pub const CuratorDepositMin: Balance = 3;
+	pub const NativeAssetKind: Option<()> = Some(());
+}
+
+/// The accounts from which on beneficiaries are considered remote and paid by the `Paymaster`.
+const REMOTE_BENEFICIARIES: u128 = 1_000;
+
+/// Treats the beneficiaries below [`REMOTE_BENEFICIARIES`] as local accounts.
+pub struct LocalBeneficiaries;
+impl TryConvert<u128, u128> for LocalBeneficiaries {
+	fn try_convert(who: u128) -> Result<u128, u128> {
+		if who < REMOTE_BENEFICIARIES {
+			Ok(who)
+		} else {
+			Err(who)
+		}
+	}
+}
 
+parameter_types! {
+	pub static GuardVerdict: PayoutVerdict<u64> = PayoutVerdict::Allow;
+}
+
+/// Returns the [`GuardVerdict`] for all payouts.
+pub struct TestPayoutGuard;
+impl PayoutGuard<u128, u64, u64> for TestPayoutGuard {
+	fn check_payout(
+		_bounty_id: BountyIndex,
+		_beneficiary: &u128,
+		_value: u64,
+	) -> PayoutVerdict<u64> {
+		GuardVerdict::get()
+	}
 }
 
 impl Config for Test {
@@ -137,15 +176,26 @@ impl Config for Test {
 	type BountyDepositBase = ConstU64<80>;
 	type BountyDepositPayoutDelay = ConstU64<3>;
 	type BountyUpdatePeriod = ConstU64<20>;
+	type FundedExpiry = ConstU64<30>;
 	type CuratorDepositMultiplier = CuratorDepositMultiplier;
 	type CuratorDepositMax = CuratorDepositMax;
 	type CuratorDepositMin = CuratorDepositMin;
 	type BountyValueMinimum = ConstU64<1>;
 	type DataDepositPerByte = ConstU64<1>;
+	type BountyWithdrawalFee = BountyWithdrawalFee;
+	type CuratorSlashReporterShare = CuratorSlashReporterShare;
+	type CuratorSlashAppealPeriod = ConstU64<20>;
 	type MaximumReasonLength = ConstU32<16384>;
 	type WeightInfo = ();
 	type ChildBountyManager = ();
+	type PayoutGuard = TestPayoutGuard;
 	type OnSlash = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type NativeBalance = Balances;
+	type OnDepositSlash = ();
+	type BlockNumberProvider = System;
+	type BeneficiaryAccount = LocalBeneficiaries;
+	type NativeAssetKind = NativeAssetKind;
 }
 
 impl Config<Instance1> for Test {
@@ -153,15 +203,26 @@ impl Config<Instance1> for Test {
 	type BountyDepositBase = ConstU64<80>;
 	type BountyDepositPayoutDelay = ConstU64<3>;
 	type BountyUpdatePeriod = ConstU64<20>;
+	type FundedExpiry = ConstU64<30>;
 	type CuratorDepositMultiplier = CuratorDepositMultiplier;
 	type CuratorDepositMax = CuratorDepositMax;
 	type CuratorDepositMin = CuratorDepositMin;
 	type BountyValueMinimum = ConstU64<1>;
 	type DataDepositPerByte = ConstU64<1>;
+	type BountyWithdrawalFee = BountyWithdrawalFee;
+	type CuratorSlashReporterShare = CuratorSlashReporterShare;
+	type CuratorSlashAppealPeriod = ConstU64<20>;
 	type MaximumReasonLength = ConstU32<16384>;
 	type WeightInfo = ();
 	type ChildBountyManager = ();
+	type PayoutGuard = TestPayoutGuard;
 	type OnSlash = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type NativeBalance = Balances;
+	type OnDepositSlash = ();
+	type BlockNumberProvider = System;
+	type BeneficiaryAccount = LocalBeneficiaries;
+	type NativeAssetKind = ();
 }
 
 type TreasuryError = pallet_treasury::Error<Test>;
@@ -344,6 +405,7 @@ fn propose_bounty_works() {
 				value: 10,
 				bond: deposit,
 				status: BountyStatus::Proposed,
+				update_period: None,
 			}
 		);
 
@@ -410,6 +472,52 @@ fn close_bounty_works() {
 	});
 }
 
+#[test]
+fn withdraw_bounty_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_noop!(
+			Bounties::withdraw_bounty(RuntimeOrigin::signed(0), 0),
+			Error::<Test>::InvalidIndex
+		);
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 10, b"1234567890".to_vec()));
+		assert_noop!(
+			Bounties::withdraw_bounty(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::RequireProposer
+		);
+
+		// the proposer gets the bond of 90 back, less the withdrawal fee of 10%.
+		assert_ok!(Bounties::withdraw_bounty(RuntimeOrigin::signed(0), 0));
+		assert_eq!(last_event(), BountiesEvent::BountyWithdrawn { index: 0, fee: 9 });
+		assert_eq!(Balances::reserved_balance(0), 0);
+		assert_eq!(Balances::free_balance(0), 91);
+		assert_eq!(pallet_bounties::Bounties::<Test>::get(0), None);
+		assert_eq!(pallet_bounties::BountyDescriptions::<Test>::get(0), None);
+
+		// asset bounty proposals can be withdrawn as well.
+		assert_ok!(Bounties::propose_asset_bounty(
+			RuntimeOrigin::signed(0),
+			Box::new(()),
+			10,
+			b"1234567890".to_vec()
+		));
+		assert_ok!(Bounties::withdraw_bounty(RuntimeOrigin::signed(0), 1));
+		assert_eq!(last_event(), BountiesEvent::BountyWithdrawn { index: 1, fee: 9 });
+		assert_eq!(Balances::free_balance(0), 82);
+		assert_eq!(AssetBounties::<Test>::get(1), None);
+
+		// approved bounties can not be withdrawn.
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(1), 10, b"1234567890".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 2));
+		assert_noop!(
+			Bounties::withdraw_bounty(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::UnexpectedStatus
+		);
+	});
+}
+
 #[test]
 fn approve_bounty_works() {
 	new_test_ext().execute_with(|| {
@@ -435,6 +543,7 @@ fn approve_bounty_works() {
 				curator_deposit: 0,
 				bond: deposit,
 				status: BountyStatus::Approved,
+				update_period: None,
 			}
 		);
 		assert_eq!(pallet_bounties::BountyApprovals::<Test>::get(), vec![0]);
@@ -463,11 +572,20 @@ fn approve_bounty_works() {
 				value: 50,
 				bond: deposit,
 				status: BountyStatus::Funded,
+				update_period: None,
 			}
 		);
 
 		assert_eq!(Treasury::pot(), 100 - 50 - 25); // burn 25
 		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 50);
+		System::assert_has_event(
+			BountiesEvent::BountyBecameActive {
+				index: 0,
+				account: Bounties::bounty_account_id(0),
+				value: 50,
+			}
+			.into(),
+		);
 	});
 }
 
@@ -506,6 +624,7 @@ fn assign_curator_works() {
 				value: 50,
 				bond: 85,
 				status: BountyStatus::CuratorProposed { curator: 4 },
+				update_period: None,
 			}
 		);
 
@@ -532,7 +651,8 @@ fn assign_curator_works() {
 				curator_deposit: expected_deposit,
 				value: 50,
 				bond: 85,
-				status: BountyStatus::Active { curator: 4, update_due: 22 },
+				status: BountyStatus::Active { curator: 4, update_due: 22, committee: None },
+				update_period: None,
 			}
 		);
 
@@ -568,6 +688,7 @@ fn unassign_curator_works() {
 				value: 50,
 				bond: 85,
 				status: BountyStatus::Funded,
+				update_period: None,
 			}
 		);
 
@@ -586,6 +707,7 @@ fn unassign_curator_works() {
 				value: 50,
 				bond: 85,
 				status: BountyStatus::Funded,
+				update_period: None,
 			}
 		);
 
@@ -630,6 +752,7 @@ fn award_and_claim_bounty_works() {
 				value: 50,
 				bond: 85,
 				status: BountyStatus::PendingPayout { curator: 4, beneficiary: 3, unlock_at: 5 },
+				update_period: None,
 			}
 		);
 
@@ -731,6 +854,7 @@ fn cancel_and_refund() {
 				value: 50,
 				bond: 85,
 				status: BountyStatus::Funded,
+				update_period: None,
 			}
 		);
 
@@ -817,6 +941,7 @@ fn expire_and_unassign() {
 		System::set_block_number(23);
 		<Treasury as OnInitialize<u64>>::on_initialize(23);
 
+		let reporter_balance = Balances::free_balance(0);
 		assert_ok!(Bounties::unassign_curator(RuntimeOrigin::signed(0), 0));
 
 		assert_eq!(
@@ -828,11 +953,105 @@ fn expire_and_unassign() {
 				value: 50,
 				bond: 85,
 				status: BountyStatus::Funded,
+				update_period: None,
 			}
 		);
 
 		assert_eq!(Balances::free_balance(1), 93);
 		assert_eq!(Balances::reserved_balance(1), 0); // slashed
+		// The reporter is paid half of the slashed deposit.
+		assert_eq!(Balances::free_balance(0), reporter_balance + 2);
+	});
+}
+
+#[test]
+fn curator_slash_appeal_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 1, 10));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(1), 0));
+
+		// The curator is reported as inactive and slashed. Only the part of the deposit which is
+		// not paid to the reporter is recorded.
+		System::set_block_number(23);
+		assert_ok!(Bounties::unassign_curator(RuntimeOrigin::signed(0), 0));
+		assert_eq!(Balances::free_balance(1), 93);
+		assert_eq!(
+			SlashedCuratorDeposits::<Test>::get(0, 1),
+			Some(SlashedCuratorDeposit { amount: 3, appealed: false, appeal_until: 43 })
+		);
+
+		assert_noop!(
+			Bounties::resolve_curator_slash_appeal(RuntimeOrigin::root(), 0, 1, true),
+			Error::<Test>::NotAppealed
+		);
+		assert_noop!(
+			Bounties::appeal_curator_slash(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NoSlashedDeposit
+		);
+		assert_ok!(Bounties::appeal_curator_slash(RuntimeOrigin::signed(1), 0));
+		assert_eq!(
+			last_event(),
+			BountiesEvent::CuratorSlashAppealed { bounty_id: 0, curator: 1, amount: 3 }
+		);
+		assert_noop!(
+			Bounties::appeal_curator_slash(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::AlreadyAppealed
+		);
+
+		// The appeal is upheld and the deposit reimbursed from the treasury.
+		assert_noop!(
+			Bounties::resolve_curator_slash_appeal(RuntimeOrigin::signed(0), 0, 1, true),
+			BadOrigin
+		);
+		let pot = Balances::free_balance(Treasury::account_id());
+		assert_ok!(Bounties::resolve_curator_slash_appeal(RuntimeOrigin::root(), 0, 1, true));
+		assert_eq!(
+			last_event(),
+			BountiesEvent::CuratorSlashReimbursed { bounty_id: 0, curator: 1, amount: 3 }
+		);
+		assert_eq!(Balances::free_balance(1), 96);
+		assert_eq!(Balances::free_balance(Treasury::account_id()), pot - 3);
+		assert_eq!(SlashedCuratorDeposits::<Test>::get(0, 1), None);
+
+		// A rejected appeal reimburses nothing.
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 1, 10));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Bounties::unassign_curator(RuntimeOrigin::root(), 0));
+		assert_ok!(Bounties::appeal_curator_slash(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Bounties::resolve_curator_slash_appeal(RuntimeOrigin::root(), 0, 1, false));
+		assert_eq!(
+			last_event(),
+			BountiesEvent::CuratorSlashAppealRejected { bounty_id: 0, curator: 1 }
+		);
+		assert_eq!(Balances::free_balance(1), 91);
+		assert_eq!(SlashedCuratorDeposits::<Test>::get(0, 1), None);
+		assert_noop!(
+			Bounties::appeal_curator_slash(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NoSlashedDeposit
+		);
+
+		// A slash which is not appealed in time may be removed by anyone.
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 1, 10));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Bounties::unassign_curator(RuntimeOrigin::root(), 0));
+		assert_noop!(
+			Bounties::remove_expired_curator_slash(RuntimeOrigin::signed(2), 0, 1),
+			Error::<Test>::AppealPeriodNotOver
+		);
+		System::set_block_number(44);
+		assert_noop!(
+			Bounties::appeal_curator_slash(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::AppealPeriodOver
+		);
+		assert_ok!(Bounties::remove_expired_curator_slash(RuntimeOrigin::signed(2), 0, 1));
+		assert_eq!(last_event(), BountiesEvent::CuratorSlashExpired { bounty_id: 0, curator: 1 });
+		assert_eq!(SlashedCuratorDeposits::<Test>::get(0, 1), None);
 	});
 }
 
@@ -877,7 +1096,8 @@ fn extend_expiry() {
 				curator_deposit: 5,
 				value: 50,
 				bond: 85,
-				status: BountyStatus::Active { curator: 4, update_due: 30 },
+				status: BountyStatus::Active { curator: 4, update_due: 30, committee: None },
+				update_period: None,
 			}
 		);
 
@@ -891,7 +1111,8 @@ fn extend_expiry() {
 				curator_deposit: 5,
 				value: 50,
 				bond: 85,
-				status: BountyStatus::Active { curator: 4, update_due: 30 }, // still the same
+				status: BountyStatus::Active { curator: 4, update_due: 30, committee: None }, // still the same
+				update_period: None,
 			}
 		);
 
@@ -909,6 +1130,53 @@ fn extend_expiry() {
 	});
 }
 
+#[test]
+fn bounty_update_period_can_be_overridden() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+
+		assert_noop!(
+			Bounties::approve_bounty_with_update_period(RuntimeOrigin::signed(1), 0, 5),
+			BadOrigin
+		);
+		assert_noop!(
+			Bounties::approve_bounty_with_update_period(RuntimeOrigin::root(), 0, 0),
+			Error::<Test>::ZeroUpdatePeriod
+		);
+		assert_ok!(Bounties::approve_bounty_with_update_period(RuntimeOrigin::root(), 0, 5));
+		assert_eq!(last_event(), BountiesEvent::BountyApproved { index: 0 });
+		assert_eq!(pallet_bounties::Bounties::<Test>::get(0).unwrap().update_period, Some(5));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		// the update period of 5 blocks applies instead of the global one of 20 blocks.
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 10));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_eq!(
+			pallet_bounties::Bounties::<Test>::get(0).unwrap().status,
+			BountyStatus::Active { curator: 4, update_due: 7, committee: None }
+		);
+
+		System::set_block_number(6);
+		assert_ok!(Bounties::extend_bounty_expiry(RuntimeOrigin::signed(4), 0, Vec::new()));
+		assert_eq!(
+			pallet_bounties::Bounties::<Test>::get(0).unwrap().status,
+			BountyStatus::Active { curator: 4, update_due: 11, committee: None }
+		);
+
+		// the curator can be unassigned once the shorter period passed.
+		System::set_block_number(12);
+		assert_ok!(Bounties::unassign_curator(RuntimeOrigin::signed(0), 0));
+
+		assert_ok!(Bounties::close_bounty(RuntimeOrigin::root(), 0));
+		assert_eq!(pallet_bounties::Bounties::<Test>::get(0), None);
+	});
+}
+
 #[test]
 fn test_migration_v4() {
 	let mut s = Storage::default();
@@ -922,6 +1190,7 @@ fn test_migration_v4() {
 		curator_deposit: 20,
 		bond: 50,
 		status: BountyStatus::<u128, u64>::Proposed,
+		update_period: None,
 	};
 
 	let data = vec![
@@ -951,6 +1220,310 @@ fn test_migration_v4() {
 	});
 }
 
+#[test]
+fn test_migration_v5() {
+	use crate::migrations::v5::{
+		old::{
+			Bounty as OldBounty, BountyStatus as OldBountyStatus, ChildBounty as OldChildBounty,
+			ChildBountyStatus as OldChildBountyStatus,
+		},
+		Bounties as OldBounties, ChildBounties as OldChildBounties,
+	};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	frame_support::parameter_types! {
+		pub const ChildBountiesPalletName: &'static str = "ChildBounties";
+	}
+
+	/// The block number provider the deadlines were measured with before the migration.
+	struct OldProvider;
+	impl BlockNumberProvider for OldProvider {
+		type BlockNumber = u64;
+
+		fn current_block_number() -> u64 {
+			100
+		}
+	}
+
+	/// The new provider produces two blocks in the time of one old block.
+	struct Double;
+	impl sp_runtime::traits::Convert<u64, u64> for Double {
+		fn convert(blocks: u64) -> u64 {
+			blocks * 2
+		}
+	}
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		StorageVersion::new(4).put::<Bounties>();
+
+		let bounty = |status| OldBounty::<u128, u64, u64> {
+			proposer: 0,
+			value: 20,
+			fee: 2,
+			curator_deposit: 2,
+			bond: 50,
+			status,
+		};
+		OldBounties::<Test, ()>::insert(0, bounty(OldBountyStatus::Proposed));
+		OldBounties::<Test, ()>::insert(
+			1,
+			bounty(OldBountyStatus::Active { curator: 4, update_due: 110 }),
+		);
+		OldBounties::<Test, ()>::insert(
+			2,
+			bounty(OldBountyStatus::PendingPayout { curator: 4, beneficiary: 5, unlock_at: 103 }),
+		);
+		// The deadline already passed.
+		OldBounties::<Test, ()>::insert(
+			3,
+			bounty(OldBountyStatus::Active { curator: 4, update_due: 90 }),
+		);
+
+		let child_bounty = |status| OldChildBounty::<u128, u64, u64> {
+			parent_bounty: 1,
+			value: 10,
+			fee: 1,
+			curator_deposit: 1,
+			status,
+		};
+		type OldChildBountiesOf = OldChildBounties<ChildBountiesPalletName, Test, ()>;
+		OldChildBountiesOf::insert(1, 0, child_bounty(OldChildBountyStatus::Active { curator: 6 }));
+		OldChildBountiesOf::insert(
+			1,
+			1,
+			child_bounty(OldChildBountyStatus::PendingPayout {
+				curator: 6,
+				beneficiary: 7,
+				unlock_at: 105,
+			}),
+		);
+
+		crate::migrations::v5::MigrateV4ToV5::<
+			Test,
+			(),
+			OldProvider,
+			Double,
+			ChildBountiesPalletName,
+		>::on_runtime_upgrade();
+
+		assert_eq!(Bounties::on_chain_storage_version(), 5);
+		assert_eq!(OldBounties::<Test, ()>::get(0).unwrap(), bounty(OldBountyStatus::Proposed));
+		assert_eq!(
+			OldBounties::<Test, ()>::get(1).unwrap(),
+			bounty(OldBountyStatus::Active { curator: 4, update_due: 30 })
+		);
+		assert_eq!(
+			OldBounties::<Test, ()>::get(2).unwrap(),
+			bounty(OldBountyStatus::PendingPayout { curator: 4, beneficiary: 5, unlock_at: 16 })
+		);
+		assert_eq!(
+			OldBounties::<Test, ()>::get(3).unwrap(),
+			bounty(OldBountyStatus::Active { curator: 4, update_due: 10 })
+		);
+		assert_eq!(
+			OldChildBountiesOf::get(1, 0).unwrap(),
+			child_bounty(OldChildBountyStatus::Active { curator: 6 })
+		);
+		assert_eq!(
+			OldChildBountiesOf::get(1, 1).unwrap(),
+			child_bounty(OldChildBountyStatus::PendingPayout {
+				curator: 6,
+				beneficiary: 7,
+				unlock_at: 20,
+			})
+		);
+	});
+}
+
+#[test]
+fn test_migration_v6() {
+	use crate::migrations::v5::{
+		old::{Bounty as OldBounty, BountyStatus as OldBountyStatus},
+		Bounties as OldBounties,
+	};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(5).put::<Bounties>();
+
+		OldBounties::<Test, ()>::insert(
+			0,
+			OldBounty::<u128, u64, u64> {
+				proposer: 0,
+				value: 20,
+				fee: 2,
+				curator_deposit: 2,
+				bond: 50,
+				status: OldBountyStatus::Active { curator: 4, update_due: 30 },
+			},
+		);
+
+		crate::migrations::v6::MigrateV5ToV6::<Test, ()>::on_runtime_upgrade();
+
+		assert_eq!(Bounties::on_chain_storage_version(), 6);
+		assert_eq!(
+			pallet_bounties::Bounties::<Test>::get(0).unwrap(),
+			Bounty {
+				proposer: 0,
+				value: 20,
+				fee: 2,
+				curator_deposit: 2,
+				bond: 50,
+				status: BountyStatus::Active { curator: 4, update_due: 30, committee: None },
+				update_period: None,
+			}
+		);
+	});
+}
+
+#[test]
+fn test_migration_v7() {
+	use crate::migrations::v6::{Bounties as OldBounties, CommitteeApprovals as OldApprovals};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	/// Moves the beneficiaries to the accounts paid by the `Paymaster`.
+	pub struct ToRemote;
+	impl Convert<u128, u128> for ToRemote {
+		fn convert(who: u128) -> u128 {
+			who + REMOTE_BENEFICIARIES
+		}
+	}
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(6).put::<Bounties>();
+
+		let bounty = |status| Bounty::<u128, u64, u64> {
+			proposer: 0,
+			value: 20,
+			fee: 2,
+			curator_deposit: 2,
+			bond: 50,
+			status,
+			update_period: None,
+		};
+		OldBounties::<Test, ()>::insert(
+			0,
+			bounty(BountyStatus::PendingPayout { curator: 4, beneficiary: 5, unlock_at: 16 }),
+		);
+		OldBounties::<Test, ()>::insert(
+			1,
+			bounty(BountyStatus::Active { curator: 4, update_due: 30, committee: None }),
+		);
+		OldApprovals::<Test, ()>::insert(1, 4, CommitteeAction::Award { beneficiary: 6 });
+
+		crate::migrations::v7::MigrateV6ToV7::<Test, (), ToRemote>::on_runtime_upgrade();
+
+		assert_eq!(Bounties::on_chain_storage_version(), 7);
+		assert_eq!(
+			pallet_bounties::Bounties::<Test>::get(0).unwrap(),
+			bounty(BountyStatus::PendingPayout { curator: 4, beneficiary: 1_005, unlock_at: 16 })
+		);
+		assert_eq!(
+			pallet_bounties::Bounties::<Test>::get(1).unwrap(),
+			bounty(BountyStatus::Active { curator: 4, update_due: 30, committee: None })
+		);
+		assert_eq!(
+			CommitteeApprovals::<Test>::get(1, 4),
+			Some(CommitteeAction::Award { beneficiary: 1_006 })
+		);
+	});
+}
+
+#[test]
+fn test_migration_v8() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(7).put::<Bounties>();
+		System::set_block_number(5);
+
+		let bounty = |status| Bounty::<u128, u64, u64> {
+			proposer: 0,
+			value: 20,
+			fee: 2,
+			curator_deposit: 0,
+			bond: 50,
+			status,
+			update_period: None,
+		};
+		pallet_bounties::Bounties::<Test>::insert(0, bounty(BountyStatus::Funded));
+		pallet_bounties::Bounties::<Test>::insert(
+			1,
+			bounty(BountyStatus::CuratorProposed { curator: 4 }),
+		);
+		pallet_bounties::Bounties::<Test>::insert(
+			2,
+			bounty(BountyStatus::Active { curator: 4, update_due: 30, committee: None }),
+		);
+
+		crate::migrations::v8::MigrateV7ToV8::<Test, ()>::on_runtime_upgrade();
+
+		assert_eq!(Bounties::on_chain_storage_version(), 8);
+		assert_eq!(FundedExpiries::<Test>::get(0), Some(35));
+		assert_eq!(FundedExpiries::<Test>::get(1), Some(35));
+		assert_eq!(FundedExpiries::<Test>::get(2), None);
+	});
+}
+
+#[test]
+fn test_migration_v9() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(8).put::<Bounties>();
+
+		let bounty = |value, status| Bounty::<u128, u64, u64> {
+			proposer: 0,
+			value,
+			fee: 2,
+			curator_deposit: 0,
+			bond: 50,
+			status,
+			update_period: None,
+		};
+		pallet_bounties::Bounties::<Test>::insert(0, bounty(20, BountyStatus::Funded));
+		Balances::make_free_balance_be(&Bounties::bounty_account_id(0), 20);
+		// 20 of the bounty were paid out through its child bounties.
+		pallet_bounties::Bounties::<Test>::insert(
+			1,
+			bounty(30, BountyStatus::Active { curator: 4, update_due: 30, committee: None }),
+		);
+		Balances::make_free_balance_be(&Bounties::bounty_account_id(1), 10);
+		pallet_bounties::Bounties::<Test>::insert(2, bounty(40, BountyStatus::Approved));
+		AssetBounties::<Test>::insert(
+			3,
+			AssetBounty {
+				proposer: 0,
+				asset_kind: (),
+				value: 10,
+				fee: 2,
+				curator_deposit: 0,
+				bond: 50,
+				status: AssetBountyStatus::CuratorProposed { curator: 4 },
+			},
+		);
+
+		crate::migrations::v9::MigrateV8ToV9::<Test, ()>::on_runtime_upgrade();
+
+		assert_eq!(Bounties::on_chain_storage_version(), 9);
+		assert_eq!(
+			pallet_bounties::BountyLedgers::<Test>::get(0),
+			Some(BountyLedger { funded: 20, ..Default::default() })
+		);
+		assert_eq!(
+			pallet_bounties::BountyLedgers::<Test>::get(1),
+			Some(BountyLedger { funded: 30, paid: 20, ..Default::default() })
+		);
+		assert_eq!(pallet_bounties::BountyLedgers::<Test>::get(2), None);
+		assert_eq!(
+			pallet_bounties::BountyLedgers::<Test>::get(3),
+			Some(BountyLedger { funded: 10, ..Default::default() })
+		);
+		assert_eq!(pallet_bounties::TotalEncumbered::<Test>::get(), 40);
+	});
+}
+
 #[test]
 fn genesis_funding_works() {
 	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
@@ -1003,6 +1576,7 @@ fn unassign_curator_self() {
 				value: 50,
 				bond: 85,
 				status: BountyStatus::Funded,
+				update_period: None,
 			}
 		);
 
@@ -1197,3 +1771,864 @@ fn propose_curator_instance1_insufficient_spend_limit_errors() {
 		);
 	});
 }
+
+#[test]
+fn asset_bounty_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+
+		assert_ok!(Bounties::propose_asset_bounty(
+			RuntimeOrigin::signed(0),
+			Box::new(()),
+			10,
+			b"1234567890".to_vec()
+		));
+		assert_eq!(
+			last_event(),
+			BountiesEvent::AssetBountyProposed { index: 0, asset_kind: (), value: 10 }
+		);
+		assert_eq!(Balances::reserved_balance(0), 90);
+
+		assert_noop!(
+			Bounties::approve_asset_bounty(RuntimeOrigin::root(), 0, 4, 10),
+			Error::<Test>::InvalidFee
+		);
+		assert_ok!(Bounties::approve_asset_bounty(RuntimeOrigin::root(), 0, 4, 4));
+		assert_eq!(Balances::reserved_balance(0), 0);
+
+		assert_ok!(Bounties::accept_asset_curator(RuntimeOrigin::signed(4), 0));
+		assert_eq!(Balances::reserved_balance(4), 3);
+
+		assert_ok!(Bounties::award_asset_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			Box::new(3),
+			Box::new(4)
+		));
+		assert_eq!(
+			last_event(),
+			BountiesEvent::AssetBountyAwarded { index: 0, beneficiary: 3, unlock_at: 4 }
+		);
+		assert_noop!(
+			Bounties::payout_asset_bounty(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::Premature
+		);
+
+		System::set_block_number(4);
+		assert_ok!(Bounties::payout_asset_bounty(RuntimeOrigin::signed(1), 0));
+		assert_noop!(
+			Bounties::payout_asset_bounty(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::PayoutInProgress
+		);
+		assert_eq!(Balances::free_balance(3), 6);
+		assert_eq!(Balances::free_balance(4), 14);
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Balances::free_balance(Treasury::account_id()), 91);
+
+		assert_ok!(Bounties::check_asset_bounty_payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(
+			last_event(),
+			BountiesEvent::AssetBountyClaimed { index: 0, payout: 6, beneficiary: 3 }
+		);
+		assert_eq!(AssetBounties::<Test>::get(0), None);
+		assert_eq!(BountyDescriptions::<Test>::get(0), None);
+	});
+}
+
+#[test]
+fn close_asset_bounty_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&4, 10);
+
+		assert_ok!(Bounties::propose_asset_bounty(
+			RuntimeOrigin::signed(0),
+			Box::new(()),
+			10,
+			b"12345".to_vec()
+		));
+		assert_ok!(Bounties::close_asset_bounty(RuntimeOrigin::root(), 0));
+		assert_eq!(last_event(), BountiesEvent::BountyRejected { index: 0, bond: 85 });
+		assert_eq!(Balances::free_balance(0), 15);
+		assert_eq!(Balances::reserved_balance(0), 0);
+
+		assert_ok!(Bounties::propose_asset_bounty(
+			RuntimeOrigin::signed(1),
+			Box::new(()),
+			10,
+			b"12345".to_vec()
+		));
+		assert_ok!(Bounties::approve_asset_bounty(RuntimeOrigin::root(), 1, 4, 4));
+		assert_ok!(Bounties::accept_asset_curator(RuntimeOrigin::signed(4), 1));
+		assert_ok!(Bounties::close_asset_bounty(RuntimeOrigin::root(), 1));
+		assert_eq!(last_event(), BountiesEvent::BountyCanceled { index: 1 });
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(AssetBounties::<Test>::get(1), None);
+	});
+}
+
+#[test]
+fn asset_bounty_curator_can_be_extended_and_unassigned() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&4, 10);
+		Balances::make_free_balance_be(&6, 10);
+		assert_ok!(Bounties::propose_asset_bounty(
+			RuntimeOrigin::signed(0),
+			Box::new(()),
+			10,
+			b"12345".to_vec()
+		));
+		assert_noop!(
+			Bounties::unassign_asset_curator(RuntimeOrigin::root(), 0),
+			Error::<Test>::UnexpectedStatus
+		);
+		assert_ok!(Bounties::approve_asset_bounty(RuntimeOrigin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_asset_curator(RuntimeOrigin::signed(4), 0));
+		assert_eq!(Balances::reserved_balance(4), 3);
+
+		// Only the curator can extend the expiry.
+		System::set_block_number(10);
+		assert_noop!(
+			Bounties::extend_asset_bounty_expiry(RuntimeOrigin::signed(5), 0, Vec::new()),
+			Error::<Test>::RequireCurator
+		);
+		assert_ok!(Bounties::extend_asset_bounty_expiry(RuntimeOrigin::signed(4), 0, Vec::new()));
+		assert_eq!(last_event(), BountiesEvent::BountyExtended { index: 0 });
+		assert_eq!(
+			AssetBounties::<Test>::get(0).unwrap().status,
+			AssetBountyStatus::Active { curator: 4, update_due: 30 }
+		);
+
+		// Anyone can unassign the curator once the update is overdue, slashing the curator.
+		System::set_block_number(30);
+		assert_noop!(
+			Bounties::unassign_asset_curator(RuntimeOrigin::signed(5), 0),
+			Error::<Test>::Premature
+		);
+		System::set_block_number(31);
+		let reporter_balance = Balances::free_balance(5);
+		assert_ok!(Bounties::unassign_asset_curator(RuntimeOrigin::signed(5), 0));
+		assert_eq!(last_event(), BountiesEvent::CuratorUnassigned { bounty_id: 0 });
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Balances::free_balance(4), 7);
+		assert!(Balances::free_balance(5) > reporter_balance);
+		assert_eq!(AssetBounties::<Test>::get(0).unwrap().status, AssetBountyStatus::Approved);
+		assert_noop!(
+			Bounties::extend_asset_bounty_expiry(RuntimeOrigin::signed(4), 0, Vec::new()),
+			Error::<Test>::UnexpectedStatus
+		);
+
+		// A new curator can be proposed, who may refuse the role.
+		assert_ok!(Bounties::propose_asset_curator(RuntimeOrigin::root(), 0, 6, 4));
+		assert_eq!(last_event(), BountiesEvent::CuratorProposed { bounty_id: 0, curator: 6 });
+		assert_noop!(
+			Bounties::propose_asset_curator(RuntimeOrigin::root(), 0, 6, 4),
+			Error::<Test>::UnexpectedStatus
+		);
+		assert_noop!(Bounties::unassign_asset_curator(RuntimeOrigin::signed(5), 0), BadOrigin);
+		assert_ok!(Bounties::unassign_asset_curator(RuntimeOrigin::signed(6), 0));
+
+		// A curator giving up their role recovers the deposit.
+		assert_ok!(Bounties::propose_asset_curator(RuntimeOrigin::root(), 0, 6, 4));
+		assert_ok!(Bounties::accept_asset_curator(RuntimeOrigin::signed(6), 0));
+		assert_eq!(Balances::reserved_balance(6), 3);
+		assert_ok!(Bounties::unassign_asset_curator(RuntimeOrigin::signed(6), 0));
+		assert_eq!(Balances::reserved_balance(6), 0);
+		assert_eq!(Balances::free_balance(6), 10);
+
+		// The bounty can still be closed without a curator.
+		assert_ok!(Bounties::close_asset_bounty(RuntimeOrigin::root(), 0));
+		assert_eq!(AssetBounties::<Test>::get(0), None);
+	});
+}
+
+#[test]
+fn curator_committee_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 10));
+		assert_noop!(
+			Bounties::set_curator_committee(RuntimeOrigin::root(), 0, vec![5, 6], 2),
+			Error::<Test>::UnexpectedStatus
+		);
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		assert_noop!(
+			Bounties::set_curator_committee(RuntimeOrigin::signed(4), 0, vec![5, 6], 2),
+			BadOrigin
+		);
+		assert_noop!(
+			Bounties::set_curator_committee(RuntimeOrigin::root(), 0, vec![5, 6], 4),
+			Error::<Test>::InvalidCommittee
+		);
+		assert_noop!(
+			Bounties::set_curator_committee(RuntimeOrigin::root(), 0, (10..26).collect(), 1),
+			Error::<Test>::InvalidCommittee
+		);
+		// the curator is always a member.
+		assert_ok!(Bounties::set_curator_committee(RuntimeOrigin::root(), 0, vec![6, 5, 4], 2));
+		assert_eq!(
+			last_event(),
+			BountiesEvent::CuratorCommitteeSet { bounty_id: 0, members: 3, threshold: 2 }
+		);
+		assert_eq!(
+			pallet_bounties::Bounties::<Test>::get(0).unwrap().status,
+			BountyStatus::Active {
+				curator: 4,
+				update_due: 22,
+				committee: Some(CuratorCommittee {
+					members: vec![4, 5, 6].try_into().unwrap(),
+					threshold: 2
+				}),
+			}
+		);
+
+		// the expiry is extended once the threshold approved it.
+		System::set_block_number(10);
+		assert_noop!(
+			Bounties::extend_bounty_expiry(RuntimeOrigin::signed(7), 0, Vec::new()),
+			Error::<Test>::RequireCurator
+		);
+		assert_ok!(Bounties::extend_bounty_expiry(RuntimeOrigin::signed(5), 0, Vec::new()));
+		assert_eq!(
+			last_event(),
+			BountiesEvent::CommitteeActionApproved {
+				bounty_id: 0,
+				member: 5,
+				action: CommitteeAction::ExtendExpiry,
+				approvals: 1,
+			}
+		);
+		assert_ok!(Bounties::extend_bounty_expiry(RuntimeOrigin::signed(6), 0, Vec::new()));
+		assert_eq!(last_event(), BountiesEvent::BountyExtended { index: 0 });
+		assert!(matches!(
+			pallet_bounties::Bounties::<Test>::get(0).unwrap().status,
+			BountyStatus::Active { update_due: 30, .. }
+		));
+
+		// approvals of different beneficiaries do not add up.
+		assert_ok!(Bounties::award_bounty(RuntimeOrigin::signed(4), 0, 3));
+		assert_ok!(Bounties::award_bounty(RuntimeOrigin::signed(5), 0, 2));
+		assert!(matches!(
+			pallet_bounties::Bounties::<Test>::get(0).unwrap().status,
+			BountyStatus::Active { .. }
+		));
+		// a member may change their approval.
+		assert_ok!(Bounties::award_bounty(RuntimeOrigin::signed(5), 0, 3));
+		assert_eq!(
+			last_event(),
+			BountiesEvent::BountyAwarded { index: 0, beneficiary: 3, unlock_at: 13 }
+		);
+		assert_eq!(
+			pallet_bounties::Bounties::<Test>::get(0).unwrap().status,
+			BountyStatus::PendingPayout { curator: 4, beneficiary: 3, unlock_at: 13 }
+		);
+		assert_eq!(CommitteeApprovals::<Test>::iter_prefix(0).count(), 0);
+	});
+}
+
+#[test]
+fn award_and_claim_bounty_to_remote_beneficiary_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&Treasury1::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		assert_ok!(Bounties1::propose_bounty(RuntimeOrigin::signed(1), 10, b"12345".to_vec()));
+		assert_ok!(Bounties1::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		<Treasury1 as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_ok!(Bounties1::propose_curator(RuntimeOrigin::root(), 0, 4, 0));
+		assert_ok!(Bounties1::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// the second instance has no asset kind for remote payouts.
+		assert_noop!(
+			Bounties1::award_bounty(RuntimeOrigin::signed(4), 0, 1_003),
+			Error::<Test, Instance1>::InvalidBeneficiary
+		);
+
+		assert_ok!(Bounties::award_bounty(RuntimeOrigin::signed(4), 0, 1_003));
+		assert_eq!(
+			last_event(),
+			BountiesEvent::BountyAwarded { index: 0, beneficiary: 1_003, unlock_at: 5 }
+		);
+
+		System::set_block_number(5);
+		let treasury_balance = Balances::free_balance(Treasury::account_id());
+		assert_ok!(Bounties::claim_bounty(RuntimeOrigin::signed(1), 0));
+
+		assert_eq!(
+			last_event(),
+			BountiesEvent::BountyClaimed { index: 0, payout: 46, beneficiary: 1_003 }
+		);
+		assert!(System::events().iter().any(|record| record.event ==
+			RuntimeEvent::Bounties(BountiesEvent::BountyPayoutAttempted {
+				index: 0,
+				payment_id: ()
+			})));
+		// the payout is paid from the treasury.
+		assert_eq!(Balances::free_balance(1_003), 46);
+		assert_eq!(Balances::free_balance(Treasury::account_id()), treasury_balance);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+		assert_eq!(pallet_bounties::Bounties::<Test>::get(0), None);
+	});
+}
+
+#[test]
+fn check_bounty_payout_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_ok!(Bounties::award_bounty(RuntimeOrigin::signed(4), 0, 1_003));
+
+		System::set_block_number(5);
+		assert_noop!(
+			Bounties::check_bounty_payout(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::InvalidIndex
+		);
+		assert_ok!(Bounties::claim_bounty(RuntimeOrigin::signed(1), 0));
+		assert_eq!(
+			RemotePayouts::<Test>::get(0),
+			Some(RemotePayout {
+				beneficiary: 1_003,
+				asset_kind: (),
+				amount: 46,
+				state: AssetPayoutState::Attempted { id: () },
+			})
+		);
+
+		assert_ok!(Bounties::check_bounty_payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(last_event(), BountiesEvent::BountyPayoutSucceeded { index: 0 });
+		assert_eq!(RemotePayouts::<Test>::get(0), None);
+
+		// failed payments are retried.
+		RemotePayouts::<Test>::insert(
+			0,
+			RemotePayout {
+				beneficiary: 1_003,
+				asset_kind: (),
+				amount: 10,
+				state: AssetPayoutState::Failed,
+			},
+		);
+		assert_ok!(Bounties::check_bounty_payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(last_event(), BountiesEvent::BountyPayoutAttempted { index: 0, payment_id: () });
+		assert_eq!(Balances::free_balance(1_003), 56);
+		assert_eq!(
+			RemotePayouts::<Test>::get(0).map(|payout| payout.state),
+			Some(AssetPayoutState::Attempted { id: () })
+		);
+	});
+}
+
+#[test]
+fn sweep_expired_bounty_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		assert_noop!(
+			Bounties::sweep_expired_bounty(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::UnexpectedStatus
+		);
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(FundedExpiries::<Test>::get(0), Some(32));
+		assert_eq!(Balances::free_balance(0), 100);
+
+		// the expiry keeps running while a curator is only proposed.
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 4));
+		System::set_block_number(31);
+		assert_noop!(
+			Bounties::sweep_expired_bounty(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::Premature
+		);
+
+		// an active bounty does not expire, and expires anew once its curator is unassigned.
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_eq!(FundedExpiries::<Test>::get(0), None);
+		System::set_block_number(40);
+		assert_noop!(
+			Bounties::sweep_expired_bounty(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::UnexpectedStatus
+		);
+		assert_ok!(Bounties::unassign_curator(RuntimeOrigin::signed(4), 0));
+		assert_eq!(FundedExpiries::<Test>::get(0), Some(70));
+
+		System::set_block_number(70);
+		let treasury_balance = Balances::free_balance(Treasury::account_id());
+		assert_ok!(Bounties::sweep_expired_bounty(RuntimeOrigin::signed(1), 0));
+		assert_eq!(last_event(), BountiesEvent::BountyExpired { index: 0, value: 50 });
+
+		assert_eq!(Balances::free_balance(Treasury::account_id()), treasury_balance + 50);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+		assert_eq!(Balances::free_balance(4), 10);
+		assert_eq!(pallet_bounties::Bounties::<Test>::get(0), None);
+		assert_eq!(pallet_bounties::BountyDescriptions::<Test>::get(0), None);
+		assert_eq!(FundedExpiries::<Test>::get(0), None);
+	});
+}
+
+#[test]
+fn update_curator_fee_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 30);
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 4));
+		assert_noop!(
+			Bounties::update_curator_fee(RuntimeOrigin::root(), 0, 20),
+			Error::<Test>::UnexpectedStatus
+		);
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_eq!(Balances::reserved_balance(4), 3);
+
+		assert_noop!(Bounties::update_curator_fee(RuntimeOrigin::signed(1), 0, 20), BadOrigin);
+		assert_noop!(
+			Bounties::update_curator_fee(RuntimeOrigin::signed(4), 0, 50),
+			Error::<Test>::InvalidFee
+		);
+
+		// an approval of a different fee replaces the pending change.
+		assert_ok!(Bounties::update_curator_fee(RuntimeOrigin::signed(4), 0, 20));
+		assert_eq!(
+			last_event(),
+			BountiesEvent::CuratorFeeUpdateApproved { bounty_id: 0, fee: 20, by_curator: true }
+		);
+		assert_ok!(Bounties::update_curator_fee(RuntimeOrigin::root(), 0, 30));
+		assert_eq!(
+			CuratorFeeUpdates::<Test>::get(0),
+			Some(CuratorFeeUpdate { fee: 30, curator_approved: false, spend_approved: true })
+		);
+		assert_eq!(pallet_bounties::Bounties::<Test>::get(0).unwrap().fee, 4);
+
+		// the fee changes once both approved it, adjusting the curator deposit.
+		assert_ok!(Bounties::update_curator_fee(RuntimeOrigin::signed(4), 0, 30));
+		assert_eq!(
+			last_event(),
+			BountiesEvent::CuratorFeeUpdated { bounty_id: 0, old_fee: 4, new_fee: 30 }
+		);
+		assert_eq!(CuratorFeeUpdates::<Test>::get(0), None);
+		let bounty = pallet_bounties::Bounties::<Test>::get(0).unwrap();
+		assert_eq!((bounty.fee, bounty.curator_deposit), (30, 15));
+		assert_eq!(Balances::reserved_balance(4), 15);
+
+		assert_ok!(Bounties::update_curator_fee(RuntimeOrigin::root(), 0, 10));
+		assert_ok!(Bounties::update_curator_fee(RuntimeOrigin::signed(4), 0, 10));
+		let bounty = pallet_bounties::Bounties::<Test>::get(0).unwrap();
+		assert_eq!((bounty.fee, bounty.curator_deposit), (10, 5));
+		assert_eq!(Balances::reserved_balance(4), 5);
+		assert_eq!(Balances::free_balance(4), 25);
+
+		// pending changes are dropped once the curator is unassigned.
+		assert_ok!(Bounties::update_curator_fee(RuntimeOrigin::root(), 0, 20));
+		assert_ok!(Bounties::unassign_curator(RuntimeOrigin::signed(4), 0));
+		assert_eq!(CuratorFeeUpdates::<Test>::get(0), None);
+	});
+}
+
+#[test]
+fn approvals_capacity_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_eq!(Bounties::approvals_capacity(), 100);
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		assert_eq!(Bounties::approvals_capacity(), 99);
+
+		// funding the bounty frees its place in the queue.
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Bounties::approvals_capacity(), 100);
+	});
+}
+
+#[test]
+fn bounty_summaries_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 200);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 10, b"first".to_vec()));
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(1), 10, b"second".to_vec()));
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"third".to_vec()));
+		assert_ok!(Bounties::close_bounty(RuntimeOrigin::root(), 1));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 2));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 2, 4, 4));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 2));
+
+		assert_eq!(
+			Bounties::bounty_summary(0),
+			Some(BountySummary {
+				index: 0,
+				proposer: 0,
+				value: BountySummaryValue::Native { value: 10, fee: 0 },
+				status: BountySummaryStatus::Proposed,
+				curator: None,
+				update_due: None,
+				unlock_at: None,
+				description_hash: Some(H256(sp_io::hashing::blake2_256(b"first"))),
+			})
+		);
+		assert_eq!(Bounties::bounty_summary(1), None);
+		let active = BountySummary {
+			index: 2,
+			proposer: 0,
+			value: BountySummaryValue::Native { value: 50, fee: 4 },
+			status: BountySummaryStatus::Active,
+			curator: Some(4),
+			update_due: Some(22),
+			unlock_at: None,
+			description_hash: Some(H256(sp_io::hashing::blake2_256(b"third"))),
+		};
+		assert_eq!(Bounties::bounty_summary(2), Some(active.clone()));
+
+		assert_ok!(Bounties::propose_asset_bounty(
+			RuntimeOrigin::signed(0),
+			Box::new(()),
+			20,
+			b"fourth".to_vec()
+		));
+		assert_eq!(
+			Bounties::bounty_summary(3),
+			Some(BountySummary {
+				index: 3,
+				proposer: 0,
+				value: BountySummaryValue::Asset { asset_kind: (), value: 20, fee: 0 },
+				status: BountySummaryStatus::Proposed,
+				curator: None,
+				update_due: None,
+				unlock_at: None,
+				description_hash: Some(H256(sp_io::hashing::blake2_256(b"fourth"))),
+			})
+		);
+
+		// closed bounties are skipped and asset bounties follow the native ones.
+		let all = Bounties::bounty_summaries(None, 10);
+		let mut indices = all.iter().map(|summary| summary.index).collect::<Vec<_>>();
+		assert_eq!(indices.pop(), Some(3));
+		indices.sort();
+		assert_eq!(indices, vec![0, 2]);
+
+		// paging with the cursor of the last returned bounty visits every bounty once.
+		let mut paged = Vec::new();
+		let mut after = None;
+		loop {
+			let page = Bounties::bounty_summaries(after, 1);
+			let Some(last) = page.last() else { break };
+			after = Some(last.cursor());
+			paged.extend(page);
+		}
+		assert_eq!(paged, all);
+		assert!(Bounties::bounty_summaries(Some(BountyCursor::Asset(3)), 10).is_empty());
+
+		assert_ok!(Bounties::award_bounty(RuntimeOrigin::signed(4), 2, 3));
+		assert_eq!(
+			Bounties::bounty_summary(2),
+			Some(BountySummary {
+				status: BountySummaryStatus::PendingPayout,
+				update_due: None,
+				unlock_at: Some(5),
+				..active
+			})
+		);
+	});
+}
+
+#[test]
+fn recurring_bounty_is_renewed_after_claim() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 1000);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+
+		assert_noop!(Bounties::set_bounty_recurring(RuntimeOrigin::signed(0), 0, true), BadOrigin);
+		assert_noop!(
+			Bounties::set_bounty_recurring(RuntimeOrigin::root(), 1, true),
+			Error::<Test>::InvalidIndex
+		);
+		assert_ok!(Bounties::set_bounty_recurring(RuntimeOrigin::root(), 0, true));
+		assert_eq!(last_event(), BountiesEvent::BountyRecurringSet { index: 0, recurring: true });
+
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_ok!(Bounties::award_bounty(RuntimeOrigin::signed(4), 0, 3));
+
+		System::set_block_number(5);
+		assert_ok!(Bounties::claim_bounty(RuntimeOrigin::signed(1), 0));
+		assert_eq!(last_event(), BountiesEvent::BountyRenewed { index: 0 });
+		assert_eq!(Balances::free_balance(3), 46);
+		assert_eq!(Balances::free_balance(4), 14);
+
+		// the bounty is approved again with the same value and funded at the next spend period.
+		assert_eq!(
+			pallet_bounties::Bounties::<Test>::get(0).unwrap(),
+			Bounty {
+				proposer: 0,
+				fee: 0,
+				curator_deposit: 0,
+				value: 50,
+				bond: 0,
+				status: BountyStatus::Approved,
+				update_period: None,
+			}
+		);
+		assert_eq!(pallet_bounties::BountyApprovals::<Test>::get().to_vec(), vec![0]);
+		assert!(pallet_bounties::BountyDescriptions::<Test>::get(0).is_some());
+
+		System::set_block_number(6);
+		<Treasury as OnInitialize<u64>>::on_initialize(6);
+		assert_eq!(pallet_bounties::Bounties::<Test>::get(0).unwrap().status, BountyStatus::Funded);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 50);
+
+		// a one-off bounty is removed once claimed.
+		assert_ok!(Bounties::set_bounty_recurring(RuntimeOrigin::root(), 0, false));
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_ok!(Bounties::award_bounty(RuntimeOrigin::signed(4), 0, 3));
+
+		System::set_block_number(9);
+		assert_ok!(Bounties::claim_bounty(RuntimeOrigin::signed(1), 0));
+		assert_eq!(
+			last_event(),
+			BountiesEvent::BountyClaimed { index: 0, payout: 46, beneficiary: 3 }
+		);
+		assert_eq!(Balances::free_balance(3), 92);
+		assert_eq!(pallet_bounties::Bounties::<Test>::get(0), None);
+		assert_eq!(pallet_bounties::BountyDescriptions::<Test>::get(0), None);
+	});
+}
+
+#[test]
+fn deposits_reserved_before_holds_are_released_and_poked() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 30);
+		let bond_reason = RuntimeHoldReason::Bounties(HoldReason::ProposerBond);
+		let deposit_reason = RuntimeHoldReason::Bounties(HoldReason::CuratorDeposit);
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_eq!(Balances::balance_on_hold(&bond_reason, &0), 85);
+
+		// a bond reserved before the deposits were held is unreserved once the bounty is funded.
+		assert_ok!(Balances::release(&bond_reason, &0, 85, Precision::Exact));
+		assert_ok!(Balances::reserve(&0, 85));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Balances::reserved_balance(0), 0);
+		assert_eq!(Balances::free_balance(0), 100);
+
+		assert_noop!(
+			Bounties::poke_deposit(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::UnexpectedStatus
+		);
+		assert_noop!(
+			Bounties::poke_deposit(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::InvalidIndex
+		);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_eq!(Balances::balance_on_hold(&deposit_reason, &4), 3);
+
+		// a reserved curator deposit is moved on hold and recalculated for the current fee.
+		assert_ok!(Balances::release(&deposit_reason, &4, 3, Precision::Exact));
+		assert_ok!(Balances::reserve(&4, 3));
+		pallet_bounties::Bounties::<Test>::mutate(0, |bounty| bounty.as_mut().unwrap().fee = 30);
+		let info = Bounties::poke_deposit(RuntimeOrigin::signed(1), 0).unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		assert_eq!(
+			last_event(),
+			BountiesEvent::CuratorDepositPoked { bounty_id: 0, old_deposit: 3, new_deposit: 15 }
+		);
+		assert_eq!(pallet_bounties::Bounties::<Test>::get(0).unwrap().curator_deposit, 15);
+		assert_eq!(Balances::balance_on_hold(&deposit_reason, &4), 15);
+		assert_eq!(Balances::reserved_balance(4), 15);
+		assert_eq!(Balances::free_balance(4), 15);
+
+		// poking an up to date deposit is not free.
+		let info = Bounties::poke_deposit(RuntimeOrigin::signed(1), 0).unwrap();
+		assert_eq!(info.pays_fee, Pays::Yes);
+
+		// the curator gets the held deposit back.
+		assert_ok!(Bounties::unassign_curator(RuntimeOrigin::signed(4), 0));
+		assert_eq!(Balances::balance_on_hold(&deposit_reason, &4), 0);
+		assert_eq!(Balances::free_balance(4), 30);
+	});
+}
+
+#[test]
+fn payout_guard_can_deny_or_delay_claims() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_ok!(Bounties::award_bounty(RuntimeOrigin::signed(4), 0, 3));
+
+		System::set_block_number(5);
+
+		// A denied payout can not be claimed.
+		GuardVerdict::set(PayoutVerdict::Deny);
+		assert_noop!(
+			Bounties::claim_bounty(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::PayoutDenied
+		);
+
+		// A delayed payout pushes back the unlock block.
+		GuardVerdict::set(PayoutVerdict::Delay(8));
+		assert_ok!(Bounties::claim_bounty(RuntimeOrigin::signed(1), 0));
+		assert_eq!(last_event(), BountiesEvent::BountyPayoutDelayed { index: 0, unlock_at: 8 });
+		assert_eq!(
+			pallet_bounties::Bounties::<Test>::get(0).unwrap().status,
+			BountyStatus::PendingPayout { curator: 4, beneficiary: 3, unlock_at: 8 }
+		);
+		assert_eq!(Balances::free_balance(3), 0);
+		assert_noop!(Bounties::claim_bounty(RuntimeOrigin::signed(1), 0), Error::<Test>::Premature);
+
+		// Once the delay has passed the payout goes through.
+		System::set_block_number(8);
+		assert_ok!(Bounties::claim_bounty(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Balances::free_balance(3), 46);
+		assert_eq!(pallet_bounties::Bounties::<Test>::get(0), None);
+
+		GuardVerdict::set(PayoutVerdict::Allow);
+	});
+}
+
+#[test]
+fn bounty_ledgers_track_encumbered_funds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"first".to_vec()));
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 30, b"second".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 1));
+		assert_eq!(pallet_bounties::BountyLedgers::<Test>::get(0), None);
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(
+			pallet_bounties::BountyLedgers::<Test>::get(0),
+			Some(BountyLedger { funded: 50, ..Default::default() })
+		);
+		assert_eq!(pallet_bounties::TotalEncumbered::<Test>::get(), 80);
+
+		// The funds of a closed bounty are no longer encumbered.
+		assert_ok!(Bounties::close_bounty(RuntimeOrigin::root(), 1));
+		assert_eq!(pallet_bounties::BountyLedgers::<Test>::get(1), None);
+		assert_eq!(pallet_bounties::TotalEncumbered::<Test>::get(), 50);
+
+		// Neither are the funds of a claimed bounty.
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_ok!(Bounties::award_bounty(RuntimeOrigin::signed(4), 0, 3));
+		System::set_block_number(5);
+		assert_ok!(Bounties::claim_bounty(RuntimeOrigin::signed(1), 0));
+		assert_eq!(pallet_bounties::BountyLedgers::<Test>::get(0), None);
+		assert_eq!(pallet_bounties::TotalEncumbered::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn asset_bounty_ledgers_track_encumbered_funds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		for description in [b"first".to_vec(), b"second".to_vec()] {
+			assert_ok!(Bounties::propose_asset_bounty(
+				RuntimeOrigin::signed(0),
+				Box::new(()),
+				10,
+				description
+			));
+		}
+		assert_eq!(pallet_bounties::BountyLedgers::<Test>::get(0), None);
+
+		// Asset bounties are encumbered from their approval.
+		assert_ok!(Bounties::approve_asset_bounty(RuntimeOrigin::root(), 0, 4, 4));
+		assert_ok!(Bounties::approve_asset_bounty(RuntimeOrigin::root(), 1, 4, 4));
+		assert_eq!(
+			pallet_bounties::BountyLedgers::<Test>::get(0),
+			Some(BountyLedger { funded: 10, ..Default::default() })
+		);
+		assert_eq!(pallet_bounties::TotalEncumbered::<Test>::get(), 20);
+
+		// Until they are closed.
+		assert_ok!(Bounties::close_asset_bounty(RuntimeOrigin::root(), 1));
+		assert_eq!(pallet_bounties::BountyLedgers::<Test>::get(1), None);
+		assert_eq!(pallet_bounties::TotalEncumbered::<Test>::get(), 10);
+
+		// Or paid out.
+		assert_ok!(Bounties::accept_asset_curator(RuntimeOrigin::signed(4), 0));
+		assert_ok!(Bounties::award_asset_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			Box::new(3),
+			Box::new(4)
+		));
+		System::set_block_number(4);
+		assert_ok!(Bounties::payout_asset_bounty(RuntimeOrigin::signed(1), 0));
+		assert_eq!(pallet_bounties::TotalEncumbered::<Test>::get(), 10);
+		assert_ok!(Bounties::check_asset_bounty_payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(pallet_bounties::BountyLedgers::<Test>::get(0), None);
+		assert_eq!(pallet_bounties::TotalEncumbered::<Test>::get(), 0);
+	});
+}
@@ -58,10 +58,31 @@ pub trait WeightInfo {
 	fn accept_curator() -> Weight;
 	fn award_bounty() -> Weight;
 	fn claim_bounty() -> Weight;
+	fn claim_bounty_remote() -> Weight;
 	fn close_bounty_proposed() -> Weight;
 	fn close_bounty_active() -> Weight;
 	fn extend_bounty_expiry() -> Weight;
 	fn spend_funds(b: u32, ) -> Weight;
+	fn propose_asset_bounty(d: u32, ) -> Weight;
+	fn approve_asset_bounty() -> Weight;
+	fn accept_asset_curator() -> Weight;
+	fn extend_asset_bounty_expiry() -> Weight;
+	fn unassign_asset_curator() -> Weight;
+	fn propose_asset_curator() -> Weight;
+	fn award_asset_bounty() -> Weight;
+	fn payout_asset_bounty() -> Weight;
+	fn check_asset_bounty_payout() -> Weight;
+	fn close_asset_bounty() -> Weight;
+	fn set_curator_committee(m: u32, ) -> Weight;
+	fn sweep_expired_bounty() -> Weight;
+	fn update_curator_fee() -> Weight;
+	fn check_bounty_payout() -> Weight;
+	fn set_bounty_recurring() -> Weight;
+	fn poke_deposit() -> Weight;
+	fn withdraw_bounty() -> Weight;
+	fn appeal_curator_slash() -> Weight;
+	fn resolve_curator_slash_appeal() -> Weight;
+	fn remove_expired_curator_slash() -> Weight;
 }
 
 /// Weights for `pallet_bounties` using the Substrate node and recommended hardware.
@@ -113,7 +134,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:1)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn unassign_curator() -> Weight {
 		// Proof Size summary in bytes:
@@ -121,8 +142,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `3642`
 		// Minimum execution time: 29_041_000 picoseconds.
 		Weight::from_parts(29_979_000, 3642)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:1)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -158,14 +179,41 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChildBounties::ChildrenCuratorFees` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
 	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyLedgers` (r:1 w:1)
+	/// Proof: `Bounties::BountyLedgers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::TotalEncumbered` (r:1 w:1)
+	/// Proof: `Bounties::TotalEncumbered` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn claim_bounty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `969`
 		//  Estimated: `8799`
 		// Minimum execution time: 112_056_000 picoseconds.
 		Weight::from_parts(114_275_000, 8799)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildrenCuratorFees` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildrenCuratorFees` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyLedgers` (r:1 w:1)
+	/// Proof: `Bounties::BountyLedgers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::TotalEncumbered` (r:1 w:1)
+	/// Proof: `Bounties::TotalEncumbered` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::RemotePayouts` (r:1 w:1)
+	/// Proof: `Bounties::RemotePayouts` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	fn claim_bounty_remote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1146`
+		//  Estimated: `8799`
+		// Minimum execution time: 131_482_000 picoseconds.
+		Weight::from_parts(134_017_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:1)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -192,14 +240,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
 	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyLedgers` (r:1 w:1)
+	/// Proof: `Bounties::BountyLedgers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::TotalEncumbered` (r:1 w:1)
+	/// Proof: `Bounties::TotalEncumbered` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn close_bounty_active() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `885`
 		//  Estimated: `6196`
 		// Minimum execution time: 76_895_000 picoseconds.
 		Weight::from_parts(79_161_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:1)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -218,6 +270,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:200 w:200)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::TotalEncumbered` (r:1 w:1)
+	/// Proof: `Bounties::TotalEncumbered` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyLedgers` (r:0 w:100)
+	/// Proof: `Bounties::BountyLedgers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[0, 100]`.
 	fn spend_funds(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -227,12 +283,298 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(6_076_743, 1887)
 			// Standard Error: 18_569
 			.saturating_add(Weight::from_parts(34_771_846, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(b.into())))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(b.into())))
 			.saturating_add(Weight::from_parts(0, 5206).saturating_mul(b.into()))
 	}
+	/// Storage: `Bounties::BountyCount` (r:1 w:1)
+	/// Proof: `Bounties::BountyCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::AssetBounties` (r:0 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[0, 300]`.
+	fn propose_asset_bounty(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `309`
+		//  Estimated: `3593`
+		// Minimum execution time: 29_731_000 picoseconds.
+		Weight::from_parts(31_524_811, 3593)
+			// Standard Error: 201
+			.saturating_add(Weight::from_parts(683, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn approve_asset_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3679`
+		// Minimum execution time: 22_914_000 picoseconds.
+		Weight::from_parts(23_882_000, 3679)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_asset_curator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `634`
+		//  Estimated: `3679`
+		// Minimum execution time: 28_613_000 picoseconds.
+		Weight::from_parts(29_870_000, 3679)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	fn extend_asset_bounty_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `494`
+		//  Estimated: `3679`
+		// Minimum execution time: 12_802_000 picoseconds.
+		Weight::from_parts(13_596_000, 3679)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::SlashedCuratorDeposits` (r:0 w:1)
+	/// Proof: `Bounties::SlashedCuratorDeposits` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	fn unassign_asset_curator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `671`
+		//  Estimated: `6196`
+		// Minimum execution time: 30_214_000 picoseconds.
+		Weight::from_parts(31_370_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	fn propose_asset_curator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `482`
+		//  Estimated: `3679`
+		// Minimum execution time: 13_590_000 picoseconds.
+		Weight::from_parts(14_288_000, 3679)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	fn award_asset_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `598`
+		//  Estimated: `3679`
+		// Minimum execution time: 13_406_000 picoseconds.
+		Weight::from_parts(14_102_000, 3679)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn payout_asset_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `902`
+		//  Estimated: `8799`
+		// Minimum execution time: 98_617_000 picoseconds.
+		Weight::from_parts(101_345_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	fn check_asset_bounty_payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `693`
+		//  Estimated: `3679`
+		// Minimum execution time: 16_284_000 picoseconds.
+		Weight::from_parts(17_015_000, 3679)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	fn close_asset_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `681`
+		//  Estimated: `3679`
+		// Minimum execution time: 31_208_000 picoseconds.
+		Weight::from_parts(32_563_000, 3679)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(694), added: 3169, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::CommitteeApprovals` (r:1 w:0)
+	/// Proof: `Bounties::CommitteeApprovals` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 15]`.
+	fn set_curator_committee(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `455`
+		//  Estimated: `4159`
+		// Minimum execution time: 18_934_000 picoseconds.
+		Weight::from_parts(19_871_245, 4159)
+			// Standard Error: 3_118
+			.saturating_add(Weight::from_parts(412_306, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::FundedExpiries` (r:1 w:1)
+	/// Proof: `Bounties::FundedExpiries` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ParentChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::ParentChildBounties` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyLedgers` (r:1 w:1)
+	/// Proof: `Bounties::BountyLedgers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::TotalEncumbered` (r:1 w:1)
+	/// Proof: `Bounties::TotalEncumbered` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn sweep_expired_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `710`
+		//  Estimated: `6196`
+		// Minimum execution time: 68_412_000 picoseconds.
+		Weight::from_parts(70_195_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildrenCuratorFees` (r:1 w:0)
+	/// Proof: `ChildBounties::ChildrenCuratorFees` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::CuratorFeeUpdates` (r:1 w:1)
+	/// Proof: `Bounties::CuratorFeeUpdates` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn update_curator_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `693`
+		//  Estimated: `3642`
+		// Minimum execution time: 40_118_000 picoseconds.
+		Weight::from_parts(41_702_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Bounties::RemotePayouts` (r:1 w:1)
+	/// Proof: `Bounties::RemotePayouts` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	fn check_bounty_payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `147`
+		//  Estimated: `3531`
+		// Minimum execution time: 12_843_000 picoseconds.
+		Weight::from_parts(13_391_000, 3531)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:0)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::RecurringBounties` (r:0 w:1)
+	/// Proof: `Bounties::RecurringBounties` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	fn set_bounty_recurring() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `515`
+		//  Estimated: `3642`
+		// Minimum execution time: 11_802_000 picoseconds.
+		Weight::from_parts(12_371_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn poke_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `771`
+		//  Estimated: `3658`
+		// Minimum execution time: 47_305_000 picoseconds.
+		Weight::from_parts(48_914_000, 3658)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(388), added: 2863, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::RecurringBounties` (r:0 w:1)
+	/// Proof: `Bounties::RecurringBounties` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	fn withdraw_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `761`
+		//  Estimated: `3853`
+		// Minimum execution time: 52_118_000 picoseconds.
+		Weight::from_parts(53_904_000, 3853)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Bounties::SlashedCuratorDeposits` (r:1 w:1)
+	/// Proof: `Bounties::SlashedCuratorDeposits` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn appeal_curator_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183`
+		//  Estimated: `3534`
+		// Minimum execution time: 12_914_000 picoseconds.
+		Weight::from_parts(13_402_000, 3534)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bounties::SlashedCuratorDeposits` (r:1 w:1)
+	/// Proof: `Bounties::SlashedCuratorDeposits` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn resolve_curator_slash_appeal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `403`
+		//  Estimated: `6196`
+		// Minimum execution time: 63_507_000 picoseconds.
+		Weight::from_parts(65_130_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Bounties::SlashedCuratorDeposits` (r:1 w:1)
+	/// Proof: `Bounties::SlashedCuratorDeposits` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn remove_expired_curator_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183`
+		//  Estimated: `3534`
+		// Minimum execution time: 12_187_000 picoseconds.
+		Weight::from_parts(12_741_000, 3534)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -283,7 +625,7 @@ impl WeightInfo for () {
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:1)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn unassign_curator() -> Weight {
 		// Proof Size summary in bytes:
@@ -291,8 +633,8 @@ impl WeightInfo for () {
 		//  Estimated: `3642`
 		// Minimum execution time: 29_041_000 picoseconds.
 		Weight::from_parts(29_979_000, 3642)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:1)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -328,14 +670,41 @@ impl WeightInfo for () {
 	/// Proof: `ChildBounties::ChildrenCuratorFees` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
 	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyLedgers` (r:1 w:1)
+	/// Proof: `Bounties::BountyLedgers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::TotalEncumbered` (r:1 w:1)
+	/// Proof: `Bounties::TotalEncumbered` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn claim_bounty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `969`
 		//  Estimated: `8799`
 		// Minimum execution time: 112_056_000 picoseconds.
 		Weight::from_parts(114_275_000, 8799)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildrenCuratorFees` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildrenCuratorFees` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyLedgers` (r:1 w:1)
+	/// Proof: `Bounties::BountyLedgers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::TotalEncumbered` (r:1 w:1)
+	/// Proof: `Bounties::TotalEncumbered` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::RemotePayouts` (r:1 w:1)
+	/// Proof: `Bounties::RemotePayouts` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	fn claim_bounty_remote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1146`
+		//  Estimated: `8799`
+		// Minimum execution time: 131_482_000 picoseconds.
+		Weight::from_parts(134_017_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:1)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -362,14 +731,18 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
 	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyLedgers` (r:1 w:1)
+	/// Proof: `Bounties::BountyLedgers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::TotalEncumbered` (r:1 w:1)
+	/// Proof: `Bounties::TotalEncumbered` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn close_bounty_active() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `885`
 		//  Estimated: `6196`
 		// Minimum execution time: 76_895_000 picoseconds.
 		Weight::from_parts(79_161_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:1)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -388,6 +761,10 @@ impl WeightInfo for () {
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:200 w:200)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::TotalEncumbered` (r:1 w:1)
+	/// Proof: `Bounties::TotalEncumbered` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyLedgers` (r:0 w:100)
+	/// Proof: `Bounties::BountyLedgers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[0, 100]`.
 	fn spend_funds(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -397,10 +774,296 @@ impl WeightInfo for () {
 		Weight::from_parts(6_076_743, 1887)
 			// Standard Error: 18_569
 			.saturating_add(Weight::from_parts(34_771_846, 0).saturating_mul(b.into()))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(b.into())))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(b.into())))
 			.saturating_add(Weight::from_parts(0, 5206).saturating_mul(b.into()))
 	}
+	/// Storage: `Bounties::BountyCount` (r:1 w:1)
+	/// Proof: `Bounties::BountyCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::AssetBounties` (r:0 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[0, 300]`.
+	fn propose_asset_bounty(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `309`
+		//  Estimated: `3593`
+		// Minimum execution time: 29_731_000 picoseconds.
+		Weight::from_parts(31_524_811, 3593)
+			// Standard Error: 201
+			.saturating_add(Weight::from_parts(683, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn approve_asset_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3679`
+		// Minimum execution time: 22_914_000 picoseconds.
+		Weight::from_parts(23_882_000, 3679)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_asset_curator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `634`
+		//  Estimated: `3679`
+		// Minimum execution time: 28_613_000 picoseconds.
+		Weight::from_parts(29_870_000, 3679)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	fn extend_asset_bounty_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `494`
+		//  Estimated: `3679`
+		// Minimum execution time: 12_802_000 picoseconds.
+		Weight::from_parts(13_596_000, 3679)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::SlashedCuratorDeposits` (r:0 w:1)
+	/// Proof: `Bounties::SlashedCuratorDeposits` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	fn unassign_asset_curator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `671`
+		//  Estimated: `6196`
+		// Minimum execution time: 30_214_000 picoseconds.
+		Weight::from_parts(31_370_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	fn propose_asset_curator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `482`
+		//  Estimated: `3679`
+		// Minimum execution time: 13_590_000 picoseconds.
+		Weight::from_parts(14_288_000, 3679)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	fn award_asset_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `598`
+		//  Estimated: `3679`
+		// Minimum execution time: 13_406_000 picoseconds.
+		Weight::from_parts(14_102_000, 3679)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn payout_asset_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `902`
+		//  Estimated: `8799`
+		// Minimum execution time: 98_617_000 picoseconds.
+		Weight::from_parts(101_345_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	fn check_asset_bounty_payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `693`
+		//  Estimated: `3679`
+		// Minimum execution time: 16_284_000 picoseconds.
+		Weight::from_parts(17_015_000, 3679)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(214), added: 2689, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	fn close_asset_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `681`
+		//  Estimated: `3679`
+		// Minimum execution time: 31_208_000 picoseconds.
+		Weight::from_parts(32_563_000, 3679)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(694), added: 3169, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::CommitteeApprovals` (r:1 w:0)
+	/// Proof: `Bounties::CommitteeApprovals` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 15]`.
+	fn set_curator_committee(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `455`
+		//  Estimated: `4159`
+		// Minimum execution time: 18_934_000 picoseconds.
+		Weight::from_parts(19_871_245, 4159)
+			// Standard Error: 3_118
+			.saturating_add(Weight::from_parts(412_306, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::FundedExpiries` (r:1 w:1)
+	/// Proof: `Bounties::FundedExpiries` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ParentChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::ParentChildBounties` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyLedgers` (r:1 w:1)
+	/// Proof: `Bounties::BountyLedgers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::TotalEncumbered` (r:1 w:1)
+	/// Proof: `Bounties::TotalEncumbered` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn sweep_expired_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `710`
+		//  Estimated: `6196`
+		// Minimum execution time: 68_412_000 picoseconds.
+		Weight::from_parts(70_195_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildrenCuratorFees` (r:1 w:0)
+	/// Proof: `ChildBounties::ChildrenCuratorFees` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::CuratorFeeUpdates` (r:1 w:1)
+	/// Proof: `Bounties::CuratorFeeUpdates` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn update_curator_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `693`
+		//  Estimated: `3642`
+		// Minimum execution time: 40_118_000 picoseconds.
+		Weight::from_parts(41_702_000, 3642)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Bounties::RemotePayouts` (r:1 w:1)
+	/// Proof: `Bounties::RemotePayouts` (`max_values`: None, `max_size`: Some(66), added: 2541, mode: `MaxEncodedLen`)
+	fn check_bounty_payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `147`
+		//  Estimated: `3531`
+		// Minimum execution time: 12_843_000 picoseconds.
+		Weight::from_parts(13_391_000, 3531)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:0)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::RecurringBounties` (r:0 w:1)
+	/// Proof: `Bounties::RecurringBounties` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	fn set_bounty_recurring() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `515`
+		//  Estimated: `3642`
+		// Minimum execution time: 11_802_000 picoseconds.
+		Weight::from_parts(12_371_000, 3642)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn poke_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `771`
+		//  Estimated: `3658`
+		// Minimum execution time: 47_305_000 picoseconds.
+		Weight::from_parts(48_914_000, 3658)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:1)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::AssetBounties` (r:1 w:1)
+	/// Proof: `Bounties::AssetBounties` (`max_values`: None, `max_size`: Some(388), added: 2863, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::BountyDescriptions` (r:0 w:1)
+	/// Proof: `Bounties::BountyDescriptions` (`max_values`: None, `max_size`: Some(314), added: 2789, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::RecurringBounties` (r:0 w:1)
+	/// Proof: `Bounties::RecurringBounties` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	fn withdraw_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `761`
+		//  Estimated: `3853`
+		// Minimum execution time: 52_118_000 picoseconds.
+		Weight::from_parts(53_904_000, 3853)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Bounties::SlashedCuratorDeposits` (r:1 w:1)
+	/// Proof: `Bounties::SlashedCuratorDeposits` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn appeal_curator_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183`
+		//  Estimated: `3534`
+		// Minimum execution time: 12_914_000 picoseconds.
+		Weight::from_parts(13_402_000, 3534)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bounties::SlashedCuratorDeposits` (r:1 w:1)
+	/// Proof: `Bounties::SlashedCuratorDeposits` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn resolve_curator_slash_appeal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `403`
+		//  Estimated: `6196`
+		// Minimum execution time: 63_507_000 picoseconds.
+		Weight::from_parts(65_130_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Bounties::SlashedCuratorDeposits` (r:1 w:1)
+	/// Proof: `Bounties::SlashedCuratorDeposits` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn remove_expired_curator_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183`
+		//  Estimated: `3534`
+		// Minimum execution time: 12_187_000 picoseconds.
+		Weight::from_parts(12_741_000, 3534)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -230,7 +230,7 @@ benchmarks! {
 		setup_pot_account::<T>();
 		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
 		Treasury::<T>::on_initialize(BlockNumberFor::<T>::zero());
-		frame_system::Pallet::<T>::set_block_number(T::BountyUpdatePeriod::get() + 1u32.into());
+		T::BlockNumberProvider::set_block_number(T::BountyUpdatePeriod::get() + 1u32.into());
 		let caller = whitelisted_caller();
 	}: _(RawOrigin::Signed(caller), bounty_setup.bounty_id,
 			bounty_setup.child_bounty_id)
@@ -266,7 +266,7 @@ benchmarks! {
 		let beneficiary_account: T::AccountId = account("beneficiary", 0, SEED);
 		let beneficiary = T::Lookup::unlookup(beneficiary_account.clone());
 
-		frame_system::Pallet::<T>::set_block_number(T::BountyDepositPayoutDelay::get());
+		T::BlockNumberProvider::set_block_number(T::BountyDepositPayoutDelay::get());
 		ensure!(T::Currency::free_balance(&beneficiary_account).is_zero(),
 			"Beneficiary already has balance.");
 
@@ -312,5 +312,47 @@ benchmarks! {
 		}.into())
 	}
 
+	update_parent_curator_fee {
+		setup_pot_account::<T>();
+		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
+	}: _(RawOrigin::Signed(bounty_setup.curator), bounty_setup.bounty_id,
+			bounty_setup.child_bounty_fee)
+	verify {
+		assert_last_event::<T>(Event::ParentCuratorFeeUpdated {
+			index: bounty_setup.bounty_id,
+			fee: bounty_setup.child_bounty_fee,
+		}.into())
+	}
+
+	add_nested_child_bounty {
+		let d in 0 .. T::MaximumReasonLength::get();
+		if T::MaxDepth::get() < 2 {
+			return Err(BenchmarkError::Weightless)
+		}
+		setup_pot_account::<T>();
+		let bounty_setup = activate_child_bounty::<T>(0, d)?;
+		let value = bounty_setup.child_bounty_value / 2u32.into();
+	}: _(RawOrigin::Signed(bounty_setup.child_curator), bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id, value, bounty_setup.reason.clone())
+	verify {
+		assert_last_event::<T>(Event::NestedAdded {
+			index: bounty_setup.bounty_id,
+			parent_child_index: bounty_setup.child_bounty_id,
+			child_index: bounty_setup.child_bounty_id + 1,
+		}.into())
+	}
+
+	extend_child_bounty_expiry {
+		setup_pot_account::<T>();
+		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
+	}: _(RawOrigin::Signed(bounty_setup.child_curator), bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id, Vec::new())
+	verify {
+		assert_last_event::<T>(Event::Extended {
+			index: bounty_setup.bounty_id,
+			child_index: bounty_setup.child_bounty_id,
+		}.into())
+	}
+
 	impl_benchmark_test_suite!(ChildBounties, crate::tests::new_test_ext(), crate::tests::Test)
 }
@@ -43,6 +43,25 @@
 //! - `unassign_curator` - Unassign an accepted curator from a specific child bounty.
 //! - `close_child_bounty` - Cancel the child bounty for a specific treasury amount and close the
 //!   bounty.
+//! - `add_nested_child_bounty` - Add a child bounty to a child bounty, for dividing its work
+//!   further.
+//! - `add_child_bounties` - Add several child bounties and propose their curators at once.
+//! - `award_child_bounties` - Award several child bounties at once.
+//!
+//! If the parent bounty has a curator committee, adding, awarding and closing its child bounties
+//! and proposing their curators on behalf of the parent bounty curator take effect once the
+//! threshold of the committee approved the same call.
+//!
+//! ### Nested Child Bounties
+//!
+//! Child bounties can be subdivided further into nested child bounties, up to a nesting depth of
+//! [`Config::MaxDepth`]. Nested child bounties are stored under the same parent bounty as the
+//! child bounty they were added to and use the same calls, but the curator of their parent
+//! child-bounty takes the role of the parent bounty curator for them.
+//!
+//! The curator fees of nested child bounties are deducted from the curator fee of their parent
+//! child-bounty, which in turn is deducted from the curator fee of its own parent. A child-bounty
+//! can only be awarded or closed once all of its nested child bounties are gone.
 
 // Most of the business logic in this pallet has been
 // originally contributed by "https://github.com/shamb0",
@@ -65,7 +84,10 @@ use frame_support::traits::{
 };
 
 use sp_runtime::{
-	traits::{AccountIdConversion, BadOrigin, CheckedSub, Saturating, StaticLookup, Zero},
+	traits::{
+		AccountIdConversion, BadOrigin, BlockNumberProvider, CheckedSub, Saturating, StaticLookup,
+		Zero,
+	},
 	DispatchResult, RuntimeDebug,
 };
 
@@ -73,6 +95,8 @@ use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
 use pallet_bounties::BountyStatus;
 use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
 pub use weights::WeightInfo;
 
 pub use pallet::*;
@@ -144,6 +168,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type ChildBountyValueMinimum: Get<BalanceOf<Self>>;
 
+		/// Maximum nesting depth of child bounties.
+		///
+		/// The child bounties of a parent bounty have a depth of 1, their nested child bounties a
+		/// depth of 2 and so on. A value of 1 disables nested child bounties.
+		#[pallet::constant]
+		type MaxDepth: Get<u32>;
+
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -159,6 +190,10 @@ pub mod pallet {
 		InsufficientBountyBalance,
 		/// Number of child bounties exceeds limit `MaxActiveChildBountyCount`.
 		TooManyChildBounties,
+		/// The parent bounty curator fee does not cover the child-bounty curator fees.
+		InsufficientParentCuratorFee,
+		/// The nesting depth of the child-bounty would exceed `MaxDepth`.
+		TooDeeplyNested,
 	}
 
 	#[pallet::event]
@@ -177,6 +212,18 @@ pub mod pallet {
 		},
 		/// A child-bounty is cancelled.
 		Canceled { index: BountyIndex, child_index: BountyIndex },
+		/// The curator fee of a parent bounty is updated.
+		ParentCuratorFeeUpdated { index: BountyIndex, fee: BalanceOf<T> },
+		/// A nested child-bounty is added to a child-bounty.
+		NestedAdded {
+			index: BountyIndex,
+			parent_child_index: BountyIndex,
+			child_index: BountyIndex,
+		},
+		/// The curator of a child-bounty has been unassigned.
+		CuratorUnassigned { index: BountyIndex, child_index: BountyIndex },
+		/// A child-bounty expiry is extended.
+		Extended { index: BountyIndex, child_index: BountyIndex },
 	}
 
 	/// Number of total child bounties.
@@ -210,6 +257,35 @@ pub mod pallet {
 	pub type ChildrenCuratorFees<T: Config> =
 		StorageMap<_, Twox64Concat, BountyIndex, BalanceOf<T>, ValueQuery>;
 
+	/// The parent child-bounty of each nested child-bounty.
+	#[pallet::storage]
+	pub type ChildBountyParents<T: Config> = StorageMap<_, Twox64Concat, BountyIndex, BountyIndex>;
+
+	/// Number of nested child bounties per child-bounty.
+	#[pallet::storage]
+	pub type NestedChildBounties<T: Config> =
+		StorageMap<_, Twox64Concat, BountyIndex, u32, ValueQuery>;
+
+	/// The cumulative curator fee of the nested child bounties of each child-bounty.
+	#[pallet::storage]
+	pub type NestedChildrenCuratorFees<T: Config> =
+		StorageMap<_, Twox64Concat, BountyIndex, BalanceOf<T>, ValueQuery>;
+
+	/// The block by which the curator of each active child-bounty must give an update, keyed by
+	/// the parent bounty and the child-bounty.
+	///
+	/// Set from the update period of the parent bounty when the curator accepts, and extended
+	/// with `extend_child_bounty_expiry`.
+	#[pallet::storage]
+	pub type ChildBountyUpdateDue<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BountyIndex,
+		Twox64Concat,
+		BountyIndex,
+		BlockNumberFor<T>,
+	>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Add a new child-bounty.
@@ -217,6 +293,9 @@ pub mod pallet {
 		/// The dispatch origin for this call must be the curator of parent
 		/// bounty and the parent bounty must be in "active" state.
 		///
+		/// If the parent bounty has a curator committee, the origin may be any member and the call
+		/// takes effect once the threshold of the committee approved it.
+		///
 		/// Child-bounty gets added successfully & fund gets transferred from
 		/// parent bounty to child-bounty account, if parent bounty has enough
 		/// funds, else the call fails.
@@ -232,7 +311,8 @@ pub mod pallet {
 		/// - `value`: Value for executing the proposal.
 		/// - `description`: Text description for the child-bounty.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::add_child_bounty(description.len() as u32))]
+		#[pallet::weight(<T as Config>::WeightInfo::add_child_bounty(description.len() as u32)
+			.saturating_add(pallet_bounties::Pallet::<T>::committee_approval_weight()))]
 		pub fn add_child_bounty(
 			origin: OriginFor<T>,
 			#[pallet::compact] parent_bounty_id: BountyIndex,
@@ -240,54 +320,14 @@ pub mod pallet {
 			description: Vec<u8>,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
-
-			// Verify the arguments.
-			let bounded_description =
-				description.try_into().map_err(|_| BountiesError::<T>::ReasonTooBig)?;
-			ensure!(value >= T::ChildBountyValueMinimum::get(), BountiesError::<T>::InvalidValue);
-			ensure!(
-				ParentChildBounties::<T>::get(parent_bounty_id) <=
-					T::MaxActiveChildBountyCount::get() as u32,
-				Error::<T>::TooManyChildBounties,
-			);
-
-			let (curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
-			ensure!(signer == curator, BountiesError::<T>::RequireCurator);
-
-			// Read parent bounty account info.
-			let parent_bounty_account =
-				pallet_bounties::Pallet::<T>::bounty_account_id(parent_bounty_id);
-
-			// Ensure parent bounty has enough balance after adding child-bounty.
-			let bounty_balance = T::Currency::free_balance(&parent_bounty_account);
-			let new_bounty_balance = bounty_balance
-				.checked_sub(&value)
-				.ok_or(Error::<T>::InsufficientBountyBalance)?;
-			T::Currency::ensure_can_withdraw(
-				&parent_bounty_account,
-				value,
-				WithdrawReasons::TRANSFER,
-				new_bounty_balance,
-			)?;
-
-			// Get child-bounty ID.
-			let child_bounty_id = ChildBountyCount::<T>::get();
-			let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
-
-			// Transfer funds from parent bounty to child-bounty.
-			T::Currency::transfer(&parent_bounty_account, &child_bounty_account, value, KeepAlive)?;
-
-			// Increment the active child-bounty count.
-			ParentChildBounties::<T>::mutate(parent_bounty_id, |count| count.saturating_inc());
-			ChildBountyCount::<T>::put(child_bounty_id.saturating_add(1));
-
-			// Create child-bounty instance.
-			Self::create_child_bounty(
+			let call = Call::<T>::add_child_bounty {
 				parent_bounty_id,
-				child_bounty_id,
 				value,
-				bounded_description,
-			);
+				description: description.clone(),
+			};
+			if Self::approve_parent_curator_call(&signer, parent_bounty_id, None, call)? {
+				Self::do_add_child_bounty(parent_bounty_id, value, description)?;
+			}
 			Ok(())
 		}
 
@@ -295,6 +335,9 @@ pub mod pallet {
 		///
 		/// The dispatch origin for this call must be curator of parent bounty.
 		///
+		/// If the parent bounty has a curator committee, the origin may be any member and the call
+		/// takes effect once the threshold of the committee approved it.
+		///
 		/// Parent bounty must be in active state, for this child-bounty call to
 		/// work.
 		///
@@ -307,7 +350,8 @@ pub mod pallet {
 		/// - `curator`: Address of child-bounty curator.
 		/// - `fee`: payment fee to child-bounty curator for execution.
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::propose_curator())]
+		#[pallet::weight(<T as Config>::WeightInfo::propose_curator()
+			.saturating_add(pallet_bounties::Pallet::<T>::committee_approval_weight()))]
 		pub fn propose_curator(
 			origin: OriginFor<T>,
 			#[pallet::compact] parent_bounty_id: BountyIndex,
@@ -317,44 +361,26 @@ pub mod pallet {
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
 			let child_bounty_curator = T::Lookup::lookup(curator)?;
-
-			let (curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
-			ensure!(signer == curator, BountiesError::<T>::RequireCurator);
-
-			// Mutate the child-bounty instance.
-			ChildBounties::<T>::try_mutate_exists(
+			let call = Call::<T>::propose_curator {
 				parent_bounty_id,
 				child_bounty_id,
-				|maybe_child_bounty| -> DispatchResult {
-					let child_bounty =
-						maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
-
-					// Ensure child-bounty is in expected state.
-					ensure!(
-						child_bounty.status == ChildBountyStatus::Added,
-						BountiesError::<T>::UnexpectedStatus,
-					);
-
-					// Ensure child-bounty curator fee is less than child-bounty value.
-					ensure!(fee < child_bounty.value, BountiesError::<T>::InvalidFee);
-
-					// Add child-bounty curator fee to the cumulative sum. To be
-					// subtracted from the parent bounty curator when claiming
-					// bounty.
-					ChildrenCuratorFees::<T>::mutate(parent_bounty_id, |value| {
-						*value = value.saturating_add(fee)
-					});
-
-					// Update the child-bounty curator fee.
-					child_bounty.fee = fee;
-
-					// Update the child-bounty state.
-					child_bounty.status =
-						ChildBountyStatus::CuratorProposed { curator: child_bounty_curator };
-
-					Ok(())
-				},
-			)
+				curator: T::Lookup::unlookup(child_bounty_curator.clone()),
+				fee,
+			};
+			if Self::approve_parent_curator_call(
+				&signer,
+				parent_bounty_id,
+				Some(child_bounty_id),
+				call,
+			)? {
+				Self::do_propose_curator(
+					parent_bounty_id,
+					child_bounty_id,
+					child_bounty_curator,
+					fee,
+				)?;
+			}
+			Ok(())
 		}
 
 		/// Accept the curator role for the child-bounty.
@@ -385,7 +411,8 @@ pub mod pallet {
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
 
-			let (parent_curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
+			let (parent_curator, _) =
+				Self::ensure_parent_active(parent_bounty_id, child_bounty_id)?;
 			// Mutate child-bounty.
 			ChildBounties::<T>::try_mutate_exists(
 				parent_bounty_id,
@@ -411,6 +438,11 @@ pub mod pallet {
 
 						child_bounty.status =
 							ChildBountyStatus::Active { curator: curator.clone() };
+						ChildBountyUpdateDue::<T>::insert(
+							parent_bounty_id,
+							child_bounty_id,
+							Self::next_update_due(parent_bounty_id),
+						);
 						Ok(())
 					} else {
 						Err(BountiesError::<T>::UnexpectedStatus.into())
@@ -441,15 +473,19 @@ pub mod pallet {
 		/// it is abused.)
 		///
 		/// Finally, the origin can be anyone iff the child-bounty curator is
-		/// "inactive". Expiry update due of parent bounty is used to estimate
-		/// inactive state of child-bounty curator.
+		/// "inactive", i.e. the child-bounty update is due. Unlike the other
+		/// cases, this works irrespective of the parent bounty state. For
+		/// child bounties accepted before update dues were tracked per
+		/// child-bounty, the update due of the parent bounty is used instead.
 		///
 		/// This allows anyone in the community to call out that a child-bounty
 		/// curator is not doing their due diligence, and we should pick a new
 		/// one. In this case the child-bounty curator deposit is slashed.
 		///
 		/// State of child-bounty is moved to Added state on successful call
-		/// completion.
+		/// completion. The curator of a child-bounty with nested child bounties
+		/// can't be unassigned, since they would be left without an active
+		/// parent.
 		///
 		/// - `parent_bounty_id`: Index of parent bounty.
 		/// - `child_bounty_id`: Index of child bounty.
@@ -463,6 +499,10 @@ pub mod pallet {
 			let maybe_sender = ensure_signed(origin.clone())
 				.map(Some)
 				.or_else(|_| T::RejectOrigin::ensure_origin(origin).map(|_| None))?;
+			ensure!(
+				NestedChildBounties::<T>::get(child_bounty_id).is_zero(),
+				BountiesError::<T>::HasActiveChildBounty
+			);
 
 			ChildBounties::<T>::try_mutate_exists(
 				parent_bounty_id,
@@ -490,10 +530,13 @@ pub mod pallet {
 							ensure!(
 								maybe_sender.map_or(true, |sender| {
 									sender == *curator ||
-										Self::ensure_bounty_active(parent_bounty_id)
-											.map_or(false, |(parent_curator, _)| {
-												sender == parent_curator
-											})
+										Self::ensure_parent_active(
+											parent_bounty_id,
+											child_bounty_id,
+										)
+										.map_or(false, |(parent_curator, _)| {
+											sender == parent_curator
+										})
 								}),
 								BadOrigin
 							);
@@ -517,11 +560,25 @@ pub mod pallet {
 									// Continue to change bounty status below.
 								},
 								Some(sender) => {
-									let (parent_curator, update_due) =
-										Self::ensure_bounty_active(parent_bounty_id)?;
-									if sender == parent_curator ||
-										update_due < frame_system::Pallet::<T>::block_number()
-									{
+									let now = T::BlockNumberProvider::current_block_number();
+									let child_update_due = ChildBountyUpdateDue::<T>::get(
+										parent_bounty_id,
+										child_bounty_id,
+									);
+									let may_slash = match child_update_due {
+										Some(update_due) if update_due < now => true,
+										_ => {
+											let (parent_curator, parent_update_due) =
+												Self::ensure_parent_active(
+													parent_bounty_id,
+													child_bounty_id,
+												)?;
+											sender == parent_curator ||
+												(child_update_due.is_none() &&
+													parent_update_due < now)
+										},
+									};
+									if may_slash {
 										// Slash the child-bounty curator if
 										// + the call is made by the parent bounty curator.
 										// + or the curator is inactive.
@@ -535,7 +592,8 @@ pub mod pallet {
 							}
 						},
 						ChildBountyStatus::PendingPayout { ref curator, .. } => {
-							let (parent_curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
+							let (parent_curator, _) =
+								Self::ensure_parent_active(parent_bounty_id, child_bounty_id)?;
 							ensure!(
 								maybe_sender.map_or(true, |sender| parent_curator == sender),
 								BadOrigin,
@@ -546,9 +604,16 @@ pub mod pallet {
 					};
 					// Move the child-bounty state to Added.
 					child_bounty.status = ChildBountyStatus::Added;
+					ChildBountyUpdateDue::<T>::remove(parent_bounty_id, child_bounty_id);
 					Ok(())
 				},
-			)
+			)?;
+
+			Self::deposit_event(Event::<T>::CuratorUnassigned {
+				index: parent_bounty_id,
+				child_index: child_bounty_id,
+			});
+			Ok(())
 		}
 
 		/// Award child-bounty to a beneficiary.
@@ -558,6 +623,10 @@ pub mod pallet {
 		/// The dispatch origin for this call must be the parent curator or
 		/// curator of this child-bounty.
 		///
+		/// If the parent bounty has a curator committee, the origin may be any member instead of
+		/// the parent curator and the call takes effect once the threshold of the committee
+		/// approved it.
+		///
 		/// Parent bounty must be in active state, for this child-bounty call to
 		/// work.
 		///
@@ -569,7 +638,8 @@ pub mod pallet {
 		/// - `child_bounty_id`: Index of child bounty.
 		/// - `beneficiary`: Beneficiary account.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::award_child_bounty())]
+		#[pallet::weight(<T as Config>::WeightInfo::award_child_bounty()
+			.saturating_add(pallet_bounties::Pallet::<T>::committee_approval_weight()))]
 		pub fn award_child_bounty(
 			origin: OriginFor<T>,
 			#[pallet::compact] parent_bounty_id: BountyIndex,
@@ -578,45 +648,25 @@ pub mod pallet {
 		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
 			let beneficiary = T::Lookup::lookup(beneficiary)?;
-
-			// Ensure parent bounty exists, and is active.
-			let (parent_curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
-
-			ChildBounties::<T>::try_mutate_exists(
+			let parent_approved =
+				Self::awarded_by_parent_bounty_curator(&signer, parent_bounty_id, child_bounty_id);
+			if parent_approved {
+				let call = Call::<T>::award_child_bounty {
+					parent_bounty_id,
+					child_bounty_id,
+					beneficiary: T::Lookup::unlookup(beneficiary.clone()),
+				};
+				if !Self::approve_parent_curator_call(&signer, parent_bounty_id, None, call)? {
+					return Ok(())
+				}
+			}
+			Self::do_award_child_bounty(
+				&signer,
 				parent_bounty_id,
 				child_bounty_id,
-				|maybe_child_bounty| -> DispatchResult {
-					let child_bounty =
-						maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
-
-					// Ensure child-bounty is in active state.
-					if let ChildBountyStatus::Active { ref curator } = child_bounty.status {
-						ensure!(
-							signer == *curator || signer == parent_curator,
-							BountiesError::<T>::RequireCurator,
-						);
-						// Move the child-bounty state to pending payout.
-						child_bounty.status = ChildBountyStatus::PendingPayout {
-							curator: signer,
-							beneficiary: beneficiary.clone(),
-							unlock_at: frame_system::Pallet::<T>::block_number() +
-								T::BountyDepositPayoutDelay::get(),
-						};
-						Ok(())
-					} else {
-						Err(BountiesError::<T>::UnexpectedStatus.into())
-					}
-				},
-			)?;
-
-			// Trigger the event Awarded.
-			Self::deposit_event(Event::<T>::Awarded {
-				index: parent_bounty_id,
-				child_index: child_bounty_id,
 				beneficiary,
-			});
-
-			Ok(())
+				parent_approved,
+			)
 		}
 
 		/// Claim the payout from an awarded child-bounty after payout delay.
@@ -661,14 +711,17 @@ pub mod pallet {
 						// Ensure block number is elapsed for processing the
 						// claim.
 						ensure!(
-							frame_system::Pallet::<T>::block_number() >= *unlock_at,
+							T::BlockNumberProvider::current_block_number() >= *unlock_at,
 							BountiesError::<T>::Premature,
 						);
 
 						// Make curator fee payment.
 						let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
 						let balance = T::Currency::free_balance(&child_bounty_account);
-						let curator_fee = child_bounty.fee.min(balance);
+						// The curator fees of nested child bounties are deducted from the
+						// curator fee of this child-bounty.
+						let nested_fees = NestedChildrenCuratorFees::<T>::take(child_bounty_id);
+						let curator_fee = child_bounty.fee.saturating_sub(nested_fees).min(balance);
 						let payout = balance.saturating_sub(curator_fee);
 
 						// Unreserve the curator deposit. Should not fail
@@ -695,6 +748,11 @@ pub mod pallet {
 							AllowDeath,
 						);
 						debug_assert!(payout_transfer_result.is_ok());
+						pallet_bounties::Pallet::<T>::note_child_bounty_ended(
+							parent_bounty_id,
+							balance,
+							true,
+						);
 
 						// Trigger the Claimed event.
 						Self::deposit_event(Event::<T>::Claimed {
@@ -708,6 +766,7 @@ pub mod pallet {
 						ParentChildBounties::<T>::mutate(parent_bounty_id, |count| {
 							count.saturating_dec()
 						});
+						Self::detach_child_bounty(child_bounty_id);
 
 						// Remove the child-bounty description.
 						ChildBountyDescriptions::<T>::remove(child_bounty_id);
@@ -730,6 +789,10 @@ pub mod pallet {
 		/// The dispatch origin for this call must be either parent curator or
 		/// `T::RejectOrigin`.
 		///
+		/// If the parent bounty has a curator committee, the origin may be any member instead of
+		/// the parent curator and the call takes effect once the threshold of the committee
+		/// approved it.
+		///
 		/// If the state of child-bounty is `Active`, curator deposit is
 		/// unreserved.
 		///
@@ -747,7 +810,8 @@ pub mod pallet {
 		/// - `child_bounty_id`: Index of child bounty.
 		#[pallet::call_index(6)]
 		#[pallet::weight(<T as Config>::WeightInfo::close_child_bounty_added()
-			.max(<T as Config>::WeightInfo::close_child_bounty_active()))]
+			.max(<T as Config>::WeightInfo::close_child_bounty_active())
+			.saturating_add(pallet_bounties::Pallet::<T>::committee_approval_weight()))]
 		pub fn close_child_bounty(
 			origin: OriginFor<T>,
 			#[pallet::compact] parent_bounty_id: BountyIndex,
@@ -757,12 +821,336 @@ pub mod pallet {
 				.map(Some)
 				.or_else(|_| T::RejectOrigin::ensure_origin(origin).map(|_| None))?;
 
-			// Ensure parent bounty exist, get parent curator.
+			// Ensure parent bounty exist, get parent curator. `T::RejectOrigin` may close nested
+			// child bounties regardless of the state of their parent child-bounty.
+			if let Some(sender) = maybe_sender {
+				let call = Call::<T>::close_child_bounty { parent_bounty_id, child_bounty_id };
+				let approved = Self::approve_parent_curator_call(
+					&sender,
+					parent_bounty_id,
+					Some(child_bounty_id),
+					call,
+				)
+				.map_err(|error| {
+					if error == BountiesError::<T>::RequireCurator.into() {
+						BadOrigin.into()
+					} else {
+						error
+					}
+				})?;
+				if !approved {
+					return Ok(())
+				}
+			} else {
+				Self::ensure_bounty_active(parent_bounty_id)?;
+			}
+
+			Self::impl_close_child_bounty(parent_bounty_id, child_bounty_id)?;
+			Ok(())
+		}
+
+		/// Update the curator fee of the parent bounty.
+		///
+		/// Child-bounty curator fees are deducted from the parent bounty curator fee when the
+		/// parent bounty is claimed. This allows to rebalance the parent bounty curator fee once
+		/// the child-bounty curator fees are known.
+		///
+		/// The dispatch origin for this call must be either the curator of the parent bounty or
+		/// `T::SpendOrigin`, in which case the parent bounty value must be within its spend
+		/// limit.
+		///
+		/// Parent bounty must be in active state, i.e. not yet awarded. The new fee must be less
+		/// than the parent bounty value and cover the cumulative child-bounty curator fees. The
+		/// parent bounty curator deposit is adjusted to the new fee.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `fee`: The new curator fee of the parent bounty.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_parent_curator_fee())]
+		pub fn update_parent_curator_fee(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] fee: BalanceOf<T>,
+		) -> DispatchResult {
 			let (parent_curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
+			if !ensure_signed(origin.clone()).map_or(false, |signer| signer == parent_curator) {
+				let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+				let parent_bounty = pallet_bounties::Bounties::<T>::get(parent_bounty_id)
+					.ok_or(BountiesError::<T>::InvalidIndex)?;
+				ensure!(
+					parent_bounty.get_value() <= max_amount,
+					pallet_treasury::Error::<T>::InsufficientPermission
+				);
+			}
 
-			ensure!(maybe_sender.map_or(true, |sender| parent_curator == sender), BadOrigin);
+			ensure!(
+				fee >= ChildrenCuratorFees::<T>::get(parent_bounty_id),
+				Error::<T>::InsufficientParentCuratorFee
+			);
+			pallet_bounties::Pallet::<T>::do_update_curator_fee(parent_bounty_id, fee)?;
 
-			Self::impl_close_child_bounty(parent_bounty_id, child_bounty_id)?;
+			Self::deposit_event(Event::<T>::ParentCuratorFeeUpdated {
+				index: parent_bounty_id,
+				fee,
+			});
+			Ok(())
+		}
+
+		/// Add a nested child-bounty to a child-bounty.
+		///
+		/// The dispatch origin for this call must be the curator of the parent child-bounty,
+		/// which must be in "Active" state, and the parent bounty must be in active state.
+		///
+		/// The nested child-bounty is funded from the parent child-bounty account and is added
+		/// under the same parent bounty, counting towards its
+		/// [`Config::MaxActiveChildBountyCount`]. Its nesting depth must not exceed
+		/// [`Config::MaxDepth`].
+		///
+		/// If the call is success, the status of the nested child-bounty is updated to "Added".
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `parent_child_bounty_id`: Index of the child-bounty to add the nested child-bounty to.
+		/// - `value`: Value for executing the proposal.
+		/// - `description`: Text description for the child-bounty.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_nested_child_bounty(description.len() as u32))]
+		pub fn add_nested_child_bounty(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] parent_child_bounty_id: BountyIndex,
+			#[pallet::compact] value: BalanceOf<T>,
+			description: Vec<u8>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+
+			// Verify the arguments.
+			let bounded_description =
+				description.try_into().map_err(|_| BountiesError::<T>::ReasonTooBig)?;
+			ensure!(value >= T::ChildBountyValueMinimum::get(), BountiesError::<T>::InvalidValue);
+			ensure!(
+				Self::child_bounty_depth(parent_child_bounty_id) < T::MaxDepth::get(),
+				Error::<T>::TooDeeplyNested,
+			);
+			ensure!(
+				ParentChildBounties::<T>::get(parent_bounty_id) <=
+					T::MaxActiveChildBountyCount::get() as u32,
+				Error::<T>::TooManyChildBounties,
+			);
+
+			Self::ensure_bounty_active(parent_bounty_id)?;
+			let curator =
+				Self::ensure_child_bounty_active(parent_bounty_id, parent_child_bounty_id)?;
+			ensure!(signer == curator, BountiesError::<T>::RequireCurator);
+
+			// Ensure parent child-bounty has enough balance after adding the nested child-bounty.
+			let parent_child_bounty_account = Self::child_bounty_account_id(parent_child_bounty_id);
+			let balance = T::Currency::free_balance(&parent_child_bounty_account);
+			let new_balance =
+				balance.checked_sub(&value).ok_or(Error::<T>::InsufficientBountyBalance)?;
+			T::Currency::ensure_can_withdraw(
+				&parent_child_bounty_account,
+				value,
+				WithdrawReasons::TRANSFER,
+				new_balance,
+			)?;
+
+			// Get child-bounty ID.
+			let child_bounty_id = ChildBountyCount::<T>::get();
+			let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
+
+			// Transfer funds from parent child-bounty to the nested child-bounty.
+			T::Currency::transfer(
+				&parent_child_bounty_account,
+				&child_bounty_account,
+				value,
+				KeepAlive,
+			)?;
+
+			// Increment the active child-bounty counts.
+			ParentChildBounties::<T>::mutate(parent_bounty_id, |count| count.saturating_inc());
+			NestedChildBounties::<T>::mutate(parent_child_bounty_id, |count| {
+				count.saturating_inc()
+			});
+			ChildBountyParents::<T>::insert(child_bounty_id, parent_child_bounty_id);
+			ChildBountyCount::<T>::put(child_bounty_id.saturating_add(1));
+
+			// Create child-bounty instance.
+			Self::create_child_bounty(
+				parent_bounty_id,
+				Some(parent_child_bounty_id),
+				child_bounty_id,
+				value,
+				bounded_description,
+			);
+			Ok(())
+		}
+
+		/// Add several child bounties to a parent bounty and propose their curators at once.
+		///
+		/// The dispatch origin for this call must be the curator of the parent bounty and the
+		/// parent bounty must be in "active" state.
+		///
+		/// If the parent bounty has a curator committee, the origin may be any member and the call
+		/// takes effect once the threshold of the committee approved it.
+		///
+		/// Each child-bounty is added as with [`Pallet::add_child_bounty`] and its curator is
+		/// proposed as with [`Pallet::propose_curator`], so each child-bounty ends up in the
+		/// "CuratorProposed" state. Either all child bounties are added or none of them.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty for which the child bounties are added.
+		/// - `child_bounties`: The value, curator fee, curator and description of each
+		///   child-bounty. At most [`Config::MaxActiveChildBountyCount`] child bounties can be
+		///   added at once.
+		#[pallet::call_index(9)]
+		#[pallet::weight(child_bounties.iter().fold(Weight::zero(), |weight, (_, _, _, description)| {
+			weight
+				.saturating_add(<T as Config>::WeightInfo::add_child_bounty(description.len() as u32))
+				.saturating_add(<T as Config>::WeightInfo::propose_curator())
+		}).saturating_add(pallet_bounties::Pallet::<T>::committee_approval_weight()))]
+		pub fn add_child_bounties(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			child_bounties: Vec<(BalanceOf<T>, BalanceOf<T>, AccountIdLookupOf<T>, Vec<u8>)>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			ensure!(
+				child_bounties.len() <= T::MaxActiveChildBountyCount::get() as usize,
+				Error::<T>::TooManyChildBounties,
+			);
+
+			let child_bounties = child_bounties
+				.into_iter()
+				.map(|(value, fee, curator, description)| {
+					Ok((value, fee, T::Lookup::lookup(curator)?, description))
+				})
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+			let call = Call::<T>::add_child_bounties {
+				parent_bounty_id,
+				child_bounties: child_bounties
+					.iter()
+					.map(|(value, fee, curator, description)| {
+						(*value, *fee, T::Lookup::unlookup(curator.clone()), description.clone())
+					})
+					.collect(),
+			};
+			if !Self::approve_parent_curator_call(&signer, parent_bounty_id, None, call)? {
+				return Ok(())
+			}
+
+			for (value, fee, curator, description) in child_bounties {
+				let child_bounty_id =
+					Self::do_add_child_bounty(parent_bounty_id, value, description)?;
+				Self::do_propose_curator(parent_bounty_id, child_bounty_id, curator, fee)?;
+			}
+			Ok(())
+		}
+
+		/// Award several child bounties of a parent bounty at once.
+		///
+		/// The dispatch origin for this call must be the parent curator or the curator of each
+		/// of the child bounties.
+		///
+		/// If the parent bounty has a curator committee, the origin may be any member instead of
+		/// the parent curator and the call takes effect once the threshold of the committee
+		/// approved it.
+		///
+		/// Each child-bounty is awarded as with [`Pallet::award_child_bounty`]. Either all child
+		/// bounties are awarded or none of them.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `awards`: The index of each child-bounty and its beneficiary. At most
+		///   [`Config::MaxActiveChildBountyCount`] child bounties can be awarded at once.
+		#[pallet::call_index(10)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::award_child_bounty()
+				.saturating_mul(awards.len() as u64)
+				.saturating_add(pallet_bounties::Pallet::<T>::committee_approval_weight())
+		)]
+		pub fn award_child_bounties(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			awards: Vec<(BountyIndex, AccountIdLookupOf<T>)>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			ensure!(
+				awards.len() <= T::MaxActiveChildBountyCount::get() as usize,
+				Error::<T>::TooManyChildBounties,
+			);
+
+			let awards = awards
+				.into_iter()
+				.map(|(child_bounty_id, beneficiary)| {
+					Ok((child_bounty_id, T::Lookup::lookup(beneficiary)?))
+				})
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+			let parent_approved = awards.iter().any(|(child_bounty_id, _)| {
+				Self::awarded_by_parent_bounty_curator(&signer, parent_bounty_id, *child_bounty_id)
+			});
+			if parent_approved {
+				let call = Call::<T>::award_child_bounties {
+					parent_bounty_id,
+					awards: awards
+						.iter()
+						.map(|(child_bounty_id, beneficiary)| {
+							(*child_bounty_id, T::Lookup::unlookup(beneficiary.clone()))
+						})
+						.collect(),
+				};
+				if !Self::approve_parent_curator_call(&signer, parent_bounty_id, None, call)? {
+					return Ok(())
+				}
+			}
+
+			for (child_bounty_id, beneficiary) in awards {
+				Self::do_award_child_bounty(
+					&signer,
+					parent_bounty_id,
+					child_bounty_id,
+					beneficiary,
+					parent_approved,
+				)?;
+			}
+			Ok(())
+		}
+
+		/// Extend the expiry time of an active child-bounty.
+		///
+		/// The dispatch origin for this call must be the curator of this child-bounty.
+		///
+		/// The child-bounty update becomes due one update period of the parent bounty from now,
+		/// unless it is already due later.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `child_bounty_id`: Index of child bounty.
+		/// - `remark`: additional information.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::extend_child_bounty_expiry())]
+		pub fn extend_child_bounty_expiry(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+			_remark: Vec<u8>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+
+			let child_bounty = ChildBounties::<T>::get(parent_bounty_id, child_bounty_id)
+				.ok_or(BountiesError::<T>::InvalidIndex)?;
+			match child_bounty.status {
+				ChildBountyStatus::Active { ref curator } =>
+					ensure!(signer == *curator, BountiesError::<T>::RequireCurator),
+				_ => return Err(BountiesError::<T>::UnexpectedStatus.into()),
+			}
+
+			let next_update_due = Self::next_update_due(parent_bounty_id);
+			ChildBountyUpdateDue::<T>::mutate(parent_bounty_id, child_bounty_id, |update_due| {
+				*update_due =
+					Some(update_due.map_or(next_update_due, |due| due.max(next_update_due)))
+			});
+
+			Self::deposit_event(Event::<T>::Extended {
+				index: parent_bounty_id,
+				child_index: child_bounty_id,
+			});
 			Ok(())
 		}
 	}
@@ -791,8 +1179,176 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_sub_account_truncating(("cb", id))
 	}
 
+	/// Add a child-bounty to the parent bounty on behalf of its curator, returning its index.
+	fn do_add_child_bounty(
+		parent_bounty_id: BountyIndex,
+		value: BalanceOf<T>,
+		description: Vec<u8>,
+	) -> Result<BountyIndex, DispatchError> {
+		// Verify the arguments.
+		let bounded_description =
+			description.try_into().map_err(|_| BountiesError::<T>::ReasonTooBig)?;
+		ensure!(value >= T::ChildBountyValueMinimum::get(), BountiesError::<T>::InvalidValue);
+		ensure!(
+			ParentChildBounties::<T>::get(parent_bounty_id) <=
+				T::MaxActiveChildBountyCount::get() as u32,
+			Error::<T>::TooManyChildBounties,
+		);
+
+		Self::ensure_bounty_active(parent_bounty_id)?;
+
+		// Read parent bounty account info.
+		let parent_bounty_account =
+			pallet_bounties::Pallet::<T>::bounty_account_id(parent_bounty_id);
+
+		// Ensure parent bounty has enough balance after adding child-bounty.
+		let bounty_balance = T::Currency::free_balance(&parent_bounty_account);
+		let new_bounty_balance = bounty_balance
+			.checked_sub(&value)
+			.ok_or(Error::<T>::InsufficientBountyBalance)?;
+		T::Currency::ensure_can_withdraw(
+			&parent_bounty_account,
+			value,
+			WithdrawReasons::TRANSFER,
+			new_bounty_balance,
+		)?;
+
+		// Get child-bounty ID.
+		let child_bounty_id = ChildBountyCount::<T>::get();
+		let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
+
+		// Transfer funds from parent bounty to child-bounty.
+		T::Currency::transfer(&parent_bounty_account, &child_bounty_account, value, KeepAlive)?;
+		pallet_bounties::Pallet::<T>::note_child_bounty_funded(parent_bounty_id, value);
+
+		// Increment the active child-bounty count.
+		ParentChildBounties::<T>::mutate(parent_bounty_id, |count| count.saturating_inc());
+		ChildBountyCount::<T>::put(child_bounty_id.saturating_add(1));
+
+		// Create child-bounty instance.
+		Self::create_child_bounty(
+			parent_bounty_id,
+			None,
+			child_bounty_id,
+			value,
+			bounded_description,
+		);
+		Ok(child_bounty_id)
+	}
+
+	/// Propose `child_bounty_curator` as curator of a child-bounty on behalf of the curator of its
+	/// parent.
+	fn do_propose_curator(
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+		child_bounty_curator: T::AccountId,
+		fee: BalanceOf<T>,
+	) -> DispatchResult {
+		Self::ensure_parent_active(parent_bounty_id, child_bounty_id)?;
+
+		// Mutate the child-bounty instance.
+		ChildBounties::<T>::try_mutate_exists(
+			parent_bounty_id,
+			child_bounty_id,
+			|maybe_child_bounty| -> DispatchResult {
+				let child_bounty =
+					maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
+
+				// Ensure child-bounty is in expected state.
+				ensure!(
+					child_bounty.status == ChildBountyStatus::Added,
+					BountiesError::<T>::UnexpectedStatus,
+				);
+
+				// Ensure child-bounty curator fee is less than child-bounty value.
+				ensure!(fee < child_bounty.value, BountiesError::<T>::InvalidFee);
+				// The curator fee of a parent child-bounty must still cover the curator fees of
+				// its nested child bounties when a new curator is proposed.
+				ensure!(
+					fee >= NestedChildrenCuratorFees::<T>::get(child_bounty_id),
+					Error::<T>::InsufficientParentCuratorFee
+				);
+
+				// Add child-bounty curator fee to the cumulative sum. To be
+				// subtracted from the parent curator when claiming the parent.
+				Self::add_parent_curator_fee(parent_bounty_id, child_bounty_id, fee)?;
+
+				// Update the child-bounty curator fee.
+				child_bounty.fee = fee;
+
+				// Update the child-bounty state.
+				child_bounty.status =
+					ChildBountyStatus::CuratorProposed { curator: child_bounty_curator };
+
+				Ok(())
+			},
+		)
+	}
+
+	/// Award a child-bounty to `beneficiary` on behalf of `signer`, the curator of the
+	/// child-bounty or of its parent.
+	///
+	/// `parent_approved` tells whether the award was approved on behalf of the curator of the
+	/// parent bounty, see [`Self::approve_parent_curator_call`].
+	fn do_award_child_bounty(
+		signer: &T::AccountId,
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+		beneficiary: T::AccountId,
+		parent_approved: bool,
+	) -> DispatchResult {
+		// Ensure parent bounty exists, and is active.
+		let (parent_curator, _) = Self::ensure_parent_active(parent_bounty_id, child_bounty_id)?;
+		// Nested child bounties must be done before their parent is awarded.
+		ensure!(
+			NestedChildBounties::<T>::get(child_bounty_id).is_zero(),
+			BountiesError::<T>::HasActiveChildBounty
+		);
+
+		ChildBounties::<T>::try_mutate_exists(
+			parent_bounty_id,
+			child_bounty_id,
+			|maybe_child_bounty| -> DispatchResult {
+				let child_bounty =
+					maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
+
+				// Ensure child-bounty is in active state.
+				if let ChildBountyStatus::Active { ref curator } = child_bounty.status {
+					// The curator of a parent child-bounty awards its nested child bounties alone.
+					let by_parent = if ChildBountyParents::<T>::contains_key(child_bounty_id) {
+						*signer == parent_curator
+					} else {
+						parent_approved
+					};
+					ensure!(*signer == *curator || by_parent, BountiesError::<T>::RequireCurator);
+					// Move the child-bounty state to pending payout.
+					child_bounty.status = ChildBountyStatus::PendingPayout {
+						curator: if *signer == *curator { signer.clone() } else { parent_curator },
+						beneficiary: beneficiary.clone(),
+						unlock_at: T::BlockNumberProvider::current_block_number() +
+							T::BountyDepositPayoutDelay::get(),
+					};
+					ChildBountyUpdateDue::<T>::remove(parent_bounty_id, child_bounty_id);
+					Ok(())
+				} else {
+					Err(BountiesError::<T>::UnexpectedStatus.into())
+				}
+			},
+		)?;
+
+		// Trigger the event Awarded.
+		Self::deposit_event(Event::<T>::Awarded {
+			index: parent_bounty_id,
+			child_index: child_bounty_id,
+			beneficiary,
+		});
+
+		Ok(())
+	}
+
 	fn create_child_bounty(
 		parent_bounty_id: BountyIndex,
+		parent_child_bounty_id: Option<BountyIndex>,
 		child_bounty_id: BountyIndex,
 		child_bounty_value: BalanceOf<T>,
 		description: BoundedVec<u8, T::MaximumReasonLength>,
@@ -806,7 +1362,17 @@ impl<T: Config> Pallet<T> {
 		};
 		ChildBounties::<T>::insert(parent_bounty_id, child_bounty_id, &child_bounty);
 		ChildBountyDescriptions::<T>::insert(child_bounty_id, description);
-		Self::deposit_event(Event::Added { index: parent_bounty_id, child_index: child_bounty_id });
+		match parent_child_bounty_id {
+			None => Self::deposit_event(Event::Added {
+				index: parent_bounty_id,
+				child_index: child_bounty_id,
+			}),
+			Some(parent_child_index) => Self::deposit_event(Event::NestedAdded {
+				index: parent_bounty_id,
+				parent_child_index,
+				child_index: child_bounty_id,
+			}),
+		}
 	}
 
 	fn ensure_bounty_active(
@@ -814,17 +1380,174 @@ impl<T: Config> Pallet<T> {
 	) -> Result<(T::AccountId, BlockNumberFor<T>), DispatchError> {
 		let parent_bounty = pallet_bounties::Bounties::<T>::get(bounty_id)
 			.ok_or(BountiesError::<T>::InvalidIndex)?;
-		if let BountyStatus::Active { curator, update_due } = parent_bounty.get_status() {
+		if let BountyStatus::Active { curator, update_due, .. } = parent_bounty.get_status() {
 			Ok((curator, update_due))
 		} else {
 			Err(Error::<T>::ParentBountyNotActive.into())
 		}
 	}
 
+	/// Ensure `signer` may make `call` as the curator of the parent of a child-bounty, returning
+	/// whether `call` may take effect.
+	///
+	/// The parent is the parent child-bounty of the nested child-bounty `maybe_child_bounty_id`,
+	/// else the parent bounty. If the parent bounty has a curator committee, `signer` may be any
+	/// of its members and `call` takes effect once the threshold of the committee approved it.
+	fn approve_parent_curator_call(
+		signer: &T::AccountId,
+		parent_bounty_id: BountyIndex,
+		maybe_child_bounty_id: Option<BountyIndex>,
+		call: Call<T>,
+	) -> Result<bool, DispatchError> {
+		let (curator, _) = match maybe_child_bounty_id {
+			Some(child_bounty_id) => Self::ensure_parent_active(parent_bounty_id, child_bounty_id)?,
+			None => Self::ensure_bounty_active(parent_bounty_id)?,
+		};
+		if maybe_child_bounty_id.map_or(false, |id| ChildBountyParents::<T>::contains_key(id)) {
+			ensure!(*signer == curator, BountiesError::<T>::RequireCurator);
+			return Ok(true)
+		}
+		let call_hash = H256(blake2_256(&call.encode()));
+		pallet_bounties::Pallet::<T>::approve_child_bounty_call(parent_bounty_id, signer, call_hash)
+	}
+
+	/// Whether awarding the active child-bounty `child_bounty_id` by `signer` has to be approved
+	/// on behalf of the curator of the parent bounty, i.e. `signer` is not its curator and it is
+	/// not a nested child-bounty.
+	fn awarded_by_parent_bounty_curator(
+		signer: &T::AccountId,
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+	) -> bool {
+		if ChildBountyParents::<T>::contains_key(child_bounty_id) {
+			return false
+		}
+		match ChildBounties::<T>::get(parent_bounty_id, child_bounty_id) {
+			Some(ChildBounty { status: ChildBountyStatus::Active { curator }, .. }) =>
+				curator != *signer,
+			_ => false,
+		}
+	}
+
+	/// Ensure the child-bounty is active, returning its curator.
+	fn ensure_child_bounty_active(
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+	) -> Result<T::AccountId, DispatchError> {
+		let child_bounty = ChildBounties::<T>::get(parent_bounty_id, child_bounty_id)
+			.ok_or(BountiesError::<T>::InvalidIndex)?;
+		if let ChildBountyStatus::Active { curator } = child_bounty.status {
+			Ok(curator)
+		} else {
+			Err(Error::<T>::ParentBountyNotActive.into())
+		}
+	}
+
+	/// Ensure the parent of a child-bounty is active, returning the curator of the parent and the
+	/// update due of the parent bounty.
+	///
+	/// The parent of a nested child-bounty is its parent child-bounty, which must be active in
+	/// addition to the parent bounty.
+	fn ensure_parent_active(
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+	) -> Result<(T::AccountId, BlockNumberFor<T>), DispatchError> {
+		let (curator, update_due) = Self::ensure_bounty_active(parent_bounty_id)?;
+		match ChildBountyParents::<T>::get(child_bounty_id) {
+			None => Ok((curator, update_due)),
+			Some(parent_child_bounty_id) => {
+				let curator =
+					Self::ensure_child_bounty_active(parent_bounty_id, parent_child_bounty_id)?;
+				Ok((curator, update_due))
+			},
+		}
+	}
+
+	/// The nesting depth of a child-bounty, 1 for the child bounties of a parent bounty.
+	fn child_bounty_depth(mut child_bounty_id: BountyIndex) -> u32 {
+		let mut depth = 1;
+		while let Some(parent_child_bounty_id) = ChildBountyParents::<T>::get(child_bounty_id) {
+			depth.saturating_inc();
+			child_bounty_id = parent_child_bounty_id;
+		}
+		depth
+	}
+
+	/// Add the curator fee of a child-bounty to the cumulative child-bounty curator fees of its
+	/// parent.
+	///
+	/// The curator fee of a parent child-bounty is fixed, so it must cover the curator fees of
+	/// all of its nested child bounties.
+	fn add_parent_curator_fee(
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+		fee: BalanceOf<T>,
+	) -> DispatchResult {
+		match ChildBountyParents::<T>::get(child_bounty_id) {
+			None => ChildrenCuratorFees::<T>::mutate(parent_bounty_id, |value| {
+				*value = value.saturating_add(fee)
+			}),
+			Some(parent_child_bounty_id) => {
+				let parent_fee = ChildBounties::<T>::get(parent_bounty_id, parent_child_bounty_id)
+					.ok_or(BountiesError::<T>::InvalidIndex)?
+					.fee;
+				NestedChildrenCuratorFees::<T>::try_mutate(
+					parent_child_bounty_id,
+					|value| -> DispatchResult {
+						*value = value.saturating_add(fee);
+						ensure!(*value <= parent_fee, Error::<T>::InsufficientParentCuratorFee);
+						Ok(())
+					},
+				)?;
+			},
+		}
+		Ok(())
+	}
+
+	/// Revert the curator fee of a child-bounty from the cumulative child-bounty curator fees of
+	/// its parent.
+	fn remove_parent_curator_fee(
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+		fee: BalanceOf<T>,
+	) {
+		match ChildBountyParents::<T>::get(child_bounty_id) {
+			None => ChildrenCuratorFees::<T>::mutate(parent_bounty_id, |value| {
+				*value = value.saturating_sub(fee)
+			}),
+			Some(parent_child_bounty_id) =>
+				NestedChildrenCuratorFees::<T>::mutate(parent_child_bounty_id, |value| {
+					*value = value.saturating_sub(fee)
+				}),
+		}
+	}
+
+	/// The update due for a child-bounty of `parent_bounty_id` whose curator gives an update now,
+	/// one update period of the parent bounty from now.
+	fn next_update_due(parent_bounty_id: BountyIndex) -> BlockNumberFor<T> {
+		T::BlockNumberProvider::current_block_number()
+			.saturating_add(pallet_bounties::Pallet::<T>::update_period(parent_bounty_id))
+	}
+
+	/// Remove the link of a removed child-bounty to its parent child-bounty, if it is nested.
+	fn detach_child_bounty(child_bounty_id: BountyIndex) {
+		if let Some(parent_child_bounty_id) = ChildBountyParents::<T>::take(child_bounty_id) {
+			NestedChildBounties::<T>::mutate(parent_child_bounty_id, |count| {
+				count.saturating_dec()
+			});
+		}
+	}
+
 	fn impl_close_child_bounty(
 		parent_bounty_id: BountyIndex,
 		child_bounty_id: BountyIndex,
 	) -> DispatchResult {
+		// Nested child bounties must be closed before their parent.
+		ensure!(
+			NestedChildBounties::<T>::get(child_bounty_id).is_zero(),
+			BountiesError::<T>::HasActiveChildBounty
+		);
+
 		ChildBounties::<T>::try_mutate_exists(
 			parent_bounty_id,
 			child_bounty_id,
@@ -852,18 +1575,27 @@ impl<T: Config> Pallet<T> {
 					},
 				}
 
-				// Revert the curator fee back to parent bounty curator &
+				// Revert the curator fee back to the parent curator &
 				// reduce the active child-bounty count.
-				ChildrenCuratorFees::<T>::mutate(parent_bounty_id, |value| {
-					*value = value.saturating_sub(child_bounty.fee)
-				});
+				Self::remove_parent_curator_fee(
+					parent_bounty_id,
+					child_bounty_id,
+					child_bounty.fee,
+				);
 				ParentChildBounties::<T>::mutate(parent_bounty_id, |count| {
 					*count = count.saturating_sub(1)
 				});
-
-				// Transfer fund from child-bounty to parent bounty.
-				let parent_bounty_account =
-					pallet_bounties::Pallet::<T>::bounty_account_id(parent_bounty_id);
+				NestedChildrenCuratorFees::<T>::remove(child_bounty_id);
+
+				// Transfer fund from child-bounty to the parent bounty, or the parent
+				// child-bounty if nested.
+				let nested = ChildBountyParents::<T>::get(child_bounty_id);
+				let parent_bounty_account = match nested {
+					Some(parent_child_bounty_id) =>
+						Self::child_bounty_account_id(parent_child_bounty_id),
+					None => pallet_bounties::Pallet::<T>::bounty_account_id(parent_bounty_id),
+				};
+				Self::detach_child_bounty(child_bounty_id);
 				let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
 				let balance = T::Currency::free_balance(&child_bounty_account);
 				let transfer_result = T::Currency::transfer(
@@ -873,9 +1605,18 @@ impl<T: Config> Pallet<T> {
 					AllowDeath,
 				); // Should not fail; child bounty account gets this balance during creation.
 				debug_assert!(transfer_result.is_ok());
+				// The funds of a nested child-bounty stay committed to its parent child-bounty.
+				if nested.is_none() {
+					pallet_bounties::Pallet::<T>::note_child_bounty_ended(
+						parent_bounty_id,
+						balance,
+						false,
+					);
+				}
 
 				// Remove the child-bounty description.
 				ChildBountyDescriptions::<T>::remove(child_bounty_id);
+				ChildBountyUpdateDue::<T>::remove(parent_bounty_id, child_bounty_id);
 
 				*maybe_child_bounty = None;
 
@@ -906,4 +1647,8 @@ impl<T: Config> pallet_bounties::ChildBountyManager<BalanceOf<T>> for Pallet<T>
 		ChildrenCuratorFees::<T>::remove(bounty_id);
 		children_fee_total
 	}
+
+	fn peek_children_curator_fees(bounty_id: pallet_bounties::BountyIndex) -> BalanceOf<T> {
+		ChildrenCuratorFees::<T>::get(bounty_id)
+	}
 }
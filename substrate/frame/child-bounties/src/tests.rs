@@ -33,7 +33,7 @@ use frame_support::{
 };
 
 use sp_runtime::{
-	traits::{BadOrigin, IdentityLookup},
+	traits::{BadOrigin, IdentityLookup, TryConvertInto},
 	BuildStorage, Perbill, Permill, TokenError,
 };
 
@@ -98,12 +98,17 @@ impl pallet_treasury::Config for Test {
 	type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
 	type BalanceConverter = UnityAssetBalanceConversion;
 	type PayoutPeriod = ConstU64<10>;
+	type Clawback = ();
+	type SpendSubmitter = ();
+	type SpendConsideration = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
 parameter_types! {
 	// This will be 50% of the bounty fee.
 	pub const CuratorDepositMultiplier: Permill = Permill::from_percent(50);
+	pub const BountyWithdrawalFee: Permill = Permill::from_percent(10);
+	pub const CuratorSlashReporterShare: Permill = Permill::from_percent(50);
 	pub const CuratorDepositMax: Balance = 1_000;
 	pub const CuratorDepositMin: Balance = 3;
 
@@ -113,20 +118,32 @@ impl pallet_bounties::Config for Test {
 	type BountyDepositBase = ConstU64<80>;
 	type BountyDepositPayoutDelay = ConstU64<3>;
 	type BountyUpdatePeriod = ConstU64<10>;
+	type FundedExpiry = ConstU64<30>;
 	type CuratorDepositMultiplier = CuratorDepositMultiplier;
 	type CuratorDepositMax = CuratorDepositMax;
 	type CuratorDepositMin = CuratorDepositMin;
 	type BountyValueMinimum = ConstU64<5>;
 	type DataDepositPerByte = ConstU64<1>;
+	type BountyWithdrawalFee = BountyWithdrawalFee;
+	type CuratorSlashReporterShare = CuratorSlashReporterShare;
+	type CuratorSlashAppealPeriod = ConstU64<20>;
 	type MaximumReasonLength = ConstU32<300>;
 	type WeightInfo = ();
 	type ChildBountyManager = ChildBounties;
+	type PayoutGuard = ();
 	type OnSlash = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type NativeBalance = Balances;
+	type OnDepositSlash = ();
+	type BlockNumberProvider = System;
+	type BeneficiaryAccount = TryConvertInto;
+	type NativeAssetKind = ();
 }
 impl pallet_child_bounties::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = ConstU32<2>;
 	type ChildBountyValueMinimum = ConstU64<1>;
+	type MaxDepth = ConstU32<3>;
 	type WeightInfo = ();
 }
 
@@ -1119,6 +1136,77 @@ fn parent_bounty_inactive_unassign_curator_child_bounty() {
 	});
 }
 
+#[test]
+fn expired_child_bounty_curator_can_be_unassigned_by_anyone() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+
+		// The child-bounty update is due one update period after the curator accepts.
+		System::set_block_number(3);
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 2));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_eq!(pallet_child_bounties::ChildBountyUpdateDue::<Test>::get(0, 0), Some(13));
+		let child_deposit = Balances::reserved_balance(8);
+
+		// Only the child-bounty curator can extend the expiry.
+		System::set_block_number(5);
+		assert_noop!(
+			ChildBounties::extend_child_bounty_expiry(RuntimeOrigin::signed(4), 0, 0, Vec::new()),
+			BountiesError::RequireCurator
+		);
+		assert_ok!(ChildBounties::extend_child_bounty_expiry(
+			RuntimeOrigin::signed(8),
+			0,
+			0,
+			Vec::new()
+		));
+		assert_eq!(last_event(), ChildBountiesEvent::Extended { index: 0, child_index: 0 });
+		assert_eq!(pallet_child_bounties::ChildBountyUpdateDue::<Test>::get(0, 0), Some(15));
+
+		// The parent bounty curator goes away, yet the child-bounty is not expired.
+		assert_ok!(Bounties::unassign_curator(RuntimeOrigin::root(), 0));
+		System::set_block_number(15);
+		assert_noop!(
+			ChildBounties::unassign_curator(RuntimeOrigin::signed(3), 0, 0),
+			Error::<Test>::ParentBountyNotActive
+		);
+
+		// Once expired, anyone can unassign the child-bounty curator, slashing its deposit.
+		System::set_block_number(16);
+		assert_ok!(ChildBounties::unassign_curator(RuntimeOrigin::signed(3), 0, 0));
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::CuratorUnassigned { index: 0, child_index: 0 }
+		);
+		assert_eq!(
+			pallet_child_bounties::ChildBounties::<Test>::get(0, 0).unwrap().status,
+			ChildBountyStatus::Added
+		);
+		assert_eq!(pallet_child_bounties::ChildBountyUpdateDue::<Test>::get(0, 0), None);
+		assert_eq!(Balances::reserved_balance(8), 0);
+		assert_eq!(Balances::free_balance(8), 101 - child_deposit);
+	});
+}
+
 #[test]
 fn close_parent_with_child_bounty() {
 	new_test_ext().execute_with(|| {
@@ -1443,3 +1531,456 @@ fn accept_curator_handles_different_deposit_calculations() {
 		assert_eq!(Balances::reserved_balance(child_curator), expected_deposit);
 	});
 }
+
+#[test]
+fn update_parent_curator_fee_works() {
+	new_test_ext().execute_with(|| {
+		// Setup a parent bounty.
+		let parent_curator = 0;
+		let parent_index = 0;
+		let parent_value = 1_000_000;
+		let parent_fee = 10_000;
+
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), parent_value * 3);
+		Balances::make_free_balance_be(&parent_curator, parent_fee * 100);
+		assert_ok!(Bounties::propose_bounty(
+			RuntimeOrigin::signed(parent_curator),
+			parent_value,
+			b"12345".to_vec()
+		));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), parent_index));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(
+			RuntimeOrigin::root(),
+			parent_index,
+			parent_curator,
+			parent_fee
+		));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(parent_curator), parent_index));
+		assert_eq!(Balances::reserved_balance(parent_curator), CuratorDepositMax::get());
+
+		// Add a child bounty with a curator fee.
+		let child_fee = 1_000;
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(parent_curator),
+			parent_index,
+			10_000,
+			b"12345-p1".to_vec()
+		));
+		assert_ok!(ChildBounties::propose_curator(
+			RuntimeOrigin::signed(parent_curator),
+			parent_index,
+			0,
+			1,
+			child_fee
+		));
+
+		// Only the parent curator or the spend origin can update the fee.
+		assert_noop!(
+			ChildBounties::update_parent_curator_fee(RuntimeOrigin::signed(1), parent_index, 5_000),
+			BadOrigin
+		);
+
+		// The fee must cover the child-bounty curator fees.
+		assert_noop!(
+			ChildBounties::update_parent_curator_fee(
+				RuntimeOrigin::signed(parent_curator),
+				parent_index,
+				child_fee - 1
+			),
+			Error::<Test>::InsufficientParentCuratorFee
+		);
+
+		// The fee must be less than the parent bounty value.
+		assert_noop!(
+			ChildBounties::update_parent_curator_fee(
+				RuntimeOrigin::signed(parent_curator),
+				parent_index,
+				parent_value
+			),
+			BountiesError::InvalidFee
+		);
+
+		// Lowering the fee releases part of the curator deposit.
+		assert_ok!(ChildBounties::update_parent_curator_fee(
+			RuntimeOrigin::signed(parent_curator),
+			parent_index,
+			child_fee
+		));
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::ParentCuratorFeeUpdated { index: parent_index, fee: child_fee }
+		);
+		let expected_deposit = CuratorDepositMultiplier::get() * child_fee;
+		assert_eq!(Balances::reserved_balance(parent_curator), expected_deposit);
+		assert_eq!(
+			pallet_bounties::Bounties::<Test>::get(parent_index).unwrap().get_fee(),
+			child_fee
+		);
+
+		// The spend origin can raise the fee back, reserving the difference.
+		assert_ok!(ChildBounties::update_parent_curator_fee(
+			RuntimeOrigin::root(),
+			parent_index,
+			parent_fee
+		));
+		assert_eq!(Balances::reserved_balance(parent_curator), CuratorDepositMax::get());
+		assert_eq!(
+			pallet_bounties::Bounties::<Test>::get(parent_index).unwrap().get_fee(),
+			parent_fee
+		);
+	});
+}
+
+#[test]
+fn nested_child_bounties_work() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Child-bounty with a curator fee of 6.
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			20,
+			b"12345-p1".to_vec()
+		));
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 6));
+
+		// Only the curator of an active child-bounty can add nested child bounties.
+		assert_noop!(
+			ChildBounties::add_nested_child_bounty(RuntimeOrigin::signed(8), 0, 0, 10, vec![]),
+			Error::<Test>::ParentBountyNotActive
+		);
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_noop!(
+			ChildBounties::add_nested_child_bounty(RuntimeOrigin::signed(4), 0, 0, 10, vec![]),
+			BountiesError::RequireCurator
+		);
+
+		// Nested child-bounty at depth 2, funded from the child-bounty.
+		assert_ok!(ChildBounties::add_nested_child_bounty(
+			RuntimeOrigin::signed(8),
+			0,
+			0,
+			10,
+			b"12345-p1-1".to_vec()
+		));
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::NestedAdded { index: 0, parent_child_index: 0, child_index: 1 }
+		);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0)), 10);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(1)), 10);
+		assert_eq!(pallet_child_bounties::ParentChildBounties::<Test>::get(0), 2);
+		assert_eq!(pallet_child_bounties::NestedChildBounties::<Test>::get(0), 1);
+		assert_eq!(pallet_child_bounties::ChildBountyParents::<Test>::get(1), Some(0));
+
+		// The curator of the parent child-bounty takes the role of the parent curator.
+		assert_noop!(
+			ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 1, 8, 2),
+			BountiesError::RequireCurator
+		);
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(8), 0, 1, 8, 2));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 1));
+		assert_eq!(pallet_child_bounties::ChildrenCuratorFees::<Test>::get(0), 6);
+		assert_eq!(pallet_child_bounties::NestedChildrenCuratorFees::<Test>::get(0), 2);
+
+		// Nested child-bounty at depth 3, the maximum.
+		assert_ok!(ChildBounties::add_nested_child_bounty(
+			RuntimeOrigin::signed(8),
+			0,
+			1,
+			4,
+			b"12345-p1-1-1".to_vec()
+		));
+		assert_noop!(
+			ChildBounties::add_nested_child_bounty(RuntimeOrigin::signed(8), 0, 2, 1, vec![]),
+			Error::<Test>::TooDeeplyNested
+		);
+		// The curator fee of the parent child-bounty must cover the nested curator fees.
+		assert_noop!(
+			ChildBounties::propose_curator(RuntimeOrigin::signed(8), 0, 2, 5, 3),
+			Error::<Test>::InsufficientParentCuratorFee
+		);
+
+		// Child bounties with nested child bounties can't be awarded, closed or unassigned.
+		assert_noop!(
+			ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 1, 7),
+			BountiesError::HasActiveChildBounty
+		);
+		assert_noop!(
+			ChildBounties::unassign_curator(RuntimeOrigin::signed(8), 0, 1),
+			BountiesError::HasActiveChildBounty
+		);
+		assert_noop!(
+			ChildBounties::close_child_bounty(RuntimeOrigin::signed(8), 0, 1),
+			BountiesError::HasActiveChildBounty
+		);
+
+		// Closing a nested child-bounty returns its funds to the parent child-bounty.
+		assert_ok!(ChildBounties::close_child_bounty(RuntimeOrigin::signed(8), 0, 2));
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(1)), 10);
+		assert_eq!(pallet_child_bounties::NestedChildBounties::<Test>::get(1), 0);
+		assert_eq!(pallet_child_bounties::ChildBountyParents::<Test>::get(2), None);
+
+		// Award and claim the nested child-bounty.
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 1, 7));
+		System::set_block_number(5);
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 1));
+		assert_eq!(Balances::free_balance(7), 8);
+		assert_eq!(Balances::free_balance(8), 101 - 3 + 2);
+
+		// Once the nested child bounties are done, the curator of the child-bounty can be
+		// unassigned, but the fee of a new curator must still cover the nested curator fees.
+		assert_ok!(ChildBounties::unassign_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_noop!(
+			ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 1),
+			Error::<Test>::InsufficientParentCuratorFee
+		);
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 6));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+
+		// The nested curator fee is deducted from the curator fee of the child-bounty.
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 7));
+		System::set_block_number(8);
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 0));
+		assert_eq!(Balances::free_balance(7), 8 + 10 - 4);
+		assert_eq!(Balances::free_balance(8), 101 + 6);
+		assert_eq!(Balances::reserved_balance(8), 0);
+
+		assert_eq!(pallet_child_bounties::ParentChildBounties::<Test>::get(0), 0);
+		assert_eq!(pallet_child_bounties::NestedChildBounties::<Test>::get(0), 0);
+		assert_eq!(pallet_child_bounties::ChildBountyParents::<Test>::get(1), None);
+		assert_eq!(pallet_child_bounties::NestedChildrenCuratorFees::<Test>::get(0), 0);
+	});
+}
+
+#[test]
+fn batch_add_and_award_child_bounties_work() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+		Balances::make_free_balance_be(&9, 101); // Child-bounty curator.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// No more than `MaxActiveChildBountyCount` child bounties at once.
+		assert_noop!(
+			ChildBounties::add_child_bounties(
+				RuntimeOrigin::signed(4),
+				0,
+				vec![
+					(5, 1, 8, b"p1".to_vec()),
+					(5, 1, 8, b"p2".to_vec()),
+					(5, 1, 8, b"p3".to_vec())
+				],
+			),
+			Error::<Test>::TooManyChildBounties,
+		);
+		// Either all child bounties are added or none of them.
+		assert_noop!(
+			ChildBounties::add_child_bounties(
+				RuntimeOrigin::signed(4),
+				0,
+				vec![(10, 2, 8, b"p1".to_vec()), (10, 10, 9, b"p2".to_vec())],
+			),
+			BountiesError::InvalidFee,
+		);
+		assert_noop!(
+			ChildBounties::add_child_bounties(
+				RuntimeOrigin::signed(8),
+				0,
+				vec![(10, 2, 8, b"p1".to_vec())],
+			),
+			BountiesError::RequireCurator,
+		);
+
+		assert_ok!(ChildBounties::add_child_bounties(
+			RuntimeOrigin::signed(4),
+			0,
+			vec![(10, 2, 8, b"p1".to_vec()), (15, 3, 9, b"p2".to_vec())],
+		));
+		assert_eq!(pallet_child_bounties::ParentChildBounties::<Test>::get(0), 2);
+		assert_eq!(pallet_child_bounties::ChildrenCuratorFees::<Test>::get(0), 5);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 25);
+		assert_eq!(
+			pallet_child_bounties::ChildBounties::<Test>::get(0, 1).unwrap(),
+			ChildBounty {
+				parent_bounty: 0,
+				value: 15,
+				fee: 3,
+				curator_deposit: 0,
+				status: ChildBountyStatus::CuratorProposed { curator: 9 },
+			}
+		);
+		assert_eq!(
+			pallet_child_bounties::ChildBountyDescriptions::<Test>::get(1).unwrap(),
+			b"p2".to_vec(),
+		);
+
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(9), 0, 1));
+
+		// The curator of one child-bounty can not award the other one.
+		assert_noop!(
+			ChildBounties::award_child_bounties(RuntimeOrigin::signed(8), 0, vec![(0, 7), (1, 7)]),
+			BountiesError::RequireCurator,
+		);
+
+		assert_ok!(ChildBounties::award_child_bounties(
+			RuntimeOrigin::signed(4),
+			0,
+			vec![(0, 7), (1, 17)],
+		));
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::Awarded { index: 0, child_index: 1, beneficiary: 17 }
+		);
+		assert_eq!(
+			pallet_child_bounties::ChildBounties::<Test>::get(0, 0).unwrap().status,
+			ChildBountyStatus::PendingPayout { curator: 4, beneficiary: 7, unlock_at: 5 },
+		);
+		assert_eq!(
+			pallet_child_bounties::ChildBounties::<Test>::get(0, 1).unwrap().status,
+			ChildBountyStatus::PendingPayout { curator: 4, beneficiary: 17, unlock_at: 5 },
+		);
+	});
+}
+
+#[test]
+fn child_bounties_are_tracked_in_parent_ledger() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101);
+		Balances::make_free_balance_be(&8, 101);
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			15,
+			b"12345-p2".to_vec()
+		));
+		assert_eq!(
+			pallet_bounties::BountyLedgers::<Test>::get(0),
+			Some(pallet_bounties::BountyLedger { funded: 50, child_bounties: 25, paid: 0 })
+		);
+		assert_eq!(pallet_bounties::TotalEncumbered::<Test>::get(), 50);
+
+		// The funds of a closed child-bounty return to the parent bounty.
+		assert_ok!(ChildBounties::close_child_bounty(RuntimeOrigin::signed(4), 0, 1));
+		assert_eq!(
+			pallet_bounties::BountyLedgers::<Test>::get(0),
+			Some(pallet_bounties::BountyLedger { funded: 50, child_bounties: 10, paid: 0 })
+		);
+
+		// The funds of a claimed child-bounty are paid out.
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 2));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 7));
+		System::set_block_number(9);
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 0));
+		assert_eq!(
+			pallet_bounties::BountyLedgers::<Test>::get(0),
+			Some(pallet_bounties::BountyLedger { funded: 50, child_bounties: 0, paid: 10 })
+		);
+		assert_eq!(pallet_bounties::TotalEncumbered::<Test>::get(), 40);
+	});
+}
+
+#[test]
+fn curator_committee_approves_child_bounty_operations() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101);
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+		assert_ok!(Bounties::set_curator_committee(RuntimeOrigin::root(), 0, vec![5], 2));
+
+		// The curator alone can no longer add a child-bounty.
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+		assert_eq!(pallet_child_bounties::ParentChildBounties::<Test>::get(0), 0);
+		assert_noop!(
+			ChildBounties::add_child_bounty(RuntimeOrigin::signed(6), 0, 10, b"12345-p1".to_vec()),
+			BountiesError::RequireCurator,
+		);
+		// A different call needs its own approvals.
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(5),
+			0,
+			11,
+			b"12345-p1".to_vec()
+		));
+		assert_eq!(pallet_child_bounties::ParentChildBounties::<Test>::get(0), 0);
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(5),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+		assert_eq!(last_event(), ChildBountiesEvent::Added { index: 0, child_index: 0 });
+
+		// Proposing the curator of the child-bounty needs the approval of the committee as well.
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(5), 0, 0, 8, 2));
+		assert_eq!(
+			pallet_child_bounties::ChildBounties::<Test>::get(0, 0).unwrap().status,
+			ChildBountyStatus::Added,
+		);
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 2));
+		assert_eq!(
+			pallet_child_bounties::ChildBounties::<Test>::get(0, 0).unwrap().status,
+			ChildBountyStatus::CuratorProposed { curator: 8 },
+		);
+	});
+}
@@ -96,6 +96,7 @@ impl shared::Config for Runtime {
 }
 
 impl configuration::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = configuration::TestWeightInfo;
 }
 
@@ -222,6 +223,7 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = MessageQueueMaxStale;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = ();
+	type OnPageReaped = ();
 	type MessageProcessor = MessageProcessor;
 	type QueueChangeHandler = ();
 	type WeightInfo = ();
@@ -235,5 +237,6 @@ construct_runtime!(
 		ParasOrigin: origin,
 		XcmPallet: pallet_xcm,
 		MessageQueue: pallet_message_queue,
+		Configuration: configuration,
 	}
 );
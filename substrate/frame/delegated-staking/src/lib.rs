@@ -117,6 +117,19 @@
 //! Nomination pool still does all the heavy lifting around pool administration, reward
 //! distribution, lazy slashing and as such, is not meant to be replaced with this pallet.
 //!
+//! ## Governance Participation
+//! Delegated funds are held in the account of the `delegator` and are part of its total balance.
+//! Hence they can be used for voting in governance, e.g. with conviction voting, which locks the
+//! total balance of an account regardless of holds.
+//!
+//! Because slashing is lazy, part of these funds may still be slashed away while they are used for
+//! voting. The pending slash of the `agent` is not attributed to its delegators until the `agent`
+//! applies it, so any delegator may lose up to the whole pending slash. The balance which can
+//! safely be relied upon for voting is therefore the total balance of the account less the
+//! pending slash of its `agent`, capped at the held delegation. See
+//! [`Pallet::delegation_voting_balance`] and [`Pallet::effective_voting_balance`], which are also
+//! exposed via [`runtime_api::DelegatedStakingApi`].
+//!
 //! ## Limitations
 //! - Rewards can not be auto-compounded.
 //! - Slashes are lazy and hence there could be a period of time when an account can use funds for
@@ -128,11 +141,13 @@
 mod impls;
 #[cfg(test)]
 mod mock;
+pub mod runtime_api;
 #[cfg(test)]
 mod tests;
 mod types;
 
 pub use pallet::*;
+pub use types::DelegationVotingBalance;
 
 use types::*;
 
@@ -438,6 +453,30 @@ impl<T: Config> Pallet<T> {
 		Delegator::from(Self::sub_account(AccountType::ProxyDelegator, agent.get()))
 	}
 
+	/// Breakdown of the balance `who` holds for its delegation into the part which can be relied
+	/// upon and the part which may still be slashed.
+	///
+	/// Returns the default (zero) breakdown if `who` is not a `delegator`.
+	pub fn delegation_voting_balance(who: &T::AccountId) -> DelegationVotingBalance<BalanceOf<T>> {
+		let Some(delegation) = Delegation::<T>::get(who) else { return Default::default() };
+
+		let held = Self::held_balance_of(Delegator::from(who.clone()));
+		let at_risk = AgentLedger::<T>::get(&delegation.agent)
+			.map_or(Zero::zero(), |ledger| ledger.pending_slash)
+			.min(held);
+
+		DelegationVotingBalance { held, at_risk, usable: held.saturating_sub(at_risk) }
+	}
+
+	/// The balance of `who` which can be relied upon when voting in governance.
+	///
+	/// This is the total balance of the account, including its delegated funds, less the part of
+	/// the delegated funds which may still be slashed. See
+	/// [`Pallet::delegation_voting_balance`].
+	pub fn effective_voting_balance(who: &T::AccountId) -> BalanceOf<T> {
+		T::Currency::total_balance(who).saturating_sub(Self::delegation_voting_balance(who).at_risk)
+	}
+
 	/// Derive a (keyless) pot account from the given agent account and account type.
 	fn sub_account(account_type: AccountType, acc: T::AccountId) -> T::AccountId {
 		T::PalletId::get().into_sub_account_truncating((account_type, acc.clone()))
@@ -0,0 +1,35 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the delegated staking pallet.
+
+use crate::DelegationVotingBalance;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait DelegatedStakingApi<AccountId, Balance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Returns the breakdown of the balance `who` holds for its delegation.
+		fn delegation_voting_balance(who: AccountId) -> DelegationVotingBalance<Balance>;
+
+		/// Returns the balance of `who` which can be relied upon when voting in governance.
+		fn effective_voting_balance(who: AccountId) -> Balance;
+	}
+}
@@ -334,6 +334,64 @@ fn apply_pending_slash() {
 	});
 }
 
+#[test]
+fn voting_balance_excludes_pending_slash() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_era(1);
+		let agent: AccountId = 200;
+		let reward_acc: AccountId = 201;
+		let ed = ExistentialDeposit::get();
+
+		// delegator 300 delegates 100 and 301 delegates 200.
+		let total_staked = setup_delegation_stake(agent, reward_acc, vec![300, 301], 100, 100);
+		assert_eq!(
+			DelegatedStaking::delegation_voting_balance(&301),
+			DelegationVotingBalance { held: 200, at_risk: 0, usable: 200 }
+		);
+		assert_eq!(DelegatedStaking::effective_voting_balance(&301), 200 + ed);
+
+		start_era(4);
+		// slash half of the stake.
+		pallet_staking::slashing::do_slash::<T>(
+			&agent,
+			total_staked / 2,
+			&mut Default::default(),
+			&mut Default::default(),
+			3,
+		);
+
+		// the whole pending slash may be applied to any delegator, up to its delegation.
+		assert_eq!(
+			DelegatedStaking::delegation_voting_balance(&300),
+			DelegationVotingBalance { held: 100, at_risk: 100, usable: 0 }
+		);
+		assert_eq!(DelegatedStaking::effective_voting_balance(&300), ed);
+		assert_eq!(
+			DelegatedStaking::delegation_voting_balance(&301),
+			DelegationVotingBalance { held: 200, at_risk: 150, usable: 50 }
+		);
+		assert_eq!(DelegatedStaking::effective_voting_balance(&301), 50 + ed);
+
+		// applying the slash reduces the balance at risk.
+		assert_ok!(<DelegatedStaking as DelegationInterface>::delegator_slash(
+			Agent::from(agent),
+			Delegator::from(301),
+			100,
+			None
+		));
+		assert_eq!(
+			DelegatedStaking::delegation_voting_balance(&301),
+			DelegationVotingBalance { held: 100, at_risk: 50, usable: 50 }
+		);
+		assert_eq!(DelegatedStaking::effective_voting_balance(&301), 50 + ed);
+
+		// accounts which do not delegate can use their whole balance.
+		fund(&302, 100);
+		assert_eq!(DelegatedStaking::delegation_voting_balance(&302), Default::default());
+		assert_eq!(DelegatedStaking::effective_voting_balance(&302), 100);
+	});
+}
+
 /// Integration tests with pallet-staking.
 mod staking_integration {
 	use super::*;
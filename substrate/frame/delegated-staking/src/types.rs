@@ -76,6 +76,24 @@ impl<T: Config> Delegation<T> {
 	}
 }
 
+/// Breakdown of the balance a `delegator` holds for its delegation, as relevant for governance.
+///
+/// Delegated funds stay in the account of the `delegator` and can be used for voting, but slashes
+/// of the `Agent` are only applied to the delegators lazily. Until then, part of the held balance
+/// may still be slashed away.
+#[derive(Default, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DelegationVotingBalance<Balance> {
+	/// The balance held in the account of the `delegator` for its delegation.
+	pub held: Balance,
+	/// The part of `held` which may still be slashed by slashes pending on the `Agent`.
+	///
+	/// Since the `Agent` decides how pending slashes are applied among its delegators, this is the
+	/// pending slash of the `Agent` capped at `held`.
+	pub at_risk: Balance,
+	/// The part of `held` which can be relied upon, i.e. `held - at_risk`.
+	pub usable: Balance,
+}
+
 /// Ledger of all delegations to an `Agent`.
 ///
 /// This keeps track of the active balance of the `Agent` that is made up from the funds that
@@ -22,8 +22,8 @@ use crate as pallet_democracy;
 use frame_support::{
 	assert_noop, assert_ok, derive_impl, ord_parameter_types, parameter_types,
 	traits::{
-		ConstU32, ConstU64, Contains, EqualPrivilegeOnly, OnInitialize, SortedMembers,
-		StorePreimage,
+		ConstBool, ConstU32, ConstU64, Contains, EqualPrivilegeOnly, OnInitialize, SortedMembers,
+		StorePreimage, UnixTime,
 	},
 	weights::Weight,
 };
@@ -93,8 +93,16 @@ impl pallet_preimage::Config for Test {
 	type Currency = Balances;
 	type ManagerOrigin = EnsureRoot<u64>;
 	type Consideration = ();
+	type DepositPrice = ();
 }
 
+/// Scheduler time source which never advances.
+pub struct MockTime;
+impl UnixTime for MockTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::ZERO
+	}
+}
 impl pallet_scheduler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeOrigin = RuntimeOrigin;
@@ -103,9 +111,22 @@ impl pallet_scheduler::Config for Test {
 	type MaximumWeight = MaximumSchedulerWeight;
 	type ScheduleOrigin = EnsureRoot<u64>;
 	type MaxScheduledPerBlock = ConstU32<100>;
+	type BlockNumberProvider = System;
 	type WeightInfo = ();
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = ();
+	type RejectOverweight = ConstBool<false>;
+	type TimeProvider = MockTime;
+	type MaximumBlockTime = ConstU64<6_000>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type ScheduleDeposit = ConstU64<0>;
+	type MaxScheduledPerOrigin = ();
+	type MaxSchedulingHorizon = ();
+	type MaxMergedAgendas = ConstU32<1>;
+	type MaxIdleWeight = ();
+	type MaxOverflowed = ConstU32<100>;
+	type OnChargeDispatch = ();
 }
 
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
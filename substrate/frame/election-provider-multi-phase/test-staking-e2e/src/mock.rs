@@ -133,6 +133,7 @@ sp_runtime::impl_opaque_keys! {
 impl pallet_session::Config for Runtime {
 	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Runtime, Staking>;
 	type Keys = SessionKeys;
+	type RequireProofOfPossession = frame_support::traits::ConstBool<false>;
 	type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
 	type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
 	type SessionHandler = (OtherSessionHandler,);
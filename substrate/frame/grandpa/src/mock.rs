@@ -95,6 +95,7 @@ impl pallet_session::Config for Test {
 	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, Staking>;
 	type SessionHandler = <TestSessionKeys as OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = TestSessionKeys;
+	type RequireProofOfPossession = frame_support::traits::ConstBool<false>;
 	type WeightInfo = ();
 }
 
@@ -723,5 +723,47 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn provide_judgement_batch(
+		n: Linear<1, { T::MaxJudgementBatch::get() }>,
+	) -> Result<(), BenchmarkError> {
+		// The registrar providing the judgements has the most registrars before it.
+		let r = T::MaxRegistrars::get() - 1;
+		add_registrars::<T>(r)?;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let registrar_origin = T::RegistrarOrigin::try_successful_origin()
+			.expect("RegistrarOrigin has no successful origin required for the benchmark");
+		Identity::<T>::add_registrar(registrar_origin, caller_lookup)?;
+
+		let mut judgements = Vec::new();
+		for i in 0..n {
+			let user: T::AccountId = account("user", i, SEED);
+			let user_origin =
+				<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(user.clone()));
+			let _ = T::Currency::make_free_balance_be(&user, BalanceOf::<T>::max_value());
+
+			let info = T::IdentityInformation::create_identity_info();
+			let info_hash = T::Hashing::hash_of(&info);
+			Identity::<T>::set_identity(user_origin.clone(), Box::new(info))?;
+			Identity::<T>::request_judgement(user_origin, r, 10u32.into())?;
+
+			judgements.push((T::Lookup::unlookup(user), Judgement::Reasonable, info_hash));
+		}
+		let judgements: BoundedVec<_, _> = judgements.try_into().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), r, judgements);
+
+		let user: T::AccountId = account("user", n - 1, SEED);
+		assert_last_event::<T>(
+			Event::<T>::JudgementGiven { target: user, registrar_index: r }.into(),
+		);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Identity, crate::tests::new_test_ext(), crate::tests::Test);
 }
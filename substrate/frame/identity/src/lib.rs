@@ -106,6 +106,7 @@ use codec::Encode;
 use frame_support::{
 	ensure,
 	pallet_prelude::{DispatchError, DispatchResult},
+	storage::with_storage_layer,
 	traits::{BalanceStatus, Currency, Get, OnUnbalanced, ReservableCurrency, StorageVersion},
 	BoundedVec,
 };
@@ -166,6 +167,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxRegistrars: Get<u32>;
 
+		/// The maximum number of judgements a registrar can provide in a single
+		/// `provide_judgement_batch` call.
+		#[pallet::constant]
+		type MaxJudgementBatch: Get<u32>;
+
 		/// What to do with slashed funds.
 		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
 
@@ -368,6 +374,9 @@ pub mod pallet {
 		JudgementUnrequested { who: T::AccountId, registrar_index: RegistrarIndex },
 		/// A judgement was given by a registrar.
 		JudgementGiven { target: T::AccountId, registrar_index: RegistrarIndex },
+		/// A judgement of a batch could not be given. `index` is the position of the judgement
+		/// within the batch.
+		JudgementFailed { index: u32, registrar_index: RegistrarIndex, error: DispatchError },
 		/// A registrar was added.
 		RegistrarAdded { registrar_index: RegistrarIndex },
 		/// A sub-identity was added to an identity and the deposit paid.
@@ -812,44 +821,11 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			let target = T::Lookup::lookup(target)?;
-			ensure!(!judgement.has_deposit(), Error::<T>::InvalidJudgement);
-			<Registrars<T>>::get()
-				.get(reg_index as usize)
-				.and_then(Option::as_ref)
-				.filter(|r| r.account == sender)
-				.ok_or(Error::<T>::InvalidIndex)?;
-			let (mut id, username) =
-				<IdentityOf<T>>::get(&target).ok_or(Error::<T>::InvalidTarget)?;
-
-			if T::Hashing::hash_of(&id.info) != identity {
-				return Err(Error::<T>::JudgementForDifferentIdentity.into())
-			}
+			Self::ensure_registrar(&sender, reg_index)?;
+			let judgements =
+				Self::do_provide_judgement(&sender, reg_index, target, judgement, identity)?;
 
-			let item = (reg_index, judgement);
-			match id.judgements.binary_search_by_key(&reg_index, |x| x.0) {
-				Ok(position) => {
-					if let Judgement::FeePaid(fee) = id.judgements[position].1 {
-						T::Currency::repatriate_reserved(
-							&target,
-							&sender,
-							fee,
-							BalanceStatus::Free,
-						)
-						.map_err(|_| Error::<T>::JudgementPaymentFailed)?;
-					}
-					id.judgements[position] = item
-				},
-				Err(position) => id
-					.judgements
-					.try_insert(position, item)
-					.map_err(|_| Error::<T>::TooManyRegistrars)?,
-			}
-
-			let judgements = id.judgements.len();
-			<IdentityOf<T>>::insert(&target, (id, username));
-			Self::deposit_event(Event::JudgementGiven { target, registrar_index: reg_index });
-
-			Ok(Some(T::WeightInfo::provide_judgement(judgements as u32)).into())
+			Ok(Some(T::WeightInfo::provide_judgement(judgements)).into())
 		}
 
 		/// Remove an account's identity and sub-account information and slash the deposits.
@@ -1193,6 +1169,50 @@ pub mod pallet {
 			Self::deposit_event(Event::DanglingUsernameRemoved { who: who.clone(), username });
 			Ok(Pays::No.into())
 		}
+
+		/// Provide judgements for a batch of accounts' identities.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the account
+		/// of the registrar whose index is `reg_index`.
+		///
+		/// - `reg_index`: the index of the registrar whose judgements are being made.
+		/// - `judgements`: the `(target, judgement, identity)` tuples, each with the same meaning
+		///   as the arguments of `provide_judgement`.
+		///
+		/// Judgements are provided independently of each other: a judgement that cannot be given
+		/// doesn't revert the others.
+		///
+		/// Emits `JudgementGiven` for every judgement given and `JudgementFailed` for every
+		/// judgement that could not be given.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::provide_judgement_batch(judgements.len() as u32))]
+		pub fn provide_judgement_batch(
+			origin: OriginFor<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+			judgements: BoundedVec<
+				(AccountIdLookupOf<T>, Judgement<BalanceOf<T>>, T::Hash),
+				T::MaxJudgementBatch,
+			>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_registrar(&sender, reg_index)?;
+
+			for (index, (target, judgement, identity)) in judgements.into_iter().enumerate() {
+				let result = with_storage_layer(|| {
+					let target = T::Lookup::lookup(target)?;
+					Self::do_provide_judgement(&sender, reg_index, target, judgement, identity)
+				});
+				if let Err(error) = result {
+					Self::deposit_event(Event::JudgementFailed {
+						index: index as u32,
+						registrar_index: reg_index,
+						error,
+					});
+				}
+			}
+
+			Ok(())
+		}
 	}
 }
 
@@ -1206,6 +1226,56 @@ impl<T: Config> Pallet<T> {
 			.collect()
 	}
 
+	/// Ensure that `who` is the account of the registrar with index `reg_index`.
+	fn ensure_registrar(who: &T::AccountId, reg_index: RegistrarIndex) -> DispatchResult {
+		<Registrars<T>>::get()
+			.get(reg_index as usize)
+			.and_then(Option::as_ref)
+			.filter(|r| &r.account == who)
+			.ok_or(Error::<T>::InvalidIndex)?;
+		Ok(())
+	}
+
+	/// Give the `judgement` of the registrar `registrar` with index `reg_index` on the identity
+	/// of `target`, returning the number of judgements of the identity.
+	///
+	/// The caller must ensure that `registrar` is the account of the registrar.
+	fn do_provide_judgement(
+		registrar: &T::AccountId,
+		reg_index: RegistrarIndex,
+		target: T::AccountId,
+		judgement: Judgement<BalanceOf<T>>,
+		identity: T::Hash,
+	) -> Result<u32, DispatchError> {
+		ensure!(!judgement.has_deposit(), Error::<T>::InvalidJudgement);
+		let (mut id, username) = <IdentityOf<T>>::get(&target).ok_or(Error::<T>::InvalidTarget)?;
+
+		if T::Hashing::hash_of(&id.info) != identity {
+			return Err(Error::<T>::JudgementForDifferentIdentity.into())
+		}
+
+		let item = (reg_index, judgement);
+		match id.judgements.binary_search_by_key(&reg_index, |x| x.0) {
+			Ok(position) => {
+				if let Judgement::FeePaid(fee) = id.judgements[position].1 {
+					T::Currency::repatriate_reserved(&target, registrar, fee, BalanceStatus::Free)
+						.map_err(|_| Error::<T>::JudgementPaymentFailed)?;
+				}
+				id.judgements[position] = item
+			},
+			Err(position) => id
+				.judgements
+				.try_insert(position, item)
+				.map_err(|_| Error::<T>::TooManyRegistrars)?,
+		}
+
+		let judgements = id.judgements.len() as u32;
+		<IdentityOf<T>>::insert(&target, (id, username));
+		Self::deposit_event(Event::JudgementGiven { target, registrar_index: reg_index });
+
+		Ok(judgements)
+	}
+
 	/// Calculate the deposit required for a number of `sub` accounts.
 	fn subs_deposit(subs: u32) -> BalanceOf<T> {
 		T::SubAccountDeposit::get().saturating_mul(<BalanceOf<T>>::from(subs))
@@ -81,6 +81,7 @@ impl pallet_identity::Config for Test {
 	type MaxSubAccounts = ConstU32<2>;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
+	type MaxJudgementBatch = ConstU32<32>;
 	type RegistrarOrigin = EnsureRoot<Self::AccountId>;
 	type ForceOrigin = EnsureRoot<Self::AccountId>;
 	type OffchainSignature = MultiSignature;
@@ -488,6 +489,98 @@ fn uninvited_judgement_should_work() {
 	});
 }
 
+#[test]
+fn provide_judgement_batch_works() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, twenty, _, _] = accounts();
+		let judgements = |items: Vec<(AccountIdOf<Test>, Judgement<u64>, H256)>| {
+			BoundedVec::try_from(items).unwrap()
+		};
+		assert_noop!(
+			Identity::provide_judgement_batch(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				judgements(vec![(ten.clone(), Judgement::Reasonable, H256::random())])
+			),
+			Error::<Test>::InvalidIndex
+		);
+
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(twenty.clone()),
+			Box::new(infoof_twenty())
+		));
+		let ten_hash = BlakeTwo256::hash_of(&infoof_ten());
+		let twenty_hash = BlakeTwo256::hash_of(&infoof_twenty());
+
+		// Only the registrar can provide judgements.
+		assert_noop!(
+			Identity::provide_judgement_batch(
+				RuntimeOrigin::signed(ten.clone()),
+				0,
+				judgements(vec![(ten.clone(), Judgement::Reasonable, ten_hash)])
+			),
+			Error::<Test>::InvalidIndex
+		);
+
+		// Failing judgements don't prevent the others from being given.
+		assert_ok!(Identity::provide_judgement_batch(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			judgements(vec![
+				(ten.clone(), Judgement::Reasonable, ten_hash),
+				(twenty.clone(), Judgement::KnownGood, ten_hash),
+				(three.clone(), Judgement::Reasonable, ten_hash),
+				(twenty.clone(), Judgement::FeePaid(1), twenty_hash),
+				(twenty.clone(), Judgement::KnownGood, twenty_hash),
+			])
+		));
+		assert_eq!(
+			Identity::identity(&ten).unwrap().0.judgements,
+			vec![(0, Judgement::Reasonable)]
+		);
+		assert_eq!(
+			Identity::identity(&twenty).unwrap().0.judgements,
+			vec![(0, Judgement::KnownGood)]
+		);
+
+		System::assert_has_event(
+			Event::JudgementGiven { target: ten.clone(), registrar_index: 0 }.into(),
+		);
+		System::assert_has_event(
+			Event::JudgementFailed {
+				index: 1,
+				registrar_index: 0,
+				error: Error::<Test>::JudgementForDifferentIdentity.into(),
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::JudgementFailed {
+				index: 2,
+				registrar_index: 0,
+				error: Error::<Test>::InvalidTarget.into(),
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::JudgementFailed {
+				index: 3,
+				registrar_index: 0,
+				error: Error::<Test>::InvalidJudgement.into(),
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			Event::JudgementGiven { target: twenty, registrar_index: 0 }.into(),
+		);
+	});
+}
+
 #[test]
 fn clearing_judgement_should_work() {
 	new_test_ext().execute_with(|| {
@@ -74,6 +74,7 @@ pub trait WeightInfo {
 	fn remove_expired_approval() -> Weight;
 	fn set_primary_username() -> Weight;
 	fn remove_dangling_username() -> Weight;
+	fn provide_judgement_batch(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_identity` using the Substrate node and recommended hardware.
@@ -441,6 +442,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:32 w:32)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	fn provide_judgement_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1165 + n * (7145 ±0)`
+		//  Estimated: `2626 + n * (10047 ±0)`
+		// Minimum execution time: 99_825_000 picoseconds.
+		Weight::from_parts(12_172_420, 2626)
+			// Standard Error: 41_315
+			.saturating_add(Weight::from_parts(90_346_281, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10047).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -807,4 +826,22 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:32 w:32)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	fn provide_judgement_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1165 + n * (7145 ±0)`
+		//  Estimated: `2626 + n * (10047 ±0)`
+		// Minimum execution time: 99_825_000 picoseconds.
+		Weight::from_parts(12_172_420, 2626)
+			// Standard Error: 41_315
+			.saturating_add(Weight::from_parts(90_346_281, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10047).saturating_mul(n.into()))
+	}
 }
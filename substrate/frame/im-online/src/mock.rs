@@ -129,6 +129,7 @@ impl pallet_session::Config for Runtime {
 	type ValidatorId = u64;
 	type ValidatorIdOf = ConvertInto;
 	type Keys = UintAuthorityId;
+	type RequireProofOfPossession = frame_support::traits::ConstBool<false>;
 	type RuntimeEvent = RuntimeEvent;
 	type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
 	type WeightInfo = ();
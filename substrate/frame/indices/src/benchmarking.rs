@@ -92,6 +92,31 @@ benchmarks! {
 		assert_eq!(Accounts::<T>::get(account_index).unwrap().2, true);
 	}
 
+	link_identity {
+		let account_index = T::AccountIndex::from(SEED);
+		// Setup accounts
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		// Claim the index
+		Indices::<T>::claim(RawOrigin::Signed(caller.clone()).into(), account_index)?;
+	}: _(RawOrigin::Signed(caller.clone()), account_index)
+	verify {
+		assert!(IdentityLinks::<T>::contains_key(account_index));
+	}
+
+	unlink_identity {
+		let account_index = T::AccountIndex::from(SEED);
+		// Setup accounts
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		// Claim and link the index
+		Indices::<T>::claim(RawOrigin::Signed(caller.clone()).into(), account_index)?;
+		Indices::<T>::link_identity(RawOrigin::Signed(caller.clone()).into(), account_index)?;
+	}: _(RawOrigin::Signed(caller.clone()), account_index)
+	verify {
+		assert!(!IdentityLinks::<T>::contains_key(account_index));
+	}
+
 	// TODO in another PR: lookup and unlookup trait weights (not critical)
 
 	impl_benchmark_test_suite!(Indices, crate::mock::new_test_ext(), crate::mock::Test);
@@ -17,11 +17,16 @@
 
 //! An index is a short form of an address. This module handles allocation
 //! of indices for a newly created accounts.
+//!
+//! The owner of an index can link it to their identity, as provided by
+//! [`Config::IdentityProvider`], so that anyone resolving the index with the
+//! [`runtime_api::IndicesApi`] can verify who owns it.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
 mod mock;
+pub mod runtime_api;
 mod tests;
 pub mod weights;
 
@@ -40,6 +45,20 @@ type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup
 
 pub use pallet::*;
 
+/// Interface for looking up the identities of accounts, e.g. from the identity pallet.
+pub trait IdentityProvider<AccountId> {
+	/// Function that returns the display name of the identity of an account, if it has one which
+	/// has been verified, e.g. judged by a registrar. Unverified display names can be spoofed.
+	fn display(who: &AccountId) -> Option<Vec<u8>>;
+}
+
+/// The non-provider. No account has an identity.
+impl<AccountId> IdentityProvider<AccountId> for () {
+	fn display(_who: &AccountId) -> Option<Vec<u8>> {
+		None
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -70,6 +89,9 @@ pub mod pallet {
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		/// The provider of the identities which indices can be linked to.
+		type IdentityProvider: IdentityProvider<Self::AccountId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -136,6 +158,7 @@ pub mod pallet {
 				*maybe_value = Some((new.clone(), amount.saturating_sub(lost), false));
 				Ok(())
 			})?;
+			IdentityLinks::<T>::remove(index);
 			Self::deposit_event(Event::IndexAssigned { who: new, index });
 			Ok(())
 		}
@@ -164,6 +187,7 @@ pub mod pallet {
 				T::Currency::unreserve(&who, amount);
 				Ok(())
 			})?;
+			IdentityLinks::<T>::remove(index);
 			Self::deposit_event(Event::IndexFreed { index });
 			Ok(())
 		}
@@ -198,6 +222,7 @@ pub mod pallet {
 				}
 				*maybe_value = Some((new.clone(), Zero::zero(), freeze));
 			});
+			IdentityLinks::<T>::remove(index);
 			Self::deposit_event(Event::IndexAssigned { who: new, index });
 			Ok(())
 		}
@@ -230,6 +255,53 @@ pub mod pallet {
 			Self::deposit_event(Event::IndexFrozen { index, who });
 			Ok(())
 		}
+
+		/// Link an index owned by the sender to their identity, so that the display of the
+		/// identity is returned when resolving the index.
+		///
+		/// The link is removed when the index is transferred or freed.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must own the index.
+		///
+		/// - `index`: the index to be linked. This must be owned by the sender.
+		///
+		/// Emits `IdentityLinked` if successful.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::link_identity())]
+		pub fn link_identity(origin: OriginFor<T>, index: T::AccountIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (account, _, _) = Accounts::<T>::get(index).ok_or(Error::<T>::NotAssigned)?;
+			ensure!(account == who, Error::<T>::NotOwner);
+			IdentityLinks::<T>::insert(index, ());
+			Self::deposit_event(Event::IdentityLinked { index, who });
+			Ok(())
+		}
+
+		/// Remove the link between an index owned by the sender and their identity.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must own the index.
+		///
+		/// - `index`: the index to be unlinked. This must be owned by the sender and linked.
+		///
+		/// Emits `IdentityUnlinked` if successful.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::unlink_identity())]
+		pub fn unlink_identity(origin: OriginFor<T>, index: T::AccountIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (account, _, _) = Accounts::<T>::get(index).ok_or(Error::<T>::NotAssigned)?;
+			ensure!(account == who, Error::<T>::NotOwner);
+			ensure!(IdentityLinks::<T>::take(index).is_some(), Error::<T>::NotLinked);
+			Self::deposit_event(Event::IdentityUnlinked { index });
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -241,6 +313,10 @@ pub mod pallet {
 		IndexFreed { index: T::AccountIndex },
 		/// A account index has been frozen to its current account ID.
 		IndexFrozen { index: T::AccountIndex, who: T::AccountId },
+		/// A account index has been linked to the identity of its owner.
+		IdentityLinked { index: T::AccountIndex, who: T::AccountId },
+		/// A account index has been unlinked from the identity of its owner.
+		IdentityUnlinked { index: T::AccountIndex },
 	}
 
 	#[pallet::error]
@@ -255,6 +331,8 @@ pub mod pallet {
 		NotTransfer,
 		/// The index is permanent and may not be freed/changed.
 		Permanent,
+		/// The index is not linked to the identity of its owner.
+		NotLinked,
 	}
 
 	/// The lookup from index to account.
@@ -262,6 +340,10 @@ pub mod pallet {
 	pub type Accounts<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountIndex, (T::AccountId, BalanceOf<T>, bool)>;
 
+	/// The indices which are linked to the identity of their owner.
+	#[pallet::storage]
+	pub type IdentityLinks<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountIndex, ()>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -294,6 +376,16 @@ impl<T: Config> Pallet<T> {
 			_ => None,
 		}
 	}
+
+	/// Resolve an index to its owner and, if the index is linked to the identity of its owner,
+	/// the display of that identity.
+	pub fn resolve_index(index: T::AccountIndex) -> Option<(T::AccountId, Option<Vec<u8>>)> {
+		let account = Self::lookup_index(index)?;
+		let display = IdentityLinks::<T>::contains_key(index)
+			.then(|| T::IdentityProvider::display(&account))
+			.flatten();
+		Some((account, display))
+	}
 }
 
 impl<T: Config> StaticLookup for Pallet<T> {
@@ -19,7 +19,7 @@
 
 #![cfg(test)]
 
-use crate::{self as pallet_indices, Config};
+use crate::{self as pallet_indices, Config, IdentityProvider};
 use frame_support::{derive_impl, traits::ConstU64};
 use sp_runtime::BuildStorage;
 
@@ -47,11 +47,20 @@ impl pallet_balances::Config for Test {
 	type AccountStore = System;
 }
 
+/// Only account 1 has an identity.
+pub struct TestIdentityProvider;
+impl IdentityProvider<u64> for TestIdentityProvider {
+	fn display(who: &u64) -> Option<Vec<u8>> {
+		(*who == 1).then(|| b"one".to_vec())
+	}
+}
+
 impl Config for Test {
 	type AccountIndex = u64;
 	type Currency = Balances;
 	type Deposit = ConstU64<1>;
 	type RuntimeEvent = RuntimeEvent;
+	type IdentityProvider = TestIdentityProvider;
 	type WeightInfo = ();
 }
 
@@ -0,0 +1,33 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the indices pallet.
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait IndicesApi<AccountIndex, AccountId>
+	where
+		AccountIndex: Codec,
+		AccountId: Codec,
+	{
+		/// Returns the owner of `index` and, if the owner linked the index to their identity,
+		/// the display of that identity.
+		fn resolve_index(index: AccountIndex) -> Option<(AccountId, Option<Vec<u8>>)>;
+	}
+}
@@ -119,3 +119,38 @@ fn force_transfer_index_on_free_should_work() {
 		assert_eq!(Indices::lookup_index(0), Some(3));
 	});
 }
+
+#[test]
+fn identity_linking_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Indices::claim(Some(1).into(), 0));
+		assert_ok!(Indices::claim(Some(2).into(), 1));
+		assert_noop!(Indices::link_identity(Some(2).into(), 0), Error::<Test>::NotOwner);
+		assert_noop!(Indices::link_identity(Some(1).into(), 2), Error::<Test>::NotAssigned);
+		assert_noop!(Indices::unlink_identity(Some(1).into(), 0), Error::<Test>::NotLinked);
+		assert_eq!(Indices::resolve_index(0), Some((1, None)));
+
+		assert_ok!(Indices::link_identity(Some(1).into(), 0));
+		assert_eq!(Indices::resolve_index(0), Some((1, Some(b"one".to_vec()))));
+		// Accounts without an identity resolve without a display.
+		assert_ok!(Indices::link_identity(Some(2).into(), 1));
+		assert_eq!(Indices::resolve_index(1), Some((2, None)));
+		assert_eq!(Indices::resolve_index(2), None);
+
+		assert_ok!(Indices::unlink_identity(Some(1).into(), 0));
+		assert_eq!(Indices::resolve_index(0), Some((1, None)));
+	});
+}
+
+#[test]
+fn identity_link_is_removed_on_transfer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Indices::claim(Some(1).into(), 0));
+		assert_ok!(Indices::link_identity(Some(1).into(), 0));
+		assert_ok!(Indices::transfer(Some(1).into(), Id(2), 0));
+		assert!(!IdentityLinks::<Test>::contains_key(0));
+		assert_ok!(Indices::transfer(Some(2).into(), Id(1), 0));
+		// The previous link does not carry over to the new owner.
+		assert_eq!(Indices::resolve_index(0), Some((1, None)));
+	});
+}
@@ -200,18 +200,21 @@ mod benchmarking;
 mod integration_test;
 mod mock;
 pub mod mock_helpers;
+pub mod runtime_api;
 mod tests;
 pub mod weights;
 
 use codec::{Codec, Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	defensive,
+	dispatch::Pays,
 	pallet_prelude::*,
 	traits::{
-		Defensive, DefensiveSaturating, DefensiveTruncateFrom, EnqueueMessage,
-		ExecuteOverweightError, Footprint, ProcessMessage, ProcessMessageError, QueueFootprint,
-		QueuePausedQuery, ServiceQueues,
+		fungible::Mutate, tokens::Preservation, Defensive, DefensiveSaturating,
+		DefensiveTruncateFrom, EnqueueMessage, ExecuteOverweightError, Footprint, ProcessMessage,
+		ProcessMessageError, QueueFootprint, QueuePausedQuery, ServiceQueues,
 	},
+	weights::RuntimeDbWeight,
 	BoundedSlice, CloneNoBound, DefaultNoBound,
 };
 use frame_system::pallet_prelude::*;
@@ -459,6 +462,50 @@ impl<Id> OnQueueChanged<Id> for () {
 	fn on_queue_changed(_: Id, _: QueueFootprint) {}
 }
 
+/// Handler code for when a page was reaped through the `reap_page` call.
+///
+/// Can be used to reward the reaper for keeping the queues clean.
+pub trait OnPageReaped<AccountId> {
+	/// Note that `reaper` successfully reaped a page.
+	fn on_page_reaped(reaper: &AccountId);
+
+	/// The maximal weight consumed by [`Self::on_page_reaped`].
+	fn weight() -> Weight;
+}
+
+impl<AccountId> OnPageReaped<AccountId> for () {
+	fn on_page_reaped(_: &AccountId) {}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Rewards the reaper of a page with `Reward` from the `Pot` account.
+///
+/// The reward is skipped if the pot cannot afford it. `DbWeight` is used to account for the
+/// weight of the transfer.
+pub struct RewardReaperFromPot<Currency, Pot, Reward, DbWeight>(
+	sp_std::marker::PhantomData<(Currency, Pot, Reward, DbWeight)>,
+);
+impl<AccountId, Currency, Pot, Reward, DbWeight> OnPageReaped<AccountId>
+	for RewardReaperFromPot<Currency, Pot, Reward, DbWeight>
+where
+	Currency: Mutate<AccountId>,
+	Pot: Get<AccountId>,
+	Reward: Get<Currency::Balance>,
+	DbWeight: Get<RuntimeDbWeight>,
+{
+	fn on_page_reaped(reaper: &AccountId) {
+		// An empty pot just means that there is no reward.
+		let _ = Currency::transfer(&Pot::get(), reaper, Reward::get(), Preservation::Preserve);
+	}
+
+	fn weight() -> Weight {
+		DbWeight::get().reads_writes(2, 2)
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -540,6 +587,11 @@ pub mod pallet {
 		/// If `None`, it will not call `ServiceQueues::service_queues` in `on_idle`.
 		#[pallet::constant]
 		type IdleMaxServiceWeight: Get<Option<Weight>>;
+
+		/// Code to be called when a page was reaped through `reap_page`.
+		///
+		/// Use [`RewardReaperFromPot`] to reward reapers, or `()` to not reward them.
+		type OnPageReaped: OnPageReaped<Self::AccountId>;
 	}
 
 	#[pallet::event]
@@ -676,15 +728,21 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Remove a page which has no more messages remaining to be processed or is stale.
+		///
+		/// The transaction fee is waived if the page was reaped, and the reaper may be rewarded
+		/// through [`Config::OnPageReaped`]. Use the
+		/// [`MessageQueueApi`](runtime_api::MessageQueueApi) to find reapable pages.
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::reap_page())]
+		#[pallet::weight(T::WeightInfo::reap_page().saturating_add(T::OnPageReaped::weight()))]
 		pub fn reap_page(
 			origin: OriginFor<T>,
 			message_origin: MessageOriginOf<T>,
 			page_index: PageIndex,
-		) -> DispatchResult {
-			let _ = ensure_signed(origin)?;
-			Self::do_reap_page(&message_origin, page_index)
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_reap_page(&message_origin, page_index)?;
+			T::OnPageReaped::on_page_reaped(&who);
+			Ok(Pays::No.into())
 		}
 
 		/// Execute an overweight message.
@@ -1035,50 +1093,7 @@ impl<T: Config> Pallet<T> {
 		ensure!(page_index < book_state.begin, Error::<T>::NotReapable);
 
 		let page = Pages::<T>::get(origin, page_index).ok_or(Error::<T>::NoPage)?;
-
-		// definitely reapable if the page has no messages in it.
-		let reapable = page.remaining.is_zero();
-
-		// also reapable if the page index has dropped below our watermark.
-		let cullable = || {
-			let total_pages = book_state.count;
-			let ready_pages = book_state.end.saturating_sub(book_state.begin).min(total_pages);
-
-			// The number of stale pages - i.e. pages which contain unprocessed overweight messages.
-			// We would prefer to keep these around but will restrict how far into history they can
-			// extend if we notice that there's too many of them.
-			//
-			// We don't know *where* in history these pages are so we use a dynamic formula which
-			// reduces the historical time horizon as the stale pages pile up and increases it as
-			// they reduce.
-			let stale_pages = total_pages - ready_pages;
-
-			// The maximum number of stale pages (i.e. of overweight messages) allowed before
-			// culling can happen at all. Once there are more stale pages than this, then historical
-			// pages may be dropped, even if they contain unprocessed overweight messages.
-			let max_stale = T::MaxStale::get();
-
-			// The amount beyond the maximum which are being used. If it's not beyond the maximum
-			// then we exit now since no culling is needed.
-			let overflow = match stale_pages.checked_sub(max_stale + 1) {
-				Some(x) => x + 1,
-				None => return false,
-			};
-
-			// The special formula which tells us how deep into index-history we will pages. As
-			// the overflow is greater (and thus the need to drop items from storage is more urgent)
-			// this is reduced, allowing a greater range of pages to be culled.
-			// With a minimum `overflow` (`1`), this returns `max_stale ** 2`, indicating we only
-			// cull beyond that number of indices deep into history.
-			// At this overflow increases, our depth reduces down to a limit of `max_stale`. We
-			// never want to reduce below this since this will certainly allow enough pages to be
-			// culled in order to bring `overflow` back to zero.
-			let backlog = (max_stale * max_stale / overflow).max(max_stale);
-
-			let watermark = book_state.begin.saturating_sub(backlog);
-			page_index < watermark
-		};
-		ensure!(reapable || cullable(), Error::<T>::NotReapable);
+		ensure!(Self::is_reapable(&book_state, page_index, &page), Error::<T>::NotReapable);
 
 		Pages::<T>::remove(origin, page_index);
 		debug_assert!(book_state.count > 0, "reaping a page implies there are pages");
@@ -1092,6 +1107,70 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Whether the page `page_index` of a queue with the given `book_state` can be reaped.
+	fn is_reapable(book_state: &BookStateOf<T>, page_index: PageIndex, page: &PageOf<T>) -> bool {
+		// definitely not reapable if the page's index is no less than the `begin`ning of ready
+		// pages.
+		if page_index >= book_state.begin {
+			return false
+		}
+
+		// definitely reapable if the page has no messages in it.
+		if page.remaining.is_zero() {
+			return true
+		}
+
+		// also reapable if the page index has dropped below our watermark.
+		let total_pages = book_state.count;
+		let ready_pages = book_state.end.saturating_sub(book_state.begin).min(total_pages);
+
+		// The number of stale pages - i.e. pages which contain unprocessed overweight messages.
+		// We would prefer to keep these around but will restrict how far into history they can
+		// extend if we notice that there's too many of them.
+		//
+		// We don't know *where* in history these pages are so we use a dynamic formula which
+		// reduces the historical time horizon as the stale pages pile up and increases it as
+		// they reduce.
+		let stale_pages = total_pages - ready_pages;
+
+		// The maximum number of stale pages (i.e. of overweight messages) allowed before
+		// culling can happen at all. Once there are more stale pages than this, then historical
+		// pages may be dropped, even if they contain unprocessed overweight messages.
+		let max_stale = T::MaxStale::get();
+
+		// The amount beyond the maximum which are being used. If it's not beyond the maximum
+		// then we exit now since no culling is needed.
+		let overflow = match stale_pages.checked_sub(max_stale + 1) {
+			Some(x) => x + 1,
+			None => return false,
+		};
+
+		// The special formula which tells us how deep into index-history we will pages. As
+		// the overflow is greater (and thus the need to drop items from storage is more urgent)
+		// this is reduced, allowing a greater range of pages to be culled.
+		// With a minimum `overflow` (`1`), this returns `max_stale ** 2`, indicating we only
+		// cull beyond that number of indices deep into history.
+		// At this overflow increases, our depth reduces down to a limit of `max_stale`. We
+		// never want to reduce below this since this will certainly allow enough pages to be
+		// culled in order to bring `overflow` back to zero.
+		let backlog = (max_stale * max_stale / overflow).max(max_stale);
+
+		let watermark = book_state.begin.saturating_sub(backlog);
+		page_index < watermark
+	}
+
+	/// The pages of all queues which can currently be reaped, at most `limit` of them.
+	pub fn reapable_pages(limit: u32) -> Vec<(MessageOriginOf<T>, PageIndex)> {
+		BookStateFor::<T>::iter()
+			.flat_map(|(origin, book_state)| {
+				Pages::<T>::iter_prefix(&origin)
+					.filter(move |(index, page)| Self::is_reapable(&book_state, *index, page))
+					.map(move |(index, _)| (origin.clone(), index))
+			})
+			.take(limit as usize)
+			.collect()
+	}
+
 	/// Execute any messages remaining to be processed in the queue of `origin`, using up to
 	/// `weight_limit` to do so. Any messages which would take more than `overweight_limit` to
 	/// execute are deemed overweight and ignored.
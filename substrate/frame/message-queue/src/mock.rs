@@ -57,6 +57,7 @@ impl Config for Test {
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
 	type IdleMaxServiceWeight = ServiceWeight;
+	type OnPageReaped = RecordingPageReaper;
 }
 
 /// Mocked `WeightInfo` impl with allows to set the weight per call.
@@ -275,6 +276,23 @@ impl OnQueueChanged<MessageOrigin> for RecordingQueueChangeHandler {
 	}
 }
 
+parameter_types! {
+	/// Storage for `RecordingPageReaper`, do not use directly.
+	pub static PageReapers: Vec<u64> = vec![];
+}
+
+/// Records the reaper of every page reaped through `reap_page` into [`PageReapers`].
+pub struct RecordingPageReaper;
+impl OnPageReaped<u64> for RecordingPageReaper {
+	fn on_page_reaped(reaper: &u64) {
+		PageReapers::mutate(|reapers| reapers.push(*reaper));
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 parameter_types! {
 	pub static PausedQueues: Vec<MessageOrigin> = vec![];
 }
@@ -306,6 +306,39 @@ fn service_queues_regression_1873() {
 	});
 }
 
+#[test]
+fn reap_page_call_rewards_reaper_and_waives_fee() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		let n = (MaxStale::get() + 10) as usize;
+		for _ in 0..n {
+			MessageQueue::enqueue_message(msg("weight=2"), Here);
+		}
+		assert!(MessageQueue::reapable_pages(u32::MAX).is_empty());
+		// Mark all pages as stale since their message is permanently overweight.
+		MessageQueue::service_queues(1.into_weight());
+		assert_eq!(MessageQueue::reapable_pages(1).len(), 1);
+
+		// Reaping a page can make others unreapable, so only ever reap the first listed one.
+		let mut reaped = 0;
+		while let Some((origin, page)) = MessageQueue::reapable_pages(1).pop() {
+			let info = MessageQueue::reap_page(RuntimeOrigin::signed(7), origin, page).unwrap();
+			assert_eq!(info.pays_fee, Pays::No);
+			reaped += 1;
+		}
+		assert!(reaped > 0);
+		assert_eq!(PageReapers::take(), vec![7; reaped]);
+
+		// Failing to reap a page is not rewarded.
+		let (origin, page, _) = Pages::<Test>::iter().next().unwrap();
+		assert_noop!(
+			MessageQueue::reap_page(RuntimeOrigin::signed(7), origin, page),
+			Error::<Test>::NotReapable
+		);
+		assert!(PageReapers::take().is_empty());
+	});
+}
+
 #[test]
 fn reap_page_permanent_overweight_works() {
 	use MessageOrigin::*;
@@ -20,7 +20,7 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use frame_benchmarking::v1::{account, benchmarks};
+use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller};
 use frame_system::RawOrigin;
 use sp_runtime::traits::Bounded;
 
@@ -36,8 +36,8 @@ fn setup_multi<T: Config>(
 	for i in 0..s {
 		let signatory = account("signatory", i, SEED);
 		// Give them some balance for a possible deposit
-		let balance = BalanceOf::<T>::max_value();
-		T::Currency::make_free_balance_be(&signatory, balance);
+		let balance = BalanceOf::<T>::max_value() / 1_000u32.into();
+		T::Currency::set_balance(&signatory, balance);
 		signatories.push(signatory);
 	}
 	signatories.sort();
@@ -121,7 +121,19 @@ benchmarks! {
 		// before the call, get the timepoint
 		let timepoint = Multisig::<T>::timepoint();
 		// Create the multi
-		Multisig::<T>::as_multi(RawOrigin::Signed(caller).into(), s as u16, signatories, None, call.clone(), Weight::zero())?;
+		Multisig::<T>::as_multi(RawOrigin::Signed(caller.clone()).into(), s as u16, signatories.clone(), None, call.clone(), Weight::zero())?;
+		// Worst case: the operation has an expiry and a scheduled deadline to clean up.
+		let expires_at = T::BlockNumberProvider::current_block_number() + 10u32.into();
+		let o = RawOrigin::Signed(caller).into();
+		Multisig::<T>::set_expiry(o, s as u16, signatories, call_hash, Some(expires_at))?;
+		T::Currency::set_balance(&multi_account_id, BalanceOf::<T>::max_value() / 1_000u32.into());
+		let deadline = Deadline {
+			at: expires_at,
+			threshold: s as u16,
+			call_weight: call.get_dispatch_info().weight,
+			call_len: z,
+		};
+		Multisig::<T>::schedule_deadline(&multi_account_id, call_hash, deadline)?;
 		// Everyone except the first person approves
 		for i in 1 .. s - 1 {
 			let mut signatories_loop = signatories2.clone();
@@ -135,6 +147,46 @@ benchmarks! {
 		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller2);
 		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
 	}: as_multi(RawOrigin::Signed(caller2), s as u16, signatories2, Some(timepoint), call, Weight::MAX)
+	verify {
+		assert!(!Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+		assert!(!MultisigDeadlines::<T>::contains_key(&multi_account_id, call_hash));
+	}
+
+	as_multi_with_signatures {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length
+		let z in 0 .. 10_000;
+		let mut signers: Vec<(T::SigningPublicKey, T::AccountId)> =
+			(0..s).map(|i| T::BenchmarkHelper::signer(i)).collect();
+		signers.sort_by(|a, b| a.1.cmp(&b.1));
+		for (_, signatory) in signers.iter() {
+			T::Currency::set_balance(signatory, BalanceOf::<T>::max_value() / 1_000u32.into());
+		}
+		let (_, caller) = signers.pop().ok_or("signers should have len 2 or more")?;
+		let signatories: Vec<T::AccountId> = signers.iter().map(|(_, a)| a.clone()).collect();
+		let mut all_signatories = signatories.clone();
+		all_signatories.push(caller.clone());
+		let multi_account_id = Multisig::<T>::multi_account_id(&all_signatories, s as u16);
+		let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark {
+			remark: vec![0; z as usize]
+		}.into();
+		let call_hash = call.using_encoded(blake2_256);
+		// before the call, get the timepoint
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::approve_as_multi(o, s as u16, signatories.clone(), None, call_hash, Weight::zero())?;
+		// Everyone else approves off-chain
+		let payload = Multisig::<T>::signature_payload(&call_hash, &timepoint, &multi_account_id);
+		let signatures: Vec<(T::AccountId, T::OffchainSignature)> = signers
+			.iter()
+			.map(|(public, signatory)| (signatory.clone(), T::BenchmarkHelper::sign(public, &payload)))
+			.collect();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller), s as u16, signatories, timepoint, Box::new(call), signatures, Weight::MAX)
 	verify {
 		assert!(!Multisigs::<T>::contains_key(&multi_account_id, call_hash));
 	}
@@ -210,5 +262,237 @@ benchmarks! {
 		assert!(!Multisigs::<T>::contains_key(multi_account_id, call_hash));
 	}
 
+	set_expiry {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length, not a component
+		let z = 10_000;
+		let (mut signatories, call) = setup_multi::<T>(s, z)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let call_hash = call.using_encoded(blake2_256);
+		// Create the multi
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::as_multi(o, s as u16, signatories.clone(), None, call, Weight::zero())?;
+		let expires_at = T::BlockNumberProvider::current_block_number() + 10u32.into();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller), s as u16, signatories, call_hash, Some(expires_at))
+	verify {
+		assert_eq!(MultisigExpiries::<T>::get(multi_account_id, call_hash), Some(expires_at));
+	}
+
+	reap_expired {
+		let s = T::MaxSignatories::get();
+		let z = 10_000;
+		let (mut signatories, call) = setup_multi::<T>(s, z)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let call_hash = call.using_encoded(blake2_256);
+		// Create the multi and let it expire
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::as_multi(o, s as u16, signatories, None, call, Weight::zero())?;
+		let now = T::BlockNumberProvider::current_block_number();
+		MultisigExpiries::<T>::insert(&multi_account_id, call_hash, now);
+		let reaper: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(reaper), multi_account_id.clone(), call_hash)
+	verify {
+		assert!(!Multisigs::<T>::contains_key(multi_account_id, call_hash));
+	}
+
+	migrate_multisig {
+		// New signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Open multisig operations to move
+		let c in 0 .. 100;
+		let (mut signatories, _) = setup_multi::<T>(2, 0)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, 2);
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let mut call_hashes = Vec::new();
+		for i in 0 .. c {
+			let call: <T as Config>::RuntimeCall =
+				frame_system::Call::<T>::remark { remark: i.encode() }.into();
+			call_hashes.push(call.using_encoded(blake2_256));
+			let o = RawOrigin::Signed(caller.clone()).into();
+			Multisig::<T>::as_multi(o, 2, signatories.clone(), None, Box::new(call), Weight::zero())?;
+		}
+		T::Currency::set_balance(
+			&multi_account_id,
+			T::Currency::minimum_balance() * 100u32.into(),
+		);
+		let mut new_signatories: Vec<T::AccountId> =
+			(0 .. s).map(|i| account("new_signatory", i, SEED)).collect();
+		new_signatories.sort();
+		let new_multi_account_id = Multisig::<T>::multi_account_id(&new_signatories, 2);
+	}: _(RawOrigin::Signed(multi_account_id.clone()), 2, new_signatories, call_hashes)
+	verify {
+		assert_eq!(Multisigs::<T>::iter_prefix(&new_multi_account_id).count(), c as usize);
+		assert!(!T::Currency::balance(&new_multi_account_id).is_zero());
+	}
+
+	as_multi_store_call {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length, up to what the preimage provider can store
+		let z in 0 .. 10_000.min(T::Preimages::MAX_LENGTH as u32);
+		let (mut signatories, call) = setup_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller), s as u16, signatories, call)
+	verify {
+		assert!(MultisigCalls::<T>::contains_key(&multi_account_id, call_hash));
+	}
+
+	cancel_as_multi_by_threshold {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length, not a component
+		let z = 10_000;
+		let (mut signatories, call) = setup_multi::<T>(s, z)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let call_hash = call.using_encoded(blake2_256);
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(signatories[0].clone()).into();
+		let others = signatories.iter().skip(1).cloned().chain(Some(caller.clone())).collect();
+		Multisig::<T>::as_multi(o, s as u16, others, None, call, Weight::zero())?;
+		// All other signatories already approved to cancel, the caller gives the final approval.
+		let approvals: BoundedVec<_, _> =
+			signatories.clone().try_into().map_err(|_| "too many signatories")?;
+		CancellationApprovals::<T>::insert(&multi_account_id, call_hash, approvals);
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller), s as u16, signatories, timepoint, call_hash)
+	verify {
+		assert!(!Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+	}
+
+	poke_deposit {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length, not a component
+		let z = 10_000;
+		let (mut signatories, call) = setup_multi::<T>(s, z)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let call_hash = call.using_encoded(blake2_256);
+		// Create the multi, with its deposit reserved as before the deposits were held
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::as_multi(o, s as u16, signatories.clone(), None, call, Weight::zero())?;
+		let deposit = Multisigs::<T>::get(&multi_account_id, call_hash)
+			.ok_or("multisig should exist")?
+			.deposit;
+		T::Currency::release(
+			&HoldReason::MultisigDeposit.into(),
+			&caller,
+			deposit,
+			Precision::Exact,
+		)?;
+		T::OldCurrency::reserve(&caller, deposit)?;
+		HeldDeposits::<T>::remove(&multi_account_id, call_hash);
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller), s as u16, signatories, call_hash)
+	verify {
+		assert!(HeldDeposits::<T>::contains_key(&multi_account_id, call_hash));
+	}
+
+	revoke_approval {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length, not a component
+		let z = 10_000;
+		let (mut signatories, call) = setup_multi::<T>(s, z)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let call_hash = call.using_encoded(blake2_256);
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::as_multi(o, s as u16, signatories.clone(), None, call, Weight::zero())?;
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller), s as u16, signatories, timepoint, call_hash)
+	verify {
+		let multisig = Multisigs::<T>::get(&multi_account_id, call_hash)
+			.ok_or("multisig should exist")?;
+		assert!(multisig.approvals.is_empty());
+	}
+
+	set_execution_delay {
+		let caller: T::AccountId = whitelisted_caller();
+		let delay: BlockNumberFor<T> = 10u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), delay)
+	verify {
+		assert_eq!(ExecutionDelays::<T>::get(&caller), delay);
+	}
+
+	reject_as_multi {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length, not a component
+		let z = 10_000;
+		let (mut signatories, call) = setup_multi::<T>(s, z)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let call_hash = call.using_encoded(blake2_256);
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(signatories[0].clone()).into();
+		let others = signatories.iter().skip(1).cloned().chain(Some(caller.clone())).collect();
+		Multisig::<T>::as_multi(o, s as u16, others, None, call, Weight::zero())?;
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+		// With every signatory needed, the rejection cancels the operation.
+	}: _(RawOrigin::Signed(caller), s as u16, signatories, timepoint, call_hash)
+	verify {
+		assert!(!Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+	}
+
+	create_multisig {
+		// Signatories, need at least 2 total people
+		let s in 2 .. T::MaxSignatories::get();
+		let (mut signatories, _) = setup_multi::<T>(s, 0)?;
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let multi_account_id = Multisig::<T>::derive_multi_account_id(signatories.clone(), 2);
+		let endowment = T::Currency::minimum_balance();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+		// Worst case: the signatories are given in reverse order.
+		signatories.reverse();
+	}: _(RawOrigin::Signed(caller), 2, signatories, endowment)
+	verify {
+		assert!(CreatedMultisigs::<T>::contains_key(&multi_account_id));
+		assert_eq!(T::Currency::balance(&multi_account_id), endowment);
+	}
+
+	destroy_multisig {
+		let (mut signatories, _) = setup_multi::<T>(2, 0)?;
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let multi_account_id = Multisig::<T>::derive_multi_account_id(signatories.clone(), 2);
+		Multisig::<T>::create_multisig(
+			RawOrigin::Signed(caller.clone()).into(),
+			2,
+			signatories,
+			Zero::zero(),
+		)?;
+		assert_eq!(T::Currency::total_balance_on_hold(&caller), T::DepositBase::get());
+	}: _(RawOrigin::Signed(multi_account_id.clone()))
+	verify {
+		assert!(!CreatedMultisigs::<T>::contains_key(&multi_account_id));
+		assert!(T::Currency::total_balance_on_hold(&caller).is_zero());
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
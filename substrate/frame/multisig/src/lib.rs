@@ -38,13 +38,51 @@
 //! * `as_multi` - Approve and if possible dispatch a call from a composite origin formed from a
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
+//! * `revoke_approval` - Revoke an approval given to a call from a composite origin, before the
+//!   threshold is reached.
+//! * `reject_as_multi` - Reject a call from a composite origin, cancelling it once too many
+//!   signatories rejected it for the threshold to be reached.
+//! * `approve_as_multi_with_metadata` - Open a multisig operation with metadata describing it, e.g.
+//!   a reference to the document of the proposal.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `cancel_as_multi_by_threshold` - Approve to cancel a call from a composite origin, cancelling
+//!   it once the threshold of signatories approved, without the depositor.
+//! * `set_expiry` - Let a multisig operation expire at a block, as given by the
+//!   [`Config::BlockNumberProvider`].
+//! * `reap_expired` - Remove an expired multisig operation, returning the deposit of its depositor.
+//! * `as_multi_with_signatures` - Approve and possibly dispatch a call from a composite origin,
+//!   together with the approvals other signatories signed off-chain.
+//! * `as_multi_store_call` - Open a multisig operation, storing its call on-chain to be executed by
+//!   the final approval.
+//! * `as_multi_store_call_with_deadline` - Open a multisig operation like `as_multi_store_call`,
+//!   with a deadline at which the [`Config::Scheduler`] executes the call if it was approved.
+//! * `execute_at_deadline` - Execute the stored call of an approved multisig operation at its
+//!   deadline. Dispatched by the scheduler.
+//! * `as_multi_charging_multisig` - Approve and if possible dispatch a call like `as_multi`,
+//!   charging the fee of the call to the composite account if it is dispatched.
+//! * `poke_deposit` - Hold the deposit of a multisig operation which is still reserved and update
+//!   it to the current deposit parameters.
+//! * `set_execution_delay` - Timelock the operations of a composite account once they reach their
+//!   threshold, giving the signatories time to cancel them.
+//! * `migrate_multisig` - Move the balance and the open multisig operations of a composite account
+//!   to the composite account of a new set of signatories.
+//! * `create_multisig` - Register a composite account on-chain, optionally endowing it, and learn
+//!   its address.
+//! * `destroy_multisig` - Destroy the registration of a composite account and return its deposit.
+//!
+//! ### Deriving Multisig Accounts
+//!
+//! The address of a composite account is derived with [`Pallet::multi_account_id`] from the
+//! sorted signatories and the threshold. [`Pallet::derive_multi_account_id`] and the
+//! [`runtime_api::MultisigApi`] sort the signatories themselves, so tooling does not need to
+//! re-implement the derivation.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
 pub mod migrations;
+pub mod runtime_api;
 mod tests;
 pub mod weights;
 
@@ -55,7 +93,15 @@ use frame_support::{
 		PostDispatchInfo,
 	},
 	ensure,
-	traits::{Currency, Get, ReservableCurrency},
+	traits::{
+		fungible::{Inspect, Mutate, MutateHold},
+		schedule::{
+			v3::{Named as ScheduleNamed, TaskName},
+			DispatchTime, LOWEST_PRIORITY,
+		},
+		tokens::{Fortitude, Precision, Preservation},
+		Bounded, Get, QueryPreimage, ReservableCurrency, StorePreimage,
+	},
 	weights::Weight,
 	BoundedVec,
 };
@@ -63,10 +109,13 @@ use frame_system::{self as system, pallet_prelude::BlockNumberFor, RawOrigin};
 use scale_info::TypeInfo;
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
-	traits::{Dispatchable, TrailingZeroInput, Zero},
+	traits::{
+		BlockNumberProvider, Convert, Dispatchable, IdentifyAccount, Saturating, TrailingZeroInput,
+		Verify, Zero,
+	},
 	DispatchError, RuntimeDebug,
 };
-use sp_std::prelude::*;
+use sp_std::{marker::PhantomData, prelude::*};
 pub use weights::WeightInfo;
 
 pub use pallet::*;
@@ -86,7 +135,7 @@ macro_rules! log {
 }
 
 type BalanceOf<T> =
-	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
 /// A global extrinsic index, formed as the extrinsic index within a block, together with that
 /// block's height. This allows a transaction in which a multisig operation of a particular
@@ -110,27 +159,120 @@ where
 {
 	/// The extrinsic when the multisig operation was opened.
 	when: Timepoint<BlockNumber>,
-	/// The amount held in reserve of the `depositor`, to be returned once the operation ends.
+	/// The amount held from the `depositor`, to be returned once the operation ends.
 	deposit: Balance,
 	/// The account who opened it (i.e. the first to approve it).
 	depositor: AccountId,
 	/// The approvals achieved so far, including the depositor. Always sorted.
 	approvals: BoundedVec<AccountId, MaxApprovals>,
+	/// The signatories which rejected the operation so far. Always sorted.
+	rejections: BoundedVec<AccountId, MaxApprovals>,
+}
+
+/// The deadline of a multisig operation, at which its stored call is executed by the
+/// [`Config::Scheduler`] if the operation was approved by its threshold.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Deadline<BlockNumber> {
+	/// The block of the scheduler at which the call is executed.
+	at: BlockNumber,
+	/// The number of approvals needed to execute the call.
+	threshold: u16,
+	/// The weight of the stored call.
+	call_weight: Weight,
+	/// The encoded length of the stored call.
+	call_len: u32,
 }
 
 type CallHash = [u8; 32];
 
+/// The prefix of the names of the scheduled executions of multisig operations at their deadline.
+const DEADLINE_ID: &[u8; 17] = b"multisig/deadline";
+
+/// The tag prefixed to the payloads signed off-chain to approve a multisig operation.
+const SIGNATURE_TAG: &[u8; 16] = b"multisig/approve";
+
+/// The metadata attached to a multisig operation.
+pub type MetadataOf<T> = BoundedVec<u8, <T as Config>::MaxMetadataLen>;
+
+/// The call of a multisig operation stored on-chain.
+pub type BoundedCallOf<T> =
+	Bounded<<T as Config>::RuntimeCall, <T as frame_system::Config>::Hashing>;
+
 enum CallOrHash<T: Config> {
 	Call(<T as Config>::RuntimeCall),
 	Hash([u8; 32]),
 }
 
+/// The fee of a call dispatched by a multisig operation, charged to the multisig account.
+pub trait CallFee<Balance> {
+	/// The fee for a call of encoded length `len` which used `weight`.
+	fn call_fee(len: u32, weight: Weight) -> Balance;
+}
+
+impl<Balance: Zero> CallFee<Balance> for () {
+	fn call_fee(_: u32, _: Weight) -> Balance {
+		Zero::zero()
+	}
+}
+
+/// The fee of a call as charged by [`pallet_transaction_payment`] for its length and weight,
+/// adjusted by the current fee multiplier.
+///
+/// The base fee is not included, since it is paid for the transaction rather than the call.
+pub struct TransactionPaymentFee<T>(PhantomData<T>);
+
+impl<T, Balance> CallFee<Balance> for TransactionPaymentFee<T>
+where
+	T: pallet_transaction_payment::Config,
+	TransactionPaymentBalanceOf<T>: Into<Balance>,
+{
+	fn call_fee(len: u32, weight: Weight) -> Balance {
+		// The weight fee is adjusted by the multiplier, as for the fee of a transaction.
+		let weight_fee = pallet_transaction_payment::Pallet::<T>::convert(weight);
+		weight_fee
+			.saturating_add(pallet_transaction_payment::Pallet::<T>::length_to_fee(len))
+			.into()
+	}
+}
+
+type TransactionPaymentBalanceOf<T> =
+	<<T as pallet_transaction_payment::Config>::OnChargeTransaction as
+		pallet_transaction_payment::OnChargeTransaction<T>>::Balance;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<Public, AccountId, Signature> {
+		/// A signatory for which signatures can be created with `sign`.
+		fn signer(i: u32) -> (Public, AccountId);
+		/// Sign `message` with the key of `signer`.
+		fn sign(signer: &Public, message: &[u8]) -> Signature;
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	impl
+		BenchmarkHelper<
+			sp_runtime::MultiSigner,
+			sp_runtime::AccountId32,
+			sp_runtime::MultiSignature,
+		> for ()
+	{
+		fn signer(_: u32) -> (sp_runtime::MultiSigner, sp_runtime::AccountId32) {
+			let public = sp_io::crypto::sr25519_generate(0.into(), None);
+			let account = sp_runtime::MultiSigner::Sr25519(public).into_account();
+			(public.into(), account)
+		}
+		fn sign(signer: &sp_runtime::MultiSigner, message: &[u8]) -> sp_runtime::MultiSignature {
+			sp_runtime::MultiSignature::Sr25519(
+				sp_io::crypto::sr25519_sign(0.into(), &signer.clone().try_into().unwrap(), message)
+					.unwrap(),
+			)
+		}
+	}
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// The overarching event type.
@@ -140,10 +282,25 @@ pub mod pallet {
 		type RuntimeCall: Parameter
 			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
 			+ GetDispatchInfo
-			+ From<frame_system::Call<Self>>;
+			+ From<frame_system::Call<Self>>
+			+ From<Call<Self>>;
+
+		/// The currency mechanism, with which the deposits are held.
+		type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ Mutate<Self::AccountId>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
 
-		/// The currency mechanism.
-		type Currency: ReservableCurrency<Self::AccountId>;
+		/// The fee charged to the multisig account for a call executed by
+		/// `as_multi_charging_multisig`.
+		type CallFee: CallFee<BalanceOf<Self>>;
+
+		/// The currency with which the deposits of multisig operations were reserved before they
+		/// were held.
+		///
+		/// Only used to return these deposits, or to move them to holds with `poke_deposit`.
+		type OldCurrency: ReservableCurrency<Self::AccountId, Balance = BalanceOf<Self>>;
 
 		/// The base amount of currency needed to reserve for creating a multisig execution or to
 		/// store a dispatch call for later.
@@ -164,17 +321,71 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxSignatories: Get<u32>;
 
+		/// The maximum length of the metadata which may be attached to a multisig operation.
+		#[pallet::constant]
+		type MaxMetadataLen: Get<u32>;
+
+		/// The preimage provider with which the calls of multisig operations are stored on-chain.
+		type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
+
+		/// The provider of the block number the expiries of multisig operations refer to.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// The scheduler with which the stored calls of multisig operations are executed at their
+		/// deadline.
+		type Scheduler: ScheduleNamed<
+			BlockNumberFor<Self>,
+			<Self as Config>::RuntimeCall,
+			Self::PalletsOrigin,
+			Hasher = Self::Hashing,
+		>;
+
+		/// The maximum number of blocks ahead of the current block at which the deadline of a
+		/// multisig operation may be set.
+		#[pallet::constant]
+		type MaxDeadline: Get<BlockNumberFor<Self>>;
+
+		/// The caller origin, overarching type of all pallets origins.
+		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+
+		/// Signature type for approving multisig operations off-chain.
+		///
+		/// Can verify whether an `Self::SigningPublicKey` created a signature.
+		type OffchainSignature: Verify<Signer = Self::SigningPublicKey> + Parameter;
+
+		/// Public key that corresponds to an on-chain `Self::AccountId`.
+		type SigningPublicKey: IdentifyAccount<AccountId = Self::AccountId>;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		/// A set of helper functions for benchmarking.
+		type BenchmarkHelper: BenchmarkHelper<
+			Self::SigningPublicKey,
+			Self::AccountId,
+			Self::OffchainSignature,
+		>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The deposit of an open multisig operation.
+		#[codec(index = 0)]
+		MultisigDeposit,
+		/// The deposit for a multisig account registered with `create_multisig`.
+		#[codec(index = 1)]
+		CreationDeposit,
+	}
+
 	/// The set of open multisig operations.
 	#[pallet::storage]
 	pub type Multisigs<T: Config> = StorageDoubleMap<
@@ -186,6 +397,93 @@ pub mod pallet {
 		Multisig<BlockNumberFor<T>, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
 	>;
 
+	/// The metadata attached to open multisig operations, e.g. a reference to the document
+	/// describing the proposal.
+	#[pallet::storage]
+	pub type MultisigMetadata<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Blake2_128Concat, [u8; 32], MetadataOf<T>>;
+
+	/// The calls of open multisig operations which are stored on-chain, to be executed by the
+	/// final approval.
+	#[pallet::storage]
+	pub type MultisigCalls<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		[u8; 32],
+		BoundedCallOf<T>,
+	>;
+
+	/// The blocks from which open multisig operations are expired and may be reaped by anyone.
+	#[pallet::storage]
+	pub type MultisigExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		[u8; 32],
+		BlockNumberFor<T>,
+	>;
+
+	/// The deadlines of open multisig operations, at which their stored call is executed if they
+	/// were approved by their threshold.
+	#[pallet::storage]
+	pub type MultisigDeadlines<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		[u8; 32],
+		Deadline<BlockNumberFor<T>>,
+	>;
+
+	/// The execution delays of multisig accounts, for which their operations are timelocked once
+	/// they reach their threshold.
+	#[pallet::storage]
+	pub type ExecutionDelays<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>, ValueQuery>;
+
+	/// The blocks from which timelocked multisig operations may be executed.
+	#[pallet::storage]
+	pub type MultisigTimelocks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		[u8; 32],
+		BlockNumberFor<T>,
+	>;
+
+	/// The signatories which approved to cancel open multisig operations so far.
+	#[pallet::storage]
+	pub type CancellationApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		[u8; 32],
+		BoundedVec<T::AccountId, T::MaxSignatories>,
+		ValueQuery,
+	>;
+
+	/// The open multisig operations whose deposit is held with [`HoldReason::MultisigDeposit`].
+	///
+	/// The deposits of the other operations are still reserved with [`Config::OldCurrency`], as
+	/// they were opened before the deposits were held and not poked since.
+	#[pallet::storage]
+	pub type HeldDeposits<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Blake2_128Concat, [u8; 32], ()>;
+
+	/// The multisig accounts registered with `create_multisig` or at genesis, with the account
+	/// which registered them and the deposit held from it.
+	///
+	/// Each of them holds a provider reference, so it exists regardless of its balance. The
+	/// multisig accounts registered at genesis are their own creator, without a deposit.
+	#[pallet::storage]
+	pub type CreatedMultisigs<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (T::AccountId, BalanceOf<T>)>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -216,6 +514,30 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// The multisig operation is expired.
+		Expired,
+		/// The multisig operation has no expiry or is not expired yet.
+		NotExpired,
+		/// The expiry is not in the future.
+		InvalidExpiry,
+		/// A signature was given for an account which is not a signatory of the multisig.
+		NotSignatory,
+		/// A signature is not valid for the multisig operation.
+		InvalidSignature,
+		/// The threshold is zero or greater than the number of signatories.
+		InvalidThreshold,
+		/// The multisig operation has not been approved by the sender.
+		NotApproved,
+		/// The multisig operation is timelocked and cannot be executed yet.
+		Timelocked,
+		/// The multisig operation is already rejected by this signatory.
+		AlreadyRejected,
+		/// The multisig account is already registered.
+		AlreadyCreated,
+		/// The deadline is not in the future or further ahead than [`Config::MaxDeadline`].
+		InvalidDeadline,
+		/// The multisig account is not registered.
+		NotCreated,
 	}
 
 	#[pallet::event]
@@ -245,11 +567,122 @@ pub mod pallet {
 			multisig: T::AccountId,
 			call_hash: CallHash,
 		},
+		/// An approval of a multisig operation has been revoked.
+		MultisigApprovalRevoked {
+			revoking: T::AccountId,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		},
+		/// A multisig operation has been rejected by a signatory.
+		MultisigRejection {
+			rejecting: T::AccountId,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		},
+		/// The cancellation of a multisig operation has been approved by a signatory.
+		MultisigCancellationApproval {
+			approving: T::AccountId,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		},
+		/// The call of a new multisig operation has been stored on-chain.
+		MultisigCallStored { multisig: T::AccountId, call_hash: CallHash },
+		/// Metadata has been attached to a new multisig operation.
+		MultisigMetadataSet { multisig: T::AccountId, call_hash: CallHash, metadata: MetadataOf<T> },
+		/// The stored call of a new multisig operation is executed at `deadline` if the operation
+		/// is approved by then.
+		MultisigDeadlineSet {
+			multisig: T::AccountId,
+			call_hash: CallHash,
+			deadline: BlockNumberFor<T>,
+		},
+		/// The stored call of a multisig operation has been executed at its deadline.
+		MultisigExecutedAtDeadline {
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+			result: DispatchResult,
+		},
+		/// The deadline of a multisig operation passed before it was approved. The operation stays
+		/// open.
+		MultisigDeadlineLapsed { multisig: T::AccountId, call_hash: CallHash },
+		/// A multisig operation reached its threshold and may be executed from `executable_at`.
+		MultisigTimelocked {
+			multisig: T::AccountId,
+			call_hash: CallHash,
+			executable_at: BlockNumberFor<T>,
+		},
+		/// The execution delay of a multisig account has been set.
+		ExecutionDelaySet { multisig: T::AccountId, delay: BlockNumberFor<T> },
+		/// The expiry of a multisig operation has been set or cleared.
+		MultisigExpirySet {
+			multisig: T::AccountId,
+			call_hash: CallHash,
+			expires_at: Option<BlockNumberFor<T>>,
+		},
+		/// An expired multisig operation has been reaped and its deposit returned.
+		MultisigExpired {
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		},
+		/// A multisig account has been migrated to the multisig account of a new set of
+		/// signatories.
+		MultisigMigrated {
+			multisig: T::AccountId,
+			new_multisig: T::AccountId,
+			amount: BalanceOf<T>,
+			operations: u32,
+		},
+		/// The deposit of a multisig operation has been poked, i.e. held and updated to the
+		/// current deposit parameters.
+		DepositPoked {
+			who: T::AccountId,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+			old_deposit: BalanceOf<T>,
+			new_deposit: BalanceOf<T>,
+		},
+		/// The fee for the execution of a call was charged to the multisig account and
+		/// transferred to `payer`, who paid it with the transaction fee.
+		MultisigFeeCharged { multisig: T::AccountId, payer: T::AccountId, fee: BalanceOf<T> },
+		/// A multisig account has been registered and endowed with `endowment`.
+		MultisigCreated {
+			creator: T::AccountId,
+			multisig: T::AccountId,
+			threshold: u16,
+			endowment: BalanceOf<T>,
+		},
+		/// A registered multisig account has been destroyed and the deposit for it returned to
+		/// its creator.
+		MultisigDestroyed { multisig: T::AccountId, creator: T::AccountId, deposit: BalanceOf<T> },
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
 
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The multisig accounts to register, as their signatories and threshold.
+		///
+		/// The signatories do not need to be sorted.
+		pub multisigs: Vec<(Vec<T::AccountId>, u16)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (signatories, threshold) in &self.multisigs {
+				Pallet::<T>::do_create_multisig(None, signatories.clone(), *threshold)
+					.expect("genesis multisigs must be valid and unique; qed");
+			}
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Immediately dispatch a multi-signature call using a single approval from the caller.
@@ -378,6 +811,9 @@ pub mod pallet {
 				maybe_timepoint,
 				CallOrHash::Call(*call),
 				max_weight,
+				None,
+				None,
+				false,
 			)
 		}
 
@@ -398,7 +834,9 @@ pub mod pallet {
 		/// transaction index) of the first approval transaction.
 		/// - `call_hash`: The hash of the call to be executed.
 		///
-		/// NOTE: If this is the final approval, you will want to use `as_multi` instead.
+		/// NOTE: If this is the final approval, you will want to use `as_multi` instead, unless
+		/// the call is stored on-chain. The stored call is then executed, and `max_weight` must
+		/// also cover fetching and decoding it, which is weighed by its length.
 		///
 		/// ## Complexity
 		/// - `O(S)`.
@@ -417,6 +855,7 @@ pub mod pallet {
 
 			T::WeightInfo::approve_as_multi_create(s)
 				.max(T::WeightInfo::approve_as_multi_approve(s))
+				.max(T::WeightInfo::as_multi_complete(s, 0))
 				.saturating_add(*max_weight)
 		})]
 		pub fn approve_as_multi(
@@ -435,6 +874,9 @@ pub mod pallet {
 				maybe_timepoint,
 				CallOrHash::Hash(call_hash),
 				max_weight,
+				None,
+				None,
+				false,
 			)
 		}
 
@@ -460,7 +902,13 @@ pub mod pallet {
 		/// - I/O: 1 read `O(S)`, one remove.
 		/// - Storage: removes one item.
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::cancel_as_multi(other_signatories.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::cancel_as_multi(other_signatories.len() as u32)
+				// Check of the timelock, removal of the metadata, the expiry, the deadline, the
+				// timelock, the cancellation approvals and the stored call of the operation and
+				// cancellation of its scheduled execution.
+				.saturating_add(T::DbWeight::get().reads_writes(6, 10))
+		)]
 		pub fn cancel_as_multi(
 			origin: OriginFor<T>,
 			threshold: u16,
@@ -479,11 +927,20 @@ pub mod pallet {
 
 			let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
 			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
-			ensure!(m.depositor == who, Error::<T>::NotOwner);
+			// Any signatory may cancel a timelocked operation.
+			ensure!(
+				m.depositor == who || <MultisigTimelocks<T>>::contains_key(&id, call_hash),
+				Error::<T>::NotOwner
+			);
 
-			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
-			debug_assert!(err_amount.is_zero());
+			Self::release_deposit(&id, &call_hash, &m.depositor, m.deposit);
 			<Multisigs<T>>::remove(&id, &call_hash);
+			<MultisigMetadata<T>>::remove(&id, &call_hash);
+			<MultisigExpiries<T>>::remove(&id, &call_hash);
+			<MultisigTimelocks<T>>::remove(&id, &call_hash);
+			<CancellationApprovals<T>>::remove(&id, &call_hash);
+			Self::drop_stored_call(&id, &call_hash);
+			Self::cancel_deadline(&id, &call_hash);
 
 			Self::deposit_event(Event::MultisigCancelled {
 				cancelling: who,
@@ -493,75 +950,1211 @@ pub mod pallet {
 			});
 			Ok(())
 		}
-	}
-}
-
-impl<T: Config> Pallet<T> {
-	/// Derive a multi-account ID from the sorted list of accounts and the threshold that are
-	/// required.
-	///
-	/// NOTE: `who` must be sorted. If it is not, then you'll get the wrong answer.
-	pub fn multi_account_id(who: &[T::AccountId], threshold: u16) -> T::AccountId {
-		let entropy = (b"modlpy/utilisuba", who, threshold).using_encoded(blake2_256);
-		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
-			.expect("infinite length input; no invalid inputs for type; qed")
-	}
 
-	fn operate(
-		who: T::AccountId,
-		threshold: u16,
-		other_signatories: Vec<T::AccountId>,
-		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
-		call_or_hash: CallOrHash<T>,
-		max_weight: Weight,
-	) -> DispatchResultWithPostInfo {
-		ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
-		let max_sigs = T::MaxSignatories::get() as usize;
-		ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
-		let other_signatories_len = other_signatories.len();
-		ensure!(other_signatories_len < max_sigs, Error::<T>::TooManySignatories);
-		let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+		/// Open a multisig operation like `approve_as_multi`, attaching `metadata` to it.
+		///
+		/// The metadata is meant to give the other signatories the context of the operation,
+		/// e.g. the IPFS CID of the document describing the proposal. It is stored along with the
+		/// operation and removed once it is executed or cancelled.
+		///
+		/// Payment: In addition to the deposit of `approve_as_multi`, `DepositFactor` is
+		/// reserved for the metadata and for each started 32 bytes of it.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `call_hash`: The hash of the call to be executed.
+		/// - `metadata`: The metadata describing the operation.
+		///
+		/// ## Complexity
+		/// - `O(S)`.
+		/// - One balance-reserve operation.
+		/// - One passthrough operation, two inserts, all `O(S)` where `S` is the number of
+		///   signatories. `S` is capped by `MaxSignatories`, with weight being proportional.
+		/// - One encode & hash, both of complexity `O(S)`.
+		/// - Two events.
+		#[pallet::call_index(4)]
+		#[pallet::weight(
+			T::WeightInfo::approve_as_multi_create(other_signatories.len() as u32)
+				.saturating_add(T::DbWeight::get().writes(1))
+		)]
+		pub fn approve_as_multi_with_metadata(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			call_hash: [u8; 32],
+			metadata: MetadataOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate(
+				who,
+				threshold,
+				other_signatories,
+				None,
+				CallOrHash::Hash(call_hash),
+				Weight::zero(),
+				Some(metadata),
+				None,
+				false,
+			)
+		}
 
-		let id = Self::multi_account_id(&signatories, threshold);
+		/// Let a pre-existing, on-going multisig operation expire at `expires_at`, or never if
+		/// `None`.
+		///
+		/// Once expired, the operation can no longer be approved or executed and anyone may reap
+		/// it with `reap_expired`, returning the deposit to the depositor.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the depositor of the operation.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `call_hash`: The hash of the call to be executed.
+		/// - `expires_at`: The block of the [`Config::BlockNumberProvider`] from which the
+		///   operation is expired. Must be in the future.
+		///
+		/// ## Complexity
+		/// - `O(S)` where `S` is the number of signatories.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_expiry(other_signatories.len() as u32))]
+		pub fn set_expiry(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			call_hash: [u8; 32],
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+			let max_sigs = T::MaxSignatories::get() as usize;
+			ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
+			ensure!(other_signatories.len() < max_sigs, Error::<T>::TooManySignatories);
+			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
 
-		// Threshold > 1; this means it's a multi-step operation. We extract the `call_hash`.
-		let (call_hash, call_len, maybe_call) = match call_or_hash {
-			CallOrHash::Call(call) => {
-				let (call_hash, call_len) = call.using_encoded(|d| (blake2_256(d), d.len()));
-				(call_hash, call_len, Some(call))
-			},
-			CallOrHash::Hash(h) => (h, 0, None),
-		};
+			let id = Self::multi_account_id(&signatories, threshold);
 
-		// Branch on whether the operation has already started or not.
-		if let Some(mut m) = <Multisigs<T>>::get(&id, call_hash) {
-			// Yes; ensure that the timepoint exists and agrees.
-			let timepoint = maybe_timepoint.ok_or(Error::<T>::NoTimepoint)?;
-			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+			let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(m.depositor == who, Error::<T>::NotOwner);
+			ensure!(!Self::is_expired(&id, &call_hash), Error::<T>::Expired);
 
-			// Ensure that either we have not yet signed or that it is at threshold.
-			let mut approvals = m.approvals.len() as u16;
-			// We only bother with the approval if we're below threshold.
-			let maybe_pos = m.approvals.binary_search(&who).err().filter(|_| approvals < threshold);
-			// Bump approvals if not yet voted and the vote is needed.
-			if maybe_pos.is_some() {
-				approvals += 1;
+			match expires_at {
+				Some(expires_at) => {
+					ensure!(
+						expires_at > T::BlockNumberProvider::current_block_number(),
+						Error::<T>::InvalidExpiry
+					);
+					<MultisigExpiries<T>>::insert(&id, call_hash, expires_at);
+				},
+				None => <MultisigExpiries<T>>::remove(&id, call_hash),
 			}
 
-			// We only bother fetching/decoding call if we know that we're ready to execute.
-			if let Some(call) = maybe_call.filter(|_| approvals >= threshold) {
-				// verify weight
-				ensure!(
-					call.get_dispatch_info().weight.all_lte(max_weight),
-					Error::<T>::MaxWeightTooLow
-				);
-
-				// Clean up storage before executing call to avoid an possibility of reentrancy
-				// attack.
-				<Multisigs<T>>::remove(&id, call_hash);
-				T::Currency::unreserve(&m.depositor, m.deposit);
+			Self::deposit_event(Event::MultisigExpirySet { multisig: id, call_hash, expires_at });
+			Ok(())
+		}
 
-				let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
+		/// Remove an expired multisig operation. The deposit reserved for it is returned to its
+		/// depositor.
+		///
+		/// The dispatch origin for this call must be _Signed_ and may be anyone.
+		///
+		/// - `multisig`: The multisig account of the operation.
+		/// - `call_hash`: The hash of the call of the operation.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			T::WeightInfo::reap_expired()
+				// Removal of the deadline, the timelock, the cancellation approvals and the stored
+				// call of the operation and cancellation of its scheduled execution.
+				.saturating_add(T::DbWeight::get().reads_writes(5, 8))
+		)]
+		pub fn reap_expired(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			let m = <Multisigs<T>>::get(&multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(Self::is_expired(&multisig, &call_hash), Error::<T>::NotExpired);
+
+			Self::release_deposit(&multisig, &call_hash, &m.depositor, m.deposit);
+			<Multisigs<T>>::remove(&multisig, &call_hash);
+			<MultisigMetadata<T>>::remove(&multisig, &call_hash);
+			<MultisigExpiries<T>>::remove(&multisig, &call_hash);
+			<MultisigTimelocks<T>>::remove(&multisig, &call_hash);
+			<CancellationApprovals<T>>::remove(&multisig, &call_hash);
+			Self::drop_stored_call(&multisig, &call_hash);
+			Self::cancel_deadline(&multisig, &call_hash);
+
+			Self::deposit_event(Event::MultisigExpired { timepoint: m.when, multisig, call_hash });
+			Ok(())
+		}
+
+		/// Approve and dispatch a call from a composite origin, together with the approvals of
+		/// other signatories given as signatures.
+		///
+		/// The signatures are over [`Pallet::signature_payload`], i.e. the encoded
+		/// `(b"multisig/approve", genesis_hash, call_hash, timepoint, multisig)`. Since the
+		/// timepoint is only known once the operation is underway, it must have been started
+		/// before, e.g. with `approve_as_multi`. This also makes the signatures single use, as no
+		/// other operation gets the same timepoint.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first
+		/// approval transaction.
+		/// - `call`: The call to be executed.
+		/// - `signatures`: The approvals of other signatories, as pairs of signatory and its
+		/// signature.
+		///
+		/// If the approvals reach the threshold, the call is dispatched as in `as_multi`, otherwise
+		/// the approvals are recorded.
+		///
+		/// ## Complexity
+		/// - `O(S + Z + Call)`, as `as_multi`, plus one signature verification for each of up to
+		///   `S` signatures.
+		#[pallet::call_index(7)]
+		#[pallet::weight({
+			let s = other_signatories.len() as u32;
+			let z = call.using_encoded(|d| d.len()) as u32;
+
+			T::WeightInfo::as_multi_with_signatures(s, z)
+				.saturating_add(*max_weight)
+		})]
+		pub fn as_multi_with_signatures(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			call: Box<<T as Config>::RuntimeCall>,
+			signatures: Vec<(T::AccountId, T::OffchainSignature)>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(signatures.len() <= other_signatories.len(), Error::<T>::TooManySignatories);
+			let signatories =
+				Self::ensure_sorted_and_insert(other_signatories.clone(), who.clone())?;
+			let id = Self::multi_account_id(&signatories, threshold);
+
+			let call_hash = call.using_encoded(blake2_256);
+			let mut m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+
+			let payload = Self::signature_payload(&call_hash, &timepoint, &id);
+			for (signer, signature) in signatures {
+				ensure!(signatories.binary_search(&signer).is_ok(), Error::<T>::NotSignatory);
+				ensure!(signature.verify(&payload[..], &signer), Error::<T>::InvalidSignature);
+				if let Err(pos) = m.approvals.binary_search(&signer) {
+					m.approvals
+						.try_insert(pos, signer.clone())
+						.map_err(|_| Error::<T>::TooManySignatories)?;
+					if let Ok(pos) = m.rejections.binary_search(&signer) {
+						m.rejections.remove(pos);
+					}
+					Self::deposit_event(Event::MultisigApproval {
+						approving: signer,
+						timepoint,
+						multisig: id.clone(),
+						call_hash,
+					});
+				}
+			}
+			<Multisigs<T>>::insert(&id, call_hash, m);
+
+			let s = other_signatories.len() as u32;
+			let z = call.using_encoded(|d| d.len()) as u32;
+			let mut post_info = Self::operate(
+				who,
+				threshold,
+				other_signatories,
+				Some(timepoint),
+				CallOrHash::Call(*call),
+				max_weight,
+				None,
+				None,
+				false,
+			)?;
+			// `operate` does not account for verifying the signatures.
+			post_info.actual_weight = post_info.actual_weight.map(|w| {
+				w.saturating_add(
+					T::WeightInfo::as_multi_with_signatures(s, z)
+						.saturating_sub(T::WeightInfo::as_multi_complete(s, z)),
+				)
+			});
+			Ok(post_info)
+		}
+
+		/// Migrate the multisig account of the origin to the multisig account of a new set of
+		/// signatories and threshold.
+		///
+		/// Changing the signatories or the threshold of a multisig changes its account. This moves
+		/// the reducible balance and the given open multisig operations over to the new account.
+		/// Balance held, reserved or frozen on the old account, e.g. deposits of other pallets,
+		/// stays with it until released; calling this again sweeps it to the new account.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the multisig account to migrate,
+		/// i.e. it is dispatched with `as_multi` and approved by the old threshold.
+		///
+		/// - `new_threshold`: The threshold of the new multisig account.
+		/// - `new_signatories`: All the signatories of the new multisig account, sorted.
+		/// - `call_hashes`: The hashes of the open multisig operations to move to the new account.
+		/// Their approvals by accounts which are not new signatories are dropped. The deposit of an
+		/// operation whose depositor is not a new signatory is returned to the depositor and held
+		/// from the new account instead.
+		///
+		/// ## Complexity
+		/// - `O(S + C)` where `S` is the number of new signatories and `C` the number of operations
+		///   to move.
+		#[pallet::call_index(8)]
+		#[pallet::weight(
+			T::WeightInfo::migrate_multisig(new_signatories.len() as u32, call_hashes.len() as u32)
+				// Moving the stored calls, the held deposit markers, the deadlines and the
+				// timelocks, rescheduling the executions at the deadlines, dropping the
+				// cancellation approvals and moving the deposits of dropped depositors to the new
+				// account.
+				.saturating_add(
+					T::DbWeight::get().reads_writes(12, 17).saturating_mul(call_hashes.len() as u64)
+				)
+				// Moving the execution delay.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+		)]
+		pub fn migrate_multisig(
+			origin: OriginFor<T>,
+			new_threshold: u16,
+			new_signatories: Vec<T::AccountId>,
+			call_hashes: Vec<[u8; 32]>,
+		) -> DispatchResult {
+			let multisig = ensure_signed(origin)?;
+			ensure!(new_signatories.len() >= 2, Error::<T>::TooFewSignatories);
+			ensure!(
+				new_signatories.len() <= T::MaxSignatories::get() as usize,
+				Error::<T>::TooManySignatories
+			);
+			ensure!(
+				new_signatories.windows(2).all(|pair| pair[0] < pair[1]),
+				Error::<T>::SignatoriesOutOfOrder
+			);
+			ensure!(
+				new_threshold >= 1 && new_threshold as usize <= new_signatories.len(),
+				Error::<T>::InvalidThreshold
+			);
+
+			let new_multisig = Self::multi_account_id(&new_signatories, new_threshold);
+
+			let mut taken_over = Vec::new();
+			for call_hash in &call_hashes {
+				let mut m =
+					<Multisigs<T>>::take(&multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+				ensure!(
+					!<Multisigs<T>>::contains_key(&new_multisig, call_hash),
+					Error::<T>::AlreadyStored
+				);
+				m.approvals.retain(|approval| new_signatories.binary_search(approval).is_ok());
+				m.rejections
+					.retain(|rejection| new_signatories.binary_search(rejection).is_ok());
+				if new_signatories.binary_search(&m.depositor).is_err() {
+					// The dropped depositor can no longer end the operation, so its deposit is
+					// returned and taken over by the new account once the balance is moved.
+					Self::release_deposit(&multisig, call_hash, &m.depositor, m.deposit);
+					m.depositor = new_multisig.clone();
+					taken_over.push((*call_hash, m.deposit));
+				}
+				<Multisigs<T>>::insert(&new_multisig, call_hash, m);
+				if let Some(metadata) = <MultisigMetadata<T>>::take(&multisig, call_hash) {
+					<MultisigMetadata<T>>::insert(&new_multisig, call_hash, metadata);
+				}
+				if let Some(expires_at) = <MultisigExpiries<T>>::take(&multisig, call_hash) {
+					<MultisigExpiries<T>>::insert(&new_multisig, call_hash, expires_at);
+				}
+				if let Some(call) = <MultisigCalls<T>>::take(&multisig, call_hash) {
+					<MultisigCalls<T>>::insert(&new_multisig, call_hash, call);
+				}
+				if let Some(executable_at) = <MultisigTimelocks<T>>::take(&multisig, call_hash) {
+					<MultisigTimelocks<T>>::insert(&new_multisig, call_hash, executable_at);
+				}
+				if <HeldDeposits<T>>::take(&multisig, call_hash).is_some() {
+					<HeldDeposits<T>>::insert(&new_multisig, call_hash, ());
+				}
+				if let Some(mut deadline) = <MultisigDeadlines<T>>::take(&multisig, call_hash) {
+					let _ =
+						T::Scheduler::cancel_named(Self::deadline_task_name(&multisig, call_hash));
+					deadline.threshold = new_threshold;
+					Self::schedule_deadline(&new_multisig, *call_hash, deadline)?;
+				}
+				// The approvals to cancel were given by the old signatories.
+				<CancellationApprovals<T>>::remove(&multisig, call_hash);
+			}
+			// The new account must not escape the execution delay of the old one.
+			let delay = <ExecutionDelays<T>>::take(&multisig);
+			if !delay.is_zero() {
+				<ExecutionDelays<T>>::insert(&new_multisig, delay);
+			}
+
+			// The old account is kept alive if it cannot be reaped, e.g. since a balance is still
+			// held or reserved from it.
+			let amount = T::Currency::reducible_balance(
+				&multisig,
+				Preservation::Expendable,
+				Fortitude::Polite,
+			);
+			if !amount.is_zero() {
+				T::Currency::transfer(&multisig, &new_multisig, amount, Preservation::Expendable)?;
+			}
+			for (call_hash, deposit) in taken_over {
+				T::Currency::hold(&HoldReason::MultisigDeposit.into(), &new_multisig, deposit)?;
+				<HeldDeposits<T>>::insert(&new_multisig, call_hash, ());
+			}
+
+			Self::deposit_event(Event::MultisigMigrated {
+				multisig,
+				new_multisig,
+				amount,
+				operations: call_hashes.len() as u32,
+			});
+			Ok(())
+		}
+
+		/// Open a multisig operation like `approve_as_multi`, storing its call on-chain.
+		///
+		/// The final approval can then be given with `approve_as_multi`, which executes the stored
+		/// call, so that the final approver does not need to obtain the call.
+		///
+		/// Payment: In addition to the deposit of `approve_as_multi`, `DepositFactor` is
+		/// reserved for the call and for each started 32 bytes of it.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `call`: The call to be executed. It must fit into the preimage provider.
+		///
+		/// ## Complexity
+		/// - `O(S + Z)`.
+		/// - One balance-reserve operation.
+		/// - One passthrough operation, two inserts, all `O(S)` where `S` is the number of
+		///   signatories. `S` is capped by `MaxSignatories`, with weight being proportional.
+		/// - One call encode & hash, and up to one preimage noted, of complexity `O(Z)` where `Z`
+		///   is tx-len.
+		/// - Two events.
+		#[pallet::call_index(9)]
+		#[pallet::weight({
+			let s = other_signatories.len() as u32;
+			let z = call.using_encoded(|d| d.len()) as u32;
+
+			T::WeightInfo::as_multi_store_call(s, z)
+		})]
+		pub fn as_multi_store_call(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let call_hash = call.using_encoded(blake2_256);
+			let bounded = T::Preimages::bound(*call)?;
+			Self::operate(
+				who,
+				threshold,
+				other_signatories,
+				None,
+				CallOrHash::Hash(call_hash),
+				Weight::zero(),
+				None,
+				Some(bounded),
+				false,
+			)
+		}
+
+		/// Approve to cancel a pre-existing, on-going multisig operation. Once `threshold`
+		/// signatories approved, the operation is cancelled and the deposit reserved for it is
+		/// returned to its depositor.
+		///
+		/// Unlike `cancel_as_multi`, this does not need the depositor, e.g. when it lost its key.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// ## Complexity
+		/// - `O(S)` where `S` is the number of signatories.
+		/// - Up to one balance-unreserve operation.
+		/// - One event.
+		#[pallet::call_index(10)]
+		#[pallet::weight(
+			T::WeightInfo::cancel_as_multi_by_threshold(other_signatories.len() as u32)
+				// Removal of the metadata, the expiry, the deadline, the timelock and the stored call
+				// of the operation and cancellation of its scheduled execution.
+				.saturating_add(T::DbWeight::get().reads_writes(5, 9))
+		)]
+		pub fn cancel_as_multi_by_threshold(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+			let max_sigs = T::MaxSignatories::get() as usize;
+			ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
+			ensure!(other_signatories.len() < max_sigs, Error::<T>::TooManySignatories);
+			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+
+			let id = Self::multi_account_id(&signatories, threshold);
+
+			let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+
+			let mut approvals = <CancellationApprovals<T>>::get(&id, call_hash);
+			let pos = approvals.binary_search(&who).err().ok_or(Error::<T>::AlreadyApproved)?;
+			approvals
+				.try_insert(pos, who.clone())
+				.map_err(|_| Error::<T>::TooManySignatories)?;
+
+			if (approvals.len() as u16) < threshold {
+				<CancellationApprovals<T>>::insert(&id, call_hash, approvals);
+				Self::deposit_event(Event::MultisigCancellationApproval {
+					approving: who,
+					timepoint,
+					multisig: id,
+					call_hash,
+				});
+				return Ok(())
+			}
+
+			Self::release_deposit(&id, &call_hash, &m.depositor, m.deposit);
+			<Multisigs<T>>::remove(&id, &call_hash);
+			<MultisigMetadata<T>>::remove(&id, &call_hash);
+			<MultisigExpiries<T>>::remove(&id, &call_hash);
+			<MultisigTimelocks<T>>::remove(&id, &call_hash);
+			<CancellationApprovals<T>>::remove(&id, &call_hash);
+			Self::drop_stored_call(&id, &call_hash);
+			Self::cancel_deadline(&id, &call_hash);
+
+			Self::deposit_event(Event::MultisigCancelled {
+				cancelling: who,
+				timepoint,
+				multisig: id,
+				call_hash,
+			});
+			Ok(())
+		}
+
+		/// Poke the deposit of a pre-existing, on-going multisig operation.
+		///
+		/// A deposit which is still reserved, as for operations opened before the deposits were
+		/// held, is moved to a hold with [`HoldReason::MultisigDeposit`]. The deposit is also
+		/// updated to the current `DepositBase` and `DepositFactor`.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the depositor of the operation.
+		/// The fee is waived if the deposit was moved or updated.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// ## Complexity
+		/// - `O(S)` where `S` is the number of signatories.
+		/// - Up to one balance-unreserve and one balance-hold or release operation.
+		/// - One event.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::poke_deposit(other_signatories.len() as u32))]
+		pub fn poke_deposit(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			call_hash: [u8; 32],
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+			let max_sigs = T::MaxSignatories::get() as usize;
+			ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
+			ensure!(other_signatories.len() < max_sigs, Error::<T>::TooManySignatories);
+			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+
+			let id = Self::multi_account_id(&signatories, threshold);
+
+			let mut m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(m.depositor == who, Error::<T>::NotOwner);
+
+			let old_deposit = m.deposit;
+			let new_deposit = Self::operation_deposit(
+				threshold,
+				<MultisigMetadata<T>>::get(&id, call_hash).map(|metadata| metadata.len() as u32),
+				<MultisigCalls<T>>::get(&id, call_hash).map(|call| call.len().unwrap_or_default()),
+			);
+			let reason = HoldReason::MultisigDeposit.into();
+			if <HeldDeposits<T>>::contains_key(&id, call_hash) {
+				if new_deposit == old_deposit {
+					return Ok(Pays::Yes.into())
+				} else if new_deposit > old_deposit {
+					T::Currency::hold(&reason, &who, new_deposit - old_deposit)?;
+				} else {
+					T::Currency::release(
+						&reason,
+						&who,
+						old_deposit - new_deposit,
+						Precision::BestEffort,
+					)?;
+				}
+			} else {
+				let err_amount = T::OldCurrency::unreserve(&who, old_deposit);
+				debug_assert!(err_amount.is_zero());
+				T::Currency::hold(&reason, &who, new_deposit)?;
+				<HeldDeposits<T>>::insert(&id, call_hash, ());
+			}
+			m.deposit = new_deposit;
+			<Multisigs<T>>::insert(&id, call_hash, m);
+
+			Self::deposit_event(Event::DepositPoked {
+				who,
+				multisig: id,
+				call_hash,
+				old_deposit,
+				new_deposit,
+			});
+			Ok(Pays::No.into())
+		}
+
+		/// Open a multisig operation like `as_multi_store_call`, with a `deadline` at which its
+		/// stored call is executed by the [`Config::Scheduler`] if it was approved by then.
+		///
+		/// This way the call is executed even if no signatory submits a final approval which
+		/// covers its weight. Once the threshold is reached, approvals whose `max_weight` does not
+		/// cover the weight of the call are recorded instead of failing. If the operation is not
+		/// approved by the deadline, the deadline lapses and the operation stays open.
+		///
+		/// Payment: As for `as_multi_store_call`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `call`: The call to be executed. It must fit into the preimage provider.
+		/// - `deadline`: The block of the [`Config::Scheduler`] at which the call is executed. Must
+		///   be in the future and at most [`Config::MaxDeadline`] blocks ahead.
+		///
+		/// The execution is scheduled with the origin of the multisig account, so the scheduler
+		/// holds its deposit for scheduled tasks from the multisig account until the deadline.
+		///
+		/// ## Complexity
+		/// - `O(S + Z)`, as `as_multi_store_call`, plus scheduling the execution.
+		#[pallet::call_index(12)]
+		#[pallet::weight({
+			let s = other_signatories.len() as u32;
+			let z = call.using_encoded(|d| d.len()) as u32;
+
+			T::WeightInfo::as_multi_store_call(s, z)
+				// Insertion of the deadline and scheduling of the execution.
+				.saturating_add(T::DbWeight::get().reads_writes(2, 3))
+		})]
+		pub fn as_multi_store_call_with_deadline(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			call: Box<<T as Config>::RuntimeCall>,
+			deadline: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let now = T::BlockNumberProvider::current_block_number();
+			ensure!(
+				deadline > now && deadline <= now.saturating_add(T::MaxDeadline::get()),
+				Error::<T>::InvalidDeadline
+			);
+			let signatories =
+				Self::ensure_sorted_and_insert(other_signatories.clone(), who.clone())?;
+			let id = Self::multi_account_id(&signatories, threshold);
+
+			let (call_hash, call_len) = call.using_encoded(|d| (blake2_256(d), d.len() as u32));
+			let call_weight = call.get_dispatch_info().weight;
+			let bounded = T::Preimages::bound(*call)?;
+			let mut post_info = Self::operate(
+				who,
+				threshold,
+				other_signatories,
+				None,
+				CallOrHash::Hash(call_hash),
+				Weight::zero(),
+				None,
+				Some(bounded),
+				false,
+			)?;
+			Self::schedule_deadline(
+				&id,
+				call_hash,
+				Deadline { at: deadline, threshold, call_weight, call_len },
+			)?;
+
+			Self::deposit_event(Event::MultisigDeadlineSet { multisig: id, call_hash, deadline });
+			post_info.actual_weight = post_info
+				.actual_weight
+				.map(|w| w.saturating_add(T::DbWeight::get().reads_writes(2, 3)));
+			Ok(post_info)
+		}
+
+		/// Execute the stored call of a multisig operation at its deadline, if the operation was
+		/// approved by its threshold. Otherwise the deadline lapses.
+		///
+		/// The dispatch origin for this call must be _Signed_ by `multisig`. It is dispatched by
+		/// the [`Config::Scheduler`] at the deadline set with
+		/// `as_multi_store_call_with_deadline`.
+		///
+		/// - `multisig`: The multisig account of the operation.
+		/// - `call_hash`: The hash of the call of the operation.
+		/// - `call_len`: The encoded length of the stored call.
+		/// - `max_weight`: The weight of the stored call.
+		///
+		/// ## Complexity
+		/// - `O(Z + Call)` where `Z` is the length of the call.
+		#[pallet::call_index(13)]
+		#[pallet::weight(
+			Pallet::<T>::execute_at_deadline_weight(*call_len).saturating_add(*max_weight)
+		)]
+		pub fn execute_at_deadline(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			call_hash: [u8; 32],
+			call_len: u32,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(who == multisig, DispatchError::BadOrigin);
+
+			let deadline =
+				<MultisigDeadlines<T>>::take(&multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+			let m = <Multisigs<T>>::get(&multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+			if (m.approvals.len() as u16) < deadline.threshold ||
+				Self::is_expired(&multisig, &call_hash) ||
+				Self::is_timelocked(&multisig, &call_hash)
+			{
+				Self::deposit_event(Event::MultisigDeadlineLapsed { multisig, call_hash });
+				return Ok(Some(T::DbWeight::get().reads_writes(4, 1)).into())
+			}
+
+			let bounded =
+				<MultisigCalls<T>>::get(&multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+			let call = T::Preimages::peek(&bounded)?.0;
+			ensure!(
+				call.get_dispatch_info().weight.all_lte(max_weight),
+				Error::<T>::MaxWeightTooLow
+			);
+
+			// Clean up storage before executing call to avoid an possibility of reentrancy attack.
+			<Multisigs<T>>::remove(&multisig, call_hash);
+			<MultisigMetadata<T>>::remove(&multisig, call_hash);
+			<MultisigExpiries<T>>::remove(&multisig, call_hash);
+			<MultisigTimelocks<T>>::remove(&multisig, call_hash);
+			<CancellationApprovals<T>>::remove(&multisig, call_hash);
+			Self::drop_stored_call(&multisig, &call_hash);
+			Self::release_deposit(&multisig, &call_hash, &m.depositor, m.deposit);
+
+			let result = call.dispatch(RawOrigin::Signed(multisig.clone()).into());
+			Self::deposit_event(Event::MultisigExecutedAtDeadline {
+				timepoint: m.when,
+				multisig,
+				call_hash,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+			Ok(get_result_weight(result)
+				.map(|actual_weight| {
+					Self::execute_at_deadline_weight(call_len).saturating_add(actual_weight)
+				})
+				.into())
+		}
+
+		/// Revoke an approval previously given to a multisig operation.
+		///
+		/// An approval can only be revoked as long as the operation has fewer approvals than its
+		/// threshold. The operation itself and its deposit are left untouched, use
+		/// `cancel_as_multi` to remove it.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a signatory which approved the
+		/// operation.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// ## Complexity
+		/// - `O(S)` where `S` is the number of signatories.
+		/// - One event.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::revoke_approval(other_signatories.len() as u32))]
+		pub fn revoke_approval(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+			let max_sigs = T::MaxSignatories::get() as usize;
+			ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
+			ensure!(other_signatories.len() < max_sigs, Error::<T>::TooManySignatories);
+			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+
+			let id = Self::multi_account_id(&signatories, threshold);
+
+			let mut m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+			ensure!((m.approvals.len() as u16) < threshold, Error::<T>::NoApprovalsNeeded);
+			let pos = m.approvals.binary_search(&who).map_err(|_| Error::<T>::NotApproved)?;
+			m.approvals.remove(pos);
+			<Multisigs<T>>::insert(&id, call_hash, m);
+
+			Self::deposit_event(Event::MultisigApprovalRevoked {
+				revoking: who,
+				timepoint,
+				multisig: id,
+				call_hash,
+			});
+			Ok(())
+		}
+
+		/// Set the execution delay of the multisig account of the origin.
+		///
+		/// Once an operation of the multisig reaches its threshold, it is timelocked for `delay`
+		/// blocks of the [`Config::BlockNumberProvider`] before it can be executed. Meanwhile any
+		/// signatory may cancel it with `cancel_as_multi`, which guards against a compromised
+		/// quorum. A zero `delay` removes the execution delay.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the multisig account, i.e. it is
+		/// dispatched with `as_multi` and approved by the threshold. Changing the delay is thus
+		/// subject to the current delay itself.
+		///
+		/// - `delay`: The number of blocks operations are timelocked for.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::set_execution_delay())]
+		pub fn set_execution_delay(
+			origin: OriginFor<T>,
+			delay: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let multisig = ensure_signed(origin)?;
+
+			if delay.is_zero() {
+				<ExecutionDelays<T>>::remove(&multisig);
+			} else {
+				<ExecutionDelays<T>>::insert(&multisig, delay);
+			}
+
+			Self::deposit_event(Event::ExecutionDelaySet { multisig, delay });
+			Ok(())
+		}
+
+		/// Reject a multisig operation, withdrawing the approval of the sender if it gave one.
+		///
+		/// Once so many signatories rejected the operation that its threshold can no longer be
+		/// reached, the operation is cancelled and the deposit returned to the depositor. Approving
+		/// the operation again withdraws the rejection.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// ## Complexity
+		/// - `O(S)` where `S` is the number of signatories.
+		/// - Up to one balance-release operation.
+		/// - Up to two events.
+		#[pallet::call_index(16)]
+		#[pallet::weight(
+			T::WeightInfo::reject_as_multi(other_signatories.len() as u32)
+				// Removal of the metadata, the expiry, the deadline, the timelock, the cancellation
+				// approvals and the stored call of the operation and cancellation of its scheduled
+				// execution.
+				.saturating_add(T::DbWeight::get().reads_writes(5, 10))
+		)]
+		pub fn reject_as_multi(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+			let max_sigs = T::MaxSignatories::get() as usize;
+			ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
+			ensure!(other_signatories.len() < max_sigs, Error::<T>::TooManySignatories);
+			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+
+			let id = Self::multi_account_id(&signatories, threshold);
+
+			let mut m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+			ensure!((m.approvals.len() as u16) < threshold, Error::<T>::NoApprovalsNeeded);
+			let pos = m.rejections.binary_search(&who).err().ok_or(Error::<T>::AlreadyRejected)?;
+			m.rejections
+				.try_insert(pos, who.clone())
+				.map_err(|_| Error::<T>::TooManySignatories)?;
+			if let Ok(pos) = m.approvals.binary_search(&who) {
+				m.approvals.remove(pos);
+			}
+
+			Self::deposit_event(Event::MultisigRejection {
+				rejecting: who.clone(),
+				timepoint,
+				multisig: id.clone(),
+				call_hash,
+			});
+
+			// The threshold can still be reached by the signatories which did not reject.
+			if signatories.len().saturating_sub(m.rejections.len()) >= threshold as usize {
+				<Multisigs<T>>::insert(&id, call_hash, m);
+				return Ok(())
+			}
+
+			Self::release_deposit(&id, &call_hash, &m.depositor, m.deposit);
+			<Multisigs<T>>::remove(&id, &call_hash);
+			<MultisigMetadata<T>>::remove(&id, &call_hash);
+			<MultisigExpiries<T>>::remove(&id, &call_hash);
+			<MultisigTimelocks<T>>::remove(&id, &call_hash);
+			<CancellationApprovals<T>>::remove(&id, &call_hash);
+			Self::drop_stored_call(&id, &call_hash);
+			Self::cancel_deadline(&id, &call_hash);
+
+			Self::deposit_event(Event::MultisigCancelled {
+				cancelling: who,
+				timepoint,
+				multisig: id,
+				call_hash,
+			});
+			Ok(())
+		}
+
+		/// Register the multisig account of `signatories` and `threshold` on-chain and transfer
+		/// `endowment` from the sender to it.
+		///
+		/// The multisig account is given a provider reference, so it exists even without a balance
+		/// and can be used right away. Its address is emitted with the `MultisigCreated` event.
+		///
+		/// Payment: `DepositBase` is held from the sender until the multisig account is destroyed
+		/// with `destroy_multisig`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `threshold`: The number of approvals required by the multisig. Must not be zero or
+		/// greater than the number of signatories.
+		/// - `signatories`: All the accounts of the multisig, which need not include the sender.
+		/// They are sorted and deduplicated, so they may be given in any order.
+		/// - `endowment`: The amount to transfer to the multisig account. May be zero.
+		///
+		/// ## Complexity
+		/// - `O(S log S)` where `S` is the number of signatories.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::create_multisig(signatories.len() as u32))]
+		pub fn create_multisig(
+			origin: OriginFor<T>,
+			threshold: u16,
+			signatories: Vec<T::AccountId>,
+			endowment: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				signatories.len() <= T::MaxSignatories::get() as usize,
+				Error::<T>::TooManySignatories
+			);
+
+			let multisig = Self::do_create_multisig(Some(who.clone()), signatories, threshold)?;
+			if !endowment.is_zero() {
+				T::Currency::transfer(&who, &multisig, endowment, Preservation::Preserve)?;
+			}
+
+			Self::deposit_event(Event::MultisigCreated {
+				creator: who,
+				multisig,
+				threshold,
+				endowment,
+			});
+			Ok(())
+		}
+
+		/// Destroy the registration of the multisig account of the sender, removing the provider
+		/// reference given to it by `create_multisig` and returning the deposit to its creator.
+		///
+		/// The multisig account is reaped if it has no other provider, which fails if it has
+		/// consumers left.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the multisig account.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::destroy_multisig())]
+		pub fn destroy_multisig(origin: OriginFor<T>) -> DispatchResult {
+			let multisig = ensure_signed(origin)?;
+			let (creator, deposit) =
+				<CreatedMultisigs<T>>::take(&multisig).ok_or(Error::<T>::NotCreated)?;
+			frame_system::Pallet::<T>::dec_providers(&multisig)?;
+			if !deposit.is_zero() {
+				let released = T::Currency::release(
+					&HoldReason::CreationDeposit.into(),
+					&creator,
+					deposit,
+					Precision::BestEffort,
+				);
+				debug_assert_eq!(released, Ok(deposit));
+			}
+
+			Self::deposit_event(Event::MultisigDestroyed { multisig, creator, deposit });
+			Ok(())
+		}
+
+		/// Register approval for a dispatch to be made from a deterministic composite account like
+		/// `as_multi`, charging the fee of the call to the composite account if it is dispatched.
+		///
+		/// The sender pays the fee of the call with the transaction fee. If this approval
+		/// dispatches the call, the fee for its length and for the weight it actually used, as
+		/// given by [`Config::CallFee`], is transferred from the composite account to the sender,
+		/// unless the composite account cannot pay it.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// The parameters are the same as for `as_multi`.
+		///
+		/// ## Complexity
+		/// - The complexity of `as_multi`.
+		/// - Up to one transfer.
+		#[pallet::call_index(19)]
+		#[pallet::weight({
+			let s = other_signatories.len() as u32;
+			let z = call.using_encoded(|d| d.len()) as u32;
+
+			T::WeightInfo::as_multi_create(s, z)
+			.max(T::WeightInfo::as_multi_approve(s, z))
+			.max(T::WeightInfo::as_multi_complete(s, z))
+			// Charging the fee of the call to the multisig account.
+			.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+			.saturating_add(*max_weight)
+		})]
+		pub fn as_multi_charging_multisig(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call: Box<<T as Config>::RuntimeCall>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate(
+				who,
+				threshold,
+				other_signatories,
+				maybe_timepoint,
+				CallOrHash::Call(*call),
+				max_weight,
+				None,
+				None,
+				true,
+			)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Derive a multi-account ID from the sorted list of accounts and the threshold that are
+	/// required.
+	///
+	/// NOTE: `who` must be sorted. If it is not, then you'll get the wrong answer.
+	pub fn multi_account_id(who: &[T::AccountId], threshold: u16) -> T::AccountId {
+		let entropy = (b"modlpy/utilisuba", who, threshold).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
+	/// Derive a multi-account ID from the accounts and the threshold that are required, like
+	/// [`Self::multi_account_id`].
+	///
+	/// The accounts are sorted and deduplicated first, so they may be given in any order.
+	pub fn derive_multi_account_id(mut who: Vec<T::AccountId>, threshold: u16) -> T::AccountId {
+		who.sort();
+		who.dedup();
+		Self::multi_account_id(&who, threshold)
+	}
+
+	/// Register the multisig account of `signatories` and `threshold` and give it a provider
+	/// reference, holding the deposit for it from `maybe_creator` if given.
+	fn do_create_multisig(
+		maybe_creator: Option<T::AccountId>,
+		mut signatories: Vec<T::AccountId>,
+		threshold: u16,
+	) -> Result<T::AccountId, DispatchError> {
+		signatories.sort();
+		signatories.dedup();
+		ensure!(signatories.len() >= 2, Error::<T>::TooFewSignatories);
+		ensure!(
+			signatories.len() <= T::MaxSignatories::get() as usize,
+			Error::<T>::TooManySignatories
+		);
+		ensure!(
+			threshold != 0 && threshold as usize <= signatories.len(),
+			Error::<T>::InvalidThreshold
+		);
+
+		let multisig = Self::multi_account_id(&signatories, threshold);
+		ensure!(!<CreatedMultisigs<T>>::contains_key(&multisig), Error::<T>::AlreadyCreated);
+		let registration = match maybe_creator {
+			Some(creator) => {
+				let deposit = T::DepositBase::get();
+				T::Currency::hold(&HoldReason::CreationDeposit.into(), &creator, deposit)?;
+				(creator, deposit)
+			},
+			None => (multisig.clone(), Zero::zero()),
+		};
+		<CreatedMultisigs<T>>::insert(&multisig, registration);
+		frame_system::Pallet::<T>::inc_providers(&multisig);
+		Ok(multisig)
+	}
+
+	fn operate(
+		who: T::AccountId,
+		threshold: u16,
+		other_signatories: Vec<T::AccountId>,
+		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+		maybe_metadata: Option<MetadataOf<T>>,
+		maybe_stored_call: Option<BoundedCallOf<T>>,
+		charge_multisig: bool,
+	) -> DispatchResultWithPostInfo {
+		ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+		let max_sigs = T::MaxSignatories::get() as usize;
+		ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
+		let other_signatories_len = other_signatories.len();
+		ensure!(other_signatories_len < max_sigs, Error::<T>::TooManySignatories);
+		let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+
+		let id = Self::multi_account_id(&signatories, threshold);
+
+		// Threshold > 1; this means it's a multi-step operation. We extract the `call_hash`.
+		let (call_hash, mut call_len, maybe_call) = match call_or_hash {
+			CallOrHash::Call(call) => {
+				let (call_hash, call_len) = call.using_encoded(|d| (blake2_256(d), d.len()));
+				(call_hash, call_len, Some(call))
+			},
+			CallOrHash::Hash(h) => (h, 0, None),
+		};
+		// Only the length of a call submitted with this transaction is paid for by the sender.
+		let submitted_call_len = call_len as u32;
+
+		// Branch on whether the operation has already started or not.
+		if let Some(mut m) = <Multisigs<T>>::get(&id, call_hash) {
+			// Yes; ensure that the timepoint exists and agrees.
+			let timepoint = maybe_timepoint.ok_or(Error::<T>::NoTimepoint)?;
+			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+			ensure!(!Self::is_expired(&id, &call_hash), Error::<T>::Expired);
+
+			// Ensure that either we have not yet signed or that it is at threshold.
+			let mut approvals = m.approvals.len() as u16;
+			// We only bother with the approval if we're below threshold.
+			let maybe_pos = m.approvals.binary_search(&who).err().filter(|_| approvals < threshold);
+			// Bump approvals if not yet voted and the vote is needed.
+			if maybe_pos.is_some() {
+				approvals += 1;
+			}
+
+			// An operation of a multisig with an execution delay is timelocked once it reaches its
+			// threshold, it can only be executed once the delay has passed.
+			let mut timelocked_until = None;
+			if approvals >= threshold {
+				if <MultisigTimelocks<T>>::contains_key(&id, call_hash) {
+					ensure!(!Self::is_timelocked(&id, &call_hash), Error::<T>::Timelocked);
+				} else {
+					let delay = <ExecutionDelays<T>>::get(&id);
+					if !delay.is_zero() {
+						timelocked_until = Some(
+							T::BlockNumberProvider::current_block_number().saturating_add(delay),
+						);
+					}
+				}
+			}
+
+			// The call may be stored on-chain, in which case it is fetched once ready to execute.
+			// The weight of fetching it depends on its length, so it must be covered by
+			// `max_weight` alongside the weight of the call.
+			let mut fetch_weight = Weight::zero();
+			let maybe_call = match maybe_call {
+				None if approvals >= threshold => match <MultisigCalls<T>>::get(&id, call_hash) {
+					Some(bounded) => {
+						call_len = bounded.len().unwrap_or_default() as usize;
+						fetch_weight = Self::stored_call_fetch_weight(
+							other_signatories_len as u32,
+							call_len as u32,
+						);
+						Some(T::Preimages::peek(&bounded)?.0)
+					},
+					None => None,
+				},
+				maybe_call => maybe_call,
+			};
+			let covers_call = |call: &<T as Config>::RuntimeCall| {
+				call.get_dispatch_info().weight.saturating_add(fetch_weight).all_lte(max_weight)
+			};
+
+			// An operation with a deadline is executed by the scheduler if the final approval does
+			// not cover the weight of its call, so the approval is only recorded.
+			let maybe_call = maybe_call.filter(|call| {
+				approvals >= threshold &&
+					timelocked_until.is_none() &&
+					(covers_call(call) || !<MultisigDeadlines<T>>::contains_key(&id, call_hash))
+			});
+
+			// We only bother fetching/decoding call if we know that we're ready to execute.
+			if let Some(call) = maybe_call {
+				// verify weight
+				ensure!(covers_call(&call), Error::<T>::MaxWeightTooLow);
+
+				// Clean up storage before executing call to avoid an possibility of reentrancy
+				// attack.
+				<Multisigs<T>>::remove(&id, call_hash);
+				<MultisigMetadata<T>>::remove(&id, call_hash);
+				<MultisigExpiries<T>>::remove(&id, call_hash);
+				<MultisigTimelocks<T>>::remove(&id, call_hash);
+				<CancellationApprovals<T>>::remove(&id, call_hash);
+				Self::drop_stored_call(&id, &call_hash);
+				Self::cancel_deadline(&id, &call_hash);
+				Self::release_deposit(&id, &call_hash, &m.depositor, m.deposit);
+
+				let call_weight = call.get_dispatch_info().weight;
+				let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
+				let maybe_actual_weight = get_result_weight(result);
+				let mut fee_weight = Weight::zero();
+				if charge_multisig {
+					// Reimburse the sender for the fee of the call, for the weight actually used.
+					let fee = T::CallFee::call_fee(
+						submitted_call_len,
+						maybe_actual_weight.unwrap_or(call_weight).min(call_weight),
+					);
+					Self::charge_fee(&id, &who, fee);
+					fee_weight = T::DbWeight::get().reads_writes(2, 2);
+				}
 				Self::deposit_event(Event::MultisigExecuted {
 					approving: who,
 					timepoint,
@@ -569,13 +2162,14 @@ impl<T: Config> Pallet<T> {
 					call_hash,
 					result: result.map(|_| ()).map_err(|e| e.error),
 				});
-				Ok(get_result_weight(result)
+				Ok(maybe_actual_weight
 					.map(|actual_weight| {
 						T::WeightInfo::as_multi_complete(
 							other_signatories_len as u32,
 							call_len as u32,
 						)
 						.saturating_add(actual_weight)
+						.saturating_add(fee_weight)
 					})
 					.into())
 			} else {
@@ -587,19 +2181,31 @@ impl<T: Config> Pallet<T> {
 					m.approvals
 						.try_insert(pos, who.clone())
 						.map_err(|_| Error::<T>::TooManySignatories)?;
+					if let Ok(pos) = m.rejections.binary_search(&who) {
+						m.rejections.remove(pos);
+					}
 					<Multisigs<T>>::insert(&id, call_hash, m);
 					Self::deposit_event(Event::MultisigApproval {
 						approving: who,
 						timepoint,
-						multisig: id,
+						multisig: id.clone(),
 						call_hash,
 					});
-				} else {
+				} else if timelocked_until.is_none() {
 					// If we already approved and didn't store the Call, then this was useless and
 					// we report an error.
 					Err(Error::<T>::AlreadyApproved)?
 				}
 
+				if let Some(executable_at) = timelocked_until {
+					<MultisigTimelocks<T>>::insert(&id, call_hash, executable_at);
+					Self::deposit_event(Event::MultisigTimelocked {
+						multisig: id,
+						call_hash,
+						executable_at,
+					});
+				}
+
 				let final_weight =
 					T::WeightInfo::as_multi_approve(other_signatories_len as u32, call_len as u32);
 				// Call is not made, so the actual weight does not include call
@@ -610,9 +2216,14 @@ impl<T: Config> Pallet<T> {
 			ensure!(maybe_timepoint.is_none(), Error::<T>::UnexpectedTimepoint);
 
 			// Just start the operation by recording it in storage.
-			let deposit = T::DepositBase::get() + T::DepositFactor::get() * threshold.into();
+			let deposit = Self::operation_deposit(
+				threshold,
+				maybe_metadata.as_ref().map(|metadata| metadata.len() as u32),
+				maybe_stored_call.as_ref().map(|call| call.len().unwrap_or_default()),
+			);
 
-			T::Currency::reserve(&who, deposit)?;
+			T::Currency::hold(&HoldReason::MultisigDeposit.into(), &who, deposit)?;
+			<HeldDeposits<T>>::insert(&id, call_hash, ());
 
 			let initial_approvals =
 				vec![who.clone()].try_into().map_err(|_| Error::<T>::TooManySignatories)?;
@@ -625,17 +2236,181 @@ impl<T: Config> Pallet<T> {
 					deposit,
 					depositor: who.clone(),
 					approvals: initial_approvals,
+					rejections: Default::default(),
 				},
 			);
-			Self::deposit_event(Event::NewMultisig { approving: who, multisig: id, call_hash });
+			Self::deposit_event(Event::NewMultisig {
+				approving: who,
+				multisig: id.clone(),
+				call_hash,
+			});
 
-			let final_weight =
+			let mut final_weight =
 				T::WeightInfo::as_multi_create(other_signatories_len as u32, call_len as u32);
+			if let Some(metadata) = maybe_metadata {
+				<MultisigMetadata<T>>::insert(&id, call_hash, &metadata);
+				Self::deposit_event(Event::MultisigMetadataSet {
+					multisig: id.clone(),
+					call_hash,
+					metadata,
+				});
+				final_weight.saturating_accrue(T::DbWeight::get().writes(1));
+			}
+			if let Some(call) = maybe_stored_call {
+				final_weight = T::WeightInfo::as_multi_store_call(
+					other_signatories_len as u32,
+					call.len().unwrap_or_default(),
+				);
+				Self::store_call(&id, call_hash, call);
+				Self::deposit_event(Event::MultisigCallStored { multisig: id, call_hash });
+			}
 			// Call is not made, so the actual weight does not include call
 			Ok(Some(final_weight).into())
 		}
 	}
 
+	/// Transfer `fee` for the execution of a call of the multisig account `multisig` to `payer`,
+	/// who paid it with the transaction fee.
+	///
+	/// Nothing is transferred if `multisig` cannot pay the fee, leaving it with `payer`.
+	fn charge_fee(multisig: &T::AccountId, payer: &T::AccountId, fee: BalanceOf<T>) {
+		if fee.is_zero() {
+			return
+		}
+		if T::Currency::transfer(multisig, payer, fee, Preservation::Preserve).is_ok() {
+			Self::deposit_event(Event::MultisigFeeCharged {
+				multisig: multisig.clone(),
+				payer: payer.clone(),
+				fee,
+			});
+		}
+	}
+
+	/// The deposit for a multisig operation with `threshold`, given the lengths of its metadata
+	/// and of its stored call, if any.
+	fn operation_deposit(
+		threshold: u16,
+		maybe_metadata_len: Option<u32>,
+		maybe_call_len: Option<u32>,
+	) -> BalanceOf<T> {
+		T::DepositBase::get() +
+			T::DepositFactor::get() * threshold.into() +
+			maybe_metadata_len.map_or(Zero::zero(), Self::data_deposit) +
+			maybe_call_len.map_or(Zero::zero(), Self::data_deposit)
+	}
+
+	/// The deposit for storing `len` bytes of data along with a multisig operation.
+	fn data_deposit(len: u32) -> BalanceOf<T> {
+		T::DepositFactor::get() * len.div_ceil(32).saturating_add(1).into()
+	}
+
+	/// Return the `deposit` of the multisig operation of `multisig` for `call_hash` to its
+	/// `depositor`, whether it is held or still reserved.
+	fn release_deposit(
+		multisig: &T::AccountId,
+		call_hash: &[u8; 32],
+		depositor: &T::AccountId,
+		deposit: BalanceOf<T>,
+	) {
+		if <HeldDeposits<T>>::take(multisig, call_hash).is_some() {
+			let released = T::Currency::release(
+				&HoldReason::MultisigDeposit.into(),
+				depositor,
+				deposit,
+				Precision::BestEffort,
+			);
+			debug_assert_eq!(released, Ok(deposit));
+		} else {
+			let err_amount = T::OldCurrency::unreserve(depositor, deposit);
+			debug_assert!(err_amount.is_zero());
+		}
+	}
+
+	/// Store `call` as the call of the multisig operation of `multisig` for `call_hash`.
+	///
+	/// Its preimage is requested for as long as it is stored, independently of other requests for
+	/// the same preimage.
+	fn store_call(multisig: &T::AccountId, call_hash: [u8; 32], call: BoundedCallOf<T>) {
+		T::Preimages::hold(&call);
+		<MultisigCalls<T>>::insert(multisig, call_hash, call);
+	}
+
+	/// Remove the stored call of the multisig operation of `multisig` for `call_hash`, if any, and
+	/// release the request for its preimage taken by `store_call`.
+	fn drop_stored_call(multisig: &T::AccountId, call_hash: &[u8; 32]) {
+		if let Some(call) = <MultisigCalls<T>>::take(multisig, call_hash) {
+			T::Preimages::drop(&call);
+		}
+	}
+
+	/// The weight of fetching a stored call of length `call_len` to execute it with the final
+	/// approval of a multisig with `s` other signatories, beyond what `approve_as_multi` is
+	/// weighed with.
+	fn stored_call_fetch_weight(s: u32, call_len: u32) -> Weight {
+		T::WeightInfo::as_multi_complete(s, call_len)
+			.saturating_sub(T::WeightInfo::as_multi_complete(s, 0))
+	}
+
+	/// Schedule the execution of the stored call of the multisig operation of `multisig` for
+	/// `call_hash` at its `deadline`.
+	fn schedule_deadline(
+		multisig: &T::AccountId,
+		call_hash: [u8; 32],
+		deadline: Deadline<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let call = Call::<T>::execute_at_deadline {
+			multisig: multisig.clone(),
+			call_hash,
+			call_len: deadline.call_len,
+			max_weight: deadline.call_weight,
+		};
+		T::Scheduler::schedule_named(
+			Self::deadline_task_name(multisig, &call_hash),
+			DispatchTime::At(deadline.at),
+			None,
+			LOWEST_PRIORITY,
+			RawOrigin::Signed(multisig.clone()).into(),
+			T::Preimages::bound(<T as Config>::RuntimeCall::from(call))?,
+		)?;
+		<MultisigDeadlines<T>>::insert(multisig, call_hash, deadline);
+		Ok(())
+	}
+
+	/// Remove the deadline of the multisig operation of `multisig` for `call_hash`, if any, and
+	/// cancel the execution scheduled for it.
+	fn cancel_deadline(multisig: &T::AccountId, call_hash: &[u8; 32]) {
+		if <MultisigDeadlines<T>>::take(multisig, call_hash).is_some() {
+			let _ = T::Scheduler::cancel_named(Self::deadline_task_name(multisig, call_hash));
+		}
+	}
+
+	/// The name of the execution of the multisig operation of `multisig` for `call_hash` scheduled
+	/// at its deadline.
+	fn deadline_task_name(multisig: &T::AccountId, call_hash: &[u8; 32]) -> TaskName {
+		(DEADLINE_ID, multisig, call_hash).using_encoded(blake2_256)
+	}
+
+	/// The weight of `execute_at_deadline` for a stored call of length `call_len`, without the
+	/// weight of the call.
+	fn execute_at_deadline_weight(call_len: u32) -> Weight {
+		T::WeightInfo::as_multi_complete(T::MaxSignatories::get(), call_len)
+	}
+
+	/// Whether the multisig operation of `multisig` for `call_hash` is timelocked and cannot be
+	/// executed yet.
+	fn is_timelocked(multisig: &T::AccountId, call_hash: &[u8; 32]) -> bool {
+		<MultisigTimelocks<T>>::get(multisig, call_hash).map_or(false, |executable_at| {
+			T::BlockNumberProvider::current_block_number() < executable_at
+		})
+	}
+
+	/// Whether the multisig operation of `multisig` for `call_hash` has an expiry which passed.
+	fn is_expired(multisig: &T::AccountId, call_hash: &[u8; 32]) -> bool {
+		<MultisigExpiries<T>>::get(multisig, call_hash).map_or(false, |expires_at| {
+			T::BlockNumberProvider::current_block_number() >= expires_at
+		})
+	}
+
 	/// The current `Timepoint`.
 	pub fn timepoint() -> Timepoint<BlockNumberFor<T>> {
 		Timepoint {
@@ -644,6 +2419,20 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The payload signatories sign off-chain to approve the multisig operation of `multisig` for
+	/// `call_hash` which started at `timepoint`.
+	///
+	/// It is prefixed with a tag and the genesis hash, so that a signature can neither be
+	/// replayed on another chain nor be mistaken for one made for another purpose.
+	pub fn signature_payload(
+		call_hash: &[u8; 32],
+		timepoint: &Timepoint<BlockNumberFor<T>>,
+		multisig: &T::AccountId,
+	) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+		(SIGNATURE_TAG, genesis_hash, call_hash, timepoint, multisig).encode()
+	}
+
 	/// Check that signatories is sorted and doesn't contain sender, then insert sender.
 	fn ensure_sorted_and_insert(
 		other_signatories: Vec<T::AccountId>,
@@ -19,7 +19,7 @@
 
 use super::*;
 use frame_support::{
-	traits::{GetStorageVersion, OnRuntimeUpgrade, WrapperKeepOpaque},
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion, WrapperKeepOpaque},
 	Identity,
 };
 
@@ -51,7 +51,6 @@ pub mod v1 {
 		fn on_runtime_upgrade() -> Weight {
 			use sp_runtime::Saturating;
 
-			let current = Pallet::<T>::in_code_storage_version();
 			let onchain = Pallet::<T>::on_chain_storage_version();
 
 			if onchain > 0 {
@@ -61,11 +60,11 @@ pub mod v1 {
 
 			let mut call_count = 0u64;
 			Calls::<T>::drain().for_each(|(_call_hash, (_data, caller, deposit))| {
-				T::Currency::unreserve(&caller, deposit);
+				T::OldCurrency::unreserve(&caller, deposit);
 				call_count.saturating_inc();
 			});
 
-			current.put::<Pallet<T>>();
+			StorageVersion::new(1).put::<Pallet<T>>();
 
 			T::DbWeight::get().reads_writes(
 				// Reads: Get Calls + Get Version
@@ -85,3 +84,77 @@ pub mod v1 {
 		}
 	}
 }
+
+pub mod v2 {
+	use super::*;
+
+	/// An open multisig operation before the rejections were recorded.
+	#[derive(Decode)]
+	struct OldMultisig<BlockNumber, Balance, AccountId, MaxApprovals: Get<u32>> {
+		when: Timepoint<BlockNumber>,
+		deposit: Balance,
+		depositor: AccountId,
+		approvals: BoundedVec<AccountId, MaxApprovals>,
+	}
+
+	type OldMultisigOf<T> = OldMultisig<
+		BlockNumberFor<T>,
+		BalanceOf<T>,
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::MaxSignatories,
+	>;
+
+	/// Adds the rejections to the open multisig operations.
+	pub struct MigrateToV2<T>(core::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let count = Multisigs::<T>::iter_keys().count() as u32;
+			log!(info, "Number of multisig operations to migrate: {}", count);
+
+			Ok(count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			use sp_runtime::Saturating;
+
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			if onchain != 1 {
+				log!(info, "MigrateToV2 should be removed");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count = 0u64;
+			Multisigs::<T>::translate::<OldMultisigOf<T>, _>(|_, _, old| {
+				count.saturating_inc();
+				Some(Multisig {
+					when: old.when,
+					deposit: old.deposit,
+					depositor: old.depositor,
+					approvals: old.approvals,
+					rejections: Default::default(),
+				})
+			});
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			// Reads: Get Multisigs + Get Version
+			// Writes: Set Multisigs + Set version
+			T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(
+				Multisigs::<T>::iter_values().count() as u32 == count,
+				"some multisig operations failed to migrate"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"the storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
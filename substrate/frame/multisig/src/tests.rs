@@ -24,9 +24,18 @@ use super::*;
 use crate as pallet_multisig;
 use frame_support::{
 	assert_noop, assert_ok, derive_impl,
-	traits::{ConstU32, ConstU64, Contains},
+	dispatch::Pays,
+	parameter_types,
+	traits::{
+		ConstBool, ConstU32, ConstU64, Contains, EqualPrivilegeOnly, GetStorageVersion,
+		OnInitialize, OnRuntimeUpgrade, StorageVersion, UnixTime,
+	},
+};
+use frame_system::EnsureRoot;
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	BuildStorage, TokenError,
 };
-use sp_runtime::{BuildStorage, TokenError};
 
 type Block = frame_system::mocking::MockBlockU32<Test>;
 
@@ -34,6 +43,7 @@ frame_support::construct_runtime!(
 	pub enum Test {
 		System: frame_system,
 		Balances: pallet_balances,
+		Scheduler: pallet_scheduler,
 		Multisig: pallet_multisig,
 	}
 );
@@ -52,6 +62,44 @@ impl pallet_balances::Config for Test {
 	type AccountStore = System;
 }
 
+parameter_types! {
+	pub MaxWeight: Weight = Weight::from_parts(2_000_000_000_000, u64::MAX);
+}
+
+/// Scheduler time source which never advances.
+pub struct MockTime;
+impl UnixTime for MockTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::ZERO
+	}
+}
+
+impl pallet_scheduler::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaxWeight;
+	type ScheduleOrigin = EnsureRoot<u64>;
+	type MaxScheduledPerBlock = ConstU32<100>;
+	type BlockNumberProvider = System;
+	type WeightInfo = ();
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type Preimages = ();
+	type RejectOverweight = ConstBool<false>;
+	type TimeProvider = MockTime;
+	type MaximumBlockTime = ConstU64<6_000>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type ScheduleDeposit = ConstU64<0>;
+	type MaxScheduledPerOrigin = ();
+	type MaxSchedulingHorizon = ();
+	type MaxMergedAgendas = ConstU32<1>;
+	type MaxIdleWeight = ();
+	type MaxOverflowed = ConstU32<100>;
+	type OnChargeDispatch = ();
+}
+
 pub struct TestBaseCallFilter;
 impl Contains<RuntimeCall> for TestBaseCallFilter {
 	fn contains(c: &RuntimeCall) -> bool {
@@ -67,12 +115,45 @@ impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type OldCurrency = Balances;
+	type CallFee = TestCallFee;
 	type DepositBase = ConstU64<1>;
 	type DepositFactor = ConstU64<1>;
 	type MaxSignatories = ConstU32<3>;
+	type MaxMetadataLen = ConstU32<64>;
+	type Preimages = ();
+	type BlockNumberProvider = System;
+	type Scheduler = Scheduler;
+	type MaxDeadline = ConstU64<10>;
+	type PalletsOrigin = OriginCaller;
+	type OffchainSignature = TestSignature;
+	type SigningPublicKey = UintAuthorityId;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestBenchmarkHelper;
 	type WeightInfo = ();
 }
 
+/// Charges one unit for a call that used any weight, plus one unit per 10 bytes of the call.
+pub struct TestCallFee;
+impl CallFee<u64> for TestCallFee {
+	fn call_fee(len: u32, weight: Weight) -> u64 {
+		weight.ref_time().min(1) + len as u64 / 10
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<UintAuthorityId, u64, TestSignature> for TestBenchmarkHelper {
+	fn signer(i: u32) -> (UintAuthorityId, u64) {
+		(UintAuthorityId(100 + i as u64), 100 + i as u64)
+	}
+	fn sign(signer: &UintAuthorityId, message: &[u8]) -> TestSignature {
+		TestSignature(signer.0, message.to_vec())
+	}
+}
+
 use pallet_balances::Call as BalancesCall;
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -130,7 +211,907 @@ fn multisig_deposit_is_taken_and_returned() {
 }
 
 #[test]
-fn cancel_multisig_returns_deposit() {
+fn cancel_multisig_returns_deposit() {
+	new_test_ext().execute_with(|| {
+		let call = call_transfer(6, 15).encode();
+		let hash = blake2_256(&call);
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(2),
+			3,
+			vec![1, 3],
+			Some(now()),
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::free_balance(1), 6);
+		assert_eq!(Balances::reserved_balance(1), 4);
+		assert_ok!(Multisig::cancel_as_multi(RuntimeOrigin::signed(1), 3, vec![2, 3], now(), hash));
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn cancel_multisig_by_threshold_returns_deposit() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3], 2);
+		let call = call_transfer(6, 15).encode();
+		let hash = blake2_256(&call);
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		assert_ok!(Multisig::cancel_as_multi_by_threshold(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			now(),
+			hash
+		));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigCancellationApproval {
+				approving: 2,
+				timepoint: now(),
+				multisig: multi,
+				call_hash: hash,
+			}
+			.into(),
+		);
+		assert_noop!(
+			Multisig::cancel_as_multi_by_threshold(
+				RuntimeOrigin::signed(2),
+				2,
+				vec![1, 3],
+				now(),
+				hash
+			),
+			Error::<Test>::AlreadyApproved,
+		);
+		assert!(Multisigs::<Test>::contains_key(multi, hash));
+
+		// The second approval cancels the operation, without the depositor.
+		assert_ok!(Multisig::cancel_as_multi_by_threshold(
+			RuntimeOrigin::signed(3),
+			2,
+			vec![1, 2],
+			now(),
+			hash
+		));
+		assert!(!Multisigs::<Test>::contains_key(&multi, hash));
+		assert!(!CancellationApprovals::<Test>::contains_key(&multi, hash));
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn poke_deposit_holds_reserved_deposit() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3], 3);
+		let reason = RuntimeHoldReason::Multisig(HoldReason::MultisigDeposit);
+		// Open two operations with their deposits reserved, as before the deposits were held.
+		let hashes = [call_transfer(6, 15), call_transfer(6, 10)].map(|c| blake2_256(&c.encode()));
+		for hash in hashes {
+			assert_ok!(Multisig::approve_as_multi(
+				RuntimeOrigin::signed(1),
+				3,
+				vec![2, 3],
+				None,
+				hash,
+				Weight::zero()
+			));
+			assert_ok!(Balances::release(&reason, &1, 4, Precision::Exact));
+			assert_ok!(Balances::reserve(&1, 4));
+			HeldDeposits::<Test>::remove(multi, hash);
+		}
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 0);
+
+		assert_noop!(
+			Multisig::poke_deposit(RuntimeOrigin::signed(2), 3, vec![1, 3], hashes[0]),
+			Error::<Test>::NotOwner,
+		);
+		let post_info =
+			Multisig::poke_deposit(RuntimeOrigin::signed(1), 3, vec![2, 3], hashes[0]).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 4);
+		assert_eq!(Balances::reserved_balance(1), 8);
+		System::assert_last_event(
+			pallet_multisig::Event::DepositPoked {
+				who: 1,
+				multisig: multi,
+				call_hash: hashes[0],
+				old_deposit: 4,
+				new_deposit: 4,
+			}
+			.into(),
+		);
+		// There is nothing left to poke.
+		let post_info =
+			Multisig::poke_deposit(RuntimeOrigin::signed(1), 3, vec![2, 3], hashes[0]).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::Yes);
+
+		// Both the held and the reserved deposit are returned.
+		for hash in hashes {
+			assert_ok!(Multisig::cancel_as_multi(
+				RuntimeOrigin::signed(1),
+				3,
+				vec![2, 3],
+				now(),
+				hash
+			));
+		}
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 10);
+	});
+}
+
+#[test]
+fn stored_call_is_executed_at_deadline_once_approved() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(4), multi, 10));
+
+		let approved = call_transfer(6, 10);
+		let approved_hash = blake2_256(&approved.encode());
+		let unapproved = call_transfer(7, 5);
+		let unapproved_hash = blake2_256(&unapproved.encode());
+		assert_ok!(Multisig::as_multi_store_call_with_deadline(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			approved,
+			5
+		));
+		assert_ok!(Multisig::as_multi_store_call_with_deadline(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			unapproved,
+			5
+		));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigDeadlineSet {
+				multisig: multi,
+				call_hash: unapproved_hash,
+				deadline: 5,
+			}
+			.into(),
+		);
+
+		// The final approval does not cover the weight of the call, so it is only recorded.
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(3),
+			2,
+			vec![1, 2],
+			Some(now()),
+			approved_hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+
+		System::set_block_number(5);
+		Scheduler::on_initialize(5);
+		assert_eq!(Balances::free_balance(6), 10);
+		assert!(!Multisigs::<Test>::contains_key(multi, approved_hash));
+		System::assert_has_event(
+			pallet_multisig::Event::MultisigExecutedAtDeadline {
+				timepoint: Timepoint { height: 1, index: 0 },
+				multisig: multi,
+				call_hash: approved_hash,
+				result: Ok(()),
+			}
+			.into(),
+		);
+
+		// The deadline of the operation which was not approved lapses, it stays open.
+		assert!(Multisigs::<Test>::contains_key(multi, unapproved_hash));
+		assert!(!MultisigDeadlines::<Test>::contains_key(multi, unapproved_hash));
+		System::assert_has_event(
+			pallet_multisig::Event::MultisigDeadlineLapsed {
+				multisig: multi,
+				call_hash: unapproved_hash,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn cancelling_removes_deadline() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let call = call_transfer(6, 10);
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::as_multi_store_call_with_deadline(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			call,
+			5
+		));
+		assert!(MultisigDeadlines::<Test>::contains_key(multi, hash));
+		assert_ok!(Multisig::cancel_as_multi(RuntimeOrigin::signed(1), 2, vec![2, 3], now(), hash));
+		assert!(!MultisigDeadlines::<Test>::contains_key(multi, hash));
+		// Nothing is executed at the deadline.
+		assert!(pallet_scheduler::Agenda::<Test>::get(5).iter().all(Option::is_none));
+	});
+}
+
+#[test]
+fn deadline_must_be_within_bounds() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		for deadline in [1, 12] {
+			assert_noop!(
+				Multisig::as_multi_store_call_with_deadline(
+					RuntimeOrigin::signed(1),
+					2,
+					vec![2, 3],
+					call_transfer(6, 10),
+					deadline
+				),
+				Error::<Test>::InvalidDeadline,
+			);
+		}
+
+		let call = call_transfer(6, 10);
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::as_multi_store_call_with_deadline(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			call,
+			11
+		));
+		// The execution is scheduled with the origin of the multisig account.
+		assert_eq!(
+			pallet_scheduler::ScheduledCount::<Test>::get(OriginCaller::system(
+				frame_system::RawOrigin::Signed(multi)
+			)),
+			1
+		);
+		// Nobody else may execute it.
+		assert_noop!(
+			Multisig::execute_at_deadline(RuntimeOrigin::signed(1), multi, hash, 0, Weight::MAX),
+			DispatchError::BadOrigin,
+		);
+	});
+}
+
+#[test]
+fn multisig_metadata_is_stored_and_removed() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 10);
+		let hash = blake2_256(&call.encode());
+		let metadata: MetadataOf<Test> = vec![7u8; 32].try_into().unwrap();
+		assert_ok!(Multisig::approve_as_multi_with_metadata(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			hash,
+			metadata.clone()
+		));
+		// the deposit for the operation plus two words for the metadata
+		assert_eq!(Balances::reserved_balance(1), 5);
+		assert_eq!(MultisigMetadata::<Test>::get(multi, hash), Some(metadata.clone()));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigMetadataSet {
+				multisig: multi,
+				call_hash: hash,
+				metadata,
+			}
+			.into(),
+		);
+
+		// metadata can only be attached when opening the operation
+		assert_noop!(
+			Multisig::approve_as_multi_with_metadata(
+				RuntimeOrigin::signed(2),
+				2,
+				vec![1, 3],
+				hash,
+				vec![1u8].try_into().unwrap()
+			),
+			Error::<Test>::NoTimepoint,
+		);
+
+		let call_weight = call.get_dispatch_info().weight;
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 10);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(MultisigMetadata::<Test>::get(multi, hash), None);
+	});
+}
+
+#[test]
+fn expired_multisig_can_be_reaped_by_anyone() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let hash = blake2_256(&call_transfer(6, 15).encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		// only the depositor can set a future expiry
+		assert_noop!(
+			Multisig::set_expiry(RuntimeOrigin::signed(2), 2, vec![1, 3], hash, Some(5)),
+			Error::<Test>::NotOwner,
+		);
+		assert_noop!(
+			Multisig::set_expiry(RuntimeOrigin::signed(1), 2, vec![2, 3], hash, Some(1)),
+			Error::<Test>::InvalidExpiry,
+		);
+		assert_ok!(Multisig::set_expiry(RuntimeOrigin::signed(1), 2, vec![2, 3], hash, Some(5)));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigExpirySet {
+				multisig: multi,
+				call_hash: hash,
+				expires_at: Some(5),
+			}
+			.into(),
+		);
+		assert_noop!(
+			Multisig::reap_expired(RuntimeOrigin::signed(4), multi, hash),
+			Error::<Test>::NotExpired,
+		);
+
+		System::set_block_number(5);
+		assert_noop!(
+			Multisig::approve_as_multi(
+				RuntimeOrigin::signed(2),
+				2,
+				vec![1, 3],
+				Some(Timepoint { height: 1, index: 0 }),
+				hash,
+				Weight::zero()
+			),
+			Error::<Test>::Expired,
+		);
+		assert_ok!(Multisig::reap_expired(RuntimeOrigin::signed(4), multi, hash));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigExpired {
+				timepoint: Timepoint { height: 1, index: 0 },
+				multisig: multi,
+				call_hash: hash,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(1), 10);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		assert!(!MultisigExpiries::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn cleared_expiry_keeps_multisig_open() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let hash = blake2_256(&call_transfer(6, 15).encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_ok!(Multisig::set_expiry(RuntimeOrigin::signed(1), 2, vec![2, 3], hash, Some(5)));
+		assert_ok!(Multisig::set_expiry(RuntimeOrigin::signed(1), 2, vec![2, 3], hash, None));
+
+		System::set_block_number(5);
+		assert_noop!(
+			Multisig::reap_expired(RuntimeOrigin::signed(4), multi, hash),
+			Error::<Test>::NotExpired,
+		);
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			Some(Timepoint { height: 1, index: 0 }),
+			hash,
+			Weight::zero()
+		));
+	});
+}
+
+#[test]
+fn multisig_3_of_3_with_signatures_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 15);
+		let call_weight = call.get_dispatch_info().weight;
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+
+		let payload = Multisig::signature_payload(&hash, &now(), &multi);
+		let sign = |who: u64| (who, TestSignature(who, payload.clone()));
+		assert_noop!(
+			Multisig::as_multi_with_signatures(
+				RuntimeOrigin::signed(1),
+				3,
+				vec![2, 3],
+				now(),
+				call.clone(),
+				vec![sign(2), sign(4)],
+				call_weight
+			),
+			Error::<Test>::NotSignatory,
+		);
+		assert_noop!(
+			Multisig::as_multi_with_signatures(
+				RuntimeOrigin::signed(1),
+				3,
+				vec![2, 3],
+				now(),
+				call.clone(),
+				vec![sign(2), (3, TestSignature(3, hash.to_vec()))],
+				call_weight
+			),
+			Error::<Test>::InvalidSignature,
+		);
+		// signatures over the payload without the tag and the genesis hash are not accepted.
+		let untagged = (hash, now(), multi).encode();
+		assert_noop!(
+			Multisig::as_multi_with_signatures(
+				RuntimeOrigin::signed(1),
+				3,
+				vec![2, 3],
+				now(),
+				call.clone(),
+				vec![sign(2), (3, TestSignature(3, untagged))],
+				call_weight
+			),
+			Error::<Test>::InvalidSignature,
+		);
+
+		assert_ok!(Multisig::as_multi_with_signatures(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			now(),
+			call,
+			vec![sign(2), sign(3)],
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 15);
+		assert_eq!(Balances::free_balance(1), 5);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn migrate_multisig_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let new_multi = Multisig::multi_account_id(&[1, 3, 4][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 15);
+		let call_weight = call.get_dispatch_info().weight;
+		let hash = blake2_256(&call.encode());
+		let metadata: MetadataOf<Test> = vec![7u8; 8].try_into().unwrap();
+		assert_ok!(Multisig::approve_as_multi_with_metadata(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			hash,
+			metadata.clone()
+		));
+
+		assert_noop!(
+			Multisig::migrate_multisig(RuntimeOrigin::signed(multi), 2, vec![1, 4, 3], vec![hash]),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_noop!(
+			Multisig::migrate_multisig(RuntimeOrigin::signed(multi), 4, vec![1, 3, 4], vec![hash]),
+			Error::<Test>::InvalidThreshold,
+		);
+		assert_noop!(
+			Multisig::migrate_multisig(
+				RuntimeOrigin::signed(multi),
+				2,
+				vec![1, 3, 4],
+				vec![[0; 32]]
+			),
+			Error::<Test>::NotFound,
+		);
+
+		assert_ok!(Multisig::migrate_multisig(
+			RuntimeOrigin::signed(multi),
+			2,
+			vec![1, 3, 4],
+			vec![hash]
+		));
+		System::assert_last_event(
+			Event::MultisigMigrated {
+				multisig: multi,
+				new_multisig: new_multi,
+				amount: 15,
+				operations: 1,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(multi), 0);
+		assert_eq!(Balances::free_balance(new_multi), 15);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		assert_eq!(MultisigMetadata::<Test>::get(new_multi, hash), Some(metadata));
+
+		// The operation continues with the new signatories.
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(4),
+			2,
+			vec![1, 3],
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 15);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn migrate_multisig_moves_deposit_of_dropped_depositor() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let new_multi = Multisig::multi_account_id(&[1, 3, 4][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 15));
+
+		let hash = blake2_256(&call_transfer(6, 15).encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(2), 3);
+
+		assert_ok!(Multisig::migrate_multisig(
+			RuntimeOrigin::signed(multi),
+			2,
+			vec![1, 3, 4],
+			vec![hash]
+		));
+		// The deposit of the dropped depositor is returned and held from the new account.
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(multi), 0);
+		assert_eq!(Balances::free_balance(new_multi), 12);
+		assert_eq!(Balances::reserved_balance(new_multi), 3);
+		assert!(HeldDeposits::<Test>::contains_key(new_multi, hash));
+
+		// Once the operation ends, the deposit is returned to the new account.
+		for (who, others) in [(1, vec![3, 4]), (3, vec![1, 4])] {
+			assert_ok!(Multisig::cancel_as_multi_by_threshold(
+				RuntimeOrigin::signed(who),
+				2,
+				others,
+				now(),
+				hash
+			));
+		}
+		assert!(!Multisigs::<Test>::contains_key(new_multi, hash));
+		assert_eq!(Balances::free_balance(new_multi), 15);
+	});
+}
+
+#[test]
+fn cancel_multisig_removes_metadata() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
+		let hash = blake2_256(&call_transfer(6, 15).encode());
+		assert_ok!(Multisig::approve_as_multi_with_metadata(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			hash,
+			vec![7u8; 33].try_into().unwrap()
+		));
+		// the deposit for the operation plus three words for the metadata
+		assert_eq!(Balances::reserved_balance(1), 7);
+
+		assert_ok!(Multisig::cancel_as_multi(RuntimeOrigin::signed(1), 3, vec![2, 3], now(), hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(MultisigMetadata::<Test>::get(multi, hash), None);
+	});
+}
+
+#[test]
+fn timepoint_checking_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 15);
+		let hash = blake2_256(&call.encode());
+
+		assert_noop!(
+			Multisig::approve_as_multi(
+				RuntimeOrigin::signed(2),
+				2,
+				vec![1, 3],
+				Some(now()),
+				hash,
+				Weight::zero()
+			),
+			Error::<Test>::UnexpectedTimepoint,
+		);
+
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+
+		assert_noop!(
+			Multisig::as_multi(
+				RuntimeOrigin::signed(2),
+				2,
+				vec![1, 3],
+				None,
+				call.clone(),
+				Weight::zero()
+			),
+			Error::<Test>::NoTimepoint,
+		);
+		let later = Timepoint { index: 1, ..now() };
+		assert_noop!(
+			Multisig::as_multi(
+				RuntimeOrigin::signed(2),
+				2,
+				vec![1, 3],
+				Some(later),
+				call,
+				Weight::zero()
+			),
+			Error::<Test>::WrongTimepoint,
+		);
+	});
+}
+
+#[test]
+fn multisig_2_of_3_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 15);
+		let call_weight = call.get_dispatch_info().weight;
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 15);
+	});
+}
+
+#[test]
+fn multisig_fee_can_be_charged_to_multisig() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 10);
+		let call_weight = call.get_dispatch_info().weight;
+		let hash = blake2_256(&call.encode());
+		// The fee for the weight of the call and its 11 bytes.
+		assert_eq!(TestCallFee::call_fee(call.encoded_size() as u32, call_weight), 2);
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_multi_charging_multisig(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 10);
+		// The multisig reimburses the sender for the fee of the call.
+		System::assert_has_event(
+			pallet_multisig::Event::MultisigFeeCharged { multisig: multi, payer: 2, fee: 2 }.into(),
+		);
+		assert_eq!(Balances::free_balance(multi), 3);
+		assert_eq!(Balances::free_balance(2), 7);
+
+		// Without the balance to pay the fee, the sender bears it.
+		let call = call_transfer(6, 3);
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_multi_charging_multisig(
+			RuntimeOrigin::signed(3),
+			2,
+			vec![1, 2],
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 13);
+		assert_eq!(Balances::free_balance(3), 5);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Multisig(Event::MultisigFeeCharged { payer: 3, .. })
+		)));
+	});
+}
+
+#[test]
+fn multisig_2_of_3_with_stored_call_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(4), multi, 5));
+
+		// Calls which do not fit into the preimage provider cannot be stored.
+		let large_call =
+			Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![0; 200] }));
+		assert_noop!(
+			Multisig::as_multi_store_call(RuntimeOrigin::signed(1), 2, vec![2, 3], large_call),
+			DispatchError::Exhausted,
+		);
+
+		let call = call_transfer(6, 15);
+		let call_weight = call.get_dispatch_info().weight;
+		let call_len = call.encoded_size() as u32;
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::as_multi_store_call(RuntimeOrigin::signed(1), 2, vec![2, 3], call));
+		// the deposit for the operation plus two words for the call
+		assert_eq!(Balances::reserved_balance(1), 5);
+		assert!(MultisigCalls::<Test>::contains_key(multi, hash));
+
+		// The final approval must also cover fetching the stored call.
+		assert_noop!(
+			Multisig::approve_as_multi(
+				RuntimeOrigin::signed(2),
+				2,
+				vec![1, 3],
+				Some(now()),
+				hash,
+				call_weight
+			),
+			Error::<Test>::MaxWeightTooLow,
+		);
+		// The final approval only needs the hash of the call.
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			Some(now()),
+			hash,
+			call_weight + Multisig::stored_call_fetch_weight(2, call_len)
+		));
+		assert_eq!(Balances::free_balance(6), 15);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!MultisigCalls::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn multisig_3_of_3_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 15);
+		let call_weight = call.get_dispatch_info().weight;
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(2),
+			3,
+			vec![1, 3],
+			Some(now()),
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(3),
+			3,
+			vec![1, 2],
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 15);
+	});
+}
+
+#[test]
+fn cancel_multisig_works() {
 	new_test_ext().execute_with(|| {
 		let call = call_transfer(6, 15).encode();
 		let hash = blake2_256(&call);
@@ -150,37 +1131,85 @@ fn cancel_multisig_returns_deposit() {
 			hash,
 			Weight::zero()
 		));
-		assert_eq!(Balances::free_balance(1), 6);
-		assert_eq!(Balances::reserved_balance(1), 4);
-		assert_ok!(Multisig::cancel_as_multi(RuntimeOrigin::signed(1), 3, vec![2, 3], now(), hash));
-		assert_eq!(Balances::free_balance(1), 10);
-		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_noop!(
+			Multisig::cancel_as_multi(RuntimeOrigin::signed(2), 3, vec![1, 3], now(), hash),
+			Error::<Test>::NotOwner,
+		);
+		assert_ok!(Multisig::cancel_as_multi(RuntimeOrigin::signed(1), 3, vec![2, 3], now(), hash),);
 	});
 }
 
 #[test]
-fn timepoint_checking_works() {
+fn revoke_approval_works() {
 	new_test_ext().execute_with(|| {
-		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
-		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 5));
-		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
-		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 15));
 
 		let call = call_transfer(6, 15);
+		let call_weight = call.get_dispatch_info().weight;
 		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(2),
+			3,
+			vec![1, 3],
+			Some(now()),
+			hash,
+			Weight::zero()
+		));
 
 		assert_noop!(
-			Multisig::approve_as_multi(
-				RuntimeOrigin::signed(2),
-				2,
-				vec![1, 3],
-				Some(now()),
-				hash,
-				Weight::zero()
-			),
-			Error::<Test>::UnexpectedTimepoint,
+			Multisig::revoke_approval(RuntimeOrigin::signed(3), 3, vec![1, 2], now(), hash),
+			Error::<Test>::NotApproved,
+		);
+		assert_ok!(Multisig::revoke_approval(RuntimeOrigin::signed(2), 3, vec![1, 3], now(), hash));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigApprovalRevoked {
+				revoking: 2,
+				timepoint: now(),
+				multisig: multi,
+				call_hash: hash,
+			}
+			.into(),
 		);
+		assert_eq!(Multisigs::<Test>::get(multi, hash).unwrap().approvals.into_inner(), vec![1]);
+
+		// The final approval no longer executes the call.
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(3),
+			3,
+			vec![1, 2],
+			Some(now()),
+			call.clone(),
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+		// The revoked approval can be given again.
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(2),
+			3,
+			vec![1, 3],
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 15);
+	});
+}
 
+#[test]
+fn rejections_cancel_unreachable_multisig() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let call = call_transfer(6, 15).encode();
+		let hash = blake2_256(&call);
 		assert_ok!(Multisig::approve_as_multi(
 			RuntimeOrigin::signed(1),
 			2,
@@ -189,44 +1218,44 @@ fn timepoint_checking_works() {
 			hash,
 			Weight::zero()
 		));
+		assert_eq!(Balances::free_balance(1), 7);
 
-		assert_noop!(
-			Multisig::as_multi(
-				RuntimeOrigin::signed(2),
-				2,
-				vec![1, 3],
-				None,
-				call.clone(),
-				Weight::zero()
-			),
-			Error::<Test>::NoTimepoint,
+		assert_ok!(Multisig::reject_as_multi(RuntimeOrigin::signed(2), 2, vec![1, 3], now(), hash));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigRejection {
+				rejecting: 2,
+				timepoint: now(),
+				multisig: multi,
+				call_hash: hash,
+			}
+			.into(),
 		);
-		let later = Timepoint { index: 1, ..now() };
 		assert_noop!(
-			Multisig::as_multi(
-				RuntimeOrigin::signed(2),
-				2,
-				vec![1, 3],
-				Some(later),
-				call,
-				Weight::zero()
-			),
-			Error::<Test>::WrongTimepoint,
+			Multisig::reject_as_multi(RuntimeOrigin::signed(2), 2, vec![1, 3], now(), hash),
+			Error::<Test>::AlreadyRejected,
+		);
+		// The depositor changes their mind, withdrawing their approval. The threshold can no
+		// longer be reached, so the operation is cancelled.
+		assert_ok!(Multisig::reject_as_multi(RuntimeOrigin::signed(1), 2, vec![2, 3], now(), hash));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigCancelled {
+				cancelling: 1,
+				timepoint: now(),
+				multisig: multi,
+				call_hash: hash,
+			}
+			.into(),
 		);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		assert_eq!(Balances::free_balance(1), 10);
 	});
 }
 
 #[test]
-fn multisig_2_of_3_works() {
+fn approving_withdraws_rejection() {
 	new_test_ext().execute_with(|| {
 		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
-		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 5));
-		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
-		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
-
-		let call = call_transfer(6, 15);
-		let call_weight = call.get_dispatch_info().weight;
-		let hash = blake2_256(&call.encode());
+		let hash = blake2_256(&call_transfer(6, 15).encode());
 		assert_ok!(Multisig::approve_as_multi(
 			RuntimeOrigin::signed(1),
 			2,
@@ -235,87 +1264,140 @@ fn multisig_2_of_3_works() {
 			hash,
 			Weight::zero()
 		));
-		assert_eq!(Balances::free_balance(6), 0);
+		assert_ok!(Multisig::reject_as_multi(RuntimeOrigin::signed(2), 2, vec![1, 3], now(), hash));
+		assert_eq!(Multisigs::<Test>::get(multi, hash).unwrap().rejections.into_inner(), vec![2]);
 
-		assert_ok!(Multisig::as_multi(
+		assert_ok!(Multisig::approve_as_multi(
 			RuntimeOrigin::signed(2),
 			2,
 			vec![1, 3],
 			Some(now()),
-			call,
-			call_weight
+			hash,
+			Weight::zero()
 		));
-		assert_eq!(Balances::free_balance(6), 15);
+		let m = Multisigs::<Test>::get(multi, hash).unwrap();
+		assert_eq!(m.approvals.into_inner(), vec![1, 2]);
+		assert!(m.rejections.is_empty());
 	});
 }
 
 #[test]
-fn multisig_3_of_3_works() {
+fn migrate_to_v2_adds_rejections() {
 	new_test_ext().execute_with(|| {
-		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
-		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 5));
-		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let hash = [1; 32];
+		let approvals: BoundedVec<u64, <Test as Config>::MaxSignatories> =
+			vec![1].try_into().unwrap();
+		// The operation as encoded before the rejections were added.
+		frame_support::storage::unhashed::put(
+			&Multisigs::<Test>::hashed_key_for(multi, hash),
+			&(now(), 3u64, 1u64, approvals.clone()),
+		);
+		StorageVersion::new(1).put::<Multisig>();
+
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		let m = Multisigs::<Test>::get(multi, hash).unwrap();
+		assert_eq!((m.deposit, m.depositor, m.approvals), (3, 1, approvals));
+		assert!(m.rejections.is_empty());
+		assert_eq!(Multisig::on_chain_storage_version(), 2);
+	});
+}
+
+#[test]
+fn execution_delay_timelocks_approved_operations() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
 		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(4), multi, 10));
+		assert_ok!(Multisig::set_execution_delay(RuntimeOrigin::signed(multi), 5));
 
-		let call = call_transfer(6, 15);
+		let call = call_transfer(6, 10);
 		let call_weight = call.get_dispatch_info().weight;
 		let hash = blake2_256(&call.encode());
-		assert_ok!(Multisig::approve_as_multi(
+		let timepoint = now();
+		assert_ok!(Multisig::as_multi(
 			RuntimeOrigin::signed(1),
-			3,
+			2,
 			vec![2, 3],
 			None,
-			hash,
+			call.clone(),
 			Weight::zero()
 		));
-		assert_ok!(Multisig::approve_as_multi(
+		// The final approval timelocks the operation instead of executing it.
+		assert_ok!(Multisig::as_multi(
 			RuntimeOrigin::signed(2),
-			3,
+			2,
 			vec![1, 3],
-			Some(now()),
-			hash,
-			Weight::zero()
+			Some(timepoint),
+			call.clone(),
+			call_weight
 		));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigTimelocked {
+				multisig: multi,
+				call_hash: hash,
+				executable_at: 6,
+			}
+			.into(),
+		);
 		assert_eq!(Balances::free_balance(6), 0);
+		assert_noop!(
+			Multisig::as_multi(
+				RuntimeOrigin::signed(3),
+				2,
+				vec![1, 2],
+				Some(timepoint),
+				call.clone(),
+				call_weight
+			),
+			Error::<Test>::Timelocked,
+		);
 
+		System::set_block_number(6);
 		assert_ok!(Multisig::as_multi(
 			RuntimeOrigin::signed(3),
-			3,
+			2,
 			vec![1, 2],
-			Some(now()),
-			call,
+			Some(timepoint),
+			call.clone(),
 			call_weight
 		));
-		assert_eq!(Balances::free_balance(6), 15);
-	});
-}
+		assert_eq!(Balances::free_balance(6), 10);
+		assert!(!MultisigTimelocks::<Test>::contains_key(multi, hash));
 
-#[test]
-fn cancel_multisig_works() {
-	new_test_ext().execute_with(|| {
-		let call = call_transfer(6, 15).encode();
-		let hash = blake2_256(&call);
+		// Any signatory may cancel a timelocked operation.
+		let timepoint = now();
 		assert_ok!(Multisig::approve_as_multi(
 			RuntimeOrigin::signed(1),
-			3,
+			2,
 			vec![2, 3],
 			None,
 			hash,
 			Weight::zero()
 		));
+		assert_noop!(
+			Multisig::cancel_as_multi(RuntimeOrigin::signed(3), 2, vec![1, 2], timepoint, hash),
+			Error::<Test>::NotOwner,
+		);
 		assert_ok!(Multisig::approve_as_multi(
 			RuntimeOrigin::signed(2),
-			3,
+			2,
 			vec![1, 3],
-			Some(now()),
+			Some(timepoint),
 			hash,
 			Weight::zero()
 		));
-		assert_noop!(
-			Multisig::cancel_as_multi(RuntimeOrigin::signed(2), 3, vec![1, 3], now(), hash),
-			Error::<Test>::NotOwner,
-		);
-		assert_ok!(Multisig::cancel_as_multi(RuntimeOrigin::signed(1), 3, vec![2, 3], now(), hash),);
+		assert_ok!(Multisig::cancel_as_multi(
+			RuntimeOrigin::signed(3),
+			2,
+			vec![1, 2],
+			timepoint,
+			hash
+		));
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		assert!(!MultisigTimelocks::<Test>::contains_key(multi, hash));
+		assert_eq!(Balances::free_balance(1), 10);
 	});
 }
 
@@ -691,3 +1773,106 @@ fn multisig_handles_no_preimage_after_all_approve() {
 		assert_eq!(Balances::free_balance(6), 15);
 	});
 }
+
+#[test]
+fn create_multisig_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_eq!(Multisig::derive_multi_account_id(vec![3, 1, 2, 1], 2), multi);
+
+		// The signatories may be unsorted and need not include the sender.
+		assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(4), 2, vec![3, 1, 2], 5));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigCreated {
+				creator: 4,
+				multisig: multi,
+				threshold: 2,
+				endowment: 5,
+			}
+			.into(),
+		);
+		// The deposit for the registration is held from the sender.
+		assert_eq!(CreatedMultisigs::<Test>::get(multi), Some((4, 1)));
+		assert_eq!(System::providers(&multi), 2);
+		assert_eq!(Balances::free_balance(multi), 5);
+		assert_eq!(Balances::free_balance(4), 4);
+		assert_eq!(Balances::total_balance_on_hold(&4), 1);
+
+		assert_noop!(
+			Multisig::create_multisig(RuntimeOrigin::signed(4), 2, vec![1, 2, 3], 0),
+			Error::<Test>::AlreadyCreated,
+		);
+
+		// Without an endowment, the multisig account still exists.
+		let multi = Multisig::multi_account_id(&[1, 2][..], 2);
+		assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), 2, vec![2, 1], 0));
+		assert!(System::account_exists(&multi));
+		assert_eq!(Balances::free_balance(1), 9);
+	});
+}
+
+#[test]
+fn destroy_multisig_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2][..], 2);
+		assert_noop!(
+			Multisig::destroy_multisig(RuntimeOrigin::signed(multi)),
+			Error::<Test>::NotCreated,
+		);
+		assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(3), 2, vec![2, 1], 0));
+		assert!(System::account_exists(&multi));
+
+		// Only the multisig account itself can destroy its registration.
+		assert_noop!(
+			Multisig::destroy_multisig(RuntimeOrigin::signed(3)),
+			Error::<Test>::NotCreated,
+		);
+		assert_ok!(Multisig::destroy_multisig(RuntimeOrigin::signed(multi)));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigDestroyed { multisig: multi, creator: 3, deposit: 1 }
+				.into(),
+		);
+		assert!(!CreatedMultisigs::<Test>::contains_key(multi));
+		assert!(!System::account_exists(&multi));
+		assert_eq!(Balances::free_balance(3), 10);
+		assert_eq!(Balances::total_balance_on_hold(&3), 0);
+
+		// It can be registered again.
+		assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(3), 2, vec![2, 1], 0));
+	});
+}
+
+#[test]
+fn create_multisig_validates_signatories_and_threshold() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Multisig::create_multisig(RuntimeOrigin::signed(1), 1, vec![2, 2], 0),
+			Error::<Test>::TooFewSignatories,
+		);
+		assert_noop!(
+			Multisig::create_multisig(RuntimeOrigin::signed(1), 0, vec![1, 2], 0),
+			Error::<Test>::InvalidThreshold,
+		);
+		assert_noop!(
+			Multisig::create_multisig(RuntimeOrigin::signed(1), 3, vec![1, 2], 0),
+			Error::<Test>::InvalidThreshold,
+		);
+		assert_noop!(
+			Multisig::create_multisig(RuntimeOrigin::signed(1), 2, (0..5).collect(), 0),
+			Error::<Test>::TooManySignatories,
+		);
+	});
+}
+
+#[test]
+fn genesis_creates_multisigs() {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_multisig::GenesisConfig::<Test> { multisigs: vec![(vec![3, 1, 2], 2)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_eq!(CreatedMultisigs::<Test>::get(multi), Some((multi, 0)));
+		assert!(System::account_exists(&multi));
+	});
+}
@@ -683,96 +683,40 @@ pub mod pallet {
 			maybe_proportion: Option<Perquintill>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			Self::do_thaw_private(who, index, maybe_proportion)
+		}
 
-			// Look for `index`
-			let mut receipt: ReceiptRecordOf<T> =
-				Receipts::<T>::get(index).ok_or(Error::<T>::UnknownReceipt)?;
-			// If found, check the owner is `who`.
-			let (owner, mut on_hold) = receipt.owner.ok_or(Error::<T>::AlreadyCommunal)?;
-			ensure!(owner == who, Error::<T>::NotOwner);
-
-			let now = frame_system::Pallet::<T>::block_number();
-			ensure!(now >= receipt.expiry, Error::<T>::NotExpired);
-
-			let mut summary: SummaryRecordOf<T> = Summary::<T>::get();
-
-			let proportion = if let Some(proportion) = maybe_proportion {
-				ensure!(proportion <= receipt.proportion, Error::<T>::PortionTooBig);
-				let remaining = receipt.proportion.saturating_sub(proportion);
-				ensure!(
-					remaining.is_zero() || remaining >= T::MinReceipt::get(),
-					Error::<T>::MakesDust
-				);
-				proportion
-			} else {
-				receipt.proportion
-			};
-
-			let (throttle, throttle_period) = T::ThawThrottle::get();
-			if now.saturating_sub(summary.last_period) >= throttle_period {
-				summary.thawed = Zero::zero();
-				summary.last_period = now;
-			}
-			summary.thawed.saturating_accrue(proportion);
-			ensure!(summary.thawed <= throttle, Error::<T>::Throttled);
-
-			// Multiply the proportion it is by the total issued.
-			let our_account = Self::account_id();
-			let effective_issuance = Self::issuance_with(&our_account, &summary).effective;
-			//			let amount = proportion.mul_ceil(effective_issuance);
-			let amount = proportion * effective_issuance;
-
-			receipt.proportion.saturating_reduce(proportion);
-			summary.proportion_owed.saturating_reduce(proportion);
-
-			let dropped = receipt.proportion.is_zero();
-
-			if amount > on_hold {
-				T::Currency::release(&HoldReason::NftReceipt.into(), &who, on_hold, Exact)?;
-				let deficit = amount - on_hold;
-				// Try to transfer deficit from pot to receipt owner.
-				summary.receipts_on_hold.saturating_reduce(on_hold);
-				on_hold = Zero::zero();
-				T::Currency::transfer(&our_account, &who, deficit, Expendable)
-					.map_err(|_| Error::<T>::Unfunded)?;
-			} else {
-				on_hold.saturating_reduce(amount);
-				summary.receipts_on_hold.saturating_reduce(amount);
-				if dropped && !on_hold.is_zero() {
-					// Reclaim any remainder:
-					// Transfer excess of `on_hold` to the pot if we have now fully compensated for
-					// the receipt.
-					T::Currency::transfer_on_hold(
-						&HoldReason::NftReceipt.into(),
-						&who,
-						&our_account,
-						on_hold,
-						Exact,
-						Free,
-						Polite,
-					)
-					.map(|_| ())
-					// We ignore this error as it just means the amount we're trying to deposit is
-					// dust and the beneficiary account doesn't exist.
-					.or_else(
-						|e| if e == TokenError::CannotCreate.into() { Ok(()) } else { Err(e) },
-					)?;
-					summary.receipts_on_hold.saturating_reduce(on_hold);
-				}
-				T::Currency::release(&HoldReason::NftReceipt.into(), &who, amount, Exact)?;
-			}
-
-			if dropped {
-				Receipts::<T>::remove(index);
-			} else {
-				receipt.owner = Some((owner, on_hold));
-				Receipts::<T>::insert(index, &receipt);
-			}
-			Summary::<T>::put(&summary);
-
-			Self::deposit_event(Event::Thawed { index, who, amount, proportion, dropped });
+		/// Reduce an outstanding receipt by the given `amount`, placing the funds into the account
+		/// of the owner.
+		///
+		/// This is a convenience over [`Pallet::thaw_private`]: the proportion of the receipt to
+		/// be thawed is computed from `amount` and the current effective total issuance, rounding
+		/// down.
+		///
+		/// - `origin`: Must be Signed and the account must be the owner of the receipt `index`.
+		/// - `index`: The index of the receipt.
+		/// - `amount`: The amount of funds to be thawed. Must not exceed the value of the receipt
+		///   and must leave either nothing or at least `MinReceipt` of it behind.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::thaw_private())]
+		pub fn thaw_private_amount(
+			origin: OriginFor<T>,
+			#[pallet::compact] index: ReceiptIndex,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-			Ok(())
+			let summary: SummaryRecordOf<T> = Summary::<T>::get();
+			let effective_issuance = Self::issuance_with(&Self::account_id(), &summary).effective;
+			let proportion = Perquintill::from_rational_with_rounding(
+				amount,
+				effective_issuance,
+				Rounding::Down,
+			)
+			.map_err(|_| Error::<T>::PortionTooBig)?;
+			ensure!(!proportion.is_zero(), Error::<T>::AmountTooSmall);
+
+			Self::do_thaw_private(who, index, Some(proportion))
 		}
 
 		/// Reduce or remove an outstanding receipt, placing the according proportion of funds into
@@ -974,6 +918,104 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Reduce or remove the private receipt `index` owned by `who` by `maybe_proportion`, or
+		/// entirely if `None`, placing the according funds into the account of `who`.
+		pub(crate) fn do_thaw_private(
+			who: T::AccountId,
+			index: ReceiptIndex,
+			maybe_proportion: Option<Perquintill>,
+		) -> DispatchResult {
+			// Look for `index`
+			let mut receipt: ReceiptRecordOf<T> =
+				Receipts::<T>::get(index).ok_or(Error::<T>::UnknownReceipt)?;
+			// If found, check the owner is `who`.
+			let (owner, mut on_hold) = receipt.owner.ok_or(Error::<T>::AlreadyCommunal)?;
+			ensure!(owner == who, Error::<T>::NotOwner);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= receipt.expiry, Error::<T>::NotExpired);
+
+			let mut summary: SummaryRecordOf<T> = Summary::<T>::get();
+
+			let proportion = if let Some(proportion) = maybe_proportion {
+				ensure!(proportion <= receipt.proportion, Error::<T>::PortionTooBig);
+				let remaining = receipt.proportion.saturating_sub(proportion);
+				ensure!(
+					remaining.is_zero() || remaining >= T::MinReceipt::get(),
+					Error::<T>::MakesDust
+				);
+				proportion
+			} else {
+				receipt.proportion
+			};
+
+			let (throttle, throttle_period) = T::ThawThrottle::get();
+			if now.saturating_sub(summary.last_period) >= throttle_period {
+				summary.thawed = Zero::zero();
+				summary.last_period = now;
+			}
+			summary.thawed.saturating_accrue(proportion);
+			ensure!(summary.thawed <= throttle, Error::<T>::Throttled);
+
+			// Multiply the proportion it is by the total issued.
+			let our_account = Self::account_id();
+			let effective_issuance = Self::issuance_with(&our_account, &summary).effective;
+			//			let amount = proportion.mul_ceil(effective_issuance);
+			let amount = proportion * effective_issuance;
+
+			receipt.proportion.saturating_reduce(proportion);
+			summary.proportion_owed.saturating_reduce(proportion);
+
+			let dropped = receipt.proportion.is_zero();
+
+			if amount > on_hold {
+				T::Currency::release(&HoldReason::NftReceipt.into(), &who, on_hold, Exact)?;
+				let deficit = amount - on_hold;
+				// Try to transfer deficit from pot to receipt owner.
+				summary.receipts_on_hold.saturating_reduce(on_hold);
+				on_hold = Zero::zero();
+				T::Currency::transfer(&our_account, &who, deficit, Expendable)
+					.map_err(|_| Error::<T>::Unfunded)?;
+			} else {
+				on_hold.saturating_reduce(amount);
+				summary.receipts_on_hold.saturating_reduce(amount);
+				if dropped && !on_hold.is_zero() {
+					// Reclaim any remainder:
+					// Transfer excess of `on_hold` to the pot if we have now fully compensated for
+					// the receipt.
+					T::Currency::transfer_on_hold(
+						&HoldReason::NftReceipt.into(),
+						&who,
+						&our_account,
+						on_hold,
+						Exact,
+						Free,
+						Polite,
+					)
+					.map(|_| ())
+					// We ignore this error as it just means the amount we're trying to deposit is
+					// dust and the beneficiary account doesn't exist.
+					.or_else(
+						|e| if e == TokenError::CannotCreate.into() { Ok(()) } else { Err(e) },
+					)?;
+					summary.receipts_on_hold.saturating_reduce(on_hold);
+				}
+				T::Currency::release(&HoldReason::NftReceipt.into(), &who, amount, Exact)?;
+			}
+
+			if dropped {
+				Receipts::<T>::remove(index);
+			} else {
+				receipt.owner = Some((owner, on_hold));
+				Receipts::<T>::insert(index, &receipt);
+			}
+			Summary::<T>::put(&summary);
+
+			Self::deposit_event(Event::Thawed { index, who, amount, proportion, dropped });
+
+			Ok(())
+		}
+
 		/// The account ID of the reserves.
 		///
 		/// This actually does computation. If you need to keep using it, then make sure you cache
@@ -399,6 +399,37 @@ fn partial_thaw_works() {
 	});
 }
 
+#[test]
+fn partial_thaw_by_amount_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		assert_ok!(Nis::place_bid(signed(1), 80, 1));
+		enlarge(80, 1);
+		assert_eq!(holdings(), 80);
+
+		run_to_block(4);
+		assert_noop!(Nis::thaw_private_amount(signed(1), 0, 0), Error::<Test>::AmountTooSmall);
+		assert_noop!(Nis::thaw_private_amount(signed(1), 0, 81), Error::<Test>::PortionTooBig);
+		assert_noop!(Nis::thaw_private_amount(signed(1), 0, 79), Error::<Test>::MakesDust);
+		assert_noop!(Nis::thaw_private_amount(signed(2), 0, 20), Error::<Test>::NotOwner);
+		assert_ok!(Nis::thaw_private_amount(signed(1), 0, 20));
+
+		assert_eq!(
+			Nis::typed_attribute::<_, Perquintill>(&0, b"proportion"),
+			Some(Perquintill::from_percent(15)),
+		);
+		assert_eq!(Nis::issuance().effective, 400);
+		assert_eq!(Balances::free_balance(1), 40);
+		assert_eq!(holdings(), 60);
+
+		// The rest of the receipt can be thawed by amount as well.
+		assert_ok!(Nis::thaw_private_amount(signed(1), 0, 60));
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(pot(), 0);
+		assert_eq!(Receipts::<Test>::get(0), None);
+	});
+}
+
 #[test]
 fn thaw_respects_transfers() {
 	new_test_ext().execute_with(|| {
@@ -3673,7 +3673,13 @@ mod withdraw_unbonded {
 				);
 				assert_eq!(
 					balances_events_since_last_call(),
-					vec![BEvent::Burned { who: default_bonded_account(), amount: 300 }]
+					vec![
+						BEvent::IssuanceDecreased {
+							amount: 300,
+							reason: pallet_balances::IssuanceChangeReason::Burned
+						},
+						BEvent::Burned { who: default_bonded_account(), amount: 300 }
+					]
 				);
 
 				// When
@@ -3789,7 +3795,13 @@ mod withdraw_unbonded {
 				);
 				assert_eq!(
 					balances_events_since_last_call(),
-					vec![BEvent::Burned { who: default_bonded_account(), amount: 300 },]
+					vec![
+						BEvent::IssuanceDecreased {
+							amount: 300,
+							reason: pallet_balances::IssuanceChangeReason::Burned
+						},
+						BEvent::Burned { who: default_bonded_account(), amount: 300 },
+					]
 				);
 
 				CurrentEra::set(StakingMock::bonding_duration());
@@ -29,7 +29,7 @@ mod tests;
 use core::marker::PhantomData;
 
 use codec::Encode;
-use frame_support::weights::Weight;
+use frame_support::{traits::Get, weights::Weight};
 use sp_runtime::{traits::Hash, Perbill};
 use sp_staking::{
 	offence::{Kind, Offence, OffenceDetails, OffenceError, OnOffenceHandler, ReportOffence},
@@ -128,7 +128,8 @@ where
 
 		let slash_perbill: Vec<_> = (0..concurrent_offenders.len()).map(|_| new_fraction).collect();
 
-		T::OnOffenceHandler::on_offence(
+		T::OnOffenceHandler::on_offence_of_kind(
+			&O::ID,
 			&concurrent_offenders,
 			&slash_perbill,
 			offence.session_index(),
@@ -244,3 +245,42 @@ impl<T: Config, O: Offence<T::IdentificationTuple>> ReportIndexStorage<T, O> {
 		);
 	}
 }
+
+/// An [`OnOffenceHandler`] which routes offences of kind `K` to `Handler` and all other offences
+/// to `Fallback`.
+///
+/// Several offence kinds can be routed by nesting routers in `Fallback`, e.g.
+/// `OffenceKindRouter<DisputesKind, Session, OffenceKindRouter<BeefyKind, (), Staking>>`.
+///
+/// Offences reported without a kind (through [`OnOffenceHandler::on_offence`]) can't be routed
+/// and are always passed on to `Fallback`.
+pub struct OffenceKindRouter<K, Handler, Fallback>(PhantomData<(K, Handler, Fallback)>);
+
+impl<Reporter, Offender, Res, K, Handler, Fallback> OnOffenceHandler<Reporter, Offender, Res>
+	for OffenceKindRouter<K, Handler, Fallback>
+where
+	K: Get<Kind>,
+	Handler: OnOffenceHandler<Reporter, Offender, Res>,
+	Fallback: OnOffenceHandler<Reporter, Offender, Res>,
+{
+	fn on_offence(
+		offenders: &[OffenceDetails<Reporter, Offender>],
+		slash_fraction: &[Perbill],
+		session: SessionIndex,
+	) -> Res {
+		Fallback::on_offence(offenders, slash_fraction, session)
+	}
+
+	fn on_offence_of_kind(
+		kind: &Kind,
+		offenders: &[OffenceDetails<Reporter, Offender>],
+		slash_fraction: &[Perbill],
+		session: SessionIndex,
+	) -> Res {
+		if *kind == K::get() {
+			Handler::on_offence_of_kind(kind, offenders, slash_fraction, session)
+		} else {
+			Fallback::on_offence_of_kind(kind, offenders, slash_fraction, session)
+		}
+	}
+}
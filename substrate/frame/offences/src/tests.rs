@@ -245,3 +245,38 @@ fn should_properly_count_offences() {
 		);
 	});
 }
+
+#[test]
+fn offence_kind_router_works() {
+	frame_support::parameter_types! {
+		pub const RoutedKind: Kind = KIND;
+	}
+	type Router = OffenceKindRouter<RoutedKind, crate::mock::OnOffenceHandler, ()>;
+
+	new_test_ext().execute_with(|| {
+		let offenders = vec![OffenceDetails { offender: 5u64, reporters: vec![] }];
+		let slash_fraction = vec![Perbill::from_percent(25)];
+
+		// offences of other kinds are passed on to the fallback.
+		<Router as OnOffenceHandler<u64, u64, Weight>>::on_offence_of_kind(
+			b"other_report_123",
+			&offenders,
+			&slash_fraction,
+			1,
+		);
+		with_on_offence_fractions(|f| assert!(f.is_empty()));
+
+		// offences reported without a kind can't be routed either.
+		<Router as OnOffenceHandler<u64, u64, Weight>>::on_offence(&offenders, &slash_fraction, 1);
+		with_on_offence_fractions(|f| assert!(f.is_empty()));
+
+		// offences of the routed kind reach the handler.
+		<Router as OnOffenceHandler<u64, u64, Weight>>::on_offence_of_kind(
+			&KIND,
+			&offenders,
+			&slash_fraction,
+			1,
+		);
+		with_on_offence_fractions(|f| assert_eq!(f.clone(), slash_fraction));
+	});
+}
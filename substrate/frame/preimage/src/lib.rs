@@ -25,6 +25,11 @@
 //! The Preimage pallet allows for the users and the runtime to store the preimage
 //! of a hash on chain. This can be used by other pallets for storing and managing
 //! large byte-blobs.
+//!
+//! Users noting a preimage which was already noted by someone else, but not requested, join as
+//! co-depositors: the deposit for the preimage is shared among all of its depositors, and the
+//! shares of the remaining depositors grow again as parties unnote it. The preimage is only
+//! cleared once its last depositor unnotes it.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -38,7 +43,7 @@ mod tests;
 pub mod weights;
 
 use sp_runtime::{
-	traits::{BadOrigin, Hash, Saturating},
+	traits::{BadOrigin, Convert, Hash, Saturating},
 	Perbill,
 };
 use sp_std::{borrow::Cow, prelude::*};
@@ -89,6 +94,8 @@ pub enum RequestStatus<AccountId, Ticket> {
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 type TicketOf<T> = <T as Config>::Consideration;
+type CoDepositorsOf<T> =
+	BoundedVec<(<T as frame_system::Config>::AccountId, TicketOf<T>), ConstU32<MAX_CO_DEPOSITORS>>;
 
 /// Maximum size of preimage we can store is 4mb.
 const MAX_SIZE: u32 = 4 * 1024 * 1024;
@@ -96,6 +103,8 @@ const MAX_SIZE: u32 = 4 * 1024 * 1024;
 ///
 /// Exists only for benchmarking purposes.
 pub const MAX_HASH_UPGRADE_BULK_COUNT: u32 = 1024;
+/// Maximum number of accounts that can share the deposit of a preimage with its first depositor.
+pub const MAX_CO_DEPOSITORS: u32 = 8;
 
 #[frame_support::pallet]
 #[allow(deprecated)]
@@ -123,8 +132,13 @@ pub mod pallet {
 
 		/// A means of providing some cost while data is stored on-chain.
 		///
-		/// Should never return a `None`, implying no cost for a non-empty preimage.
-		type Consideration: Consideration<Self::AccountId, Footprint>;
+		/// The cost is the deposit for a preimage as priced by [`Config::DepositPrice`], or the
+		/// share of a depositor in it. Should never return a `None`, implying no cost for a
+		/// non-empty preimage.
+		type Consideration: Consideration<Self::AccountId, BalanceOf<Self>>;
+
+		/// Converts the footprint of a preimage into the deposit for it.
+		type DepositPrice: Convert<Footprint, BalanceOf<Self>>;
 	}
 
 	#[pallet::pallet]
@@ -140,6 +154,10 @@ pub mod pallet {
 		Requested { hash: T::Hash },
 		/// A preimage has ben cleared.
 		Cleared { hash: T::Hash },
+		/// An account joined the depositors of a preimage, sharing its deposit.
+		DepositorAdded { hash: T::Hash, who: T::AccountId },
+		/// An account left the depositors of a preimage, which remains noted by the others.
+		DepositorRemoved { hash: T::Hash, who: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -162,6 +180,8 @@ pub mod pallet {
 		TooFew,
 		/// No ticket with a cost was returned by [`Config::Consideration`] to store the preimage.
 		NoCost,
+		/// The deposit of the preimage is already shared by the maximum number of accounts.
+		TooManyDepositors,
 	}
 
 	/// A reason for this pallet placing a hold on funds.
@@ -182,6 +202,12 @@ pub mod pallet {
 	pub(super) type RequestStatusFor<T: Config> =
 		StorageMap<_, Identity, T::Hash, RequestStatus<T::AccountId, TicketOf<T>>>;
 
+	/// The accounts sharing the deposit of a user-noted preimage with the depositor recorded in
+	/// its request status, in the order they joined.
+	#[pallet::storage]
+	pub(super) type CoDepositorsFor<T: Config> =
+		StorageMap<_, Identity, T::Hash, CoDepositorsOf<T>, ValueQuery>;
+
 	#[pallet::storage]
 	pub(super) type PreimageFor<T: Config> =
 		StorageMap<_, Identity, (T::Hash, u32), BoundedVec<u8, ConstU32<MAX_SIZE>>>;
@@ -192,8 +218,14 @@ pub mod pallet {
 		///
 		/// If the preimage was previously requested, no fees or deposits are taken for providing
 		/// the preimage. Otherwise, a deposit is taken proportional to the size of the preimage.
+		///
+		/// If the preimage was already noted by other users, the sender joins them as a
+		/// co-depositor and the deposit is shared among all of them.
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::note_preimage(bytes.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::note_preimage(bytes.len() as u32)
+				.saturating_add(Pallet::<T>::share_deposit_weight())
+		)]
 		pub fn note_preimage(origin: OriginFor<T>, bytes: Vec<u8>) -> DispatchResultWithPostInfo {
 			// We accept a signed origin which will pay a deposit, or a root origin where a deposit
 			// is not taken.
@@ -210,9 +242,15 @@ pub mod pallet {
 		///
 		/// If `len` is provided, then it will be a much cheaper operation.
 		///
+		/// If the deposit of the preimage is shared, a signed sender only leaves its depositors
+		/// and gets its share back, while the preimage is kept by the others.
+		///
 		/// - `hash`: The hash of the preimage to be removed from the store.
 		/// - `len`: The length of the preimage of `hash`.
 		#[pallet::call_index(1)]
+		#[pallet::weight(
+			T::WeightInfo::unnote_preimage().saturating_add(Pallet::<T>::share_deposit_weight())
+		)]
 		pub fn unnote_preimage(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
 			let maybe_sender = Self::ensure_signed_or_manager(origin)?;
 			Self::do_unnote_preimage(&hash, maybe_sender)
@@ -272,9 +310,7 @@ impl<T: Config> Pallet<T> {
 				// unreserve deposit
 				T::Currency::unreserve(&who, amount);
 				// take consideration
-				let Ok(Some(ticket)) =
-					T::Consideration::new(&who, Footprint::from_parts(1, len as usize))
-				else {
+				let Ok(Some(ticket)) = T::Consideration::new(&who, Self::deposit_for(len)) else {
 					defensive!("None ticket or inability to take deposit after unreserved");
 					return true
 				};
@@ -286,8 +322,7 @@ impl<T: Config> Pallet<T> {
 					T::Currency::unreserve(&who, deposit);
 					// take consideration
 					if let Some(len) = maybe_len {
-						let Ok(Some(ticket)) =
-							T::Consideration::new(&who, Footprint::from_parts(1, len as usize))
+						let Ok(Some(ticket)) = T::Consideration::new(&who, Self::deposit_for(len))
 						else {
 							defensive!("None ticket or inability to take deposit after unreserved");
 							return true
@@ -338,8 +373,10 @@ impl<T: Config> Pallet<T> {
 		let status = match (RequestStatusFor::<T>::get(hash), maybe_depositor) {
 			(Some(RequestStatus::Requested { maybe_ticket, count, .. }), _) =>
 				RequestStatus::Requested { maybe_ticket, count, maybe_len: Some(len) },
-			(Some(RequestStatus::Unrequested { .. }), Some(_)) =>
-				return Err(Error::<T>::AlreadyNoted.into()),
+			(Some(RequestStatus::Unrequested { ticket, len }), Some(depositor)) => {
+				Self::add_depositor(&hash, len, ticket, depositor)?;
+				return Ok((false, hash))
+			},
 			(Some(RequestStatus::Unrequested { ticket, len }), None) => RequestStatus::Requested {
 				maybe_ticket: Some(ticket),
 				count: 1,
@@ -348,9 +385,8 @@ impl<T: Config> Pallet<T> {
 			(None, None) =>
 				RequestStatus::Requested { maybe_ticket: None, count: 1, maybe_len: Some(len) },
 			(None, Some(depositor)) => {
-				let ticket =
-					T::Consideration::new(depositor, Footprint::from_parts(1, len as usize))?
-						.ok_or(Error::<T>::NoCost)?;
+				let ticket = T::Consideration::new(depositor, Self::deposit_for(len))?
+					.ok_or(Error::<T>::NoCost)?;
 				RequestStatus::Unrequested { ticket: (depositor.clone(), ticket), len }
 			},
 		};
@@ -400,8 +436,24 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		Self::do_ensure_updated(&hash);
 		match RequestStatusFor::<T>::get(hash).ok_or(Error::<T>::NotNoted)? {
-			RequestStatus::Requested { maybe_ticket: Some((owner, ticket)), count, maybe_len } => {
-				ensure!(maybe_check_owner.map_or(true, |c| c == owner), Error::<T>::NotAuthorized);
+			RequestStatus::Requested { maybe_ticket: Some(mut depositor), count, maybe_len } => {
+				if let Some(who) = maybe_check_owner {
+					let len = maybe_len.unwrap_or_default();
+					if Self::remove_depositor(hash, len, &mut depositor, &who)? {
+						RequestStatusFor::<T>::insert(
+							hash,
+							RequestStatus::Requested {
+								maybe_ticket: Some(depositor),
+								count,
+								maybe_len,
+							},
+						);
+						return Ok(())
+					}
+				} else {
+					Self::drop_co_depositors(hash);
+				}
+				let (owner, ticket) = depositor;
 				let _ = ticket.drop(&owner);
 				RequestStatusFor::<T>::insert(
 					hash,
@@ -413,8 +465,19 @@ impl<T: Config> Pallet<T> {
 				ensure!(maybe_check_owner.is_none(), Error::<T>::NotAuthorized);
 				Self::do_unrequest_preimage(hash)
 			},
-			RequestStatus::Unrequested { ticket: (owner, ticket), len } => {
-				ensure!(maybe_check_owner.map_or(true, |c| c == owner), Error::<T>::NotAuthorized);
+			RequestStatus::Unrequested { ticket: mut depositor, len } => {
+				if let Some(who) = maybe_check_owner {
+					if Self::remove_depositor(hash, len, &mut depositor, &who)? {
+						RequestStatusFor::<T>::insert(
+							hash,
+							RequestStatus::Unrequested { ticket: depositor, len },
+						);
+						return Ok(())
+					}
+				} else {
+					Self::drop_co_depositors(hash);
+				}
+				let (owner, ticket) = depositor;
 				let _ = ticket.drop(&owner);
 				RequestStatusFor::<T>::remove(hash);
 
@@ -461,6 +524,117 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// The deposit for a preimage of `len` bytes.
+	fn deposit_for(len: u32) -> BalanceOf<T> {
+		T::DepositPrice::convert(Footprint::from_parts(1, len as usize))
+	}
+
+	/// The shares of the first depositor and of each co-depositor in the deposit for a preimage
+	/// of `len` bytes noted by `parties` depositors.
+	///
+	/// The deposit is split evenly, the first depositor also covers the remainder.
+	fn deposit_shares(len: u32, parties: u32) -> (BalanceOf<T>, BalanceOf<T>) {
+		let deposit = Self::deposit_for(len);
+		let parties = parties.max(1);
+		let share = deposit / parties.into();
+		let first = deposit.saturating_sub(share.saturating_mul((parties - 1).into()));
+		(first, share)
+	}
+
+	/// The worst case weight of updating the tickets of all depositors of a preimage.
+	fn share_deposit_weight() -> Weight {
+		// Reads and writes `CoDepositorsFor` and the holds and accounts of all depositors.
+		let depositors = MAX_CO_DEPOSITORS as u64 + 1;
+		T::DbWeight::get().reads_writes(1 + 2 * depositors, 1 + 2 * depositors)
+	}
+
+	/// Update the tickets of `depositor` and `co_depositors` of a preimage of `len` bytes to
+	/// their shares of its deposit.
+	fn share_deposit(
+		len: u32,
+		depositor: &mut (T::AccountId, TicketOf<T>),
+		co_depositors: &mut CoDepositorsOf<T>,
+	) -> DispatchResult {
+		let (first, share) = Self::deposit_shares(len, co_depositors.len() as u32 + 1);
+		depositor.1 = depositor.1.clone().update(&depositor.0, first)?.ok_or(Error::<T>::NoCost)?;
+		for (who, ticket) in co_depositors.iter_mut() {
+			*ticket = ticket.clone().update(who, share)?.ok_or(Error::<T>::NoCost)?;
+		}
+		Ok(())
+	}
+
+	/// Add `who` to the co-depositors of the unrequested preimage of `hash` with `len` bytes,
+	/// noted by `depositor`.
+	fn add_depositor(
+		hash: &T::Hash,
+		len: u32,
+		mut depositor: (T::AccountId, TicketOf<T>),
+		who: &T::AccountId,
+	) -> DispatchResult {
+		let mut co_depositors = CoDepositorsFor::<T>::get(hash);
+		ensure!(
+			depositor.0 != *who && co_depositors.iter().all(|(c, _)| c != who),
+			Error::<T>::AlreadyNoted
+		);
+		let (_, share) = Self::deposit_shares(len, co_depositors.len() as u32 + 2);
+		let ticket = T::Consideration::new(who, share)?.ok_or(Error::<T>::NoCost)?;
+		co_depositors
+			.try_push((who.clone(), ticket))
+			.map_err(|_| Error::<T>::TooManyDepositors)?;
+		Self::share_deposit(len, &mut depositor, &mut co_depositors)?;
+
+		RequestStatusFor::<T>::insert(hash, RequestStatus::Unrequested { ticket: depositor, len });
+		CoDepositorsFor::<T>::insert(hash, co_depositors);
+		Self::deposit_event(Event::DepositorAdded { hash: *hash, who: who.clone() });
+		Ok(())
+	}
+
+	/// Remove `who` from the depositors of the preimage of `hash` with `len` bytes, returning its
+	/// share of the deposit.
+	///
+	/// If a co-depositor remains, the first of them takes the place of `depositor` when `who` is
+	/// `depositor` and `true` is returned. Returns `false` if `who` is the only depositor, leaving
+	/// its ticket untouched.
+	fn remove_depositor(
+		hash: &T::Hash,
+		len: u32,
+		depositor: &mut (T::AccountId, TicketOf<T>),
+		who: &T::AccountId,
+	) -> Result<bool, DispatchError> {
+		let mut co_depositors = CoDepositorsFor::<T>::get(hash);
+		if co_depositors.is_empty() {
+			ensure!(depositor.0 == *who, Error::<T>::NotAuthorized);
+			return Ok(false)
+		}
+
+		let (removed, ticket) = if depositor.0 == *who {
+			core::mem::replace(depositor, co_depositors.remove(0))
+		} else {
+			let index = co_depositors
+				.iter()
+				.position(|(c, _)| c == who)
+				.ok_or(Error::<T>::NotAuthorized)?;
+			co_depositors.remove(index)
+		};
+		let _ = ticket.drop(&removed);
+		Self::share_deposit(len, depositor, &mut co_depositors)?;
+
+		if co_depositors.is_empty() {
+			CoDepositorsFor::<T>::remove(hash);
+		} else {
+			CoDepositorsFor::<T>::insert(hash, co_depositors);
+		}
+		Self::deposit_event(Event::DepositorRemoved { hash: *hash, who: removed });
+		Ok(true)
+	}
+
+	/// Drop the tickets of all co-depositors of the preimage of `hash`.
+	fn drop_co_depositors(hash: &T::Hash) {
+		for (who, ticket) in CoDepositorsFor::<T>::take(hash) {
+			let _ = ticket.drop(&who);
+		}
+	}
+
 	fn insert(hash: &T::Hash, preimage: Cow<[u8]>) -> Result<(), ()> {
 		BoundedSlice::<u8, ConstU32<MAX_SIZE>>::try_from(preimage.as_ref())
 			.map_err(|_| ())
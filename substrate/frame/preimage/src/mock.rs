@@ -22,12 +22,12 @@ use super::*;
 use crate as pallet_preimage;
 use frame_support::{
 	derive_impl, ord_parameter_types, parameter_types,
-	traits::{fungible::HoldConsideration, ConstU64},
+	traits::{fungible::HoldConsideration, ConstU64, LinearStoragePrice},
 };
 use frame_system::EnsureSignedBy;
 use sp_core::H256;
 use sp_runtime::{
-	traits::{BlakeTwo256, Convert},
+	traits::{BlakeTwo256, Identity},
 	BuildStorage,
 };
 
@@ -62,19 +62,13 @@ parameter_types! {
 	pub const PreimageHoldReason: RuntimeHoldReason = RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
 }
 
-pub struct ConvertDeposit;
-impl Convert<Footprint, u64> for ConvertDeposit {
-	fn convert(a: Footprint) -> u64 {
-		a.count * 2 + a.size
-	}
-}
-
 impl Config for Test {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type ManagerOrigin = EnsureSignedBy<One, u64>;
-	type Consideration = HoldConsideration<u64, Balances, PreimageHoldReason, ConvertDeposit>;
+	type Consideration = HoldConsideration<u64, Balances, PreimageHoldReason, Identity, u64>;
+	type DepositPrice = LinearStoragePrice<ConstU64<2>, ConstU64<1>, u64>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
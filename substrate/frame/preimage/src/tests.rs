@@ -153,6 +153,58 @@ fn manager_unnote_user_preimage_works() {
 	});
 }
 
+#[test]
+fn deposit_is_shared_by_co_depositors() {
+	new_test_ext().execute_with(|| {
+		let h = hashed([1; 9]);
+		let held = |who| Balances::balance_on_hold(&PreimageHoldReason::get(), &who);
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(2), vec![1; 9]));
+		assert_eq!(held(2), 11);
+
+		// Later noters join and the deposit is split evenly, the first depositor covers the
+		// remainder.
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(3), vec![1; 9]));
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(4), vec![1; 9]));
+		assert_eq!((held(2), held(3), held(4)), (5, 3, 3));
+		assert_noop!(
+			Preimage::note_preimage(RuntimeOrigin::signed(3), vec![1; 9]),
+			Error::<Test>::AlreadyNoted,
+		);
+		assert_noop!(
+			Preimage::unnote_preimage(RuntimeOrigin::signed(5), h),
+			Error::<Test>::NotAuthorized
+		);
+
+		// The first depositor leaves and the next one takes its place.
+		assert_ok!(Preimage::unnote_preimage(RuntimeOrigin::signed(2), h));
+		assert_eq!((held(2), held(3), held(4)), (0, 6, 5));
+		assert!(Preimage::have_preimage(&h));
+
+		assert_ok!(Preimage::unnote_preimage(RuntimeOrigin::signed(4), h));
+		assert_eq!((held(3), held(4)), (11, 0));
+		assert!(!CoDepositorsFor::<Test>::contains_key(h));
+
+		assert_ok!(Preimage::unnote_preimage(RuntimeOrigin::signed(3), h));
+		assert_eq!(held(3), 0);
+		assert!(!Preimage::have_preimage(&h));
+	});
+}
+
+#[test]
+fn manager_unnote_releases_all_shares() {
+	new_test_ext().execute_with(|| {
+		let h = hashed([1; 9]);
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(2), vec![1; 9]));
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(3), vec![1; 9]));
+
+		assert_ok!(Preimage::unnote_preimage(RuntimeOrigin::signed(1), h));
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &2), 0);
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &3), 0);
+		assert!(!CoDepositorsFor::<Test>::contains_key(h));
+		assert!(!Preimage::have_preimage(&h));
+	});
+}
+
 #[test]
 fn requested_then_noted_preimage_cannot_be_unnoted() {
 	new_test_ext().execute_with(|| {
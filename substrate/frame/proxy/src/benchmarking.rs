@@ -260,5 +260,47 @@ benchmarks! {
 		assert!(!Proxies::<T>::contains_key(&pure_account));
 	}
 
+	create_pure_namespaced {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		T::ProxyType::default(),
+		BlockNumberFor::<T>::zero(),
+		*b"namespac"
+	)
+	verify {
+		let pure_account = Pallet::<T>::namespaced_pure_account(&caller, *b"namespac", 0);
+		assert_last_event::<T>(Event::NamespacedPureCreated {
+			pure: pure_account,
+			who: caller,
+			proxy_type: T::ProxyType::default(),
+			namespace: *b"namespac",
+			disambiguation_index: 0,
+		}.into());
+	}
+
+	kill_pure_namespaced {
+		let p in 0 .. (T::MaxProxies::get() - 2);
+
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		Pallet::<T>::create_pure_namespaced(
+			RawOrigin::Signed(whitelisted_caller()).into(),
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::zero(),
+			*b"namespac"
+		)?;
+		let pure_account = Pallet::<T>::namespaced_pure_account(&caller, *b"namespac", 0);
+
+		add_proxies::<T>(p, Some(pure_account.clone()))?;
+		ensure!(Proxies::<T>::contains_key(&pure_account), "pure proxy not created");
+	}: _(RawOrigin::Signed(pure_account.clone()), caller_lookup, *b"namespac", 0)
+	verify {
+		assert!(!Proxies::<T>::contains_key(&pure_account));
+		assert!(PureProxies::<T>::get((&caller, *b"namespac"), 0).is_none());
+	}
+
 	impl_benchmark_test_suite!(Proxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -30,6 +30,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
+pub mod runtime_api;
 mod tests;
 pub mod weights;
 
@@ -57,6 +58,18 @@ type BalanceOf<T> =
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+/// A pure proxy spawned in a namespace, as recorded in [`PureProxies`].
+pub type PureProxyInfoOf<T> = PureProxyInfo<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::ProxyType,
+	BlockNumberFor<T>,
+	BalanceOf<T>,
+>;
+
+/// A namespace in which the disambiguation indices of the pure proxies spawned by an account are
+/// allocated, see [`Pallet::create_pure_namespaced`].
+pub type PureNamespace = [u8; 8];
+
 /// The parameters under which a particular account has a proxy relationship with some other
 /// account.
 #[derive(
@@ -93,6 +106,21 @@ pub struct Announcement<AccountId, Hash, BlockNumber> {
 	height: BlockNumber,
 }
 
+/// Details of a pure proxy spawned with [`Pallet::create_pure_namespaced`].
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PureProxyInfo<AccountId, ProxyType, BlockNumber, Balance> {
+	/// The pure account.
+	pub pure: AccountId,
+	/// The type of the proxy that the spawner was registered as over the pure account.
+	pub proxy_type: ProxyType,
+	/// The height of the chain when the pure account was created.
+	pub height: BlockNumber,
+	/// The index of the extrinsic in which the pure account was created.
+	pub ext_index: u32,
+	/// The amount held from the spawner for this record.
+	pub deposit: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::{DispatchResult, *};
@@ -512,6 +540,108 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Spawn a fresh new account that is guaranteed to be otherwise inaccessible, and
+		/// initialize it with a proxy of `proxy_type` for `origin` sender.
+		///
+		/// Unlike `create_pure`, the disambiguation index is allocated sequentially within the
+		/// `namespace` of the sender, and the address of the account only depends on the sender,
+		/// the namespace and the index. The pure proxy is recorded in `PureProxies`, so it can
+		/// be found again without knowing when it was created.
+		///
+		/// Requires a `Signed` origin.
+		///
+		/// - `proxy_type`: The type of the proxy that the sender will be registered as over the
+		/// new account.
+		/// - `delay`: The announcement period required of the initial proxy. Will generally be
+		/// zero.
+		/// - `namespace`: The namespace in which the disambiguation index is allocated.
+		///
+		/// Fails if there are insufficient funds to pay for the deposit, which besides the proxy
+		/// also covers the record.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::create_pure_namespaced())]
+		pub fn create_pure_namespaced(
+			origin: OriginFor<T>,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+			namespace: PureNamespace,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let index = NextPureIndex::<T>::get((&who, namespace));
+			let next_index = index.checked_add(1).ok_or(Error::<T>::TooMany)?;
+			let pure = Self::namespaced_pure_account(&who, namespace, index);
+			ensure!(!Proxies::<T>::contains_key(&pure), Error::<T>::Duplicate);
+
+			let proxy_def =
+				ProxyDefinition { delegate: who.clone(), proxy_type: proxy_type.clone(), delay };
+			let bounded_proxies: BoundedVec<_, T::MaxProxies> =
+				vec![proxy_def].try_into().map_err(|_| Error::<T>::TooMany)?;
+
+			let deposit = T::ProxyDepositBase::get() + T::ProxyDepositFactor::get();
+			let record_deposit = T::ProxyDepositBase::get();
+			T::Currency::reserve(&who, deposit.saturating_add(record_deposit))?;
+
+			Proxies::<T>::insert(&pure, (bounded_proxies, deposit));
+			PureProxies::<T>::insert(
+				(&who, namespace),
+				index,
+				PureProxyInfo {
+					pure: pure.clone(),
+					proxy_type: proxy_type.clone(),
+					height: system::Pallet::<T>::block_number(),
+					ext_index: system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
+					deposit: record_deposit,
+				},
+			);
+			NextPureIndex::<T>::insert((&who, namespace), next_index);
+			Self::deposit_event(Event::NamespacedPureCreated {
+				pure,
+				who,
+				proxy_type,
+				namespace,
+				disambiguation_index: index,
+			});
+
+			Ok(())
+		}
+
+		/// Removes a pure proxy previously spawned with `create_pure_namespaced`.
+		///
+		/// WARNING: **All access to this account will be lost.** Any funds held in it will be
+		/// inaccessible.
+		///
+		/// Requires a `Signed` origin, and the sender account must be the pure proxy recorded for
+		/// the given parameters.
+		///
+		/// - `spawner`: The account that originally called `create_pure_namespaced`.
+		/// - `namespace`: The namespace originally passed to `create_pure_namespaced`.
+		/// - `index`: The disambiguation index allocated to the pure proxy in the namespace.
+		///
+		/// Fails with `NotFound` if no such pure proxy is recorded, and with `NoPermission` if
+		/// the sender is not the recorded pure proxy.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::kill_pure_namespaced(T::MaxProxies::get()))]
+		pub fn kill_pure_namespaced(
+			origin: OriginFor<T>,
+			spawner: AccountIdLookupOf<T>,
+			namespace: PureNamespace,
+			index: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let spawner = T::Lookup::lookup(spawner)?;
+
+			let info =
+				PureProxies::<T>::get((&spawner, namespace), index).ok_or(Error::<T>::NotFound)?;
+			ensure!(info.pure == who, Error::<T>::NoPermission);
+
+			PureProxies::<T>::remove((&spawner, namespace), index);
+			let (_, deposit) = Proxies::<T>::take(&who);
+			T::Currency::unreserve(&spawner, deposit.saturating_add(info.deposit));
+
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -527,6 +657,15 @@ pub mod pallet {
 			proxy_type: T::ProxyType,
 			disambiguation_index: u16,
 		},
+		/// A pure account has been created by new proxy with given disambiguation index allocated
+		/// in the namespace of the spawner.
+		NamespacedPureCreated {
+			pure: T::AccountId,
+			who: T::AccountId,
+			proxy_type: T::ProxyType,
+			namespace: PureNamespace,
+			disambiguation_index: u16,
+		},
 		/// An announcement was placed to make a call in the future.
 		Announced { real: T::AccountId, proxy: T::AccountId, call_hash: CallHashOf<T> },
 		/// A proxy was added.
@@ -596,6 +735,24 @@ pub mod pallet {
 		),
 		ValueQuery,
 	>;
+
+	/// The next disambiguation index allocated to a pure proxy spawned by an account (first key
+	/// element) in a namespace (second key element).
+	#[pallet::storage]
+	pub type NextPureIndex<T: Config> =
+		StorageMap<_, Twox64Concat, (T::AccountId, PureNamespace), u16, ValueQuery>;
+
+	/// The pure proxies spawned by an account (first key element) in a namespace (second key
+	/// element) which were not killed yet, keyed by their disambiguation index.
+	#[pallet::storage]
+	pub type PureProxies<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		(T::AccountId, PureNamespace),
+		Twox64Concat,
+		u16,
+		PureProxyInfoOf<T>,
+	>;
 }
 
 impl<T: Config> Pallet<T> {
@@ -628,6 +785,32 @@ impl<T: Config> Pallet<T> {
 			.expect("infinite length input; no invalid inputs for type; qed")
 	}
 
+	/// Calculate the address of a pure account spawned with [`Pallet::create_pure_namespaced`].
+	///
+	/// Unlike [`Self::pure_account`], the address only depends on the spawner `who`, the
+	/// `namespace` and the disambiguation `index` allocated in it, so it can be re-derived
+	/// without knowing when the account was created.
+	pub fn namespaced_pure_account(
+		who: &T::AccountId,
+		namespace: PureNamespace,
+		index: u16,
+	) -> T::AccountId {
+		let entropy = (b"modlpy/proxyns__", who, namespace, index).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
+	/// The pure proxies spawned by `spawner` in `namespace` which were not killed yet, together
+	/// with their disambiguation indices and ordered by them.
+	pub fn pure_proxies(
+		spawner: &T::AccountId,
+		namespace: PureNamespace,
+	) -> Vec<(u16, PureProxyInfoOf<T>)> {
+		let mut proxies: Vec<_> = PureProxies::<T>::iter_prefix((spawner, namespace)).collect();
+		proxies.sort_by_key(|(index, _)| *index);
+		proxies
+	}
+
 	/// Register a proxy account for the delegator that is able to make calls on its behalf.
 	///
 	/// Parameters:
@@ -0,0 +1,43 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the proxy pallet.
+
+use crate::{PureNamespace, PureProxyInfo};
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait ProxyApi<AccountId, ProxyType, BlockNumber, Balance>
+	where
+		AccountId: Codec,
+		ProxyType: Codec,
+		BlockNumber: Codec,
+		Balance: Codec,
+	{
+		/// Returns the address of the pure proxy spawned by `spawner` with the disambiguation
+		/// `index` in `namespace`.
+		fn namespaced_pure_account(spawner: AccountId, namespace: PureNamespace, index: u16) -> AccountId;
+
+		/// Returns the pure proxies spawned by `spawner` in `namespace` which were not killed
+		/// yet, together with their disambiguation indices and ordered by them.
+		fn pure_proxies(
+			spawner: AccountId,
+			namespace: PureNamespace,
+		) -> Vec<(u16, PureProxyInfo<AccountId, ProxyType, BlockNumber, Balance>)>;
+	}
+}
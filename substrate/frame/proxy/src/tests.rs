@@ -590,3 +590,95 @@ fn pure_works() {
 		);
 	});
 }
+
+#[test]
+fn namespaced_pure_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 11); // An extra one for the ED.
+		assert_ok!(Proxy::create_pure_namespaced(
+			RuntimeOrigin::signed(1),
+			ProxyType::Any,
+			0,
+			*b"treasury"
+		));
+		let anon = Proxy::namespaced_pure_account(&1, *b"treasury", 0);
+		System::assert_last_event(
+			ProxyEvent::NamespacedPureCreated {
+				pure: anon,
+				who: 1,
+				proxy_type: ProxyType::Any,
+				namespace: *b"treasury",
+				disambiguation_index: 0,
+			}
+			.into(),
+		);
+		// the deposit covers the proxy and the record.
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		// the same call allocates the next index, within the same extrinsic as well.
+		assert_ok!(Proxy::create_pure_namespaced(
+			RuntimeOrigin::signed(1),
+			ProxyType::JustTransfer,
+			0,
+			*b"treasury"
+		));
+		System::set_block_number(2);
+		System::set_extrinsic_index(3);
+		assert_ok!(Proxy::create_pure_namespaced(
+			RuntimeOrigin::signed(1),
+			ProxyType::Any,
+			0,
+			*b"payroll_"
+		));
+		let anon2 = Proxy::namespaced_pure_account(&1, *b"treasury", 1);
+		let anon3 = Proxy::namespaced_pure_account(&1, *b"payroll_", 0);
+		assert_ne!(anon, anon2);
+		assert_ne!(anon, anon3);
+
+		let pure = |pure, proxy_type, height, ext_index| PureProxyInfo {
+			pure,
+			proxy_type,
+			height,
+			ext_index,
+			deposit: 1,
+		};
+		assert_eq!(
+			Proxy::pure_proxies(&1, *b"treasury"),
+			vec![
+				(0, pure(anon, ProxyType::Any, 1, 0)),
+				(1, pure(anon2, ProxyType::JustTransfer, 1, 0))
+			]
+		);
+		assert_eq!(
+			Proxy::pure_proxies(&1, *b"payroll_"),
+			vec![(0, pure(anon3, ProxyType::Any, 2, 3))]
+		);
+		assert!(Proxy::pure_proxies(&2, *b"treasury").is_empty());
+
+		// only the recorded pure proxy can kill itself.
+		assert_noop!(
+			Proxy::kill_pure_namespaced(RuntimeOrigin::signed(anon2), 1, *b"treasury", 0),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Proxy::kill_pure_namespaced(RuntimeOrigin::signed(anon), 1, *b"treasury", 2),
+			Error::<Test>::NotFound
+		);
+		assert_ok!(Proxy::kill_pure_namespaced(RuntimeOrigin::signed(anon), 1, *b"treasury", 0));
+		assert_eq!(Balances::reserved_balance(1), 6);
+		assert!(!Proxies::<Test>::contains_key(anon));
+		assert_eq!(
+			Proxy::pure_proxies(&1, *b"treasury"),
+			vec![(1, pure(anon2, ProxyType::JustTransfer, 1, 0))]
+		);
+
+		// indices are not reused.
+		assert_ok!(Proxy::create_pure_namespaced(
+			RuntimeOrigin::signed(1),
+			ProxyType::Any,
+			0,
+			*b"treasury"
+		));
+		assert!(Proxies::<Test>::contains_key(Proxy::namespaced_pure_account(&1, *b"treasury", 2)));
+	});
+}
@@ -61,6 +61,8 @@ pub trait WeightInfo {
 	fn remove_proxies(p: u32, ) -> Weight;
 	fn create_pure(p: u32, ) -> Weight;
 	fn kill_pure(p: u32, ) -> Weight;
+	fn create_pure_namespaced() -> Weight;
+	fn kill_pure_namespaced(p: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_proxy` using the Substrate node and recommended hardware.
@@ -237,6 +239,37 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Proxy::NextPureIndex` (r:1 w:1)
+	/// Proof: `Proxy::NextPureIndex` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::PureProxies` (r:0 w:1)
+	/// Proof: `Proxy::PureProxies` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	fn create_pure_namespaced() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `173`
+		//  Estimated: `4706`
+		// Minimum execution time: 28_417_000 picoseconds.
+		Weight::from_parts(29_365_000, 4706)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Proxy::PureProxies` (r:1 w:1)
+	/// Proof: `Proxy::PureProxies` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 30]`.
+	fn kill_pure_namespaced(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `330 + p * (37 ±0)`
+		//  Estimated: `4706`
+		// Minimum execution time: 25_772_000 picoseconds.
+		Weight::from_parts(26_906_318, 4706)
+			// Standard Error: 1_702
+			.saturating_add(Weight::from_parts(51_954, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -412,4 +445,35 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Proxy::NextPureIndex` (r:1 w:1)
+	/// Proof: `Proxy::NextPureIndex` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::PureProxies` (r:0 w:1)
+	/// Proof: `Proxy::PureProxies` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	fn create_pure_namespaced() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `173`
+		//  Estimated: `4706`
+		// Minimum execution time: 28_417_000 picoseconds.
+		Weight::from_parts(29_365_000, 4706)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Proxy::PureProxies` (r:1 w:1)
+	/// Proof: `Proxy::PureProxies` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 30]`.
+	fn kill_pure_namespaced(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `330 + p * (37 ±0)`
+		//  Estimated: `4706`
+		// Minimum execution time: 25_772_000 picoseconds.
+		Weight::from_parts(26_906_318, 4706)
+			// Standard Error: 1_702
+			.saturating_add(Weight::from_parts(51_954, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
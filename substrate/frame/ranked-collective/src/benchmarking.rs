@@ -181,5 +181,23 @@ benchmarks_instance_pallet! {
 		assert_has_event::<T, I>(Event::MemberExchanged { who, new_who }.into());
 	}
 
+	import_members {
+		let n in 1 .. 100;
+		let r in 0 .. 100;
+		// Spread the ranks evenly across the members.
+		let members = (0..n)
+			.map(|i| {
+				let who = account::<T::AccountId>("member", i, SEED);
+				let rank = r / n + if i < r % n { 1 } else { 0 };
+				(who, rank as Rank)
+			})
+			.collect::<Vec<_>>();
+		let (last, last_rank) = members.last().cloned().unwrap();
+	}: _(SystemOrigin::Root, members)
+	verify {
+		assert_eq!(MemberCount::<T, I>::get(0), n);
+		assert_eq!(Members::<T, I>::get(&last).unwrap().rank, last_rank);
+	}
+
 	impl_benchmark_test_suite!(RankedCollective, crate::tests::ExtBuilder::default().build(), crate::tests::Test);
 }
@@ -724,6 +724,35 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Introduce a number of new members along with their ranks, e.g. to bootstrap the
+		/// collective from an existing roster.
+		///
+		/// - `origin`: Must be `Root`.
+		/// - `members`: Accounts of non-members which will become members, each along with the rank
+		///   it will have.
+		///
+		/// Weight: `O(M + R)` where `M` is the number of members and `R` is the sum of their
+		/// ranks.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::import_members(
+			members.len() as u32,
+			Pallet::<T, I>::total_rank(members),
+		))]
+		pub fn import_members(
+			origin: OriginFor<T>,
+			members: Vec<(T::AccountId, Rank)>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			for (who, rank) in members {
+				Self::do_add_member_to_rank(who.clone(), rank, false)?;
+				Self::deposit_event(Event::MemberAdded { who: who.clone() });
+				if rank > 0 {
+					Self::deposit_event(Event::RankChanged { who, rank });
+				}
+			}
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -739,6 +768,11 @@ pub mod pallet {
 			Members::<T, I>::get(who).ok_or(Error::<T, I>::NotMember.into())
 		}
 
+		/// The sum of the ranks of the given `members`.
+		fn total_rank(members: &[(T::AccountId, Rank)]) -> u32 {
+			members.iter().fold(0u32, |total, (_, rank)| total.saturating_add(*rank as u32))
+		}
+
 		fn rank_to_votes(rank: Rank, min: Rank) -> Result<Votes, DispatchError> {
 			let excess = rank.checked_sub(min).ok_or(Error::<T, I>::RankTooLow)?;
 			Ok(T::VoteWeight::convert(excess))
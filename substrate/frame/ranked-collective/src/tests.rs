@@ -686,3 +686,32 @@ fn max_member_count_works() {
 		assert_eq!(member_count(12), 2);
 	});
 }
+
+#[test]
+fn import_members_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_noop!(
+			Club::import_members(RuntimeOrigin::signed(1), vec![(1, 0)]),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		// The whole import fails if any of the accounts is already a member.
+		assert_noop!(
+			Club::import_members(RuntimeOrigin::root(), vec![(2, 1), (1, 0)]),
+			Error::<Test>::AlreadyMember
+		);
+
+		assert_ok!(Club::import_members(RuntimeOrigin::root(), vec![(2, 2), (3, 0), (4, 1)]));
+		assert_eq!(member_count(0), 4);
+		assert_eq!(member_count(1), 2);
+		assert_eq!(member_count(2), 1);
+		assert_eq!(member_count(3), 0);
+		assert_eq!(Members::<Test>::get(2), Some(MemberRecord { rank: 2 }));
+		assert_eq!(Members::<Test>::get(3), Some(MemberRecord { rank: 0 }));
+		assert_eq!(Members::<Test>::get(4), Some(MemberRecord { rank: 1 }));
+		assert_eq!(IdToIndex::<Test>::get(1, 4), Some(1));
+		assert_eq!(IndexToId::<Test>::get(1, 1), Some(4));
+		System::assert_has_event(Event::MemberAdded { who: 3 }.into());
+		System::assert_last_event(Event::RankChanged { who: 4, rank: 1 }.into());
+	});
+}
@@ -377,5 +377,82 @@ benchmarks! {
 		account_lookup
 	)
 
+	set_recovery_veto {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		insert_recovery_account::<T>(&caller, &caller);
+
+		let veto = RecoveryVeto {
+			threshold: T::MaxFriends::get() as u16,
+			delay_period: 10u32.into(),
+		};
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		Some(veto.clone())
+	) verify {
+		assert_last_event::<T>(
+			Event::RecoveryVetoSet {
+				account: caller,
+				veto: Some(veto),
+			}.into()
+		);
+	}
+
+	veto_recovery {
+		let n in 1 .. T::MaxFriends::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		let lost_account: T::AccountId = account("lost_account", 0, SEED);
+		let lost_account_lookup = T::Lookup::unlookup(lost_account.clone());
+		let rescuer_account: T::AccountId = account("rescuer_account", 0, SEED);
+		let rescuer_account_lookup = T::Lookup::unlookup(rescuer_account.clone());
+
+		// Create friends
+		let friends = add_caller_and_generate_friends::<T>(caller.clone(), n);
+		let bounded_friends: FriendsOf<T> = friends.try_into().unwrap();
+
+		// Get deposit for recovery
+		let total_deposit = get_total_deposit::<T>(&bounded_friends).unwrap();
+
+		// Use a delay period so that the recovery can still be vetoed
+		let delay_period: frame_system::pallet_prelude::BlockNumberFor<T> = 10u32.into();
+		let recovery_config = RecoveryConfig {
+			delay_period,
+			deposit: total_deposit,
+			friends: bounded_friends.clone(),
+			threshold: n as u16,
+		};
+
+		// Create the recovery config storage item
+		<Recoverable<T>>::insert(&lost_account, recovery_config);
+		<RecoveryVetoes<T>>::insert(
+			&lost_account,
+			RecoveryVeto { threshold: n as u16, delay_period: 20u32.into() },
+		);
+
+		// Create an active recovery status
+		let recovery_status = ActiveRecovery {
+			created: frame_system::Pallet::<T>::block_number(),
+			deposit: total_deposit,
+			friends: generate_friends::<T>(n - 1).try_into().unwrap(),
+		};
+
+		// Create the active recovery storage item
+		<ActiveRecoveries<T>>::insert(&lost_account, &rescuer_account, recovery_status);
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		lost_account_lookup,
+		rescuer_account_lookup
+	) verify {
+		assert_last_event::<T>(
+			Event::RecoveryContested {
+				lost_account,
+				rescuer_account,
+				sender: caller,
+			}.into()
+		);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
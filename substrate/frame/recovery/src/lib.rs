@@ -40,6 +40,11 @@
 //! * `delay_period` - The minimum number of blocks after the beginning of the recovery process that
 //!   need to pass before the account can be successfully recovered.
 //!
+//! Optionally, the account owner can allow their friends to veto recoveries with
+//! `set_recovery_veto`. Any friend can then contest an active recovery process during its delay
+//! period, after which the recovery requires a higher threshold or a longer delay period, as set
+//! by the account owner.
+//!
 //! There is a configurable deposit that all users need to pay to create a recovery
 //! configuration. This deposit is composed of a base deposit plus a multiplier for
 //! the number of friends chosen. This deposit is returned in full when the account
@@ -127,6 +132,8 @@
 //! #### For Friends of a Recoverable Account
 //! * `vouch_recovery` - As a `friend` of a recoverable account, vouch for a recovery attempt on the
 //!   account.
+//! * `veto_recovery` - As a `friend` of a recoverable account, contest a recovery attempt on the
+//!   account during its delay period.
 //!
 //! #### For a User Who Successfully Recovered an Account
 //!
@@ -141,6 +148,7 @@
 //!   deposit.
 //! * `remove_recovery` - Remove the recovery configuration from the account, making it
 //!   un-recoverable.
+//! * `set_recovery_veto` - Allow or disallow friends to veto recoveries of the account.
 //!
 //! #### For Super Users
 //!
@@ -209,6 +217,17 @@ pub struct RecoveryConfig<BlockNumber, Balance, Friends> {
 	threshold: u16,
 }
 
+/// The stricter requirements of a recovery which a friend vetoed.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RecoveryVeto<BlockNumber> {
+	/// The number of approving friends needed to recover an account once the recovery is
+	/// contested.
+	pub threshold: u16,
+	/// The minimum number of blocks since the start of the recovery process before the account
+	/// can be recovered once the recovery is contested.
+	pub delay_period: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -292,6 +311,14 @@ pub mod pallet {
 		AccountRecovered { lost_account: T::AccountId, rescuer_account: T::AccountId },
 		/// A recovery process has been removed for an account.
 		RecoveryRemoved { lost_account: T::AccountId },
+		/// The friends of an account have been allowed or disallowed to veto its recovery.
+		RecoveryVetoSet { account: T::AccountId, veto: Option<RecoveryVeto<BlockNumberFor<T>>> },
+		/// A recovery process for lost account by rescuer account has been contested by sender.
+		RecoveryContested {
+			lost_account: T::AccountId,
+			rescuer_account: T::AccountId,
+			sender: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -328,6 +355,14 @@ pub mod pallet {
 		AlreadyProxy,
 		/// Some internal state is broken.
 		BadState,
+		/// The friends of this account are not allowed to veto its recovery
+		VetoNotAllowed,
+		/// The veto must not lower the threshold or delay period of the recovery
+		InvalidVeto,
+		/// The delay period of this recovery has passed, so it can no longer be vetoed
+		VetoPeriodOver,
+		/// This recovery has already been contested
+		AlreadyContested,
 	}
 
 	/// The set of recoverable accounts and their recovery configuration.
@@ -362,6 +397,20 @@ pub mod pallet {
 	#[pallet::getter(fn proxy)]
 	pub type Proxy<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
 
+	/// The stricter requirements of contested recoveries of accounts whose friends may veto
+	/// their recovery.
+	#[pallet::storage]
+	pub type RecoveryVetoes<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RecoveryVeto<BlockNumberFor<T>>>;
+
+	/// Active recovery attempts which a friend vetoed.
+	///
+	/// First account is the account to be recovered, and the second account is the user trying
+	/// to recover the account. The value is the friend who vetoed the recovery.
+	#[pallet::storage]
+	pub type ContestedRecoveries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, T::AccountId>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Send a call through a recovered account.
@@ -593,18 +642,24 @@ pub mod pallet {
 			let active_recovery =
 				Self::active_recovery(&account, &who).ok_or(Error::<T>::NotStarted)?;
 			ensure!(!Proxy::<T>::contains_key(&who), Error::<T>::AlreadyProxy);
+			// A contested recovery has to meet the stricter requirements of the veto
+			let (threshold, delay_period) =
+				match <ContestedRecoveries<T>>::contains_key(&account, &who)
+					.then(|| <RecoveryVetoes<T>>::get(&account))
+					.flatten()
+				{
+					Some(veto) => (veto.threshold, veto.delay_period),
+					None => (recovery_config.threshold, recovery_config.delay_period),
+				};
 			// Make sure the delay period has passed
 			let current_block_number = <frame_system::Pallet<T>>::block_number();
 			let recoverable_block_number = active_recovery
 				.created
-				.checked_add(&recovery_config.delay_period)
+				.checked_add(&delay_period)
 				.ok_or(ArithmeticError::Overflow)?;
 			ensure!(recoverable_block_number <= current_block_number, Error::<T>::DelayPeriod);
 			// Make sure the threshold is met
-			ensure!(
-				recovery_config.threshold as usize <= active_recovery.friends.len(),
-				Error::<T>::Threshold
-			);
+			ensure!(threshold as usize <= active_recovery.friends.len(), Error::<T>::Threshold);
 			frame_system::Pallet::<T>::inc_consumers(&who).map_err(|_| Error::<T>::BadState)?;
 			// Create the recovery storage item
 			Proxy::<T>::insert(&who, &account);
@@ -637,6 +692,7 @@ pub mod pallet {
 			// Take the active recovery process started by the rescuer for this account.
 			let active_recovery =
 				<ActiveRecoveries<T>>::take(&who, &rescuer).ok_or(Error::<T>::NotStarted)?;
+			<ContestedRecoveries<T>>::remove(&who, &rescuer);
 			// Move the reserved funds from the rescuer to the rescued account.
 			// Acts like a slashing mechanism for those who try to maliciously recover accounts.
 			let res = T::Currency::repatriate_reserved(
@@ -673,6 +729,7 @@ pub mod pallet {
 			ensure!(active_recoveries.next().is_none(), Error::<T>::StillActive);
 			// Take the recovery configuration for this account.
 			let recovery_config = <Recoverable<T>>::take(&who).ok_or(Error::<T>::NotRecoverable)?;
+			<RecoveryVetoes<T>>::remove(&who);
 
 			// Unreserve the initial deposit for the recovery configuration.
 			T::Currency::unreserve(&who, recovery_config.deposit);
@@ -702,6 +759,95 @@ pub mod pallet {
 			frame_system::Pallet::<T>::dec_consumers(&who);
 			Ok(())
 		}
+
+		/// Allow or disallow your friends to veto recoveries of your account.
+		///
+		/// Any friend may then veto an active recovery process for your account during its
+		/// delay period, after which the recovery has to meet the stricter `veto` requirements.
+		/// This protects against the collusion of a minimal subset of friends.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a recoverable account
+		/// (i.e. has a recovery configuration).
+		///
+		/// Parameters:
+		/// - `veto`: The threshold and delay period of contested recoveries, which must not be
+		///   lower than the ones of the recovery configuration, or `None` to disallow vetoes.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_recovery_veto())]
+		pub fn set_recovery_veto(
+			origin: OriginFor<T>,
+			veto: Option<RecoveryVeto<BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let recovery_config = Self::recovery_config(&who).ok_or(Error::<T>::NotRecoverable)?;
+			match &veto {
+				Some(veto) => {
+					ensure!(
+						veto.threshold >= recovery_config.threshold &&
+							veto.delay_period >= recovery_config.delay_period,
+						Error::<T>::InvalidVeto
+					);
+					ensure!(
+						veto.threshold as usize <= recovery_config.friends.len(),
+						Error::<T>::NotEnoughFriends
+					);
+					<RecoveryVetoes<T>>::insert(&who, veto);
+				},
+				None => <RecoveryVetoes<T>>::remove(&who),
+			}
+			Self::deposit_event(Event::<T>::RecoveryVetoSet { account: who, veto });
+			Ok(())
+		}
+
+		/// Allow a "friend" of a recoverable account to veto an active recovery process for that
+		/// account during its delay period.
+		///
+		/// The recovery is then contested and has to meet the stricter requirements set by the
+		/// owner of the account with `set_recovery_veto`.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a "friend"
+		/// for the recoverable account.
+		///
+		/// Parameters:
+		/// - `lost`: The lost account whose recovery you want to contest.
+		/// - `rescuer`: The account trying to rescue the lost account.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::veto_recovery(T::MaxFriends::get()))]
+		pub fn veto_recovery(
+			origin: OriginFor<T>,
+			lost: AccountIdLookupOf<T>,
+			rescuer: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let lost = T::Lookup::lookup(lost)?;
+			let rescuer = T::Lookup::lookup(rescuer)?;
+			// Get the recovery configuration for the lost account.
+			let recovery_config = Self::recovery_config(&lost).ok_or(Error::<T>::NotRecoverable)?;
+			ensure!(<RecoveryVetoes<T>>::contains_key(&lost), Error::<T>::VetoNotAllowed);
+			// Get the active recovery process for the rescuer.
+			let active_recovery =
+				Self::active_recovery(&lost, &rescuer).ok_or(Error::<T>::NotStarted)?;
+			// Make sure the voter is a friend
+			ensure!(Self::is_friend(&recovery_config.friends, &who), Error::<T>::NotFriend);
+			// Make sure the delay period has not passed yet
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			let recoverable_block_number = active_recovery
+				.created
+				.checked_add(&recovery_config.delay_period)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(current_block_number < recoverable_block_number, Error::<T>::VetoPeriodOver);
+			ensure!(
+				!<ContestedRecoveries<T>>::contains_key(&lost, &rescuer),
+				Error::<T>::AlreadyContested
+			);
+			<ContestedRecoveries<T>>::insert(&lost, &rescuer, &who);
+			Self::deposit_event(Event::<T>::RecoveryContested {
+				lost_account: lost,
+				rescuer_account: rescuer,
+				sender: who,
+			});
+			Ok(())
+		}
 	}
 }
 
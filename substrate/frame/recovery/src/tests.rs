@@ -495,3 +495,122 @@ fn remove_recovery_works() {
 		assert_ok!(Recovery::remove_recovery(RuntimeOrigin::signed(5)));
 	});
 }
+
+#[test]
+fn set_recovery_veto_handles_basic_errors() {
+	new_test_ext().execute_with(|| {
+		let veto = RecoveryVeto { threshold: 3, delay_period: 20 };
+		// No recovery configuration
+		assert_noop!(
+			Recovery::set_recovery_veto(RuntimeOrigin::signed(5), Some(veto.clone())),
+			Error::<Test>::NotRecoverable
+		);
+		assert_ok!(Recovery::create_recovery(RuntimeOrigin::signed(5), vec![2, 3, 4], 2, 10));
+		// Cannot lower the threshold or the delay period
+		assert_noop!(
+			Recovery::set_recovery_veto(
+				RuntimeOrigin::signed(5),
+				Some(RecoveryVeto { threshold: 1, delay_period: 20 })
+			),
+			Error::<Test>::InvalidVeto
+		);
+		assert_noop!(
+			Recovery::set_recovery_veto(
+				RuntimeOrigin::signed(5),
+				Some(RecoveryVeto { threshold: 3, delay_period: 5 })
+			),
+			Error::<Test>::InvalidVeto
+		);
+		// Threshold must be reachable by the friends
+		assert_noop!(
+			Recovery::set_recovery_veto(
+				RuntimeOrigin::signed(5),
+				Some(RecoveryVeto { threshold: 4, delay_period: 20 })
+			),
+			Error::<Test>::NotEnoughFriends
+		);
+		assert_ok!(Recovery::set_recovery_veto(RuntimeOrigin::signed(5), Some(veto.clone())));
+		assert_eq!(<RecoveryVetoes<Test>>::get(&5), Some(veto));
+		// Vetoes can be disallowed again
+		assert_ok!(Recovery::set_recovery_veto(RuntimeOrigin::signed(5), None));
+		assert_eq!(<RecoveryVetoes<Test>>::get(&5), None);
+	});
+}
+
+#[test]
+fn veto_recovery_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Recovery::create_recovery(RuntimeOrigin::signed(5), vec![2, 3, 4], 2, 10));
+		run_to_block(10);
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		// Friends cannot veto unless the account owner allows it
+		assert_noop!(
+			Recovery::veto_recovery(RuntimeOrigin::signed(2), 5, 1),
+			Error::<Test>::VetoNotAllowed
+		);
+		assert_ok!(Recovery::set_recovery_veto(
+			RuntimeOrigin::signed(5),
+			Some(RecoveryVeto { threshold: 3, delay_period: 20 })
+		));
+		// Only friends can veto an active recovery
+		assert_noop!(
+			Recovery::veto_recovery(RuntimeOrigin::signed(2), 5, 3),
+			Error::<Test>::NotStarted
+		);
+		assert_noop!(
+			Recovery::veto_recovery(RuntimeOrigin::signed(1), 5, 1),
+			Error::<Test>::NotFriend
+		);
+		assert_ok!(Recovery::veto_recovery(RuntimeOrigin::signed(2), 5, 1));
+		assert_eq!(<ContestedRecoveries<Test>>::get(&5, &1), Some(2));
+		assert_noop!(
+			Recovery::veto_recovery(RuntimeOrigin::signed(3), 5, 1),
+			Error::<Test>::AlreadyContested
+		);
+		// The normal requirements are no longer enough
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(3), 5, 1));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(4), 5, 1));
+		run_to_block(20);
+		assert_noop!(
+			Recovery::claim_recovery(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::DelayPeriod
+		);
+		run_to_block(30);
+		assert_noop!(
+			Recovery::claim_recovery(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::Threshold
+		);
+		// The stricter requirements of the veto are met
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(2), 5, 1));
+		assert_ok!(Recovery::claim_recovery(RuntimeOrigin::signed(1), 5));
+		assert_eq!(<Proxy<Test>>::get(&1), Some(5));
+		// Closing the recovery cleans up the veto
+		assert_ok!(Recovery::close_recovery(RuntimeOrigin::signed(5), 1));
+		assert_eq!(<ContestedRecoveries<Test>>::get(&5, &1), None);
+	});
+}
+
+#[test]
+fn veto_recovery_is_limited_to_the_delay_period() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Recovery::create_recovery(RuntimeOrigin::signed(5), vec![2, 3, 4], 2, 10));
+		assert_ok!(Recovery::set_recovery_veto(
+			RuntimeOrigin::signed(5),
+			Some(RecoveryVeto { threshold: 3, delay_period: 20 })
+		));
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		run_to_block(10);
+		assert_noop!(
+			Recovery::veto_recovery(RuntimeOrigin::signed(2), 5, 1),
+			Error::<Test>::VetoPeriodOver
+		);
+		// An uncontested recovery only needs the normal requirements
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(2), 5, 1));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(3), 5, 1));
+		assert_ok!(Recovery::claim_recovery(RuntimeOrigin::signed(1), 5));
+		// Removing the recovery configuration also removes the veto
+		assert_ok!(Recovery::close_recovery(RuntimeOrigin::signed(5), 1));
+		assert_ok!(Recovery::remove_recovery(RuntimeOrigin::signed(5)));
+		assert_eq!(<RecoveryVetoes<Test>>::get(&5), None);
+	});
+}
@@ -43,9 +43,12 @@ fn funded_account<T: Config<I>, I: 'static>(name: &'static str, index: u32) -> T
 }
 
 fn dummy_call<T: Config<I>, I: 'static>() -> BoundedCallOf<T, I> {
-	let inner = frame_system::Call::remark { remark: vec![] };
-	let call = <T as Config<I>>::RuntimeCall::from(inner);
-	T::Preimages::bound(call).unwrap()
+	// A valued proposal, if any, takes a consideration from its submitter.
+	T::ProposalValue::valued_proposal().unwrap_or_else(|| {
+		let inner = frame_system::Call::remark { remark: vec![] };
+		let call = <T as Config<I>>::RuntimeCall::from(inner);
+		T::Preimages::bound(call).unwrap()
+	})
 }
 
 fn create_referendum<T: Config<I>, I: 'static>(origin: T::RuntimeOrigin) -> ReferendumIndex {
@@ -656,6 +659,27 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::MetadataCleared { index, hash }.into());
 	}
 
+	release_enactment_consideration {
+		let origin = T::SubmitOrigin::try_successful_origin(&RawOrigin::Root.into())
+			.expect("SubmitOrigin has no successful origin required for the benchmark");
+		let index = create_referendum::<T, I>(origin.clone());
+		let caller = frame_system::ensure_signed(origin.clone()).unwrap();
+		assert_ok!(Referenda::<T, I>::end_ongoing(index, true));
+		let name = Referenda::<T, I>::enactment_name(index);
+		if !EnactmentConsiderations::<T, I>::contains_key(name) {
+			let value = BalanceOf::<T, I>::max_value() / 1_000u32.into();
+			let ticket = T::ProposalConsideration::new(&caller, value)?
+				.ok_or(BenchmarkError::Weightless)?;
+			EnactmentConsiderations::<T, I>::insert(name, (caller.clone(), ticket));
+		}
+	}: _<T::RuntimeOrigin>(origin, index)
+	verify {
+		assert!(!EnactmentConsiderations::<T, I>::contains_key(name));
+		assert_last_event::<T, I>(
+			Event::EnactmentConsiderationReleased { index, who: caller }.into()
+		);
+	}
+
 	impl_benchmark_test_suite!(
 		Referenda,
 		crate::mock::ExtBuilder::default().build(),
@@ -70,11 +70,11 @@ use frame_support::{
 	ensure,
 	traits::{
 		schedule::{
-			v3::{Anon as ScheduleAnon, Named as ScheduleNamed},
+			v3::{Anon as ScheduleAnon, Named as ScheduleNamed, TaskName},
 			DispatchTime,
 		},
-		Currency, LockIdentifier, OnUnbalanced, OriginTrait, PollStatus, Polling, QueryPreimage,
-		ReservableCurrency, StorePreimage, VoteTally,
+		Consideration, Currency, LockIdentifier, OnUnbalanced, OriginTrait, PollStatus, Polling,
+		QueryPreimage, ReservableCurrency, StorePreimage, VoteTally,
 	},
 	BoundedVec,
 };
@@ -114,6 +114,25 @@ pub mod benchmarking;
 pub use frame_support::traits::Get;
 pub use sp_std::vec::Vec;
 
+/// Values the proposal of a referendum for the [`Config::ProposalConsideration`] taken from its
+/// submitter, e.g. by the amount of a treasury spend.
+pub trait ProposalValue<Call, Balance> {
+	/// The value of `proposal`. No consideration is taken for a proposal of no value.
+	fn value(proposal: &Call) -> Balance;
+
+	/// A proposal of some value, used to benchmark the worst case.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn valued_proposal() -> Option<Call> {
+		None
+	}
+}
+
+impl<Call, Balance: Zero> ProposalValue<Call, Balance> for () {
+	fn value(_: &Call) -> Balance {
+		Zero::zero()
+	}
+}
+
 #[macro_export]
 macro_rules! impl_tracksinfo_get {
 	($tracksinfo:ty, $balance:ty, $blocknumber:ty) => {
@@ -220,6 +239,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type AlarmInterval: Get<BlockNumberFor<Self>>;
 
+		/// The maximum number of blocks into the future the [`Config::Scheduler`] lets the
+		/// proposal of a referendum be scheduled for enactment at, e.g. the `MaxSchedulingHorizon`
+		/// of `pallet_scheduler`. `None` means that there is no limit.
+		///
+		/// Referenda whose enactment moment is beyond it are rejected at submission.
+		#[pallet::constant]
+		type MaxEnactmentHorizon: Get<Option<BlockNumberFor<Self>>>;
+
 		// The other stuff.
 		/// Information concerning the different referendum tracks.
 		#[pallet::constant]
@@ -236,6 +263,19 @@ pub mod pallet {
 
 		/// The preimage provider.
 		type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
+
+		/// The value of a proposal, which prices the [`Config::ProposalConsideration`]. Use `()`
+		/// to value every proposal at zero.
+		type ProposalValue: ProposalValue<BoundedCallOf<Self, I>, BalanceOf<Self, I>>;
+
+		/// A consideration taken from the submitter of a referendum when it is submitted, given
+		/// the [`Config::ProposalValue`] of its proposal.
+		///
+		/// It is returned when the referendum is cancelled and burnt when it is rejected, times
+		/// out or is killed. Once the referendum is approved it is kept for the enactment of the
+		/// proposal, which may take it over with [`Pallet::take_enactment_consideration`], and
+		/// otherwise returned by [`Pallet::release_enactment_consideration`].
+		type ProposalConsideration: Consideration<Self::AccountId, BalanceOf<Self, I>>;
 	}
 
 	/// The next free referendum index, aka the number of referenda started so far.
@@ -275,6 +315,18 @@ pub mod pallet {
 	pub type MetadataOf<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, ReferendumIndex, T::Hash>;
 
+	/// The considerations taken from the submitters of ongoing referenda, see
+	/// [`Config::ProposalConsideration`].
+	#[pallet::storage]
+	pub type ProposalConsiderations<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ReferendumIndex, (T::AccountId, T::ProposalConsideration)>;
+
+	/// The considerations of approved referenda, keyed by the name of the scheduler task which
+	/// enacts their proposal, until the enactment takes them over or they are released.
+	#[pallet::storage]
+	pub type EnactmentConsiderations<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, TaskName, (T::AccountId, T::ProposalConsideration)>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -394,6 +446,14 @@ pub mod pallet {
 			/// Preimage hash.
 			hash: T::Hash,
 		},
+		/// The consideration of an approved referendum was not taken over by its enactment and
+		/// has been returned.
+		EnactmentConsiderationReleased {
+			/// Index of the referendum.
+			index: ReferendumIndex,
+			/// The account the consideration was taken from.
+			who: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -426,6 +486,8 @@ pub mod pallet {
 		PreimageNotExist,
 		/// The preimage is stored with a different length than the one provided.
 		PreimageStoredWithDifferentLength,
+		/// The enactment moment is beyond the scheduling horizon.
+		EnactmentBeyondHorizon,
 	}
 
 	#[pallet::hooks]
@@ -439,6 +501,14 @@ pub mod pallet {
 		#[cfg(any(feature = "std", test))]
 		fn integrity_test() {
 			T::Tracks::check_integrity().expect("Static tracks configuration is valid.");
+			if let Some(horizon) = T::MaxEnactmentHorizon::get() {
+				assert!(
+					T::Tracks::tracks()
+						.iter()
+						.all(|(_, track)| track.min_enactment_period <= horizon),
+					"The minimum enactment period of every track must be within the horizon."
+				);
+			}
 		}
 	}
 
@@ -476,7 +546,8 @@ pub mod pallet {
 
 			let track =
 				T::Tracks::track_for(&proposal_origin).map_err(|_| Error::<T, I>::NoTrack)?;
-			let submission_deposit = Self::take_deposit(who, T::SubmissionDeposit::get())?;
+			Self::ensure_enactment_within_horizon(&enactment_moment)?;
+			let submission_deposit = Self::take_deposit(who.clone(), T::SubmissionDeposit::get())?;
 			let index = ReferendumCount::<T, I>::mutate(|x| {
 				let r = *x;
 				*x += 1;
@@ -499,6 +570,12 @@ pub mod pallet {
 				alarm: Self::set_alarm(nudge_call, now.saturating_add(T::UndecidingTimeout::get())),
 			};
 			ReferendumInfoFor::<T, I>::insert(index, ReferendumInfo::Ongoing(status));
+			let value = T::ProposalValue::value(&proposal);
+			if !value.is_zero() {
+				if let Some(ticket) = T::ProposalConsideration::new(&who, value)? {
+					ProposalConsiderations::<T, I>::insert(index, (who, ticket));
+				}
+			}
 
 			Self::deposit_event(Event::<T, I>::Submitted { index, track, proposal });
 			Ok(())
@@ -579,6 +656,9 @@ pub mod pallet {
 				let _ = T::Scheduler::cancel(last_alarm);
 			}
 			Self::note_one_fewer_deciding(status.track);
+			if let Some((who, ticket)) = ProposalConsiderations::<T, I>::take(index) {
+				let _ = ticket.drop(&who);
+			}
 			Self::deposit_event(Event::<T, I>::Cancelled { index, tally: status.tally });
 			let info = ReferendumInfo::Cancelled(
 				frame_system::Pallet::<T>::block_number(),
@@ -607,6 +687,7 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::Killed { index, tally: status.tally });
 			Self::slash_deposit(Some(status.submission_deposit.clone()));
 			Self::slash_deposit(status.decision_deposit.clone());
+			Self::burn_proposal_consideration(index);
 			Self::do_clear_metadata(index);
 			let info = ReferendumInfo::Killed(frame_system::Pallet::<T>::block_number());
 			ReferendumInfoFor::<T, I>::insert(index, info);
@@ -702,6 +783,34 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Return the consideration of an approved referendum which the enactment of its proposal
+		/// did not take over.
+		///
+		/// - `origin`: must be `Signed` or `Root`.
+		/// - `index`: The index of an approved referendum whose proposal is no longer scheduled for
+		///   enactment.
+		///
+		/// Emits `EnactmentConsiderationReleased`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::release_enactment_consideration())]
+		pub fn release_enactment_consideration(
+			origin: OriginFor<T>,
+			index: ReferendumIndex,
+		) -> DispatchResult {
+			ensure_signed_or_root(origin)?;
+			ensure!(
+				matches!(ReferendumInfoFor::<T, I>::get(index), Some(ReferendumInfo::Approved(..))),
+				Error::<T, I>::BadStatus
+			);
+			let name = Self::enactment_name(index);
+			ensure!(T::Scheduler::next_dispatch_time(name).is_err(), Error::<T, I>::Unfinished);
+			let (who, ticket) =
+				EnactmentConsiderations::<T, I>::take(name).ok_or(Error::<T, I>::NoDeposit)?;
+			ticket.drop(&who)?;
+			Self::deposit_event(Event::<T, I>::EnactmentConsiderationReleased { index, who });
+			Ok(())
+		}
+
 		/// Set or clear metadata of a referendum.
 		///
 		/// Parameters:
@@ -827,8 +936,12 @@ impl<T: Config<I>, I: 'static> Polling<T::Tally> for Pallet<T, I> {
 		Self::note_one_fewer_deciding(status.track);
 		let now = frame_system::Pallet::<T>::block_number();
 		let info = if approved {
+			if let Some(consideration) = ProposalConsiderations::<T, I>::take(index) {
+				EnactmentConsiderations::<T, I>::insert(Self::enactment_name(index), consideration);
+			}
 			ReferendumInfo::Approved(now, Some(status.submission_deposit), status.decision_deposit)
 		} else {
+			Self::burn_proposal_consideration(index);
 			ReferendumInfo::Rejected(now, Some(status.submission_deposit), status.decision_deposit)
 		};
 		ReferendumInfoFor::<T, I>::insert(index, info);
@@ -882,6 +995,45 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
+	/// Take over the consideration of the referendum whose proposal is enacted by the scheduler
+	/// task `name`, along with the account it was taken from.
+	///
+	/// Combined with the name of the task being dispatched, this lets the proposal being enacted
+	/// take over the consideration of its referendum.
+	pub fn take_enactment_consideration(
+		name: TaskName,
+	) -> Option<(T::AccountId, T::ProposalConsideration)> {
+		EnactmentConsiderations::<T, I>::take(name)
+	}
+
+	/// Ensure that a proposal to be enacted at `desired` can be scheduled within
+	/// [`Config::MaxEnactmentHorizon`], however late its referendum is approved.
+	fn ensure_enactment_within_horizon(
+		desired: &DispatchTime<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let Some(horizon) = T::MaxEnactmentHorizon::get() else { return Ok(()) };
+		let within = match desired {
+			// The horizon only moves further out until the referendum is approved.
+			DispatchTime::At(when) =>
+				*when <= frame_system::Pallet::<T>::block_number().saturating_add(horizon),
+			DispatchTime::After(delay) => *delay <= horizon,
+		};
+		ensure!(within, Error::<T, I>::EnactmentBeyondHorizon);
+		Ok(())
+	}
+
+	/// The name of the scheduler task which enacts the proposal of referendum `index`.
+	fn enactment_name(index: ReferendumIndex) -> TaskName {
+		(ASSEMBLY_ID, "enactment", index).using_encoded(sp_io::hashing::blake2_256)
+	}
+
+	/// Burn the consideration taken from the submitter of referendum `index`, if any.
+	fn burn_proposal_consideration(index: ReferendumIndex) {
+		if let Some((who, ticket)) = ProposalConsiderations::<T, I>::take(index) {
+			ticket.burn(&who);
+		}
+	}
+
 	// Enqueue a proposal from a referendum which has presumably passed.
 	fn schedule_enactment(
 		index: ReferendumIndex,
@@ -893,16 +1045,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let now = frame_system::Pallet::<T>::block_number();
 		// Earliest allowed block is always at minimum the next block.
 		let earliest_allowed = now.saturating_add(track.min_enactment_period.max(One::one()));
-		let desired = desired.evaluate(now);
-		let ok = T::Scheduler::schedule_named(
-			(ASSEMBLY_ID, "enactment", index).using_encoded(sp_io::hashing::blake2_256),
-			DispatchTime::At(desired.max(earliest_allowed)),
-			None,
-			63,
-			origin,
-			call,
-		)
-		.is_ok();
+		let desired = desired.evaluate(now).max(earliest_allowed);
+		// Should the horizon have been lowered since submission, enact the proposal at its end
+		// rather than failing to schedule it.
+		let when = T::MaxEnactmentHorizon::get()
+			.map_or(desired, |horizon| desired.min(now.saturating_add(horizon)));
+		let name = Self::enactment_name(index);
+		if let Some(consideration) = ProposalConsiderations::<T, I>::take(index) {
+			EnactmentConsiderations::<T, I>::insert(name, consideration);
+		}
+		let ok = T::Scheduler::schedule_named(name, DispatchTime::At(when), None, 63, origin, call)
+			.is_ok();
 		debug_assert!(ok, "LOGIC ERROR: bake_referendum/schedule_named failed");
 	}
 
@@ -1148,6 +1301,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				if status.deciding.is_none() && now >= timeout && !status.in_queue {
 					// Too long without being decided - end it.
 					Self::ensure_no_alarm(&mut status);
+					Self::burn_proposal_consideration(index);
 					Self::deposit_event(Event::<T, I>::TimedOut { index, tally: status.tally });
 					return (
 						ReferendumInfo::TimedOut(
@@ -1205,6 +1359,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						// Failed!
 						Self::ensure_no_alarm(&mut status);
 						Self::note_one_fewer_deciding(status.track);
+						Self::burn_proposal_consideration(index);
 						Self::deposit_event(Event::<T, I>::Rejected { index, tally: status.tally });
 						return (
 							ReferendumInfo::Rejected(
@@ -1347,6 +1502,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})?;
 
+		ProposalConsiderations::<T, I>::iter_keys().try_for_each(
+			|referendum_index| -> DispatchResult {
+				ensure!(
+					Self::ensure_ongoing(referendum_index).is_ok(),
+					"Referendum indices in `ProposalConsiderations` must be ongoing"
+				);
+				Ok(())
+			},
+		)?;
+
 		Self::try_state_referenda_info()?;
 		Self::try_state_tracks()?;
 
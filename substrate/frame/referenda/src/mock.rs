@@ -23,8 +23,8 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	assert_ok, derive_impl, ord_parameter_types, parameter_types,
 	traits::{
-		ConstU32, ConstU64, Contains, EqualPrivilegeOnly, OnInitialize, OriginTrait, Polling,
-		SortedMembers,
+		ConstBool, ConstU32, ConstU64, Contains, EqualPrivilegeOnly, OnInitialize, OriginTrait,
+		Polling, ReservableCurrency, SortedMembers, UnixTime,
 	},
 	weights::Weight,
 };
@@ -70,6 +70,14 @@ impl pallet_preimage::Config for Test {
 	type Currency = Balances;
 	type ManagerOrigin = EnsureRoot<u64>;
 	type Consideration = ();
+	type DepositPrice = ();
+}
+/// Scheduler time source which never advances.
+pub struct MockTime;
+impl UnixTime for MockTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::ZERO
+	}
 }
 impl pallet_scheduler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -79,9 +87,22 @@ impl pallet_scheduler::Config for Test {
 	type MaximumWeight = MaxWeight;
 	type ScheduleOrigin = EnsureRoot<u64>;
 	type MaxScheduledPerBlock = ConstU32<100>;
+	type BlockNumberProvider = System;
 	type WeightInfo = ();
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
+	type RejectOverweight = ConstBool<false>;
+	type TimeProvider = MockTime;
+	type MaximumBlockTime = ConstU64<6_000>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type ScheduleDeposit = ConstU64<0>;
+	type MaxScheduledPerOrigin = ();
+	type MaxSchedulingHorizon = SchedulingHorizon;
+	type MaxMergedAgendas = ConstU32<1>;
+	type MaxIdleWeight = ();
+	type MaxOverflowed = ConstU32<100>;
+	type OnChargeDispatch = ();
 }
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
 impl pallet_balances::Config for Test {
@@ -89,6 +110,7 @@ impl pallet_balances::Config for Test {
 }
 parameter_types! {
 	pub static AlarmInterval: u64 = 1;
+	pub static SchedulingHorizon: Option<u64> = None;
 }
 ord_parameter_types! {
 	pub const One: u64 = 1;
@@ -197,6 +219,44 @@ impl TracksInfo<u64, u64> for TestTracksInfo {
 }
 impl_tracksinfo_get!(TestTracksInfo, u64, u64);
 
+/// Values remarks by their length and any other proposal at zero.
+pub struct RemarkLength;
+impl ProposalValue<BoundedCallOf<Test, ()>, u64> for RemarkLength {
+	fn value(proposal: &BoundedCallOf<Test, ()>) -> u64 {
+		match <Preimage as QueryPreimage>::peek(proposal) {
+			Ok((RuntimeCall::System(frame_system::Call::remark { remark }), _)) =>
+				remark.len() as u64,
+			_ => 0,
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn valued_proposal() -> Option<BoundedCallOf<Test, ()>> {
+		Some(remark_proposal_bounded(10))
+	}
+}
+
+/// A consideration reserving its footprint.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Eq, PartialEq, Debug)]
+pub struct ReserveConsideration(u64);
+impl Consideration<u64, u64> for ReserveConsideration {
+	fn new(who: &u64, amount: u64) -> Result<Option<Self>, DispatchError> {
+		Balances::reserve(who, amount)?;
+		Ok(Some(Self(amount)))
+	}
+	fn update(self, who: &u64, amount: u64) -> Result<Option<Self>, DispatchError> {
+		self.drop(who)?;
+		Self::new(who, amount)
+	}
+	fn drop(self, who: &u64) -> Result<(), DispatchError> {
+		Balances::unreserve(who, self.0);
+		Ok(())
+	}
+	fn burn(self, who: &u64) {
+		let _ = Balances::slash_reserved(who, self.0);
+	}
+}
+
 impl Config for Test {
 	type WeightInfo = ();
 	type RuntimeCall = RuntimeCall;
@@ -213,8 +273,11 @@ impl Config for Test {
 	type MaxQueued = ConstU32<3>;
 	type UndecidingTimeout = ConstU64<20>;
 	type AlarmInterval = AlarmInterval;
+	type MaxEnactmentHorizon = SchedulingHorizon;
 	type Tracks = TestTracksInfo;
 	type Preimages = Preimage;
+	type ProposalValue = RemarkLength;
+	type ProposalConsideration = ReserveConsideration;
 }
 pub struct ExtBuilder {}
 
@@ -305,6 +368,11 @@ pub fn set_balance_proposal_bounded(value: u64) -> BoundedCallOf<Test, ()> {
 	<Preimage as StorePreimage>::bound(c).unwrap()
 }
 
+pub fn remark_proposal_bounded(len: usize) -> BoundedCallOf<Test, ()> {
+	let c = RuntimeCall::System(frame_system::Call::remark { remark: vec![0; len] });
+	<Preimage as StorePreimage>::bound(c).unwrap()
+}
+
 #[allow(dead_code)]
 pub fn propose_set_balance(who: u64, value: u64, delay: u64) -> DispatchResult {
 	Referenda::submit(
@@ -70,6 +70,59 @@ fn basic_happy_path_works() {
 	});
 }
 
+#[test]
+fn proposal_consideration_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let submit = |who| {
+			Referenda::submit(
+				RuntimeOrigin::signed(who),
+				Box::new(RawOrigin::Root.into()),
+				remark_proposal_bounded(10),
+				DispatchTime::At(10),
+			)
+		};
+		// The consideration is taken on submission and returned if the referendum is cancelled.
+		assert_ok!(submit(1));
+		assert_eq!(Balances::reserved_balance(&1), 12);
+		assert!(ProposalConsiderations::<Test>::contains_key(0));
+		assert_ok!(Referenda::cancel(RuntimeOrigin::signed(4), 0));
+		assert_eq!(Balances::reserved_balance(&1), 2);
+		assert!(!ProposalConsiderations::<Test>::contains_key(0));
+
+		// It is burnt if the referendum times out.
+		assert_ok!(submit(2));
+		run_to(22);
+		assert_matches!(ReferendumInfoFor::<Test>::get(1), Some(ReferendumInfo::TimedOut(..)));
+		assert_eq!(Balances::reserved_balance(&2), 2);
+		assert_eq!(Balances::free_balance(&2), 88);
+		assert!(!ProposalConsiderations::<Test>::contains_key(1));
+
+		// It is kept for the enactment of an approved proposal, and released once it is enacted
+		// without taking it.
+		assert_ok!(submit(3));
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(3), 2));
+		run_to(27);
+		set_tally(2, 100, 0);
+		run_to(30);
+		assert_eq!(approved_since(2), 30);
+		let enactment = (ASSEMBLY_ID, "enactment", 2u32).using_encoded(sp_io::hashing::blake2_256);
+		assert!(EnactmentConsiderations::<Test>::contains_key(enactment));
+		assert_noop!(
+			Referenda::release_enactment_consideration(RuntimeOrigin::signed(5), 2),
+			Error::<Test>::Unfinished
+		);
+		run_to(35);
+		assert_eq!(Balances::reserved_balance(&3), 22);
+		assert_ok!(Referenda::release_enactment_consideration(RuntimeOrigin::signed(5), 2));
+		assert_eq!(Balances::reserved_balance(&3), 12);
+		assert!(!EnactmentConsiderations::<Test>::contains_key(enactment));
+		assert_noop!(
+			Referenda::release_enactment_consideration(RuntimeOrigin::signed(5), 2),
+			Error::<Test>::NoDeposit
+		);
+	});
+}
+
 #[test]
 fn insta_confirm_then_kill_works() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -706,3 +759,31 @@ fn zero_enactment_delay_executes_proposal_at_next_block() {
 		assert_eq!(Balances::free_balance(42), 20);
 	});
 }
+
+#[test]
+fn enactment_beyond_horizon_is_rejected() {
+	ExtBuilder::default().build_and_execute(|| {
+		SchedulingHorizon::set(Some(10));
+		let submit = |when| {
+			Referenda::submit(
+				RuntimeOrigin::signed(1),
+				Box::new(RawOrigin::Signed(1).into()),
+				Preimage::bound(
+					pallet_balances::Call::transfer_keep_alive { dest: 42, value: 20 }.into(),
+				)
+				.unwrap(),
+				when,
+			)
+		};
+		assert_noop!(submit(DispatchTime::After(11)), Error::<Test>::EnactmentBeyondHorizon);
+		assert_noop!(submit(DispatchTime::At(12)), Error::<Test>::EnactmentBeyondHorizon);
+		assert_ok!(submit(DispatchTime::At(11)));
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(1), 0));
+		set_tally(0, 100, 0);
+
+		// Should the horizon be lowered in the meantime, the proposal is enacted at its end.
+		SchedulingHorizon::set(Some(2));
+		run_to(10);
+		assert_eq!(Balances::free_balance(42), 20);
+	});
+}
@@ -55,7 +55,7 @@ fn fill_schedule<T: Config>(
 	when: frame_system::pallet_prelude::BlockNumberFor<T>,
 	n: u32,
 ) -> Result<(), &'static str> {
-	let t = DispatchTime::At(when);
+	let t = ScheduleTime::At(when);
 	let origin: <T as Config>::PalletsOrigin = frame_system::RawOrigin::Root.into();
 	for i in 0..n {
 		let call = make_call::<T>(None);
@@ -326,17 +326,28 @@ benchmarks! {
 		let root: <T as Config>::PalletsOrigin = frame_system::RawOrigin::Root.into();
 		let retry_config = RetryConfig { total_retries: 10, remaining: 10, period };
 		Retries::<T>::insert(address, retry_config);
+		let backoff = RetryBackoff::Exponential { cap: 10u32.into() };
 		let (mut when, index) = address;
 		let task = Agenda::<T>::get(when)[index as usize].clone().unwrap();
 		let mut weight_counter = WeightMeter::with_limit(T::MaximumWeight::get());
 	}: {
-		Scheduler::<T>::schedule_retry(&mut weight_counter, when, when, index, &task, retry_config);
+		Scheduler::<T>::schedule_retry(
+			&mut weight_counter,
+			when,
+			when,
+			index,
+			&task,
+			retry_config,
+			backoff,
+			None,
+		);
 	} verify {
 		when = when + BlockNumberFor::<T>::one();
 		assert_eq!(
 			Retries::<T>::get((when, 0)),
 			Some(RetryConfig { total_retries: 10, remaining: 9, period })
 		);
+		assert_eq!(RetryBackoffs::<T>::get((when, 0)), Some(backoff));
 	}
 
 	set_retry {
@@ -415,5 +426,309 @@ benchmarks! {
 		);
 	}
 
+	set_retry_backoff {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+		let (when, index) = address;
+		let period = BlockNumberFor::<T>::one();
+		assert!(Scheduler::<T>::set_retry(RawOrigin::Root.into(), (when, index), 10, period).is_ok());
+		let backoff = RetryBackoff::Exponential { cap: 10u32.into() };
+	}: _(RawOrigin::Root, (when, index), backoff)
+	verify {
+		assert_eq!(RetryBackoffs::<T>::get((when, index)), Some(backoff));
+		assert_last_event::<T>(
+			Event::RetryBackoffSet { task: address, id: None, backoff }.into(),
+		);
+	}
+
+	set_retry_backoff_named {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+		let (when, index) = address;
+		let period = BlockNumberFor::<T>::one();
+		assert!(Scheduler::<T>::set_retry_named(RawOrigin::Root.into(), name, 10, period).is_ok());
+		let backoff = RetryBackoff::Exponential { cap: 10u32.into() };
+	}: _(RawOrigin::Root, name, backoff)
+	verify {
+		assert_eq!(RetryBackoffs::<T>::get((when, index)), Some(backoff));
+		assert_last_event::<T>(
+			Event::RetryBackoffSet { task: address, id: Some(name), backoff }.into(),
+		);
+	}
+
+	schedule_named_after_task {
+		let when = BLOCK_NUMBER.into();
+		fill_schedule::<T>(when, 1)?;
+		let id = u32_to_name(1);
+		let depends_on = u32_to_name(0);
+		let after = BlockNumberFor::<T>::one();
+		let priority = 0;
+		// Essentially a no-op call.
+		let call = Box::new(SystemCall::set_storage { items: vec![] }.into());
+	}: _(RawOrigin::Root, id, depends_on, after, None, priority, call)
+	verify {
+		ensure!(DependsOn::<T>::get(id) == Some((when, 0)), "didn't add the dependant");
+		ensure!(Dependants::<T>::contains_key((when, 0), id), "didn't add the dependant");
+	}
+
+	reschedule_overflowed {
+		let s in 0 .. (T::MaxScheduledPerBlock::get() - 1);
+		let when = BLOCK_NUMBER.into();
+		fill_schedule::<T>(when, s)?;
+		let mut task = make_task::<T>(true, true, false, None, 0);
+		task.maybe_id = Some(u32_to_name(s));
+		let retry = RetryConfig { total_retries: 10, remaining: 10, period: 1u32.into() };
+		let backoff = RetryBackoff::Exponential { cap: 10u32.into() };
+		let overflowed = Overflowed {
+			when,
+			task,
+			retry: Some(retry),
+			backoff: Some(backoff),
+			recurrence: None,
+			reservation: None,
+			valid_until: None,
+		};
+		Overflow::<T>::insert(0, overflowed);
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		ensure!(Overflow::<T>::get(0).is_none(), "didn't remove from overflow");
+		ensure!(
+			Agenda::<T>::get(when).len() == (s + 1) as usize,
+			"didn't add to schedule"
+		);
+	}
+
+	cancel_overflowed {
+		let task = make_task::<T>(true, true, false, None, 0);
+		let overflowed = Overflowed {
+			when: BLOCK_NUMBER.into(),
+			task,
+			retry: None,
+			backoff: None,
+			recurrence: None,
+			reservation: None,
+			valid_until: None,
+		};
+		Overflow::<T>::insert(0, overflowed);
+	}: _(RawOrigin::Root, 0)
+	verify {
+		ensure!(Overflow::<T>::get(0).is_none(), "didn't remove from overflow");
+	}
+
+	cancel_all_for_origin {
+		let a in 0 .. 100;
+		let s = T::MaxScheduledPerBlock::get();
+		let origin: <T as Config>::PalletsOrigin = frame_system::RawOrigin::Root.into();
+		for i in 0..a {
+			let when = ScheduleTime::At((BLOCK_NUMBER + i).into());
+			for j in 0..s {
+				let name = u32_to_name(i * s + j);
+				let period = Some(((j + 100).into(), 100));
+				Scheduler::<T>::do_schedule_named(
+					name, when, period, 0, origin.clone(), make_call::<T>(None),
+				)?;
+			}
+		}
+		let schedule_origin =
+			T::ScheduleOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<SystemOrigin<T>>(schedule_origin, Box::new(origin), a)
+	verify {
+		ensure!(Agenda::<T>::iter_keys().next().is_none(), "didn't cancel all tasks");
+	}
+
+	set_recurrence {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+		let recurrence = Some(Recurrence::Every { period: 86_400_000, offset: 3_600_000 });
+	}: _(RawOrigin::Root, address, recurrence)
+	verify {
+		assert_eq!(Recurrences::<T>::get(address), recurrence);
+		assert_last_event::<T>(
+			Event::RecurrenceSet { task: address, id: None, recurrence }.into(),
+		);
+	}
+
+	set_recurrence_named {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+		let recurrence = Some(Recurrence::Every { period: 86_400_000, offset: 3_600_000 });
+	}: _(RawOrigin::Root, name, recurrence)
+	verify {
+		assert_eq!(Recurrences::<T>::get(address), recurrence);
+		assert_last_event::<T>(
+			Event::RecurrenceSet { task: address, id: Some(name), recurrence }.into(),
+		);
+	}
+
+	reschedule {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+		let new_when = when + One::one();
+
+		fill_schedule::<T>(when, s - 1)?;
+		let origin: <T as Config>::PalletsOrigin = RawOrigin::Root.into();
+		let address = Scheduler::<T>::do_schedule(
+			ScheduleTime::At(when),
+			None,
+			0,
+			origin,
+			make_call::<T>(None),
+		)?;
+		let period = BlockNumberFor::<T>::one();
+		assert!(Scheduler::<T>::set_retry(RawOrigin::Root.into(), address, 10, period).is_ok());
+	}: _(RawOrigin::Root, address.0, address.1, ScheduleTime::At(new_when))
+	verify {
+		assert!(!Retries::<T>::contains_key(address));
+		assert!(Retries::<T>::contains_key((new_when, 0)));
+		assert_last_event::<T>(
+			Event::Scheduled { when: new_when, index: 0 }.into(),
+		);
+	}
+
+	reschedule_named {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+		let new_when = when + One::one();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+		let period = BlockNumberFor::<T>::one();
+		assert!(Scheduler::<T>::set_retry_named(RawOrigin::Root.into(), name, 10, period).is_ok());
+	}: _(RawOrigin::Root, name, ScheduleTime::At(new_when))
+	verify {
+		assert_eq!(Lookup::<T>::get(name), Some((new_when, 0)));
+		assert!(!Retries::<T>::contains_key(address));
+		assert!(Retries::<T>::contains_key((new_when, 0)));
+		assert_last_event::<T>(
+			Event::Scheduled { when: new_when, index: 0 }.into(),
+		);
+	}
+
+	pause {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+	}: _(RawOrigin::Root, address)
+	verify {
+		assert!(Paused::<T>::contains_key(address));
+		assert_last_event::<T>(
+			Event::Paused { task: address, id: None }.into(),
+		);
+	}
+
+	pause_named {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+	}: _(RawOrigin::Root, name)
+	verify {
+		assert!(Paused::<T>::contains_key(address));
+		assert_last_event::<T>(
+			Event::Paused { task: address, id: Some(name) }.into(),
+		);
+	}
+
+	resume {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+		let period = BlockNumberFor::<T>::one();
+		assert!(Scheduler::<T>::set_retry(RawOrigin::Root.into(), address, 10, period).is_ok());
+		assert!(Scheduler::<T>::pause(RawOrigin::Root.into(), address).is_ok());
+		// The agenda is serviced while the task is paused, so it needs to be placed again.
+		frame_system::Pallet::<T>::set_block_number(when);
+	}: _(RawOrigin::Root, address)
+	verify {
+		let new_address = Lookup::<T>::get(name).unwrap();
+		assert_eq!(new_address.0, when + One::one());
+		assert!(!Paused::<T>::contains_key(address));
+		assert!(Retries::<T>::contains_key(new_address));
+		assert_last_event::<T>(
+			Event::Resumed { task: new_address, id: None }.into(),
+		);
+	}
+
+	resume_named {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+		let period = BlockNumberFor::<T>::one();
+		assert!(Scheduler::<T>::set_retry_named(RawOrigin::Root.into(), name, 10, period).is_ok());
+		assert!(Scheduler::<T>::pause_named(RawOrigin::Root.into(), name).is_ok());
+		// The agenda is serviced while the task is paused, so it needs to be placed again.
+		frame_system::Pallet::<T>::set_block_number(when);
+	}: _(RawOrigin::Root, name)
+	verify {
+		let new_address = Lookup::<T>::get(name).unwrap();
+		assert_eq!(new_address.0, when + One::one());
+		assert!(!Paused::<T>::contains_key(address));
+		assert!(Retries::<T>::contains_key(new_address));
+		assert_last_event::<T>(
+			Event::Resumed { task: new_address, id: Some(name) }.into(),
+		);
+	}
+
+	set_valid_until {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+		let valid_until = Some(when + 10u32.into());
+	}: _(RawOrigin::Root, address, valid_until)
+	verify {
+		assert_eq!(ValidUntil::<T>::get(address), valid_until);
+		assert_last_event::<T>(
+			Event::ValidUntilSet { task: address, id: None, valid_until }.into(),
+		);
+	}
+
+	set_valid_until_named {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+		let valid_until = Some(when + 10u32.into());
+	}: _(RawOrigin::Root, name, valid_until)
+	verify {
+		assert_eq!(ValidUntil::<T>::get(address), valid_until);
+		assert_last_event::<T>(
+			Event::ValidUntilSet { task: address, id: Some(name), valid_until }.into(),
+		);
+	}
+
 	impl_benchmark_test_suite!(Scheduler, crate::mock::new_test_ext(), crate::mock::Test);
 }
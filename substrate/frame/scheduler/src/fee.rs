@@ -0,0 +1,117 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Charging fees for the execution of scheduled calls.
+
+use crate::Config;
+use frame_support::{
+	dispatch::RawOrigin,
+	traits::{
+		fungible::{Balanced, Credit},
+		tokens::{Fortitude, Precision, Preservation},
+		CallerTrait, Imbalance, OnUnbalanced,
+	},
+	weights::{Weight, WeightToFee},
+};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchError,
+};
+use sp_std::marker::PhantomData;
+
+/// Handles the fee for executing a scheduled call, similar to the `OnChargeTransaction` of the
+/// transaction payment pallet.
+///
+/// The fee for the weight which a call may use is withdrawn right before the call is dispatched
+/// and corrected to the weight it actually used afterwards. If the fee can not be withdrawn, the
+/// call is not dispatched and the task fails with the returned error.
+pub trait OnChargeDispatch<T: Config> {
+	/// The fee withdrawn before the dispatch, which is handed to
+	/// [`Self::correct_and_deposit_fee`].
+	type Liquidity;
+
+	/// Withdraw the fee for executing a call scheduled by `origin` using up to `weight`.
+	fn withdraw_fee(
+		origin: &T::PalletsOrigin,
+		weight: Weight,
+	) -> Result<Self::Liquidity, DispatchError>;
+
+	/// Refund the part of the fee withdrawn for `weight` which exceeds the fee for the
+	/// `actual_weight` used by the call, and handle the remaining fee.
+	fn correct_and_deposit_fee(
+		origin: &T::PalletsOrigin,
+		weight: Weight,
+		actual_weight: Weight,
+		liquidity: Self::Liquidity,
+	);
+}
+
+impl<T: Config> OnChargeDispatch<T> for () {
+	type Liquidity = ();
+
+	fn withdraw_fee(_: &T::PalletsOrigin, _: Weight) -> Result<(), DispatchError> {
+		Ok(())
+	}
+
+	fn correct_and_deposit_fee(_: &T::PalletsOrigin, _: Weight, _: Weight, _: ()) {}
+}
+
+/// Charges calls scheduled by a signed origin to the signing account in the fungible `F`.
+///
+/// The weight is converted to a fee by `W` and the fee paid is handed to `OU`. Calls of any other
+/// origin are executed free of charge.
+pub struct FungibleAdapter<F, W, OU>(PhantomData<(F, W, OU)>);
+
+impl<T, F, W, OU> OnChargeDispatch<T> for FungibleAdapter<F, W, OU>
+where
+	T: Config,
+	F: Balanced<T::AccountId>,
+	W: WeightToFee<Balance = F::Balance>,
+	OU: OnUnbalanced<Credit<T::AccountId, F>>,
+{
+	type Liquidity = Option<(T::AccountId, Credit<T::AccountId, F>)>;
+
+	fn withdraw_fee(
+		origin: &T::PalletsOrigin,
+		weight: Weight,
+	) -> Result<Self::Liquidity, DispatchError> {
+		let Some(RawOrigin::Signed(who)) = origin.as_system_ref() else { return Ok(None) };
+		let fee = W::weight_to_fee(&weight);
+		if fee.is_zero() {
+			return Ok(None)
+		}
+		let paid =
+			F::withdraw(who, fee, Precision::Exact, Preservation::Preserve, Fortitude::Polite)?;
+		Ok(Some((who.clone(), paid)))
+	}
+
+	fn correct_and_deposit_fee(
+		_origin: &T::PalletsOrigin,
+		_weight: Weight,
+		actual_weight: Weight,
+		liquidity: Self::Liquidity,
+	) {
+		let Some((who, paid)) = liquidity else { return };
+		let refund = paid.peek().saturating_sub(W::weight_to_fee(&actual_weight));
+		let (refund, fee) = paid.split(refund);
+		let fee = match F::resolve(&who, refund) {
+			Ok(()) => fee,
+			Err(refund) => fee.merge(refund),
+		};
+		OU::on_unbalanced(fee);
+	}
+}
@@ -49,6 +49,15 @@
 //! 2. Scheduling a preimage hash of a runtime call at a specific block
 #![doc = docify::embed!("src/tests.rs", scheduling_with_preimages_works)]
 
+//!
+//! ### Skipped blocks
+//!
+//! The block number at which tasks are scheduled is provided by
+//! [`Config::BlockNumberProvider`]. It does not have to increase by one with every block, e.g. a
+//! parachain using Agile Coretime may use the block number of the relay chain. All agendas up to
+//! the current block number are serviced, including those of skipped blocks. The blocks with
+//! pending agendas are kept in the [`Queue`], so servicing them does not depend on the number of
+//! blocks skipped.
 //!
 //! ## Pallet API
 //!
@@ -78,9 +87,11 @@
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod fee;
 pub mod migration;
-#[cfg(test)]
-mod mock;
+#[cfg(any(feature = "test-utils", test))]
+pub mod mock;
+pub mod runtime_api;
 #[cfg(test)]
 mod tests;
 pub mod weights;
@@ -88,11 +99,14 @@ pub mod weights;
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::{DispatchResult, GetDispatchInfo, Parameter, RawOrigin},
+	dispatch_context::{run_in_context, with_context},
 	ensure,
 	traits::{
+		fungible::{hold::Mutate as FunHoldMutate, Inspect as FunInspect},
 		schedule::{self, DispatchTime, MaybeHashed},
+		tokens::Precision::BestEffort,
 		Bounded, CallerTrait, EnsureOrigin, Get, IsType, OriginTrait, PalletInfoAccess,
-		PrivilegeCmp, QueryPreimage, StorageVersion, StorePreimage,
+		PrivilegeCmp, QueryPreimage, StorageVersion, StorePreimage, UnixTime,
 	},
 	weights::{Weight, WeightMeter},
 };
@@ -103,11 +117,15 @@ use frame_system::{
 use scale_info::TypeInfo;
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
-	traits::{BadOrigin, Dispatchable, One, Saturating, Zero},
-	BoundedVec, DispatchError, RuntimeDebug,
+	traits::{
+		AtLeast32BitUnsigned, BadOrigin, BlockNumberProvider, Dispatchable, One, Saturating,
+		TrailingZeroInput, Zero,
+	},
+	BoundedVec, DispatchError, RuntimeDebug, SaturatedConversion,
 };
 use sp_std::{borrow::Borrow, cmp::Ordering, marker::PhantomData, prelude::*};
 
+pub use fee::OnChargeDispatch;
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -115,6 +133,8 @@ pub use weights::WeightInfo;
 pub type PeriodicIndex = u32;
 /// The location of a scheduled task that can be used to remove it.
 pub type TaskAddress<BlockNumber> = (BlockNumber, u32);
+/// The key of a block in the [`Queue`], which is its number in big-endian.
+pub type QueueKey = [u8; 16];
 
 pub type CallOrHashOf<T> =
 	MaybeHashed<<T as Config>::RuntimeCall, <T as frame_system::Config>::Hash>;
@@ -122,6 +142,34 @@ pub type CallOrHashOf<T> =
 pub type BoundedCallOf<T> =
 	Bounded<<T as Config>::RuntimeCall, <T as frame_system::Config>::Hashing>;
 
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as FunInspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// The time at which a task is scheduled to be dispatched.
+///
+/// Besides the block-based [`DispatchTime`]s, tasks of this pallet may be dispatched at a moment
+/// in milliseconds since the Unix epoch, as provided by [`Config::TimeProvider`].
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ScheduleTime<BlockNumber> {
+	/// At specified block.
+	At(BlockNumber),
+	/// After specified number of blocks.
+	After(BlockNumber),
+	/// At the first block whose time is no earlier than the specified moment.
+	AtMoment(u64),
+	/// At the first block whose time is no earlier than the specified duration from now.
+	AfterMoment(u64),
+}
+
+impl<BlockNumber> From<DispatchTime<BlockNumber>> for ScheduleTime<BlockNumber> {
+	fn from(when: DispatchTime<BlockNumber>) -> Self {
+		match when {
+			DispatchTime::At(x) => Self::At(x),
+			DispatchTime::After(x) => Self::After(x),
+		}
+	}
+}
+
 /// The configuration of the retry mechanism for a given task along with its current state.
 #[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub struct RetryConfig<Period> {
@@ -133,6 +181,190 @@ pub struct RetryConfig<Period> {
 	period: Period,
 }
 
+/// The strategy by which the time between the retry attempts of a task grows.
+#[derive(
+	Clone, Copy, Default, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo,
+)]
+pub enum RetryBackoff<Period> {
+	/// Every retry attempt is made `period` blocks after the previous failure.
+	#[default]
+	Fixed,
+	/// The `n`-th retry attempt is made `n * period` blocks after the previous failure.
+	Linear,
+	/// The `n`-th retry attempt is made `2^(n - 1) * period` blocks after the previous failure,
+	/// but no later than `cap` blocks after it.
+	Exponential { cap: Period },
+	/// The `n`-th retry attempt is made up to `multiplier^(n - 1) * period` blocks after the
+	/// previous failure, but no later than `cap` blocks after it.
+	///
+	/// The attempt is brought forward by up to half of that delay by a pseudo-random jitter
+	/// derived from the address of the task, so that tasks which failed together do not all
+	/// retry together.
+	ExponentialJitter { multiplier: u8, cap: Period },
+}
+
+impl<Period: AtLeast32BitUnsigned + Copy> RetryBackoff<Period> {
+	/// Whether the backoff grows the delay between the retry attempts at all.
+	pub fn is_valid(&self) -> bool {
+		match self {
+			Self::ExponentialJitter { multiplier, .. } => *multiplier > 0,
+			_ => true,
+		}
+	}
+
+	/// The number of blocks to wait before making the retry attempt number `attempt`, starting at
+	/// one, of a task retried every `period` blocks.
+	///
+	/// The `jitter` is a pseudo-random number by which jittered backoffs spread the attempts.
+	pub fn delay(&self, period: Period, attempt: u8, jitter: u32) -> Period {
+		let attempt = attempt.max(1);
+		match self {
+			Self::Fixed => period,
+			Self::Linear => period.saturating_mul(attempt.into()),
+			Self::Exponential { cap } => {
+				let factor = Period::from(2u8).saturating_pow((attempt - 1) as usize);
+				period.saturating_mul(factor).min((*cap).max(period))
+			},
+			Self::ExponentialJitter { multiplier, cap } => {
+				let factor =
+					Period::from((*multiplier).max(1)).saturating_pow((attempt - 1) as usize);
+				let delay = period.saturating_mul(factor).min((*cap).max(period));
+				let spread = (delay / Period::from(2u8)).saturating_add(Period::from(1u8));
+				delay.saturating_sub(Period::from(jitter) % spread)
+			},
+		}
+	}
+}
+
+/// A calendar-style rule by which the renewals of a periodic task are placed in time rather than
+/// a fixed number of blocks apart.
+///
+/// The next moment is evaluated on every renewal, so the renewals stay aligned to the wall clock
+/// even when the block time changes.
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum Recurrence {
+	/// Renew at every moment which is `offset` milliseconds past a multiple of `period`
+	/// milliseconds since the Unix epoch.
+	///
+	/// E.g. a `period` of one day and an `offset` of six hours renews daily at 06:00 UTC.
+	Every { period: u64, offset: u64 },
+}
+
+impl Recurrence {
+	/// Whether the recurrence describes any moments at all.
+	pub fn is_valid(&self) -> bool {
+		match self {
+			Self::Every { period, .. } => *period > 0,
+		}
+	}
+
+	/// The first moment strictly after `now`, both in milliseconds since the Unix epoch, at
+	/// which the task is due again.
+	pub fn next_after(&self, now: u64) -> u64 {
+		match *self {
+			Self::Every { period, offset } => {
+				let period = period.max(1);
+				let next = now.saturating_sub(now % period).saturating_add(offset % period);
+				if next > now {
+					next
+				} else {
+					next.saturating_add(period)
+				}
+			},
+		}
+	}
+}
+
+/// Summary of a scheduled task, as exposed through the [`runtime_api::SchedulerApi`].
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct TaskSummary<BlockNumber, Hash> {
+	/// The block at which the task is to be executed.
+	pub when: BlockNumber,
+	/// The index of the task in the agenda of `when`.
+	pub index: u32,
+	/// The unique identity of the task, if there is one.
+	pub maybe_id: Option<TaskName>,
+	/// The task's priority.
+	pub priority: schedule::Priority,
+	/// The hash of the call to be dispatched.
+	pub call_hash: Hash,
+	/// If the task is periodic, its period and the number of remaining executions.
+	pub maybe_periodic: Option<schedule::Period<BlockNumber>>,
+	/// The retry configuration of the task, if there is one.
+	pub retry: Option<RetryConfig<BlockNumber>>,
+	/// The backoff strategy of the retries of the task, if it is not fixed.
+	pub retry_backoff: Option<RetryBackoff<BlockNumber>>,
+	/// The rule by which the renewals of the task are placed, if it is not renewed every period.
+	pub recurrence: Option<Recurrence>,
+	/// The moment in milliseconds since the Unix epoch the task waits for, if it is time-based.
+	pub maybe_moment: Option<u64>,
+	/// Whether the task is paused.
+	pub paused: bool,
+	/// The last block at which the task may still be executed, if it expires.
+	pub valid_until: Option<BlockNumber>,
+}
+
+pub type TaskSummaryOf<T> = TaskSummary<BlockNumberFor<T>, <T as frame_system::Config>::Hash>;
+
+/// How full the agenda of a block is, as exposed through the [`runtime_api::SchedulerApi`].
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct AgendaOccupancy {
+	/// The number of tasks scheduled in the agenda.
+	pub scheduled: u32,
+	/// The maximum number of tasks the agenda can hold, see [`Config::MaxScheduledPerBlock`].
+	pub capacity: u32,
+}
+
+/// The name of the task being dispatched, kept in the dispatch context of the call of the task.
+#[derive(Default)]
+struct DispatchingTask(Option<TaskName>);
+
+/// The quota slot and deposit reserved on behalf of a scheduled task.
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct Reservation<PalletsOrigin, AccountId, Balance> {
+	/// The origin whose quota the task counts against.
+	pub origin: PalletsOrigin,
+	/// The account from which the deposit is held and its amount, if any.
+	pub deposit: Option<(AccountId, Balance)>,
+}
+
+pub type ReservationOf<T> =
+	Reservation<<T as Config>::PalletsOrigin, <T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+/// A task waiting for the successful dispatch of the task it depends on.
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct Dependant<BlockNumber, Task, Reservation> {
+	/// The number of blocks after the successful dispatch at which the task is executed.
+	pub delay: BlockNumber,
+	/// The task to be placed into the agenda.
+	pub task: Task,
+	/// The quota slot and deposit reserved on behalf of the task.
+	pub reservation: Reservation,
+}
+
+pub type DependantOf<T> = Dependant<BlockNumberFor<T>, ScheduledOf<T>, ReservationOf<T>>;
+
+/// A periodic or retry task which could not be placed since the agenda it was due in was full.
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct Overflowed<BlockNumber, Task, Reservation> {
+	/// The block at which the task was due.
+	pub when: BlockNumber,
+	/// The task to be placed into the agenda.
+	pub task: Task,
+	/// The retry configuration of the task, if any.
+	pub retry: Option<RetryConfig<BlockNumber>>,
+	/// The backoff strategy of the retries of the task, if any.
+	pub backoff: Option<RetryBackoff<BlockNumber>>,
+	/// The rule by which the renewals of the task are placed, if any.
+	pub recurrence: Option<Recurrence>,
+	/// The quota slot and deposit reserved on behalf of the task, if any.
+	pub reservation: Option<Reservation>,
+	/// The last block at which the task may still be executed, if it expires.
+	pub valid_until: Option<BlockNumber>,
+}
+
+pub type OverflowedOf<T> = Overflowed<BlockNumberFor<T>, ScheduledOf<T>, ReservationOf<T>>;
+
 #[cfg_attr(any(feature = "std", test), derive(PartialEq, Eq))]
 #[derive(Clone, RuntimeDebug, Encode, Decode)]
 struct ScheduledV1<Call, BlockNumber> {
@@ -230,7 +462,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -284,16 +516,133 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxScheduledPerBlock: Get<u32>;
 
+		/// Provider for the block number which tasks are scheduled at.
+		///
+		/// Usually this is `frame_system::Pallet<Self>`. Parachains which produce blocks only
+		/// occasionally, e.g. with Agile Coretime, may instead use the block number of the relay
+		/// chain. Since this skips blocks, the agendas of all blocks up to the current block number
+		/// are serviced, looking them up in the [`Queue`] instead of visiting every block.
+		///
+		/// Changing the provider of a live chain changes the numbering of the blocks tasks are
+		/// scheduled at. Remap them with
+		/// [`migration::block_provider_swap::MigrateBlockNumberProvider`] in the same upgrade.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
 		/// The preimage provider with which we look up call hashes to get the call.
 		type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
+
+		/// Whether to reject scheduling a call which can never be executed since its weight does
+		/// not fit into [`Config::MaximumWeight`].
+		///
+		/// If `false`, such a call is still scheduled but a
+		/// [`Event::ScheduledPermanentlyOverweight`] is emitted. The check is only performed for
+		/// calls which are available without a preimage lookup.
+		#[pallet::constant]
+		type RejectOverweight: Get<bool>;
+
+		/// The source of time for tasks scheduled with a time-based [`ScheduleTime`].
+		type TimeProvider: UnixTime;
+
+		/// The longest time in milliseconds which is expected to pass between two blocks.
+		///
+		/// This is used to estimate the earliest block at which a time-based task may become due.
+		/// A task which is not due yet once its block is reached is moved to a later block, so an
+		/// overestimate only results in additional reschedules, while an underestimate may delay
+		/// the task past its dispatch time.
+		#[pallet::constant]
+		type MaximumBlockTime: Get<u64>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// The currency from which deposits for scheduled tasks are held.
+		type Currency: FunHoldMutate<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+		/// The deposit held from a signed origin for each task it schedules, released once the
+		/// task has been executed or canceled.
+		#[pallet::constant]
+		type ScheduleDeposit: Get<BalanceOf<Self>>;
+
+		/// The maximum number of tasks which may be scheduled by a single origin at once.
+		///
+		/// `None` means that there is no limit. Retry attempts of a task do not count against it.
+		#[pallet::constant]
+		type MaxScheduledPerOrigin: Get<Option<u32>>;
+
+		/// The maximum number of blocks into the future a task may be scheduled at.
+		///
+		/// Scheduling a task for a block beyond this horizon fails, unless it is scheduled by root.
+		/// A time-based task is placed at the earliest block it may be due at, as estimated with
+		/// [`Config::MaximumBlockTime`], so its moment may not be further out than the horizon
+		/// multiplied by that time. `None` means that there is no limit. Tasks which are already
+		/// scheduled beyond the horizon are kept and may still be rescheduled to any block up to
+		/// their current one.
+		///
+		/// Other pallets scheduling tasks of non-root origins, like `pallet_referenda`, must make
+		/// sure that they do not exceed the horizon.
+		#[pallet::constant]
+		type MaxSchedulingHorizon: Get<Option<BlockNumberFor<Self>>>;
+
+		/// The maximum number of pending agendas whose tasks are serviced together when catching
+		/// up on agendas which could not be completed in an earlier block.
+		///
+		/// The tasks of the merged agendas are executed strictly by priority, so that a backlog of
+		/// low priority tasks does not delay tasks with a higher priority which became due more
+		/// recently. Tasks of equal priority are executed in the order of their blocks. A value
+		/// of `0` or `1` services the agendas one after another in the order of their blocks.
+		#[pallet::constant]
+		type MaxMergedAgendas: Get<u32>;
+
+		/// The maximum weight which may be used in `on_idle` for catching up on agendas which
+		/// could not be completed in an earlier block.
+		///
+		/// The backlog is serviced with the weight left in the block, but never more than this
+		/// limit. `None` disables servicing agendas in `on_idle`.
+		#[pallet::constant]
+		type MaxIdleWeight: Get<Option<Weight>>;
+
+		/// The maximum number of periodic and retry tasks which are kept in the [`Overflow`]
+		/// after their agenda was full.
+		///
+		/// Tasks which overflow while it is full are dropped.
+		#[pallet::constant]
+		type MaxOverflowed: Get<u32>;
+
+		/// Handler for the fee of executing scheduled calls.
+		///
+		/// The fee is charged for the weight of the dispatch when the call is executed, not when it
+		/// is scheduled. Use `()` to execute scheduled calls free of charge, or
+		/// [`fee::FungibleAdapter`] to charge signed origins.
+		type OnChargeDispatch: OnChargeDispatch<Self>;
+	}
+
+	/// A reason for the scheduler pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are held as deposit for a scheduled task.
+		#[codec(index = 0)]
+		ScheduledTask,
 	}
 
 	#[pallet::storage]
 	pub type IncompleteSince<T: Config> = StorageValue<_, BlockNumberFor<T>>;
 
+	/// The number of times a task was postponed to a later block since there was not enough
+	/// weight left to service it in its block.
+	#[pallet::storage]
+	pub type PostponedCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The blocks with pending agendas, keyed by their [`QueueKey`] so that iterating the keys
+	/// yields the blocks in ascending order.
+	///
+	/// Used to find the next agendas to service without visiting every block, which matters when
+	/// the [`Config::BlockNumberProvider`] skips blocks.
+	#[pallet::storage]
+	pub type Queue<T: Config> = StorageMap<_, Identity, QueueKey, ()>;
+
 	/// Items to be executed, indexed by the block number that they should be executed on.
 	#[pallet::storage]
 	pub type Agenda<T: Config> = StorageMap<
@@ -314,6 +663,18 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Backoff strategies of the retries of tasks, indexed by task address.
+	///
+	/// Tasks without an entry are retried at a fixed period.
+	#[pallet::storage]
+	pub type RetryBackoffs<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		TaskAddress<BlockNumberFor<T>>,
+		RetryBackoff<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	/// Lookup from a name to the block number and index of the task.
 	///
 	/// For v3 -> v4 the previously unbounded identities are Blake2-256 hashed to form the v4
@@ -322,6 +683,81 @@ pub mod pallet {
 	pub(crate) type Lookup<T: Config> =
 		StorageMap<_, Twox64Concat, TaskName, TaskAddress<BlockNumberFor<T>>>;
 
+	/// Addresses of the tasks which are paused and will not be executed until resumed.
+	#[pallet::storage]
+	pub type Paused<T: Config> =
+		StorageMap<_, Blake2_128Concat, TaskAddress<BlockNumberFor<T>>, (), OptionQuery>;
+
+	/// Moments in milliseconds since the Unix epoch that time-based tasks must wait for before
+	/// being executed, indexed by task address.
+	#[pallet::storage]
+	pub type DispatchMoments<T: Config> =
+		StorageMap<_, Blake2_128Concat, TaskAddress<BlockNumberFor<T>>, u64, OptionQuery>;
+
+	/// Rules by which the renewals of periodic tasks are placed, indexed by task address.
+	///
+	/// Periodic tasks without an entry are renewed every period blocks.
+	#[pallet::storage]
+	pub type Recurrences<T: Config> =
+		StorageMap<_, Blake2_128Concat, TaskAddress<BlockNumberFor<T>>, Recurrence, OptionQuery>;
+
+	/// The last blocks at which tasks may still be executed, indexed by task address.
+	///
+	/// Tasks which are still pending after this block, e.g. since they kept being postponed, are
+	/// dropped instead of being executed.
+	#[pallet::storage]
+	pub type ValidUntil<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		TaskAddress<BlockNumberFor<T>>,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// The number of tasks scheduled by each origin which count against its quota.
+	#[pallet::storage]
+	pub type ScheduledCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PalletsOrigin, u32, ValueQuery>;
+
+	/// Quota slots and deposits reserved on behalf of scheduled tasks, indexed by task address.
+	#[pallet::storage]
+	pub type Reservations<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		TaskAddress<BlockNumberFor<T>>,
+		ReservationOf<T>,
+		OptionQuery,
+	>;
+
+	/// Named tasks waiting for the successful dispatch of the task at the given address.
+	#[pallet::storage]
+	pub type Dependants<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		TaskAddress<BlockNumberFor<T>>,
+		Twox64Concat,
+		TaskName,
+		DependantOf<T>,
+		OptionQuery,
+	>;
+
+	/// Lookup from the name of a waiting task to the address of the task it depends on.
+	#[pallet::storage]
+	pub type DependsOn<T: Config> =
+		StorageMap<_, Twox64Concat, TaskName, TaskAddress<BlockNumberFor<T>>>;
+
+	/// Periodic and retry tasks which could not be placed since the agenda they were due in was
+	/// full, indexed by overflow index.
+	///
+	/// They can be placed again by anyone through [`Pallet::reschedule_overflowed`]. There are at
+	/// most [`Config::MaxOverflowed`] of them.
+	#[pallet::storage]
+	pub type Overflow<T: Config> = CountedStorageMap<_, Twox64Concat, u32, OverflowedOf<T>>;
+
+	/// The index of the next task to be added to [`Overflow`].
+	#[pallet::storage]
+	pub type NextOverflowIndex<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	/// Events type.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -343,17 +779,72 @@ pub mod pallet {
 			period: BlockNumberFor<T>,
 			retries: u8,
 		},
+		/// Set a retry backoff strategy for some task.
+		RetryBackoffSet {
+			task: TaskAddress<BlockNumberFor<T>>,
+			id: Option<TaskName>,
+			backoff: RetryBackoff<BlockNumberFor<T>>,
+		},
 		/// Cancel a retry configuration for some task.
 		RetryCancelled { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
 		/// The call for the provided hash was not found so the task has been aborted.
 		CallUnavailable { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
-		/// The given task was unable to be renewed since the agenda is full at that block.
+		/// The given task was unable to be renewed since the agenda is full at that block, so it
+		/// was moved to the overflow, or dropped if the overflow is full.
 		PeriodicFailed { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
 		/// The given task was unable to be retried since the agenda is full at that block or there
-		/// was not enough weight to reschedule it.
+		/// was not enough weight to reschedule it. In the former case it was moved to the
+		/// overflow, or dropped if the overflow is full.
 		RetryFailed { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
 		/// The given task can never be executed since it is overweight.
 		PermanentlyOverweight { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
+		/// The given task was scheduled but will never be executed since it is overweight.
+		ScheduledPermanentlyOverweight {
+			task: TaskAddress<BlockNumberFor<T>>,
+			id: Option<TaskName>,
+		},
+		/// Paused some task.
+		Paused { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
+		/// Resumed some task, which is now placed at `task`.
+		Resumed { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
+		/// Scheduled some task to be placed once the task at `depends_on` succeeds.
+		DependantScheduled { id: TaskName, depends_on: TaskAddress<BlockNumberFor<T>> },
+		/// The given task was dropped since the task it depends on failed permanently or was
+		/// canceled.
+		DependencyFailed { id: TaskName, depends_on: TaskAddress<BlockNumberFor<T>> },
+		/// The given task was dropped since it could not be placed after the task it depends on
+		/// succeeded, as the agenda is full at that block.
+		DependantScheduleFailed { id: TaskName },
+		/// The renewal or retry of the task at `task` could not be placed since the agenda is full
+		/// and was moved to the overflow at `index`.
+		TaskOverflowed { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName>, index: u32 },
+		/// The overflowed task at `index` was canceled.
+		OverflowedCanceled { index: u32, id: Option<TaskName> },
+		/// The overflowed task at `index` was dropped since its name was taken in the meantime.
+		OverflowedDropped { index: u32, id: TaskName },
+		/// All tasks of an origin were canceled, `count` of them in total.
+		OriginTasksCanceled { count: u32 },
+		/// Set or cleared the recurrence of some periodic task.
+		RecurrenceSet {
+			task: TaskAddress<BlockNumberFor<T>>,
+			id: Option<TaskName>,
+			recurrence: Option<Recurrence>,
+		},
+		/// Serviced the backlog of agendas in `on_idle`, with the agendas of `remaining` blocks
+		/// left to be completed.
+		BacklogServiced { remaining: BlockNumberFor<T> },
+		/// Set or cleared the last block at which some task may still be executed.
+		ValidUntilSet {
+			task: TaskAddress<BlockNumberFor<T>>,
+			id: Option<TaskName>,
+			valid_until: Option<BlockNumberFor<T>>,
+		},
+		/// The given task was dropped since it was still pending after the last block at which
+		/// it could be executed.
+		Expired { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
+		/// The given number of tasks could not be serviced since there was not enough weight left,
+		/// and are retried from block `to` on.
+		Postponed { count: u32, to: BlockNumberFor<T> },
 	}
 
 	#[pallet::error]
@@ -368,16 +859,58 @@ pub mod pallet {
 		RescheduleNoChange,
 		/// Attempt to use a non-named function on a named task.
 		Named,
+		/// The call can never be executed since its weight exceeds the maximum scheduler weight.
+		Overweight,
+		/// The task is already paused.
+		AlreadyPaused,
+		/// The task is not paused.
+		NotPaused,
+		/// Given target moment is in the past.
+		TargetMomentInPast,
+		/// The origin has reached its quota of scheduled tasks.
+		TooManyScheduled,
+		/// The witness data given does not match the current state of the chain.
+		InvalidWitnessData,
+		/// The task is not periodic.
+		NotPeriodic,
+		/// The recurrence does not describe any moments.
+		InvalidRecurrence,
+		/// The backoff does not grow the delay between the retry attempts.
+		InvalidBackoff,
+		/// Given target block number is beyond the scheduling horizon.
+		TargetBeyondHorizon,
+		/// The task would expire before the block it is scheduled at.
+		ExpiresBeforeDispatch,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		/// Execute the scheduled calls
-		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let now = T::BlockNumberProvider::current_block_number();
 			let mut weight_counter = WeightMeter::with_limit(T::MaximumWeight::get());
 			Self::service_agendas(&mut weight_counter, now, u32::max_value());
 			weight_counter.consumed()
 		}
+
+		/// Catch up on the backlog of agendas with the weight left in the block.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let now = T::BlockNumberProvider::current_block_number();
+			let Some(max_weight) = T::MaxIdleWeight::get() else { return Weight::zero() };
+			let mut weight_counter = WeightMeter::with_limit(remaining_weight.min(max_weight));
+			if weight_counter.try_consume(T::DbWeight::get().reads(1)).is_err() ||
+				!IncompleteSince::<T>::exists() ||
+				!weight_counter.can_consume(T::WeightInfo::service_agendas_base())
+			{
+				return weight_counter.consumed()
+			}
+
+			Self::service_agendas(&mut weight_counter, now, u32::max_value());
+			let remaining = IncompleteSince::<T>::get()
+				.map_or(Zero::zero(), |since| now.saturating_add(One::one()).saturating_sub(since));
+			Self::deposit_event(Event::BacklogServiced { remaining });
+			weight_counter.consumed()
+		}
 	}
 
 	#[pallet::call]
@@ -395,7 +928,7 @@ pub mod pallet {
 			T::ScheduleOrigin::ensure_origin(origin.clone())?;
 			let origin = <T as Config>::RuntimeOrigin::from(origin);
 			Self::do_schedule(
-				DispatchTime::At(when),
+				ScheduleTime::At(when),
 				maybe_periodic,
 				priority,
 				origin.caller().clone(),
@@ -429,7 +962,7 @@ pub mod pallet {
 			let origin = <T as Config>::RuntimeOrigin::from(origin);
 			Self::do_schedule_named(
 				id,
-				DispatchTime::At(when),
+				ScheduleTime::At(when),
 				maybe_periodic,
 				priority,
 				origin.caller().clone(),
@@ -461,7 +994,7 @@ pub mod pallet {
 			T::ScheduleOrigin::ensure_origin(origin.clone())?;
 			let origin = <T as Config>::RuntimeOrigin::from(origin);
 			Self::do_schedule(
-				DispatchTime::After(after),
+				ScheduleTime::After(after),
 				maybe_periodic,
 				priority,
 				origin.caller().clone(),
@@ -485,7 +1018,7 @@ pub mod pallet {
 			let origin = <T as Config>::RuntimeOrigin::from(origin);
 			Self::do_schedule_named(
 				id,
-				DispatchTime::After(after),
+				ScheduleTime::After(after),
 				maybe_periodic,
 				priority,
 				origin.caller().clone(),
@@ -598,73 +1131,565 @@ pub mod pallet {
 			Self::deposit_event(Event::RetryCancelled { task, id: Some(id) });
 			Ok(())
 		}
-	}
-}
-
-impl<T: Config> Pallet<T> {
-	/// Migrate storage format from V1 to V4.
-	///
-	/// Returns the weight consumed by this migration.
-	pub fn migrate_v1_to_v4() -> Weight {
-		use migration::v1 as old;
-		let mut weight = T::DbWeight::get().reads_writes(1, 1);
 
-		// Delete all undecodable values.
-		// `StorageMap::translate` is not enough since it just skips them and leaves the keys in.
-		let keys = old::Agenda::<T>::iter_keys().collect::<Vec<_>>();
-		for key in keys {
-			weight.saturating_accrue(T::DbWeight::get().reads(1));
-			if let Err(_) = old::Agenda::<T>::try_get(&key) {
-				weight.saturating_accrue(T::DbWeight::get().writes(1));
-				old::Agenda::<T>::remove(&key);
-				log::warn!("Deleted undecodable agenda");
-			}
+		/// Pause a task, so that it is not executed until it is resumed.
+		///
+		/// The task keeps its place in the agenda along with its retry configuration.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::pause())]
+		pub fn pause(origin: OriginFor<T>, task: TaskAddress<BlockNumberFor<T>>) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_pause(origin.caller(), task)?;
+			Self::deposit_event(Event::Paused { task, id: None });
+			Ok(())
 		}
 
-		Agenda::<T>::translate::<
-			Vec<Option<ScheduledV1<<T as Config>::RuntimeCall, BlockNumberFor<T>>>>,
-			_,
-		>(|_, agenda| {
-			Some(BoundedVec::truncate_from(
-				agenda
-					.into_iter()
-					.map(|schedule| {
-						weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+		/// Pause a named task, so that it is not executed until it is resumed.
+		///
+		/// The task keeps its place in the agenda along with its retry configuration.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::pause_named())]
+		pub fn pause_named(origin: OriginFor<T>, id: TaskName) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let task = Lookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+			Self::do_pause(origin.caller(), task)?;
+			Self::deposit_event(Event::Paused { task, id: Some(id) });
+			Ok(())
+		}
 
-						schedule.and_then(|schedule| {
-							if let Some(id) = schedule.maybe_id.as_ref() {
-								let name = blake2_256(id);
-								if let Some(item) = old::Lookup::<T>::take(id) {
-									Lookup::<T>::insert(name, item);
-								}
-								weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
-							}
+		/// Resume a paused task.
+		///
+		/// If the block the task was scheduled for has passed while it was paused, the task is
+		/// placed into the agenda of the next block.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::resume(T::MaxScheduledPerBlock::get()))]
+		pub fn resume(
+			origin: OriginFor<T>,
+			task: TaskAddress<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let task = Self::do_resume(origin.caller(), task)?;
+			Self::deposit_event(Event::Resumed { task, id: None });
+			Ok(())
+		}
 
-							let call = T::Preimages::bound(schedule.call).ok()?;
+		/// Resume a paused named task.
+		///
+		/// If the block the task was scheduled for has passed while it was paused, the task is
+		/// placed into the agenda of the next block.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::resume_named(T::MaxScheduledPerBlock::get()))]
+		pub fn resume_named(origin: OriginFor<T>, id: TaskName) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let task = Lookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+			let task = Self::do_resume(origin.caller(), task)?;
+			Self::deposit_event(Event::Resumed { task, id: Some(id) });
+			Ok(())
+		}
 
-							if call.lookup_needed() {
-								weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 1));
-							}
+		/// Set the strategy by which the time between the retry attempts of a task grows.
+		///
+		/// The task must have a retry configuration, which is set through [`Pallet::set_retry`].
+		/// The `period` of the retry configuration is used as the base of the `backoff`.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_retry_backoff())]
+		pub fn set_retry_backoff(
+			origin: OriginFor<T>,
+			task: TaskAddress<BlockNumberFor<T>>,
+			backoff: RetryBackoff<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_set_retry_backoff(origin.caller(), task, backoff)?;
+			Self::deposit_event(Event::RetryBackoffSet { task, id: None, backoff });
+			Ok(())
+		}
 
-							Some(Scheduled {
-								maybe_id: schedule.maybe_id.map(|x| blake2_256(&x[..])),
-								priority: schedule.priority,
-								call,
-								maybe_periodic: schedule.maybe_periodic,
-								origin: system::RawOrigin::Root.into(),
-								_phantom: Default::default(),
-							})
-						})
-					})
-					.collect::<Vec<_>>(),
-			))
-		});
+		/// Set the strategy by which the time between the retry attempts of a named task grows.
+		///
+		/// The task must have a retry configuration, which is set through
+		/// [`Pallet::set_retry_named`]. The `period` of the retry configuration is used as the
+		/// base of the `backoff`.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_retry_backoff_named())]
+		pub fn set_retry_backoff_named(
+			origin: OriginFor<T>,
+			id: TaskName,
+			backoff: RetryBackoff<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let task = Lookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+			Self::do_set_retry_backoff(origin.caller(), task, backoff)?;
+			Self::deposit_event(Event::RetryBackoffSet { task, id: Some(id), backoff });
+			Ok(())
+		}
 
-		#[allow(deprecated)]
-		frame_support::storage::migration::remove_storage_prefix(
-			Self::name().as_bytes(),
-			b"StorageVersion",
-			&[],
+		/// Schedule a named task to be executed `after` blocks once the named task `depends_on`
+		/// has been dispatched successfully.
+		///
+		/// If the task it depends on fails without being retried or renewed, or is canceled, the
+		/// task is dropped and [`Event::DependencyFailed`] is emitted.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_named_after_task())]
+		pub fn schedule_named_after_task(
+			origin: OriginFor<T>,
+			id: TaskName,
+			depends_on: TaskName,
+			after: BlockNumberFor<T>,
+			maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_schedule_named_after_task(
+				id,
+				depends_on,
+				after,
+				maybe_periodic,
+				priority,
+				origin.caller().clone(),
+				T::Preimages::bound(*call)?,
+			)?;
+			Ok(())
+		}
+
+		/// Place a task which overflowed its agenda into the agenda of the block it was due in,
+		/// or of the next block if that has passed.
+		///
+		/// If the name of the task was taken while it was in the overflow, the task is dropped
+		/// instead. Can be called by any signed origin.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::reschedule_overflowed(T::MaxScheduledPerBlock::get()))]
+		pub fn reschedule_overflowed(origin: OriginFor<T>, index: u32) -> DispatchResult {
+			ensure_signed_or_root(origin)?;
+			Self::do_reschedule_overflowed(index)?;
+			Ok(())
+		}
+
+		/// Cancel all tasks scheduled with the origin `target`, including tasks waiting for
+		/// another task and tasks in the overflow.
+		///
+		/// `witness` must be at least the number of agendas, waiting tasks and overflowed tasks,
+		/// which are all scanned.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_all_for_origin(*witness))]
+		pub fn cancel_all_for_origin(
+			origin: OriginFor<T>,
+			target: Box<T::PalletsOrigin>,
+			witness: u32,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_cancel_all_for_origin(Some(origin.caller().clone()), *target, witness)?;
+			Ok(())
+		}
+
+		/// Set the rule by which the renewals of a periodic task are placed, or clear it with
+		/// `None` to renew the task every period blocks again.
+		///
+		/// The period of the task still bounds the number of renewals, but each renewal is
+		/// placed at the next moment of the `recurrence` instead of a period after the last one.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_recurrence())]
+		pub fn set_recurrence(
+			origin: OriginFor<T>,
+			task: TaskAddress<BlockNumberFor<T>>,
+			recurrence: Option<Recurrence>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_set_recurrence(origin.caller(), task, recurrence)?;
+			Self::deposit_event(Event::RecurrenceSet { task, id: None, recurrence });
+			Ok(())
+		}
+
+		/// Set the rule by which the renewals of a named periodic task are placed, or clear it
+		/// with `None` to renew the task every period blocks again.
+		///
+		/// See [`Pallet::set_recurrence`].
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_recurrence_named())]
+		pub fn set_recurrence_named(
+			origin: OriginFor<T>,
+			id: TaskName,
+			recurrence: Option<Recurrence>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let task = Lookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+			Self::do_set_recurrence(origin.caller(), task, recurrence)?;
+			Self::deposit_event(Event::RecurrenceSet { task, id: Some(id), recurrence });
+			Ok(())
+		}
+
+		/// Move an anonymously scheduled task to `new_time`.
+		///
+		/// The task keeps its retry configuration and any other settings made for it.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::reschedule(T::MaxScheduledPerBlock::get()))]
+		pub fn reschedule(
+			origin: OriginFor<T>,
+			when: BlockNumberFor<T>,
+			index: u32,
+			new_time: ScheduleTime<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_reschedule(Some(origin.caller().clone()), (when, index), new_time)?;
+			Ok(())
+		}
+
+		/// Move a named task to `new_time`.
+		///
+		/// The task keeps its retry configuration and any other settings made for it.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::reschedule_named(T::MaxScheduledPerBlock::get()))]
+		pub fn reschedule_named(
+			origin: OriginFor<T>,
+			id: TaskName,
+			new_time: ScheduleTime<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_reschedule_named(Some(origin.caller().clone()), id, new_time)?;
+			Ok(())
+		}
+
+		/// Anonymously schedule a task which is dispatched from the account derived from the
+		/// scheduling origin and `index`, rather than from the scheduling origin itself.
+		///
+		/// The account is given by [`Pallet::derivative_account_id`]. Since the task is scheduled
+		/// with the signed origin of that account, it can only be canceled or changed by origins
+		/// with at least its privilege.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule(T::MaxScheduledPerBlock::get()))]
+		pub fn schedule_as_derivative(
+			origin: OriginFor<T>,
+			index: u16,
+			when: BlockNumberFor<T>,
+			maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let who = Self::derivative_account_id(origin.caller(), index);
+			Self::do_schedule(
+				ScheduleTime::At(when),
+				maybe_periodic,
+				priority,
+				system::RawOrigin::Signed(who).into(),
+				T::Preimages::bound(*call)?,
+			)?;
+			Ok(())
+		}
+
+		/// Schedule a named task which is dispatched from the account derived from the scheduling
+		/// origin and `index`.
+		///
+		/// See [`Pallet::schedule_as_derivative`].
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_named(T::MaxScheduledPerBlock::get()))]
+		pub fn schedule_named_as_derivative(
+			origin: OriginFor<T>,
+			id: TaskName,
+			index: u16,
+			when: BlockNumberFor<T>,
+			maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let who = Self::derivative_account_id(origin.caller(), index);
+			Self::do_schedule_named(
+				id,
+				ScheduleTime::At(when),
+				maybe_periodic,
+				priority,
+				system::RawOrigin::Signed(who).into(),
+				T::Preimages::bound(*call)?,
+			)?;
+			Ok(())
+		}
+
+		/// Set the last block at which a task may still be executed, or clear it with `None` to
+		/// keep the task until it is executed.
+		///
+		/// A task which is still pending after `valid_until`, e.g. since it kept being postponed
+		/// for lack of weight, is dropped and [`Event::Expired`] is emitted. The expiry also
+		/// applies to the renewals and retries of the task. `valid_until` may not be before the
+		/// block the task is scheduled at.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_valid_until())]
+		pub fn set_valid_until(
+			origin: OriginFor<T>,
+			task: TaskAddress<BlockNumberFor<T>>,
+			valid_until: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_set_valid_until(origin.caller(), task, valid_until)?;
+			Self::deposit_event(Event::ValidUntilSet { task, id: None, valid_until });
+			Ok(())
+		}
+
+		/// Set the last block at which a named task may still be executed, or clear it with
+		/// `None`.
+		///
+		/// See [`Pallet::set_valid_until`].
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_valid_until_named())]
+		pub fn set_valid_until_named(
+			origin: OriginFor<T>,
+			id: TaskName,
+			valid_until: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let task = Lookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+			Self::do_set_valid_until(origin.caller(), task, valid_until)?;
+			Self::deposit_event(Event::ValidUntilSet { task, id: Some(id), valid_until });
+			Ok(())
+		}
+
+		/// Schedule a named task, replacing the task scheduled under the same name if there is
+		/// one.
+		///
+		/// The replaced task is canceled, which requires the origin to have at least its
+		/// privilege. If the new task can not be scheduled, the replaced task is kept. Tasks
+		/// waiting for another task under the same name are not replaced.
+		#[pallet::call_index(27)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::cancel_named(T::MaxScheduledPerBlock::get())
+				.saturating_add(<T as Config>::WeightInfo::schedule_named(
+					T::MaxScheduledPerBlock::get()
+				))
+		)]
+		pub fn schedule_named_upsert(
+			origin: OriginFor<T>,
+			id: TaskName,
+			when: BlockNumberFor<T>,
+			maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			if Lookup::<T>::contains_key(&id) {
+				Self::do_cancel_named(Some(origin.caller().clone()), id)?;
+			}
+			Self::do_schedule_named(
+				id,
+				ScheduleTime::At(when),
+				maybe_periodic,
+				priority,
+				origin.caller().clone(),
+				T::Preimages::bound(*call)?,
+			)?;
+			Ok(())
+		}
+
+		/// Cancel a task which overflowed its agenda.
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_overflowed())]
+		pub fn cancel_overflowed(origin: OriginFor<T>, index: u32) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_cancel_overflowed(Some(origin.caller().clone()), index)?;
+			Ok(())
+		}
+
+		/// Anonymously schedule a task which may not be executed after `valid_until`.
+		///
+		/// See [`Pallet::set_valid_until`].
+		#[pallet::call_index(29)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::schedule(T::MaxScheduledPerBlock::get())
+				.saturating_add(<T as Config>::WeightInfo::set_valid_until())
+		)]
+		pub fn schedule_with_expiry(
+			origin: OriginFor<T>,
+			when: BlockNumberFor<T>,
+			maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
+			priority: schedule::Priority,
+			valid_until: BlockNumberFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			ensure!(valid_until >= when, Error::<T>::ExpiresBeforeDispatch);
+			let task = Self::do_schedule(
+				ScheduleTime::At(when),
+				maybe_periodic,
+				priority,
+				origin.caller().clone(),
+				T::Preimages::bound(*call)?,
+			)?;
+			ValidUntil::<T>::insert(task, valid_until);
+			Self::deposit_event(Event::ValidUntilSet {
+				task,
+				id: None,
+				valid_until: Some(valid_until),
+			});
+			Ok(())
+		}
+
+		/// Schedule a named task which may not be executed after `valid_until`.
+		///
+		/// See [`Pallet::set_valid_until`].
+		#[pallet::call_index(30)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::schedule_named(T::MaxScheduledPerBlock::get())
+				.saturating_add(<T as Config>::WeightInfo::set_valid_until())
+		)]
+		pub fn schedule_named_with_expiry(
+			origin: OriginFor<T>,
+			id: TaskName,
+			when: BlockNumberFor<T>,
+			maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
+			priority: schedule::Priority,
+			valid_until: BlockNumberFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			ensure!(valid_until >= when, Error::<T>::ExpiresBeforeDispatch);
+			let task = Self::do_schedule_named(
+				id,
+				ScheduleTime::At(when),
+				maybe_periodic,
+				priority,
+				origin.caller().clone(),
+				T::Preimages::bound(*call)?,
+			)?;
+			ValidUntil::<T>::insert(task, valid_until);
+			Self::deposit_event(Event::ValidUntilSet {
+				task,
+				id: Some(id),
+				valid_until: Some(valid_until),
+			});
+			Ok(())
+		}
+
+		/// Anonymously schedule a task at `when`, which may also be a moment in time.
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule(T::MaxScheduledPerBlock::get()))]
+		pub fn schedule_at_time(
+			origin: OriginFor<T>,
+			when: ScheduleTime<BlockNumberFor<T>>,
+			maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_schedule(
+				when,
+				maybe_periodic,
+				priority,
+				origin.caller().clone(),
+				T::Preimages::bound(*call)?,
+			)?;
+			Ok(())
+		}
+
+		/// Schedule a named task at `when`, which may also be a moment in time.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_named(T::MaxScheduledPerBlock::get()))]
+		pub fn schedule_named_at_time(
+			origin: OriginFor<T>,
+			id: TaskName,
+			when: ScheduleTime<BlockNumberFor<T>>,
+			maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_schedule_named(
+				id,
+				when,
+				maybe_periodic,
+				priority,
+				origin.caller().clone(),
+				T::Preimages::bound(*call)?,
+			)?;
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Migrate storage format from V1 to V4.
+	///
+	/// Returns the weight consumed by this migration.
+	pub fn migrate_v1_to_v4() -> Weight {
+		use migration::v1 as old;
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+		// Delete all undecodable values.
+		// `StorageMap::translate` is not enough since it just skips them and leaves the keys in.
+		let keys = old::Agenda::<T>::iter_keys().collect::<Vec<_>>();
+		for key in keys {
+			weight.saturating_accrue(T::DbWeight::get().reads(1));
+			if let Err(_) = old::Agenda::<T>::try_get(&key) {
+				weight.saturating_accrue(T::DbWeight::get().writes(1));
+				old::Agenda::<T>::remove(&key);
+				log::warn!("Deleted undecodable agenda");
+			}
+		}
+
+		Agenda::<T>::translate::<
+			Vec<Option<ScheduledV1<<T as Config>::RuntimeCall, BlockNumberFor<T>>>>,
+			_,
+		>(|_, agenda| {
+			Some(BoundedVec::truncate_from(
+				agenda
+					.into_iter()
+					.map(|schedule| {
+						weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+						schedule.and_then(|schedule| {
+							if let Some(id) = schedule.maybe_id.as_ref() {
+								let name = blake2_256(id);
+								if let Some(item) = old::Lookup::<T>::take(id) {
+									Lookup::<T>::insert(name, item);
+								}
+								weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+							}
+
+							let call = T::Preimages::bound(schedule.call).ok()?;
+
+							if call.lookup_needed() {
+								weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 1));
+							}
+
+							Some(Scheduled {
+								maybe_id: schedule.maybe_id.map(|x| blake2_256(&x[..])),
+								priority: schedule.priority,
+								call,
+								maybe_periodic: schedule.maybe_periodic,
+								origin: system::RawOrigin::Root.into(),
+								_phantom: Default::default(),
+							})
+						})
+					})
+					.collect::<Vec<_>>(),
+			))
+		});
+
+		#[allow(deprecated)]
+		frame_support::storage::migration::remove_storage_prefix(
+			Self::name().as_bytes(),
+			b"StorageVersion",
+			&[],
 		);
 
 		StorageVersion::new(4).put::<Self>();
@@ -883,23 +1908,74 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	/// Resolve the block at which a task should be placed, along with the moment the task must
+	/// wait for in case of a time-based dispatch time.
 	fn resolve_time(
-		when: DispatchTime<BlockNumberFor<T>>,
-	) -> Result<BlockNumberFor<T>, DispatchError> {
-		let now = frame_system::Pallet::<T>::block_number();
+		when: ScheduleTime<BlockNumberFor<T>>,
+	) -> Result<(BlockNumberFor<T>, Option<u64>), DispatchError> {
+		let now = T::BlockNumberProvider::current_block_number();
 
 		let when = match when {
-			DispatchTime::At(x) => x,
+			ScheduleTime::At(x) => x,
 			// The current block has already completed it's scheduled tasks, so
 			// Schedule the task at lest one block after this current block.
-			DispatchTime::After(x) => now.saturating_add(x).saturating_add(One::one()),
+			ScheduleTime::After(x) => now.saturating_add(x).saturating_add(One::one()),
+			ScheduleTime::AtMoment(x) => return Self::resolve_moment(now, x),
+			ScheduleTime::AfterMoment(x) =>
+				return Self::resolve_moment(now, Self::current_moment().saturating_add(x)),
 		};
 
 		if when <= now {
 			return Err(Error::<T>::TargetBlockNumberInPast.into())
 		}
 
-		Ok(when)
+		Ok((when, None))
+	}
+
+	/// Ensure that a task of `origin` may be placed at the block `when`, as per
+	/// [`Config::MaxSchedulingHorizon`].
+	///
+	/// Root is exempt. A task which is already placed at `current` may be moved to any block up to
+	/// it, even if the horizon was lowered since it was scheduled.
+	fn ensure_within_horizon(
+		origin: Option<&T::PalletsOrigin>,
+		when: BlockNumberFor<T>,
+		current: Option<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let Some(horizon) = T::MaxSchedulingHorizon::get() else { return Ok(()) };
+		if origin.map_or(true, |o| matches!(o.as_system_ref(), Some(RawOrigin::Root))) {
+			return Ok(())
+		}
+		let limit = T::BlockNumberProvider::current_block_number().saturating_add(horizon);
+		ensure!(
+			when <= limit || current.map_or(false, |current| when <= current),
+			Error::<T>::TargetBeyondHorizon
+		);
+		Ok(())
+	}
+
+	/// The current moment in milliseconds since the Unix epoch.
+	fn current_moment() -> u64 {
+		T::TimeProvider::now().as_millis().saturated_into()
+	}
+
+	fn resolve_moment(
+		now: BlockNumberFor<T>,
+		moment: u64,
+	) -> Result<(BlockNumberFor<T>, Option<u64>), DispatchError> {
+		ensure!(moment >= Self::current_moment(), Error::<T>::TargetMomentInPast);
+		Ok((Self::earliest_block_for(now, moment), Some(moment)))
+	}
+
+	/// Estimate the earliest block after `now` at which `moment` may have passed.
+	fn earliest_block_for(now: BlockNumberFor<T>, moment: u64) -> BlockNumberFor<T> {
+		// No more than `MaximumBlockTime` passes per block, so the moment can not have passed
+		// before this many blocks were produced.
+		let blocks = moment
+			.saturating_sub(Self::current_moment())
+			.checked_div(T::MaximumBlockTime::get())
+			.unwrap_or_default();
+		now.saturating_add(One::one()).saturating_add(blocks.saturated_into())
 	}
 
 	fn place_task(
@@ -921,6 +1997,9 @@ impl<T: Config> Pallet<T> {
 		what: ScheduledOf<T>,
 	) -> Result<u32, (DispatchError, ScheduledOf<T>)> {
 		let mut agenda = Agenda::<T>::get(when);
+		if agenda.is_empty() {
+			Self::enqueue(when);
+		}
 		let index = if (agenda.len() as u32) < T::MaxScheduledPerBlock::get() {
 			// will always succeed due to the above check.
 			let _ = agenda.try_push(Some(what));
@@ -937,6 +2016,66 @@ impl<T: Config> Pallet<T> {
 		Ok(index)
 	}
 
+	/// Count a new task against the quota of `origin` and hold the deposit for it if the origin is
+	/// signed.
+	fn reserve(origin: &T::PalletsOrigin) -> Result<ReservationOf<T>, DispatchError> {
+		let count = ScheduledCount::<T>::get(origin);
+		if let Some(max) = T::MaxScheduledPerOrigin::get() {
+			ensure!(count < max, Error::<T>::TooManyScheduled);
+		}
+		let amount = T::ScheduleDeposit::get();
+		let deposit = match origin.as_system_ref() {
+			Some(RawOrigin::Signed(who)) if !amount.is_zero() => {
+				T::Currency::hold(&HoldReason::ScheduledTask.into(), who, amount)?;
+				Some((who.clone(), amount))
+			},
+			_ => None,
+		};
+		ScheduledCount::<T>::insert(origin, count.saturating_add(1));
+		Ok(Reservation { origin: origin.clone(), deposit })
+	}
+
+	/// Place a newly scheduled task, counting it against the quota of its origin and holding the
+	/// deposit for it if the origin is signed.
+	fn reserve_and_place(
+		when: BlockNumberFor<T>,
+		what: ScheduledOf<T>,
+	) -> Result<TaskAddress<BlockNumberFor<T>>, DispatchError> {
+		let reservation = Self::reserve(&what.origin)?;
+		match Self::place_task(when, what) {
+			Ok(address) => {
+				Reservations::<T>::insert(address, reservation);
+				Ok(address)
+			},
+			Err((error, _)) => {
+				Self::unreserve(reservation);
+				Err(error)
+			},
+		}
+	}
+
+	/// Release the quota slot and deposit reserved on behalf of the task at `address`, if any.
+	fn release_reservation(address: TaskAddress<BlockNumberFor<T>>) {
+		if let Some(reservation) = Reservations::<T>::take(address) {
+			Self::unreserve(reservation);
+		}
+	}
+
+	/// Release the given quota slot and deposit.
+	fn unreserve(Reservation { origin, deposit }: ReservationOf<T>) {
+		let count = ScheduledCount::<T>::get(&origin).saturating_sub(1);
+		if count.is_zero() {
+			ScheduledCount::<T>::remove(&origin);
+		} else {
+			ScheduledCount::<T>::insert(&origin, count);
+		}
+		if let Some((who, amount)) = deposit {
+			// The deposit might have been slashed in the meantime, so release whatever is left.
+			let _ =
+				T::Currency::release(&HoldReason::ScheduledTask.into(), &who, amount, BestEffort);
+		}
+	}
+
 	/// Remove trailing `None` items of an agenda at `when`. If all items are `None` remove the
 	/// agenda record entirely.
 	fn cleanup_agenda(when: BlockNumberFor<T>) {
@@ -949,18 +2088,42 @@ impl<T: Config> Pallet<T> {
 			Some(_) => {},
 			None => {
 				Agenda::<T>::remove(when);
+				Self::dequeue(&[when]);
 			},
 		}
 	}
 
+	/// The key of the block `when` in the [`Queue`].
+	fn queue_key(when: BlockNumberFor<T>) -> QueueKey {
+		when.saturated_into::<u128>().to_be_bytes()
+	}
+
+	/// The block of the [`Queue`] entry `key`.
+	fn queued_block(key: QueueKey) -> BlockNumberFor<T> {
+		u128::from_be_bytes(key).saturated_into()
+	}
+
+	/// Add `when` to the [`Queue`] of blocks with pending agendas.
+	fn enqueue(when: BlockNumberFor<T>) {
+		Queue::<T>::insert(Self::queue_key(when), ());
+	}
+
+	/// Remove the blocks `whens` from the [`Queue`] of blocks with pending agendas.
+	fn dequeue(whens: &[BlockNumberFor<T>]) {
+		for when in whens {
+			Queue::<T>::remove(Self::queue_key(*when));
+		}
+	}
+
 	fn do_schedule(
-		when: DispatchTime<BlockNumberFor<T>>,
+		when: ScheduleTime<BlockNumberFor<T>>,
 		maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
 		priority: schedule::Priority,
 		origin: T::PalletsOrigin,
 		call: BoundedCallOf<T>,
 	) -> Result<TaskAddress<BlockNumberFor<T>>, DispatchError> {
-		let when = Self::resolve_time(when)?;
+		let (when, maybe_moment) = Self::resolve_time(when)?;
+		Self::ensure_within_horizon(Some(&origin), when, None)?;
 
 		let lookup_hash = call.lookup_hash();
 
@@ -977,7 +2140,14 @@ impl<T: Config> Pallet<T> {
 			origin,
 			_phantom: PhantomData,
 		};
-		let res = Self::place_task(when, task).map_err(|x| x.0)?;
+		let overweight = Self::check_overweight(&task)?;
+		let res = Self::reserve_and_place(when, task)?;
+		if let Some(moment) = maybe_moment {
+			DispatchMoments::<T>::insert(res, moment);
+		}
+		if overweight {
+			Self::deposit_event(Event::ScheduledPermanentlyOverweight { task: res, id: None });
+		}
 
 		if let Some(hash) = lookup_hash {
 			// Request the call to be made available.
@@ -987,6 +2157,42 @@ impl<T: Config> Pallet<T> {
 		Ok(res)
 	}
 
+	/// Check whether the given task can never be executed since the weight of its call together
+	/// with the overhead of servicing it exceeds [`Config::MaximumWeight`].
+	///
+	/// Returns an error if such a task should be rejected as per [`Config::RejectOverweight`],
+	/// otherwise returns whether the task is overweight. Calls which require a preimage lookup are
+	/// never considered overweight here, since they might not be available yet.
+	fn check_overweight(task: &ScheduledOf<T>) -> Result<bool, DispatchError> {
+		if task.call.lookup_needed() {
+			return Ok(false)
+		}
+		let call = match T::Preimages::peek(&task.call) {
+			Ok((call, _)) => call,
+			Err(_) => return Ok(false),
+		};
+
+		let dispatch_weight = match task.origin.as_system_ref() {
+			Some(&RawOrigin::Signed(_)) => T::WeightInfo::execute_dispatch_signed(),
+			_ => T::WeightInfo::execute_dispatch_unsigned(),
+		};
+		let required = T::WeightInfo::service_agendas_base()
+			.saturating_add(T::WeightInfo::service_agenda_base(1))
+			.saturating_add(T::WeightInfo::service_task(
+				None,
+				task.maybe_id.is_some(),
+				task.maybe_periodic.is_some(),
+			))
+			.saturating_add(dispatch_weight)
+			.saturating_add(call.get_dispatch_info().weight);
+
+		if required.all_lte(T::MaximumWeight::get()) {
+			return Ok(false)
+		}
+		ensure!(!T::RejectOverweight::get(), Error::<T>::Overweight);
+		Ok(true)
+	}
+
 	fn do_cancel(
 		origin: Option<T::PalletsOrigin>,
 		(when, index): TaskAddress<BlockNumberFor<T>>,
@@ -1003,61 +2209,210 @@ impl<T: Config> Pallet<T> {
 			)
 		})?;
 		if let Some(s) = scheduled {
-			T::Preimages::drop(&s.call);
-			if let Some(id) = s.maybe_id {
-				Lookup::<T>::remove(id);
-			}
-			Retries::<T>::remove((when, index));
+			Self::clear_canceled((when, index), s);
 			Self::cleanup_agenda(when);
-			Self::deposit_event(Event::Canceled { when, index });
 			Ok(())
 		} else {
 			return Err(Error::<T>::NotFound.into())
 		}
 	}
 
+	/// Clean up after the task at `address` was taken out of its agenda to be canceled.
+	fn clear_canceled(address: TaskAddress<BlockNumberFor<T>>, task: ScheduledOf<T>) {
+		Self::clear_task(address, task);
+		let (when, index) = address;
+		Self::deposit_event(Event::Canceled { when, index });
+	}
+
+	/// Clean up after the task at `address` was taken out of its agenda to be dropped without
+	/// being executed.
+	fn clear_task(address: TaskAddress<BlockNumberFor<T>>, task: ScheduledOf<T>) {
+		T::Preimages::drop(&task.call);
+		if let Some(id) = task.maybe_id {
+			Lookup::<T>::remove(id);
+		}
+		Retries::<T>::remove(address);
+		RetryBackoffs::<T>::remove(address);
+		Recurrences::<T>::remove(address);
+		ValidUntil::<T>::remove(address);
+		Paused::<T>::remove(address);
+		DispatchMoments::<T>::remove(address);
+		Self::release_reservation(address);
+		Self::fail_dependants(address);
+	}
+
+	/// Cancel all tasks scheduled with the origin `target` and return their number.
+	///
+	/// Fails if there are more agendas, waiting tasks and overflowed tasks than `witness`.
+	fn do_cancel_all_for_origin(
+		origin: Option<T::PalletsOrigin>,
+		target: T::PalletsOrigin,
+		witness: u32,
+	) -> Result<u32, DispatchError> {
+		if let Some(ref o) = origin {
+			Self::ensure_privilege(o, &target)?;
+		}
+		let limit = witness as usize;
+		let whens = Agenda::<T>::iter_keys().take(limit.saturating_add(1)).collect::<Vec<_>>();
+		let waiting = Dependants::<T>::iter_keys()
+			.take(limit.saturating_sub(whens.len()).saturating_add(1))
+			.collect::<Vec<_>>();
+		let overflowed = Overflow::<T>::iter_keys()
+			.take(limit.saturating_sub(whens.len() + waiting.len()).saturating_add(1))
+			.collect::<Vec<_>>();
+		ensure!(
+			whens.len() + waiting.len() + overflowed.len() <= limit,
+			Error::<T>::InvalidWitnessData
+		);
+
+		let mut count = 0u32;
+		for when in whens {
+			let mut agenda = Agenda::<T>::get(when);
+			let mut canceled = Vec::new();
+			for (index, slot) in agenda.iter_mut().enumerate() {
+				if matches!(slot, Some(task) if task.origin == target) {
+					canceled.extend(slot.take().map(|task| (index as u32, task)));
+				}
+			}
+			if canceled.is_empty() {
+				continue
+			}
+			Agenda::<T>::insert(when, agenda);
+			Self::cleanup_agenda(when);
+			for (index, task) in canceled {
+				Self::clear_canceled((when, index), task);
+				count.saturating_inc();
+			}
+		}
+		// Dependants of the canceled tasks were already dropped along with them.
+		for (address, id) in waiting {
+			match Dependants::<T>::get(address, id) {
+				Some(dependant) if dependant.task.origin == target => {
+					Dependants::<T>::remove(address, id);
+					DependsOn::<T>::remove(id);
+					T::Preimages::drop(&dependant.task.call);
+					Self::unreserve(dependant.reservation);
+					count.saturating_inc();
+				},
+				_ => {},
+			}
+		}
+		for index in overflowed {
+			match Overflow::<T>::get(index) {
+				Some(overflowed) if overflowed.task.origin == target => {
+					Overflow::<T>::remove(index);
+					Self::clear_overflowed(overflowed);
+					count.saturating_inc();
+				},
+				_ => {},
+			}
+		}
+		Self::deposit_event(Event::OriginTasksCanceled { count });
+		Ok(count)
+	}
+
 	fn do_reschedule(
+		origin: Option<T::PalletsOrigin>,
 		(when, index): TaskAddress<BlockNumberFor<T>>,
-		new_time: DispatchTime<BlockNumberFor<T>>,
+		new_time: ScheduleTime<BlockNumberFor<T>>,
 	) -> Result<TaskAddress<BlockNumberFor<T>>, DispatchError> {
-		let new_time = Self::resolve_time(new_time)?;
+		let (new_time, maybe_moment) = Self::resolve_time(new_time)?;
 
-		if new_time == when {
+		if new_time == when && maybe_moment == DispatchMoments::<T>::get((when, index)) {
 			return Err(Error::<T>::RescheduleNoChange.into())
 		}
+		Self::ensure_within_horizon(origin.as_ref(), new_time, Some(when))?;
+
+		let task = Agenda::<T>::try_mutate(when, |agenda| {
+			let task = agenda.get_mut(index as usize).ok_or(Error::<T>::NotFound)?;
+			ensure!(!matches!(task, Some(Scheduled { maybe_id: Some(_), .. })), Error::<T>::Named);
+			if let (Some(ref o), Some(t)) = (origin, task.as_ref()) {
+				Self::ensure_privilege(o, &t.origin)?;
+			}
+			task.take().ok_or(Error::<T>::NotFound)
+		})?;
+		Self::cleanup_agenda(when);
+		Self::deposit_event(Event::Canceled { when, index });
+
+		let new_address = Self::place_task(new_time, task).map_err(|x| x.0)?;
+		Self::move_task_metadata((when, index), new_address);
+		DispatchMoments::<T>::remove((when, index));
+		if let Some(moment) = maybe_moment {
+			DispatchMoments::<T>::insert(new_address, moment);
+		}
+		Ok(new_address)
+	}
+
+	fn do_schedule_named(
+		id: TaskName,
+		when: ScheduleTime<BlockNumberFor<T>>,
+		maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
+		priority: schedule::Priority,
+		origin: T::PalletsOrigin,
+		call: BoundedCallOf<T>,
+	) -> Result<TaskAddress<BlockNumberFor<T>>, DispatchError> {
+		// ensure id it is unique
+		if Lookup::<T>::contains_key(&id) || DependsOn::<T>::contains_key(&id) {
+			return Err(Error::<T>::FailedToSchedule.into())
+		}
+
+		let (when, maybe_moment) = Self::resolve_time(when)?;
+		Self::ensure_within_horizon(Some(&origin), when, None)?;
+
+		let lookup_hash = call.lookup_hash();
+
+		// sanitize maybe_periodic
+		let maybe_periodic = maybe_periodic
+			.filter(|p| p.1 > 1 && !p.0.is_zero())
+			// Remove one from the number of repetitions since we will schedule one now.
+			.map(|(p, c)| (p, c - 1));
+
+		let task = Scheduled {
+			maybe_id: Some(id),
+			priority,
+			call,
+			maybe_periodic,
+			origin,
+			_phantom: Default::default(),
+		};
+		let overweight = Self::check_overweight(&task)?;
+		let res = Self::reserve_and_place(when, task)?;
+		if let Some(moment) = maybe_moment {
+			DispatchMoments::<T>::insert(res, moment);
+		}
+		if overweight {
+			Self::deposit_event(Event::ScheduledPermanentlyOverweight { task: res, id: Some(id) });
+		}
 
-		let task = Agenda::<T>::try_mutate(when, |agenda| {
-			let task = agenda.get_mut(index as usize).ok_or(Error::<T>::NotFound)?;
-			ensure!(!matches!(task, Some(Scheduled { maybe_id: Some(_), .. })), Error::<T>::Named);
-			task.take().ok_or(Error::<T>::NotFound)
-		})?;
-		Self::cleanup_agenda(when);
-		Self::deposit_event(Event::Canceled { when, index });
+		if let Some(hash) = lookup_hash {
+			// Request the call to be made available.
+			T::Preimages::request(&hash);
+		}
 
-		Self::place_task(new_time, task).map_err(|x| x.0)
+		Ok(res)
 	}
 
-	fn do_schedule_named(
+	fn do_schedule_named_after_task(
 		id: TaskName,
-		when: DispatchTime<BlockNumberFor<T>>,
+		depends_on: TaskName,
+		after: BlockNumberFor<T>,
 		maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
 		priority: schedule::Priority,
 		origin: T::PalletsOrigin,
 		call: BoundedCallOf<T>,
-	) -> Result<TaskAddress<BlockNumberFor<T>>, DispatchError> {
+	) -> Result<(), DispatchError> {
 		// ensure id it is unique
-		if Lookup::<T>::contains_key(&id) {
+		if Lookup::<T>::contains_key(&id) || DependsOn::<T>::contains_key(&id) {
 			return Err(Error::<T>::FailedToSchedule.into())
 		}
-
-		let when = Self::resolve_time(when)?;
+		let parent = Lookup::<T>::get(&depends_on).ok_or(Error::<T>::NotFound)?;
 
 		let lookup_hash = call.lookup_hash();
 
 		// sanitize maybe_periodic
 		let maybe_periodic = maybe_periodic
 			.filter(|p| p.1 > 1 && !p.0.is_zero())
-			// Remove one from the number of repetitions since we will schedule one now.
+			// Remove one from the number of repetitions since we will schedule one later.
 			.map(|(p, c)| (p, c - 1));
 
 		let task = Scheduled {
@@ -1068,14 +2423,163 @@ impl<T: Config> Pallet<T> {
 			origin,
 			_phantom: Default::default(),
 		};
-		let res = Self::place_task(when, task).map_err(|x| x.0)?;
+		Self::check_overweight(&task)?;
+		let reservation = Self::reserve(&task.origin)?;
+		Dependants::<T>::insert(parent, id, Dependant { delay: after, task, reservation });
+		DependsOn::<T>::insert(id, parent);
+		Self::deposit_event(Event::DependantScheduled { id, depends_on: parent });
 
 		if let Some(hash) = lookup_hash {
 			// Request the call to be made available.
 			T::Preimages::request(&hash);
 		}
 
-		Ok(res)
+		Ok(())
+	}
+
+	/// Move the settings kept for the task at `from` to its new address `to`.
+	///
+	/// The dispatch moment of the task is left to the caller, since it may change with the move.
+	fn move_task_metadata(
+		from: TaskAddress<BlockNumberFor<T>>,
+		to: TaskAddress<BlockNumberFor<T>>,
+	) {
+		if Paused::<T>::take(from).is_some() {
+			Paused::<T>::insert(to, ());
+		}
+		if let Some(reservation) = Reservations::<T>::take(from) {
+			Reservations::<T>::insert(to, reservation);
+		}
+		if let Some(recurrence) = Recurrences::<T>::take(from) {
+			Recurrences::<T>::insert(to, recurrence);
+		}
+		if let Some(valid_until) = ValidUntil::<T>::take(from) {
+			ValidUntil::<T>::insert(to, valid_until);
+		}
+		if let Some(retry_config) = Retries::<T>::take(from) {
+			Retries::<T>::insert(to, retry_config);
+		}
+		if let Some(backoff) = RetryBackoffs::<T>::take(from) {
+			RetryBackoffs::<T>::insert(to, backoff);
+		}
+	}
+
+	fn move_dependants(from: TaskAddress<BlockNumberFor<T>>, to: TaskAddress<BlockNumberFor<T>>) {
+		for (id, dependant) in Dependants::<T>::drain_prefix(from).collect::<Vec<_>>() {
+			Dependants::<T>::insert(to, id, dependant);
+			DependsOn::<T>::insert(id, to);
+		}
+	}
+
+	/// Place the tasks waiting for the task at `address`, which was dispatched successfully.
+	fn release_dependants(
+		weight: &mut WeightMeter,
+		now: BlockNumberFor<T>,
+		address: TaskAddress<BlockNumberFor<T>>,
+	) {
+		for (id, dependant) in Dependants::<T>::drain_prefix(address).collect::<Vec<_>>() {
+			DependsOn::<T>::remove(id);
+			weight.consume(T::WeightInfo::schedule_retry(T::MaxScheduledPerBlock::get()));
+			let Dependant { delay, task, reservation } = dependant;
+			// The agenda of `now` is being serviced, so the task can not be placed into it.
+			let wake = now.saturating_add(delay.max(One::one()));
+			match Self::place_task(wake, task) {
+				Ok(new_address) => Reservations::<T>::insert(new_address, reservation),
+				Err((_, task)) => {
+					T::Preimages::drop(&task.call);
+					Self::unreserve(reservation);
+					Self::deposit_event(Event::DependantScheduleFailed { id });
+				},
+			}
+		}
+	}
+
+	/// Move a task which could not be placed since the agenda of `overflowed.when` is full to the
+	/// [`Overflow`], or drop it if that is full.
+	fn overflow(address: TaskAddress<BlockNumberFor<T>>, overflowed: OverflowedOf<T>) {
+		if Overflow::<T>::count() >= T::MaxOverflowed::get() {
+			Self::clear_overflowed(overflowed);
+			return
+		}
+		let index = NextOverflowIndex::<T>::mutate(|next| {
+			let index = *next;
+			*next = next.wrapping_add(1);
+			index
+		});
+		let id = overflowed.task.maybe_id;
+		Overflow::<T>::insert(index, overflowed);
+		Self::deposit_event(Event::TaskOverflowed { task: address, id, index });
+	}
+
+	/// Place the overflowed task at `index` and return its new address, or drop it and return
+	/// `None` if its name was taken in the meantime.
+	fn do_reschedule_overflowed(
+		index: u32,
+	) -> Result<Option<TaskAddress<BlockNumberFor<T>>>, DispatchError> {
+		let overflowed = Overflow::<T>::get(index).ok_or(Error::<T>::NotFound)?;
+		// The name might have been taken while the task was in the overflow, in which case it can
+		// never be placed again.
+		if let Some(id) = overflowed.task.maybe_id {
+			if Lookup::<T>::contains_key(id) || DependsOn::<T>::contains_key(id) {
+				Overflow::<T>::remove(index);
+				Self::clear_overflowed(overflowed);
+				Self::deposit_event(Event::OverflowedDropped { index, id });
+				return Ok(None)
+			}
+		}
+		let Overflowed { when, task, retry, backoff, recurrence, reservation, valid_until } =
+			overflowed;
+
+		let now = T::BlockNumberProvider::current_block_number();
+		let when = when.max(now.saturating_add(One::one()));
+		let new_address = Self::place_task(when, task).map_err(|x| x.0)?;
+		Overflow::<T>::remove(index);
+		if let Some(retry_config) = retry {
+			Retries::<T>::insert(new_address, retry_config);
+		}
+		if let Some(backoff) = backoff {
+			RetryBackoffs::<T>::insert(new_address, backoff);
+		}
+		if let Some(recurrence) = recurrence {
+			Recurrences::<T>::insert(new_address, recurrence);
+		}
+		if let Some(reservation) = reservation {
+			Reservations::<T>::insert(new_address, reservation);
+		}
+		if let Some(valid_until) = valid_until {
+			ValidUntil::<T>::insert(new_address, valid_until);
+		}
+		Ok(Some(new_address))
+	}
+
+	fn do_cancel_overflowed(origin: Option<T::PalletsOrigin>, index: u32) -> DispatchResult {
+		let overflowed = Overflow::<T>::get(index).ok_or(Error::<T>::NotFound)?;
+		if let Some(ref o) = origin {
+			Self::ensure_privilege(o, &overflowed.task.origin)?;
+		}
+		Overflow::<T>::remove(index);
+		let id = overflowed.task.maybe_id;
+		Self::clear_overflowed(overflowed);
+		Self::deposit_event(Event::OverflowedCanceled { index, id });
+		Ok(())
+	}
+
+	/// Release the preimage and the reservation of an overflowed task which is dropped.
+	fn clear_overflowed(overflowed: OverflowedOf<T>) {
+		T::Preimages::drop(&overflowed.task.call);
+		if let Some(reservation) = overflowed.reservation {
+			Self::unreserve(reservation);
+		}
+	}
+
+	/// Drop the tasks waiting for the task at `address`, which will never succeed.
+	fn fail_dependants(address: TaskAddress<BlockNumberFor<T>>) {
+		for (id, dependant) in Dependants::<T>::drain_prefix(address).collect::<Vec<_>>() {
+			DependsOn::<T>::remove(id);
+			T::Preimages::drop(&dependant.task.call);
+			Self::unreserve(dependant.reservation);
+			Self::deposit_event(Event::DependencyFailed { id, depends_on: address });
+		}
 	}
 
 	fn do_cancel_named(origin: Option<T::PalletsOrigin>, id: TaskName) -> DispatchResult {
@@ -1087,12 +2591,19 @@ impl<T: Config> Pallet<T> {
 						if let (Some(ref o), Some(ref s)) = (origin, s.borrow()) {
 							Self::ensure_privilege(o, &s.origin)?;
 							Retries::<T>::remove((when, index));
+							RetryBackoffs::<T>::remove((when, index));
+							Recurrences::<T>::remove((when, index));
+							ValidUntil::<T>::remove((when, index));
 							T::Preimages::drop(&s.call);
 						}
 						*s = None;
 					}
 					Ok(())
 				})?;
+				Paused::<T>::remove((when, index));
+				DispatchMoments::<T>::remove((when, index));
+				Self::release_reservation((when, index));
+				Self::fail_dependants((when, index));
 				Self::cleanup_agenda(when);
 				Self::deposit_event(Event::Canceled { when, index });
 				Ok(())
@@ -1103,25 +2614,37 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn do_reschedule_named(
+		origin: Option<T::PalletsOrigin>,
 		id: TaskName,
-		new_time: DispatchTime<BlockNumberFor<T>>,
+		new_time: ScheduleTime<BlockNumberFor<T>>,
 	) -> Result<TaskAddress<BlockNumberFor<T>>, DispatchError> {
-		let new_time = Self::resolve_time(new_time)?;
+		let (new_time, maybe_moment) = Self::resolve_time(new_time)?;
 
 		let lookup = Lookup::<T>::get(id);
 		let (when, index) = lookup.ok_or(Error::<T>::NotFound)?;
 
-		if new_time == when {
+		if new_time == when && maybe_moment == DispatchMoments::<T>::get((when, index)) {
 			return Err(Error::<T>::RescheduleNoChange.into())
 		}
+		Self::ensure_within_horizon(origin.as_ref(), new_time, Some(when))?;
 
 		let task = Agenda::<T>::try_mutate(when, |agenda| {
 			let task = agenda.get_mut(index as usize).ok_or(Error::<T>::NotFound)?;
+			if let (Some(ref o), Some(t)) = (origin, task.as_ref()) {
+				Self::ensure_privilege(o, &t.origin)?;
+			}
 			task.take().ok_or(Error::<T>::NotFound)
 		})?;
 		Self::cleanup_agenda(when);
 		Self::deposit_event(Event::Canceled { when, index });
-		Self::place_task(new_time, task).map_err(|x| x.0)
+		let new_address = Self::place_task(new_time, task).map_err(|x| x.0)?;
+		Self::move_task_metadata((when, index), new_address);
+		Self::move_dependants((when, index), new_address);
+		DispatchMoments::<T>::remove((when, index));
+		if let Some(moment) = maybe_moment {
+			DispatchMoments::<T>::insert(new_address, moment);
+		}
+		Ok(new_address)
 	}
 
 	fn do_cancel_retry(
@@ -1135,8 +2658,196 @@ impl<T: Config> Pallet<T> {
 			.ok_or(Error::<T>::NotFound)?;
 		Self::ensure_privilege(origin, &scheduled.origin)?;
 		Retries::<T>::remove((when, index));
+		RetryBackoffs::<T>::remove((when, index));
+		Ok(())
+	}
+
+	fn do_set_retry_backoff(
+		origin: &T::PalletsOrigin,
+		(when, index): TaskAddress<BlockNumberFor<T>>,
+		backoff: RetryBackoff<BlockNumberFor<T>>,
+	) -> Result<(), DispatchError> {
+		let agenda = Agenda::<T>::get(when);
+		let scheduled = agenda
+			.get(index as usize)
+			.and_then(Option::as_ref)
+			.ok_or(Error::<T>::NotFound)?;
+		Self::ensure_privilege(origin, &scheduled.origin)?;
+		ensure!(Retries::<T>::contains_key((when, index)), Error::<T>::NotFound);
+		ensure!(backoff.is_valid(), Error::<T>::InvalidBackoff);
+		if backoff == RetryBackoff::Fixed {
+			RetryBackoffs::<T>::remove((when, index));
+		} else {
+			RetryBackoffs::<T>::insert((when, index), backoff);
+		}
+		Ok(())
+	}
+
+	fn do_set_recurrence(
+		origin: &T::PalletsOrigin,
+		(when, index): TaskAddress<BlockNumberFor<T>>,
+		recurrence: Option<Recurrence>,
+	) -> Result<(), DispatchError> {
+		let agenda = Agenda::<T>::get(when);
+		let scheduled = agenda
+			.get(index as usize)
+			.and_then(Option::as_ref)
+			.ok_or(Error::<T>::NotFound)?;
+		Self::ensure_privilege(origin, &scheduled.origin)?;
+		match recurrence {
+			Some(recurrence) => {
+				ensure!(scheduled.maybe_periodic.is_some(), Error::<T>::NotPeriodic);
+				ensure!(recurrence.is_valid(), Error::<T>::InvalidRecurrence);
+				Recurrences::<T>::insert((when, index), recurrence);
+			},
+			None => Recurrences::<T>::remove((when, index)),
+		}
+		Ok(())
+	}
+
+	fn do_set_valid_until(
+		origin: &T::PalletsOrigin,
+		(when, index): TaskAddress<BlockNumberFor<T>>,
+		valid_until: Option<BlockNumberFor<T>>,
+	) -> Result<(), DispatchError> {
+		let agenda = Agenda::<T>::get(when);
+		let scheduled = agenda
+			.get(index as usize)
+			.and_then(Option::as_ref)
+			.ok_or(Error::<T>::NotFound)?;
+		Self::ensure_privilege(origin, &scheduled.origin)?;
+		match valid_until {
+			Some(valid_until) => {
+				ensure!(valid_until >= when, Error::<T>::ExpiresBeforeDispatch);
+				ValidUntil::<T>::insert((when, index), valid_until)
+			},
+			None => ValidUntil::<T>::remove((when, index)),
+		}
 		Ok(())
 	}
+
+	fn do_pause(
+		origin: &T::PalletsOrigin,
+		(when, index): TaskAddress<BlockNumberFor<T>>,
+	) -> Result<(), DispatchError> {
+		let agenda = Agenda::<T>::get(when);
+		let scheduled = agenda
+			.get(index as usize)
+			.and_then(Option::as_ref)
+			.ok_or(Error::<T>::NotFound)?;
+		Self::ensure_privilege(origin, &scheduled.origin)?;
+		ensure!(!Paused::<T>::contains_key((when, index)), Error::<T>::AlreadyPaused);
+		Paused::<T>::insert((when, index), ());
+		Ok(())
+	}
+
+	/// Resume a paused task, returning its new address.
+	fn do_resume(
+		origin: &T::PalletsOrigin,
+		(when, index): TaskAddress<BlockNumberFor<T>>,
+	) -> Result<TaskAddress<BlockNumberFor<T>>, DispatchError> {
+		let mut agenda = Agenda::<T>::get(when);
+		let scheduled = agenda
+			.get(index as usize)
+			.and_then(Option::as_ref)
+			.ok_or(Error::<T>::NotFound)?;
+		Self::ensure_privilege(origin, &scheduled.origin)?;
+		ensure!(Paused::<T>::take((when, index)).is_some(), Error::<T>::NotPaused);
+
+		let now = T::BlockNumberProvider::current_block_number();
+		if when > now {
+			// The agenda is yet to be serviced, so the task can stay where it is.
+			return Ok((when, index))
+		}
+
+		// The agenda was serviced while the task was paused, so the task needs to be placed into
+		// the next one to be executed.
+		let task = agenda[index as usize].take().ok_or(Error::<T>::NotFound)?;
+		Agenda::<T>::insert(when, agenda);
+		Self::cleanup_agenda(when);
+		let new_address =
+			Self::place_task(now.saturating_add(One::one()), task).map_err(|x| x.0)?;
+		if let Some(moment) = DispatchMoments::<T>::take((when, index)) {
+			DispatchMoments::<T>::insert(new_address, moment);
+		}
+		Self::move_task_metadata((when, index), new_address);
+		Self::move_dependants((when, index), new_address);
+		Ok(new_address)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns the summary of the named task `id`, if it is scheduled.
+	pub fn lookup(id: TaskName) -> Option<TaskSummaryOf<T>> {
+		let (when, index) = Lookup::<T>::get(id)?;
+		let agenda = Agenda::<T>::get(when);
+		let task = agenda.get(index as usize)?.as_ref()?;
+		Some(Self::summarize((when, index), task))
+	}
+
+	/// Returns the summaries of all tasks scheduled in the agenda of block `when`.
+	pub fn agenda(when: BlockNumberFor<T>) -> Vec<TaskSummaryOf<T>> {
+		Agenda::<T>::get(when)
+			.iter()
+			.enumerate()
+			.filter_map(|(index, maybe_task)| {
+				maybe_task.as_ref().map(|task| Self::summarize((when, index as u32), task))
+			})
+			.collect()
+	}
+
+	/// Returns the block at which the named task `id` is next dispatched, if it is scheduled and
+	/// not paused.
+	///
+	/// The task may still be postponed to a later block if the weight of that block runs out, and
+	/// the block of a time-based task is only an estimate.
+	pub fn next_dispatch(id: TaskName) -> Option<BlockNumberFor<T>> {
+		let address = Lookup::<T>::get(id)?;
+		(!Paused::<T>::contains_key(address)).then_some(address.0)
+	}
+
+	/// Returns the name of the task which is being dispatched, if it is a named task.
+	///
+	/// Only returns a name when called from within the dispatch of a scheduled task.
+	pub fn dispatching_task() -> Option<TaskName> {
+		with_context::<DispatchingTask, _>(|v| v.get().and_then(|task| task.0)).flatten()
+	}
+
+	/// Returns how full the agenda of block `when` is.
+	pub fn agenda_occupancy(when: BlockNumberFor<T>) -> AgendaOccupancy {
+		let scheduled = Agenda::<T>::get(when).iter().filter(|t| t.is_some()).count() as u32;
+		AgendaOccupancy { scheduled, capacity: T::MaxScheduledPerBlock::get() }
+	}
+
+	/// Returns the total number of tasks scheduled in all agendas.
+	///
+	/// This iterates over all agendas and is therefore not meant to be used on-chain. Tasks waiting
+	/// for another task to be executed are not included.
+	pub fn total_scheduled() -> u32 {
+		Agenda::<T>::iter_values()
+			.map(|agenda| agenda.iter().filter(|t| t.is_some()).count() as u32)
+			.fold(0, |total, count| total.saturating_add(count))
+	}
+
+	fn summarize(
+		(when, index): TaskAddress<BlockNumberFor<T>>,
+		task: &ScheduledOf<T>,
+	) -> TaskSummaryOf<T> {
+		TaskSummary {
+			when,
+			index,
+			maybe_id: task.maybe_id,
+			priority: task.priority,
+			call_hash: task.call.hash(),
+			maybe_periodic: task.maybe_periodic,
+			retry: Retries::<T>::get((when, index)),
+			retry_backoff: RetryBackoffs::<T>::get((when, index)),
+			recurrence: Recurrences::<T>::get((when, index)),
+			maybe_moment: DispatchMoments::<T>::get((when, index)),
+			paused: Paused::<T>::contains_key((when, index)),
+			valid_until: ValidUntil::<T>::get((when, index)),
+		}
+	}
 }
 
 enum ServiceTaskError {
@@ -1148,27 +2859,61 @@ enum ServiceTaskError {
 use ServiceTaskError::*;
 
 impl<T: Config> Pallet<T> {
-	/// Service up to `max` agendas queue starting from earliest incompletely executed agenda.
+	/// Service up to `max` pending agendas of the blocks up to `now`, earliest first.
+	///
+	/// The blocks with pending agendas are looked up in the [`Queue`], so blocks without an agenda
+	/// are skipped at no cost. This allows the [`Config::BlockNumberProvider`] to skip blocks.
 	fn service_agendas(weight: &mut WeightMeter, now: BlockNumberFor<T>, max: u32) {
 		if weight.try_consume(T::WeightInfo::service_agendas_base()).is_err() {
 			return
 		}
 
 		let mut incomplete_since = now + One::one();
-		let mut when = IncompleteSince::<T>::take().unwrap_or(now);
+		IncompleteSince::<T>::kill();
 		let mut executed = 0;
 
 		let max_items = T::MaxScheduledPerBlock::get();
-		let mut count_down = max;
+		let max_merged = T::MaxMergedAgendas::get().max(1) as usize;
 		let service_agenda_base_weight = T::WeightInfo::service_agenda_base(max_items);
-		while count_down > 0 && when <= now && weight.can_consume(service_agenda_base_weight) {
-			if !Self::service_agenda(weight, &mut executed, now, when, u32::max_value()) {
-				incomplete_since = incomplete_since.min(when);
+		// Only look up as many due agendas as there is weight for servicing, plus one to remember
+		// where to continue.
+		let serviceable = weight
+			.remaining()
+			.checked_div_per_component(&service_agenda_base_weight)
+			.unwrap_or(u64::MAX)
+			.min(max as u64)
+			.saturating_add(1);
+		let due = Queue::<T>::iter_keys()
+			.map(Self::queued_block)
+			.take_while(|when| *when <= now)
+			.take(serviceable.saturated_into())
+			.collect::<Vec<_>>();
+		weight.consume(T::DbWeight::get().reads(due.len() as u64));
+		let limit = due.len().min(max as usize);
+		let mut next = 0;
+
+		while next < limit && weight.can_consume(service_agenda_base_weight) {
+			// Merge the agendas of the following blocks as long as there is weight for servicing
+			// them, which is only the case when catching up.
+			let mut whens = vec![due[next]];
+			next += 1;
+			while whens.len() < max_merged && next < limit {
+				let required = service_agenda_base_weight.saturating_mul(whens.len() as u64 + 1);
+				if !weight.can_consume(required) {
+					break
+				}
+				whens.push(due[next]);
+				next += 1;
+			}
+			for incomplete in
+				Self::service_merged_agendas(weight, &mut executed, now, &whens, u32::max_value())
+			{
+				incomplete_since = incomplete_since.min(incomplete);
 			}
-			when.saturating_inc();
-			count_down.saturating_dec();
 		}
-		incomplete_since = incomplete_since.min(when);
+		if let Some(when) = due.get(next) {
+			incomplete_since = incomplete_since.min(*when);
+		}
 		if incomplete_since <= now {
 			IncompleteSince::<T>::put(incomplete_since);
 		}
@@ -1183,47 +2928,113 @@ impl<T: Config> Pallet<T> {
 		when: BlockNumberFor<T>,
 		max: u32,
 	) -> bool {
-		let mut agenda = Agenda::<T>::get(when);
-		let mut ordered = agenda
-			.iter()
-			.enumerate()
-			.filter_map(|(index, maybe_item)| {
-				maybe_item.as_ref().map(|item| (index as u32, item.priority))
-			})
-			.collect::<Vec<_>>();
-		ordered.sort_by_key(|k| k.1);
-		let within_limit = weight
-			.try_consume(T::WeightInfo::service_agenda_base(ordered.len() as u32))
-			.is_ok();
-		debug_assert!(within_limit, "weight limit should have been checked in advance");
+		Self::service_merged_agendas(weight, executed, now, &[when], max).is_empty()
+	}
+
+	/// Service the agendas of the blocks `whens` together, executing their tasks in order of
+	/// priority and, for tasks of equal priority, in the order of `whens`.
+	///
+	/// Returns the blocks whose agendas were not fully completed and should be revisited at a
+	/// later block.
+	fn service_merged_agendas(
+		weight: &mut WeightMeter,
+		executed: &mut u32,
+		now: BlockNumberFor<T>,
+		whens: &[BlockNumberFor<T>],
+		max: u32,
+	) -> Vec<BlockNumberFor<T>> {
+		let mut agendas = whens.iter().map(|when| Agenda::<T>::get(when)).collect::<Vec<_>>();
+		let mut ordered = Vec::new();
+		for (position, agenda) in agendas.iter().enumerate() {
+			let len = ordered.len();
+			ordered.extend(agenda.iter().enumerate().filter_map(|(index, maybe_item)| {
+				maybe_item.as_ref().map(|item| (position, index as u32, item.priority))
+			}));
+			let within_limit = weight
+				.try_consume(T::WeightInfo::service_agenda_base((ordered.len() - len) as u32))
+				.is_ok();
+			debug_assert!(within_limit, "weight limit should have been checked in advance");
+		}
+		ordered.sort_by_key(|k| k.2);
 
 		// Items which we know can be executed and have postponed for execution in a later block.
-		let mut postponed = (ordered.len() as u32).saturating_sub(max);
+		let mut postponed = vec![0u32; whens.len()];
 		// Items which we don't know can ever be executed.
-		let mut dropped = 0;
-
-		for (agenda_index, _) in ordered.into_iter().take(max as usize) {
+		let mut dropped = vec![0u32; whens.len()];
+		// Items which are paused and stay in the agenda until resumed.
+		let mut paused = vec![0u32; whens.len()];
+		// Items which were postponed since there was not enough weight left.
+		let mut overweight = 0u32;
+
+		for &(position, _, _) in ordered.iter().skip(max as usize) {
+			postponed[position] += 1;
+		}
+		let mut tasks = ordered.into_iter().take(max as usize);
+		while let Some((position, agenda_index, _)) = tasks.next() {
+			let when = whens[position];
+			let agenda = &mut agendas[position];
+			weight.consume(T::DbWeight::get().reads(3));
+			if Paused::<T>::contains_key((when, agenda_index)) {
+				paused[position] += 1;
+				continue
+			}
 			let task = match agenda[agenda_index as usize].take() {
 				None => continue,
 				Some(t) => t,
 			};
+			if ValidUntil::<T>::get((when, agenda_index)).map_or(false, |until| now > until) {
+				if weight
+					.try_consume(T::WeightInfo::cancel(T::MaxScheduledPerBlock::get()))
+					.is_err()
+				{
+					agenda[agenda_index as usize] = Some(task);
+					postponed[position] += 1;
+					overweight += 1;
+					continue
+				}
+				let id = task.maybe_id;
+				Self::clear_task((when, agenda_index), task);
+				Self::deposit_event(Event::Expired { task: (when, agenda_index), id });
+				continue
+			}
+			if let Some(moment) = DispatchMoments::<T>::take((when, agenda_index)) {
+				if moment > Self::current_moment() {
+					agenda[agenda_index as usize] =
+						match Self::postpone_until(weight, now, when, agenda_index, moment, task) {
+							Ok(()) => None,
+							Err(task) => {
+								postponed[position] += 1;
+								Some(task)
+							},
+						};
+					continue
+				}
+			}
 			let base_weight = T::WeightInfo::service_task(
 				task.call.lookup_len().map(|x| x as usize),
 				task.maybe_id.is_some(),
 				task.maybe_periodic.is_some(),
 			);
 			if !weight.can_consume(base_weight) {
-				postponed += 1;
+				agenda[agenda_index as usize] = Some(task);
+				postponed[position] += 1;
+				overweight += 1;
+				for (position, _, _) in tasks.by_ref() {
+					postponed[position] += 1;
+					overweight += 1;
+				}
 				break
 			}
 			let result = Self::service_task(weight, now, when, agenda_index, *executed == 0, task);
 			agenda[agenda_index as usize] = match result {
 				Err((Unavailable, slot)) => {
-					dropped += 1;
+					Self::fail_dependants((when, agenda_index));
+					dropped[position] += 1;
 					slot
 				},
 				Err((Overweight, slot)) => {
-					postponed += 1;
+					postponed[position] += 1;
+					overweight += 1;
 					slot
 				},
 				Ok(()) => {
@@ -1232,13 +3043,36 @@ impl<T: Config> Pallet<T> {
 				},
 			};
 		}
-		if postponed > 0 || dropped > 0 {
-			Agenda::<T>::insert(when, agenda);
-		} else {
-			Agenda::<T>::remove(when);
+
+		if overweight > 0 {
+			weight.consume(T::DbWeight::get().reads_writes(1, 1));
+			PostponedCount::<T>::mutate(|count| count.saturating_accrue(overweight));
+			Self::deposit_event(Event::Postponed {
+				count: overweight,
+				to: now.saturating_add(One::one()),
+			});
 		}
 
-		postponed == 0
+		let mut incomplete = Vec::new();
+		let mut completed = Vec::new();
+		for (position, agenda) in agendas.into_iter().enumerate() {
+			let when = whens[position];
+			if postponed[position] > 0 || dropped[position] > 0 || paused[position] > 0 {
+				Agenda::<T>::insert(when, agenda);
+			} else {
+				Agenda::<T>::remove(when);
+			}
+			if postponed[position] > 0 {
+				incomplete.push(when);
+			} else {
+				completed.push(when);
+			}
+		}
+		if !completed.is_empty() {
+			weight.consume(T::DbWeight::get().writes(completed.len() as u64));
+			Self::dequeue(&completed);
+		}
+		incomplete
 	}
 
 	/// Service (i.e. execute) the given task, being careful not to overflow the `weight` counter.
@@ -1288,7 +3122,7 @@ impl<T: Config> Pallet<T> {
 			task.maybe_periodic.is_some(),
 		));
 
-		match Self::execute_dispatch(weight, task.origin.clone(), call) {
+		match Self::execute_dispatch(weight, task.origin.clone(), task.maybe_id, call) {
 			Err(()) if is_first => {
 				T::Preimages::drop(&task.call);
 				Self::deposit_event(Event::PermanentlyOverweight {
@@ -1301,15 +3135,30 @@ impl<T: Config> Pallet<T> {
 			Ok(result) => {
 				let failed = result.is_err();
 				let maybe_retry_config = Retries::<T>::take((when, agenda_index));
+				let maybe_backoff = RetryBackoffs::<T>::take((when, agenda_index));
+				let maybe_recurrence = Recurrences::<T>::take((when, agenda_index));
+				let maybe_valid_until = ValidUntil::<T>::take((when, agenda_index));
 				Self::deposit_event(Event::Dispatched {
 					task: (when, agenda_index),
 					id: task.maybe_id,
 					result,
 				});
+				if !failed {
+					Self::release_dependants(weight, now, (when, agenda_index));
+				}
 
 				match maybe_retry_config {
 					Some(retry_config) if failed => {
-						Self::schedule_retry(weight, now, when, agenda_index, &task, retry_config);
+						Self::schedule_retry(
+							weight,
+							now,
+							when,
+							agenda_index,
+							&task,
+							retry_config,
+							maybe_backoff.unwrap_or_default(),
+							maybe_valid_until,
+						);
 					},
 					_ => {},
 				}
@@ -1320,27 +3169,100 @@ impl<T: Config> Pallet<T> {
 					} else {
 						task.maybe_periodic = None;
 					}
-					let wake = now.saturating_add(period);
+					// A recurrence places the renewal at its next moment, which the renewal must
+					// wait for in case blocks are produced faster than expected.
+					let maybe_moment =
+						maybe_recurrence.map(|r| r.next_after(Self::current_moment()));
+					let wake = match maybe_moment {
+						Some(moment) => Self::earliest_block_for(now, moment),
+						None => now.saturating_add(period),
+					};
 					match Self::place_task(wake, task) {
-						Ok(new_address) =>
+						Ok(new_address) => {
 							if let Some(retry_config) = maybe_retry_config {
 								Retries::<T>::insert(new_address, retry_config);
-							},
+							}
+							if let Some(backoff) = maybe_backoff {
+								RetryBackoffs::<T>::insert(new_address, backoff);
+							}
+							if let Some(recurrence) = maybe_recurrence {
+								Recurrences::<T>::insert(new_address, recurrence);
+							}
+							if let Some(moment) = maybe_moment {
+								DispatchMoments::<T>::insert(new_address, moment);
+							}
+							if let Some(valid_until) = maybe_valid_until {
+								ValidUntil::<T>::insert(new_address, valid_until);
+							}
+							if let Some(reservation) = Reservations::<T>::take((when, agenda_index))
+							{
+								Reservations::<T>::insert(new_address, reservation);
+							}
+							Self::move_dependants((when, agenda_index), new_address);
+						},
 						Err((_, task)) => {
-							// TODO: Leave task in storage somewhere for it to be rescheduled
-							// manually.
-							T::Preimages::drop(&task.call);
+							let id = task.maybe_id;
+							Self::overflow(
+								(when, agenda_index),
+								Overflowed {
+									when: wake,
+									task,
+									retry: maybe_retry_config,
+									backoff: maybe_backoff,
+									recurrence: maybe_recurrence,
+									reservation: Reservations::<T>::take((when, agenda_index)),
+									valid_until: maybe_valid_until,
+								},
+							);
 							Self::deposit_event(Event::PeriodicFailed {
 								task: (when, agenda_index),
-								id: task.maybe_id,
+								id,
 							});
 						},
 					}
 				} else {
 					T::Preimages::drop(&task.call);
+					Self::release_reservation((when, agenda_index));
 				}
+				// Dependants which were neither placed nor moved along with the task can never
+				// run.
+				Self::fail_dependants((when, agenda_index));
+				Ok(())
+			},
+		}
+	}
+
+	/// Move a time-based task which is not due yet to the earliest block at which its `moment` may
+	/// have passed.
+	///
+	/// If the task can not be moved, it is returned and stays at its current address.
+	fn postpone_until(
+		weight: &mut WeightMeter,
+		now: BlockNumberFor<T>,
+		when: BlockNumberFor<T>,
+		agenda_index: u32,
+		moment: u64,
+		task: ScheduledOf<T>,
+	) -> Result<(), ScheduledOf<T>> {
+		if weight
+			.try_consume(T::WeightInfo::schedule_retry(T::MaxScheduledPerBlock::get()))
+			.is_err()
+		{
+			DispatchMoments::<T>::insert((when, agenda_index), moment);
+			return Err(task)
+		}
+
+		match Self::place_task(Self::earliest_block_for(now, moment), task) {
+			Ok(new_address) => {
+				DispatchMoments::<T>::insert(new_address, moment);
+				Self::move_task_metadata((when, agenda_index), new_address);
+				Self::move_dependants((when, agenda_index), new_address);
 				Ok(())
 			},
+			Err((_, task)) => {
+				DispatchMoments::<T>::insert((when, agenda_index), moment);
+				Err(task)
+			},
 		}
 	}
 
@@ -1351,10 +3273,14 @@ impl<T: Config> Pallet<T> {
 	/// NOTE: Only the weight for this function will be counted (origin lookup, dispatch and the
 	/// call itself).
 	///
+	/// The fee for the dispatch is charged through [`Config::OnChargeDispatch`]. If it can not be
+	/// paid, the call is not dispatched and the error is returned as its result.
+	///
 	/// Returns an error if the call is overweight.
 	fn execute_dispatch(
 		weight: &mut WeightMeter,
 		origin: T::PalletsOrigin,
+		maybe_id: Option<TaskName>,
 		call: <T as Config>::RuntimeCall,
 	) -> Result<DispatchResult, ()> {
 		let base_weight = match origin.as_system_ref() {
@@ -1369,13 +3295,31 @@ impl<T: Config> Pallet<T> {
 			return Err(())
 		}
 
-		let dispatch_origin = origin.into();
-		let (maybe_actual_call_weight, result) = match call.dispatch(dispatch_origin) {
+		let liquidity = match T::OnChargeDispatch::withdraw_fee(&origin, max_weight) {
+			Ok(liquidity) => liquidity,
+			Err(error) => {
+				let _ = weight.try_consume(base_weight);
+				return Ok(Err(error))
+			},
+		};
+
+		let dispatch_origin = origin.clone().into();
+		let dispatch_result = run_in_context(|| {
+			with_context::<DispatchingTask, _>(|v| v.set(DispatchingTask(maybe_id)));
+			call.dispatch(dispatch_origin)
+		});
+		let (maybe_actual_call_weight, result) = match dispatch_result {
 			Ok(post_info) => (post_info.actual_weight, Ok(())),
 			Err(error_and_info) =>
 				(error_and_info.post_info.actual_weight, Err(error_and_info.error)),
 		};
 		let call_weight = maybe_actual_call_weight.unwrap_or(call_weight);
+		T::OnChargeDispatch::correct_and_deposit_fee(
+			&origin,
+			max_weight,
+			base_weight.saturating_add(call_weight),
+			liquidity,
+		);
 		let _ = weight.try_consume(base_weight);
 		let _ = weight.try_consume(call_weight);
 		Ok(result)
@@ -1395,6 +3339,8 @@ impl<T: Config> Pallet<T> {
 		agenda_index: u32,
 		task: &ScheduledOf<T>,
 		retry_config: RetryConfig<BlockNumberFor<T>>,
+		backoff: RetryBackoff<BlockNumberFor<T>>,
+		valid_until: Option<BlockNumberFor<T>>,
 	) {
 		if weight
 			.try_consume(T::WeightInfo::schedule_retry(T::MaxScheduledPerBlock::get()))
@@ -1412,25 +3358,67 @@ impl<T: Config> Pallet<T> {
 			Some(n) => n,
 			None => return,
 		};
-		let wake = now.saturating_add(period);
+		let jitter = Self::retry_jitter((when, agenda_index));
+		let delay = backoff.delay(period, total_retries.saturating_sub(remaining), jitter);
+		let wake = now.saturating_add(delay);
+		// The renewal of a periodic task keeps its reservation, otherwise the retry takes it over.
+		let reservation = match task.maybe_periodic {
+			Some(_) => None,
+			None => Reservations::<T>::take((when, agenda_index)),
+		};
 		match Self::place_task(wake, task.as_retry()) {
 			Ok(address) => {
 				// Reinsert the retry config to the new address of the task after it was
 				// placed.
 				Retries::<T>::insert(address, RetryConfig { total_retries, remaining, period });
+				if backoff != RetryBackoff::Fixed {
+					RetryBackoffs::<T>::insert(address, backoff);
+				}
+				if let Some(valid_until) = valid_until {
+					ValidUntil::<T>::insert(address, valid_until);
+				}
+				if let Some(reservation) = reservation {
+					Reservations::<T>::insert(address, reservation);
+				}
+				Self::move_dependants((when, agenda_index), address);
 			},
 			Err((_, task)) => {
-				// TODO: Leave task in storage somewhere for it to be
-				// rescheduled manually.
-				T::Preimages::drop(&task.call);
-				Self::deposit_event(Event::RetryFailed {
-					task: (when, agenda_index),
-					id: task.maybe_id,
-				});
+				let id = task.maybe_id;
+				Self::overflow(
+					(when, agenda_index),
+					Overflowed {
+						when: wake,
+						task,
+						retry: Some(RetryConfig { total_retries, remaining, period }),
+						backoff: (backoff != RetryBackoff::Fixed).then_some(backoff),
+						recurrence: None,
+						reservation,
+						valid_until,
+					},
+				);
+				Self::deposit_event(Event::RetryFailed { task: (when, agenda_index), id });
 			},
 		}
 	}
 
+	/// The pseudo-random jitter of the retry of the task which failed at `address`.
+	fn retry_jitter(address: TaskAddress<BlockNumberFor<T>>) -> u32 {
+		let hash = (b"scheduler/jitter", address).using_encoded(blake2_256);
+		u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]])
+	}
+
+	/// Derive the account from which the tasks scheduled by `origin` with
+	/// [`Pallet::schedule_as_derivative`] and `index` are dispatched.
+	///
+	/// Any origin, including origins which do not correspond to an account, has its own
+	/// derivative accounts, so that e.g. a governance track can schedule payments from an
+	/// account of its own without granting its origin to the scheduled call.
+	pub fn derivative_account_id(origin: &T::PalletsOrigin, index: u16) -> T::AccountId {
+		let entropy = (b"scheduler/derivative", origin, index).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
 	/// Ensure that `left` has at least the same level of privilege or higher than `right`.
 	///
 	/// Returns an error if `left` has a lower level of privilege or the two cannot be compared.
@@ -1462,7 +3450,7 @@ impl<T: Config> schedule::v2::Anon<BlockNumberFor<T>, <T as Config>::RuntimeCall
 	) -> Result<Self::Address, DispatchError> {
 		let call = call.as_value().ok_or(DispatchError::CannotLookup)?;
 		let call = T::Preimages::bound(call)?.transmute();
-		Self::do_schedule(when, maybe_periodic, priority, origin, call)
+		Self::do_schedule(when.into(), maybe_periodic, priority, origin, call)
 	}
 
 	fn cancel((when, index): Self::Address) -> Result<(), ()> {
@@ -1473,7 +3461,7 @@ impl<T: Config> schedule::v2::Anon<BlockNumberFor<T>, <T as Config>::RuntimeCall
 		address: Self::Address,
 		when: DispatchTime<BlockNumberFor<T>>,
 	) -> Result<Self::Address, DispatchError> {
-		Self::do_reschedule(address, when)
+		Self::do_reschedule(None, address, when.into())
 	}
 
 	fn next_dispatch_time((when, index): Self::Address) -> Result<BlockNumberFor<T>, ()> {
@@ -1500,7 +3488,8 @@ impl<T: Config> schedule::v2::Named<BlockNumberFor<T>, <T as Config>::RuntimeCal
 		let call = call.as_value().ok_or(())?;
 		let call = T::Preimages::bound(call).map_err(|_| ())?.transmute();
 		let name = blake2_256(&id[..]);
-		Self::do_schedule_named(name, when, maybe_periodic, priority, origin, call).map_err(|_| ())
+		Self::do_schedule_named(name, when.into(), maybe_periodic, priority, origin, call)
+			.map_err(|_| ())
 	}
 
 	fn cancel_named(id: Vec<u8>) -> Result<(), ()> {
@@ -1513,7 +3502,7 @@ impl<T: Config> schedule::v2::Named<BlockNumberFor<T>, <T as Config>::RuntimeCal
 		when: DispatchTime<BlockNumberFor<T>>,
 	) -> Result<Self::Address, DispatchError> {
 		let name = blake2_256(&id[..]);
-		Self::do_reschedule_named(name, when)
+		Self::do_reschedule_named(None, name, when.into())
 	}
 
 	fn next_dispatch_time(id: Vec<u8>) -> Result<BlockNumberFor<T>, ()> {
@@ -1537,7 +3526,7 @@ impl<T: Config> schedule::v3::Anon<BlockNumberFor<T>, <T as Config>::RuntimeCall
 		origin: T::PalletsOrigin,
 		call: BoundedCallOf<T>,
 	) -> Result<Self::Address, DispatchError> {
-		Self::do_schedule(when, maybe_periodic, priority, origin, call)
+		Self::do_schedule(when.into(), maybe_periodic, priority, origin, call)
 	}
 
 	fn cancel((when, index): Self::Address) -> Result<(), DispatchError> {
@@ -1548,7 +3537,7 @@ impl<T: Config> schedule::v3::Anon<BlockNumberFor<T>, <T as Config>::RuntimeCall
 		address: Self::Address,
 		when: DispatchTime<BlockNumberFor<T>>,
 	) -> Result<Self::Address, DispatchError> {
-		Self::do_reschedule(address, when).map_err(map_err_to_v3_err::<T>)
+		Self::do_reschedule(None, address, when.into()).map_err(map_err_to_v3_err::<T>)
 	}
 
 	fn next_dispatch_time(
@@ -1577,7 +3566,7 @@ impl<T: Config> schedule::v3::Named<BlockNumberFor<T>, <T as Config>::RuntimeCal
 		origin: T::PalletsOrigin,
 		call: BoundedCallOf<T>,
 	) -> Result<Self::Address, DispatchError> {
-		Self::do_schedule_named(id, when, maybe_periodic, priority, origin, call)
+		Self::do_schedule_named(id, when.into(), maybe_periodic, priority, origin, call)
 	}
 
 	fn cancel_named(id: TaskName) -> Result<(), DispatchError> {
@@ -1588,7 +3577,7 @@ impl<T: Config> schedule::v3::Named<BlockNumberFor<T>, <T as Config>::RuntimeCal
 		id: TaskName,
 		when: DispatchTime<BlockNumberFor<T>>,
 	) -> Result<Self::Address, DispatchError> {
-		Self::do_reschedule_named(id, when).map_err(map_err_to_v3_err::<T>)
+		Self::do_reschedule_named(None, id, when.into()).map_err(map_err_to_v3_err::<T>)
 	}
 
 	fn next_dispatch_time(id: TaskName) -> Result<BlockNumberFor<T>, DispatchError> {
@@ -300,12 +300,241 @@ pub mod v4 {
 	}
 }
 
+pub mod v5 {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	/// Migrate the scheduler pallet from V4 to V5 by indexing the blocks with pending agendas in
+	/// the [`Queue`].
+	pub struct MigrateToV5<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 4, "Can only upgrade from version 4");
+
+			let agendas = Agenda::<T>::iter_keys().count() as u32;
+			log::info!(target: TARGET, "Indexing {} agendas", agendas);
+			Ok(agendas.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let version = StorageVersion::get::<Pallet<T>>();
+			if version != 4 {
+				log::warn!(
+					target: TARGET,
+					"skipping v4 to v5 migration: executed on wrong storage version.\
+				Expected version 4, found {:?}",
+					version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut agendas = 0u64;
+			for when in Agenda::<T>::iter_keys() {
+				Queue::<T>::insert(Pallet::<T>::queue_key(when), ());
+				agendas += 1;
+			}
+			StorageVersion::new(5).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(1 + agendas, 1 + agendas)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 5, "Must upgrade");
+
+			let agendas: u32 =
+				Decode::decode(&mut state.as_ref()).expect("Must decode pre_upgrade state");
+			let queued = Queue::<T>::iter_keys().count() as u32;
+			ensure!(queued == agendas, "Expected all agendas to be queued");
+			Ok(())
+		}
+	}
+}
+
+pub mod block_provider_swap {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use sp_runtime::traits::Convert;
+	use sp_std::collections::btree_map::BTreeMap;
+
+	/// Remap the block numbers kept by the scheduler when its [`Config::BlockNumberProvider`] is
+	/// swapped, e.g. from `frame_system` to the block number of the relay chain.
+	///
+	/// `C` converts a block number of the old provider to the block number of the new provider
+	/// at which a task due at it is now executed, usually by shifting and scaling it around the
+	/// current block numbers of both providers. The agendas, the [`Queue`], `IncompleteSince`, the
+	/// addresses of the tasks in `Lookup`, `DependsOn` and all the storage keyed by task address,
+	/// as well as the blocks of the [`Overflow`] and [`ValidUntil`], are remapped. Tasks which
+	/// don't fit into the agenda they are converted to are moved to the next block with room.
+	/// Addresses which refer to no task are given an empty slot of their own in the block they are
+	/// converted to, so they never collide with the address of a remapped task.
+	///
+	/// Durations in blocks, i.e. the periods of periodic tasks and retries and the delays of
+	/// dependants, are kept as they are.
+	///
+	/// This iterates all the tasks in a single block, so it is meant to be run once, in the runtime
+	/// upgrade which swaps the provider.
+	pub struct MigrateBlockNumberProvider<T, C>(PhantomData<(T, C)>);
+
+	impl<T: Config, C: Convert<BlockNumberFor<T>, BlockNumberFor<T>>> OnRuntimeUpgrade
+		for MigrateBlockNumberProvider<T, C>
+	{
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let tasks = Agenda::<T>::iter_values()
+				.map(|agenda| agenda.iter().flatten().count() as u32)
+				.sum::<u32>();
+			log::info!(target: TARGET, "Remapping the blocks of {} tasks", tasks);
+			Ok(tasks.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut agendas = Agenda::<T>::drain().collect::<Vec<_>>();
+			agendas.sort_by_key(|(when, _)| *when);
+			let _ = Queue::<T>::clear(u32::MAX, None);
+			let mut reads = agendas.len() as u64;
+			let mut writes = agendas.len() as u64 * 2;
+
+			// Move the agendas, keeping the new address of each task.
+			let mut addresses = BTreeMap::new();
+			let capacity = T::MaxScheduledPerBlock::get() as usize;
+			for (old_when, agenda) in agendas {
+				let tasks = agenda.iter().flatten().count();
+				if tasks == 0 {
+					continue
+				}
+				let mut when = C::convert(old_when);
+				let mut target = Agenda::<T>::get(when);
+				reads += 1;
+				while target.len() + tasks > capacity {
+					when.saturating_inc();
+					target = Agenda::<T>::get(when);
+					reads += 1;
+				}
+				for (index, task) in agenda.into_iter().enumerate() {
+					if let Some(task) = task {
+						addresses.insert((old_when, index as u32), (when, target.len() as u32));
+						let _ = target.try_push(Some(task));
+					}
+				}
+				Agenda::<T>::insert(when, target);
+				Queue::<T>::insert(Pallet::<T>::queue_key(when), ());
+				writes += 2;
+			}
+			let tasks = addresses.len();
+
+			if let Some(since) = IncompleteSince::<T>::get() {
+				IncompleteSince::<T>::put(C::convert(since));
+				writes += 1;
+			}
+			reads += 1;
+
+			// An address which refers to no task in the agendas is given an empty slot of its own,
+			// so that it can not collide with the address of a remapped task.
+			let mut remap = |(when, index): TaskAddress<BlockNumberFor<T>>| {
+				if let Some(address) = addresses.get(&(when, index)) {
+					return *address
+				}
+				let mut new_when = C::convert(when);
+				loop {
+					let mut agenda = Agenda::<T>::get(new_when);
+					reads += 1;
+					let new_index = agenda.len() as u32;
+					if agenda.try_push(None).is_ok() {
+						Agenda::<T>::insert(new_when, agenda);
+						Queue::<T>::insert(Pallet::<T>::queue_key(new_when), ());
+						writes += 2;
+						addresses.insert((when, index), (new_when, new_index));
+						return (new_when, new_index)
+					}
+					new_when.saturating_inc();
+				}
+			};
+
+			let mut translated = 0u64;
+			Lookup::<T>::translate::<TaskAddress<BlockNumberFor<T>>, _>(|_, address| {
+				translated += 1;
+				Some(remap(address))
+			});
+			DependsOn::<T>::translate::<TaskAddress<BlockNumberFor<T>>, _>(|_, address| {
+				translated += 1;
+				Some(remap(address))
+			});
+			Overflow::<T>::translate::<OverflowedOf<T>, _>(|_, mut overflowed| {
+				translated += 1;
+				overflowed.when = C::convert(overflowed.when);
+				overflowed.valid_until = overflowed.valid_until.map(C::convert);
+				Some(overflowed)
+			});
+
+			let mut moved = 0u64;
+			for (address, retry) in Retries::<T>::drain().collect::<Vec<_>>() {
+				Retries::<T>::insert(remap(address), retry);
+				moved += 1;
+			}
+			for (address, backoff) in RetryBackoffs::<T>::drain().collect::<Vec<_>>() {
+				RetryBackoffs::<T>::insert(remap(address), backoff);
+				moved += 1;
+			}
+			for (address, ()) in Paused::<T>::drain().collect::<Vec<_>>() {
+				Paused::<T>::insert(remap(address), ());
+				moved += 1;
+			}
+			for (address, moment) in DispatchMoments::<T>::drain().collect::<Vec<_>>() {
+				DispatchMoments::<T>::insert(remap(address), moment);
+				moved += 1;
+			}
+			for (address, recurrence) in Recurrences::<T>::drain().collect::<Vec<_>>() {
+				Recurrences::<T>::insert(remap(address), recurrence);
+				moved += 1;
+			}
+			for (address, until) in ValidUntil::<T>::drain().collect::<Vec<_>>() {
+				ValidUntil::<T>::insert(remap(address), C::convert(until));
+				moved += 1;
+			}
+			for (address, reservation) in Reservations::<T>::drain().collect::<Vec<_>>() {
+				Reservations::<T>::insert(remap(address), reservation);
+				moved += 1;
+			}
+			for (address, name, dependant) in Dependants::<T>::drain().collect::<Vec<_>>() {
+				Dependants::<T>::insert(remap(address), name, dependant);
+				moved += 1;
+			}
+
+			log::info!(
+				target: TARGET,
+				"Remapped the blocks of {} tasks to the new block number provider",
+				tasks,
+			);
+			T::DbWeight::get()
+				.reads_writes(reads + translated + moved, writes + translated + moved * 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let tasks: u32 =
+				Decode::decode(&mut state.as_ref()).expect("Must decode pre_upgrade state");
+			let remapped = Agenda::<T>::iter_values()
+				.map(|agenda| agenda.iter().flatten().count() as u32)
+				.sum::<u32>();
+			ensure!(remapped == tasks, "Expected all tasks to be kept");
+			ensure!(
+				Queue::<T>::iter_keys().count() == Agenda::<T>::iter_keys().count(),
+				"Expected all agendas to be queued"
+			);
+			Ok(())
+		}
+	}
+}
+
 #[cfg(test)]
 #[cfg(feature = "try-runtime")]
 mod test {
 	use super::*;
 	use crate::mock::*;
-	use frame_support::Hashable;
+	use frame_support::{assert_ok, Hashable};
 	use sp_std::borrow::Cow;
 	use substrate_test_utils::assert_eq_uvec;
 
@@ -557,6 +786,93 @@ mod test {
 		});
 	}
 
+	#[test]
+	fn block_provider_swap_remaps_the_tasks() {
+		/// Halves the block numbers and shifts them by 10, so blocks 4 and 5 both become 12.
+		struct HalveAndShift;
+		impl sp_runtime::traits::Convert<u64, u64> for HalveAndShift {
+			fn convert(when: u64) -> u64 {
+				when / 2 + 10
+			}
+		}
+
+		new_test_ext().execute_with(|| {
+			let call =
+				RuntimeCall::Logger(LoggerCall::log { i: 1, weight: Weight::from_parts(10, 0) });
+			for (id, when) in [([1u8; 32], 4), ([2u8; 32], 5)] {
+				assert_ok!(Scheduler::do_schedule_named(
+					id,
+					ScheduleTime::At(when),
+					None,
+					127,
+					root(),
+					Preimage::bound(call.clone()).unwrap()
+				));
+			}
+			assert_ok!(Scheduler::set_retry_named(root().into(), [1u8; 32], 10, 3));
+			IncompleteSince::<Test>::put(4);
+
+			type Migration = block_provider_swap::MigrateBlockNumberProvider<Test, HalveAndShift>;
+			let data = Migration::pre_upgrade().unwrap();
+			let _w = Migration::on_runtime_upgrade();
+			Migration::post_upgrade(data).unwrap();
+
+			// Both agendas were merged into block 12.
+			assert!(!Agenda::<Test>::contains_key(4));
+			assert!(!Agenda::<Test>::contains_key(5));
+			assert_eq!(Agenda::<Test>::get(12).len(), 2);
+			assert_eq!(
+				Queue::<Test>::iter_keys().collect::<Vec<_>>(),
+				vec![Scheduler::queue_key(12)]
+			);
+			assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((12, 0)));
+			assert_eq!(Lookup::<Test>::get([2u8; 32]), Some((12, 1)));
+			assert!(Retries::<Test>::contains_key((12, 0)));
+			assert_eq!(Retries::<Test>::iter().count(), 1);
+			assert_eq!(IncompleteSince::<Test>::get(), Some(12));
+		});
+	}
+
+	#[test]
+	fn block_provider_swap_keeps_addresses_without_task_apart() {
+		/// Halves the block numbers and shifts them by 10, so blocks 4 and 5 both become 12.
+		struct HalveAndShift;
+		impl sp_runtime::traits::Convert<u64, u64> for HalveAndShift {
+			fn convert(when: u64) -> u64 {
+				when / 2 + 10
+			}
+		}
+
+		new_test_ext().execute_with(|| {
+			let call =
+				RuntimeCall::Logger(LoggerCall::log { i: 1, weight: Weight::from_parts(10, 0) });
+			assert_ok!(Scheduler::do_schedule_named(
+				[1u8; 32],
+				ScheduleTime::At(4),
+				None,
+				127,
+				root(),
+				Preimage::bound(call).unwrap()
+			));
+			// Stale entries for a task at block 5 which is not in any agenda, converted to the
+			// same block as the task at block 4.
+			Lookup::<Test>::insert([2u8; 32], (5, 0));
+			Paused::<Test>::insert((5, 0), ());
+
+			type Migration = block_provider_swap::MigrateBlockNumberProvider<Test, HalveAndShift>;
+			let data = Migration::pre_upgrade().unwrap();
+			let _w = Migration::on_runtime_upgrade();
+			Migration::post_upgrade(data).unwrap();
+
+			// The stale entries got an empty slot next to the task instead of its address.
+			assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((12, 0)));
+			assert_eq!(Lookup::<Test>::get([2u8; 32]), Some((12, 1)));
+			assert_eq!(Agenda::<Test>::get(12).len(), 2);
+			assert!(Agenda::<Test>::get(12)[1].is_none());
+			assert_eq!(Paused::<Test>::iter_keys().collect::<Vec<_>>(), vec![(12, 1)]);
+		});
+	}
+
 	fn signed(i: u64) -> OriginCaller {
 		system::RawOrigin::Signed(i).into()
 	}
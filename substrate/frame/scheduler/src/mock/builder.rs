@@ -0,0 +1,213 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A builder of deterministic scheduler scenarios.
+
+use crate::{
+	Agenda, Config, IncompleteSince, Pallet, Retries, RetryConfig, ScheduleTime, TaskAddress,
+	TaskName,
+};
+use frame_support::{
+	dispatch::RawOrigin,
+	traits::{schedule, Get, StorePreimage},
+	weights::{Weight, WeightMeter},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{traits::One, DispatchError};
+use sp_std::prelude::*;
+
+/// A task to be scheduled by the [`SchedulerBuilder`].
+struct TaskSpec<T: Config> {
+	maybe_id: Option<TaskName>,
+	when: BlockNumberFor<T>,
+	maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
+	priority: schedule::Priority,
+	origin: T::PalletsOrigin,
+	call: <T as Config>::RuntimeCall,
+	retry: Option<(u8, BlockNumberFor<T>)>,
+}
+
+/// Builder of a set of scheduled tasks together with the weight available for servicing them.
+///
+/// Tasks are added with [`Self::task`] or [`Self::named_task`] and are dispatched from the root
+/// origin with priority `127` unless changed by the modifiers, which always apply to the task
+/// added last. The tasks are placed into the agendas by [`Self::build`], which must be called
+/// within the externalities of the runtime under test.
+pub struct SchedulerBuilder<T: Config> {
+	weight_limit: Weight,
+	tasks: Vec<TaskSpec<T>>,
+}
+
+impl<T: Config> Default for SchedulerBuilder<T> {
+	fn default() -> Self {
+		Self { weight_limit: T::MaximumWeight::get(), tasks: Vec::new() }
+	}
+}
+
+impl<T: Config> SchedulerBuilder<T> {
+	/// Create a builder without any tasks, which services agendas with
+	/// [`Config::MaximumWeight`].
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Set the weight available for servicing the agendas of each block.
+	pub fn weight_limit(mut self, weight_limit: Weight) -> Self {
+		self.weight_limit = weight_limit;
+		self
+	}
+
+	/// Add an anonymous task dispatching `call` at block `when`.
+	pub fn task(self, when: BlockNumberFor<T>, call: <T as Config>::RuntimeCall) -> Self {
+		self.push(None, when, call)
+	}
+
+	/// Add a task named `id` dispatching `call` at block `when`.
+	pub fn named_task(
+		self,
+		id: TaskName,
+		when: BlockNumberFor<T>,
+		call: <T as Config>::RuntimeCall,
+	) -> Self {
+		self.push(Some(id), when, call)
+	}
+
+	/// Dispatch the last task from `origin`.
+	pub fn origin(mut self, origin: T::PalletsOrigin) -> Self {
+		self.last().origin = origin;
+		self
+	}
+
+	/// Set the priority of the last task.
+	pub fn priority(mut self, priority: schedule::Priority) -> Self {
+		self.last().priority = priority;
+		self
+	}
+
+	/// Repeat the last task `count` times in total, every `period` blocks.
+	pub fn periodic(mut self, period: BlockNumberFor<T>, count: u32) -> Self {
+		self.last().maybe_periodic = Some((period, count));
+		self
+	}
+
+	/// Retry the last task up to `retries` times, every `period` blocks, if it fails.
+	pub fn retry(mut self, retries: u8, period: BlockNumberFor<T>) -> Self {
+		self.last().retry = Some((retries, period));
+		self
+	}
+
+	/// Schedule all tasks in the order they were added.
+	pub fn build(self) -> Result<SchedulerHarness<T>, DispatchError> {
+		let mut addresses = Vec::with_capacity(self.tasks.len());
+		for spec in self.tasks {
+			let when = ScheduleTime::At(spec.when);
+			let call = T::Preimages::bound(spec.call)?;
+			let address = match spec.maybe_id {
+				Some(id) => Pallet::<T>::do_schedule_named(
+					id,
+					when,
+					spec.maybe_periodic,
+					spec.priority,
+					spec.origin,
+					call,
+				)?,
+				None => Pallet::<T>::do_schedule(
+					when,
+					spec.maybe_periodic,
+					spec.priority,
+					spec.origin,
+					call,
+				)?,
+			};
+			if let Some((retries, period)) = spec.retry {
+				Retries::<T>::insert(
+					address,
+					RetryConfig { total_retries: retries, remaining: retries, period },
+				);
+			}
+			addresses.push(address);
+		}
+		Ok(SchedulerHarness { weight_limit: self.weight_limit, addresses })
+	}
+
+	fn push(
+		mut self,
+		maybe_id: Option<TaskName>,
+		when: BlockNumberFor<T>,
+		call: <T as Config>::RuntimeCall,
+	) -> Self {
+		self.tasks.push(TaskSpec {
+			maybe_id,
+			when,
+			maybe_periodic: None,
+			priority: 127,
+			origin: RawOrigin::Root.into(),
+			call,
+			retry: None,
+		});
+		self
+	}
+
+	fn last(&mut self) -> &mut TaskSpec<T> {
+		self.tasks.last_mut().expect("a task must be added before it is modified")
+	}
+}
+
+/// Services the agendas block by block with a fixed weight limit.
+pub struct SchedulerHarness<T: Config> {
+	weight_limit: Weight,
+	addresses: Vec<TaskAddress<BlockNumberFor<T>>>,
+}
+
+impl<T: Config> SchedulerHarness<T> {
+	/// The addresses at which the tasks were initially placed, in the order they were added.
+	pub fn addresses(&self) -> &[TaskAddress<BlockNumberFor<T>>] {
+		&self.addresses
+	}
+
+	/// Change the weight available for servicing the agendas of each block.
+	pub fn set_weight_limit(&mut self, weight_limit: Weight) {
+		self.weight_limit = weight_limit;
+	}
+
+	/// Move to the next block and service the agendas due, returning the weight consumed.
+	pub fn next_block(&mut self) -> Weight {
+		let now = frame_system::Pallet::<T>::block_number() + One::one();
+		frame_system::Pallet::<T>::set_block_number(now);
+		let mut weight = WeightMeter::with_limit(self.weight_limit);
+		Pallet::<T>::service_agendas(&mut weight, now, u32::MAX);
+		weight.consumed()
+	}
+
+	/// Service the agendas of every block up to and including block `n`.
+	pub fn run_to_block(&mut self, n: BlockNumberFor<T>) {
+		while frame_system::Pallet::<T>::block_number() < n {
+			self.next_block();
+		}
+	}
+
+	/// The number of tasks left in the agenda of block `when`.
+	pub fn pending(&self, when: BlockNumberFor<T>) -> usize {
+		Agenda::<T>::get(when).iter().filter(|t| t.is_some()).count()
+	}
+
+	/// The earliest block whose agenda could not be serviced completely, e.g. since the weight
+	/// limit was exhausted.
+	pub fn incomplete_since(&self) -> Option<BlockNumberFor<T>> {
+		IncompleteSince::<T>::get()
+	}
+}
@@ -0,0 +1,357 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Scheduler test environment.
+
+use crate::*;
+
+use crate as scheduler;
+use frame_support::{
+	derive_impl, ord_parameter_types, parameter_types,
+	traits::{
+		ConstU32, ConstU64, Contains, EitherOfDiverse, EqualPrivilegeOnly, OnFinalize,
+		OnInitialize, UnixTime,
+	},
+	weights::WeightToFee,
+};
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use sp_runtime::{BuildStorage, Perbill};
+
+// Logger module to track execution.
+#[frame_support::pallet]
+pub mod logger {
+	use super::{OriginCaller, OriginTrait, Scheduler, TaskName};
+	use frame_support::{pallet_prelude::*, parameter_types};
+	use frame_system::pallet_prelude::*;
+
+	parameter_types! {
+		static Log: Vec<(OriginCaller, u32)> = Vec::new();
+		static TaskNames: Vec<Option<TaskName>> = Vec::new();
+	}
+	pub fn log() -> Vec<(OriginCaller, u32)> {
+		Log::get().clone()
+	}
+	/// The names of the scheduled tasks which dispatched [`Pallet::log`].
+	pub fn task_names() -> Vec<Option<TaskName>> {
+		TaskNames::get().clone()
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	pub type Threshold<T: Config> = StorageValue<_, (BlockNumberFor<T>, BlockNumberFor<T>)>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Under the threshold.
+		TooEarly,
+		/// Over the threshold.
+		TooLate,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		Logged(u32, Weight),
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T>
+	where
+		<T as frame_system::Config>::RuntimeOrigin: OriginTrait<PalletsOrigin = OriginCaller>,
+	{
+		#[pallet::call_index(0)]
+		#[pallet::weight(*weight)]
+		pub fn log(origin: OriginFor<T>, i: u32, weight: Weight) -> DispatchResult {
+			Self::deposit_event(Event::Logged(i, weight));
+			Log::mutate(|log| {
+				log.push((origin.caller().clone(), i));
+			});
+			TaskNames::mutate(|names| names.push(Scheduler::dispatching_task()));
+			Ok(())
+		}
+
+		#[pallet::call_index(1)]
+		#[pallet::weight(*weight)]
+		pub fn log_without_filter(origin: OriginFor<T>, i: u32, weight: Weight) -> DispatchResult {
+			Self::deposit_event(Event::Logged(i, weight));
+			Log::mutate(|log| {
+				log.push((origin.caller().clone(), i));
+			});
+			Ok(())
+		}
+
+		#[pallet::call_index(2)]
+		#[pallet::weight(*weight)]
+		pub fn timed_log(origin: OriginFor<T>, i: u32, weight: Weight) -> DispatchResult {
+			let now = frame_system::Pallet::<T>::block_number();
+			let (start, end) = Threshold::<T>::get().unwrap_or((0u32.into(), u32::MAX.into()));
+			ensure!(now >= start, Error::<T>::TooEarly);
+			ensure!(now <= end, Error::<T>::TooLate);
+			Self::deposit_event(Event::Logged(i, weight));
+			Log::mutate(|log| {
+				log.push((origin.caller().clone(), i));
+			});
+			Ok(())
+		}
+	}
+}
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Logger: logger,
+		Scheduler: scheduler,
+		Preimage: pallet_preimage,
+		Balances: pallet_balances,
+	}
+);
+
+// Scheduler must dispatch with root and no filter, this tests base filter is indeed not used.
+pub struct BaseFilter;
+impl Contains<RuntimeCall> for BaseFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		!matches!(call, RuntimeCall::Logger(LoggerCall::log { .. }))
+	}
+}
+
+parameter_types! {
+	pub BlockWeights: frame_system::limits::BlockWeights =
+		frame_system::limits::BlockWeights::simple_max(
+			Weight::from_parts(2_000_000_000_000, u64::MAX),
+		);
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl system::Config for Test {
+	type BaseCallFilter = BaseFilter;
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+impl logger::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+}
+ord_parameter_types! {
+	pub const One: u64 = 1;
+}
+
+impl pallet_preimage::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = ();
+	type ManagerOrigin = EnsureRoot<u64>;
+	type Consideration = ();
+	type DepositPrice = ();
+}
+
+pub struct TestWeightInfo;
+impl WeightInfo for TestWeightInfo {
+	fn service_agendas_base() -> Weight {
+		Weight::from_parts(0b0000_0001, 0)
+	}
+	fn service_agenda_base(i: u32) -> Weight {
+		Weight::from_parts((i << 8) as u64 + 0b0000_0010, 0)
+	}
+	fn service_task_base() -> Weight {
+		Weight::from_parts(0b0000_0100, 0)
+	}
+	fn service_task_periodic() -> Weight {
+		Weight::from_parts(0b0000_1100, 0)
+	}
+	fn service_task_named() -> Weight {
+		Weight::from_parts(0b0001_0100, 0)
+	}
+	fn service_task_fetched(s: u32) -> Weight {
+		Weight::from_parts((s << 8) as u64 + 0b0010_0100, 0)
+	}
+	fn execute_dispatch_signed() -> Weight {
+		Weight::from_parts(0b0100_0000, 0)
+	}
+	fn execute_dispatch_unsigned() -> Weight {
+		Weight::from_parts(0b1000_0000, 0)
+	}
+	fn schedule(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn cancel(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn schedule_named(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn cancel_named(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn schedule_retry(_s: u32) -> Weight {
+		Weight::from_parts(100000, 0)
+	}
+	fn set_retry() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_retry_named() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn cancel_retry() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn cancel_retry_named() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn pause() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn pause_named() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn resume(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn resume_named(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_retry_backoff() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_retry_backoff_named() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn schedule_named_after_task() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn reschedule_overflowed(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn cancel_overflowed() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn cancel_all_for_origin(a: u32) -> Weight {
+		Weight::from_parts(50 * a as u64, 0)
+	}
+	fn set_recurrence() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_recurrence_named() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn reschedule(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn reschedule_named(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_valid_until() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_valid_until_named() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+}
+parameter_types! {
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
+		BlockWeights::get().max_block;
+	pub static RejectOverweight: bool = false;
+	pub static Now: u64 = 0;
+	pub static ScheduleDeposit: u64 = 0;
+	pub static MaxScheduledPerOrigin: Option<u32> = None;
+	pub static MaxSchedulingHorizon: Option<u64> = None;
+	pub static MaxMergedAgendas: u32 = 1;
+	pub static MaxIdleWeight: Option<Weight> = None;
+	pub static MaxOverflowed: u32 = 10;
+	pub static DispatchFeePerRefTime: u64 = 0;
+}
+
+/// Converts the ref time of a dispatch into a fee of [`DispatchFeePerRefTime`] per unit.
+pub struct MockWeightToFee;
+impl WeightToFee for MockWeightToFee {
+	type Balance = u64;
+
+	fn weight_to_fee(weight: &Weight) -> u64 {
+		weight.ref_time().saturating_mul(DispatchFeePerRefTime::get())
+	}
+}
+
+/// Provides the time in milliseconds set through [`Now`].
+pub struct MockTime;
+impl UnixTime for MockTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::from_millis(Now::get())
+	}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EitherOfDiverse<EnsureRoot<u64>, EnsureSignedBy<One, u64>>;
+	type MaxScheduledPerBlock = ConstU32<10>;
+	type BlockNumberProvider = System;
+	type WeightInfo = TestWeightInfo;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type Preimages = Preimage;
+	type RejectOverweight = RejectOverweight;
+	type TimeProvider = MockTime;
+	type MaximumBlockTime = ConstU64<6_000>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type ScheduleDeposit = ScheduleDeposit;
+	type MaxScheduledPerOrigin = MaxScheduledPerOrigin;
+	type MaxSchedulingHorizon = MaxSchedulingHorizon;
+	type MaxMergedAgendas = MaxMergedAgendas;
+	type MaxIdleWeight = MaxIdleWeight;
+	type MaxOverflowed = MaxOverflowed;
+	type OnChargeDispatch = fee::FungibleAdapter<Balances, MockWeightToFee, ()>;
+}
+
+pub type LoggerCall = logger::Call<Test>;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 100), (2, 100)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	t.into()
+}
+
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		Scheduler::on_finalize(System::block_number());
+		System::set_block_number(System::block_number() + 1);
+		Scheduler::on_initialize(System::block_number());
+	}
+}
+
+pub fn root() -> OriginCaller {
+	system::RawOrigin::Root.into()
+}
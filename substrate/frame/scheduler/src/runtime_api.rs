@@ -0,0 +1,46 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the FRAME Scheduler pallet.
+
+use crate::{AgendaOccupancy, TaskName, TaskSummary};
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait SchedulerApi<BlockNumber, Hash>
+	where
+		BlockNumber: Codec,
+		Hash: Codec,
+	{
+		/// Returns the summary of the named task `id`, if it is scheduled.
+		fn lookup(id: TaskName) -> Option<TaskSummary<BlockNumber, Hash>>;
+
+		/// Returns the summaries of all tasks scheduled in the agenda of block `when`.
+		fn agenda(when: BlockNumber) -> Vec<TaskSummary<BlockNumber, Hash>>;
+
+		/// Returns the block at which the named task `id` is next dispatched, if it is scheduled
+		/// and not paused.
+		fn next_dispatch(id: TaskName) -> Option<BlockNumber>;
+
+		/// Returns how full the agenda of block `when` is.
+		fn agenda_occupancy(when: BlockNumber) -> AgendaOccupancy;
+
+		/// Returns the total number of tasks scheduled in all agendas.
+		fn total_scheduled() -> u32;
+	}
+}
@@ -24,10 +24,13 @@ use crate::mock::{
 };
 use frame_support::{
 	assert_err, assert_noop, assert_ok,
-	traits::{Contains, GetStorageVersion, OnInitialize, QueryPreimage, StorePreimage},
+	traits::{
+		fungible::InspectHold, Contains, GetStorageVersion, OnIdle, OnInitialize, QueryPreimage,
+		StorePreimage,
+	},
 	Hashable,
 };
-use sp_runtime::traits::Hash;
+use sp_runtime::{traits::Hash, TokenError};
 use substrate_test_utils::assert_eq_uvec;
 
 #[test]
@@ -44,7 +47,7 @@ fn basic_scheduling_works() {
 
 		// Schedule call to be executed at the 4th block
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -80,7 +83,7 @@ fn scheduling_with_preimages_works() {
 		let hashed = Bounded::Lookup { hash, len };
 
 		// Schedule call to be executed at block 4 with the PreImage hash
-		assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), hashed));
+		assert_ok!(Scheduler::do_schedule(ScheduleTime::At(4), None, 127, root(), hashed));
 
 		// Register preimage on chain
 		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(0), call.encode()));
@@ -102,6 +105,35 @@ fn scheduling_with_preimages_works() {
 	});
 }
 
+#[test]
+fn dispatching_task_is_known_to_the_call() {
+	new_test_ext().execute_with(|| {
+		let call =
+			|i| RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call(1)).unwrap()
+		));
+		assert_ok!(Scheduler::do_schedule(
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call(2)).unwrap()
+		));
+		assert_eq!(Scheduler::dispatching_task(), None);
+
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 1u32), (root(), 2u32)]);
+		assert_eq!(logger::task_names(), vec![Some([1u8; 32]), None]);
+		assert_eq!(Scheduler::dispatching_task(), None);
+	});
+}
+
 #[test]
 fn schedule_after_works() {
 	new_test_ext().execute_with(|| {
@@ -111,7 +143,7 @@ fn schedule_after_works() {
 		assert!(!<Test as frame_system::Config>::BaseCallFilter::contains(&call));
 		// This will schedule the call 3 blocks after the next block... so block 3 + 3 = 6
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::After(3),
+			ScheduleTime::After(3),
 			None,
 			127,
 			root(),
@@ -134,7 +166,7 @@ fn schedule_after_zero_works() {
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
 		assert!(!<Test as frame_system::Config>::BaseCallFilter::contains(&call));
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::After(0),
+			ScheduleTime::After(0),
 			None,
 			127,
 			root(),
@@ -153,7 +185,7 @@ fn periodic_scheduling_works() {
 	new_test_ext().execute_with(|| {
 		// at #4, every 3 blocks, 3 times.
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			Some((3, 3)),
 			127,
 			root(),
@@ -187,7 +219,7 @@ fn retry_scheduling_works() {
 		Threshold::<Test>::put((8, 100));
 		// task 42 at #4
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -249,7 +281,7 @@ fn named_retry_scheduling_works() {
 		assert_eq!(
 			Scheduler::do_schedule_named(
 				[1u8; 32],
-				DispatchTime::At(4),
+				ScheduleTime::At(4),
 				None,
 				127,
 				root(),
@@ -304,7 +336,7 @@ fn retry_scheduling_multiple_tasks_works() {
 		Threshold::<Test>::put((8, 100));
 		// task 20 at #4
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -316,7 +348,7 @@ fn retry_scheduling_multiple_tasks_works() {
 		));
 		// task 42 at #4
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -392,7 +424,7 @@ fn retry_scheduling_multiple_named_tasks_works() {
 		// task 20 at #4
 		assert_ok!(Scheduler::do_schedule_named(
 			[20u8; 32],
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -405,7 +437,7 @@ fn retry_scheduling_multiple_named_tasks_works() {
 		// task 42 at #4
 		assert_ok!(Scheduler::do_schedule_named(
 			[42u8; 32],
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -480,7 +512,7 @@ fn retry_scheduling_with_period_works() {
 		Threshold::<Test>::put((4, 8));
 		// task 42 at #4, every 3 blocks, 6 times
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			Some((3, 6)),
 			127,
 			root(),
@@ -624,7 +656,7 @@ fn named_retry_scheduling_with_period_works() {
 		// task 42 at #4, every 3 blocks, 6 times
 		assert_ok!(Scheduler::do_schedule_named(
 			[42u8; 32],
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			Some((3, 6)),
 			127,
 			root(),
@@ -772,7 +804,7 @@ fn retry_scheduling_expires() {
 		Threshold::<Test>::put((1, 3));
 		// task 42 at #4
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -828,7 +860,7 @@ fn set_retry_bad_origin() {
 	new_test_ext().execute_with(|| {
 		// task 42 at #4 with account 101 as origin
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			101.into(),
@@ -853,7 +885,7 @@ fn set_named_retry_bad_origin() {
 		// task 42 at #4 with account 101 as origin
 		assert_ok!(Scheduler::do_schedule_named(
 			[42u8; 32],
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			101.into(),
@@ -877,7 +909,7 @@ fn set_retry_works() {
 	new_test_ext().execute_with(|| {
 		// task 42 at #4
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -904,7 +936,7 @@ fn set_named_retry_works() {
 		// task 42 at #4 with account 101 as origin
 		assert_ok!(Scheduler::do_schedule_named(
 			[42u8; 32],
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -926,6 +958,126 @@ fn set_named_retry_works() {
 	});
 }
 
+#[test]
+fn retry_backoff_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		// task fails until block 20 is reached
+		Threshold::<Test>::put((20, 100));
+		// task 42 at #4
+		assert_ok!(Scheduler::do_schedule(
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(logger::Call::timed_log {
+				i: 42,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+		// a backoff cannot be set without a retry configuration
+		assert_noop!(
+			Scheduler::set_retry_backoff(
+				root().into(),
+				(4, 0),
+				RetryBackoff::Exponential { cap: 6 }
+			),
+			Error::<Test>::NotFound
+		);
+		// retry 10 times every 2 blocks, doubling the delay up to 6 blocks
+		assert_ok!(Scheduler::set_retry(root().into(), (4, 0), 10, 2));
+		assert_ok!(Scheduler::set_retry_backoff(
+			root().into(),
+			(4, 0),
+			RetryBackoff::Exponential { cap: 6 }
+		));
+		System::assert_last_event(
+			Event::RetryBackoffSet {
+				task: (4, 0),
+				id: None,
+				backoff: RetryBackoff::Exponential { cap: 6 },
+			}
+			.into(),
+		);
+		assert_eq!(RetryBackoffs::<Test>::get((4, 0)), Some(RetryBackoff::Exponential { cap: 6 }));
+		// first retry 2 blocks later
+		run_to_block(4);
+		assert!(Agenda::<Test>::get(6)[0].is_some());
+		assert_eq!(RetryBackoffs::<Test>::get((4, 0)), None);
+		assert_eq!(RetryBackoffs::<Test>::get((6, 0)), Some(RetryBackoff::Exponential { cap: 6 }));
+		// second retry 4 blocks later
+		run_to_block(6);
+		assert!(Agenda::<Test>::get(10)[0].is_some());
+		// third and fourth retries are capped at 6 blocks
+		run_to_block(10);
+		assert!(Agenda::<Test>::get(16)[0].is_some());
+		run_to_block(16);
+		assert!(Agenda::<Test>::get(22)[0].is_some());
+		assert!(logger::log().is_empty());
+		// finally it should succeed and clean up
+		run_to_block(22);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert_eq!(Retries::<Test>::iter().count(), 0);
+		assert_eq!(RetryBackoffs::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn retry_exponential_jitter_backoff_works() {
+	let backoff = RetryBackoff::ExponentialJitter { multiplier: 3, cap: 20u64 };
+	assert_eq!(backoff.delay(2, 1, 0), 2);
+	assert_eq!(backoff.delay(2, 2, 0), 6);
+	// the jitter brings the attempt forward by up to half of the delay
+	assert_eq!(backoff.delay(2, 2, 3), 3);
+	assert_eq!(backoff.delay(2, 2, 4), 6);
+	assert_eq!(backoff.delay(2, 3, 0), 18);
+	// capped at 20 blocks
+	assert_eq!(backoff.delay(2, 4, 0), 20);
+	assert_eq!(backoff.delay(2, 4, 10), 10);
+
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		// task fails until block 100 is reached
+		Threshold::<Test>::put((100, 200));
+		// task 42 at #4
+		assert_ok!(Scheduler::do_schedule(
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(logger::Call::timed_log {
+				i: 42,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+		assert_ok!(Scheduler::set_retry(root().into(), (4, 0), 10, 2));
+		assert_noop!(
+			Scheduler::set_retry_backoff(
+				root().into(),
+				(4, 0),
+				RetryBackoff::ExponentialJitter { multiplier: 0, cap: 20 }
+			),
+			Error::<Test>::InvalidBackoff
+		);
+		assert_ok!(Scheduler::set_retry_backoff(root().into(), (4, 0), backoff));
+
+		// the retries are jittered by the address of the task which failed
+		run_to_block(4);
+		let wake = 4 + backoff.delay(2, 1, Scheduler::retry_jitter((4, 0)));
+		assert!((5..=6).contains(&wake));
+		assert!(Agenda::<Test>::get(wake)[0].is_some());
+		assert_eq!(RetryBackoffs::<Test>::get((wake, 0)), Some(backoff));
+
+		run_to_block(wake);
+		let next = wake + backoff.delay(2, 2, Scheduler::retry_jitter((wake, 0)));
+		assert!((wake + 3..=wake + 6).contains(&next));
+		assert!(Agenda::<Test>::get(next)[0].is_some());
+		assert!(logger::log().is_empty());
+	});
+}
+
 #[test]
 fn retry_periodic_full_cycle() {
 	new_test_ext().execute_with(|| {
@@ -934,7 +1086,7 @@ fn retry_periodic_full_cycle() {
 		// task 42 at #4, every 100 blocks, 4 times
 		assert_ok!(Scheduler::do_schedule_named(
 			[42u8; 32],
-			DispatchTime::At(10),
+			ScheduleTime::At(10),
 			Some((100, 4)),
 			127,
 			root(),
@@ -1047,7 +1199,7 @@ fn reschedule_works() {
 		assert!(!<Test as frame_system::Config>::BaseCallFilter::contains(&call));
 		assert_eq!(
 			Scheduler::do_schedule(
-				DispatchTime::At(4),
+				ScheduleTime::At(4),
 				None,
 				127,
 				root(),
@@ -1060,10 +1212,10 @@ fn reschedule_works() {
 		run_to_block(3);
 		assert!(logger::log().is_empty());
 
-		assert_eq!(Scheduler::do_reschedule((4, 0), DispatchTime::At(6)).unwrap(), (6, 0));
+		assert_eq!(Scheduler::do_reschedule(None, (4, 0), ScheduleTime::At(6)).unwrap(), (6, 0));
 
 		assert_noop!(
-			Scheduler::do_reschedule((6, 0), DispatchTime::At(6)),
+			Scheduler::do_reschedule(None, (6, 0), ScheduleTime::At(6)),
 			Error::<Test>::RescheduleNoChange
 		);
 
@@ -1087,7 +1239,7 @@ fn reschedule_named_works() {
 		assert_eq!(
 			Scheduler::do_schedule_named(
 				[1u8; 32],
-				DispatchTime::At(4),
+				ScheduleTime::At(4),
 				None,
 				127,
 				root(),
@@ -1100,10 +1252,13 @@ fn reschedule_named_works() {
 		run_to_block(3);
 		assert!(logger::log().is_empty());
 
-		assert_eq!(Scheduler::do_reschedule_named([1u8; 32], DispatchTime::At(6)).unwrap(), (6, 0));
+		assert_eq!(
+			Scheduler::do_reschedule_named(None, [1u8; 32], ScheduleTime::At(6)).unwrap(),
+			(6, 0)
+		);
 
 		assert_noop!(
-			Scheduler::do_reschedule_named([1u8; 32], DispatchTime::At(6)),
+			Scheduler::do_reschedule_named(None, [1u8; 32], ScheduleTime::At(6)),
 			Error::<Test>::RescheduleNoChange
 		);
 
@@ -1118,6 +1273,110 @@ fn reschedule_named_works() {
 	});
 }
 
+#[test]
+fn reschedule_calls_keep_retry_config() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule(
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call.clone()).unwrap()
+		));
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		assert_ok!(Scheduler::set_retry(root().into(), (4, 0), 10, 1));
+		assert_ok!(Scheduler::set_retry_named(root().into(), [1u8; 32], 10, 1));
+
+		// a signed origin can not move the tasks of root
+		assert_noop!(
+			Scheduler::reschedule(RuntimeOrigin::signed(1), 4, 0, ScheduleTime::At(6)),
+			BadOrigin
+		);
+		assert_noop!(
+			Scheduler::reschedule_named(RuntimeOrigin::signed(1), [1u8; 32], ScheduleTime::At(6)),
+			BadOrigin
+		);
+		// named tasks can only be moved by their name
+		assert_noop!(
+			Scheduler::reschedule(root().into(), 4, 1, ScheduleTime::At(6)),
+			Error::<Test>::Named
+		);
+
+		assert_ok!(Scheduler::reschedule(root().into(), 4, 0, ScheduleTime::At(6)));
+		assert_ok!(Scheduler::reschedule_named(root().into(), [1u8; 32], ScheduleTime::At(6)));
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((6, 1)));
+		assert_eq!(Retries::<Test>::iter().count(), 2);
+		assert!(Retries::<Test>::contains_key((6, 0)));
+		assert!(Retries::<Test>::contains_key((6, 1)));
+
+		run_to_block(4);
+		assert!(logger::log().is_empty());
+		run_to_block(6);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 42u32)]);
+	});
+}
+
+#[test]
+fn schedule_as_derivative_dispatches_from_derived_account() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+		let derived = Scheduler::derivative_account_id(&root(), 0);
+		// every origin and index has a distinct account.
+		assert_ne!(derived, Scheduler::derivative_account_id(&root(), 1));
+		assert_ne!(
+			derived,
+			Scheduler::derivative_account_id(&system::RawOrigin::Signed(1).into(), 0)
+		);
+
+		assert_ok!(Scheduler::schedule_as_derivative(root().into(), 0, 4, None, 127, call.clone()));
+		assert_ok!(Scheduler::schedule_named_as_derivative(
+			root().into(),
+			[1u8; 32],
+			1,
+			4,
+			None,
+			127,
+			call
+		));
+		// the scheduling origin must still pass `ScheduleOrigin`.
+		assert_noop!(
+			Scheduler::schedule_as_derivative(
+				RuntimeOrigin::signed(2),
+				0,
+				4,
+				None,
+				127,
+				Box::new(RuntimeCall::from(frame_system::Call::remark { remark: vec![] }))
+			),
+			BadOrigin
+		);
+
+		run_to_block(4);
+		assert_eq!(
+			logger::log(),
+			vec![
+				(system::RawOrigin::Signed(derived).into(), 42u32),
+				(
+					system::RawOrigin::Signed(Scheduler::derivative_account_id(&root(), 1)).into(),
+					42u32
+				),
+			]
+		);
+	});
+}
+
 #[test]
 fn reschedule_named_periodic_works() {
 	new_test_ext().execute_with(|| {
@@ -1127,7 +1386,7 @@ fn reschedule_named_periodic_works() {
 		assert_eq!(
 			Scheduler::do_schedule_named(
 				[1u8; 32],
-				DispatchTime::At(4),
+				ScheduleTime::At(4),
 				Some((3, 3)),
 				127,
 				root(),
@@ -1140,8 +1399,14 @@ fn reschedule_named_periodic_works() {
 		run_to_block(3);
 		assert!(logger::log().is_empty());
 
-		assert_eq!(Scheduler::do_reschedule_named([1u8; 32], DispatchTime::At(5)).unwrap(), (5, 0));
-		assert_eq!(Scheduler::do_reschedule_named([1u8; 32], DispatchTime::At(6)).unwrap(), (6, 0));
+		assert_eq!(
+			Scheduler::do_reschedule_named(None, [1u8; 32], ScheduleTime::At(5)).unwrap(),
+			(5, 0)
+		);
+		assert_eq!(
+			Scheduler::do_reschedule_named(None, [1u8; 32], ScheduleTime::At(6)).unwrap(),
+			(6, 0)
+		);
 
 		run_to_block(5);
 		assert!(logger::log().is_empty());
@@ -1150,7 +1415,7 @@ fn reschedule_named_periodic_works() {
 		assert_eq!(logger::log(), vec![(root(), 42u32)]);
 
 		assert_eq!(
-			Scheduler::do_reschedule_named([1u8; 32], DispatchTime::At(10)).unwrap(),
+			Scheduler::do_reschedule_named(None, [1u8; 32], ScheduleTime::At(10)).unwrap(),
 			(10, 0)
 		);
 
@@ -1174,7 +1439,7 @@ fn cancel_named_scheduling_works_with_normal_cancel() {
 		// at #4.
 		Scheduler::do_schedule_named(
 			[1u8; 32],
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -1186,7 +1451,7 @@ fn cancel_named_scheduling_works_with_normal_cancel() {
 		)
 		.unwrap();
 		let i = Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -1212,7 +1477,7 @@ fn cancel_named_periodic_scheduling_works() {
 		// at #4, every 3 blocks, 3 times.
 		Scheduler::do_schedule_named(
 			[1u8; 32],
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			Some((3, 3)),
 			127,
 			root(),
@@ -1226,7 +1491,7 @@ fn cancel_named_periodic_scheduling_works() {
 		// same id results in error.
 		assert!(Scheduler::do_schedule_named(
 			[1u8; 32],
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -1240,7 +1505,7 @@ fn cancel_named_periodic_scheduling_works() {
 		// different id is ok.
 		Scheduler::do_schedule_named(
 			[2u8; 32],
-			DispatchTime::At(8),
+			ScheduleTime::At(8),
 			None,
 			127,
 			root(),
@@ -1268,7 +1533,7 @@ fn scheduler_respects_weight_limits() {
 	new_test_ext().execute_with(|| {
 		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight / 3 * 2 });
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -1276,7 +1541,7 @@ fn scheduler_respects_weight_limits() {
 		));
 		let call = RuntimeCall::Logger(LoggerCall::log { i: 69, weight: max_weight / 3 * 2 });
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -1285,71 +1550,436 @@ fn scheduler_respects_weight_limits() {
 		// 69 and 42 do not fit together
 		run_to_block(4);
 		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		System::assert_has_event(crate::Event::Postponed { count: 1, to: 5 }.into());
+		assert_eq!(PostponedCount::<Test>::get(), 1);
 		run_to_block(5);
 		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 69u32)]);
+		assert_eq!(PostponedCount::<Test>::get(), 1);
 	});
 }
 
 #[test]
-fn retry_respects_weight_limits() {
+fn schedule_named_upsert_replaces_task() {
+	new_test_ext().execute_with(|| {
+		let log = |i| {
+			Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
+				i,
+				weight: Weight::from_parts(10, 0),
+			}))
+		};
+		assert_ok!(Scheduler::schedule_named_upsert(
+			root().into(),
+			[1u8; 32],
+			4,
+			None,
+			127,
+			log(42)
+		));
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((4, 0)));
+
+		// scheduling the same name again fails, but upserting replaces the task.
+		assert_noop!(
+			Scheduler::schedule_named(root().into(), [1u8; 32], 5, None, 127, log(69)),
+			Error::<Test>::FailedToSchedule
+		);
+		assert_ok!(Scheduler::schedule_named_upsert(
+			root().into(),
+			[1u8; 32],
+			5,
+			None,
+			127,
+			log(69)
+		));
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((5, 0)));
+		assert!(Agenda::<Test>::get(4).is_empty());
+
+		// a less privileged origin can not replace the task.
+		assert_noop!(
+			Scheduler::schedule_named_upsert(
+				RuntimeOrigin::signed(1),
+				[1u8; 32],
+				6,
+				None,
+				127,
+				log(7)
+			),
+			BadOrigin
+		);
+
+		run_to_block(6);
+		assert_eq!(logger::log(), vec![(root(), 69u32)]);
+	});
+}
+
+#[test]
+fn postponed_task_expires() {
 	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
 	new_test_ext().execute_with(|| {
-		// schedule 42
 		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight / 3 * 2 });
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(8),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
 			Preimage::bound(call).unwrap(),
 		));
-		// schedule 20 with a call that will fail until we reach block 8
-		Threshold::<Test>::put((8, 100));
-		let call = RuntimeCall::Logger(LoggerCall::timed_log { i: 20, weight: max_weight / 3 * 2 });
-		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 69, weight: max_weight / 3 * 2 });
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
 			Preimage::bound(call).unwrap(),
 		));
-		// set a retry config for 20 for 10 retries every block
-		assert_ok!(Scheduler::set_retry(root().into(), (4, 0), 10, 1));
-		// 20 should fail and be retried later
+		assert_ok!(Scheduler::set_valid_until_named(root().into(), [1u8; 32], Some(4)));
+		assert_eq!(ValidUntil::<Test>::get((4, 1)), Some(4));
+		System::assert_last_event(
+			crate::Event::ValidUntilSet { task: (4, 1), id: Some([1u8; 32]), valid_until: Some(4) }
+				.into(),
+		);
+
+		// 69 does not fit in block 4 and is postponed past its expiry.
 		run_to_block(4);
-		assert!(Agenda::<Test>::get(5)[0].is_some());
-		assert!(Agenda::<Test>::get(8)[0].is_some());
-		assert_eq!(Retries::<Test>::iter().count(), 1);
-		assert!(logger::log().is_empty());
-		// 20 still fails but is scheduled next block together with 42
-		run_to_block(7);
-		assert_eq!(Agenda::<Test>::get(8).len(), 2);
-		assert_eq!(Retries::<Test>::iter().count(), 1);
-		assert!(logger::log().is_empty());
-		// 20 and 42 do not fit together
-		// 42 is executed as it was first in the queue
-		// 20 is still on the 8th block's agenda
-		run_to_block(8);
-		assert!(Agenda::<Test>::get(8)[0].is_none());
-		assert!(Agenda::<Test>::get(8)[1].is_some());
-		assert_eq!(Retries::<Test>::iter().count(), 1);
 		assert_eq!(logger::log(), vec![(root(), 42u32)]);
-		// 20 is executed and the schedule is cleared
-		run_to_block(9);
-		assert_eq!(Agenda::<Test>::iter().count(), 0);
-		assert_eq!(Retries::<Test>::iter().count(), 0);
-		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 20u32)]);
+		run_to_block(5);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		System::assert_last_event(
+			crate::Event::Expired { task: (4, 1), id: Some([1u8; 32]) }.into(),
+		);
+		assert!(Lookup::<Test>::get([1u8; 32]).is_none());
+		assert!(ValidUntil::<Test>::get((4, 1)).is_none());
+		assert!(Agenda::<Test>::get(4).is_empty());
+		assert_eq!(IncompleteSince::<Test>::get(), None);
 	});
 }
 
 #[test]
-fn try_schedule_retry_respects_weight_limits() {
-	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+fn expiry_is_set_when_scheduling_and_moves_with_the_task() {
 	new_test_ext().execute_with(|| {
-		let service_agendas_weight = <Test as Config>::WeightInfo::service_agendas_base();
-		let service_agenda_weight = <Test as Config>::WeightInfo::service_agenda_base(
-			<Test as Config>::MaxScheduledPerBlock::get(),
-		);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::schedule_named_with_expiry(
+			root().into(),
+			[1u8; 32],
+			4,
+			None,
+			127,
+			6,
+			Box::new(call),
+		));
+		assert_eq!(ValidUntil::<Test>::get((4, 0)), Some(6));
+		System::assert_last_event(
+			crate::Event::ValidUntilSet { task: (4, 0), id: Some([1u8; 32]), valid_until: Some(6) }
+				.into(),
+		);
+
+		assert_ok!(Scheduler::reschedule_named(root().into(), [1u8; 32], ScheduleTime::At(7)));
+		assert!(ValidUntil::<Test>::get((4, 0)).is_none());
+		assert_eq!(ValidUntil::<Test>::get((7, 0)), Some(6));
+
+		// the task is dropped instead of being executed after its expiry.
+		run_to_block(7);
+		assert!(logger::log().is_empty());
+		System::assert_last_event(
+			crate::Event::Expired { task: (7, 0), id: Some([1u8; 32]) }.into(),
+		);
+		assert!(ValidUntil::<Test>::get((7, 0)).is_none());
+	});
+}
+
+#[test]
+fn expiry_before_dispatch_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_noop!(
+			Scheduler::schedule_with_expiry(root().into(), 4, None, 127, 3, Box::new(call.clone())),
+			Error::<Test>::ExpiresBeforeDispatch,
+		);
+		assert_noop!(
+			Scheduler::schedule_named_with_expiry(
+				root().into(),
+				[1u8; 32],
+				4,
+				None,
+				127,
+				3,
+				Box::new(call.clone()),
+			),
+			Error::<Test>::ExpiresBeforeDispatch,
+		);
+
+		assert_ok!(Scheduler::schedule_named(
+			root().into(),
+			[1u8; 32],
+			4,
+			None,
+			127,
+			Box::new(call),
+		));
+		assert_noop!(
+			Scheduler::set_valid_until(root().into(), (4, 0), Some(3)),
+			Error::<Test>::ExpiresBeforeDispatch,
+		);
+		assert_noop!(
+			Scheduler::set_valid_until_named(root().into(), [1u8; 32], Some(3)),
+			Error::<Test>::ExpiresBeforeDispatch,
+		);
+		// the task may still be executed in the block it is scheduled at.
+		assert_ok!(Scheduler::set_valid_until_named(root().into(), [1u8; 32], Some(4)));
+		assert_eq!(ValidUntil::<Test>::get((4, 0)), Some(4));
+	});
+}
+
+#[test]
+fn scheduler_builder_services_agendas_deterministically() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+	new_test_ext().execute_with(|| {
+		// task 7 fails until block 6 is reached
+		Threshold::<Test>::put((6, 100));
+		let log = |i| RuntimeCall::Logger(LoggerCall::log { i, weight: max_weight / 3 });
+		let mut harness = SchedulerBuilder::<Test>::new()
+			.weight_limit(max_weight / 2)
+			.task(4, log(42))
+			.named_task([1u8; 32], 4, log(69))
+			.priority(0)
+			.task(
+				5,
+				RuntimeCall::Logger(LoggerCall::timed_log {
+					i: 7,
+					weight: Weight::from_parts(10, 0),
+				}),
+			)
+			.retry(1, 2)
+			.build()
+			.unwrap();
+		assert_eq!(harness.addresses(), &[(4, 0), (4, 1), (5, 0)]);
+
+		// 69 and 42 do not fit together, 69 has the higher priority
+		harness.run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 69u32)]);
+		assert_eq!(harness.incomplete_since(), Some(4));
+		assert_eq!(harness.pending(4), 1);
+		// 42 is serviced in the next block, while 7 fails and is retried
+		harness.run_to_block(5);
+		assert_eq!(logger::log(), vec![(root(), 69u32), (root(), 42u32)]);
+		assert_eq!(harness.incomplete_since(), None);
+		assert_eq!(harness.pending(7), 1);
+		harness.run_to_block(7);
+		assert_eq!(logger::log(), vec![(root(), 69u32), (root(), 42u32), (root(), 7u32)]);
+	});
+}
+
+#[test]
+fn merged_agendas_are_serviced_by_priority() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+	new_test_ext().execute_with(|| {
+		MaxMergedAgendas::set(2);
+		let log = |i| RuntimeCall::Logger(LoggerCall::log { i, weight: max_weight / 3 });
+		let mut harness = SchedulerBuilder::<Test>::new()
+			.weight_limit(max_weight / 2)
+			.task(4, log(1))
+			.priority(255)
+			.task(4, log(2))
+			.priority(255)
+			.task(4, log(3))
+			.priority(255)
+			.task(5, log(99))
+			.priority(0)
+			.build()
+			.unwrap();
+
+		// only one task fits into a block
+		harness.run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 1u32)]);
+		assert_eq!(harness.incomplete_since(), Some(4));
+		// the backlog of block 4 does not delay the more important task of block 5
+		harness.run_to_block(5);
+		assert_eq!(logger::log(), vec![(root(), 1u32), (root(), 99u32)]);
+		assert_eq!(harness.incomplete_since(), Some(4));
+		assert_eq!(harness.pending(4), 2);
+		assert_eq!(harness.pending(5), 0);
+		harness.run_to_block(7);
+		assert_eq!(
+			logger::log(),
+			vec![(root(), 1u32), (root(), 99u32), (root(), 2u32), (root(), 3u32)]
+		);
+		assert_eq!(harness.incomplete_since(), None);
+	});
+}
+
+#[test]
+fn on_idle_catches_up_on_backlog() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+	new_test_ext().execute_with(|| {
+		for i in 1..=3 {
+			let call = RuntimeCall::Logger(LoggerCall::log { i, weight: max_weight / 3 * 2 });
+			assert_ok!(Scheduler::do_schedule(
+				ScheduleTime::At(4),
+				None,
+				127,
+				root(),
+				Preimage::bound(call).unwrap(),
+			));
+		}
+
+		// only one task fits into a block
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 1u32)]);
+		assert_eq!(IncompleteSince::<Test>::get(), Some(4));
+
+		// servicing in `on_idle` is disabled by default
+		assert_eq!(Scheduler::on_idle(4, max_weight), Weight::zero());
+		assert_eq!(logger::log(), vec![(root(), 1u32)]);
+
+		MaxIdleWeight::set(Some(max_weight));
+		// nothing is serviced without weight left in the block
+		assert_eq!(Scheduler::on_idle(4, Weight::zero()), Weight::zero());
+		assert_eq!(logger::log(), vec![(root(), 1u32)]);
+
+		assert!(Scheduler::on_idle(4, max_weight * 2).all_lte(max_weight));
+		assert_eq!(logger::log(), vec![(root(), 1u32), (root(), 2u32)]);
+		assert_eq!(IncompleteSince::<Test>::get(), Some(4));
+		System::assert_last_event(crate::Event::BacklogServiced { remaining: 1 }.into());
+
+		run_to_block(5);
+		assert_eq!(logger::log(), vec![(root(), 1u32), (root(), 2u32), (root(), 3u32)]);
+		assert_eq!(IncompleteSince::<Test>::get(), None);
+
+		// without a backlog only its absence is checked
+		assert_eq!(
+			Scheduler::on_idle(5, max_weight),
+			<Test as frame_system::Config>::DbWeight::get().reads(1)
+		);
+	});
+}
+
+/// The blocks in the [`Queue`], in the order they are serviced.
+fn queued_blocks() -> Vec<u64> {
+	Queue::<Test>::iter_keys().map(Scheduler::queued_block).collect()
+}
+
+#[test]
+fn agendas_of_skipped_blocks_are_serviced() {
+	new_test_ext().execute_with(|| {
+		for (i, when) in [(1u32, 4), (2, 10), (3, 1_000)] {
+			let call =
+				RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) });
+			assert_ok!(Scheduler::do_schedule(
+				ScheduleTime::At(when),
+				None,
+				127,
+				root(),
+				Preimage::bound(call).unwrap(),
+			));
+		}
+		assert_eq!(queued_blocks(), vec![4, 10, 1_000]);
+
+		// the block number jumps past the first two agendas
+		System::set_block_number(20);
+		Scheduler::on_initialize(20);
+		assert_eq!(logger::log(), vec![(root(), 1u32), (root(), 2u32)]);
+		assert_eq!(queued_blocks(), vec![1_000]);
+		assert_eq!(IncompleteSince::<Test>::get(), None);
+
+		// blocks without agendas are not visited
+		System::set_block_number(5_000);
+		assert_eq!(
+			Scheduler::on_initialize(5_000),
+			TestWeightInfo::service_agendas_base() +
+				TestWeightInfo::service_agenda_base(1) +
+				<TestWeightInfo as MarginalWeightInfo>::service_task(None, false, false) +
+				TestWeightInfo::execute_dispatch_unsigned() +
+				Weight::from_parts(10, 0)
+		);
+		assert_eq!(logger::log(), vec![(root(), 1u32), (root(), 2u32), (root(), 3u32)]);
+		assert!(queued_blocks().is_empty());
+		assert!(Agenda::<Test>::iter_keys().next().is_none());
+	});
+}
+
+#[test]
+fn queue_is_ordered_and_unbounded() {
+	new_test_ext().execute_with(|| {
+		let mut whens = vec![70_000, 1_000, 256, 255, 5];
+		whens.extend((0..200).map(|i| 2_000 - 5 * i));
+		for when in &whens {
+			let call = RuntimeCall::Logger(LoggerCall::log { i: 1, weight: Weight::zero() });
+			assert_ok!(Scheduler::do_schedule(
+				ScheduleTime::At(*when),
+				None,
+				127,
+				root(),
+				Preimage::bound(call).unwrap(),
+			));
+		}
+		whens.sort();
+		whens.dedup();
+		assert_eq!(queued_blocks(), whens);
+	});
+}
+
+#[test]
+fn retry_respects_weight_limits() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+	new_test_ext().execute_with(|| {
+		// schedule 42
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight / 3 * 2 });
+		assert_ok!(Scheduler::do_schedule(
+			ScheduleTime::At(8),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		// schedule 20 with a call that will fail until we reach block 8
+		Threshold::<Test>::put((8, 100));
+		let call = RuntimeCall::Logger(LoggerCall::timed_log { i: 20, weight: max_weight / 3 * 2 });
+		assert_ok!(Scheduler::do_schedule(
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		// set a retry config for 20 for 10 retries every block
+		assert_ok!(Scheduler::set_retry(root().into(), (4, 0), 10, 1));
+		// 20 should fail and be retried later
+		run_to_block(4);
+		assert!(Agenda::<Test>::get(5)[0].is_some());
+		assert!(Agenda::<Test>::get(8)[0].is_some());
+		assert_eq!(Retries::<Test>::iter().count(), 1);
+		assert!(logger::log().is_empty());
+		// 20 still fails but is scheduled next block together with 42
+		run_to_block(7);
+		assert_eq!(Agenda::<Test>::get(8).len(), 2);
+		assert_eq!(Retries::<Test>::iter().count(), 1);
+		assert!(logger::log().is_empty());
+		// 20 and 42 do not fit together
+		// 42 is executed as it was first in the queue
+		// 20 is still on the 8th block's agenda
+		run_to_block(8);
+		assert!(Agenda::<Test>::get(8)[0].is_none());
+		assert!(Agenda::<Test>::get(8)[1].is_some());
+		assert_eq!(Retries::<Test>::iter().count(), 1);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		// 20 is executed and the schedule is cleared
+		run_to_block(9);
+		assert_eq!(Agenda::<Test>::iter().count(), 0);
+		assert_eq!(Retries::<Test>::iter().count(), 0);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 20u32)]);
+	});
+}
+
+#[test]
+fn try_schedule_retry_respects_weight_limits() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+	new_test_ext().execute_with(|| {
+		let service_agendas_weight = <Test as Config>::WeightInfo::service_agendas_base();
+		let service_agenda_weight = <Test as Config>::WeightInfo::service_agenda_base(
+			<Test as Config>::MaxScheduledPerBlock::get(),
+		);
 		let actual_service_agenda_weight = <Test as Config>::WeightInfo::service_agenda_base(1);
 		// Some weight for `service_agenda` will be refunded, so we need to make sure the weight
 		// `try_schedule_retry` is going to ask for is greater than this difference, and we take a
@@ -1377,7 +2007,7 @@ fn try_schedule_retry_respects_weight_limits() {
 		Threshold::<Test>::put((8, 100));
 
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -1408,7 +2038,7 @@ fn scheduler_does_not_delete_permanently_overweight_call() {
 	new_test_ext().execute_with(|| {
 		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight });
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -1428,6 +2058,75 @@ fn scheduler_does_not_delete_permanently_overweight_call() {
 	});
 }
 
+/// Permanently overweight calls are reported when they are scheduled.
+#[test]
+fn scheduling_permanently_overweight_call_emits_event() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+	new_test_ext().execute_with(|| {
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight });
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		assert_eq!(
+			System::events().last().unwrap().event,
+			crate::Event::ScheduledPermanentlyOverweight { task: (4, 0), id: Some([1u8; 32]) }
+				.into(),
+		);
+		assert!(Agenda::<Test>::get(4)[0].is_some());
+
+		// A call that fits does not emit the event.
+		System::reset_events();
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 69, weight: max_weight / 2 });
+		assert_ok!(Scheduler::do_schedule(
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		assert_eq!(
+			System::events().last().unwrap().event,
+			crate::Event::Scheduled { when: 4, index: 1 }.into(),
+		);
+	});
+}
+
+/// Permanently overweight calls are rejected if configured so.
+#[test]
+fn scheduling_permanently_overweight_call_can_be_rejected() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+	new_test_ext().execute_with(|| {
+		RejectOverweight::set(true);
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight });
+		assert_noop!(
+			Scheduler::do_schedule(
+				ScheduleTime::At(4),
+				None,
+				127,
+				root(),
+				Preimage::bound(call.clone()).unwrap(),
+			),
+			Error::<Test>::Overweight
+		);
+		assert_noop!(
+			Scheduler::schedule(RuntimeOrigin::root(), 4, None, 127, Box::new(call)),
+			Error::<Test>::Overweight
+		);
+		assert_eq!(Agenda::<Test>::iter().count(), 0);
+
+		// A call that fits is scheduled as usual.
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 69, weight: max_weight / 2 });
+		assert_ok!(Scheduler::schedule(RuntimeOrigin::root(), 4, None, 127, Box::new(call)));
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 69u32)]);
+	});
+}
+
 #[test]
 fn scheduler_handles_periodic_failure() {
 	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
@@ -1438,7 +2137,7 @@ fn scheduler_handles_periodic_failure() {
 		let bound = Preimage::bound(call).unwrap();
 
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			Some((4, u32::MAX)),
 			127,
 			root(),
@@ -1451,7 +2150,7 @@ fn scheduler_handles_periodic_failure() {
 		// Block 28 will already be full.
 		for _ in 0..max_per_block {
 			assert_ok!(Scheduler::do_schedule(
-				DispatchTime::At(28),
+				ScheduleTime::At(28),
 				None,
 				120,
 				root(),
@@ -1471,29 +2170,238 @@ fn scheduler_handles_periodic_failure() {
 }
 
 #[test]
-fn scheduler_handles_periodic_unavailable_preimage() {
+fn overflowed_periodic_task_can_be_rescheduled() {
 	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+	let max_per_block = <Test as Config>::MaxScheduledPerBlock::get();
 
 	new_test_ext().execute_with(|| {
 		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: (max_weight / 3) * 2 });
-		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
-		let len = call.using_encoded(|x| x.len()) as u32;
-		// Important to use here `Bounded::Lookup` to ensure that we request the hash.
-		let bound = Bounded::Lookup { hash, len };
-		// The preimage isn't requested yet.
-		assert!(!Preimage::is_requested(&hash));
+		let bound = Preimage::bound(call).unwrap();
 
-		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
-			Some((4, u32::MAX)),
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			ScheduleTime::At(4),
+			Some((4, 3)),
 			127,
 			root(),
 			bound.clone(),
 		));
+		assert_ok!(Scheduler::set_retry_named(root().into(), [1u8; 32], 2, 1));
+		// Block 8 is full.
+		for _ in 0..max_per_block {
+			assert_ok!(Scheduler::do_schedule(
+				ScheduleTime::At(8),
+				None,
+				120,
+				root(),
+				bound.clone(),
+			));
+		}
+
+		// The renewal of the task does not fit into block 8, so it overflows.
+		run_to_block(4);
+		assert_eq!(logger::log().len(), 1);
+		System::assert_has_event(
+			Event::TaskOverflowed { task: (4, 0), id: Some([1u8; 32]), index: 0 }.into(),
+		);
+		let overflowed = Overflow::<Test>::get(0).unwrap();
+		assert_eq!(overflowed.when, 8);
+		assert_eq!(overflowed.task.maybe_periodic, Some((4, 1)));
+		assert_eq!(NextOverflowIndex::<Test>::get(), 1);
+		assert!(Scheduler::lookup([1u8; 32]).is_none());
+
+		// It can not be placed while the agenda is still full.
+		assert_noop!(
+			Scheduler::reschedule_overflowed(RuntimeOrigin::signed(1), 0),
+			DispatchError::Exhausted
+		);
+		assert_noop!(
+			Scheduler::reschedule_overflowed(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::NotFound
+		);
+		assert_ok!(Scheduler::do_cancel(None, (8, 3)));
+		assert_ok!(Scheduler::reschedule_overflowed(RuntimeOrigin::signed(1), 0));
+		assert!(Overflow::<Test>::get(0).is_none());
+		let summary = Scheduler::lookup([1u8; 32]).unwrap();
+		assert_eq!((summary.when, summary.index), (8, 3));
+		assert_eq!(summary.retry, Some(RetryConfig { total_retries: 2, remaining: 2, period: 1 }));
+	});
+}
+
+#[test]
+fn overflowed_retry_keeps_its_reservation_until_canceled() {
+	let max_per_block = <Test as Config>::MaxScheduledPerBlock::get();
+
+	new_test_ext().execute_with(|| {
+		ScheduleDeposit::set(10);
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+		let reason = HoldReason::ScheduledTask.into();
+		// The task fails until block 8 is reached.
+		Threshold::<Test>::put((8, 100));
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::timed_log {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+		assert_ok!(Scheduler::schedule(RuntimeOrigin::signed(1), 4, None, 127, call));
+		assert_ok!(Scheduler::set_retry(RuntimeOrigin::signed(1), (4, 0), 10, 3));
+		// Block 7 is full.
+		let bound = Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+			i: 69,
+			weight: Weight::from_parts(10, 0),
+		}))
+		.unwrap();
+		for _ in 0..max_per_block {
+			assert_ok!(Scheduler::do_schedule(
+				ScheduleTime::At(7),
+				None,
+				127,
+				root(),
+				bound.clone()
+			));
+		}
+
+		// The retry overflows along with the reservation of the task.
+		run_to_block(4);
+		System::assert_has_event(Event::TaskOverflowed { task: (4, 0), id: None, index: 0 }.into());
+		assert!(Overflow::<Test>::get(0).unwrap().reservation.is_some());
+		assert!(Reservations::<Test>::get((4, 0)).is_none());
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 10);
+		assert_eq!(ScheduledCount::<Test>::get(&signed), 1);
+
+		// Only an origin with at least the privilege of the task can cancel it.
+		assert_noop!(Scheduler::cancel_overflowed(RuntimeOrigin::root(), 0), BadOrigin);
+		assert_noop!(
+			Scheduler::cancel_overflowed(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::NotFound
+		);
+		assert_ok!(Scheduler::cancel_overflowed(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::OverflowedCanceled { index: 0, id: None }.into());
+		assert_eq!(Overflow::<Test>::count(), 0);
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 0);
+		assert!(!ScheduledCount::<Test>::contains_key(&signed));
+	});
+}
+
+#[test]
+fn overflowed_task_is_dropped_once_its_name_is_taken() {
+	let max_per_block = <Test as Config>::MaxScheduledPerBlock::get();
+
+	new_test_ext().execute_with(|| {
+		ScheduleDeposit::set(10);
+		let reason = HoldReason::ScheduledTask.into();
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+		assert_ok!(Scheduler::schedule_named(
+			RuntimeOrigin::signed(1),
+			[1u8; 32],
+			4,
+			Some((4, 3)),
+			127,
+			call.clone()
+		));
+		// Block 8 is full.
+		let bound = Preimage::bound(*call.clone()).unwrap();
+		for _ in 0..max_per_block {
+			assert_ok!(Scheduler::do_schedule(
+				ScheduleTime::At(8),
+				None,
+				127,
+				root(),
+				bound.clone()
+			));
+		}
+		run_to_block(4);
+		assert_eq!(Overflow::<Test>::get(0).unwrap().task.maybe_id, Some([1u8; 32]));
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 10);
+
+		// The name is taken while the task is in the overflow.
+		assert_ok!(Scheduler::schedule_named(
+			RuntimeOrigin::signed(1),
+			[1u8; 32],
+			10,
+			None,
+			127,
+			call
+		));
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 20);
+
+		// The overflowed task can never be placed, so it is dropped and its deposit released.
+		assert_ok!(Scheduler::reschedule_overflowed(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::OverflowedDropped { index: 0, id: [1u8; 32] }.into());
+		assert!(Overflow::<Test>::get(0).is_none());
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 10);
+		let summary = Scheduler::lookup([1u8; 32]).unwrap();
+		assert_eq!((summary.when, summary.index), (10, 0));
+	});
+}
+
+#[test]
+fn overflow_is_bounded() {
+	let max_per_block = <Test as Config>::MaxScheduledPerBlock::get();
+
+	new_test_ext().execute_with(|| {
+		MaxOverflowed::set(1);
+		let bound = Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}))
+		.unwrap();
+		for _ in 0..2 {
+			assert_ok!(Scheduler::do_schedule(
+				ScheduleTime::At(4),
+				Some((4, 3)),
+				127,
+				root(),
+				bound.clone()
+			));
+		}
+		// Block 8 is full.
+		for _ in 0..max_per_block {
+			assert_ok!(Scheduler::do_schedule(
+				ScheduleTime::At(8),
+				None,
+				127,
+				root(),
+				bound.clone()
+			));
+		}
+
+		// Only the first renewal fits into the overflow, the second one is dropped.
+		run_to_block(4);
+		assert_eq!(logger::log().len(), 2);
+		System::assert_has_event(Event::PeriodicFailed { task: (4, 1), id: None }.into());
+		assert_eq!(Overflow::<Test>::count(), 1);
+		assert!(Overflow::<Test>::get(0).is_some());
+		assert_eq!(NextOverflowIndex::<Test>::get(), 1);
+	});
+}
+
+#[test]
+fn scheduler_handles_periodic_unavailable_preimage() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+
+	new_test_ext().execute_with(|| {
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: (max_weight / 3) * 2 });
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+		let len = call.using_encoded(|x| x.len()) as u32;
+		// Important to use here `Bounded::Lookup` to ensure that we request the hash.
+		let bound = Bounded::Lookup { hash, len };
+		// The preimage isn't requested yet.
+		assert!(!Preimage::is_requested(&hash));
+
+		assert_ok!(Scheduler::do_schedule(
+			ScheduleTime::At(4),
+			Some((4, u32::MAX)),
+			127,
+			root(),
+			bound.clone(),
+		));
+
+		// The preimage is requested.
+		assert!(Preimage::is_requested(&hash));
 
-		// The preimage is requested.
-		assert!(Preimage::is_requested(&hash));
-
 		// Note the preimage.
 		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(1), call.encode()));
 
@@ -1522,7 +2430,7 @@ fn scheduler_respects_priority_ordering() {
 	new_test_ext().execute_with(|| {
 		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight / 3 });
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			1,
 			root(),
@@ -1530,7 +2438,7 @@ fn scheduler_respects_priority_ordering() {
 		));
 		let call = RuntimeCall::Logger(LoggerCall::log { i: 69, weight: max_weight / 3 });
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			0,
 			root(),
@@ -1547,7 +2455,7 @@ fn scheduler_respects_priority_ordering_with_soft_deadlines() {
 		let max_weight: Weight = <Test as Config>::MaximumWeight::get();
 		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight / 5 * 2 });
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			255,
 			root(),
@@ -1555,7 +2463,7 @@ fn scheduler_respects_priority_ordering_with_soft_deadlines() {
 		));
 		let call = RuntimeCall::Logger(LoggerCall::log { i: 69, weight: max_weight / 5 * 2 });
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -1563,7 +2471,7 @@ fn scheduler_respects_priority_ordering_with_soft_deadlines() {
 		));
 		let call = RuntimeCall::Logger(LoggerCall::log { i: 2600, weight: max_weight / 5 * 4 });
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			126,
 			root(),
@@ -1591,7 +2499,7 @@ fn on_initialize_weight_is_correct() {
 		});
 		assert_ok!(Scheduler::do_schedule_named(
 			[1u8; 32],
-			DispatchTime::At(3),
+			ScheduleTime::At(3),
 			None,
 			255,
 			root(),
@@ -1603,7 +2511,7 @@ fn on_initialize_weight_is_correct() {
 		});
 		// Anon Periodic
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(2),
+			ScheduleTime::At(2),
 			Some((1000, 3)),
 			128,
 			root(),
@@ -1615,7 +2523,7 @@ fn on_initialize_weight_is_correct() {
 		});
 		// Anon
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(2),
+			ScheduleTime::At(2),
 			None,
 			127,
 			root(),
@@ -1628,7 +2536,7 @@ fn on_initialize_weight_is_correct() {
 		});
 		assert_ok!(Scheduler::do_schedule_named(
 			[2u8; 32],
-			DispatchTime::At(1),
+			ScheduleTime::At(1),
 			Some((1000, 3)),
 			126,
 			root(),
@@ -1636,6 +2544,7 @@ fn on_initialize_weight_is_correct() {
 		));
 
 		// Will include the named periodic only
+		System::set_block_number(1);
 		assert_eq!(
 			Scheduler::on_initialize(1),
 			TestWeightInfo::service_agendas_base() +
@@ -1648,6 +2557,7 @@ fn on_initialize_weight_is_correct() {
 		assert_eq!(logger::log(), vec![(root(), 2600u32)]);
 
 		// Will include anon and anon periodic
+		System::set_block_number(2);
 		assert_eq!(
 			Scheduler::on_initialize(2),
 			TestWeightInfo::service_agendas_base() +
@@ -1663,6 +2573,7 @@ fn on_initialize_weight_is_correct() {
 		assert_eq!(logger::log(), vec![(root(), 2600u32), (root(), 69u32), (root(), 42u32)]);
 
 		// Will include named only
+		System::set_block_number(3);
 		assert_eq!(
 			Scheduler::on_initialize(3),
 			TestWeightInfo::service_agendas_base() +
@@ -1678,11 +2589,9 @@ fn on_initialize_weight_is_correct() {
 		);
 
 		// Will contain none
+		System::set_block_number(4);
 		let actual_weight = Scheduler::on_initialize(4);
-		assert_eq!(
-			actual_weight,
-			TestWeightInfo::service_agendas_base() + TestWeightInfo::service_agenda_base(0)
-		);
+		assert_eq!(actual_weight, TestWeightInfo::service_agendas_base());
 	});
 }
 
@@ -1718,264 +2627,1010 @@ fn fails_to_schedule_task_in_the_past() {
 	new_test_ext().execute_with(|| {
 		run_to_block(3);
 
-		let call1 = Box::new(RuntimeCall::Logger(LoggerCall::log {
-			i: 69,
-			weight: Weight::from_parts(10, 0),
-		}));
-		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log {
-			i: 42,
-			weight: Weight::from_parts(10, 0),
-		}));
-		let call3 = Box::new(RuntimeCall::Logger(LoggerCall::log {
+		let call1 = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 69,
+			weight: Weight::from_parts(10, 0),
+		}));
+		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+		let call3 = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+
+		assert_noop!(
+			Scheduler::schedule_named(RuntimeOrigin::root(), [1u8; 32], 2, None, 127, call1),
+			Error::<Test>::TargetBlockNumberInPast,
+		);
+
+		assert_noop!(
+			Scheduler::schedule(RuntimeOrigin::root(), 2, None, 127, call2),
+			Error::<Test>::TargetBlockNumberInPast,
+		);
+
+		assert_noop!(
+			Scheduler::schedule(RuntimeOrigin::root(), 3, None, 127, call3),
+			Error::<Test>::TargetBlockNumberInPast,
+		);
+	});
+}
+
+#[test]
+fn should_use_origin() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 69,
+			weight: Weight::from_parts(10, 0),
+		}));
+		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+		assert_ok!(Scheduler::schedule_named(
+			system::RawOrigin::Signed(1).into(),
+			[1u8; 32],
+			4,
+			None,
+			127,
+			call,
+		));
+		assert_ok!(Scheduler::schedule(system::RawOrigin::Signed(1).into(), 4, None, 127, call2,));
+		run_to_block(3);
+		// Scheduled calls are in the agenda.
+		assert_eq!(Agenda::<Test>::get(4).len(), 2);
+		assert!(logger::log().is_empty());
+		assert_ok!(Scheduler::cancel_named(system::RawOrigin::Signed(1).into(), [1u8; 32]));
+		assert_ok!(Scheduler::cancel(system::RawOrigin::Signed(1).into(), 4, 1));
+		// Scheduled calls are made NONE, so should not effect state
+		run_to_block(100);
+		assert!(logger::log().is_empty());
+	});
+}
+
+#[test]
+fn should_check_origin() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 69,
+			weight: Weight::from_parts(10, 0),
+		}));
+		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+		assert_noop!(
+			Scheduler::schedule_named(
+				system::RawOrigin::Signed(2).into(),
+				[1u8; 32],
+				4,
+				None,
+				127,
+				call
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Scheduler::schedule(system::RawOrigin::Signed(2).into(), 4, None, 127, call2),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn should_check_origin_for_cancel() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 69,
+			weight: Weight::from_parts(10, 0),
+		}));
+		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+		assert_ok!(Scheduler::schedule_named(
+			system::RawOrigin::Signed(1).into(),
+			[1u8; 32],
+			4,
+			None,
+			127,
+			call,
+		));
+		assert_ok!(Scheduler::schedule(system::RawOrigin::Signed(1).into(), 4, None, 127, call2,));
+		run_to_block(3);
+		// Scheduled calls are in the agenda.
+		assert_eq!(Agenda::<Test>::get(4).len(), 2);
+		assert!(logger::log().is_empty());
+		assert_noop!(
+			Scheduler::cancel_named(system::RawOrigin::Signed(2).into(), [1u8; 32]),
+			BadOrigin
+		);
+		assert_noop!(Scheduler::cancel(system::RawOrigin::Signed(2).into(), 4, 1), BadOrigin);
+		assert_noop!(Scheduler::cancel_named(system::RawOrigin::Root.into(), [1u8; 32]), BadOrigin);
+		assert_noop!(Scheduler::cancel(system::RawOrigin::Root.into(), 4, 1), BadOrigin);
+		run_to_block(5);
+		assert_eq!(
+			logger::log(),
+			vec![
+				(system::RawOrigin::Signed(1).into(), 69u32),
+				(system::RawOrigin::Signed(1).into(), 42u32)
+			]
+		);
+	});
+}
+
+#[test]
+fn cancel_all_for_origin_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let call = |i| {
+			Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
+				i,
+				weight: Weight::from_parts(10, 0),
+			}))
+		};
+		let signed = || RuntimeOrigin::signed(1);
+		let target = || Box::new(system::RawOrigin::Signed(1).into());
+		assert_ok!(Scheduler::schedule_named(signed(), [1u8; 32], 4, Some((2, 3)), 127, call(1)));
+		assert_ok!(Scheduler::schedule(signed(), 5, None, 127, call(2)));
+		assert_ok!(Scheduler::schedule_named(root().into(), [3u8; 32], 4, None, 127, call(3)));
+		assert_ok!(Scheduler::set_retry(signed(), (5, 0), 2, 1));
+		// waits for the task of another origin
+		assert_ok!(Scheduler::schedule_named_after_task(
+			signed(),
+			[2u8; 32],
+			[3u8; 32],
+			1,
+			None,
+			127,
+			call(4),
+		));
+
+		// there are two agendas and a waiting task
+		assert_noop!(
+			Scheduler::cancel_all_for_origin(signed(), target(), 2),
+			Error::<Test>::InvalidWitnessData
+		);
+		assert_noop!(Scheduler::cancel_all_for_origin(root().into(), target(), 3), BadOrigin);
+		assert_ok!(Scheduler::cancel_all_for_origin(signed(), target(), 3));
+		System::assert_last_event(Event::OriginTasksCanceled { count: 3 }.into());
+
+		assert!(Scheduler::lookup([1u8; 32]).is_none());
+		assert!(Retries::<Test>::get((5, 0)).is_none());
+		assert!(DependsOn::<Test>::get([2u8; 32]).is_none());
+		assert_eq!(Dependants::<Test>::iter().count(), 0);
+		assert!(Agenda::<Test>::get(5).is_empty());
+		assert_eq!(Agenda::<Test>::get(4).len(), 2);
+
+		// only the task of the other origin is dispatched
+		run_to_block(10);
+		assert_eq!(logger::log(), vec![(root(), 3u32)]);
+	});
+}
+
+#[test]
+fn cancel_removes_retry_entry() {
+	new_test_ext().execute_with(|| {
+		// task fails until block 99 is reached
+		Threshold::<Test>::put((99, 100));
+		// task 20 at #4
+		assert_ok!(Scheduler::do_schedule(
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(logger::Call::timed_log {
+				i: 20,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+		// named task 42 at #4
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(logger::Call::timed_log {
+				i: 42,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+
+		assert_eq!(Agenda::<Test>::get(4).len(), 2);
+		// task 20 will be retried 3 times every block
+		assert_ok!(Scheduler::set_retry(root().into(), (4, 0), 10, 1));
+		// task 42 will be retried 10 times every 3 blocks
+		assert_ok!(Scheduler::set_retry_named(root().into(), [1u8; 32], 10, 1));
+		assert_eq!(Retries::<Test>::iter().count(), 2);
+		run_to_block(3);
+		assert!(logger::log().is_empty());
+		assert_eq!(Agenda::<Test>::get(4).len(), 2);
+		// both tasks fail
+		run_to_block(4);
+		assert!(Agenda::<Test>::get(4).is_empty());
+		// 42 and 20 are rescheduled for next block
+		assert_eq!(Agenda::<Test>::get(5).len(), 2);
+		assert!(logger::log().is_empty());
+		// 42 and 20 still fail
+		run_to_block(5);
+		// 42 and 20 rescheduled for next block
+		assert_eq!(Agenda::<Test>::get(6).len(), 2);
+		assert_eq!(Retries::<Test>::iter().count(), 2);
+		assert!(logger::log().is_empty());
+
+		// even though 42 is being retried, the tasks scheduled for retries are not named
+		assert_eq!(Lookup::<Test>::iter().count(), 0);
+		assert!(Scheduler::cancel(root().into(), 6, 0).is_ok());
+
+		// 20 is removed, 42 still fails
+		run_to_block(6);
+		// 42 rescheduled for next block
+		assert_eq!(Agenda::<Test>::get(7).len(), 1);
+		// 20's retry entry is removed
+		assert!(!Retries::<Test>::contains_key((4, 0)));
+		assert_eq!(Retries::<Test>::iter().count(), 1);
+		assert!(logger::log().is_empty());
+
+		assert!(Scheduler::cancel(root().into(), 7, 0).is_ok());
+
+		// both tasks are canceled, everything is removed now
+		run_to_block(7);
+		assert!(Agenda::<Test>::get(8).is_empty());
+		assert_eq!(Retries::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn cancel_retries_works() {
+	new_test_ext().execute_with(|| {
+		// task fails until block 99 is reached
+		Threshold::<Test>::put((99, 100));
+		// task 20 at #4
+		assert_ok!(Scheduler::do_schedule(
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(logger::Call::timed_log {
+				i: 20,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+		// named task 42 at #4
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(logger::Call::timed_log {
+				i: 42,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+
+		assert_eq!(Agenda::<Test>::get(4).len(), 2);
+		// task 20 will be retried 3 times every block
+		assert_ok!(Scheduler::set_retry(root().into(), (4, 0), 10, 1));
+		// task 42 will be retried 10 times every 3 blocks
+		assert_ok!(Scheduler::set_retry_named(root().into(), [1u8; 32], 10, 1));
+		assert_eq!(Retries::<Test>::iter().count(), 2);
+		run_to_block(3);
+		assert!(logger::log().is_empty());
+		assert_eq!(Agenda::<Test>::get(4).len(), 2);
+		// cancel the retry config for 20
+		assert_ok!(Scheduler::cancel_retry(root().into(), (4, 0)));
+		assert_eq!(Retries::<Test>::iter().count(), 1);
+		// cancel the retry config for 42
+		assert_ok!(Scheduler::cancel_retry_named(root().into(), [1u8; 32]));
+		assert_eq!(Retries::<Test>::iter().count(), 0);
+		run_to_block(4);
+		// both tasks failed and there are no more retries, so they are evicted
+		assert_eq!(Agenda::<Test>::get(4).len(), 0);
+		assert_eq!(Retries::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn pause_and_resume_works() {
+	new_test_ext().execute_with(|| {
+		// named periodic task 42 at #4, every 3 blocks
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			ScheduleTime::At(4),
+			Some((3, 3)),
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+				i: 42,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+		// task 69 at #4
+		assert_ok!(Scheduler::do_schedule(
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+				i: 69,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+		assert_ok!(Scheduler::set_retry_named(root().into(), [1u8; 32], 10, 1));
+		assert_ok!(Scheduler::pause_named(root().into(), [1u8; 32]));
+		assert!(Paused::<Test>::contains_key((4, 0)));
+		assert_noop!(
+			Scheduler::pause_named(root().into(), [1u8; 32]),
+			Error::<Test>::AlreadyPaused
+		);
+
+		// only the task which is not paused is executed, the paused one keeps its slot.
+		run_to_block(6);
+		assert_eq!(logger::log(), vec![(root(), 69u32)]);
+		assert!(Agenda::<Test>::get(4)[0].is_some());
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((4, 0)));
+
+		// the agenda was serviced, so the task is placed into the next one.
+		assert_ok!(Scheduler::resume_named(root().into(), [1u8; 32]));
+		System::assert_last_event(Event::Resumed { task: (7, 0), id: Some([1u8; 32]) }.into());
+		assert!(Agenda::<Test>::get(4).is_empty());
+		assert!(Paused::<Test>::iter().next().is_none());
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((7, 0)));
+		assert_eq!(
+			Retries::<Test>::get((7, 0)),
+			Some(RetryConfig { total_retries: 10, remaining: 10, period: 1 })
+		);
+		assert_noop!(Scheduler::resume_named(root().into(), [1u8; 32]), Error::<Test>::NotPaused);
+
+		run_to_block(7);
+		assert_eq!(logger::log(), vec![(root(), 69u32), (root(), 42u32)]);
+		run_to_block(10);
+		assert_eq!(logger::log(), vec![(root(), 69u32), (root(), 42u32), (root(), 42u32)]);
+	});
+}
+
+#[test]
+fn pause_and_resume_before_execution_keeps_task_in_place() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		// task 42 at #4
+		assert_ok!(Scheduler::do_schedule(
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+				i: 42,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+		assert_noop!(Scheduler::pause(root().into(), (4, 1)), Error::<Test>::NotFound);
+		assert_noop!(Scheduler::resume(root().into(), (4, 0)), Error::<Test>::NotPaused);
+		assert_noop!(Scheduler::pause(system::RawOrigin::Signed(2).into(), (4, 0)), BadOrigin);
+
+		assert_ok!(Scheduler::pause(root().into(), (4, 0)));
+		System::assert_last_event(Event::Paused { task: (4, 0), id: None }.into());
+		run_to_block(3);
+		assert_ok!(Scheduler::resume(root().into(), (4, 0)));
+		System::assert_last_event(Event::Resumed { task: (4, 0), id: None }.into());
+
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+	});
+}
+
+#[test]
+fn cancel_removes_paused_task() {
+	new_test_ext().execute_with(|| {
+		// task 42 at #4
+		assert_ok!(Scheduler::do_schedule(
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+				i: 42,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+		assert_ok!(Scheduler::pause(root().into(), (4, 0)));
+		run_to_block(5);
+		assert!(logger::log().is_empty());
+
+		assert_ok!(Scheduler::cancel(root().into(), 4, 0));
+		assert!(Agenda::<Test>::get(4).is_empty());
+		assert!(Paused::<Test>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn scheduling_at_moment_works() {
+	new_test_ext().execute_with(|| {
+		Now::set(10_000);
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+		assert_noop!(
+			Scheduler::schedule_at_time(
+				RuntimeOrigin::root(),
+				ScheduleTime::AtMoment(9_999),
+				None,
+				127,
+				call.clone()
+			),
+			Error::<Test>::TargetMomentInPast
+		);
+
+		// At most 6s pass per block, so the moment can not have passed before block #4.
+		assert_ok!(Scheduler::schedule_at_time(
+			RuntimeOrigin::root(),
+			ScheduleTime::AtMoment(25_000),
+			None,
+			127,
+			call
+		));
+		assert!(Agenda::<Test>::get(4)[0].is_some());
+		assert_eq!(DispatchMoments::<Test>::get((4, 0)), Some(25_000));
+
+		// Blocks were faster than expected, so the task is moved to a later block.
+		Now::set(20_000);
+		run_to_block(4);
+		assert!(logger::log().is_empty());
+		assert!(Agenda::<Test>::get(4).is_empty());
+		assert!(Agenda::<Test>::get(5)[0].is_some());
+		assert_eq!(DispatchMoments::<Test>::get((5, 0)), Some(25_000));
+
+		Now::set(25_000);
+		run_to_block(5);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert!(Agenda::<Test>::get(5).is_empty());
+		assert!(DispatchMoments::<Test>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn scheduling_after_moment_works() {
+	new_test_ext().execute_with(|| {
+		Now::set(10_000);
+		assert_ok!(Scheduler::schedule_named_at_time(
+			RuntimeOrigin::root(),
+			[1u8; 32],
+			ScheduleTime::AfterMoment(3_000),
+			None,
+			127,
+			Box::new(RuntimeCall::Logger(LoggerCall::log {
+				i: 42,
+				weight: Weight::from_parts(10, 0)
+			}))
+		));
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((2, 0)));
+		assert_eq!(DispatchMoments::<Test>::get((2, 0)), Some(13_000));
+
+		// Canceling the task removes its moment.
+		assert_ok!(Scheduler::cancel_named(RuntimeOrigin::root(), [1u8; 32]));
+		assert!(DispatchMoments::<Test>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn recurrence_places_renewals_by_time() {
+	new_test_ext().execute_with(|| {
+		let recurrence = Recurrence::Every { period: 60_000, offset: 30_000 };
+		assert_eq!(recurrence.next_after(29_999), 30_000);
+		assert_eq!(recurrence.next_after(30_000), 90_000);
+
+		run_to_block(1);
+		Now::set(10_000);
+		let call = || {
+			Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+				i: 42,
+				weight: Weight::from_parts(10, 0),
+			}))
+			.unwrap()
+		};
+		assert_ok!(Scheduler::do_schedule(ScheduleTime::At(4), None, 127, root(), call()));
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			ScheduleTime::At(4),
+			Some((3, 4)),
+			127,
+			root(),
+			call()
+		));
+		assert_noop!(
+			Scheduler::set_recurrence(root().into(), (4, 0), Some(recurrence)),
+			Error::<Test>::NotPeriodic
+		);
+		assert_noop!(
+			Scheduler::set_recurrence_named(
+				root().into(),
+				[1u8; 32],
+				Some(Recurrence::Every { period: 0, offset: 0 })
+			),
+			Error::<Test>::InvalidRecurrence
+		);
+		assert_ok!(Scheduler::set_recurrence_named(root().into(), [1u8; 32], Some(recurrence)));
+		System::assert_last_event(
+			Event::RecurrenceSet {
+				task: (4, 1),
+				id: Some([1u8; 32]),
+				recurrence: Some(recurrence),
+			}
+			.into(),
+		);
+
+		// The renewal is due at 30s, which can not have passed before block #6.
+		Now::set(20_000);
+		run_to_block(4);
+		assert_eq!(logger::log().len(), 2);
+		assert!(Agenda::<Test>::get(7).is_empty());
+		assert!(Agenda::<Test>::get(6)[0].is_some());
+		assert_eq!(DispatchMoments::<Test>::get((6, 0)), Some(30_000));
+		assert_eq!(Recurrences::<Test>::get((6, 0)), Some(recurrence));
+
+		// The next renewal is placed by the time left until its moment rather than the period.
+		Now::set(60_000);
+		run_to_block(6);
+		assert_eq!(logger::log().len(), 3);
+		assert_eq!(DispatchMoments::<Test>::get((12, 0)), Some(90_000));
+		assert_eq!(Recurrences::<Test>::get((12, 0)), Some(recurrence));
+
+		// Clearing the recurrence renews the task every period blocks again.
+		assert_ok!(Scheduler::set_recurrence_named(root().into(), [1u8; 32], None));
+		Now::set(90_000);
+		run_to_block(12);
+		assert_eq!(logger::log().len(), 4);
+		assert!(Recurrences::<Test>::iter().next().is_none());
+		assert!(DispatchMoments::<Test>::iter().next().is_none());
+		assert!(Agenda::<Test>::get(15)[0].is_some());
+	});
+}
+
+#[test]
+fn lookup_and_agenda_work() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bounded = Preimage::bound(call).unwrap();
+		assert_ok!(Scheduler::do_schedule(ScheduleTime::At(4), None, 127, root(), bounded.clone()));
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			ScheduleTime::At(4),
+			Some((3, 3)),
+			10,
+			root(),
+			bounded.clone()
+		));
+		assert_ok!(Scheduler::set_retry_named(root().into(), [1u8; 32], 10, 3));
+		assert_ok!(Scheduler::pause(root().into(), (4, 0)));
+
+		let named = TaskSummary {
+			when: 4,
+			index: 1,
+			maybe_id: Some([1u8; 32]),
+			priority: 10,
+			call_hash: bounded.hash(),
+			maybe_periodic: Some((3, 2)),
+			retry: Some(RetryConfig { total_retries: 10, remaining: 10, period: 3 }),
+			retry_backoff: None,
+			recurrence: None,
+			maybe_moment: None,
+			paused: false,
+			valid_until: None,
+		};
+		assert_eq!(Scheduler::lookup([1u8; 32]), Some(named.clone()));
+		assert_eq!(Scheduler::lookup([2u8; 32]), None);
+		assert_eq!(
+			Scheduler::agenda(4),
+			vec![
+				TaskSummary {
+					when: 4,
+					index: 0,
+					maybe_id: None,
+					priority: 127,
+					call_hash: bounded.hash(),
+					maybe_periodic: None,
+					retry: None,
+					retry_backoff: None,
+					recurrence: None,
+					maybe_moment: None,
+					paused: true,
+					valid_until: None,
+				},
+				named,
+			]
+		);
+		assert!(Scheduler::agenda(5).is_empty());
+	});
+}
+
+#[test]
+fn occupancy_and_next_dispatch_work() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bounded = Preimage::bound(call).unwrap();
+		assert_ok!(Scheduler::do_schedule(ScheduleTime::At(4), None, 127, root(), bounded.clone()));
+		assert_ok!(Scheduler::do_schedule(ScheduleTime::At(4), None, 127, root(), bounded.clone()));
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			ScheduleTime::At(5),
+			None,
+			127,
+			root(),
+			bounded.clone()
+		));
+		assert_ok!(Scheduler::do_schedule_named(
+			[2u8; 32],
+			ScheduleTime::At(5),
+			None,
+			127,
+			root(),
+			bounded
+		));
+		assert_ok!(Scheduler::pause_named(root().into(), [2u8; 32]));
+		assert_ok!(Scheduler::cancel(root().into(), 4, 0));
+
+		assert_eq!(Scheduler::agenda_occupancy(4), AgendaOccupancy { scheduled: 1, capacity: 10 });
+		assert_eq!(Scheduler::agenda_occupancy(6), AgendaOccupancy { scheduled: 0, capacity: 10 });
+		assert_eq!(Scheduler::total_scheduled(), 3);
+		assert_eq!(Scheduler::next_dispatch([1u8; 32]), Some(5));
+		// paused and unknown tasks are not dispatched.
+		assert_eq!(Scheduler::next_dispatch([2u8; 32]), None);
+		assert_eq!(Scheduler::next_dispatch([3u8; 32]), None);
+
+		run_to_block(5);
+		assert_eq!(Scheduler::next_dispatch([1u8; 32]), None);
+		assert_eq!(Scheduler::total_scheduled(), 1);
+	});
+}
+
+#[test]
+fn dispatch_fee_is_charged_on_execution() {
+	new_test_ext().execute_with(|| {
+		DispatchFeePerRefTime::set(1);
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
 			i: 42,
 			weight: Weight::from_parts(10, 0),
 		}));
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+		let fee = <Test as Config>::WeightInfo::execute_dispatch_signed().ref_time() + 10;
 
-		assert_noop!(
-			Scheduler::schedule_named(RuntimeOrigin::root(), [1u8; 32], 2, None, 127, call1),
-			Error::<Test>::TargetBlockNumberInPast,
-		);
+		// Nothing is charged when scheduling.
+		assert_ok!(Scheduler::schedule(RuntimeOrigin::signed(1), 4, None, 127, call.clone()));
+		assert_ok!(Scheduler::schedule(RuntimeOrigin::signed(1), 5, None, 127, call.clone()));
+		assert_ok!(Scheduler::schedule(RuntimeOrigin::root(), 5, None, 127, call));
+		assert_eq!(Balances::free_balance(1), 100);
 
-		assert_noop!(
-			Scheduler::schedule(RuntimeOrigin::root(), 2, None, 127, call2),
-			Error::<Test>::TargetBlockNumberInPast,
-		);
+		// The signed origin pays for the weight of the dispatch.
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(signed.clone(), 42u32)]);
+		assert_eq!(Balances::free_balance(1), 100 - fee);
 
-		assert_noop!(
-			Scheduler::schedule(RuntimeOrigin::root(), 3, None, 127, call3),
-			Error::<Test>::TargetBlockNumberInPast,
+		// A call whose fee can not be paid fails without being dispatched.
+		DispatchFeePerRefTime::set(2);
+		run_to_block(5);
+		assert_eq!(logger::log(), vec![(signed, 42u32), (root(), 42u32)]);
+		assert_eq!(Balances::free_balance(1), 100 - fee);
+		System::assert_has_event(
+			crate::Event::Dispatched {
+				task: (5, 0),
+				id: None,
+				result: Err(TokenError::FundsUnavailable.into()),
+			}
+			.into(),
 		);
 	});
 }
 
 #[test]
-fn should_use_origin() {
+fn schedule_quota_and_deposit_work() {
 	new_test_ext().execute_with(|| {
-		let call = Box::new(RuntimeCall::Logger(LoggerCall::log {
-			i: 69,
-			weight: Weight::from_parts(10, 0),
-		}));
-		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log {
+		ScheduleDeposit::set(10);
+		MaxScheduledPerOrigin::set(Some(2));
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
 			i: 42,
 			weight: Weight::from_parts(10, 0),
 		}));
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+		let reason = HoldReason::ScheduledTask.into();
+
+		// Every task scheduled by a signed origin holds a deposit.
+		assert_ok!(Scheduler::schedule(RuntimeOrigin::signed(1), 4, None, 127, call.clone()));
 		assert_ok!(Scheduler::schedule_named(
-			system::RawOrigin::Signed(1).into(),
+			RuntimeOrigin::signed(1),
 			[1u8; 32],
 			4,
 			None,
 			127,
-			call,
+			call.clone()
 		));
-		assert_ok!(Scheduler::schedule(system::RawOrigin::Signed(1).into(), 4, None, 127, call2,));
-		run_to_block(3);
-		// Scheduled calls are in the agenda.
-		assert_eq!(Agenda::<Test>::get(4).len(), 2);
-		assert!(logger::log().is_empty());
-		assert_ok!(Scheduler::cancel_named(system::RawOrigin::Signed(1).into(), [1u8; 32]));
-		assert_ok!(Scheduler::cancel(system::RawOrigin::Signed(1).into(), 4, 1));
-		// Scheduled calls are made NONE, so should not effect state
-		run_to_block(100);
-		assert!(logger::log().is_empty());
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 20);
+		assert_eq!(ScheduledCount::<Test>::get(&signed), 2);
+
+		// The quota of the origin is used up.
+		assert_noop!(
+			Scheduler::schedule(RuntimeOrigin::signed(1), 5, None, 127, call.clone()),
+			Error::<Test>::TooManyScheduled
+		);
+		// Other origins have their own quota and do not pay a deposit.
+		assert_ok!(Scheduler::schedule(RuntimeOrigin::root(), 4, None, 127, call.clone()));
+		assert_eq!(ScheduledCount::<Test>::get(root()), 1);
+
+		// Canceling a task releases its quota slot and deposit.
+		assert_ok!(Scheduler::cancel_named(RuntimeOrigin::signed(1), [1u8; 32]));
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 10);
+		assert_eq!(ScheduledCount::<Test>::get(&signed), 1);
+
+		// The reservation moves along with a rescheduled task.
+		assert_ok!(Scheduler::do_reschedule(None, (4, 0), ScheduleTime::At(5)));
+		assert!(Reservations::<Test>::get((5, 0)).is_some());
+		assert!(Reservations::<Test>::get((4, 0)).is_none());
+
+		// Executing a task releases its quota slot and deposit.
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 10);
+		assert!(!ScheduledCount::<Test>::contains_key(root()));
+		run_to_block(5);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (signed.clone(), 42u32)]);
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 0);
+		assert!(!ScheduledCount::<Test>::contains_key(&signed));
+		assert_eq!(Reservations::<Test>::iter().count(), 0);
 	});
 }
 
 #[test]
-fn should_check_origin() {
+fn scheduling_horizon_works() {
 	new_test_ext().execute_with(|| {
-		let call = Box::new(RuntimeCall::Logger(LoggerCall::log {
-			i: 69,
-			weight: Weight::from_parts(10, 0),
-		}));
-		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log {
+		run_to_block(1);
+		MaxSchedulingHorizon::set(Some(10));
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
 			i: 42,
 			weight: Weight::from_parts(10, 0),
 		}));
+
+		// Signed origins cannot schedule tasks beyond the horizon.
 		assert_noop!(
-			Scheduler::schedule_named(
-				system::RawOrigin::Signed(2).into(),
-				[1u8; 32],
-				4,
+			Scheduler::schedule(RuntimeOrigin::signed(1), 12, None, 127, call.clone()),
+			Error::<Test>::TargetBeyondHorizon
+		);
+		assert_noop!(
+			Scheduler::schedule_after(RuntimeOrigin::signed(1), 10, None, 127, call.clone()),
+			Error::<Test>::TargetBeyondHorizon
+		);
+		assert_ok!(Scheduler::schedule(RuntimeOrigin::signed(1), 11, None, 127, call.clone()));
+		assert_ok!(Scheduler::schedule_after(RuntimeOrigin::signed(1), 9, None, 127, call.clone()));
+		// Root is exempt.
+		assert_ok!(Scheduler::schedule(RuntimeOrigin::root(), 100, None, 127, call.clone()));
+		// Time-based tasks are held to the horizon by the earliest block they may be due at.
+		assert_noop!(
+			Scheduler::schedule_at_time(
+				RuntimeOrigin::signed(1),
+				ScheduleTime::AfterMoment(60_000),
 				None,
 				127,
-				call
+				call.clone()
 			),
-			BadOrigin
+			Error::<Test>::TargetBeyondHorizon
 		);
+		assert_ok!(Scheduler::schedule_at_time(
+			RuntimeOrigin::signed(1),
+			ScheduleTime::AfterMoment(54_000),
+			None,
+			127,
+			call.clone()
+		));
+
+		// A task scheduled before the horizon was lowered can still be moved closer, but not
+		// further out.
+		MaxSchedulingHorizon::set(None);
+		assert_ok!(Scheduler::schedule_named(
+			RuntimeOrigin::signed(1),
+			[1u8; 32],
+			50,
+			None,
+			127,
+			call.clone()
+		));
+		MaxSchedulingHorizon::set(Some(10));
 		assert_noop!(
-			Scheduler::schedule(system::RawOrigin::Signed(2).into(), 4, None, 127, call2),
-			BadOrigin
+			Scheduler::reschedule_named(RuntimeOrigin::signed(1), [1u8; 32], ScheduleTime::At(60)),
+			Error::<Test>::TargetBeyondHorizon
 		);
+		assert_ok!(Scheduler::reschedule_named(
+			RuntimeOrigin::signed(1),
+			[1u8; 32],
+			ScheduleTime::At(40)
+		));
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((40, 0)));
 	});
 }
 
 #[test]
-fn should_check_origin_for_cancel() {
+fn dependant_task_is_placed_after_parent_succeeds() {
 	new_test_ext().execute_with(|| {
-		let call = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
+		run_to_block(1);
+		// named task 42 at #4
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			ScheduleTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+				i: 42,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log {
 			i: 69,
 			weight: Weight::from_parts(10, 0),
 		}));
-		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
-			i: 42,
-			weight: Weight::from_parts(10, 0),
-		}));
-		assert_ok!(Scheduler::schedule_named(
-			system::RawOrigin::Signed(1).into(),
+		// the task depended on must exist
+		assert_noop!(
+			Scheduler::schedule_named_after_task(
+				root().into(),
+				[2u8; 32],
+				[3u8; 32],
+				2,
+				None,
+				127,
+				call.clone()
+			),
+			Error::<Test>::NotFound
+		);
+		// task 69 two blocks after task 42 succeeded
+		assert_ok!(Scheduler::schedule_named_after_task(
+			root().into(),
+			[2u8; 32],
 			[1u8; 32],
-			4,
+			2,
 			None,
 			127,
-			call,
+			call.clone()
 		));
-		assert_ok!(Scheduler::schedule(system::RawOrigin::Signed(1).into(), 4, None, 127, call2,));
-		run_to_block(3);
-		// Scheduled calls are in the agenda.
-		assert_eq!(Agenda::<Test>::get(4).len(), 2);
-		assert!(logger::log().is_empty());
+		System::assert_last_event(
+			Event::DependantScheduled { id: [2u8; 32], depends_on: (4, 0) }.into(),
+		);
+		// the name of a waiting task is taken
 		assert_noop!(
-			Scheduler::cancel_named(system::RawOrigin::Signed(2).into(), [1u8; 32]),
-			BadOrigin
+			Scheduler::schedule_named_after_task(
+				root().into(),
+				[2u8; 32],
+				[1u8; 32],
+				2,
+				None,
+				127,
+				call.clone()
+			),
+			Error::<Test>::FailedToSchedule
 		);
-		assert_noop!(Scheduler::cancel(system::RawOrigin::Signed(2).into(), 4, 1), BadOrigin);
-		assert_noop!(Scheduler::cancel_named(system::RawOrigin::Root.into(), [1u8; 32]), BadOrigin);
-		assert_noop!(Scheduler::cancel(system::RawOrigin::Root.into(), 4, 1), BadOrigin);
-		run_to_block(5);
-		assert_eq!(
-			logger::log(),
-			vec![
-				(system::RawOrigin::Signed(1).into(), 69u32),
-				(system::RawOrigin::Signed(1).into(), 42u32)
-			]
+		assert_noop!(
+			Scheduler::schedule_named(root().into(), [2u8; 32], 5, None, 127, call),
+			Error::<Test>::FailedToSchedule
 		);
+		assert!(Scheduler::lookup([2u8; 32]).is_none());
+
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert_eq!(Scheduler::lookup([2u8; 32]).map(|t| (t.when, t.index)), Some((6, 0)));
+		assert_eq!(DependsOn::<Test>::iter().count(), 0);
+		assert_eq!(Dependants::<Test>::iter().count(), 0);
+		run_to_block(6);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 69u32)]);
 	});
 }
 
 #[test]
-fn cancel_removes_retry_entry() {
+fn dependant_task_follows_retries_of_parent() {
 	new_test_ext().execute_with(|| {
-		// task fails until block 99 is reached
-		Threshold::<Test>::put((99, 100));
-		// task 20 at #4
-		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+		run_to_block(1);
+		// task fails until block 6 is reached
+		Threshold::<Test>::put((6, 100));
+		// named task 42 at #4, retried once after 2 blocks
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
 			Preimage::bound(RuntimeCall::Logger(logger::Call::timed_log {
-				i: 20,
+				i: 42,
 				weight: Weight::from_parts(10, 0)
 			}))
 			.unwrap()
 		));
-		// named task 42 at #4
-		assert_ok!(Scheduler::do_schedule_named(
+		assert_ok!(Scheduler::set_retry_named(root().into(), [1u8; 32], 1, 2));
+		assert_ok!(Scheduler::schedule_named_after_task(
+			root().into(),
+			[2u8; 32],
 			[1u8; 32],
-			DispatchTime::At(4),
+			1,
 			None,
 			127,
-			root(),
-			Preimage::bound(RuntimeCall::Logger(logger::Call::timed_log {
-				i: 42,
+			Box::new(RuntimeCall::Logger(LoggerCall::log {
+				i: 69,
 				weight: Weight::from_parts(10, 0)
 			}))
-			.unwrap()
 		));
 
-		assert_eq!(Agenda::<Test>::get(4).len(), 2);
-		// task 20 will be retried 3 times every block
-		assert_ok!(Scheduler::set_retry(root().into(), (4, 0), 10, 1));
-		// task 42 will be retried 10 times every 3 blocks
-		assert_ok!(Scheduler::set_retry_named(root().into(), [1u8; 32], 10, 1));
-		assert_eq!(Retries::<Test>::iter().count(), 2);
-		run_to_block(3);
-		assert!(logger::log().is_empty());
-		assert_eq!(Agenda::<Test>::get(4).len(), 2);
-		// both tasks fail
+		// the task failed but is retried, so the dependant keeps waiting
 		run_to_block(4);
-		assert!(Agenda::<Test>::get(4).is_empty());
-		// 42 and 20 are rescheduled for next block
-		assert_eq!(Agenda::<Test>::get(5).len(), 2);
-		assert!(logger::log().is_empty());
-		// 42 and 20 still fail
-		run_to_block(5);
-		// 42 and 20 rescheduled for next block
-		assert_eq!(Agenda::<Test>::get(6).len(), 2);
-		assert_eq!(Retries::<Test>::iter().count(), 2);
 		assert!(logger::log().is_empty());
-
-		// even though 42 is being retried, the tasks scheduled for retries are not named
-		assert_eq!(Lookup::<Test>::iter().count(), 0);
-		assert!(Scheduler::cancel(root().into(), 6, 0).is_ok());
-
-		// 20 is removed, 42 still fails
+		assert_eq!(DependsOn::<Test>::get([2u8; 32]), Some((6, 0)));
 		run_to_block(6);
-		// 42 rescheduled for next block
-		assert_eq!(Agenda::<Test>::get(7).len(), 1);
-		// 20's retry entry is removed
-		assert!(!Retries::<Test>::contains_key((4, 0)));
-		assert_eq!(Retries::<Test>::iter().count(), 1);
-		assert!(logger::log().is_empty());
-
-		assert!(Scheduler::cancel(root().into(), 7, 0).is_ok());
-
-		// both tasks are canceled, everything is removed now
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
 		run_to_block(7);
-		assert!(Agenda::<Test>::get(8).is_empty());
-		assert_eq!(Retries::<Test>::iter().count(), 0);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 69u32)]);
 	});
 }
 
 #[test]
-fn cancel_retries_works() {
+fn dependant_task_is_dropped_when_parent_fails() {
 	new_test_ext().execute_with(|| {
-		// task fails until block 99 is reached
-		Threshold::<Test>::put((99, 100));
-		// task 20 at #4
-		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+		run_to_block(1);
+		// task always fails
+		Threshold::<Test>::put((100, 200));
+		// named task 42 at #4
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
 			Preimage::bound(RuntimeCall::Logger(logger::Call::timed_log {
-				i: 20,
+				i: 42,
 				weight: Weight::from_parts(10, 0)
 			}))
 			.unwrap()
 		));
-		// named task 42 at #4
-		assert_ok!(Scheduler::do_schedule_named(
+		assert_ok!(Scheduler::schedule_named_after_task(
+			root().into(),
+			[2u8; 32],
 			[1u8; 32],
-			DispatchTime::At(4),
+			1,
 			None,
 			127,
-			root(),
-			Preimage::bound(RuntimeCall::Logger(logger::Call::timed_log {
-				i: 42,
+			Box::new(RuntimeCall::Logger(LoggerCall::log {
+				i: 69,
 				weight: Weight::from_parts(10, 0)
 			}))
-			.unwrap()
 		));
 
-		assert_eq!(Agenda::<Test>::get(4).len(), 2);
-		// task 20 will be retried 3 times every block
-		assert_ok!(Scheduler::set_retry(root().into(), (4, 0), 10, 1));
-		// task 42 will be retried 10 times every 3 blocks
-		assert_ok!(Scheduler::set_retry_named(root().into(), [1u8; 32], 10, 1));
-		assert_eq!(Retries::<Test>::iter().count(), 2);
-		run_to_block(3);
-		assert!(logger::log().is_empty());
-		assert_eq!(Agenda::<Test>::get(4).len(), 2);
-		// cancel the retry config for 20
-		assert_ok!(Scheduler::cancel_retry(root().into(), (4, 0)));
-		assert_eq!(Retries::<Test>::iter().count(), 1);
-		// cancel the retry config for 42
-		assert_ok!(Scheduler::cancel_retry_named(root().into(), [1u8; 32]));
-		assert_eq!(Retries::<Test>::iter().count(), 0);
 		run_to_block(4);
-		// both tasks failed and there are no more retries, so they are evicted
-		assert_eq!(Agenda::<Test>::get(4).len(), 0);
-		assert_eq!(Retries::<Test>::iter().count(), 0);
+		System::assert_last_event(
+			Event::DependencyFailed { id: [2u8; 32], depends_on: (4, 0) }.into(),
+		);
+		assert_eq!(DependsOn::<Test>::iter().count(), 0);
+		assert_eq!(Dependants::<Test>::iter().count(), 0);
+		run_to_block(10);
+		assert!(logger::log().is_empty());
+		// the name can be used again
+		assert_ok!(Scheduler::schedule_named(
+			root().into(),
+			[2u8; 32],
+			12,
+			None,
+			127,
+			Box::new(RuntimeCall::Logger(LoggerCall::log {
+				i: 69,
+				weight: Weight::from_parts(10, 0)
+			}))
+		));
 	});
 }
 
@@ -2276,7 +3931,7 @@ fn postponed_named_task_cannot_be_rescheduled() {
 
 		let address = Scheduler::do_schedule_named(
 			name,
-			DispatchTime::At(4),
+			ScheduleTime::At(4),
 			None,
 			127,
 			root(),
@@ -2328,12 +3983,12 @@ fn postponed_named_task_cannot_be_rescheduled() {
 
 		// Manually re-schedule the call by name does not work.
 		assert_err!(
-			Scheduler::do_reschedule_named(name, DispatchTime::At(1001)),
+			Scheduler::do_reschedule_named(None, name, ScheduleTime::At(1001)),
 			Error::<Test>::NotFound
 		);
 		// Manually re-scheduling the call by address errors.
 		assert_err!(
-			Scheduler::do_reschedule(address, DispatchTime::At(1001)),
+			Scheduler::do_reschedule(None, address, ScheduleTime::At(1001)),
 			Error::<Test>::Named
 		);
 	});
@@ -2854,7 +4509,7 @@ fn cancel_last_task_removes_agenda() {
 		let call =
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
 		let address = Scheduler::do_schedule(
-			DispatchTime::At(when),
+			ScheduleTime::At(when),
 			None,
 			127,
 			root(),
@@ -2862,7 +4517,7 @@ fn cancel_last_task_removes_agenda() {
 		)
 		.unwrap();
 		let address2 = Scheduler::do_schedule(
-			DispatchTime::At(when),
+			ScheduleTime::At(when),
 			None,
 			127,
 			root(),
@@ -2889,7 +4544,7 @@ fn cancel_named_last_task_removes_agenda() {
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
 		Scheduler::do_schedule_named(
 			[1u8; 32],
-			DispatchTime::At(when),
+			ScheduleTime::At(when),
 			None,
 			127,
 			root(),
@@ -2898,7 +4553,7 @@ fn cancel_named_last_task_removes_agenda() {
 		.unwrap();
 		Scheduler::do_schedule_named(
 			[2u8; 32],
-			DispatchTime::At(when),
+			ScheduleTime::At(when),
 			None,
 			127,
 			root(),
@@ -2924,7 +4579,7 @@ fn reschedule_last_task_removes_agenda() {
 		let call =
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
 		let address = Scheduler::do_schedule(
-			DispatchTime::At(when),
+			ScheduleTime::At(when),
 			None,
 			127,
 			root(),
@@ -2932,7 +4587,7 @@ fn reschedule_last_task_removes_agenda() {
 		)
 		.unwrap();
 		let address2 = Scheduler::do_schedule(
-			DispatchTime::At(when),
+			ScheduleTime::At(when),
 			None,
 			127,
 			root(),
@@ -2946,7 +4601,7 @@ fn reschedule_last_task_removes_agenda() {
 		assert!(Agenda::<Test>::get(when).len() == 2);
 		// reschedule last task from `when` agenda.
 		assert_eq!(
-			Scheduler::do_reschedule(address2, DispatchTime::At(when + 1)).unwrap(),
+			Scheduler::do_reschedule(None, address2, ScheduleTime::At(when + 1)).unwrap(),
 			(when + 1, 0)
 		);
 		// if all tasks `None`, agenda fully removed.
@@ -2962,7 +4617,7 @@ fn reschedule_named_last_task_removes_agenda() {
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
 		Scheduler::do_schedule_named(
 			[1u8; 32],
-			DispatchTime::At(when),
+			ScheduleTime::At(when),
 			None,
 			127,
 			root(),
@@ -2971,7 +4626,7 @@ fn reschedule_named_last_task_removes_agenda() {
 		.unwrap();
 		Scheduler::do_schedule_named(
 			[2u8; 32],
-			DispatchTime::At(when),
+			ScheduleTime::At(when),
 			None,
 			127,
 			root(),
@@ -2985,7 +4640,7 @@ fn reschedule_named_last_task_removes_agenda() {
 		assert!(Agenda::<Test>::get(when).len() == 2);
 		// reschedule last task from `when` agenda.
 		assert_eq!(
-			Scheduler::do_reschedule_named([2u8; 32], DispatchTime::At(when + 1)).unwrap(),
+			Scheduler::do_reschedule_named(None, [2u8; 32], ScheduleTime::At(when + 1)).unwrap(),
 			(when + 1, 0)
 		);
 		// if all tasks `None`, agenda fully removed.
@@ -37,6 +37,15 @@ pub struct Pallet<T: Config>(pallet_session::Pallet<T>);
 pub trait Config:
 	pallet_session::Config + pallet_session::historical::Config + pallet_staking::Config
 {
+	/// Generate a set of session keys along with a proof that they are possessed by `owner`.
+	///
+	/// Needs to be implemented by runtimes setting
+	/// [`pallet_session::Config::RequireProofOfPossession`], so that the proof is verified in the
+	/// benchmarks.
+	fn generate_session_keys_and_proof(_owner: Self::AccountId) -> (Self::Keys, Vec<u8>) {
+		let keys = Self::Keys::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+		(keys, vec![0, 1, 2, 3])
+	}
 }
 
 impl<T: Config> OnInitialize<BlockNumberFor<T>> for Pallet<T> {
@@ -57,8 +66,7 @@ benchmarks! {
 		)?;
 		let v_controller = pallet_staking::Pallet::<T>::bonded(&v_stash).ok_or("not stash")?;
 
-		let keys = T::Keys::decode(&mut TrailingZeroInput::zeroes()).unwrap();
-		let proof: Vec<u8> = vec![0,1,2,3];
+		let (keys, proof) = T::generate_session_keys_and_proof(v_controller.clone());
 		// Whitelist controller account from further DB operations.
 		let v_controller_key = frame_system::Account::<T>::hashed_key_for(&v_controller);
 		frame_benchmarking::benchmarking::add_to_whitelist(v_controller_key.into());
@@ -74,8 +82,7 @@ benchmarks! {
 			RewardDestination::Staked,
 		)?;
 		let v_controller = pallet_staking::Pallet::<T>::bonded(&v_stash).ok_or("not stash")?;
-		let keys = T::Keys::decode(&mut TrailingZeroInput::zeroes()).unwrap();
-		let proof: Vec<u8> = vec![0,1,2,3];
+		let (keys, proof) = T::generate_session_keys_and_proof(v_controller.clone());
 		Session::<T>::set_keys(RawOrigin::Signed(v_controller.clone()).into(), keys, proof)?;
 		// Whitelist controller account from further DB operations.
 		let v_controller_key = frame_system::Account::<T>::hashed_key_for(&v_controller);
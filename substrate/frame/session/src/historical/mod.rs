@@ -33,10 +33,13 @@ mod shared;
 use codec::{Decode, Encode};
 use sp_runtime::{
 	traits::{Convert, OpaqueKeys},
-	KeyTypeId,
+	KeyTypeId, Perbill,
 };
 use sp_session::{MembershipProof, ValidatorCount};
-use sp_staking::SessionIndex;
+use sp_staking::{
+	offence::{OffenceDetails, OnOffenceHandler},
+	SessionIndex,
+};
 use sp_std::prelude::*;
 use sp_trie::{
 	trie_types::{TrieDBBuilder, TrieDBMutBuilderV0},
@@ -220,6 +223,32 @@ where
 pub type IdentificationTuple<T> =
 	(<T as pallet_session::Config>::ValidatorId, <T as Config>::FullIdentification);
 
+/// An [`OnOffenceHandler`] which disables the offenders in the current session without slashing
+/// them.
+///
+/// Offences from past sessions are ignored, since the validators disabled in a session are
+/// re-enabled once the session ends.
+pub struct DisableOffenders<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config, Reporter, Res: Default> OnOffenceHandler<Reporter, IdentificationTuple<T>, Res>
+	for DisableOffenders<T>
+{
+	fn on_offence(
+		offenders: &[OffenceDetails<Reporter, IdentificationTuple<T>>],
+		_slash_fraction: &[Perbill],
+		session: SessionIndex,
+	) -> Res {
+		if session == Session::<T>::current_index() {
+			for details in offenders {
+				let (validator_id, _) = &details.offender;
+				Session::<T>::disable(validator_id);
+			}
+		}
+
+		Default::default()
+	}
+}
+
 /// A trie instance for checking and generating proofs.
 pub struct ProvingTrie<T: Config> {
 	db: MemoryDB<T::Hashing>,
@@ -492,4 +521,27 @@ pub(crate) mod tests {
 			}
 		});
 	}
+
+	#[test]
+	fn disable_offenders_works() {
+		new_test_ext().execute_with(|| {
+			let offenders = vec![OffenceDetails { offender: (2, 2), reporters: vec![] }];
+			let slash_fraction = vec![Perbill::zero()];
+
+			// offences from other sessions are ignored.
+			<DisableOffenders<Test> as OnOffenceHandler<u64, _, ()>>::on_offence(
+				&offenders,
+				&slash_fraction,
+				Session::current_index() + 1,
+			);
+			assert!(Session::disabled_validators().is_empty());
+
+			<DisableOffenders<Test> as OnOffenceHandler<u64, _, ()>>::on_offence(
+				&offenders,
+				&slash_fraction,
+				Session::current_index(),
+			);
+			assert_eq!(Session::disabled_validators(), vec![1]);
+		});
+	}
 }
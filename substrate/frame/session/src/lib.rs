@@ -115,7 +115,7 @@ mod mock;
 mod tests;
 pub mod weights;
 
-use codec::{Decode, MaxEncodedLen};
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
@@ -414,6 +414,14 @@ pub mod pallet {
 		/// The keys.
 		type Keys: OpaqueKeys + Member + Parameter + MaybeSerializeDeserialize;
 
+		/// Whether [`Pallet::set_keys`] requires a proof that the caller possesses every key.
+		///
+		/// If `true`, the proof must consist of the SCALE encoded signatures of the encoded caller
+		/// account, made with each of the keys in the order of [`Config::Keys`]. See
+		/// [`OpaqueKeys::proof_of_possession_is_valid`].
+		#[pallet::constant]
+		type RequireProofOfPossession: Get<bool>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -571,6 +579,9 @@ pub mod pallet {
 		///
 		/// The dispatch origin of this function must be signed.
 		///
+		/// If [`Config::RequireProofOfPossession`] is set, `proof` must contain a signature of the
+		/// caller account by each of the `keys`.
+		///
 		/// ## Complexity
 		/// - `O(1)`. Actual cost depends on the number of length of `T::Keys::key_ids()` which is
 		///   fixed.
@@ -578,7 +589,14 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::set_keys())]
 		pub fn set_keys(origin: OriginFor<T>, keys: T::Keys, proof: Vec<u8>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			ensure!(keys.ownership_proof_is_valid(&proof), Error::<T>::InvalidProof);
+			if T::RequireProofOfPossession::get() {
+				ensure!(
+					keys.proof_of_possession_is_valid(&who.encode(), &proof),
+					Error::<T>::InvalidProof
+				);
+			} else {
+				ensure!(keys.ownership_proof_is_valid(&proof), Error::<T>::InvalidProof);
+			}
 
 			Self::do_set_keys(&who, keys)?;
 			Ok(())
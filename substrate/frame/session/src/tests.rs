@@ -21,11 +21,11 @@ use super::*;
 use crate::mock::{
 	authorities, before_session_end_called, force_new_session, new_test_ext,
 	reset_before_session_end_called, session_changed, set_next_validators, set_session_length,
-	PreUpgradeMockSessionKeys, RuntimeOrigin, Session, SessionChanged, System, Test,
-	TestSessionChanged, TestValidatorIdOf,
+	PreUpgradeMockSessionKeys, RequireProofOfPossession, RuntimeOrigin, Session, SessionChanged,
+	System, Test, TestSessionChanged, TestValidatorIdOf,
 };
 
-use codec::Decode;
+use codec::{Decode, Encode};
 use sp_core::crypto::key_types::DUMMY;
 use sp_runtime::testing::UintAuthorityId;
 
@@ -366,6 +366,37 @@ fn session_keys_generate_output_works_as_set_keys_input() {
 	});
 }
 
+#[test]
+fn set_keys_requires_proof_of_possession_if_configured() {
+	new_test_ext().execute_with(|| {
+		RequireProofOfPossession::set(true);
+		let keys = mock::MockSessionKeys::from(UintAuthorityId(10));
+
+		// An empty proof or one for another account is rejected.
+		assert_noop!(
+			Session::set_keys(RuntimeOrigin::signed(1), keys.clone(), vec![]),
+			Error::<Test>::InvalidProof,
+		);
+		let proof = keys.create_proof_of_possession(&2u64.encode()).unwrap();
+		assert_noop!(
+			Session::set_keys(RuntimeOrigin::signed(1), keys.clone(), proof),
+			Error::<Test>::InvalidProof,
+		);
+
+		// Trailing bytes after the signatures are rejected.
+		let mut proof = keys.create_proof_of_possession(&1u64.encode()).unwrap();
+		proof.push(0);
+		assert_noop!(
+			Session::set_keys(RuntimeOrigin::signed(1), keys.clone(), proof),
+			Error::<Test>::InvalidProof,
+		);
+
+		let proof = keys.create_proof_of_possession(&1u64.encode()).unwrap();
+		assert_ok!(Session::set_keys(RuntimeOrigin::signed(1), keys.clone(), proof));
+		assert_eq!(Session::load_keys(&1), Some(keys));
+	});
+}
+
 #[test]
 fn disable_index_returns_false_if_already_disabled() {
 	new_test_ext().execute_with(|| {
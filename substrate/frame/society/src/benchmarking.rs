@@ -368,6 +368,24 @@ benchmarks_instance_pallet! {
 		assert_eq!(DefenderVotes::<T, I>::get(challenge_round, &defender), None);
 	}
 
+	claim_all_matured {
+		let n in 1 .. T::MaxPayouts::get();
+		let founder = setup_funded_society::<T, I>()?;
+		// Payee's account already exists and is a member.
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, mock_balance_deposit::<T, I>());
+		let _ = Society::<T, I>::insert_member(&caller, 0u32.into());
+		// Introduce matured payouts.
+		for i in 0..n {
+			Society::<T, I>::bump_payout(&caller, i.into(), 1u32.into());
+		}
+		frame_system::Pallet::<T>::set_block_number(n.into());
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		let record = Payouts::<T, I>::get(caller);
+		assert!(record.payouts.is_empty());
+	}
+
 	impl_benchmark_test_suite!(
 		Society,
 		sp_io::TestExternalities::from(
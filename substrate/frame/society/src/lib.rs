@@ -188,7 +188,9 @@
 //!
 //! Regular participation involves voting on candidates who want to join the membership
 //! society, and by voting in the right way, a member will accumulate future payouts.
-//! When a payout matures, members are able to claim those payouts.
+//! When a payout matures, members are able to claim those payouts. Large payouts may be
+//! smoothed over several rotation periods, as configured by
+//! [`Config::PayoutSmoothingThreshold`] and [`Config::PayoutSmoothingPeriods`].
 //!
 //! Members can also vouch for users to join the society, and request a "tip" from
 //! the fees the new member would collect by joining the society. This vouching
@@ -228,6 +230,7 @@
 //! * `defender_vote` - A member can vote to approve or reject a defender's continued membership
 //! to the society.
 //! * `payout` - A member can claim their first matured payment.
+//! * `claim_all_matured` - A member can claim all of their matured payments at once.
 //! * `unfound` - Allow the founder to unfound the society when they are the only member.
 //!
 //! #### For Super Users
@@ -525,6 +528,17 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxBids: Get<u32>;
 
+		/// Payouts of accepted candidates and their vouchers which are larger than this are
+		/// smoothed over `PayoutSmoothingPeriods` rotation periods. `None` disables smoothing.
+		#[pallet::constant]
+		type PayoutSmoothingThreshold: Get<Option<BalanceOf<Self, I>>>;
+
+		/// The number of rotation periods over which large payouts are smoothed.
+		///
+		/// Each smoothed payout takes up to this number of the `MaxPayouts` slots of a member.
+		#[pallet::constant]
+		type PayoutSmoothingPeriods: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -784,6 +798,13 @@ pub mod pallet {
 
 			weight
 		}
+
+		fn integrity_test() {
+			assert!(
+				T::PayoutSmoothingPeriods::get() <= T::MaxPayouts::get(),
+				"`PayoutSmoothingPeriods` must not exceed `MaxPayouts`",
+			);
+		}
 	}
 
 	#[pallet::genesis_config]
@@ -1026,6 +1047,39 @@ pub mod pallet {
 			Err(Error::<T, I>::NoPayout)?
 		}
 
+		/// Transfer all matured payouts for the sender and remove them from the records.
+		///
+		/// Payment: The member will receive a payment equal to the sum of their matured payouts
+		/// to their free balance.
+		///
+		/// The dispatch origin for this call must be _Signed_ and a member with
+		/// payouts remaining.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::claim_all_matured(T::MaxPayouts::get()))]
+		pub fn claim_all_matured(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				Members::<T, I>::get(&who).ok_or(Error::<T, I>::NotMember)?.rank == 0,
+				Error::<T, I>::NoPayout
+			);
+			let mut record = Payouts::<T, I>::get(&who);
+
+			// Payouts are sorted by their maturity.
+			let now = <frame_system::Pallet<T>>::block_number();
+			let matured = record.payouts.iter().take_while(|(when, _)| when <= &now).count();
+			ensure!(matured > 0, Error::<T, I>::NoPayout);
+			let amount = record.payouts[..matured]
+				.iter()
+				.try_fold(BalanceOf::<T, I>::zero(), |total, (_, amount)| total.checked_add(amount))
+				.ok_or(Overflow)?;
+			record.paid = record.paid.checked_add(&amount).ok_or(Overflow)?;
+			T::Currency::transfer(&Self::payouts(), &who, amount, AllowDeath)?;
+			record.payouts = BoundedVec::truncate_from(record.payouts[matured..].to_vec());
+			Payouts::<T, I>::insert(&who, record);
+
+			Ok(Some(T::WeightInfo::claim_all_matured(matured as u32)).into())
+		}
+
 		/// Repay the payment previously given to the member with the signed origin, remove any
 		/// pending payments, and elevate them from rank 0 to rank 1.
 		#[pallet::call_index(7)]
@@ -1915,7 +1969,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						// In the case that a vouched-for bid is accepted we unset the
 						// vouching status and transfer the tip over to the voucher.
 						record.vouching = None;
-						Self::bump_payout(&voucher, maturity, tip.min(value));
+						Self::bump_smoothed_payout(&voucher, maturity, tip.min(value));
 						Members::<T, I>::insert(&voucher, record);
 						value.saturating_sub(tip)
 					} else {
@@ -1927,7 +1981,59 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			},
 		};
 
-		Self::bump_payout(candidate, maturity, value);
+		Self::bump_smoothed_payout(candidate, maturity, value);
+	}
+
+	/// Bump the payout amount of `who` like [`Self::bump_payout`], splitting a `value` larger
+	/// than [`Config::PayoutSmoothingThreshold`] into [`Config::PayoutSmoothingPeriods`] payouts,
+	/// each unlocked one rotation period after the previous one.
+	///
+	/// The value is split over fewer payouts if `who` has not enough free payout slots left.
+	fn bump_smoothed_payout(who: &T::AccountId, when: BlockNumberFor<T>, value: BalanceOf<T, I>) {
+		let periods = T::PayoutSmoothingPeriods::get();
+		match T::PayoutSmoothingThreshold::get() {
+			Some(threshold) if value > threshold && periods > 1 => {
+				let rotation_period = T::VotingPeriod::get() + T::ClaimPeriod::get();
+				let periods = Self::smoothing_periods(who, when, rotation_period, periods);
+				let part = value / periods.into();
+				let mut rest = value;
+				let mut when = when;
+				for _ in 1..periods {
+					Self::bump_payout(who, when, part);
+					rest.saturating_reduce(part);
+					when.saturating_accrue(rotation_period);
+				}
+				// The last payout includes the remainder of the division.
+				Self::bump_payout(who, when, rest);
+			},
+			_ => Self::bump_payout(who, when, value),
+		}
+	}
+
+	/// The number of payouts, up to `periods`, that fit into the payout slots of `who` when
+	/// splitting a payout unlocked at `when` over rotation periods. At least one.
+	fn smoothing_periods(
+		who: &T::AccountId,
+		when: BlockNumberFor<T>,
+		rotation_period: BlockNumberFor<T>,
+		periods: u32,
+	) -> u32 {
+		let payouts = Payouts::<T, I>::get(who).payouts;
+		let mut free = (T::MaxPayouts::get() as usize).saturating_sub(payouts.len());
+		let mut fitting = 0;
+		let mut when = when;
+		while fitting < periods {
+			// A payout at an already used block number is merged into the existing one.
+			if payouts.binary_search_by_key(&when, |x| x.0).is_err() {
+				if free == 0 {
+					break
+				}
+				free -= 1;
+			}
+			fitting += 1;
+			when.saturating_accrue(rotation_period);
+		}
+		fitting.max(1)
 	}
 
 	/// Bump the payout amount of `who`, to be unlocked at the given block number.
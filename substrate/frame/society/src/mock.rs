@@ -45,6 +45,10 @@ parameter_types! {
 	pub const SocietyPalletId: PalletId = PalletId(*b"py/socie");
 }
 
+parameter_types! {
+	pub static PayoutSmoothingThreshold: Option<u64> = None;
+}
+
 ord_parameter_types! {
 	pub const ChallengePeriod: u64 = 8;
 	pub const ClaimPeriod: u64 = 1;
@@ -82,6 +86,8 @@ impl Config for Test {
 	type ChallengePeriod = ChallengePeriod;
 	type MaxPayouts = MaxPayouts;
 	type MaxBids = MaxBids;
+	type PayoutSmoothingThreshold = PayoutSmoothingThreshold;
+	type PayoutSmoothingPeriods = ConstU32<3>;
 	type WeightInfo = ();
 }
 
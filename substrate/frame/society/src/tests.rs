@@ -342,6 +342,72 @@ fn payout_works() {
 	});
 }
 
+#[test]
+fn claim_all_matured_works() {
+	EnvBuilder::new().execute(|| {
+		assert_eq!(Balances::free_balance(20), 50);
+		place_members([20]);
+		Society::bump_payout(&20, 5, 100);
+		Society::bump_payout(&20, 10, 200);
+		Society::bump_payout(&20, 15, 300);
+		// payouts not ready
+		assert_noop!(Society::claim_all_matured(Origin::signed(20)), Error::<Test>::NoPayout);
+		assert_noop!(Society::claim_all_matured(Origin::signed(30)), Error::<Test>::NotMember);
+		// the first two payouts are claimed at once
+		System::set_block_number(10);
+		assert_ok!(Society::claim_all_matured(Origin::signed(20)));
+		assert_eq!(Balances::free_balance(20), 350);
+		assert_eq!(
+			Payouts::<Test>::get(20),
+			PayoutRecord { paid: 300, payouts: vec![(15, 300)].try_into().unwrap() }
+		);
+		assert_noop!(Society::claim_all_matured(Origin::signed(20)), Error::<Test>::NoPayout);
+	});
+}
+
+#[test]
+fn large_payouts_are_smoothed() {
+	EnvBuilder::new().execute(|| {
+		PayoutSmoothingThreshold::set(Some(500));
+		assert_ok!(Society::bid(Origin::signed(20), 1000));
+		next_intake();
+		assert_ok!(Society::vote(Origin::signed(10), 20, true));
+		conclude_intake(true, None);
+		// The payout is split over three rotation periods of four blocks.
+		let payouts = Payouts::<Test>::get(20).payouts.into_inner();
+		let first = payouts[0].0;
+		assert_eq!(payouts, vec![(first, 333), (first + 4, 333), (first + 8, 334)]);
+		// All of them can be claimed once matured.
+		System::set_block_number(first + 8);
+		assert_ok!(Society::claim_all_matured(Origin::signed(20)));
+		assert_eq!(Balances::free_balance(20), 1050);
+		assert!(Payouts::<Test>::get(20).payouts.is_empty());
+	});
+}
+
+#[test]
+fn smoothed_payouts_fit_into_the_free_payout_slots() {
+	EnvBuilder::new().execute(|| {
+		PayoutSmoothingThreshold::set(Some(500));
+		// Only one of the ten payout slots is left.
+		let pending: Vec<_> = (100..109).map(|when| (when, 10)).collect();
+		Payouts::<Test>::insert(
+			20,
+			PayoutRecord { paid: 0, payouts: pending.clone().try_into().unwrap() },
+		);
+		assert_ok!(Society::bid(Origin::signed(20), 1000));
+		next_intake();
+		assert_ok!(Society::vote(Origin::signed(10), 20, true));
+		conclude_intake(true, None);
+		// The whole payout is made in the single free slot instead of being partly dropped.
+		let payouts = Payouts::<Test>::get(20).payouts.into_inner();
+		let first = payouts[0].0;
+		let mut expected = vec![(first, 1000)];
+		expected.extend(pending);
+		assert_eq!(payouts, expected);
+	});
+}
+
 #[test]
 fn non_voting_skeptic_is_punished() {
 	EnvBuilder::new().execute(|| {
@@ -71,6 +71,7 @@ pub trait WeightInfo {
 	fn drop_candidate() -> Weight;
 	fn cleanup_candidacy() -> Weight;
 	fn cleanup_challenge() -> Weight;
+	fn claim_all_matured(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_society` using the Substrate node and recommended hardware.
@@ -440,6 +441,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Society::Members` (r:1 w:0)
+	/// Proof: `Society::Members` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::Payouts` (r:1 w:1)
+	/// Proof: `Society::Payouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn claim_all_matured(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `634 + n * (32 ±0)`
+		//  Estimated: `4099 + n * (32 ±0)`
+		// Minimum execution time: 52_716_000 picoseconds.
+		Weight::from_parts(53_341_208, 4099)
+			// Standard Error: 4_412
+			.saturating_add(Weight::from_parts(187_340, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -808,4 +828,23 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Society::Members` (r:1 w:0)
+	/// Proof: `Society::Members` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::Payouts` (r:1 w:1)
+	/// Proof: `Society::Payouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn claim_all_matured(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `634 + n * (32 ±0)`
+		//  Estimated: `4099 + n * (32 ±0)`
+		// Minimum execution time: 52_716_000 picoseconds.
+		Weight::from_parts(53_341_208, 4099)
+			// Standard Error: 4_412
+			.saturating_add(Weight::from_parts(187_340, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(n.into()))
+	}
 }
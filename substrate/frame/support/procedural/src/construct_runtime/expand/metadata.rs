@@ -159,6 +159,16 @@ pub fn expand_runtime_metadata(
 			pub fn metadata_versions() -> #scrate::__private::sp_std::vec::Vec<u32> {
 				#scrate::__private::metadata_ir::supported_versions()
 			}
+
+			pub fn module_error_metadata(
+				error: #scrate::sp_runtime::ModuleError,
+			) -> Option<#scrate::sp_runtime::ModuleErrorMetadata> {
+				#scrate::dispatch::module_error_metadata(&#runtime::metadata_ir(), &error)
+			}
+
+			pub fn storage_layout_hash() -> [u8; 32] {
+				#scrate::storage::layout::storage_layout_hash(&#runtime::metadata_ir())
+			}
 		}
 	}
 }
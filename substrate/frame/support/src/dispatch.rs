@@ -26,7 +26,7 @@ use serde::{Deserialize, Serialize};
 use sp_runtime::{
 	generic::{CheckedExtrinsic, UncheckedExtrinsic},
 	traits::SignedExtension,
-	DispatchError, RuntimeDebug,
+	DispatchError, ModuleError, ModuleErrorMetadata, RuntimeDebug,
 };
 use sp_std::fmt;
 use sp_weights::Weight;
@@ -277,6 +277,29 @@ pub fn extract_actual_pays_fee(result: &DispatchResultWithPostInfo, info: &Dispa
 	.pays_fee(info)
 }
 
+/// Resolve a [`ModuleError`] to the name and documentation of the pallet error it encodes.
+///
+/// Only the first byte of [`ModuleError::error`] is taken into account, i.e. nested errors are
+/// resolved to the variant of the outer pallet error. Returns `None` if the runtime has no pallet
+/// at the given index, the pallet declares no errors or the variant index is unknown.
+pub fn module_error_metadata(
+	metadata: &sp_metadata_ir::MetadataIR,
+	error: &ModuleError,
+) -> Option<ModuleErrorMetadata> {
+	let pallet = metadata.pallets.iter().find(|pallet| pallet.index == error.index)?;
+	let variants = match pallet.error.as_ref()?.ty.type_info().type_def {
+		scale_info::TypeDef::Variant(def) => def.variants,
+		_ => return None,
+	};
+	let variant = variants.into_iter().find(|variant| variant.index == error.error[0])?;
+
+	Some(ModuleErrorMetadata {
+		pallet: pallet.name.as_bytes().to_vec(),
+		error: variant.name.as_bytes().to_vec(),
+		docs: variant.docs.iter().map(|doc| doc.as_bytes().to_vec()).collect(),
+	})
+}
+
 /// Weight information that is only available post dispatch.
 /// NOTE: This can only be used to reduce the weight or fee, not increase it.
 #[derive(Clone, Copy, Eq, PartialEq, Default, RuntimeDebug, Encode, Decode, TypeInfo)]
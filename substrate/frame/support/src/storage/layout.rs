@@ -0,0 +1,212 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Canonical hash of the storage layout of a runtime.
+//!
+//! The hash commits to the prefix and name of every storage entry of every pallet, the hashers of
+//! storage maps and the SCALE encoding of their key and value types. Comparing the hash of two
+//! runtime versions detects accidental changes of the storage layout, e.g. in CI or as part of
+//! `try-runtime`, without diffing the full metadata.
+
+use codec::Encode;
+use core::any::TypeId;
+use scale_info::{MetaType, TypeDef};
+use sp_io::hashing::blake2_256;
+use sp_metadata_ir::{MetadataIR, StorageEntryTypeIR};
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+
+/// Compute the canonical hash of the storage layout of all pallets in `metadata`.
+///
+/// Storage entries are hashed ordered by their pallet prefix and name, so the hash doesn't depend
+/// on the order of the pallets in the runtime or of the storage items in a pallet. Types are
+/// hashed by the shape of their SCALE encoding only, which means that documentation, default
+/// values, as well as renaming types, fields or variants don't change the hash.
+pub fn storage_layout_hash(metadata: &MetadataIR) -> [u8; 32] {
+	let mut entries = metadata
+		.pallets
+		.iter()
+		.filter_map(|pallet| pallet.storage.as_ref())
+		.flat_map(|storage| storage.entries.iter().map(move |entry| (storage.prefix, entry)))
+		.collect::<Vec<_>>();
+	entries.sort_by_key(|(prefix, entry)| (*prefix, entry.name));
+
+	let mut hasher = TypeHasher::default();
+	let mut encoded = Vec::new();
+	for (prefix, entry) in entries {
+		(prefix, entry.name).encode_to(&mut encoded);
+		match &entry.ty {
+			StorageEntryTypeIR::Plain(value) => {
+				0u8.encode_to(&mut encoded);
+				hasher.hash(value).encode_to(&mut encoded);
+			},
+			StorageEntryTypeIR::Map { hashers, key, value } => {
+				1u8.encode_to(&mut encoded);
+				hashers.encode_to(&mut encoded);
+				hasher.hash(key).encode_to(&mut encoded);
+				hasher.hash(value).encode_to(&mut encoded);
+			},
+		}
+	}
+	blake2_256(&encoded)
+}
+
+/// Hashes types by the shape of their SCALE encoding.
+#[derive(Default)]
+struct TypeHasher {
+	/// The types which are currently being hashed, used to detect recursive types.
+	stack: Vec<TypeId>,
+	/// The hashes of all types which don't refer to a type further up the stack.
+	cache: BTreeMap<TypeId, [u8; 32]>,
+}
+
+impl TypeHasher {
+	/// Hash the given type.
+	fn hash(&mut self, ty: &MetaType) -> [u8; 32] {
+		self.hash_inner(ty).0
+	}
+
+	/// Hash the given type, returning its hash and the lowest position on the stack it refers to.
+	fn hash_inner(&mut self, ty: &MetaType) -> ([u8; 32], usize) {
+		let id = ty.type_id();
+		if let Some(hash) = self.cache.get(&id) {
+			return (*hash, usize::MAX)
+		}
+		if let Some(position) = self.stack.iter().position(|other| *other == id) {
+			// Recursive types are referred to by their distance on the stack.
+			let distance = (self.stack.len() - position) as u32;
+			return (blake2_256(&(b"recursive", distance).encode()), position)
+		}
+
+		self.stack.push(id);
+		let mut lowest = usize::MAX;
+		let mut encoded = Vec::new();
+		let mut push = |hasher: &mut Self, ty: &MetaType, encoded: &mut Vec<u8>| {
+			let (hash, position) = hasher.hash_inner(ty);
+			lowest = lowest.min(position);
+			hash.encode_to(encoded);
+		};
+		match ty.type_info().type_def {
+			TypeDef::Composite(composite) => {
+				0u8.encode_to(&mut encoded);
+				(composite.fields.len() as u32).encode_to(&mut encoded);
+				for field in composite.fields {
+					push(self, &field.ty, &mut encoded);
+				}
+			},
+			TypeDef::Variant(variant) => {
+				1u8.encode_to(&mut encoded);
+				(variant.variants.len() as u32).encode_to(&mut encoded);
+				for variant in variant.variants {
+					(variant.index, variant.fields.len() as u32).encode_to(&mut encoded);
+					for field in variant.fields {
+						push(self, &field.ty, &mut encoded);
+					}
+				}
+			},
+			TypeDef::Sequence(sequence) => {
+				2u8.encode_to(&mut encoded);
+				push(self, &sequence.type_param, &mut encoded);
+			},
+			TypeDef::Array(array) => {
+				3u8.encode_to(&mut encoded);
+				array.len.encode_to(&mut encoded);
+				push(self, &array.type_param, &mut encoded);
+			},
+			TypeDef::Tuple(tuple) => {
+				4u8.encode_to(&mut encoded);
+				(tuple.fields.len() as u32).encode_to(&mut encoded);
+				for field in tuple.fields {
+					push(self, &field, &mut encoded);
+				}
+			},
+			TypeDef::Primitive(primitive) => {
+				(5u8, primitive as u8).encode_to(&mut encoded);
+			},
+			TypeDef::Compact(compact) => {
+				6u8.encode_to(&mut encoded);
+				push(self, &compact.type_param, &mut encoded);
+			},
+			TypeDef::BitSequence(bits) => {
+				7u8.encode_to(&mut encoded);
+				push(self, &bits.bit_store_type, &mut encoded);
+				// The bit order types only differ by their path.
+				bits.bit_order_type.type_info().path.segments.encode_to(&mut encoded);
+			},
+		}
+		self.stack.pop();
+
+		let hash = blake2_256(&encoded);
+		if lowest >= self.stack.len() {
+			self.cache.insert(id, hash);
+		}
+		(hash, lowest)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use scale_info::{meta_type, TypeInfo};
+
+	fn hash<T: TypeInfo + 'static>() -> [u8; 32] {
+		TypeHasher::default().hash(&meta_type::<T>())
+	}
+
+	#[derive(TypeInfo)]
+	struct Foo {
+		_a: u32,
+		_b: Vec<u8>,
+	}
+
+	/// Documentation doesn't change the layout.
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	struct Bar(u32, Vec<u8>);
+
+	#[derive(TypeInfo)]
+	struct Baz {
+		_a: u64,
+		_b: Vec<u8>,
+	}
+
+	#[derive(TypeInfo)]
+	struct Tree {
+		_children: Vec<Tree>,
+	}
+
+	#[derive(TypeInfo)]
+	struct Forest {
+		_trees: Vec<Tree>,
+		_other: Vec<Tree>,
+	}
+
+	#[test]
+	fn type_hash_only_depends_on_the_encoding() {
+		assert_eq!(hash::<Foo>(), hash::<Bar>());
+		assert_ne!(hash::<Foo>(), hash::<Baz>());
+		assert_ne!(hash::<u32>(), hash::<u64>());
+		assert_ne!(hash::<Vec<u8>>(), hash::<[u8; 32]>());
+		assert_ne!(hash::<(u8, u16)>(), hash::<(u16, u8)>());
+	}
+
+	#[test]
+	fn recursive_types_are_hashed() {
+		assert_ne!(hash::<Tree>(), hash::<Vec<Tree>>());
+		assert_ne!(hash::<Forest>(), hash::<Tree>());
+		assert_eq!(hash::<Forest>(), hash::<Forest>());
+	}
+}
@@ -489,6 +489,35 @@ pub mod v3 {
 		/// Will return an `Unavailable` error if the `id` is invalid.
 		fn next_dispatch_time(id: TaskName) -> Result<BlockNumber, DispatchError>;
 	}
+
+	/// A scheduler which is not available, e.g. in runtimes without one.
+	impl<BlockNumber, Call, Origin> Named<BlockNumber, Call, Origin> for () {
+		type Address = ();
+		type Hasher = sp_runtime::traits::BlakeTwo256;
+
+		fn schedule_named(
+			_: TaskName,
+			_: DispatchTime<BlockNumber>,
+			_: Option<Period<BlockNumber>>,
+			_: Priority,
+			_: Origin,
+			_: Bounded<Call, Self::Hasher>,
+		) -> Result<Self::Address, DispatchError> {
+			Err(DispatchError::Unavailable)
+		}
+		fn cancel_named(_: TaskName) -> Result<(), DispatchError> {
+			Err(DispatchError::Unavailable)
+		}
+		fn reschedule_named(
+			_: TaskName,
+			_: DispatchTime<BlockNumber>,
+		) -> Result<Self::Address, DispatchError> {
+			Err(DispatchError::Unavailable)
+		}
+		fn next_dispatch_time(_: TaskName) -> Result<BlockNumber, DispatchError> {
+			Err(DispatchError::Unavailable)
+		}
+	}
 }
 
 #[allow(deprecated)]
@@ -37,7 +37,7 @@ use sp_io::{
 };
 use sp_runtime::{
 	traits::{Dispatchable, Extrinsic as ExtrinsicT, SignaturePayload as SignaturePayloadT},
-	DispatchError, ModuleError,
+	DispatchError, ModuleError, ModuleErrorMetadata,
 };
 
 parameter_types! {
@@ -878,6 +878,60 @@ fn error_expand() {
 	assert_eq!(format!("{:?}", pallet::Error::<Runtime>::FeatureTest), String::from("FeatureTest"),);
 }
 
+#[test]
+fn module_error_metadata_works() {
+	let error = |index, error| ModuleError { index, error, message: None };
+
+	assert_eq!(
+		Runtime::module_error_metadata(error(1, [0, 0, 0, 0])),
+		Some(ModuleErrorMetadata {
+			pallet: b"Example".to_vec(),
+			error: b"InsufficientProposersBalance".to_vec(),
+			docs: maybe_docs(vec![" error doc comment put in metadata"])
+				.into_iter()
+				.map(|doc| doc.as_bytes().to_vec())
+				.collect(),
+		}),
+	);
+	assert_eq!(
+		Runtime::module_error_metadata(error(1, [2, 5, 0, 0])),
+		Some(ModuleErrorMetadata {
+			pallet: b"Example".to_vec(),
+			error: b"Code".to_vec(),
+			docs: vec![],
+		}),
+	);
+	// Unknown variant.
+	assert_eq!(Runtime::module_error_metadata(error(1, [100, 0, 0, 0])), None);
+	// Unknown pallet.
+	assert_eq!(Runtime::module_error_metadata(error(100, [0, 0, 0, 0])), None);
+}
+
+#[test]
+fn storage_layout_hash_works() {
+	use frame_support::storage::layout::storage_layout_hash;
+
+	let mut metadata = Runtime::metadata_ir();
+	assert_eq!(Runtime::storage_layout_hash(), storage_layout_hash(&metadata));
+
+	// The order of the pallets and their documentation don't matter.
+	metadata.pallets.reverse();
+	for pallet in metadata.pallets.iter_mut().filter_map(|pallet| pallet.storage.as_mut()) {
+		pallet.entries.iter_mut().for_each(|entry| entry.docs.clear());
+	}
+	assert_eq!(Runtime::storage_layout_hash(), storage_layout_hash(&metadata));
+
+	// Removing a storage entry changes the hash.
+	let pallet = metadata
+		.pallets
+		.iter_mut()
+		.find(|pallet| pallet.name == "Example")
+		.and_then(|pallet| pallet.storage.as_mut())
+		.unwrap();
+	pallet.entries.pop();
+	assert_ne!(Runtime::storage_layout_hash(), storage_layout_hash(&metadata));
+}
+
 #[test]
 fn instance_expand() {
 	// Assert same type.
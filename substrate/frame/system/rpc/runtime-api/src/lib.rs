@@ -23,6 +23,33 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// A pre-runtime digest of a block, decoded if the consensus engine is known to the runtime.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum PreRuntimeDigest<AccountId> {
+	/// A BABE pre-runtime digest.
+	Babe {
+		/// The slot the block was authored in.
+		slot: u64,
+		/// The index of the block author in the BABE authorities.
+		authority_index: u32,
+		/// The account of the block author, if it is known.
+		author: Option<AccountId>,
+	},
+	/// A pre-runtime digest of another consensus engine, as found in the block header.
+	Other {
+		/// The id of the consensus engine.
+		engine: [u8; 4],
+		/// The encoded digest.
+		data: Vec<u8>,
+	},
+}
+
 #[docify::export(AccountNonceApi)]
 sp_api::decl_runtime_apis! {
 	/// The API to query account nonce.
@@ -33,4 +60,18 @@ sp_api::decl_runtime_apis! {
 		/// Get current account nonce of given `AccountId`.
 		fn account_nonce(account: AccountId) -> Nonce;
 	}
+
+	/// The API to inspect the author and the consensus digests of a block.
+	///
+	/// Called at a block, it describes that block, so recent blocks can be inspected as long as
+	/// their state is kept.
+	pub trait BlockDigestApi<AccountId> where
+		AccountId: codec::Codec,
+	{
+		/// Get the account of the block author, if it is known.
+		fn block_author() -> Option<AccountId>;
+
+		/// Get the pre-runtime digests of the block, in the order of the block header.
+		fn pre_runtime_digests() -> Vec<PreRuntimeDigest<AccountId>>;
+	}
 }
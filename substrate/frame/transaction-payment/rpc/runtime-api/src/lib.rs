@@ -20,9 +20,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
-use sp_runtime::traits::MaybeDisplay;
+use sp_runtime::{traits::MaybeDisplay, transaction_validity::TransactionPriority};
 
-pub use pallet_transaction_payment::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
+pub use pallet_transaction_payment::{FeeDetails, InclusionFee, PriorityInfo, RuntimeDispatchInfo};
 
 sp_api::decl_runtime_apis! {
 	#[api_version(4)]
@@ -33,6 +33,16 @@ sp_api::decl_runtime_apis! {
 		fn query_fee_details(uxt: Block::Extrinsic, len: u32) -> FeeDetails<Balance>;
 		fn query_weight_to_fee(weight: sp_weights::Weight) -> Balance;
 		fn query_length_to_fee(length: u32) -> Balance;
+
+		/// Query the priority of a given extrinsic paying `tip`, the current fee multiplier and the
+		/// tip needed to outbid `target_priority`, e.g. the median priority of the pool.
+		#[api_version(5)]
+		fn query_priority_info(
+			uxt: Block::Extrinsic,
+			len: u32,
+			tip: Balance,
+			target_priority: TransactionPriority,
+		) -> PriorityInfo<Balance>;
 	}
 
 	#[api_version(3)]
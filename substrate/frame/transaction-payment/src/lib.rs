@@ -70,7 +70,7 @@ use sp_runtime::{
 	FixedPointNumber, FixedU128, Perbill, Perquintill, RuntimeDebug,
 };
 use sp_std::prelude::*;
-pub use types::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
+pub use types::{FeeDetails, InclusionFee, PriorityInfo, RuntimeDispatchInfo};
 
 #[cfg(test)]
 mod mock;
@@ -539,6 +539,49 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Query the priority of a given extrinsic paying `tip`, together with the current fee
+	/// multiplier and the tip it would need to pay to outbid `target_priority`.
+	///
+	/// Like [`Self::query_info`], this cannot read the tip from the extrinsic itself, so the tip
+	/// is passed separately. The target priority is usually the median priority of the
+	/// transactions in the pool, which is not known to the runtime and must be provided by the
+	/// caller. Unsigned extrinsics are not prioritized by this pallet, so their priority is zero
+	/// and no tip is returned for them.
+	pub fn query_priority_info<Extrinsic: sp_runtime::traits::Extrinsic + GetDispatchInfo>(
+		unchecked_extrinsic: Extrinsic,
+		len: u32,
+		tip: BalanceOf<T>,
+		target_priority: TransactionPriority,
+	) -> PriorityInfo<BalanceOf<T>>
+	where
+		T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+		BalanceOf<T>: Send + Sync,
+	{
+		let multiplier = NextFeeMultiplier::<T>::get();
+		if !unchecked_extrinsic.is_signed().unwrap_or(false) {
+			return PriorityInfo { priority: 0, multiplier, tip_to_outbid: None }
+		}
+
+		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
+		let priority_with = |tip: BalanceOf<T>| {
+			let fee = Self::compute_fee(len, &dispatch_info, tip);
+			ChargeTransactionPayment::<T>::get_priority(&dispatch_info, len as usize, tip, fee)
+		};
+
+		let base_priority = priority_with(Zero::zero());
+		let tip_to_outbid = if base_priority > target_priority {
+			Some(Zero::zero())
+		} else {
+			// The priority grows linearly with the tip, until it saturates.
+			let step = priority_with(One::one()).saturating_sub(base_priority);
+			(step > 0 && target_priority < TransactionPriority::max_value()).then(|| {
+				((target_priority - base_priority) / step).saturating_add(1).saturated_into()
+			})
+		};
+
+		PriorityInfo { priority: priority_with(tip), multiplier, tip_to_outbid }
+	}
+
 	/// Query information of a dispatch class, weight, and fee of a given encoded `Call`.
 	pub fn query_call_info(call: T::RuntimeCall, len: u32) -> RuntimeDispatchInfo<BalanceOf<T>>
 	where
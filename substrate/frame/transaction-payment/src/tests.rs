@@ -349,6 +349,57 @@ fn query_info_and_fee_details_works() {
         });
 }
 
+#[test]
+fn query_priority_info_works() {
+	let call = RuntimeCall::Balances(BalancesCall::transfer_allow_death { dest: 2, value: 69 });
+	let xt = TestXt::new(call.clone(), Some((111111, ())));
+	let len = xt.encode().len() as u32;
+	let unsigned_xt = TestXt::<_, ()>::new(call, None);
+
+	ExtBuilder::default()
+		.base_weight(Weight::from_parts(5, 0))
+		.build()
+		.execute_with(|| {
+			let multiplier = Multiplier::saturating_from_rational(3, 2);
+			<NextFeeMultiplier<Runtime>>::put(multiplier);
+
+			// without a tip, the priority is that of a single unit of tip
+			let unit = TransactionPayment::query_priority_info(xt.clone(), len, 0, 0).priority;
+			assert!(unit > 0);
+			assert_eq!(
+				TransactionPayment::query_priority_info(xt.clone(), len, 0, 0),
+				PriorityInfo { priority: unit, multiplier, tip_to_outbid: Some(0) },
+			);
+
+			// a tip of 10 is needed to get above the priority of a tip of 9
+			let info = TransactionPayment::query_priority_info(xt.clone(), len, 2, 10 * unit);
+			assert_eq!(
+				info,
+				PriorityInfo { priority: 3 * unit, multiplier, tip_to_outbid: Some(10) }
+			);
+			assert!(
+				TransactionPayment::query_priority_info(xt.clone(), len, 10, 0).priority >
+					10 * unit
+			);
+
+			// nothing outbids the maximum priority
+			assert_eq!(
+				TransactionPayment::query_priority_info(
+					xt,
+					len,
+					0,
+					TransactionPriority::max_value()
+				)
+				.tip_to_outbid,
+				None,
+			);
+			assert_eq!(
+				TransactionPayment::query_priority_info(unsigned_xt, len, 0, 0),
+				PriorityInfo { priority: 0, multiplier, tip_to_outbid: None },
+			);
+		});
+}
+
 #[test]
 fn query_call_info_and_fee_details_works() {
 	let call = RuntimeCall::Balances(BalancesCall::transfer_allow_death { dest: 2, value: 69 });
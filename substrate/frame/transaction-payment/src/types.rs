@@ -27,6 +27,9 @@ use sp_runtime::traits::{AtLeast32BitUnsigned, Zero};
 use sp_std::prelude::*;
 
 use frame_support::dispatch::DispatchClass;
+use sp_runtime::transaction_validity::TransactionPriority;
+
+use crate::Multiplier;
 
 /// The base fee and adjusted weight and length fees constitute the _inclusion fee_.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
@@ -117,6 +120,21 @@ pub struct RuntimeDispatchInfo<Balance, Weight = frame_support::weights::Weight>
 	pub partial_fee: Balance,
 }
 
+/// The priority of a transaction in the pool and what it takes to raise it, as queried from the
+/// runtime.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct PriorityInfo<Balance> {
+	/// The priority of the transaction with the queried tip.
+	pub priority: TransactionPriority,
+	/// The fee multiplier for the next block, which reflects the congestion of the network.
+	pub multiplier: Multiplier,
+	/// The smallest tip with which the priority of the transaction exceeds the target priority.
+	///
+	/// `None` if no tip can raise the priority of the transaction above the target priority.
+	pub tip_to_outbid: Option<Balance>,
+}
+
 #[cfg(feature = "std")]
 mod serde_balance {
 	use serde::{Deserialize, Deserializer, Serializer};
@@ -34,6 +34,7 @@ use frame_support::{
 };
 use frame_system::RawOrigin;
 use sp_core::crypto::FromEntropy;
+use sp_runtime::traits::Hash;
 
 /// Trait describing factory functions for dispatchables' parameters.
 pub trait ArgumentsFactory<AssetKind, Beneficiary> {
@@ -71,12 +72,36 @@ fn setup_proposal<T: Config<I>, I: 'static>(
 	(caller, value, beneficiary_lookup)
 }
 
+// Take the consideration of a spend of `value` from `who`, if the runtime takes any.
+fn setup_consideration<T: Config<I>, I: 'static>(
+	who: &T::AccountId,
+	value: BalanceOf<T, I>,
+) -> Option<T::SpendConsideration> {
+	let balance = T::Currency::minimum_balance().saturating_mul(1_000_000_000u32.into());
+	let _ = T::Currency::make_free_balance_be(who, balance);
+	T::SpendConsideration::new(who, value).ok().flatten()
+}
+
+// Approve a spend backed by a consideration taken from `caller`.
+fn spend_with_consideration<T: Config<I>, I: 'static>(
+	origin: T::RuntimeOrigin,
+	caller: T::AccountId,
+	value: BalanceOf<T, I>,
+	beneficiary_lookup: AccountIdLookupOf<T>,
+) -> Result<(), &'static str> {
+	if let Some(ticket) = setup_consideration::<T, I>(&caller, value) {
+		T::SpendSubmitter::set_consideration(caller, ticket);
+	}
+	Treasury::<T, I>::spend_local(origin, value, beneficiary_lookup)?;
+	Ok(())
+}
+
 // Create proposals that are approved for use in `on_initialize`.
 fn create_approved_proposals<T: Config<I>, I: 'static>(n: u32) -> Result<(), &'static str> {
 	let origin = T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 	for i in 0..n {
-		let (_, value, lookup) = setup_proposal::<T, I>(i);
-		Treasury::<T, I>::spend_local(origin.clone(), value, lookup)?;
+		let (caller, value, lookup) = setup_proposal::<T, I>(i);
+		spend_with_consideration::<T, I>(origin.clone(), caller, value, lookup)?;
 	}
 	ensure!(<Approvals<T, I>>::get().len() == n as usize, "Not all approved");
 	Ok(())
@@ -110,10 +135,13 @@ mod benchmarks {
 	// a successful origin, in which case `spend` is un-callable and can use weight=0.
 	#[benchmark]
 	fn spend_local() -> Result<(), BenchmarkError> {
-		let (_, value, beneficiary_lookup) = setup_proposal::<T, _>(SEED);
+		let (caller, value, beneficiary_lookup) = setup_proposal::<T, _>(SEED);
 		let origin =
 			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 		let beneficiary = T::Lookup::lookup(beneficiary_lookup.clone()).unwrap();
+		if let Some(ticket) = setup_consideration::<T, _>(&caller, value) {
+			T::SpendSubmitter::set_consideration(caller, ticket);
+		}
 
 		#[extrinsic_call]
 		_(origin as T::RuntimeOrigin, value, beneficiary_lookup);
@@ -128,8 +156,8 @@ mod benchmarks {
 	fn remove_approval() -> Result<(), BenchmarkError> {
 		let origin =
 			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
-		let (_, value, beneficiary_lookup) = setup_proposal::<T, _>(SEED);
-		Treasury::<T, _>::spend_local(origin, value, beneficiary_lookup)?;
+		let (caller, value, beneficiary_lookup) = setup_proposal::<T, _>(SEED);
+		spend_with_consideration::<T, _>(origin, caller, value, beneficiary_lookup)?;
 		let proposal_id = Treasury::<T, _>::proposal_count() - 1;
 		let reject_origin =
 			T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
@@ -274,6 +302,31 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn void_spend_with_reason() -> Result<(), BenchmarkError> {
+		let origin = T::SpendOrigin::try_successful_origin().map_err(|_| "No origin")?;
+		let (asset_kind, amount, _, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		Treasury::<T, _>::spend(
+			origin,
+			Box::new(asset_kind.clone()),
+			amount,
+			Box::new(beneficiary_lookup),
+			None,
+		)?;
+		assert!(Spends::<T, I>::get(0).is_some());
+		let origin =
+			T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let reason = T::Hashing::hash(b"reason");
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0u32, reason);
+
+		assert!(Spends::<T, I>::get(0).is_none());
+		assert_eq!(VoidReasons::<T, I>::get(0), Some(reason));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Treasury,
 		crate::tests::ExtBuilder::default().build(),
@@ -86,7 +86,7 @@ use scale_info::TypeInfo;
 
 use sp_runtime::{
 	traits::{AccountIdConversion, CheckedAdd, Saturating, StaticLookup, Zero},
-	Permill, RuntimeDebug,
+	DispatchError, Permill, RuntimeDebug,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
@@ -94,12 +94,15 @@ use frame_support::{
 	dispatch::{DispatchResult, DispatchResultWithPostInfo},
 	ensure, print,
 	traits::{
-		tokens::Pay, Currency, ExistenceRequirement::KeepAlive, Get, Imbalance, OnUnbalanced,
-		ReservableCurrency, WithdrawReasons,
+		tokens::{ConversionFromAssetBalance, Pay},
+		Consideration, Currency, EnsureOrigin,
+		ExistenceRequirement::KeepAlive,
+		Get, Imbalance, OnUnbalanced, ReservableCurrency, WithdrawReasons,
 	},
 	weights::Weight,
 	PalletId,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -137,6 +140,40 @@ pub trait SpendFunds<T: Config<I>, I: 'static = ()> {
 	);
 }
 
+/// Recovery of payments made by the [`pallet::Config::Paymaster`] for spends which were voided
+/// after their payout was attempted.
+pub trait PaymentClawback<Id> {
+	/// Register an attempt to claw back the payment `id`, which may recover only a part of it.
+	///
+	/// Returns an error if claw-backs are not supported or the payment can not be clawed back.
+	fn claw_back(id: Id) -> Result<(), ()>;
+}
+
+impl<Id> PaymentClawback<Id> for () {
+	fn claw_back(_: Id) -> Result<(), ()> {
+		Err(())
+	}
+}
+
+/// Source of the consideration already taken from the submitter of a spend approved with
+/// [`Pallet::spend_local`], e.g. the consideration taken when the referendum enacting it was
+/// submitted.
+pub trait SpendSubmitter<AccountId, Ticket> {
+	/// Take the submitter of the spend being approved and the ticket of its consideration, if
+	/// any.
+	fn take_consideration() -> Option<(AccountId, Ticket)>;
+
+	/// Make `ticket` of `who` the consideration taken by the next [`Pallet::spend_local`].
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_consideration(_who: AccountId, _ticket: Ticket) {}
+}
+
+impl<AccountId, Ticket> SpendSubmitter<AccountId, Ticket> for () {
+	fn take_consideration() -> Option<(AccountId, Ticket)> {
+		None
+	}
+}
+
 /// An index of a proposal. Just a `u32`.
 pub type ProposalIndex = u32;
 
@@ -187,13 +224,24 @@ pub struct SpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, Paymen
 /// Index of an approved treasury spend.
 pub type SpendIndex = u32;
 
+/// The outcome of checking a prospective spend, see [`Pallet::check_spend`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SpendCheck<Balance, BlockNumber> {
+	/// The maximum amount of native balance the origin may spend.
+	pub max_amount: Balance,
+	/// The amount of the spend converted to native balance.
+	pub native_amount: Balance,
+	/// The block number from which the spend could be claimed.
+	pub valid_from: BlockNumber,
+	/// The block number by which the spend would have to be claimed.
+	pub expire_at: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::{
-		dispatch_context::with_context,
-		pallet_prelude::*,
-		traits::tokens::{ConversionFromAssetBalance, PaymentStatus},
+		dispatch_context::with_context, pallet_prelude::*, traits::tokens::PaymentStatus,
 	};
 	use frame_system::pallet_prelude::*;
 
@@ -269,6 +317,23 @@ pub mod pallet {
 		#[pallet::constant]
 		type PayoutPeriod: Get<BlockNumberFor<Self>>;
 
+		/// Type for clawing back payments of spends voided with
+		/// [`Pallet::void_spend_with_reason`] after their payout was attempted. Use `()` if the
+		/// [`Config::Paymaster`] does not support claw-backs.
+		type Clawback: PaymentClawback<<Self::Paymaster as Pay>::Id>;
+
+		/// The consideration already taken from the submitter of a spend approved with
+		/// [`Pallet::spend_local`]. Use `()` if there is none, in which case the spend is not
+		/// backed by a consideration.
+		type SpendSubmitter: SpendSubmitter<Self::AccountId, Self::SpendConsideration>;
+
+		/// The consideration of a spend approved with [`Pallet::spend_local`], as handed over by
+		/// the [`Config::SpendSubmitter`].
+		///
+		/// The consideration is returned once the spend is paid and burnt if its approval is
+		/// removed. Use `()` if there is none.
+		type SpendConsideration: Consideration<Self::AccountId, BalanceOf<Self, I>>;
+
 		/// Helper type for benchmarks.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: ArgumentsFactory<Self::AssetKind, Self::Beneficiary>;
@@ -322,6 +387,33 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The hashes of the reasons given for voiding spends with
+	/// [`Pallet::void_spend_with_reason`].
+	// Hasher: Twox safe since `SpendIndex` is an internal count based index.
+	#[pallet::storage]
+	pub type VoidReasons<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, SpendIndex, T::Hash, OptionQuery>;
+
+	/// The considerations taken from the submitters of approved proposals, see
+	/// [`Config::SpendConsideration`].
+	// Hasher: Twox safe since `ProposalIndex` is an internal count based index.
+	#[pallet::storage]
+	pub type SpendConsiderations<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		ProposalIndex,
+		(T::AccountId, T::SpendConsideration),
+		OptionQuery,
+	>;
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason<I: 'static = ()> {
+		/// The consideration for a spend approved with [`Pallet::spend_local`].
+		#[codec(index = 0)]
+		SpendConsideration,
+	}
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -373,6 +465,13 @@ pub mod pallet {
 		},
 		/// An approved spend was voided.
 		AssetSpendVoided { index: SpendIndex },
+		/// An approved spend was voided for the given reason. If its payout was already
+		/// attempted, a claw-back of the payment was registered.
+		AssetSpendVoidedWithReason {
+			index: SpendIndex,
+			reason: T::Hash,
+			clawback: Option<<T::Paymaster as Pay>::Id>,
+		},
 		/// A payment happened.
 		Paid { index: SpendIndex, payment_id: <T::Paymaster as Pay>::Id },
 		/// A payment failed and can be retried.
@@ -461,6 +560,10 @@ pub mod pallet {
 		/// NOTE: For record-keeping purposes, the proposer is deemed to be equivalent to the
 		/// beneficiary.
 		///
+		/// If the [`Config::SpendSubmitter`] hands over a [`Config::SpendConsideration`], it is
+		/// kept until the spend is paid. Since it was taken beforehand, the spend never fails
+		/// because the submitter can not afford it.
+		///
 		/// ### Parameters
 		/// - `amount`: The amount to be transferred from the treasury to the `beneficiary`.
 		/// - `beneficiary`: The destination account for the transfer.
@@ -502,6 +605,9 @@ pub mod pallet {
 			let proposal_index = Self::proposal_count();
 			Approvals::<T, I>::try_append(proposal_index)
 				.map_err(|_| Error::<T, I>::TooManyApprovals)?;
+			if let Some(consideration) = T::SpendSubmitter::take_consideration() {
+				SpendConsiderations::<T, I>::insert(proposal_index, consideration);
+			}
 			let proposal = Proposal {
 				proposer: beneficiary.clone(),
 				value: amount,
@@ -523,7 +629,8 @@ pub mod pallet {
 		///
 		/// ## Details
 		///
-		/// The original deposit will no longer be returned.
+		/// The original deposit will no longer be returned, and the consideration taken from the
+		/// submitter of the spend, if any, is burnt.
 		///
 		/// ### Parameters
 		/// - `proposal_id`: The index of a proposal
@@ -552,6 +659,9 @@ pub mod pallet {
 					Err(Error::<T, I>::ProposalNotApproved.into())
 				}
 			})?;
+			if let Some((submitter, ticket)) = SpendConsiderations::<T, I>::take(proposal_id) {
+				ticket.burn(&submitter);
+			}
 
 			Ok(())
 		}
@@ -591,20 +701,10 @@ pub mod pallet {
 			beneficiary: Box<BeneficiaryLookupOf<T, I>>,
 			valid_from: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
-			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let SpendCheck { max_amount, native_amount, valid_from, expire_at } =
+				Self::check_spend(origin, *asset_kind.clone(), amount, valid_from)?;
 			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
 
-			let now = frame_system::Pallet::<T>::block_number();
-			let valid_from = valid_from.unwrap_or(now);
-			let expire_at = valid_from.saturating_add(T::PayoutPeriod::get());
-			ensure!(expire_at > now, Error::<T, I>::SpendExpired);
-
-			let native_amount =
-				T::BalanceConverter::from_asset_balance(amount, *asset_kind.clone())
-					.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
-
-			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
-
 			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
 				let context = v.or_default();
 				// We group based on `max_amount`, to distinguish between different kind of
@@ -778,6 +878,52 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::AssetSpendVoided { index });
 			Ok(())
 		}
+
+		/// Void previously approved spend, recording the hash of the reason for doing so.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::RejectOrigin`].
+		///
+		/// ## Details
+		///
+		/// Unlike [`Pallet::void_spend`], a spend whose payout was already attempted may be voided
+		/// as well, provided that a claw-back of the payment can be registered with
+		/// [`Config::Clawback`]. The claw-back may recover only a part of the payment.
+		///
+		/// ### Parameters
+		/// - `index`: The spend index.
+		/// - `reason`: The hash of the reason for voiding the spend.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::AssetSpendVoidedWithReason`] if successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::void_spend_with_reason())]
+		pub fn void_spend_with_reason(
+			origin: OriginFor<T>,
+			index: SpendIndex,
+			reason: T::Hash,
+		) -> DispatchResult {
+			T::RejectOrigin::ensure_origin(origin)?;
+			let spend = Spends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let clawback = match spend.status {
+				PaymentState::Pending | PaymentState::Failed => None,
+				PaymentState::Attempted { id } => {
+					T::Clawback::claw_back(id).map_err(|_| Error::<T, I>::AlreadyAttempted)?;
+					Some(id)
+				},
+			};
+
+			Spends::<T, I>::remove(index);
+			VoidReasons::<T, I>::insert(index, reason);
+			Self::deposit_event(Event::<T, I>::AssetSpendVoidedWithReason {
+				index,
+				reason,
+				clawback,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -792,6 +938,31 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// Check whether `origin` may approve a spend of `amount` of `asset_kind` which can be claimed
+	/// from `valid_from` on, as [`Pallet::spend`] does, without approving it.
+	///
+	/// The limit on the total amount spent by an origin within a single dispatch is not checked.
+	pub fn check_spend(
+		origin: T::RuntimeOrigin,
+		asset_kind: T::AssetKind,
+		amount: AssetBalanceOf<T, I>,
+		valid_from: Option<BlockNumberFor<T>>,
+	) -> Result<SpendCheck<BalanceOf<T, I>, BlockNumberFor<T>>, DispatchError> {
+		let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let valid_from = valid_from.unwrap_or(now);
+		let expire_at = valid_from.saturating_add(T::PayoutPeriod::get());
+		ensure!(expire_at > now, Error::<T, I>::SpendExpired);
+
+		let native_amount = T::BalanceConverter::from_asset_balance(amount, asset_kind)
+			.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
+
+		ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
+
+		Ok(SpendCheck { max_amount, native_amount, valid_from, expire_at })
+	}
+
 	/// Spend some money! returns number of approvals before spend.
 	pub fn spend_funds() -> Weight {
 		let mut total_weight = Weight::zero();
@@ -814,6 +985,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						// return their deposit.
 						let err_amount = T::Currency::unreserve(&p.proposer, p.bond);
 						debug_assert!(err_amount.is_zero());
+						if let Some((submitter, ticket)) = SpendConsiderations::<T, I>::take(index)
+						{
+							let _ = ticket.drop(&submitter);
+						}
 
 						// provide the allocation.
 						imbalance.subsume(T::Currency::deposit_creating(&p.beneficiary, p.value));
@@ -897,6 +1072,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// [`ProposalCount`].
 	/// 3. Each [`ProposalIndex`] contained in [`Approvals`] should exist in [`Proposals`].
 	/// Note, that this automatically implies [`Approvals`].count() <= [`Proposals`].count().
+	/// 4. Each [`ProposalIndex`] used as a key for [`SpendConsiderations`] should be contained in
+	/// [`Approvals`].
 	#[cfg(any(feature = "try-runtime", test))]
 	fn try_state_proposals() -> Result<(), sp_runtime::TryRuntimeError> {
 		let current_proposal_count = ProposalCount::<T, I>::get();
@@ -923,6 +1100,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				Ok(())
 			})?;
 
+		let approvals = Approvals::<T, I>::get();
+		SpendConsiderations::<T, I>::iter_keys().try_for_each(
+			|proposal_index| -> DispatchResult {
+				ensure!(
+					approvals.contains(&proposal_index),
+					"Proposal indices in `SpendConsiderations` must be contained in `Approvals`."
+				);
+				Ok(())
+			},
+		)?;
+
 		Ok(())
 	}
 
@@ -21,7 +21,7 @@
 
 use core::{cell::RefCell, marker::PhantomData};
 use sp_runtime::{
-	traits::{BadOrigin, Dispatchable, IdentityLookup},
+	traits::{BadOrigin, BlakeTwo256, ConvertInto, Dispatchable, Hash, IdentityLookup},
 	BuildStorage,
 };
 
@@ -30,6 +30,7 @@ use frame_support::{
 	pallet_prelude::Pays,
 	parameter_types,
 	traits::{
+		fungible::{HoldConsideration, InspectHold},
 		tokens::{ConversionFromAssetBalance, PaymentStatus},
 		ConstU32, ConstU64, OnInitialize,
 	},
@@ -77,6 +78,9 @@ thread_local! {
 	pub static PAID: RefCell<BTreeMap<(u128, u32), u64>> = RefCell::new(BTreeMap::new());
 	pub static STATUS: RefCell<BTreeMap<u64, PaymentStatus>> = RefCell::new(BTreeMap::new());
 	pub static LAST_ID: RefCell<u64> = RefCell::new(0u64);
+	pub static CLAWBACKS: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+	pub static CONSIDERATION: RefCell<Option<(u128, <Test as Config>::SpendConsideration)>> =
+		RefCell::new(None);
 }
 
 /// paid balance for a given account and asset ids
@@ -125,8 +129,40 @@ impl Pay for TestPay {
 	}
 }
 
+/// Registers claw-backs of payments which have not succeeded yet.
+pub struct TestClawback;
+impl PaymentClawback<u64> for TestClawback {
+	fn claw_back(id: u64) -> Result<(), ()> {
+		if TestPay::check_payment(id) == PaymentStatus::Success {
+			return Err(())
+		}
+		CLAWBACKS.with(|c| c.borrow_mut().push(id));
+		Ok(())
+	}
+}
+
+/// The consideration of the next spend, as set by the tests.
+pub struct TestSpendSubmitter;
+impl SpendSubmitter<u128, <Test as Config>::SpendConsideration> for TestSpendSubmitter {
+	fn take_consideration() -> Option<(u128, <Test as Config>::SpendConsideration)> {
+		CONSIDERATION.with(|c| c.borrow_mut().take())
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_consideration(who: u128, ticket: <Test as Config>::SpendConsideration) {
+		CONSIDERATION.with(|c| *c.borrow_mut() = Some((who, ticket)));
+	}
+}
+
+/// Take a consideration of `amount` from `who` for the next spend.
+fn set_consideration(who: u128, amount: u64) {
+	let ticket = <Test as Config>::SpendConsideration::new(&who, amount).unwrap().unwrap();
+	CONSIDERATION.with(|c| *c.borrow_mut() = Some((who, ticket)));
+}
+
 parameter_types! {
 	pub const Burn: Permill = Permill::from_percent(50);
+	pub const SpendConsiderationReason: RuntimeHoldReason =
+		RuntimeHoldReason::Treasury(HoldReason::SpendConsideration);
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 	pub TreasuryAccount: u128 = Treasury::account_id();
 	pub const SpendPayoutPeriod: u64 = 5;
@@ -179,6 +215,10 @@ impl Config for Test {
 	type Paymaster = TestPay;
 	type BalanceConverter = MulBy<ConstU64<2>>;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type Clawback = TestClawback;
+	type SpendSubmitter = TestSpendSubmitter;
+	type SpendConsideration =
+		HoldConsideration<u128, Balances, SpendConsiderationReason, ConvertInto, u64>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -448,6 +488,38 @@ fn remove_already_removed_approval_fails() {
 	});
 }
 
+#[test]
+fn spend_consideration_is_kept_until_paid() {
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		let reason = SpendConsiderationReason::get();
+
+		// Without a consideration handed over, the spend is not backed by one.
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(14), 5, 3));
+		assert!(!SpendConsiderations::<Test>::contains_key(0));
+
+		set_consideration(1, 10);
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(14), 10, 3));
+		set_consideration(1, 20);
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(14), 20, 3));
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 30);
+		assert!(SpendConsiderations::<Test>::contains_key(1));
+		assert!(SpendConsiderations::<Test>::contains_key(2));
+
+		// The consideration of a removed approval is burnt.
+		assert_ok!(Treasury::remove_approval(RuntimeOrigin::root(), 2));
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 10);
+		assert_eq!(Balances::free_balance(1), 68);
+
+		// The consideration of a paid spend is returned.
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Balances::free_balance(3), 15);
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 0);
+		assert_eq!(Balances::free_balance(1), 78);
+		assert_eq!(SpendConsiderations::<Test>::iter().count(), 0);
+	});
+}
+
 #[test]
 fn spending_local_in_batch_respects_max_total() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -594,6 +666,37 @@ fn spend_expires() {
 	});
 }
 
+#[test]
+fn check_spend_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(
+			Treasury::check_spend(RuntimeOrigin::signed(10), 1, 2, None),
+			Ok(SpendCheck { max_amount: 5, native_amount: 4, valid_from: 1, expire_at: 6 })
+		);
+		assert_eq!(
+			Treasury::check_spend(RuntimeOrigin::signed(10), 1, 2, Some(3)),
+			Ok(SpendCheck { max_amount: 5, native_amount: 4, valid_from: 3, expire_at: 8 })
+		);
+		assert_eq!(
+			Treasury::check_spend(RuntimeOrigin::signed(10), 1, 3, None),
+			Err(Error::<Test, _>::InsufficientPermission.into())
+		);
+		assert_eq!(
+			Treasury::check_spend(RuntimeOrigin::signed(1), 1, 1, None),
+			Err(DispatchError::BadOrigin)
+		);
+		System::set_block_number(6);
+		assert_eq!(
+			Treasury::check_spend(RuntimeOrigin::signed(10), 1, 2, Some(0)),
+			Err(Error::<Test, _>::SpendExpired.into())
+		);
+
+		// nothing is stored by the check.
+		assert_eq!(SpendCount::<Test, _>::get(), 0);
+	});
+}
+
 #[docify::export]
 #[test]
 fn spend_payout_works() {
@@ -705,6 +808,60 @@ fn void_spend_works() {
 	});
 }
 
+#[test]
+fn void_spend_with_reason_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let reason = BlakeTwo256::hash(b"duplicate");
+		for _ in 0..3 {
+			assert_ok!(Treasury::spend(
+				RuntimeOrigin::signed(10),
+				Box::new(1),
+				2,
+				Box::new(6),
+				None
+			));
+		}
+		assert_noop!(
+			Treasury::void_spend_with_reason(RuntimeOrigin::signed(10), 0, reason),
+			BadOrigin
+		);
+
+		// a pending spend is voided without a claw-back.
+		assert_ok!(Treasury::void_spend_with_reason(RuntimeOrigin::root(), 0, reason));
+		assert_eq!(Spends::<Test, _>::get(0), None);
+		assert_eq!(VoidReasons::<Test, _>::get(0), Some(reason));
+		System::assert_last_event(
+			Event::<Test, _>::AssetSpendVoidedWithReason { index: 0, reason, clawback: None }
+				.into(),
+		);
+
+		// an attempted payment is clawed back.
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 1));
+		let payment_id = get_payment_id(1).expect("no payment attempt");
+		assert_ok!(Treasury::void_spend_with_reason(RuntimeOrigin::root(), 1, reason));
+		assert_eq!(Spends::<Test, _>::get(1), None);
+		assert_eq!(CLAWBACKS.with(|c| c.borrow().clone()), vec![payment_id]);
+		System::assert_last_event(
+			Event::<Test, _>::AssetSpendVoidedWithReason {
+				index: 1,
+				reason,
+				clawback: Some(payment_id),
+			}
+			.into(),
+		);
+
+		// a succeeded payment can not be clawed back.
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 2));
+		let payment_id = get_payment_id(2).expect("no payment attempt");
+		set_status(payment_id, PaymentStatus::Success);
+		assert_noop!(
+			Treasury::void_spend_with_reason(RuntimeOrigin::root(), 2, reason),
+			Error::<Test, _>::AlreadyAttempted
+		);
+	});
+}
+
 #[test]
 fn check_status_works() {
 	ExtBuilder::default().build().execute_with(|| {
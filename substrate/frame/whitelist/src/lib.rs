@@ -28,6 +28,9 @@
 //!
 //! In the meantime the call corresponding to the hash must have been submitted to the pre-image
 //! handler [`pallet::Config::Preimages`].
+//!
+//! Dispatching a whitelisted call only charges the weight the call actually consumed, refunding
+//! the rest of the weight witnessed by the caller.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -92,9 +95,18 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		CallWhitelisted { call_hash: T::Hash },
-		WhitelistedCallRemoved { call_hash: T::Hash },
-		WhitelistedCallDispatched { call_hash: T::Hash, result: DispatchResultWithPostInfo },
+		CallWhitelisted {
+			call_hash: T::Hash,
+		},
+		WhitelistedCallRemoved {
+			call_hash: T::Hash,
+		},
+		/// A whitelisted call was dispatched, consuming `actual_weight`.
+		WhitelistedCallDispatched {
+			call_hash: T::Hash,
+			result: DispatchResultWithPostInfo,
+			actual_weight: Weight,
+		},
 	}
 
 	#[pallet::error]
@@ -180,11 +192,10 @@ pub mod pallet {
 				Error::<T>::InvalidCallWeightWitness
 			);
 
-			let actual_weight = Self::clean_and_dispatch(call_hash, call).map(|w| {
-				w.saturating_add(T::WeightInfo::dispatch_whitelisted_call(call_encoded_len))
-			});
+			let actual_weight = Self::clean_and_dispatch(call_hash, call)
+				.saturating_add(T::WeightInfo::dispatch_whitelisted_call(call_encoded_len));
 
-			Ok(actual_weight.into())
+			Ok(Some(actual_weight).into())
 		}
 
 		#[pallet::call_index(3)]
@@ -209,11 +220,10 @@ pub mod pallet {
 			);
 
 			let call_len = call.encoded_size() as u32;
-			let actual_weight = Self::clean_and_dispatch(call_hash, *call).map(|w| {
-				w.saturating_add(T::WeightInfo::dispatch_whitelisted_call_with_preimage(call_len))
-			});
+			let actual_weight = Self::clean_and_dispatch(call_hash, *call)
+				.saturating_add(T::WeightInfo::dispatch_whitelisted_call_with_preimage(call_len));
 
-			Ok(actual_weight.into())
+			Ok(Some(actual_weight).into())
 		}
 	}
 }
@@ -221,21 +231,27 @@ pub mod pallet {
 impl<T: Config> Pallet<T> {
 	/// Clean whitelisting/preimage and dispatch call.
 	///
-	/// Return the call actual weight of the dispatched call if there is some.
-	fn clean_and_dispatch(call_hash: T::Hash, call: <T as Config>::RuntimeCall) -> Option<Weight> {
+	/// Return the actual weight of the dispatched call, which is its declared weight unless the
+	/// call reports less. The weight witnessed by the caller beyond it is thus refunded.
+	fn clean_and_dispatch(call_hash: T::Hash, call: <T as Config>::RuntimeCall) -> Weight {
 		WhitelistedCall::<T>::remove(call_hash);
 
 		T::Preimages::unrequest(&call_hash);
 
+		let info = call.get_dispatch_info();
 		let result = call.dispatch(frame_system::Origin::<T>::Root.into());
 
-		let call_actual_weight = match result {
-			Ok(call_post_info) => call_post_info.actual_weight,
-			Err(call_err) => call_err.post_info.actual_weight,
+		let actual_weight = match result {
+			Ok(call_post_info) => call_post_info.calc_actual_weight(&info),
+			Err(call_err) => call_err.post_info.calc_actual_weight(&info),
 		};
 
-		Self::deposit_event(Event::<T>::WhitelistedCallDispatched { call_hash, result });
+		Self::deposit_event(Event::<T>::WhitelistedCallDispatched {
+			call_hash,
+			result,
+			actual_weight,
+		});
 
-		call_actual_weight
+		actual_weight
 	}
 }
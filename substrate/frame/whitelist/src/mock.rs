@@ -53,6 +53,7 @@ impl pallet_preimage::Config for Test {
 	type Currency = Balances;
 	type ManagerOrigin = EnsureRoot<Self::AccountId>;
 	type Consideration = ();
+	type DepositPrice = ();
 	type WeightInfo = ();
 }
 
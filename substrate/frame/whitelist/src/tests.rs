@@ -145,6 +145,44 @@ fn test_whitelist_call_and_execute() {
 	});
 }
 
+#[test]
+fn test_whitelist_call_and_execute_refunds_unused_weight() {
+	new_test_ext().execute_with(|| {
+		let call = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![1] });
+		let call_weight = call.get_dispatch_info().weight;
+		let encoded_call = call.encode();
+		let call_encoded_len = encoded_call.len() as u32;
+		let call_hash = <Test as frame_system::Config>::Hashing::hash(&encoded_call[..]);
+
+		assert_ok!(Whitelist::whitelist_call(RuntimeOrigin::root(), call_hash));
+		assert_ok!(Preimage::note(encoded_call.into()));
+
+		// The weight witnessed beyond the weight of the call is refunded.
+		let witness = call_weight.saturating_mul(10);
+		let post_info = Whitelist::dispatch_whitelisted_call(
+			RuntimeOrigin::root(),
+			call_hash,
+			call_encoded_len,
+			witness,
+		)
+		.unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(call_weight.saturating_add(<() as crate::WeightInfo>::dispatch_whitelisted_call(
+				call_encoded_len
+			)))
+		);
+		System::assert_last_event(
+			crate::Event::<Test>::WhitelistedCallDispatched {
+				call_hash,
+				result: Ok(().into()),
+				actual_weight: call_weight,
+			}
+			.into(),
+		);
+	});
+}
+
 #[test]
 fn test_whitelist_call_and_execute_failing_call() {
 	new_test_ext().execute_with(|| {
@@ -119,7 +119,9 @@ use sp_externalities::{Extension, Extensions};
 use sp_runtime::traits::HashingFor;
 #[cfg(feature = "std")]
 pub use sp_runtime::TransactionOutcome;
-use sp_runtime::{traits::Block as BlockT, ExtrinsicInclusionMode};
+use sp_runtime::{
+	traits::Block as BlockT, ExtrinsicInclusionMode, ModuleError, ModuleErrorMetadata,
+};
 #[cfg(feature = "std")]
 pub use sp_state_machine::StorageProof;
 #[cfg(feature = "std")]
@@ -834,6 +836,19 @@ decl_runtime_apis! {
 		///
 		/// This can be used to call `metadata_at_version`.
 		fn metadata_versions() -> sp_std::vec::Vec<u32>;
+
+		/// Resolves the given module error to the name and documentation of the pallet error.
+		///
+		/// Returns `None` if the error does not match any pallet error known to the runtime.
+		#[api_version(3)]
+		fn module_error_metadata(error: ModuleError) -> Option<ModuleErrorMetadata>;
+
+		/// Returns a canonical hash of the storage layout of all pallets.
+		///
+		/// The hash covers the names, hashers and key and value types of all storage entries and
+		/// can be compared between runtime versions to detect changes of the storage layout.
+		#[api_version(4)]
+		fn storage_layout_hash() -> [u8; 32];
 	}
 }
 
@@ -364,6 +364,33 @@ pub struct Epoch {
 	pub config: BabeEpochConfiguration,
 }
 
+/// The slots an authority authored blocks in or missed during an epoch.
+#[derive(
+	Decode, Encode, PartialEq, Eq, Clone, Copy, Default, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub struct AuthoringStats {
+	/// The number of blocks authored in primary slots.
+	pub primary: u32,
+	/// The number of blocks authored in secondary slots.
+	pub secondary: u32,
+	/// The number of slots without a block for which the authority was the secondary slot author.
+	pub missed: u32,
+}
+
+/// The block authoring statistics of the authorities of an epoch.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, RuntimeDebug, TypeInfo)]
+pub struct EpochAuthoringStats {
+	/// The epoch index.
+	pub epoch_index: u64,
+	/// The session index during the epoch.
+	pub session_index: u32,
+	/// The authorities of the epoch and their statistics.
+	pub authorities: Vec<(AuthorityId, AuthoringStats)>,
+	/// The number of slots without a block which could not be attributed to an authority, since
+	/// the epoch did not allow secondary slots.
+	pub unattributed_missed: u32,
+}
+
 /// Returns the epoch index the given slot belongs to.
 pub fn epoch_index(slot: Slot, genesis_slot: Slot, epoch_duration: u64) -> u64 {
 	*slot.saturating_sub(genesis_slot) / epoch_duration
@@ -433,4 +460,13 @@ sp_api::decl_runtime_apis! {
 			key_owner_proof: OpaqueKeyOwnershipProof,
 		) -> Option<()>;
 	}
+
+	/// API to query how the BABE authorities performed in authoring blocks.
+	pub trait BabeAuthoringStatsApi {
+		/// Returns the authoring statistics of the current epoch so far.
+		fn current_epoch_authoring_stats() -> EpochAuthoringStats;
+
+		/// Returns the authoring statistics of the previous epoch, if it was tracked.
+		fn previous_epoch_authoring_stats() -> Option<EpochAuthoringStats>;
+	}
 }
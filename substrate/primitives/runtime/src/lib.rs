@@ -534,6 +534,22 @@ impl PartialEq for ModuleError {
 	}
 }
 
+/// Human readable description of a [`ModuleError`], resolved from the runtime metadata.
+///
+/// All strings are UTF-8 encoded.
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ModuleErrorMetadata {
+	/// Name of the pallet which returned the error, as declared in the runtime.
+	pub pallet: Vec<u8>,
+	/// Name of the error variant.
+	pub error: Vec<u8>,
+	/// Documentation of the error variant.
+	///
+	/// Empty if the runtime was built without metadata docs.
+	pub docs: Vec<Vec<u8>>,
+}
+
 /// Errors related to transactional storage layers.
 #[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -1817,6 +1817,13 @@ pub trait OpaqueKeys: Clone {
 	fn ownership_proof_is_valid(&self, _proof: &[u8]) -> bool {
 		true
 	}
+	/// Verify a proof that `owner` was signed with the private counterpart of every key.
+	///
+	/// The proof is expected to be the concatenation of the SCALE encoded signatures in the order
+	/// of the keys. Rejects every proof unless implemented.
+	fn proof_of_possession_is_valid(&self, _owner: &[u8], _proof: &[u8]) -> bool {
+		false
+	}
 }
 
 /// Input that adds infinite number of zero after wrapped input.
@@ -2048,6 +2055,25 @@ macro_rules! impl_opaque_keys_inner {
 				$crate::codec::Encode::encode(&keys)
 			}
 
+			/// Create a proof of possession of all keys for the given `owner`, as verified by
+			/// [`OpaqueKeys::proof_of_possession_is_valid`](
+			/// $crate::traits::OpaqueKeys::proof_of_possession_is_valid).
+			///
+			/// The private keys are taken from the keystore.
+			///
+			/// Returns `None` if any of the private keys is not available.
+			pub fn create_proof_of_possession(
+				&self,
+				owner: &[u8],
+			) -> Option<$crate::sp_std::vec::Vec<u8>> {
+				let mut proof = $crate::sp_std::vec::Vec::new();
+				$(
+					let signature = $crate::RuntimeAppPublic::sign(&self.$field, &owner)?;
+					$crate::codec::Encode::encode_to(&signature, &mut proof);
+				)*
+				Some(proof)
+			}
+
 			/// Converts `Self` into a `Vec` of `(raw public key, KeyTypeId)`.
 			pub fn into_raw_public_keys(
 				self,
@@ -2108,6 +2134,25 @@ macro_rules! impl_opaque_keys_inner {
 					_ => &[],
 				}
 			}
+
+			fn proof_of_possession_is_valid(&self, owner: &[u8], mut proof: &[u8]) -> bool {
+				$(
+					let signature = match <
+						<
+							<
+								$type as $crate::BoundToRuntimeAppPublic
+							>::Public as $crate::RuntimeAppPublic
+						>::Signature as $crate::codec::Decode
+					>::decode(&mut proof) {
+						Ok(signature) => signature,
+						Err(_) => return false,
+					};
+					if !$crate::RuntimeAppPublic::verify(&self.$field, &owner, &signature) {
+						return false
+					}
+				)*
+				proof.is_empty()
+			}
 		}
 	};
 }
@@ -2293,6 +2338,12 @@ pub trait BlockIdTo<Block: self::Block> {
 }
 
 /// Get current block number
+///
+/// The provided block number is not required to increase by one with every block. For example a
+/// parachain which only produces a block every few relay chain blocks, as with Agile Coretime, may
+/// use the block number of the relay chain. Users of a provider which skips blocks must not expect
+/// to observe every block number; e.g. the scheduler pallet services the agendas of all blocks up
+/// to the current block number.
 pub trait BlockNumberProvider {
 	/// Type of `BlockNumber` to provide.
 	type BlockNumber: Codec
@@ -156,6 +156,20 @@ pub trait OnOffenceHandler<Reporter, Offender, Res> {
 		slash_fraction: &[Perbill],
 		session: SessionIndex,
 	) -> Res;
+
+	/// A handler for an offence of the given `kind`.
+	///
+	/// This is what gets called when an offence is reported, which allows implementers to handle
+	/// offences differently depending on their kind. By default the kind is ignored and the
+	/// offence is passed on to [`Self::on_offence`].
+	fn on_offence_of_kind(
+		_kind: &Kind,
+		offenders: &[OffenceDetails<Reporter, Offender>],
+		slash_fraction: &[Perbill],
+		session: SessionIndex,
+	) -> Res {
+		Self::on_offence(offenders, slash_fraction, session)
+	}
 }
 
 impl<Reporter, Offender, Res: Default> OnOffenceHandler<Reporter, Offender, Res> for () {